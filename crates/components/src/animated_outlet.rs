@@ -0,0 +1,288 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::{
+    Outlet,
+    Routable,
+};
+use freya_elements as dioxus_elements;
+use freya_hooks::{
+    apply_value,
+    use_animation_clock,
+    use_node_signal,
+    use_platform,
+    Ease,
+    Function,
+};
+
+use crate::{
+    use_animated_router,
+    AnimatedRouterContext,
+};
+
+/// Transition used by [AnimatedOutlet] to go from a route to another.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RouteTransition {
+    /// Fade out the previous route while fading in the new one.
+    #[default]
+    Fade,
+    /// Push the previous route to the left while the new one comes from the right.
+    SlideHorizontal,
+    /// Push the previous route to the top while the new one comes from the bottom.
+    SlideVertical,
+}
+
+impl RouteTransition {
+    /// Get the opacity and offsets of the previous and new routes for the given progress, from `0.0` to `1.0`.
+    fn values(&self, progress: f32, width: f32, height: f32) -> [(f32, f32, f32); 2] {
+        match self {
+            Self::Fade => [(1. - progress, 0., 0.), (progress, 0., 0.)],
+            Self::SlideHorizontal => [
+                (1., -width * progress, 0.),
+                (1., width * (1. - progress), 0.),
+            ],
+            Self::SlideVertical => [
+                (1., 0., -height * progress),
+                (1., 0., height * (1. - progress)),
+            ],
+        }
+    }
+}
+
+/// Render the current route like an `Outlet`, but animating the transition from the previous route.
+///
+/// It must be descendant of an [AnimatedRouter](crate::AnimatedRouter).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// # #[allow(non_snake_case)]
+/// fn AppSidebar() -> Element {
+///     rsx!(
+///         NativeRouter {
+///             AnimatedRouter::<Route> {
+///                 Link {
+///                     to: Route::Other,
+///                     label {
+///                         "Other"
+///                     }
+///                 }
+///                 AnimatedOutlet::<Route> {
+///                     transition: RouteTransition::SlideHorizontal,
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// #[rustfmt::skip]
+/// #[derive(Routable, Clone, PartialEq)]
+/// # pub enum Route {
+/// #     #[layout(AppSidebar)]
+/// #         #[route("/")]
+/// #         Home,
+/// #         #[route("/other")]
+/// #         Other,
+/// # }
+/// #
+/// # #[component]
+/// # fn Home() -> Element {
+/// #     rsx!(label { "Home" })
+/// # }
+/// #
+/// # #[component]
+/// # fn Other() -> Element {
+/// #     rsx!(label { "Other" })
+/// # }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn AnimatedOutlet<R: Routable + PartialEq + Clone>(
+    #[props(default)] transition: RouteTransition,
+    #[props(default = Function::Expo)] function: Function,
+    #[props(default = Duration::from_millis(350))] duration: Duration,
+    #[props(default = Ease::Out)] ease: Ease,
+    /// Nesting level of the outlet in the routes, `1` when it is only inside one layout.
+    #[props(default = 1)]
+    level: usize,
+) -> Element {
+    let mut animated_router = use_animated_router::<R>();
+    let (reference, size) = use_node_signal();
+    let platform = use_platform();
+    let clock = use_animation_clock();
+    let mut progress = use_signal(|| 0f32);
+    let mut task = use_signal::<Option<Task>>(|| None);
+
+    use_effect(move || {
+        if !matches!(*animated_router.read(), AnimatedRouterContext::FromTo(..)) {
+            return;
+        }
+
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+        progress.set(0.);
+
+        let mut ticker = platform.new_ticker();
+        let clock = clock.clone();
+        let transition_task = spawn(async move {
+            let start = clock.now();
+            loop {
+                platform.request_animation_frame();
+
+                let elapsed = clock.now() - start;
+                if elapsed >= duration {
+                    break;
+                }
+
+                progress.set(apply_value(
+                    0.,
+                    1.,
+                    elapsed.as_millis(),
+                    duration,
+                    ease,
+                    function,
+                ));
+
+                ticker.tick().await;
+            }
+
+            task.write().take();
+            // Only render the new route once the transition has finished
+            animated_router.write().settle();
+            progress.set(0.);
+        });
+        task.set(Some(transition_task));
+    });
+
+    let (from, progress) = match &*animated_router.read() {
+        AnimatedRouterContext::FromTo(from, _) => (Some(from.clone()), progress()),
+        AnimatedRouterContext::In(_) => (None, 1.),
+    };
+    let size = size.read().area.size;
+    let [(from_opacity, from_offset_x, from_offset_y), (to_opacity, to_offset_x, to_offset_y)] =
+        transition.values(progress, size.width, size.height);
+
+    rsx!(
+        rect {
+            reference,
+            width: "fill",
+            height: "fill",
+            overflow: "clip",
+            if let Some(from) = from {
+                rect {
+                    position: "absolute",
+                    position_top: "0",
+                    position_left: "0",
+                    width: "100%",
+                    height: "100%",
+                    opacity: "{from_opacity}",
+                    offset_x: "{from_offset_x}",
+                    offset_y: "{from_offset_y}",
+                    {from.render(level)}
+                }
+            }
+            rect {
+                width: "100%",
+                height: "100%",
+                opacity: "{to_opacity}",
+                offset_x: "{to_offset_x}",
+                offset_y: "{to_offset_y}",
+                Outlet::<R> {}
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use dioxus_router::prelude::{
+        Routable,
+        Router,
+    };
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn animated_outlet() {
+        #[derive(Routable, Clone, PartialEq)]
+        #[rustfmt::skip]
+        pub enum Route {
+            #[layout(App)]
+                #[route("/")]
+                Home,
+                #[route("/other")]
+                Other,
+        }
+
+        #[allow(non_snake_case)]
+        #[component]
+        fn Home() -> Element {
+            rsx!(
+                label {
+                    "Home"
+                }
+            )
+        }
+
+        #[allow(non_snake_case)]
+        #[component]
+        fn Other() -> Element {
+            rsx!(
+                label {
+                    "Other"
+                }
+            )
+        }
+
+        #[allow(non_snake_case)]
+        fn App() -> Element {
+            rsx!(
+                NativeRouter {
+                    AnimatedRouter::<Route> {
+                        Link {
+                            to: Route::Other,
+                            label {
+                                "Go to Other"
+                            }
+                        }
+                        AnimatedOutlet::<Route> {
+                            transition: RouteTransition::Fade,
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(|| -> Element { rsx!(Router::<Route> {}) });
+        utils.wait_for_update().await;
+
+        let outlet = utils.root().get(0).get(1);
+        assert_eq!(outlet.children_ids().len(), 1);
+        assert_eq!(outlet.get(0).get(0).get(0).text(), Some("Home"));
+
+        utils.click_cursor((5., 5.)).await;
+        utils.advance_time(Duration::from_millis(100)).await;
+
+        // Both routes are rendered during the transition
+        let outlet = utils.root().get(0).get(1);
+        assert_eq!(outlet.children_ids().len(), 2);
+        assert_eq!(outlet.get(0).get(0).get(0).text(), Some("Home"));
+        assert_eq!(outlet.get(1).get(0).get(0).text(), Some("Other"));
+        let from_opacity = *outlet.get(0).state().transform.opacities.last().unwrap();
+        let to_opacity = *outlet.get(1).state().transform.opacities.last().unwrap();
+        assert!(from_opacity < 1.);
+        assert!(to_opacity > 0.);
+        assert!((from_opacity + to_opacity - 1.).abs() < 0.001);
+
+        utils.advance_time(Duration::from_millis(400)).await;
+
+        // Only the new route is rendered once the transition finished
+        let outlet = utils.root().get(0).get(1);
+        assert_eq!(outlet.children_ids().len(), 1);
+        assert_eq!(outlet.get(0).get(0).get(0).text(), Some("Other"));
+    }
+}
@@ -0,0 +1,218 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use freya_elements as dioxus_elements;
+use freya_hooks::{
+    use_animation_with_dependencies,
+    AnimNum,
+    Ease,
+    Function,
+};
+
+/// Transition used by [AnimatedPresence] to show and hide its content.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PresenceTransition {
+    /// Fade in and out.
+    #[default]
+    Fade,
+    /// Fade while growing in and shrinking out.
+    Scale,
+    /// Fade while sliding up in and down out.
+    Slide,
+}
+
+impl PresenceTransition {
+    /// Distance in pixels that [PresenceTransition::Slide] travels.
+    const SLIDE_DISTANCE: f32 = 20.;
+
+    /// Scale that [PresenceTransition::Scale] starts from.
+    const SCALE_ORIGIN: f32 = 0.9;
+
+    /// Get the opacity, scale and vertical offset for the given progress, from `0.0` (hidden) to `1.0` (shown).
+    fn values(&self, progress: f32) -> (f32, f32, f32) {
+        match self {
+            Self::Fade => (progress, 1., 0.),
+            Self::Scale => (
+                progress,
+                Self::SCALE_ORIGIN + (1. - Self::SCALE_ORIGIN) * progress,
+                0.,
+            ),
+            Self::Slide => (progress, 1., Self::SLIDE_DISTANCE * (1. - progress)),
+        }
+    }
+}
+
+/// Animate its content when it is shown or hidden with `visible`.
+///
+/// Hiding plays the exit transition first, the content is only unmounted once it finishes.
+/// Showing mounts the content and plays the enter transition,
+/// also on the first render unless `initial` is `false`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut show = use_signal(|| true);
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| show.toggle(),
+///             label {
+///                 "Toggle"
+///             }
+///         }
+///         AnimatedPresence {
+///             visible: show(),
+///             transition: PresenceTransition::Slide,
+///             label {
+///                 "Hello, World!"
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[component]
+pub fn AnimatedPresence(
+    children: Element,
+    /// Whether the content is shown.
+    visible: bool,
+    /// Play the enter transition when first rendered. Defaults to `true`.
+    #[props(default = true)]
+    initial: bool,
+    #[props(default = "auto".into())] width: String,
+    #[props(default = "auto".into())] height: String,
+    #[props(default)] transition: PresenceTransition,
+    #[props(default = Function::default())] function: Function,
+    #[props(default = Duration::from_millis(250))] duration: Duration,
+    #[props(default = Ease::default())] ease: Ease,
+) -> Element {
+    let mut is_mounted = use_signal(|| visible);
+    let mut is_visible = use_signal(|| visible);
+
+    let animation = use_animation_with_dependencies(
+        &(function, duration, ease),
+        move |_conf, (function, duration, ease)| {
+            AnimNum::new(0., 1.)
+                .duration(duration)
+                .ease(ease)
+                .function(function)
+        },
+    );
+
+    use_effect(use_reactive!(|visible| {
+        is_visible.set(visible);
+        if visible {
+            is_mounted.set(true);
+            if initial || animation.peek_has_run_yet() {
+                animation.start();
+            }
+        } else if *is_mounted.peek() {
+            animation.reverse();
+        }
+    }));
+
+    // Unmount the content once the exit transition has finished
+    use_effect(move || {
+        let progress = animation.get().read().read();
+        if !is_visible() && !animation.is_running() && progress == 0. {
+            is_mounted.set(false);
+        }
+    });
+
+    let progress = if animation.has_run_yet() {
+        animation.get().read().read()
+    } else if initial {
+        0.
+    } else {
+        1.
+    };
+    let (opacity, scale, offset_y) = transition.values(progress);
+
+    rsx!(
+        if is_mounted() {
+            rect {
+                width: "{width}",
+                height: "{height}",
+                opacity: "{opacity}",
+                scale: "{scale}",
+                offset_y: "{offset_y}",
+                {children}
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn animated_presence() {
+        fn animated_presence_app() -> Element {
+            let mut show = use_signal(|| true);
+
+            rsx!(
+                rect {
+                    width: "100%",
+                    height: "100%",
+                    onclick: move |_| show.toggle(),
+                    AnimatedPresence {
+                        visible: show(),
+                        function: Function::Linear,
+                        rect {
+                            width: "50",
+                            height: "50",
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(animated_presence_app);
+
+        let root = utils.root();
+        let opacity = || {
+            root.get(0)
+                .get(0)
+                .state()
+                .transform
+                .opacities
+                .last()
+                .copied()
+        };
+
+        // Enter transition
+        utils.wait_for_update().await;
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(opacity(), Some(1.0));
+
+        // Disable event loop ticker
+        utils.config().event_loop_ticker = false;
+
+        // The content is still mounted during the exit transition
+        utils.click_cursor((5.0, 5.0)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        tokio::time::sleep(Duration::from_millis(125)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert!(root.get(0).get(0).is_element());
+        assert!(opacity().unwrap() > 0.);
+        assert!(opacity().unwrap() < 1.);
+
+        utils.config().event_loop_ticker = true;
+
+        // And unmounted once it finishes
+        utils.wait_for_update().await;
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert!(root.get(0).get(0).is_placeholder());
+    }
+}
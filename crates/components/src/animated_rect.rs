@@ -0,0 +1,192 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use freya_elements as dioxus_elements;
+use freya_hooks::{
+    use_animation_with_dependencies,
+    use_node_signal_with_prev,
+    AnimDirection,
+    AnimNum,
+    Ease,
+    Function,
+};
+
+/// Animate the position and size of an element every time its layout changes,
+/// e.g when a list is reordered or a panel is expanded.
+///
+/// The content starts from the previous layout and moves towards the new one,
+/// so no manual measurements are needed.
+///
+/// `width` and `height` must not depend on the content (e.g `auto`),
+/// as the content is what gets animated.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut expanded = use_signal(|| false);
+///     let height = if expanded() { 200 } else { 50 };
+///
+///     rsx!(
+///         AnimatedRect {
+///             width: "100%",
+///             height: "{height}",
+///             rect {
+///                 width: "100%",
+///                 height: "100%",
+///                 background: "rgb(235, 235, 235)",
+///                 onclick: move |_| expanded.toggle(),
+///                 label {
+///                     "Click to expand"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[component]
+pub fn AnimatedRect(
+    children: Element,
+    #[props(into)] width: String,
+    #[props(into)] height: String,
+    #[props(default = Function::default())] function: Function,
+    #[props(default = Duration::from_millis(250))] duration: Duration,
+    #[props(default = Ease::default())] ease: Ease,
+) -> Element {
+    let (reference, size, old_size) = use_node_signal_with_prev();
+
+    let animation = use_animation_with_dependencies(
+        &(function, duration, ease),
+        move |_conf, (function, duration, ease)| {
+            let size = size().unwrap_or_default();
+            let old_size = old_size().unwrap_or(size.clone());
+            (
+                AnimNum::new(0., old_size.area.origin.x - size.area.origin.x)
+                    .duration(duration)
+                    .ease(ease)
+                    .function(function),
+                AnimNum::new(0., old_size.area.origin.y - size.area.origin.y)
+                    .duration(duration)
+                    .ease(ease)
+                    .function(function),
+                AnimNum::new(size.area.width(), old_size.area.width())
+                    .duration(duration)
+                    .ease(ease)
+                    .function(function),
+                AnimNum::new(size.area.height(), old_size.area.height())
+                    .duration(duration)
+                    .ease(ease)
+                    .function(function),
+            )
+        },
+    );
+
+    use_effect(move || {
+        let has_size = size.read().is_some();
+        let has_old_size = old_size.read().is_some();
+        if has_size && has_old_size {
+            animation.run(AnimDirection::Reverse);
+        }
+    });
+
+    let (offset_x, offset_y, animated_width, animated_height) = &*animation.get().read_unchecked();
+    let offset_x = offset_x.read();
+    let offset_y = offset_y.read();
+
+    // Fill the container until its layout is known
+    let (inner_width, inner_height) = if size.read().is_some() {
+        (
+            animated_width.read().to_string(),
+            animated_height.read().to_string(),
+        )
+    } else {
+        ("100%".to_string(), "100%".to_string())
+    };
+
+    rsx!(
+        rect {
+            reference,
+            width: "{width}",
+            height: "{height}",
+            rect {
+                width: "{inner_width}",
+                height: "{inner_height}",
+                offset_x: "{offset_x}",
+                offset_y: "{offset_y}",
+                {children}
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn animated_rect() {
+        fn animated_rect_app() -> Element {
+            let mut padding = use_signal(|| 100.);
+            let mut width = use_signal(|| 50.);
+
+            rsx!(
+                rect {
+                    padding: "{padding()}",
+                    onclick: move |_| {
+                        padding += 10.;
+                        width += 50.;
+                    },
+                    AnimatedRect {
+                        width: "{width()}",
+                        height: "50",
+                        function: Function::Linear
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(animated_rect_app);
+
+        // Disable event loop ticker
+        utils.config().event_loop_ticker = false;
+
+        let root = utils.root();
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let get_area = || root.get(0).get(0).get(0).layout().unwrap().area;
+
+        assert_eq!(get_area().origin.x, 100.);
+        assert_eq!(get_area().origin.y, 100.);
+        assert_eq!(get_area().width(), 50.);
+
+        utils.click_cursor((5.0, 5.0)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        tokio::time::sleep(Duration::from_millis(125)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Halfway between the old and new layouts
+        assert!(get_area().origin.x > 100.);
+        assert!(get_area().origin.x < 110.);
+        assert!(get_area().origin.y > 100.);
+        assert!(get_area().origin.y < 110.);
+        assert!(get_area().width() > 50.);
+        assert!(get_area().width() < 100.);
+
+        utils.config().event_loop_ticker = true;
+
+        utils.wait_for_update().await;
+        tokio::time::sleep(Duration::from_millis(125)).await;
+        utils.wait_for_update().await;
+
+        assert_eq!(get_area().origin.x, 110.);
+        assert_eq!(get_area().origin.y, 110.);
+        assert_eq!(get_area().width(), 100.);
+    }
+}
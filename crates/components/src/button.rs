@@ -11,13 +11,17 @@ use freya_elements::{
     TouchPhase,
 };
 use freya_hooks::{
+    theme_with,
     use_applied_theme,
     use_focus,
     use_platform,
     ButtonTheme,
     ButtonThemeWith,
+    LoaderThemeWith,
 };
 
+use crate::Loader;
+
 /// Properties for the [`Button`], [`FilledButton`] and [`OutlineButton`] components.
 #[derive(Props, Clone, PartialEq)]
 pub struct ButtonProps {
@@ -29,12 +33,56 @@ pub struct ButtonProps {
     pub onpress: Option<EventHandler<PressEvent>>,
     /// Event handler for when the button is clicked. Not recommended, use `onpress` instead.
     pub onclick: Option<EventHandler<()>>,
+    /// Look of the button, only used by [`Button`].
+    #[props(default)]
+    pub variant: ButtonVariant,
+    /// Size of the button.
+    #[props(default)]
+    pub size: ButtonSize,
+    /// Show a spinner instead of the leading element and ignore the presses, e.g. while the action is running.
+    #[props(default = false)]
+    pub loading: bool,
+    /// Optional element to be placed before the inner children of the button, such as an icon.
+    pub leading: Option<Element>,
+    /// Optional element to be placed after the inner children of the button, such as an icon.
+    pub trailing: Option<Element>,
+}
+
+/// Look of a [`Button`], each one with its own theme.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum ButtonVariant {
+    /// Inherits the `button` theme.
+    #[default]
+    Normal,
+    /// Inherits the `filled_button` theme, same as [`FilledButton`].
+    Filled,
+    /// Inherits the `outline_button` theme, same as [`OutlineButton`].
+    Outline,
+    /// Inherits the `ghost_button` theme, without background until it's hovered.
+    Ghost,
+    /// Inherits the `danger_button` theme, for destructive actions.
+    Danger,
+}
+
+/// Size presets of the buttons.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum ButtonSize {
+    /// Smaller padding and font size.
+    Small,
+    /// Padding of the theme and the inherited font size.
+    #[default]
+    Medium,
+    /// Bigger padding and font size.
+    Large,
 }
 
 /// Clickable button.
 ///
+/// Use `variant` to pick its look and `size` to pick its size,
+/// `leading` and `trailing` to place icons around the children, and `loading` to show a spinner.
+///
 /// # Styling
-/// Inherits the [`ButtonTheme`](freya_hooks::ButtonTheme) theme.
+/// Inherits the [`ButtonTheme`](freya_hooks::ButtonTheme) theme of its [`ButtonVariant`].
 ///
 /// # Example
 ///
@@ -48,6 +96,14 @@ pub struct ButtonProps {
 ///                 "Click this"
 ///             }
 ///         }
+///         Button {
+///             variant: ButtonVariant::Danger,
+///             size: ButtonSize::Small,
+///             loading: true,
+///             label {
+///                 "Deleting"
+///             }
+///         }
 ///     )
 /// }
 /// # use freya_testing::prelude::*;
@@ -67,12 +123,22 @@ pub struct ButtonProps {
 )]
 #[allow(non_snake_case)]
 pub fn Button(props: ButtonProps) -> Element {
-    let theme = use_applied_theme!(&props.theme, button);
+    let theme = match props.variant {
+        ButtonVariant::Normal => use_applied_theme!(&props.theme, button),
+        ButtonVariant::Filled => use_applied_theme!(&props.theme, filled_button),
+        ButtonVariant::Outline => use_applied_theme!(&props.theme, outline_button),
+        ButtonVariant::Ghost => use_applied_theme!(&props.theme, ghost_button),
+        ButtonVariant::Danger => use_applied_theme!(&props.theme, danger_button),
+    };
     ButtonBase(BaseButtonProps {
         theme,
         children: props.children,
         onpress: props.onpress,
         onclick: props.onclick,
+        size: props.size,
+        loading: props.loading,
+        leading: props.leading,
+        trailing: props.trailing,
     })
 }
 
@@ -118,6 +184,10 @@ pub fn FilledButton(props: ButtonProps) -> Element {
         children: props.children,
         onpress: props.onpress,
         onclick: props.onclick,
+        size: props.size,
+        loading: props.loading,
+        leading: props.leading,
+        trailing: props.trailing,
     })
 }
 
@@ -163,6 +233,10 @@ pub fn OutlineButton(props: ButtonProps) -> Element {
         children: props.children,
         onpress: props.onpress,
         onclick: props.onclick,
+        size: props.size,
+        loading: props.loading,
+        leading: props.leading,
+        trailing: props.trailing,
     })
 }
 
@@ -193,6 +267,16 @@ pub struct BaseButtonProps {
     pub onpress: Option<EventHandler<PressEvent>>,
     /// Event handler for when the button is clicked. Not recommended, use `onpress` instead.
     pub onclick: Option<EventHandler<()>>,
+    /// Size of the button.
+    #[props(default)]
+    pub size: ButtonSize,
+    /// Show a spinner instead of the leading element and ignore the presses.
+    #[props(default = false)]
+    pub loading: bool,
+    /// Optional element to be placed before the inner children of the button.
+    pub leading: Option<Element>,
+    /// Optional element to be placed after the inner children of the button.
+    pub trailing: Option<Element>,
 }
 
 /// Identifies the current status of the Button.
@@ -212,6 +296,10 @@ pub fn ButtonBase(
         children,
         theme,
         onclick,
+        size,
+        loading,
+        leading,
+        trailing,
     }: BaseButtonProps,
 ) -> Element {
     let mut focus = use_focus();
@@ -238,6 +326,9 @@ pub fn ButtonBase(
         to_owned![onpress, onclick];
         move |ev: PointerEvent| {
             focus.request_focus();
+            if loading {
+                return;
+            }
             if let Some(onpress) = &onpress {
                 let is_valid = match ev.data.pointer_type {
                     PointerType::Mouse {
@@ -268,7 +359,9 @@ pub fn ButtonBase(
     });
 
     let onmouseenter = move |_| {
-        platform.set_cursor(CursorIcon::Pointer);
+        if !loading {
+            platform.set_cursor(CursorIcon::Pointer);
+        }
         status.set(ButtonStatus::Hovering);
     };
 
@@ -278,7 +371,7 @@ pub fn ButtonBase(
     };
 
     let onkeydown = move |ev: KeyboardEvent| {
-        if focus.validate_keydown(&ev) {
+        if !loading && focus.validate_keydown(&ev) {
             if let Some(onpress) = &onpress {
                 onpress.call(PressEvent::Key(ev))
             }
@@ -294,6 +387,12 @@ pub fn ButtonBase(
     } else {
         format!("1 inner {border_fill}")
     };
+    let (padding, font_size, loader_size) = match size {
+        ButtonSize::Small => ("4 8".into(), "13", "13"),
+        ButtonSize::Medium => (padding, "", "16"),
+        ButtonSize::Large => ("10 18".into(), "18", "20"),
+    };
+    let has_slots = loading || leading.is_some() || trailing.is_some();
 
     rsx!(
         rect {
@@ -309,6 +408,7 @@ pub fn ButtonBase(
             overflow: "clip",
             a11y_role:"button",
             color: "{font_theme.color}",
+            font_size: if size != ButtonSize::Medium { "{font_size}" },
             shadow: "{shadow}",
             border,
             corner_radius: "{corner_radius}",
@@ -316,7 +416,20 @@ pub fn ButtonBase(
             text_height: "disable-least-ascent",
             main_align: "center",
             cross_align: "center",
+            direction: if has_slots { "horizontal" },
+            spacing: if has_slots { "6" },
+            if loading {
+                Loader {
+                    size: loader_size,
+                    theme: theme_with!(LoaderTheme {
+                        primary_color: font_theme.color.clone(),
+                    })
+                }
+            } else if let Some(leading) = leading {
+                {leading}
+            }
             {&children}
+            {trailing}
         }
     )
 }
@@ -372,4 +485,49 @@ mod test {
 
         assert_eq!(label.get(0).text(), Some("false"));
     }
+
+    #[tokio::test]
+    pub async fn loading_button() {
+        fn button_app() -> Element {
+            let mut loading = use_signal(|| false);
+            let mut presses = use_signal(|| 0);
+
+            rsx!(
+                Button {
+                    variant: ButtonVariant::Danger,
+                    loading: loading(),
+                    onpress: move |_| {
+                        presses += 1;
+                        loading.set(true);
+                    },
+                    trailing: rsx!(
+                        label {
+                            "x"
+                        }
+                    ),
+                    label {
+                        "{presses}"
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(button_app);
+        let button = utils.root().get(0);
+        utils.wait_for_update().await;
+
+        // The trailing element is placed after the children
+        assert!(button.get(0).is_placeholder());
+        assert_eq!(button.get(1).get(0).text(), Some("0"));
+        assert!(button.get(2).area().unwrap().min_x() >= button.get(1).area().unwrap().max_x());
+
+        utils.click_cursor((15.0, 15.0)).await;
+        assert_eq!(button.get(1).get(0).text(), Some("1"));
+
+        // The spinner is shown and the presses are ignored while loading
+        assert!(button.get(0).is_element());
+        utils.click_cursor((15.0, 15.0)).await;
+        utils.press(Key::Enter).await;
+        assert_eq!(button.get(1).get(0).text(), Some("1"));
+    }
 }
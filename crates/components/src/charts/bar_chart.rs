@@ -0,0 +1,315 @@
+use dioxus::prelude::*;
+use freya_core::{
+    custom_attributes::CanvasRunnerContext,
+    parsing::Parse,
+};
+use freya_elements::{
+    self as dioxus_elements,
+    events::MouseEvent,
+};
+use freya_engine::prelude::*;
+use freya_hooks::{
+    use_animation_with_dependencies,
+    use_applied_theme,
+    use_canvas_with_deps,
+    use_node_signal,
+    use_platform,
+    AnimNum,
+    ChartTheme,
+    ChartThemeWith,
+    Ease,
+    OnDepsChange,
+};
+
+use super::{
+    draw_axes,
+    format_value,
+    item_color,
+    parse_palette,
+    ChartHover,
+    ChartScale,
+    ChartSeries,
+    ChartTooltip,
+    PlotArea,
+    ANIMATION_TIME,
+};
+
+/// Portion of the space of every label used by its bars.
+const GROUP_WIDTH: f32 = 0.7;
+/// Space between the bars of the same label.
+const BAR_GAP: f32 = 2.;
+
+/// Properties for the [`BarChart`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct BarChartProps {
+    /// Theme override.
+    pub theme: Option<ChartThemeWith>,
+    /// Labels of the X axis, one for every value of the series.
+    pub labels: Vec<String>,
+    /// Series of the chart, the values of every label are shown side by side.
+    pub series: Vec<ChartSeries>,
+    /// Animate the bars when the data changes. Default to `true`.
+    #[props(default = true)]
+    pub animated: bool,
+}
+
+/// Rectangle of every value of every series, in the same order.
+fn bar_rects(plot: &PlotArea, scale: &ChartScale, props: &BarChartProps) -> Vec<Vec<Rect>> {
+    let slots = props.labels.len();
+    let group_width = plot.slot_width(slots) * GROUP_WIDTH;
+    let bar_width = group_width / props.series.len().max(1) as f32;
+    let baseline_y = plot.value_y(scale.baseline(), scale);
+    props
+        .series
+        .iter()
+        .enumerate()
+        .map(|(item, series)| {
+            series
+                .values
+                .iter()
+                .take(slots)
+                .enumerate()
+                .map(|(i, value)| {
+                    let left = plot.slot_center(i, slots) - group_width / 2.
+                        + bar_width * item as f32
+                        + BAR_GAP / 2.;
+                    let right = left + (bar_width - BAR_GAP).max(1.);
+                    let value_y = plot.value_y(*value, scale);
+                    Rect::new(
+                        left,
+                        value_y.min(baseline_y),
+                        right,
+                        value_y.max(baseline_y),
+                    )
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Bar under the cursor.
+fn hit_test(
+    (x, y): (f32, f32),
+    (width, height): (f32, f32),
+    props: &BarChartProps,
+) -> Option<ChartHover> {
+    let plot = PlotArea::new(width, height);
+    let scale = ChartScale::new(props.series.iter().flat_map(|s| s.values.clone()));
+    bar_rects(&plot, &scale, props)
+        .into_iter()
+        .enumerate()
+        .find_map(|(item, rects)| {
+            let index = rects.iter().position(|rect| {
+                // Short bars are still easy to hover
+                let top = rect.top.min(rect.bottom - 4.);
+                x >= rect.left && x <= rect.right && y >= top && y <= rect.bottom
+            })?;
+            let rect = rects[index];
+            let series = &props.series[item];
+            Some(ChartHover {
+                item,
+                index,
+                x: rect.center_x(),
+                y: rect.top,
+                text: format!(
+                    "{} · {}: {}",
+                    props.labels[index],
+                    series.name,
+                    format_value(series.values[index])
+                ),
+            })
+        })
+}
+
+/// Compare series of values with bars over the labels of the X axis.
+///
+/// Hovering a bar shows its value in a tooltip.
+///
+/// # Styling
+/// Inherits the [`ChartTheme`](freya_hooks::ChartTheme) theme.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(BarChart {
+///         labels: vec!["Q1".to_string(), "Q2".to_string(), "Q3".to_string()],
+///         series: vec![
+///             ChartSeries::new("2023", [40., 55., 35.]),
+///             ChartSeries::new("2024", [50., 60., 70.]),
+///         ],
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn BarChart(props: BarChartProps) -> Element {
+    let platform = use_platform();
+    let (reference, size) = use_node_signal();
+    let mut hover = use_signal(|| None::<ChartHover>);
+    let ChartTheme {
+        width,
+        height,
+        background,
+        color,
+        grid_color,
+        palette,
+    } = use_applied_theme!(&props.theme, chart);
+
+    let animation = use_animation_with_dependencies(&props.series, |conf, _| {
+        conf.auto_start(true);
+        conf.on_deps_change(OnDepsChange::Rerun);
+        AnimNum::new(0., 1.).time(ANIMATION_TIME).ease(Ease::Out)
+    });
+    let progress = if props.animated {
+        animation.get().read().read()
+    } else {
+        1.
+    };
+
+    let colors = (
+        Color::parse(&color).unwrap_or(Color::BLACK),
+        Color::parse(&grid_color).unwrap_or(Color::GRAY),
+        parse_palette(&palette),
+    );
+    let hovered = hover.read().as_ref().map(|hover| (hover.item, hover.index));
+
+    let canvas = use_canvas_with_deps(
+        &(props.clone(), colors, progress, hovered),
+        move |(props, (text_color, grid_color, palette), progress, hovered)| {
+            platform.invalidate_drawing_area(size.peek().area);
+            platform.request_animation_frame();
+            move |ctx: &mut CanvasRunnerContext| {
+                ctx.canvas.save();
+                ctx.canvas.translate((ctx.area.min_x(), ctx.area.min_y()));
+                ctx.canvas.scale((ctx.scale_factor, ctx.scale_factor));
+
+                let area = ctx.area.size / ctx.scale_factor;
+                let plot = PlotArea::new(area.width, area.height);
+                let scale = ChartScale::new(props.series.iter().flat_map(|s| s.values.clone()));
+                draw_axes(
+                    ctx.canvas,
+                    ctx.font_collection,
+                    &plot,
+                    &scale,
+                    &props.labels,
+                    text_color,
+                    grid_color,
+                );
+
+                // Grow the bars from the baseline
+                let baseline_y = plot.value_y(scale.baseline(), &scale);
+                for (item, rects) in bar_rects(&plot, &scale, &props).iter().enumerate() {
+                    let series = &props.series[item];
+                    let color = item_color(&palette, series.color.as_deref(), item);
+                    for (i, rect) in rects.iter().enumerate() {
+                        let mut paint = Paint::default();
+                        paint.set_anti_alias(true);
+                        paint.set_style(PaintStyle::Fill);
+                        if hovered.is_some() && hovered != Some((item, i)) {
+                            paint.set_color(color.with_a(170));
+                        } else {
+                            paint.set_color(color);
+                        }
+
+                        let top = baseline_y + (rect.top - baseline_y) * progress;
+                        let bottom = baseline_y + (rect.bottom - baseline_y) * progress;
+                        ctx.canvas
+                            .draw_rect(Rect::new(rect.left, top, rect.right, bottom), &paint);
+                    }
+                }
+
+                ctx.canvas.restore();
+            }
+        },
+    );
+
+    let onmousemove = {
+        let props = props.clone();
+        move |e: MouseEvent| {
+            let cursor = e.get_element_coordinates().to_f32();
+            let area = size.peek().area;
+            let new_hover = hit_test((cursor.x, cursor.y), (area.width(), area.height()), &props);
+            if *hover.peek() != new_hover {
+                hover.set(new_hover);
+            }
+        }
+    };
+
+    let onmouseleave = move |_| hover.set(None);
+
+    rsx!(
+        rect {
+            width: "{width}",
+            height: "{height}",
+            background: "{background}",
+            padding: "8",
+            rect {
+                canvas_reference: canvas.attribute(),
+                reference,
+                onmousemove,
+                onmouseleave,
+                width: "100%",
+                height: "100%",
+                if let Some(hover) = hover() {
+                    ChartTooltip {
+                        hover
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use crate::charts::{
+        ChartScale,
+        PlotArea,
+    };
+
+    #[tokio::test]
+    pub async fn bar_chart() {
+        fn bar_chart_app() -> Element {
+            rsx!(BarChart {
+                theme: theme_with!(ChartTheme {
+                    width: "400".into(),
+                    height: "300".into(),
+                }),
+                labels: vec!["Q1".to_string(), "Q2".to_string()],
+                series: vec![
+                    ChartSeries::new("2023", [40., 55.]),
+                    ChartSeries::new("2024", [50., 60.]),
+                ],
+            })
+        }
+
+        let mut utils = launch_test(bar_chart_app);
+        utils.wait_for_update().await;
+        let canvas = utils.root().get(0).get(0);
+        assert_eq!(canvas.children_ids().len(), 0);
+
+        // Hover the bar of the second series in the first label, at the right of its center
+        let area = canvas.area().unwrap();
+        let plot = PlotArea::new(area.width(), area.height());
+        let scale = ChartScale::new([40., 55., 50., 60.]);
+        let x = plot.slot_center(0, 2) + plot.slot_width(2) * 0.2;
+        let y = plot.value_y(25., &scale);
+        utils
+            .move_cursor(((area.min_x() + x) as f64, (area.min_y() + y) as f64))
+            .await;
+
+        let tooltip = canvas.get(0).get(0).get(0);
+        assert_eq!(tooltip.get(0).text(), Some("Q1 · 2024: 50"));
+
+        // Hover the space between the labels
+        let x = plot.x + plot.slot_width(2);
+        utils
+            .move_cursor(((area.min_x() + x) as f64, (area.min_y() + y) as f64))
+            .await;
+        assert_eq!(canvas.children_ids().len(), 0);
+    }
+}
@@ -0,0 +1,303 @@
+use dioxus::prelude::*;
+use freya_core::{
+    custom_attributes::CanvasRunnerContext,
+    parsing::Parse,
+};
+use freya_elements::{
+    self as dioxus_elements,
+    events::MouseEvent,
+};
+use freya_engine::prelude::*;
+use freya_hooks::{
+    use_animation_with_dependencies,
+    use_applied_theme,
+    use_canvas_with_deps,
+    use_node_signal,
+    use_platform,
+    AnimNum,
+    ChartTheme,
+    ChartThemeWith,
+    Ease,
+    OnDepsChange,
+};
+
+use super::{
+    draw_axes,
+    format_value,
+    item_color,
+    parse_palette,
+    ChartHover,
+    ChartScale,
+    ChartSeries,
+    ChartTooltip,
+    PlotArea,
+    ANIMATION_TIME,
+};
+
+/// Maximum distance from the cursor to a point to show its tooltip.
+const HOVER_DISTANCE: f32 = 24.;
+
+/// Properties for the [`LineChart`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct LineChartProps {
+    /// Theme override.
+    pub theme: Option<ChartThemeWith>,
+    /// Labels of the X axis, one for every value of the series.
+    pub labels: Vec<String>,
+    /// Lines of the chart.
+    pub series: Vec<ChartSeries>,
+    /// Animate the lines when the data changes. Default to `true`.
+    #[props(default = true)]
+    pub animated: bool,
+}
+
+/// Position of every value of every series, in the same order.
+fn line_points(
+    plot: &PlotArea,
+    scale: &ChartScale,
+    props: &LineChartProps,
+) -> Vec<Vec<(f32, f32)>> {
+    let slots = props.labels.len();
+    props
+        .series
+        .iter()
+        .map(|series| {
+            series
+                .values
+                .iter()
+                .take(slots)
+                .enumerate()
+                .map(|(i, value)| (plot.slot_center(i, slots), plot.value_y(*value, scale)))
+                .collect()
+        })
+        .collect()
+}
+
+/// Point closest to the cursor in the label under it, if close enough.
+fn hit_test(
+    (x, y): (f32, f32),
+    (width, height): (f32, f32),
+    props: &LineChartProps,
+) -> Option<ChartHover> {
+    let plot = PlotArea::new(width, height);
+    let scale = ChartScale::new(props.series.iter().flat_map(|s| s.values.clone()));
+    let slot = plot.slot_at(x, props.labels.len())?;
+    let (item, (point_x, point_y)) = line_points(&plot, &scale, props)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(item, points)| Some((item, *points.get(slot)?)))
+        .filter(|(_, (_, point_y))| (point_y - y).abs() <= HOVER_DISTANCE)
+        .min_by(|(_, (_, a)), (_, (_, b))| (a - y).abs().total_cmp(&(b - y).abs()))?;
+    let series = &props.series[item];
+    Some(ChartHover {
+        item,
+        index: slot,
+        x: point_x,
+        y: point_y,
+        text: format!(
+            "{} · {}: {}",
+            props.labels[slot],
+            series.name,
+            format_value(series.values[slot])
+        ),
+    })
+}
+
+/// Plot series of values as lines over the labels of the X axis.
+///
+/// Hovering a point shows its value in a tooltip.
+///
+/// # Styling
+/// Inherits the [`ChartTheme`](freya_hooks::ChartTheme) theme.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(LineChart {
+///         labels: vec!["Jan".to_string(), "Feb".to_string(), "Mar".to_string()],
+///         series: vec![
+///             ChartSeries::new("Visits", [120., 180., 150.]),
+///             ChartSeries::new("Sales", [30., 45., 60.]).with_color("rgb(234, 88, 12)"),
+///         ],
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn LineChart(props: LineChartProps) -> Element {
+    let platform = use_platform();
+    let (reference, size) = use_node_signal();
+    let mut hover = use_signal(|| None::<ChartHover>);
+    let ChartTheme {
+        width,
+        height,
+        background,
+        color,
+        grid_color,
+        palette,
+    } = use_applied_theme!(&props.theme, chart);
+
+    let animation = use_animation_with_dependencies(&props.series, |conf, _| {
+        conf.auto_start(true);
+        conf.on_deps_change(OnDepsChange::Rerun);
+        AnimNum::new(0., 1.).time(ANIMATION_TIME).ease(Ease::Out)
+    });
+    let progress = if props.animated {
+        animation.get().read().read()
+    } else {
+        1.
+    };
+
+    let colors = (
+        Color::parse(&color).unwrap_or(Color::BLACK),
+        Color::parse(&grid_color).unwrap_or(Color::GRAY),
+        parse_palette(&palette),
+    );
+    let hovered = hover.read().as_ref().map(|hover| (hover.item, hover.index));
+
+    let canvas = use_canvas_with_deps(
+        &(props.clone(), colors, progress, hovered),
+        move |(props, (text_color, grid_color, palette), progress, hovered)| {
+            platform.invalidate_drawing_area(size.peek().area);
+            platform.request_animation_frame();
+            move |ctx: &mut CanvasRunnerContext| {
+                ctx.canvas.save();
+                ctx.canvas.translate((ctx.area.min_x(), ctx.area.min_y()));
+                ctx.canvas.scale((ctx.scale_factor, ctx.scale_factor));
+
+                let area = ctx.area.size / ctx.scale_factor;
+                let plot = PlotArea::new(area.width, area.height);
+                let scale = ChartScale::new(props.series.iter().flat_map(|s| s.values.clone()));
+                draw_axes(
+                    ctx.canvas,
+                    ctx.font_collection,
+                    &plot,
+                    &scale,
+                    &props.labels,
+                    text_color,
+                    grid_color,
+                );
+
+                // Grow the lines from the baseline
+                let baseline_y = plot.value_y(scale.baseline(), &scale);
+                for (item, points) in line_points(&plot, &scale, &props).iter().enumerate() {
+                    let series = &props.series[item];
+                    let mut paint = Paint::default();
+                    paint.set_anti_alias(true);
+                    paint.set_style(PaintStyle::Stroke);
+                    paint.set_stroke_width(2.);
+                    paint.set_color(item_color(&palette, series.color.as_deref(), item));
+
+                    let points = points
+                        .iter()
+                        .map(|(x, y)| (*x, baseline_y + (y - baseline_y) * progress))
+                        .collect::<Vec<_>>();
+
+                    let mut path = Path::new();
+                    for (i, point) in points.iter().enumerate() {
+                        if i == 0 {
+                            path.move_to(*point);
+                        } else {
+                            path.line_to(*point);
+                        }
+                    }
+                    ctx.canvas.draw_path(&path, &paint);
+
+                    paint.set_style(PaintStyle::Fill);
+                    for (i, point) in points.iter().enumerate() {
+                        let radius = if hovered == Some((item, i)) { 6. } else { 3.5 };
+                        ctx.canvas.draw_circle(*point, radius, &paint);
+                    }
+                }
+
+                ctx.canvas.restore();
+            }
+        },
+    );
+
+    let onmousemove = {
+        let props = props.clone();
+        move |e: MouseEvent| {
+            let cursor = e.get_element_coordinates().to_f32();
+            let area = size.peek().area;
+            let new_hover = hit_test((cursor.x, cursor.y), (area.width(), area.height()), &props);
+            if *hover.peek() != new_hover {
+                hover.set(new_hover);
+            }
+        }
+    };
+
+    let onmouseleave = move |_| hover.set(None);
+
+    rsx!(
+        rect {
+            width: "{width}",
+            height: "{height}",
+            background: "{background}",
+            padding: "8",
+            rect {
+                canvas_reference: canvas.attribute(),
+                reference,
+                onmousemove,
+                onmouseleave,
+                width: "100%",
+                height: "100%",
+                if let Some(hover) = hover() {
+                    ChartTooltip {
+                        hover
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use crate::charts::{
+        ChartScale,
+        PlotArea,
+    };
+
+    #[tokio::test]
+    pub async fn line_chart() {
+        fn line_chart_app() -> Element {
+            rsx!(LineChart {
+                theme: theme_with!(ChartTheme {
+                    width: "400".into(),
+                    height: "300".into(),
+                }),
+                labels: vec!["Jan".to_string(), "Feb".to_string(), "Mar".to_string()],
+                series: vec![
+                    ChartSeries::new("Visits", [10., 40., 20.]),
+                    ChartSeries::new("Sales", [5., 15., 35.]),
+                ],
+            })
+        }
+
+        let mut utils = launch_test(line_chart_app);
+        utils.wait_for_update().await;
+        let canvas = utils.root().get(0).get(0);
+        assert_eq!(canvas.children_ids().len(), 0);
+
+        // Hover the second value of the first series
+        let area = canvas.area().unwrap();
+        let plot = PlotArea::new(area.width(), area.height());
+        let scale = ChartScale::new([10., 40., 20., 5., 15., 35.]);
+        let (x, y) = (plot.slot_center(1, 3), plot.value_y(40., &scale));
+        utils
+            .move_cursor(((area.min_x() + x) as f64, (area.min_y() + y + 4.) as f64))
+            .await;
+
+        let tooltip = canvas.get(0).get(0).get(0);
+        assert_eq!(tooltip.get(0).text(), Some("Feb · Visits: 40"));
+
+        // Leave the chart
+        utils.move_cursor((399., 1.)).await;
+        assert_eq!(canvas.children_ids().len(), 0);
+    }
+}
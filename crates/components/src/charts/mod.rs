@@ -0,0 +1,274 @@
+mod bar_chart;
+mod line_chart;
+mod pie_chart;
+
+pub use bar_chart::*;
+use dioxus::prelude::*;
+use freya_core::parsing::Parse;
+use freya_elements as dioxus_elements;
+use freya_engine::prelude::*;
+pub use line_chart::*;
+pub use pie_chart::*;
+
+use crate::Tooltip;
+
+/// Width reserved at the left of the plot for the labels of the Y axis.
+const Y_AXIS_WIDTH: f32 = 44.;
+/// Height reserved below the plot for the labels of the X axis.
+const X_AXIS_HEIGHT: f32 = 24.;
+/// Space between the plot and the other edges of the chart.
+const PLOT_PADDING: f32 = 8.;
+/// Approximate number of steps in the Y axis.
+const Y_AXIS_STEPS: f32 = 5.;
+/// Font size of the labels of the axes.
+const AXIS_FONT_SIZE: f32 = 12.;
+/// Duration of the animation when the data changes, in milliseconds.
+const ANIMATION_TIME: u64 = 500;
+
+/// Named values of a [`LineChart`] or a [`BarChart`], one for every label.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartSeries {
+    pub name: String,
+    pub values: Vec<f32>,
+    /// Color of the series, the palette of the theme is used otherwise.
+    pub color: Option<String>,
+}
+
+impl ChartSeries {
+    pub fn new(name: impl Into<String>, values: impl Into<Vec<f32>>) -> Self {
+        Self {
+            name: name.into(),
+            values: values.into(),
+            color: None,
+        }
+    }
+
+    /// Use a color instead of the one from the palette of the theme.
+    pub fn with_color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// Colors of the `palette` of a [`ChartTheme`](freya_hooks::ChartTheme), separated by `;`.
+pub(crate) fn parse_palette(palette: &str) -> Vec<Color> {
+    palette
+        .split(';')
+        .filter_map(|color| Color::parse(color.trim()).ok())
+        .collect()
+}
+
+/// Color of the item in the given index, either its own or the one from the palette.
+pub(crate) fn item_color(palette: &[Color], color: Option<&str>, index: usize) -> Color {
+    color
+        .and_then(|color| Color::parse(color).ok())
+        .or_else(|| palette.get(index % palette.len().max(1)).copied())
+        .unwrap_or(Color::GRAY)
+}
+
+/// Format a value with the fewest decimals that keep it exact.
+pub(crate) fn format_value(value: f32) -> String {
+    let value = (value * 100.).round() / 100.;
+    format!("{value}")
+}
+
+/// Values of the Y axis, with round steps that fit the data and zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ChartScale {
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+}
+
+impl ChartScale {
+    pub fn new(values: impl IntoIterator<Item = f32>) -> Self {
+        let (mut min, mut max) = (0f32, 0f32);
+        for value in values.into_iter().filter(|value| value.is_finite()) {
+            min = min.min(value);
+            max = max.max(value);
+        }
+        if min == max {
+            max = min + 1.;
+        }
+
+        let raw_step = (max - min) / Y_AXIS_STEPS;
+        let magnitude = 10f32.powf(raw_step.log10().floor());
+        let step = match raw_step / magnitude {
+            normalized if normalized <= 1. => 1.,
+            normalized if normalized <= 2. => 2.,
+            normalized if normalized <= 5. => 5.,
+            _ => 10.,
+        } * magnitude;
+
+        Self {
+            min: (min / step).floor() * step,
+            max: (max / step).ceil() * step,
+            step,
+        }
+    }
+
+    /// Values of the lines of the Y axis, from the smallest.
+    pub fn ticks(&self) -> Vec<f32> {
+        let steps = ((self.max - self.min) / self.step).round() as usize;
+        (0..=steps)
+            .map(|i| self.min + self.step * i as f32)
+            .collect()
+    }
+
+    /// Position of a value in the scale, from `0` for the smallest value to `1` for the biggest.
+    pub fn ratio(&self, value: f32) -> f32 {
+        (value - self.min) / (self.max - self.min)
+    }
+
+    /// Value where bars start and lines grow from when animating.
+    pub fn baseline(&self) -> f32 {
+        0f32.clamp(self.min, self.max)
+    }
+}
+
+/// Area where the data of a [`LineChart`] or a [`BarChart`] is drawn, leaving room for the axes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PlotArea {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl PlotArea {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            x: Y_AXIS_WIDTH,
+            y: PLOT_PADDING,
+            width: (width - Y_AXIS_WIDTH - PLOT_PADDING).max(0.),
+            height: (height - X_AXIS_HEIGHT - PLOT_PADDING).max(0.),
+        }
+    }
+
+    /// Width of the space of every label in the X axis.
+    pub fn slot_width(&self, slots: usize) -> f32 {
+        self.width / slots.max(1) as f32
+    }
+
+    /// Horizontal center of the space of a label in the X axis.
+    pub fn slot_center(&self, slot: usize, slots: usize) -> f32 {
+        self.x + self.slot_width(slots) * (slot as f32 + 0.5)
+    }
+
+    /// Label of the X axis whose space contains the given horizontal position.
+    pub fn slot_at(&self, x: f32, slots: usize) -> Option<usize> {
+        if x < self.x || x >= self.x + self.width {
+            return None;
+        }
+        let slot = ((x - self.x) / self.slot_width(slots)) as usize;
+        (slot < slots).then_some(slot)
+    }
+
+    /// Vertical position of a value.
+    pub fn value_y(&self, value: f32, scale: &ChartScale) -> f32 {
+        self.y + self.height * (1. - scale.ratio(value))
+    }
+}
+
+/// Draw a line of text vertically centered in the given position.
+pub(crate) fn draw_text(
+    canvas: &Canvas,
+    font_collection: &FontCollection,
+    text: &str,
+    color: Color,
+    align: TextAlign,
+    (x, y): (f32, f32),
+    width: f32,
+) {
+    let mut paragraph_style = ParagraphStyle::default();
+    paragraph_style.set_text_align(align);
+    paragraph_style.set_max_lines(1);
+
+    let mut text_style = TextStyle::new();
+    text_style.set_color(color);
+    text_style.set_font_size(AXIS_FONT_SIZE);
+    paragraph_style.set_text_style(&text_style);
+
+    let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection.clone());
+    paragraph_builder.add_text(text);
+    let mut paragraph = paragraph_builder.build();
+    paragraph.layout(width);
+    paragraph.paint(canvas, (x, y - paragraph.height() / 2.));
+}
+
+/// Draw the lines and values of the Y axis and the labels of the X axis.
+pub(crate) fn draw_axes(
+    canvas: &Canvas,
+    font_collection: &FontCollection,
+    plot: &PlotArea,
+    scale: &ChartScale,
+    labels: &[String],
+    text_color: Color,
+    grid_color: Color,
+) {
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_style(PaintStyle::Stroke);
+    paint.set_stroke_width(1.);
+    paint.set_color(grid_color);
+
+    for tick in scale.ticks() {
+        let y = plot.value_y(tick, scale);
+        canvas.draw_line((plot.x, y), (plot.x + plot.width, y), &paint);
+        draw_text(
+            canvas,
+            font_collection,
+            &format_value(tick),
+            text_color,
+            TextAlign::Right,
+            (0., y),
+            plot.x - PLOT_PADDING,
+        );
+    }
+
+    let slot_width = plot.slot_width(labels.len());
+    for (i, label) in labels.iter().enumerate() {
+        draw_text(
+            canvas,
+            font_collection,
+            label,
+            text_color,
+            TextAlign::Center,
+            (
+                plot.x + slot_width * i as f32,
+                plot.y + plot.height + X_AXIS_HEIGHT / 2.,
+            ),
+            slot_width,
+        );
+    }
+}
+
+/// Data point of a chart under the cursor.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ChartHover {
+    /// Series or slice of the point.
+    pub item: usize,
+    /// Index of the value in the series.
+    pub index: usize,
+    pub x: f32,
+    pub y: f32,
+    pub text: String,
+}
+
+/// Tooltip of the data point under the cursor, placed over it.
+#[allow(non_snake_case)]
+#[component]
+pub(crate) fn ChartTooltip(hover: ChartHover) -> Element {
+    rsx!(
+        rect {
+            position: "absolute",
+            position_top: "{hover.y - 36.}",
+            position_left: "{hover.x + 8.}",
+            width: "0",
+            height: "0",
+            Tooltip {
+                text: hover.text
+            }
+        }
+    )
+}
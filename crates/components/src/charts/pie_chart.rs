@@ -0,0 +1,307 @@
+use dioxus::prelude::*;
+use freya_core::{
+    custom_attributes::CanvasRunnerContext,
+    parsing::Parse,
+};
+use freya_elements::{
+    self as dioxus_elements,
+    events::MouseEvent,
+};
+use freya_engine::prelude::*;
+use freya_hooks::{
+    use_animation_with_dependencies,
+    use_applied_theme,
+    use_canvas_with_deps,
+    use_node_signal,
+    use_platform,
+    AnimNum,
+    ChartTheme,
+    ChartThemeWith,
+    Ease,
+    OnDepsChange,
+};
+
+use super::{
+    format_value,
+    item_color,
+    parse_palette,
+    ChartHover,
+    ChartTooltip,
+    ANIMATION_TIME,
+    PLOT_PADDING,
+};
+
+/// How much the hovered slice grows.
+const HOVER_GROWTH: f32 = 6.;
+
+/// Slice of a [`PieChart`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PieSlice {
+    pub label: String,
+    pub value: f32,
+    /// Color of the slice, the palette of the theme is used otherwise.
+    pub color: Option<String>,
+}
+
+impl PieSlice {
+    pub fn new(label: impl Into<String>, value: f32) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            color: None,
+        }
+    }
+
+    /// Use a color instead of the one from the palette of the theme.
+    pub fn with_color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// Properties for the [`PieChart`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct PieChartProps {
+    /// Theme override.
+    pub theme: Option<ChartThemeWith>,
+    /// Slices of the chart, sized by their share of the total. Negative values are ignored.
+    pub slices: Vec<PieSlice>,
+    /// Animate the slices when the data changes. Default to `true`.
+    #[props(default = true)]
+    pub animated: bool,
+}
+
+/// Center and radius of the pie in a chart of the given size.
+fn pie_circle(width: f32, height: f32) -> ((f32, f32), f32) {
+    let radius = (width.min(height) / 2. - PLOT_PADDING).max(0.);
+    ((width / 2., height / 2.), radius)
+}
+
+/// Start and sweep angles of every slice in degrees, clockwise from the top.
+fn slice_angles(slices: &[PieSlice]) -> Vec<(f32, f32)> {
+    let total = slices.iter().map(|slice| slice.value.max(0.)).sum::<f32>();
+    let mut start = -90.;
+    slices
+        .iter()
+        .map(|slice| {
+            let sweep = if total > 0. {
+                slice.value.max(0.) / total * 360.
+            } else {
+                0.
+            };
+            let angles = (start, sweep);
+            start += sweep;
+            angles
+        })
+        .collect()
+}
+
+/// Slice under the cursor.
+fn hit_test(
+    (x, y): (f32, f32),
+    (width, height): (f32, f32),
+    props: &PieChartProps,
+) -> Option<ChartHover> {
+    let ((center_x, center_y), radius) = pie_circle(width, height);
+    let (dx, dy) = (x - center_x, y - center_y);
+    if dx.hypot(dy) > radius {
+        return None;
+    }
+
+    // Angle in the same range as the slices, starting from the top
+    let mut angle = dy.atan2(dx).to_degrees();
+    if angle < -90. {
+        angle += 360.;
+    }
+
+    let total = props
+        .slices
+        .iter()
+        .map(|slice| slice.value.max(0.))
+        .sum::<f32>();
+    let angles = slice_angles(&props.slices);
+    let item = angles
+        .iter()
+        .position(|(start, sweep)| *sweep > 0. && angle >= *start && angle < start + sweep)?;
+    let (start, sweep) = angles[item];
+    let slice = &props.slices[item];
+
+    // Show the tooltip in the middle of the slice
+    let middle = (start + sweep / 2.).to_radians();
+    Some(ChartHover {
+        item,
+        index: 0,
+        x: center_x + middle.cos() * radius / 2.,
+        y: center_y + middle.sin() * radius / 2.,
+        text: format!(
+            "{}: {} ({}%)",
+            slice.label,
+            format_value(slice.value),
+            format_value(slice.value.max(0.) / total * 100.)
+        ),
+    })
+}
+
+/// Show the share of every slice in a total.
+///
+/// Hovering a slice shows its value and percentage in a tooltip.
+///
+/// # Styling
+/// Inherits the [`ChartTheme`](freya_hooks::ChartTheme) theme.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(PieChart {
+///         slices: vec![
+///             PieSlice::new("Desktop", 60.),
+///             PieSlice::new("Mobile", 30.),
+///             PieSlice::new("Tablet", 10.).with_color("rgb(120, 120, 120)"),
+///         ],
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn PieChart(props: PieChartProps) -> Element {
+    let platform = use_platform();
+    let (reference, size) = use_node_signal();
+    let mut hover = use_signal(|| None::<ChartHover>);
+    let ChartTheme {
+        width,
+        height,
+        background,
+        palette,
+        ..
+    } = use_applied_theme!(&props.theme, chart);
+
+    let animation = use_animation_with_dependencies(&props.slices, |conf, _| {
+        conf.auto_start(true);
+        conf.on_deps_change(OnDepsChange::Rerun);
+        AnimNum::new(0., 1.).time(ANIMATION_TIME).ease(Ease::Out)
+    });
+    let progress = if props.animated {
+        animation.get().read().read()
+    } else {
+        1.
+    };
+
+    let palette = parse_palette(&palette);
+    let hovered = hover.read().as_ref().map(|hover| hover.item);
+
+    let canvas = use_canvas_with_deps(
+        &(props.clone(), palette, progress, hovered),
+        move |(props, palette, progress, hovered)| {
+            platform.invalidate_drawing_area(size.peek().area);
+            platform.request_animation_frame();
+            move |ctx: &mut CanvasRunnerContext| {
+                ctx.canvas.save();
+                ctx.canvas.translate((ctx.area.min_x(), ctx.area.min_y()));
+                ctx.canvas.scale((ctx.scale_factor, ctx.scale_factor));
+
+                let area = ctx.area.size / ctx.scale_factor;
+                let ((center_x, center_y), radius) = pie_circle(area.width, area.height);
+
+                // Sweep the slices clockwise from the top
+                for (item, (start, sweep)) in slice_angles(&props.slices).into_iter().enumerate() {
+                    let slice = &props.slices[item];
+                    let mut paint = Paint::default();
+                    paint.set_anti_alias(true);
+                    paint.set_style(PaintStyle::Fill);
+                    paint.set_color(item_color(&palette, slice.color.as_deref(), item));
+
+                    let radius = if hovered == Some(item) {
+                        radius + HOVER_GROWTH
+                    } else {
+                        radius
+                    };
+                    let oval = Rect::new(
+                        center_x - radius,
+                        center_y - radius,
+                        center_x + radius,
+                        center_y + radius,
+                    );
+                    let start = -90. + (start + 90.) * progress;
+                    ctx.canvas
+                        .draw_arc(oval, start, sweep * progress, true, &paint);
+                }
+
+                ctx.canvas.restore();
+            }
+        },
+    );
+
+    let onmousemove = {
+        let props = props.clone();
+        move |e: MouseEvent| {
+            let cursor = e.get_element_coordinates().to_f32();
+            let area = size.peek().area;
+            let new_hover = hit_test((cursor.x, cursor.y), (area.width(), area.height()), &props);
+            if *hover.peek() != new_hover {
+                hover.set(new_hover);
+            }
+        }
+    };
+
+    let onmouseleave = move |_| hover.set(None);
+
+    rsx!(
+        rect {
+            width: "{width}",
+            height: "{height}",
+            background: "{background}",
+            padding: "8",
+            rect {
+                canvas_reference: canvas.attribute(),
+                reference,
+                onmousemove,
+                onmouseleave,
+                width: "100%",
+                height: "100%",
+                if let Some(hover) = hover() {
+                    ChartTooltip {
+                        hover
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn pie_chart() {
+        fn pie_chart_app() -> Element {
+            rsx!(PieChart {
+                theme: theme_with!(ChartTheme {
+                    width: "300".into(),
+                    height: "300".into(),
+                }),
+                slices: vec![PieSlice::new("Desktop", 75.), PieSlice::new("Mobile", 25.),],
+            })
+        }
+
+        let mut utils = launch_test(pie_chart_app);
+        utils.wait_for_update().await;
+        let canvas = utils.root().get(0).get(0);
+
+        // The first slice goes clockwise from the top to the left
+        utils.move_cursor((200., 150.)).await;
+        let tooltip = canvas.get(0).get(0).get(0);
+        assert_eq!(tooltip.get(0).text(), Some("Desktop: 75 (75%)"));
+
+        // The second slice fills the top left quarter
+        utils.move_cursor((100., 100.)).await;
+        let tooltip = canvas.get(0).get(0).get(0);
+        assert_eq!(tooltip.get(0).text(), Some("Mobile: 25 (25%)"));
+
+        // Outside of the pie
+        utils.move_cursor((12., 12.)).await;
+        assert_eq!(canvas.children_ids().len(), 0);
+    }
+}
@@ -12,8 +12,63 @@ use freya_hooks::{
 
 use crate::TickIcon;
 
+/// State of a [`Checkbox`]. Can be created from a `bool`.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum CheckboxState {
+    #[default]
+    Unchecked,
+    Checked,
+    /// Only some of the options it represents are checked, e.g. in a "Select all" checkbox.
+    Indeterminate,
+}
+
+impl CheckboxState {
+    /// Get the state of a checkbox that represents all the given states, like a "Select all" checkbox.
+    pub fn from_states(states: impl IntoIterator<Item = bool>) -> Self {
+        let (mut checked, mut unchecked) = (false, false);
+        for state in states {
+            if state {
+                checked = true;
+            } else {
+                unchecked = true;
+            }
+        }
+        match (checked, unchecked) {
+            (true, true) => Self::Indeterminate,
+            (true, false) => Self::Checked,
+            (false, _) => Self::Unchecked,
+        }
+    }
+
+    pub fn is_checked(&self) -> bool {
+        *self == Self::Checked
+    }
+
+    /// Value of the `a11y_toggled` attribute.
+    fn a11y_toggled(&self) -> &'static str {
+        match self {
+            Self::Unchecked => "false",
+            Self::Checked => "true",
+            Self::Indeterminate => "mixed",
+        }
+    }
+}
+
+impl From<bool> for CheckboxState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            Self::Checked
+        } else {
+            Self::Unchecked
+        }
+    }
+}
+
 /// Controlled `Checkbox` component.
 ///
+/// Besides selected or not, it can be [indeterminate](CheckboxState::Indeterminate), e.g. for a "Select all" checkbox
+/// when only some of the options are selected, see [`CheckboxState::from_states`].
+///
 /// # Styling
 /// Inherits the [`CheckboxTheme`](freya_hooks::CheckboxTheme) theme.
 ///
@@ -85,8 +140,12 @@ use crate::TickIcon;
 #[allow(non_snake_case)]
 #[component]
 pub fn Checkbox(
-    /// Indicate whether this checkbox is selected or not.
-    selected: bool,
+    /// Indicate whether this checkbox is selected, not selected or [indeterminate](CheckboxState::Indeterminate).
+    #[props(into)]
+    selected: CheckboxState,
+    /// Whether it can be focused by itself. Disabled in a [`CheckboxItem`](crate::CheckboxItem()), which is focused instead.
+    #[props(default = true)]
+    focusable: bool,
     /// Theme override.
     theme: Option<CheckboxThemeWith>,
 ) -> Element {
@@ -97,10 +156,11 @@ pub fn Checkbox(
         selected_fill,
         selected_icon_fill,
     } = use_applied_theme!(&theme, checkbox);
-    let (inner_fill, outer_fill) = if selected {
-        (selected_fill.as_ref(), selected_fill.as_ref())
-    } else {
-        ("transparent", unselected_fill.as_ref())
+    let (inner_fill, outer_fill) = match selected {
+        CheckboxState::Checked | CheckboxState::Indeterminate => {
+            (selected_fill.as_ref(), selected_fill.as_ref())
+        }
+        CheckboxState::Unchecked => ("transparent", unselected_fill.as_ref()),
     };
     let border = if focus.is_focused_with_keyboard() {
         format!("2 inner {outer_fill}, 4 outer {border_fill}")
@@ -117,6 +177,9 @@ pub fn Checkbox(
     rsx!(
         rect {
             a11y_id: focus.attribute(),
+            a11y_focusable: "{focusable}",
+            a11y_role: if focusable { "check-box" },
+            a11y_toggled: if focusable { selected.a11y_toggled() },
             width: "18",
             height: "18",
             padding: "4",
@@ -126,10 +189,17 @@ pub fn Checkbox(
             border,
             background: "{inner_fill}",
             onkeydown,
-            if selected {
+            if selected == CheckboxState::Checked {
                 TickIcon {
                     fill: selected_icon_fill
                 }
+            } else if selected == CheckboxState::Indeterminate {
+                rect {
+                    width: "10",
+                    height: "2",
+                    corner_radius: "1",
+                    background: "{selected_icon_fill}",
+                }
             }
         }
     )
@@ -234,4 +304,57 @@ mod test {
         assert!(root.get(1).get(0).get(0).get(0).is_placeholder());
         assert!(root.get(2).get(0).get(0).get(0).is_element());
     }
+
+    #[tokio::test]
+    pub async fn indeterminate_checkbox() {
+        fn checkbox_app() -> Element {
+            let mut selected = use_signal(|| vec![true, false]);
+            let all = CheckboxState::from_states(selected.read().iter().copied());
+
+            rsx!(
+                Tile {
+                    onselect: move |_| {
+                        let checked = !all.is_checked();
+                        selected.write().fill(checked);
+                    },
+                    leading: rsx!(
+                        Checkbox {
+                            selected: all,
+                        }
+                    ),
+                    label { "Select all" }
+                }
+                for i in 0..2 {
+                    Tile {
+                        onselect: move |_| selected.write()[i] ^= true,
+                        leading: rsx!(
+                            Checkbox {
+                                selected: selected.read()[i],
+                            }
+                        ),
+                        label { "Choice {i}" }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(checkbox_app);
+        let root = utils.root();
+        utils.wait_for_update().await;
+
+        let select_all = root.get(0).get(0).get(0);
+        assert_eq!(select_all.toggled(), Some(Toggled::Mixed));
+        assert_eq!(select_all.get(0).layout().unwrap().width(), 10.);
+
+        // Selecting all when some are selected
+        utils.click_cursor((10., 10.)).await;
+        utils.wait_for_update().await;
+        assert_eq!(select_all.toggled(), Some(Toggled::True));
+        assert_eq!(root.get(2).get(0).get(0).toggled(), Some(Toggled::True));
+
+        utils.click_cursor((10., 10.)).await;
+        utils.wait_for_update().await;
+        assert_eq!(select_all.toggled(), Some(Toggled::False));
+        assert!(select_all.get(0).is_placeholder());
+    }
 }
@@ -0,0 +1,428 @@
+use dioxus::prelude::*;
+use freya_core::platform::CursorIcon;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        keyboard::Key,
+        KeyboardEvent,
+        MouseEvent,
+    },
+};
+use freya_hooks::{
+    theme_with,
+    use_applied_theme,
+    use_focus,
+    use_platform,
+    ChipTheme,
+    ChipThemeWith,
+    IconThemeWith,
+};
+
+use crate::{
+    CrossIcon,
+    Input,
+};
+
+/// Indicates the current status of the Chip.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum ChipStatus {
+    /// Default state.
+    #[default]
+    Idle,
+    /// Mouse is hovering the chip.
+    Hovering,
+}
+
+/// Properties for the [`Chip`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct ChipProps {
+    /// Theme override.
+    pub theme: Option<ChipThemeWith>,
+    /// Inner children for the Chip.
+    pub children: Element,
+    /// Whether the Chip is selected.
+    #[props(default = false)]
+    pub selected: bool,
+    /// Handler for when the Chip is selected, e.g when clicking on it.
+    pub onselect: Option<EventHandler<()>>,
+    /// Handler for when the Chip is deleted. Shows a button to delete it when set.
+    pub ondelete: Option<EventHandler<()>>,
+}
+
+/// Small element to show a value, such as a tag or a filter.
+///
+/// It can be selected by clicking it or with `Enter` and `Space`,
+/// and deleted with its button or with `Backspace` and `Delete` when focused.
+///
+/// # Styling
+/// Inherits the [`ChipTheme`](freya_hooks::ChipTheme) theme.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut selected = use_signal(|| false);
+///     let mut deleted = use_signal(|| false);
+///
+///     if deleted() {
+///         return rsx!(label { "Deleted" });
+///     }
+///
+///     rsx!(
+///         Chip {
+///             selected: selected(),
+///             onselect: move |_| selected.toggle(),
+///             ondelete: move |_| deleted.set(true),
+///             label {
+///                 "Rust"
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn Chip(
+    ChipProps {
+        theme,
+        children,
+        selected,
+        onselect,
+        ondelete,
+    }: ChipProps,
+) -> Element {
+    let ChipTheme {
+        background,
+        hover_background,
+        selected_background,
+        border_fill,
+        focus_border_fill,
+        cross_fill,
+        padding,
+        corner_radius,
+        font_theme,
+    } = use_applied_theme!(&theme, chip);
+    let mut focus = use_focus();
+    let mut status = use_signal(ChipStatus::default);
+    let platform = use_platform();
+
+    let a11y_id = focus.attribute();
+
+    use_drop(move || {
+        if *status.peek() == ChipStatus::Hovering {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let onclick = move |e: MouseEvent| {
+        if let Some(onselect) = &onselect {
+            e.stop_propagation();
+            focus.request_focus();
+            onselect.call(());
+        }
+    };
+
+    let onkeydown = move |e: KeyboardEvent| {
+        if !focus.is_focused() {
+            return;
+        }
+        match &e.key {
+            Key::Backspace | Key::Delete => {
+                if let Some(ondelete) = &ondelete {
+                    e.stop_propagation();
+                    ondelete.call(());
+                }
+            }
+            _ => {
+                if let Some(onselect) = onselect.filter(|_| focus.validate_keydown(&e)) {
+                    e.stop_propagation();
+                    onselect.call(());
+                }
+            }
+        }
+    };
+
+    let onmouseenter = move |_| {
+        if onselect.is_some() {
+            platform.set_cursor(CursorIcon::Pointer);
+        }
+        status.set(ChipStatus::Hovering);
+    };
+
+    let onmouseleave = move |_| {
+        platform.set_cursor(CursorIcon::default());
+        status.set(ChipStatus::default());
+    };
+
+    let ondeleteclick = move |e: MouseEvent| {
+        e.stop_propagation();
+        if let Some(ondelete) = &ondelete {
+            ondelete.call(());
+        }
+    };
+
+    let background = if selected {
+        selected_background
+    } else if *status.read() == ChipStatus::Hovering && onselect.is_some() {
+        hover_background
+    } else {
+        background
+    };
+    let border = if focus.is_focused_with_keyboard() {
+        format!("2 inner {focus_border_fill}")
+    } else {
+        format!("1 inner {border_fill}")
+    };
+
+    rsx!(
+        rect {
+            onclick,
+            onkeydown,
+            onmouseenter,
+            onmouseleave,
+            a11y_id,
+            a11y_role: "button",
+            a11y_toggled: if onselect.is_some() { "{selected}" },
+            direction: "horizontal",
+            cross_align: "center",
+            spacing: "6",
+            padding: "{padding}",
+            corner_radius: "{corner_radius}",
+            background: "{background}",
+            border,
+            color: "{font_theme.color}",
+            {children}
+            if ondelete.is_some() {
+                rect {
+                    onclick: ondeleteclick,
+                    corner_radius: "99",
+                    CrossIcon {
+                        fill: cross_fill,
+                        theme: theme_with!(IconTheme {
+                            width: "8".into(),
+                            height: "8".into(),
+                            margin: "0".into(),
+                        })
+                    }
+                }
+            }
+        }
+    )
+}
+
+/// Properties for the [`ChipInput`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct ChipInputProps {
+    /// Theme override for the chips.
+    pub chip_theme: Option<ChipThemeWith>,
+    /// Current values shown as chips.
+    pub values: Vec<String>,
+    /// Handler for when a value is added or deleted.
+    pub onchange: EventHandler<Vec<String>>,
+    /// Text to show in the Input when there is no text.
+    pub placeholder: Option<String>,
+    /// Width of the Input.
+    #[props(default = "150".to_string())]
+    pub width: String,
+}
+
+/// [`Input`] that turns the text into [`Chip`]s when pressing `Enter` or writing a comma,
+/// such as the recipients of an email or the filters of a search.
+///
+/// Pressing `Backspace` with no text deletes the last value.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut tags = use_signal(|| vec!["rust".to_string()]);
+///
+///     rsx!(ChipInput {
+///         values: tags(),
+///         onchange: move |values| tags.set(values),
+///         placeholder: "Add a tag",
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn ChipInput(
+    ChipInputProps {
+        chip_theme,
+        values,
+        onchange,
+        placeholder,
+        width,
+    }: ChipInputProps,
+) -> Element {
+    let mut text = use_signal(String::new);
+
+    let add_values = {
+        to_owned![values];
+        move |new_text: &str| {
+            let mut values = values.clone();
+            for value in new_text.split(',').map(str::trim) {
+                if !value.is_empty() && !values.iter().any(|v| v == value) {
+                    values.push(value.to_string());
+                }
+            }
+            onchange.call(values);
+        }
+    };
+
+    let oninputchange = {
+        to_owned![add_values];
+        move |new_text: String| {
+            if new_text.contains(',') {
+                add_values(&new_text);
+                text.set(String::new());
+            } else {
+                text.set(new_text);
+            }
+        }
+    };
+
+    let onkeydown = {
+        to_owned![values];
+        move |e: KeyboardEvent| match e.key {
+            Key::Enter => {
+                e.stop_propagation();
+                let new_text = text.peek().clone();
+                if !new_text.trim().is_empty() {
+                    add_values(&new_text);
+                    text.set(String::new());
+                }
+            }
+            // Only reaches here when the Input has no text
+            Key::Backspace if !values.is_empty() => {
+                e.stop_propagation();
+                let mut values = values.clone();
+                values.pop();
+                onchange.call(values);
+            }
+            _ => {}
+        }
+    };
+
+    rsx!(
+        rect {
+            onkeydown,
+            direction: "horizontal",
+            cross_align: "center",
+            spacing: "6",
+            for (i, value) in values.iter().enumerate() {
+                Chip {
+                    key: "{value}",
+                    theme: chip_theme.clone(),
+                    ondelete: {
+                        to_owned![values];
+                        move |_| {
+                            let mut values = values.clone();
+                            values.remove(i);
+                            onchange.call(values);
+                        }
+                    },
+                    label {
+                        "{value}"
+                    }
+                }
+            }
+            Input {
+                value: text,
+                onchange: oninputchange,
+                placeholder,
+                width,
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn chip() {
+        fn chip_app() -> Element {
+            let mut selected = use_signal(|| false);
+            let mut deletes = use_signal(|| 0);
+
+            rsx!(
+                Chip {
+                    selected: selected(),
+                    onselect: move |_| selected.toggle(),
+                    ondelete: move |_| deletes += 1,
+                    label {
+                        "Chip"
+                    }
+                }
+                label {
+                    "{selected} {deletes}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(chip_app);
+        let root = utils.root();
+        let label = root.get(1);
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("false 0"));
+
+        // Select it by clicking it
+        utils.click_cursor((15., 10.)).await;
+        assert_eq!(label.get(0).text(), Some("true 0"));
+
+        // Unselect it with the keyboard
+        utils.press(Key::Enter).await;
+        assert_eq!(label.get(0).text(), Some("false 0"));
+
+        // Delete it with the keyboard
+        utils.press(Key::Backspace).await;
+        assert_eq!(label.get(0).text(), Some("false 1"));
+
+        // Delete it with its button
+        let cross = root.get(0).get(1).area().unwrap().center();
+        utils.click_cursor((cross.x as f64, cross.y as f64)).await;
+        assert_eq!(label.get(0).text(), Some("false 2"));
+    }
+
+    #[tokio::test]
+    pub async fn chip_input() {
+        fn chip_input_app() -> Element {
+            let mut values = use_signal(Vec::<String>::new);
+
+            rsx!(
+                ChipInput {
+                    values: values(),
+                    onchange: move |new_values| values.set(new_values),
+                }
+                label {
+                    "{values:?}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(chip_input_app);
+        let root = utils.root();
+        let label = root.get(1);
+        utils.wait_for_update().await;
+
+        // Focus the Input
+        utils.click_cursor((10., 10.)).await;
+
+        // Enter turns the text into a chip
+        utils.press(Key::Character("a".to_string())).await;
+        utils.press(Key::Enter).await;
+        assert_eq!(label.get(0).text(), Some("[\"a\"]"));
+
+        // And so does a comma
+        utils.press(Key::Character("b".to_string())).await;
+        utils.press(Key::Character(",".to_string())).await;
+        assert_eq!(label.get(0).text(), Some("[\"a\", \"b\"]"));
+
+        // Backspace without text deletes the last chip
+        utils.press(Key::Backspace).await;
+        assert_eq!(label.get(0).text(), Some("[\"a\"]"));
+    }
+}
@@ -0,0 +1,602 @@
+use dioxus::prelude::*;
+use freya_core::types::AccessibilityId;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        keyboard::Key,
+        KeyboardEvent,
+        MouseEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_focus,
+    CheckboxThemeWith,
+    RadioThemeWith,
+    UseFocus,
+};
+
+use crate::{
+    Checkbox,
+    Radio,
+    Tile,
+};
+
+/// Keyboard navigation between the items of a [`RadioGroup`] or a [`CheckboxGroup`].
+///
+/// Only one item is reached with `Tab`, the others are reached with the arrow keys.
+struct ChoiceGroup<T: 'static> {
+    /// Accessibility id of the group, the items are members of it.
+    id: AccessibilityId,
+    /// Items in the order they were mounted, with their values.
+    items: CopyValue<Vec<(AccessibilityId, T)>>,
+    /// Item reached with `Tab`, the first one if `None`.
+    tab_stop: Signal<Option<AccessibilityId>>,
+}
+
+impl<T: 'static> Clone for ChoiceGroup<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for ChoiceGroup<T> {}
+
+impl<T: Clone + PartialEq + 'static> ChoiceGroup<T> {
+    fn new() -> Self {
+        Self {
+            id: UseFocus::new_id(),
+            items: CopyValue::new(Vec::new()),
+            tab_stop: Signal::new(None),
+        }
+    }
+
+    fn is_tab_stop(&self, id: AccessibilityId) -> bool {
+        let items = self.items.read();
+        match *self.tab_stop.read() {
+            Some(tab_stop) if items.iter().any(|(item, _)| *item == tab_stop) => tab_stop == id,
+            _ => items.first().is_some_and(|(item, _)| *item == id),
+        }
+    }
+
+    /// Get the item before or after the given one in the direction of the arrow key, wrapping around the ends.
+    fn sibling(&self, id: AccessibilityId, key: &Key) -> Option<(AccessibilityId, T)> {
+        let forward = match key {
+            Key::ArrowDown | Key::ArrowRight => true,
+            Key::ArrowUp | Key::ArrowLeft => false,
+            _ => return None,
+        };
+        let items = self.items.read();
+        let index = items.iter().position(|(item, _)| *item == id)?;
+        let sibling = if forward {
+            (index + 1) % items.len()
+        } else {
+            (index + items.len() - 1) % items.len()
+        };
+        Some(items[sibling].clone())
+    }
+}
+
+/// Register an item in its group while it's mounted, keeping its value up to date.
+fn use_choice_item<T: Clone + PartialEq + 'static>(
+    mut group: ChoiceGroup<T>,
+    id: AccessibilityId,
+    value: &T,
+) {
+    use_hook(|| group.items.write().push((id, value.clone())));
+
+    if let Some((_, item_value)) = group.items.write().iter_mut().find(|(item, _)| *item == id) {
+        if item_value != value {
+            *item_value = value.clone();
+        }
+    }
+
+    use_drop(move || group.items.write().retain(|(item, _)| *item != id));
+}
+
+/// Value and handler of a [`RadioGroup`] shared with its [`RadioItem`]s.
+struct RadioGroupState<T: 'static> {
+    value: Signal<T>,
+    onchange: CopyValue<Option<EventHandler<T>>>,
+    group: ChoiceGroup<T>,
+}
+
+impl<T: 'static> Clone for RadioGroupState<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for RadioGroupState<T> {}
+
+/// Properties for the [`RadioGroup`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct RadioGroupProps<T: 'static + Clone + PartialEq> {
+    /// Selected value.
+    pub value: T,
+    /// Handler for when another [`RadioItem`] is selected, with the mouse or the keyboard.
+    pub onchange: Option<EventHandler<T>>,
+    /// Name of the group for the assistive technologies.
+    pub name: Option<String>,
+    /// Direction of the items, `vertical` or `horizontal`. Defaults to `vertical`.
+    #[props(default = "vertical".to_string(), into)]
+    pub direction: String,
+    /// The [`RadioItem`]s, they can be inside other elements.
+    pub children: Element,
+}
+
+/// Group of [`RadioItem`]s where only one can be selected.
+///
+/// Only the selected item is reached with `Tab`, the arrow keys move the selection to the other items.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// #[derive(Clone, PartialEq)]
+/// enum Size {
+///     Small,
+///     Medium,
+///     Large,
+/// }
+///
+/// fn app() -> Element {
+///     let mut size = use_signal(|| Size::Medium);
+///
+///     rsx!(
+///         RadioGroup {
+///             value: size(),
+///             onchange: move |value| size.set(value),
+///             name: "Size",
+///             RadioItem {
+///                 value: Size::Small,
+///                 label { "Small" }
+///             }
+///             RadioItem {
+///                 value: Size::Medium,
+///                 label { "Medium" }
+///             }
+///             RadioItem {
+///                 value: Size::Large,
+///                 label { "Large" }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn RadioGroup<T>(
+    RadioGroupProps {
+        value,
+        onchange,
+        name,
+        direction,
+        children,
+    }: RadioGroupProps<T>,
+) -> Element
+where
+    T: Clone + PartialEq + 'static,
+{
+    let mut state = use_context_provider(|| RadioGroupState {
+        value: Signal::new(value.clone()),
+        onchange: CopyValue::new(onchange),
+        group: ChoiceGroup::new(),
+    });
+
+    if *state.value.peek() != value {
+        *state.value.write() = value;
+    }
+    state.onchange.set(onchange);
+
+    rsx!(
+        rect {
+            a11y_id: UseFocus::attribute_for_id(state.group.id),
+            a11y_focusable: "false",
+            a11y_role: "radio-group",
+            a11y_name: name,
+            direction: "{direction}",
+            {children}
+        }
+    )
+}
+
+/// Properties for the [`RadioItem`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct RadioItemProps<T: 'static + Clone + PartialEq> {
+    /// Value selected in the [`RadioGroup`] by this item.
+    pub value: T,
+    /// Theme override for the [`Radio`].
+    pub theme: Option<RadioThemeWith>,
+    /// Inner children, usually a `label`.
+    pub children: Element,
+}
+
+/// Selectable item of a [`RadioGroup`], made of a [`Radio`] and the children.
+///
+/// # Styling
+/// Inherits the [`RadioTheme`](freya_hooks::RadioTheme) theme.
+#[allow(non_snake_case)]
+pub fn RadioItem<T>(
+    RadioItemProps {
+        value,
+        theme,
+        children,
+    }: RadioItemProps<T>,
+) -> Element
+where
+    T: Clone + PartialEq + 'static,
+{
+    let state = use_context::<RadioGroupState<T>>();
+    let mut group = state.group;
+    let mut focus = use_focus();
+    let border_fill = use_applied_theme!(&theme, radio).border_fill;
+    let id = focus.id();
+
+    use_choice_item(group, id, &value);
+
+    let is_selected = *state.value.read() == value;
+
+    use_effect(use_reactive!(|is_selected| {
+        if is_selected && *group.tab_stop.peek() != Some(id) {
+            group.tab_stop.set(Some(id));
+        }
+    }));
+
+    let select = move |value: T| {
+        if let Some(onchange) = *state.onchange.peek() {
+            onchange.call(value);
+        }
+    };
+
+    let onclick = {
+        to_owned![value];
+        move |_: MouseEvent| {
+            focus.request_focus();
+            select(value.clone());
+        }
+    };
+
+    let onkeydown = {
+        to_owned![value];
+        move |e: KeyboardEvent| {
+            if focus.validate_keydown(&e) {
+                e.stop_propagation();
+                select(value.clone());
+            } else if let Some((sibling, sibling_value)) = group.sibling(id, &e.key) {
+                e.stop_propagation();
+                UseFocus::focus_id(sibling);
+                select(sibling_value);
+            }
+        }
+    };
+
+    let is_tab_stop = group.is_tab_stop(id);
+    let border = if focus.is_focused_with_keyboard() {
+        format!("2 inner {border_fill}")
+    } else {
+        "none".to_string()
+    };
+
+    rsx!(
+        rect {
+            a11y_id: focus.attribute(),
+            a11y_focusable: "{is_tab_stop}",
+            a11y_role: "radio-button",
+            a11y_toggled: "{is_selected}",
+            a11y_member_of: UseFocus::attribute_for_id(group.id),
+            corner_radius: "6",
+            border,
+            onclick,
+            onkeydown,
+            Tile {
+                leading: rsx!(
+                    Radio {
+                        selected: is_selected,
+                        focusable: false,
+                        theme,
+                    }
+                ),
+                {children}
+            }
+        }
+    )
+}
+
+/// Values and handler of a [`CheckboxGroup`] shared with its [`CheckboxItem`]s.
+struct CheckboxGroupState<T: 'static> {
+    values: Signal<Vec<T>>,
+    onchange: CopyValue<Option<EventHandler<Vec<T>>>>,
+    group: ChoiceGroup<T>,
+}
+
+impl<T: 'static> Clone for CheckboxGroupState<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for CheckboxGroupState<T> {}
+
+/// Properties for the [`CheckboxGroup`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct CheckboxGroupProps<T: 'static + Clone + PartialEq> {
+    /// Selected values.
+    pub values: Vec<T>,
+    /// Handler for when a [`CheckboxItem`] is toggled, with the new selected values.
+    pub onchange: Option<EventHandler<Vec<T>>>,
+    /// Name of the group for the assistive technologies.
+    pub name: Option<String>,
+    /// Direction of the items, `vertical` or `horizontal`. Defaults to `vertical`.
+    #[props(default = "vertical".to_string(), into)]
+    pub direction: String,
+    /// The [`CheckboxItem`]s, they can be inside other elements.
+    pub children: Element,
+}
+
+/// Group of [`CheckboxItem`]s where any of them can be selected.
+///
+/// Only one item is reached with `Tab`, the last one used, and the arrow keys move the focus to the other items.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut toppings = use_signal(|| vec!["Cheese"]);
+///
+///     rsx!(
+///         CheckboxGroup {
+///             values: toppings(),
+///             onchange: move |values| toppings.set(values),
+///             name: "Toppings",
+///             for topping in ["Cheese", "Olives", "Mushrooms"] {
+///                 CheckboxItem {
+///                     key: "{topping}",
+///                     value: topping,
+///                     label { "{topping}" }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn CheckboxGroup<T>(
+    CheckboxGroupProps {
+        values,
+        onchange,
+        name,
+        direction,
+        children,
+    }: CheckboxGroupProps<T>,
+) -> Element
+where
+    T: Clone + PartialEq + 'static,
+{
+    let mut state = use_context_provider(|| CheckboxGroupState {
+        values: Signal::new(values.clone()),
+        onchange: CopyValue::new(onchange),
+        group: ChoiceGroup::new(),
+    });
+
+    if *state.values.peek() != values {
+        *state.values.write() = values;
+    }
+    state.onchange.set(onchange);
+
+    rsx!(
+        rect {
+            a11y_id: UseFocus::attribute_for_id(state.group.id),
+            a11y_focusable: "false",
+            a11y_role: "group",
+            a11y_name: name,
+            direction: "{direction}",
+            {children}
+        }
+    )
+}
+
+/// Properties for the [`CheckboxItem`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct CheckboxItemProps<T: 'static + Clone + PartialEq> {
+    /// Value added to or removed from the [`CheckboxGroup`] by this item.
+    pub value: T,
+    /// Theme override for the [`Checkbox`].
+    pub theme: Option<CheckboxThemeWith>,
+    /// Inner children, usually a `label`.
+    pub children: Element,
+}
+
+/// Toggleable item of a [`CheckboxGroup`], made of a [`Checkbox`] and the children.
+///
+/// # Styling
+/// Inherits the [`CheckboxTheme`](freya_hooks::CheckboxTheme) theme.
+#[allow(non_snake_case)]
+pub fn CheckboxItem<T>(
+    CheckboxItemProps {
+        value,
+        theme,
+        children,
+    }: CheckboxItemProps<T>,
+) -> Element
+where
+    T: Clone + PartialEq + 'static,
+{
+    let state = use_context::<CheckboxGroupState<T>>();
+    let mut group = state.group;
+    let mut focus = use_focus();
+    let border_fill = use_applied_theme!(&theme, checkbox).border_fill;
+    let id = focus.id();
+
+    use_choice_item(group, id, &value);
+
+    let is_selected = state.values.read().contains(&value);
+
+    let mut toggle = {
+        to_owned![value];
+        move || {
+            group.tab_stop.set(Some(id));
+            let mut values = state.values.peek().clone();
+            if let Some(index) = values.iter().position(|v| *v == value) {
+                values.remove(index);
+            } else {
+                values.push(value.clone());
+            }
+            if let Some(onchange) = *state.onchange.peek() {
+                onchange.call(values);
+            }
+        }
+    };
+
+    let onclick = {
+        to_owned![toggle];
+        move |_: MouseEvent| {
+            focus.request_focus();
+            toggle();
+        }
+    };
+
+    let onkeydown = move |e: KeyboardEvent| {
+        if focus.validate_keydown(&e) {
+            e.stop_propagation();
+            toggle();
+        } else if let Some((sibling, _)) = group.sibling(id, &e.key) {
+            e.stop_propagation();
+            group.tab_stop.set(Some(sibling));
+            UseFocus::focus_id(sibling);
+        }
+    };
+
+    let is_tab_stop = group.is_tab_stop(id);
+    let border = if focus.is_focused_with_keyboard() {
+        format!("2 inner {border_fill}")
+    } else {
+        "none".to_string()
+    };
+
+    rsx!(
+        rect {
+            a11y_id: focus.attribute(),
+            a11y_focusable: "{is_tab_stop}",
+            a11y_role: "check-box",
+            a11y_toggled: "{is_selected}",
+            a11y_member_of: UseFocus::attribute_for_id(group.id),
+            corner_radius: "6",
+            border,
+            onclick,
+            onkeydown,
+            Tile {
+                leading: rsx!(
+                    Checkbox {
+                        selected: is_selected,
+                        focusable: false,
+                        theme,
+                    }
+                ),
+                {children}
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn radio_group() {
+        fn radio_group_app() -> Element {
+            let mut selected = use_signal(|| 1);
+
+            rsx!(
+                RadioGroup {
+                    value: selected(),
+                    onchange: move |value| selected.set(value),
+                    for value in 0..3 {
+                        RadioItem {
+                            key: "{value}",
+                            value,
+                            label { "Choice {value}" }
+                        }
+                    }
+                }
+                label { "{selected}" }
+            )
+        }
+
+        let mut utils = launch_test(radio_group_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let selected = utils.root().get(1).get(0);
+        assert_eq!(selected.text(), Some("1"));
+
+        // Only the selected item is reached with Tab
+        assert_eq!(utils.focus_order().len(), 1);
+        utils.focus_next().await;
+        assert_eq!(
+            utils.focused_node().and_then(|node| node.role()),
+            Some(Role::RadioButton)
+        );
+
+        // The arrow keys select the next and previous items, wrapping around
+        utils.press(Key::ArrowDown).await;
+        utils.wait_for_update().await;
+        assert_eq!(selected.text(), Some("2"));
+        utils.press(Key::ArrowDown).await;
+        utils.wait_for_update().await;
+        assert_eq!(selected.text(), Some("0"));
+        utils.press(Key::ArrowUp).await;
+        utils.wait_for_update().await;
+        assert_eq!(selected.text(), Some("2"));
+
+        utils.click_cursor((15., 15.)).await;
+        assert_eq!(selected.text(), Some("0"));
+        utils.wait_for_update().await;
+        assert_eq!(utils.focus_order().len(), 1);
+    }
+
+    #[tokio::test]
+    pub async fn checkbox_group() {
+        fn checkbox_group_app() -> Element {
+            let mut selected = use_signal(|| vec!["b"]);
+
+            rsx!(
+                CheckboxGroup {
+                    values: selected(),
+                    onchange: move |values| selected.set(values),
+                    for value in ["a", "b", "c"] {
+                        CheckboxItem {
+                            key: "{value}",
+                            value,
+                            label { "{value}" }
+                        }
+                    }
+                }
+                label { "{selected.read().join(\",\")}" }
+            )
+        }
+
+        let mut utils = launch_test(checkbox_group_app);
+        utils.wait_for_update().await;
+
+        let selected = utils.root().get(1).get(0);
+        assert_eq!(selected.text(), Some("b"));
+        assert_eq!(utils.focus_order().len(), 1);
+
+        utils.click_cursor((15., 15.)).await;
+        assert_eq!(selected.text(), Some("b,a"));
+        utils.click_cursor((15., 15.)).await;
+        assert_eq!(selected.text(), Some("b"));
+
+        // The arrow keys only move the focus, Space toggles the focused item
+        utils.focus_next().await;
+        utils.press(Key::ArrowUp).await;
+        utils.wait_for_update().await;
+        utils.press(Key::Character(" ".to_string())).await;
+        utils.wait_for_update().await;
+        assert_eq!(selected.text(), Some("b,c"));
+        assert_eq!(utils.focus_order().len(), 1);
+    }
+}
@@ -1,7 +1,6 @@
 use dioxus::prelude::*;
 use freya_core::platform::CursorIcon;
 use freya_elements as dioxus_elements;
-use freya_hooks::use_platform;
 
 /// Properties for the [`CursorArea`] component.
 #[derive(Props, Clone, PartialEq)]
@@ -34,29 +33,9 @@ pub struct CursorAreaProps {
 /// ```
 #[allow(non_snake_case)]
 pub fn CursorArea(CursorAreaProps { children, icon }: CursorAreaProps) -> Element {
-    let platform = use_platform();
-    let mut is_hovering = use_signal(|| false);
-
-    let onmousemove = move |_| {
-        *is_hovering.write() = true;
-        platform.set_cursor(icon);
-    };
-
-    let onmouseleave = move |_| {
-        *is_hovering.write() = false;
-        platform.set_cursor(CursorIcon::default());
-    };
-
-    use_drop(move || {
-        if *is_hovering.peek() {
-            platform.set_cursor(CursorIcon::default());
-        }
-    });
-
     rsx!(
         rect {
-            onmousemove,
-            onmouseleave,
+            cursor: icon.name(),
             {children}
         }
     )
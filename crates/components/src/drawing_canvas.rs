@@ -0,0 +1,554 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use dioxus::prelude::*;
+use freya_core::{
+    custom_attributes::CanvasRunnerContext,
+    parsing::Parse,
+};
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        pointer::PointerType,
+        MouseButton,
+        PointerEvent,
+    },
+};
+use freya_engine::prelude::*;
+use freya_hooks::{
+    use_canvas,
+    use_node_signal,
+    use_platform,
+    use_platform_information,
+};
+
+/// Pointer id used for the mouse, fingers use their own id.
+const MOUSE_POINTER: u64 = u64::MAX;
+/// Smallest pressure used to draw, so light strokes are still visible.
+const MIN_PRESSURE: f32 = 0.1;
+
+/// Point of a [`Stroke`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrokePoint {
+    pub x: f32,
+    pub y: f32,
+    /// Pressure from `0` to `1`, the mouse always uses `1`.
+    pub pressure: f32,
+}
+
+/// Freehand line drawn in a [`DrawingCanvas`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stroke {
+    pub points: Vec<StrokePoint>,
+    pub color: Color,
+    /// Width of the stroke with full pressure.
+    pub width: f32,
+}
+
+impl Stroke {
+    /// Draw the stroke from the point in the given index, the previous points must be drawn already.
+    fn draw(&self, canvas: &Canvas, from: usize) {
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_color(self.color);
+
+        let width = |point: &StrokePoint| self.width * point.pressure.clamp(MIN_PRESSURE, 1.);
+
+        if from == 0 {
+            let Some(point) = self.points.first() else {
+                return;
+            };
+            paint.set_style(PaintStyle::Fill);
+            canvas.draw_circle((point.x, point.y), width(point) / 2., &paint);
+        }
+
+        paint.set_style(PaintStyle::Stroke);
+        paint.set_stroke_cap(PaintCap::Round);
+        for segment in self.points[from.saturating_sub(1)..].windows(2) {
+            let [start, end] = segment else {
+                continue;
+            };
+            paint.set_stroke_width((width(start) + width(end)) / 2.);
+            canvas.draw_line((start.x, start.y), (end.x, end.y), &paint);
+        }
+    }
+}
+
+/// Strokes of a [`DrawingCanvas`], shared with its renderer.
+#[derive(Default)]
+struct Drawing {
+    strokes: Vec<Stroke>,
+    /// Whether the last stroke is still being drawn.
+    drawing: bool,
+    /// Increased when strokes are removed, so the renderer starts over.
+    revision: usize,
+    /// Logical size of the canvas.
+    size: (f32, f32),
+    background: Option<Color>,
+}
+
+/// Control the strokes of a [`DrawingCanvas`]. Created with [`use_drawing_controller`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct DrawingController {
+    drawing: Signal<Arc<Mutex<Drawing>>>,
+    /// Changes when strokes are finished or removed.
+    version: Signal<usize>,
+}
+
+impl DrawingController {
+    /// Get the drawn strokes.
+    pub fn strokes(&self) -> Vec<Stroke> {
+        self.version.read();
+        self.drawing.peek().lock().unwrap().strokes.clone()
+    }
+
+    /// Check if there is any stroke to undo.
+    pub fn can_undo(&self) -> bool {
+        self.version.read();
+        !self.drawing.peek().lock().unwrap().strokes.is_empty()
+    }
+
+    /// Remove the last stroke.
+    pub fn undo(&mut self) -> Option<Stroke> {
+        let stroke = {
+            let drawing = self.drawing.peek();
+            let mut drawing = drawing.lock().unwrap();
+            let stroke = drawing.strokes.pop()?;
+            drawing.drawing = false;
+            drawing.revision += 1;
+            stroke
+        };
+        *self.version.write() += 1;
+        Some(stroke)
+    }
+
+    /// Remove all the strokes.
+    pub fn clear(&mut self) {
+        {
+            let drawing = self.drawing.peek();
+            let mut drawing = drawing.lock().unwrap();
+            drawing.strokes.clear();
+            drawing.drawing = false;
+            drawing.revision += 1;
+        }
+        *self.version.write() += 1;
+    }
+
+    /// Render the background and the strokes into a PNG image.
+    ///
+    /// The image size will be the logical size of the canvas multiplied by `scale`.
+    /// Returns `None` if the canvas is not mounted or has an empty size.
+    pub fn export_png(&self, scale: f32) -> Option<Vec<u8>> {
+        let drawing = self.drawing.peek();
+        let drawing = drawing.lock().unwrap();
+        let (width, height) = (
+            (drawing.size.0 * scale).ceil() as i32,
+            (drawing.size.1 * scale).ceil() as i32,
+        );
+        if width <= 0 || height <= 0 {
+            return None;
+        }
+
+        let mut surface = raster_n32_premul((width, height))?;
+        let canvas = surface.canvas();
+        canvas.clear(drawing.background.unwrap_or(Color::TRANSPARENT));
+        canvas.scale((scale, scale));
+        for stroke in &drawing.strokes {
+            stroke.draw(canvas, 0);
+        }
+
+        let image = surface.image_snapshot();
+        let data = image.encode(None, EncodedImageFormat::PNG, None)?;
+        Some(data.to_vec())
+    }
+
+    fn start_stroke(&self, point: StrokePoint, color: Color, width: f32) {
+        let drawing = self.drawing.peek();
+        let mut drawing = drawing.lock().unwrap();
+        drawing.strokes.push(Stroke {
+            points: vec![point],
+            color,
+            width,
+        });
+        drawing.drawing = true;
+    }
+
+    fn extend_stroke(&self, point: StrokePoint) {
+        let drawing = self.drawing.peek();
+        let mut drawing = drawing.lock().unwrap();
+        if !drawing.drawing {
+            return;
+        }
+        if let Some(stroke) = drawing.strokes.last_mut() {
+            stroke.points.push(point);
+        }
+    }
+
+    fn finish_stroke(&mut self) {
+        let finished = {
+            let drawing = self.drawing.peek();
+            let mut drawing = drawing.lock().unwrap();
+            std::mem::replace(&mut drawing.drawing, false)
+        };
+        if finished {
+            *self.version.write() += 1;
+        }
+    }
+}
+
+/// Create a [`DrawingController`] to pass to a [`DrawingCanvas`].
+pub fn use_drawing_controller() -> DrawingController {
+    DrawingController {
+        drawing: use_signal(Arc::default),
+        version: use_signal(|| 0),
+    }
+}
+
+struct SurfaceWrapper(Surface);
+
+// SAFETY: The surface is only used from the render thread.
+unsafe impl Sync for SurfaceWrapper {}
+unsafe impl Send for SurfaceWrapper {}
+
+/// Strokes rendered into a surface that is kept across frames,
+/// so only the new points need to be drawn.
+#[derive(Default)]
+struct RetainedStrokes {
+    surface: Option<SurfaceWrapper>,
+    size: (i32, i32),
+    scale_factor: f32,
+    revision: usize,
+    /// Index of the last drawn stroke and how many of its points are drawn.
+    drawn: (usize, usize),
+}
+
+impl RetainedStrokes {
+    /// Start over with a new surface if the size changed or strokes were removed.
+    fn prepare(&mut self, size: (i32, i32), scale_factor: f32, revision: usize) {
+        if self.surface.is_some()
+            && self.size == size
+            && self.scale_factor == scale_factor
+            && self.revision == revision
+        {
+            return;
+        }
+        self.surface = raster_n32_premul(size).map(SurfaceWrapper);
+        self.size = size;
+        self.scale_factor = scale_factor;
+        self.revision = revision;
+        self.drawn = (0, 0);
+    }
+
+    /// Draw the points that are not in the surface yet.
+    fn draw_pending(&mut self, strokes: &[Stroke]) {
+        let Some(SurfaceWrapper(surface)) = &mut self.surface else {
+            return;
+        };
+        let canvas = surface.canvas();
+        canvas.save();
+        canvas.scale((self.scale_factor, self.scale_factor));
+
+        let (mut stroke_index, mut drawn_points) = self.drawn;
+        while let Some(stroke) = strokes.get(stroke_index) {
+            stroke.draw(canvas, drawn_points);
+            if stroke_index + 1 < strokes.len() {
+                stroke_index += 1;
+                drawn_points = 0;
+            } else {
+                drawn_points = stroke.points.len();
+                break;
+            }
+        }
+        self.drawn = (stroke_index, drawn_points);
+
+        canvas.restore();
+    }
+}
+
+/// Properties for the [`DrawingCanvas`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DrawingCanvasProps {
+    /// Controller of the strokes, to undo or export them.
+    pub controller: Option<DrawingController>,
+    /// Color of the new strokes. Default to `black`.
+    #[props(default = "black".into(), into)]
+    pub color: String,
+    /// Width of the new strokes with full pressure. Default to `4`.
+    #[props(default = 4.)]
+    pub stroke_width: f32,
+    /// Background of the canvas, also used when exporting. Default to `white`.
+    #[props(default = "white".into(), into)]
+    pub background: String,
+    /// Width of the DrawingCanvas. Default to `100%`.
+    #[props(default = "100%".into())]
+    pub width: String,
+    /// Height of the DrawingCanvas. Default to `100%`.
+    #[props(default = "100%".into())]
+    pub height: String,
+}
+
+/// Surface to draw freehand strokes with the mouse, fingers or pens.
+///
+/// The width of the strokes follows the pressure of touch pointers that report it.
+/// Use a [`DrawingController`] to undo strokes or export the drawing as an image.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut controller = use_drawing_controller();
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| {
+///                 controller.undo();
+///             },
+///             label { "Undo" }
+///         }
+///         DrawingCanvas {
+///             controller,
+///             color: "rgb(103, 80, 164)",
+///             stroke_width: 6.,
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn DrawingCanvas(
+    DrawingCanvasProps {
+        controller,
+        color,
+        stroke_width,
+        background,
+        width,
+        height,
+    }: DrawingCanvasProps,
+) -> Element {
+    let mut controller = controller.unwrap_or_else(use_drawing_controller);
+    let platform = use_platform();
+    let platform_information = use_platform_information();
+    let (reference, size) = use_node_signal();
+    let mut active_pointer = use_signal(|| None::<u64>);
+    let retained = use_hook(|| Arc::new(Mutex::new(RetainedStrokes::default())));
+
+    let background_color = Color::parse(&background).ok();
+    use_effect(use_reactive!(|background_color| {
+        controller.drawing.peek().lock().unwrap().background = background_color;
+    }));
+
+    use_effect(move || {
+        let area = size.read().area;
+        controller.drawing.peek().lock().unwrap().size = (area.width(), area.height());
+    });
+
+    // Undone or cleared strokes need to be removed from the screen
+    use_effect(move || {
+        controller.version.read();
+        platform.invalidate_drawing_area(size.peek().area);
+        platform.request_animation_frame();
+    });
+
+    let canvas = use_canvas(move || {
+        let drawing = controller.drawing.peek().clone();
+        let retained = retained.clone();
+        move |ctx: &mut CanvasRunnerContext| {
+            let drawing = drawing.lock().unwrap();
+            let mut retained = retained.lock().unwrap();
+            let surface_size = (
+                ctx.area.width().ceil() as i32,
+                ctx.area.height().ceil() as i32,
+            );
+            if surface_size.0 <= 0 || surface_size.1 <= 0 {
+                return;
+            }
+
+            retained.prepare(surface_size, ctx.scale_factor, drawing.revision);
+            retained.draw_pending(&drawing.strokes);
+
+            if let Some(SurfaceWrapper(surface)) = &mut retained.surface {
+                surface.draw(
+                    ctx.canvas,
+                    (ctx.area.min_x(), ctx.area.min_y()),
+                    SamplingOptions::default(),
+                    None,
+                );
+            }
+        }
+    });
+
+    // Logical position, pressure and id of the pointer
+    let pointer_point = move |e: &PointerEvent| {
+        let coordinates = e.get_element_coordinates();
+        match e.get_pointer_type() {
+            PointerType::Mouse { .. } => (
+                StrokePoint {
+                    x: coordinates.x as f32,
+                    y: coordinates.y as f32,
+                    pressure: 1.,
+                },
+                MOUSE_POINTER,
+            ),
+            PointerType::Touch {
+                finger_id, force, ..
+            } => {
+                let scale_factor = platform_information.peek().scale_factor;
+                (
+                    StrokePoint {
+                        x: (coordinates.x / scale_factor) as f32,
+                        y: (coordinates.y / scale_factor) as f32,
+                        pressure: force.map(|force| force.normalized() as f32).unwrap_or(1.),
+                    },
+                    finger_id,
+                )
+            }
+        }
+    };
+
+    let stroke_color = Color::parse(&color).unwrap_or(Color::BLACK);
+    let onpointerdown = move |e: PointerEvent| {
+        let is_secondary_button = matches!(
+            e.get_pointer_type(),
+            PointerType::Mouse { trigger_button } if trigger_button != Some(MouseButton::Left)
+        );
+        if is_secondary_button || active_pointer.peek().is_some() {
+            return;
+        }
+        let (point, pointer) = pointer_point(&e);
+        active_pointer.set(Some(pointer));
+        controller.start_stroke(point, stroke_color, stroke_width);
+        platform.invalidate_drawing_area(size.peek().area);
+        platform.request_animation_frame();
+    };
+
+    let onpointermove = move |e: PointerEvent| {
+        let (point, pointer) = pointer_point(&e);
+        if *active_pointer.peek() != Some(pointer) {
+            return;
+        }
+        controller.extend_stroke(point);
+        platform.invalidate_drawing_area(size.peek().area);
+        platform.request_animation_frame();
+    };
+
+    let onglobalpointerup = move |e: PointerEvent| {
+        let (_, pointer) = pointer_point(&e);
+        if *active_pointer.peek() == Some(pointer) {
+            active_pointer.set(None);
+            controller.finish_stroke();
+        }
+    };
+
+    rsx!(rect {
+        reference,
+        width,
+        height,
+        background,
+        canvas_reference: canvas.attribute(),
+        onpointerdown,
+        onpointermove,
+        onglobalpointerup,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn drawing_canvas() {
+        fn drawing_canvas_app() -> Element {
+            let mut controller = use_drawing_controller();
+            let strokes = controller.strokes();
+            let strokes = strokes
+                .iter()
+                .map(|stroke| {
+                    let points = stroke
+                        .points
+                        .iter()
+                        .map(|point| format!("{}:{}", point.x, point.pressure))
+                        .collect::<Vec<_>>();
+                    points.join(",")
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            let mut exported = use_signal(|| false);
+
+            rsx!(
+                DrawingCanvas {
+                    controller,
+                    height: "200",
+                }
+                rect {
+                    onclick: move |_| {
+                        controller.undo();
+                    },
+                    width: "50",
+                    height: "50",
+                }
+                rect {
+                    onclick: move |_| {
+                        let png = controller.export_png(1.).unwrap_or_default();
+                        exported.set(png.starts_with(b"\x89PNG"));
+                    },
+                    width: "50",
+                    height: "50",
+                    label {
+                        "{exported}"
+                    }
+                }
+                label {
+                    "{strokes}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(drawing_canvas_app);
+        utils.wait_for_update().await;
+        let label = utils.root().get(3);
+
+        // Draw a stroke with the mouse
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (10., 10.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.move_cursor((20., 10.)).await;
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseUp,
+            cursor: (20., 10.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("10:1,20:1"));
+
+        // Draw a stroke with a finger that reports its pressure
+        for (x, phase, name) in [
+            (30., TouchPhase::Started, EventName::TouchStart),
+            (40., TouchPhase::Moved, EventName::TouchMove),
+            (40., TouchPhase::Ended, EventName::TouchEnd),
+        ] {
+            utils.push_event(TestEvent::Touch {
+                name,
+                location: (x, 10.).into(),
+                finger_id: 1,
+                phase,
+                force: Some(Force::Normalized(0.5)),
+            });
+            utils.wait_for_update().await;
+        }
+        assert_eq!(label.get(0).text(), Some("10:1,20:1 30:0.5,40:0.5"));
+
+        // Undo the last stroke
+        utils.click_cursor((25., 225.)).await;
+        assert_eq!(label.get(0).text(), Some("10:1,20:1"));
+
+        // Export the drawing
+        utils.click_cursor((25., 275.)).await;
+        assert_eq!(utils.root().get(2).get(0).get(0).text(), Some("true"));
+    }
+}
@@ -0,0 +1,353 @@
+use dioxus::prelude::*;
+use emojis::{
+    Emoji,
+    Group,
+    SkinTone,
+};
+use freya_core::{
+    platform::CursorIcon,
+    style::emoji_fonts,
+};
+use freya_elements as dioxus_elements;
+use freya_hooks::{
+    use_applied_theme,
+    use_platform,
+    EmojiPickerTheme,
+    EmojiPickerThemeWith,
+};
+
+use crate::{
+    Input,
+    VirtualScrollView,
+};
+
+/// Groups of emojis shown as tabs, along with the emoji representing them.
+const GROUPS: [(Group, &str); 9] = [
+    (Group::SmileysAndEmotion, "😀"),
+    (Group::PeopleAndBody, "👋"),
+    (Group::AnimalsAndNature, "🐶"),
+    (Group::FoodAndDrink, "🍔"),
+    (Group::TravelAndPlaces, "🚗"),
+    (Group::Activities, "⚽"),
+    (Group::Objects, "💡"),
+    (Group::Symbols, "💕"),
+    (Group::Flags, "🏁"),
+];
+
+/// Skin tones that can be selected, along with the emoji representing them.
+const SKIN_TONES: [(SkinTone, &str); 6] = [
+    (SkinTone::Default, "✋"),
+    (SkinTone::Light, "✋🏻"),
+    (SkinTone::MediumLight, "✋🏼"),
+    (SkinTone::Medium, "✋🏽"),
+    (SkinTone::MediumDark, "✋🏾"),
+    (SkinTone::Dark, "✋🏿"),
+];
+
+/// Whether the name or any of the shortcodes of the emoji contain the lowercase query.
+fn emoji_matches(emoji: &Emoji, query: &str) -> bool {
+    emoji.name().to_lowercase().contains(query)
+        || emoji
+            .shortcodes()
+            .any(|shortcode| shortcode.contains(query))
+}
+
+/// Properties for the [`EmojiPicker`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct EmojiPickerProps {
+    /// Theme override.
+    pub theme: Option<EmojiPickerThemeWith>,
+    /// Emojis in every row. Default to `8`.
+    #[props(default = 8)]
+    pub columns: usize,
+    /// Width and height of every emoji. Default to `36`.
+    #[props(default = 36.)]
+    pub emoji_size: f32,
+    /// Handler for the `onselect` event, with the selected emoji in the selected skin tone.
+    pub onselect: EventHandler<String>,
+}
+
+/// Pick an emoji by group or by searching its name, in any skin tone.
+///
+/// Only the visible rows of emojis are rendered, and they are drawn with the color emoji fonts
+/// of the platform, see [`emoji_fonts`].
+///
+/// # Styling
+/// Inherits the [`EmojiPickerTheme`](freya_hooks::EmojiPickerTheme) theme.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut text = use_signal(String::new);
+///
+///     rsx!(
+///         label { "{text}" }
+///         EmojiPicker {
+///             onselect: move |emoji: String| text.write().push_str(&emoji)
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn EmojiPicker(
+    EmojiPickerProps {
+        theme,
+        columns,
+        emoji_size,
+        onselect,
+    }: EmojiPickerProps,
+) -> Element {
+    let EmojiPickerTheme {
+        background,
+        border_fill,
+        hover_background,
+        selected_background,
+        color,
+        width,
+        height,
+    } = use_applied_theme!(&theme, emoji_picker);
+    let mut query = use_signal(String::new);
+    let mut group = use_signal(|| Group::SmileysAndEmotion);
+    let mut skin_tone = use_signal(|| SkinTone::Default);
+
+    let emojis = use_memo(move || {
+        let query = query.read().trim().to_lowercase();
+        let group = *group.read();
+        let skin_tone = *skin_tone.read();
+        emojis::iter()
+            .filter(|emoji| {
+                if query.is_empty() {
+                    emoji.group() == group
+                } else {
+                    emoji_matches(emoji, &query)
+                }
+            })
+            .map(|emoji| {
+                let emoji = emoji.with_skin_tone(skin_tone).unwrap_or(emoji);
+                (emoji.as_str(), emoji.name())
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let columns = columns.max(1);
+    let rows = emojis.read().len().div_ceil(columns);
+    let font_family = emoji_fonts().join(", ");
+
+    rsx!(
+        rect {
+            width: "{width}",
+            height: "{height}",
+            background: "{background}",
+            border: "1 inner {border_fill}",
+            corner_radius: "8",
+            color: "{color}",
+            padding: "6",
+            spacing: "6",
+            Input {
+                value: query(),
+                placeholder: "Search",
+                width: "fill",
+                onchange: move |value| query.set(value),
+            }
+            rect {
+                direction: "horizontal",
+                width: "fill",
+                main_align: "space-between",
+                for (i, (tab_group, icon)) in GROUPS.into_iter().enumerate() {
+                    EmojiPickerButton {
+                        key: "{i}",
+                        emoji: icon,
+                        name: "{tab_group:?}",
+                        size: 28.,
+                        font_family: font_family.clone(),
+                        selected: query.read().is_empty() && *group.read() == tab_group,
+                        hover_background: hover_background.clone(),
+                        selected_background: selected_background.clone(),
+                        onpress: move |_| {
+                            query.set(String::new());
+                            group.set(tab_group);
+                        }
+                    }
+                }
+            }
+            VirtualScrollView {
+                length: rows,
+                item_size: emoji_size,
+                height: "flex(1)",
+                builder_args: (emojis.read().clone(), columns, emoji_size, font_family.clone(), hover_background.to_string()),
+                builder: move |row, args: &Option<(Vec<(&'static str, &'static str)>, usize, f32, String, String)>| {
+                    let (emojis, columns, emoji_size, font_family, hover_background) = args.as_ref().unwrap();
+                    let start = row * columns;
+                    let end = (start + columns).min(emojis.len());
+                    rsx!(
+                        rect {
+                            key: "{row}",
+                            direction: "horizontal",
+                            height: "{emoji_size}",
+                            for (emoji, name) in emojis[start..end].iter().copied() {
+                                EmojiPickerButton {
+                                    key: "{emoji}",
+                                    emoji,
+                                    name: name.to_string(),
+                                    size: *emoji_size,
+                                    font_family: font_family.clone(),
+                                    selected: false,
+                                    hover_background: hover_background.clone(),
+                                    selected_background: hover_background.clone(),
+                                    onpress: move |_| onselect.call(emoji.to_string())
+                                }
+                            }
+                        }
+                    )
+                }
+            }
+            rect {
+                direction: "horizontal",
+                width: "fill",
+                main_align: "end",
+                for (i, (tone, icon)) in SKIN_TONES.into_iter().enumerate() {
+                    EmojiPickerButton {
+                        key: "{i}",
+                        emoji: icon,
+                        name: "{tone:?}",
+                        size: 28.,
+                        font_family: font_family.clone(),
+                        selected: *skin_tone.read() == tone,
+                        hover_background: hover_background.clone(),
+                        selected_background: selected_background.clone(),
+                        onpress: move |_| skin_tone.set(tone)
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn EmojiPickerButton(
+    emoji: &'static str,
+    name: String,
+    size: f32,
+    font_family: String,
+    selected: bool,
+    hover_background: String,
+    selected_background: String,
+    onpress: EventHandler<()>,
+) -> Element {
+    let platform = use_platform();
+    let mut hovering = use_signal(|| false);
+
+    use_drop(move || {
+        if hovering() {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let onmouseenter = move |_| {
+        platform.set_cursor(CursorIcon::Pointer);
+        hovering.set(true);
+    };
+
+    let onmouseleave = move |_| {
+        platform.set_cursor(CursorIcon::default());
+        hovering.set(false);
+    };
+
+    let background = if hovering() {
+        hover_background
+    } else if selected {
+        selected_background
+    } else {
+        "transparent".to_string()
+    };
+
+    rsx!(
+        rect {
+            onclick: move |_| onpress.call(()),
+            onmouseenter,
+            onmouseleave,
+            a11y_role: "button",
+            a11y_name: "{name}",
+            width: "{size}",
+            height: "{size}",
+            corner_radius: "6",
+            background: "{background}",
+            main_align: "center",
+            cross_align: "center",
+            label {
+                font_family: "{font_family}",
+                font_size: "{size * 0.6}",
+                "{emoji}"
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn emoji_picker() {
+        fn emoji_picker_app() -> Element {
+            let mut selected = use_signal(String::new);
+
+            rsx!(
+                EmojiPicker {
+                    onselect: move |emoji| selected.set(emoji)
+                }
+                label { "{selected}" }
+            )
+        }
+
+        let mut utils = launch_test(emoji_picker_app);
+        let root = utils.root();
+        let picker = root.get(0);
+        let selected = root.get(1);
+        utils.wait_for_update().await;
+
+        // The first row of emojis starts right below the groups
+        let groups = picker.get(1).area().unwrap();
+        let first_emoji = (24., groups.max_y() as f64 + 24.);
+
+        // Select the first emoji of the default group
+        utils.click_cursor(first_emoji).await;
+        assert_eq!(selected.get(0).text(), Some("😀"));
+
+        // Select the darkest skin tone and the people group
+        let dark_tone = picker.get(3).get(5).area().unwrap().center();
+        utils
+            .click_cursor((dark_tone.x as f64, dark_tone.y as f64))
+            .await;
+        let people = picker.get(1).get(1).area().unwrap().center();
+        utils.click_cursor((people.x as f64, people.y as f64)).await;
+
+        // Emojis with skin tones are selected in the chosen one
+        utils.click_cursor(first_emoji).await;
+        assert_eq!(selected.get(0).text(), Some("👋🏿"));
+
+        // Search an emoji by its name
+        let input = picker.get(0).area().unwrap().center();
+        utils.click_cursor((input.x as f64, input.y as f64)).await;
+        for ch in "rocket".chars() {
+            utils.push_event(TestEvent::Keyboard {
+                name: EventName::KeyDown,
+                key: Key::Character(ch.to_string()),
+                code: Code::Unidentified,
+                modifiers: Modifiers::default(),
+            });
+            utils.wait_for_update().await;
+        }
+        utils.wait_for_update().await;
+
+        assert!(picker.get(2).get_by_text("🚀").is_some());
+        assert!(picker.get(2).get_by_text("😀").is_none());
+
+        utils.click_cursor(first_emoji).await;
+        assert_eq!(selected.get(0).text(), Some("🚀"));
+    }
+}
@@ -10,6 +10,8 @@ use dioxus::prelude::*;
 
 use crate::{
     ButtonProps,
+    ButtonSize,
+    ButtonVariant,
     InputMode,
     InputProps,
 };
@@ -57,6 +59,11 @@ impl<Id: Clone + Hash + Eq + Display> UseForm<Id> {
             })),
             children: Ok(VNode::placeholder()),
             onclick: None,
+            variant: ButtonVariant::default(),
+            size: ButtonSize::default(),
+            loading: false,
+            leading: None,
+            trailing: None,
         }
     }
 }
@@ -0,0 +1,54 @@
+use dioxus::prelude::*;
+use freya_elements as dioxus_elements;
+use freya_hooks::{
+    use_applied_theme,
+    IconTheme,
+    IconThemeWith,
+};
+
+/// Properties for the [`EyeIcon`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct EyeIconProps {
+    /// Theme override.
+    pub theme: Option<IconThemeWith>,
+    /// Color.
+    #[props(into)]
+    pub fill: String,
+    /// Cross out the eye. Default `false`.
+    #[props(default = false)]
+    pub crossed: bool,
+}
+
+/// Icon component for an Eye, e.g to reveal a hidden text.
+#[allow(non_snake_case)]
+pub fn EyeIcon(
+    EyeIconProps {
+        theme,
+        fill,
+        crossed,
+    }: EyeIconProps,
+) -> Element {
+    let IconTheme {
+        height,
+        width,
+        margin,
+    } = use_applied_theme!(&theme, icon);
+
+    let cross = if crossed {
+        format!(r#"<path d="M3.7 2.3L21.7 20.3L20.3 21.7L2.3 3.7Z" fill="{fill}"/>"#)
+    } else {
+        String::new()
+    };
+
+    rsx!(svg {
+        height: "{height}",
+        width: "{width}",
+        margin: "{margin}",
+        svg_content: r#"
+            <svg viewBox="0 0 24 24" fill="none" xmlns="http://www.w3.org/2000/svg">
+                <path d="M12 5C6.5 5 2.3 8.4 1 12C2.3 15.6 6.5 19 12 19C17.5 19 21.7 15.6 23 12C21.7 8.4 17.5 5 12 5ZM12 16.5C9.5 16.5 7.5 14.5 7.5 12C7.5 9.5 9.5 7.5 12 7.5C14.5 7.5 16.5 9.5 16.5 12C16.5 14.5 14.5 16.5 12 16.5ZM12 9.5C10.6 9.5 9.5 10.6 9.5 12C9.5 13.4 10.6 14.5 12 14.5C13.4 14.5 14.5 13.4 14.5 12C14.5 10.6 13.4 9.5 12 9.5Z" fill="{fill}"/>
+                {cross}
+            </svg>
+        "#
+    })
+}
@@ -1,7 +1,9 @@
 mod arrow;
 mod cross;
+mod eye;
 mod tick;
 
 pub use arrow::*;
 pub use cross::*;
+pub use eye::*;
 pub use tick::*;
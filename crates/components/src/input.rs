@@ -1,6 +1,7 @@
 use std::{
     borrow::Cow,
     cell::{
+        Cell,
         Ref,
         RefCell,
     },
@@ -8,20 +9,32 @@ use std::{
 };
 
 use dioxus::prelude::*;
-use freya_core::platform::CursorIcon;
+use freya_core::{
+    platform::CursorIcon,
+    types::AccessibilityRole,
+};
 use freya_elements::{
     self as dioxus_elements,
     events::{
-        keyboard::Key,
+        keyboard::{
+            Key,
+            Modifiers,
+        },
+        ImeEvent,
         KeyboardData,
         MouseEvent,
     },
 };
 use freya_hooks::{
+    is_redo_shortcut,
+    is_undo_shortcut,
     use_applied_theme,
     use_editable,
     use_focus,
     use_platform,
+    use_shared_history,
+    use_soft_keyboard,
+    Command,
     EditableConfig,
     EditableEvent,
     EditableMode,
@@ -30,7 +43,10 @@ use freya_hooks::{
     TextEditor,
 };
 
-use crate::ScrollView;
+use crate::{
+    EyeIcon,
+    ScrollView,
+};
 
 /// Enum to declare is [`Input`] hidden.
 #[derive(Default, Clone, PartialEq)]
@@ -93,7 +109,7 @@ impl InputValidator {
 pub struct InputProps {
     /// Theme override.
     pub theme: Option<InputThemeWith>,
-    /// Text to show for when there is no value
+    /// Text to show for when there is no value, it's cut with an ellipsis if it doesn't fit.
     pub placeholder: ReadOnlySignal<Option<String>>,
     /// Current value of the Input.
     pub value: ReadOnlySignal<String>,
@@ -102,6 +118,11 @@ pub struct InputProps {
     /// Display mode for Input. By default, input text is shown as it is provided.
     #[props(default = InputMode::Shown, into)]
     pub mode: InputMode,
+    /// Show a button to reveal the text when the mode is [`InputMode::Hidden`]. Default `false`.
+    #[props(default = false)]
+    pub reveal_toggle: bool,
+    /// Maximum number of characters, longer changes are discarded and written text is cut to fit.
+    pub max_length: Option<usize>,
     /// Automatically focus this Input upon creation. Default `false`.
     #[props(default = false)]
     pub auto_focus: bool,
@@ -113,6 +134,12 @@ pub struct InputProps {
 
 /// Small box to edit text.
 ///
+/// Changes can be undone with `Ctrl + Z` and redone with `Ctrl + Shift + Z` or `Ctrl + Y` (`Cmd` in macOS).
+/// Inside a [`SharedHistory`](freya_hooks::SharedHistory) the changes are registered in it instead,
+/// so they are undone along with the ones of the app by its shortcuts handler.
+///
+/// Use [`InputMode::Hidden`] for passwords, along with `reveal_toggle` to let the user see what they wrote.
+///
 /// # Styling
 /// Inherits the [`InputTheme`](freya_hooks::InputTheme) theme.
 ///
@@ -159,6 +186,8 @@ pub fn Input(
         value,
         onchange,
         mode,
+        reveal_toggle,
+        max_length,
         placeholder,
         auto_focus,
         onvalidate,
@@ -183,7 +212,15 @@ pub fn Input(
         hover_background,
     } = use_applied_theme!(&theme, input);
     let mut focus = use_focus();
+    let soft_keyboard = use_soft_keyboard();
+    let mut was_focused = use_hook(|| CopyValue::new(false));
     let mut drag_origin = use_signal(|| None);
+    let mut revealed = use_signal(|| false);
+    let shared_history = use_shared_history();
+    // Length of the shared history after registering the last change
+    let mut shared_history_len = use_signal(|| None);
+    // Expires the changes registered in the shared history once unmounted
+    let mounted = use_hook(|| Rc::new(Cell::new(true)));
 
     let value = value.read();
     let placeholder = placeholder.read();
@@ -194,47 +231,141 @@ pub fn Input(
         editable.editor_mut().write().editor_history().clear();
     }
 
-    use_drop(move || {
-        if *status.peek() == InputStatus::Hovering {
-            platform.set_cursor(CursorIcon::default());
+    use_drop({
+        to_owned![mounted];
+        move || {
+            if *status.peek() == InputStatus::Hovering {
+                platform.set_cursor(CursorIcon::default());
+            }
+            mounted.set(false);
+            if let Some(mut history) = shared_history {
+                history.forget_expired();
+            }
         }
     });
 
     use_effect(move || {
-        if !focus.is_focused() {
+        let is_focused = focus.is_focused();
+        if is_focused {
+            soft_keyboard.show();
+        } else {
             editable.editor_mut().write().clear_selection();
+            // Keep the keyboard if the focus moved to another text input
+            let is_text_input_focused = matches!(
+                focus.focused_node().peek().role(),
+                AccessibilityRole::TextInput | AccessibilityRole::MultilineTextInput
+            );
+            if *was_focused.peek() && !is_text_input_focused {
+                soft_keyboard.hide();
+            }
         }
+        was_focused.set(is_focused);
     });
 
     let onkeydown = move |e: Event<KeyboardData>| {
-        if e.data.key != Key::Enter && e.data.key != Key::Tab {
+        // Let the containers handle a Backspace with nothing to delete, e.g. to remove the previous item
+        let is_empty_backspace =
+            e.data.key == Key::Backspace && editable.editor().peek().len_chars() == 0;
+        if e.data.key != Key::Enter && e.data.key != Key::Tab && !is_empty_backspace {
             e.stop_propagation();
-            editable.process_event(&EditableEvent::KeyDown(e.data));
-            let text = editable.editor().peek().to_string();
+            let mut data = e.data;
+
+            // Leave the shortcuts to the handler of the shared history
+            if shared_history.is_some() && (is_undo_shortcut(&data) || is_redo_shortcut(&data)) {
+                return;
+            }
+
+            if let Some(max_length) = max_length {
+                let editor = editable.editor().peek();
+                let is_shortcut = data.modifiers.contains(Modifiers::CONTROL)
+                    || data.modifiers.contains(Modifiers::META);
 
-            let apply_change = if let Some(onvalidate) = onvalidate {
-                let editor = editable.editor_mut();
-                let mut editor = editor.write();
-                let validator = InputValidator::new(text.clone());
-                onvalidate(validator.clone());
-                let is_valid = validator.is_valid();
-
-                if !is_valid {
-                    // If it is not valid then undo the latest change and discard all the redos
-                    let undo_result = editor.undo();
-                    if let Some(idx) = undo_result {
-                        editor.set_cursor_pos(idx);
+                // Cut the written text, e.g committed with an IME, to what fits
+                if let Some(text) = data.to_text().filter(|_| !is_shortcut) {
+                    let selected_len = editor
+                        .get_selected_text()
+                        .map(|text| text.chars().count())
+                        .unwrap_or_default();
+                    let available = max_length.saturating_sub(editor.len_chars() - selected_len);
+                    if available == 0 {
+                        return;
                     }
-                    editor.editor_history().clear_redos();
+                    let text = text.chars().take(available).collect();
+                    data = Rc::new(KeyboardData::new(
+                        Key::Character(text),
+                        data.code,
+                        data.modifiers,
+                    ));
                 }
+            }
 
-                is_valid
-            } else {
-                true
-            };
+            let is_shared_history_changed = shared_history
+                .is_some_and(|history| Some(history.undo_len()) != *shared_history_len.peek());
+            if onvalidate.is_some() || max_length.is_some() || is_shared_history_changed {
+                // Keep every change in its own batch so an invalid one can be undone alone,
+                // or so it's not merged with one made before the last change of the shared history
+                editable.editor_mut().write().editor_history().end_batch();
+            }
+            let prev_change = editable
+                .editor_mut()
+                .write()
+                .editor_history()
+                .current_change();
+            let prev_text = editable.editor().peek().to_string();
+            editable.process_event(&EditableEvent::KeyDown(data));
+            let text = editable.editor().peek().to_string();
+
+            // Changes that don't make the text longer, e.g deletions, are always allowed
+            // so a value that is already over the limit can still be shortened
+            let fits = max_length
+                .map(|max_length| {
+                    let len = text.chars().count();
+                    len <= max_length || len <= prev_text.chars().count()
+                })
+                .unwrap_or(true);
+            let is_valid = fits
+                && if let Some(onvalidate) = onvalidate {
+                    let validator = InputValidator::new(text.clone());
+                    onvalidate(validator.clone());
+                    validator.is_valid()
+                } else {
+                    true
+                };
 
-            if apply_change {
+            if is_valid {
+                let change = editable
+                    .editor_mut()
+                    .write()
+                    .editor_history()
+                    .current_change();
+                if let Some(mut history) = shared_history.filter(|_| change > prev_change) {
+                    let mounted = mounted.clone();
+                    history.push(Box::new(
+                        Command::new(
+                            move || {
+                                editable.undo();
+                                editable.editor_mut().write().editor_history().end_batch();
+                                onchange.call(editable.editor().peek().to_string());
+                            },
+                            move || {
+                                editable.redo();
+                                editable.editor_mut().write().editor_history().end_batch();
+                                onchange.call(editable.editor().peek().to_string());
+                            },
+                        )
+                        .with_expiration(move || !mounted.get()),
+                    ));
+                    shared_history_len.set(Some(history.undo_len()));
+                }
                 onchange.call(text);
+            } else if text != prev_text {
+                // If it is not valid then undo the latest change and discard all the redos
+                let mut editor = editable.editor_mut().write();
+                let undo_result = editor.undo();
+                if let Some(idx) = undo_result {
+                    editor.set_cursor_pos(idx);
+                }
+                editor.editor_history().clear_redos();
             }
         }
     };
@@ -244,6 +375,11 @@ pub fn Input(
         editable.process_event(&EditableEvent::KeyUp(e.data));
     };
 
+    let onimepreedit = move |e: ImeEvent| {
+        e.stop_propagation();
+        editable.process_event(&EditableEvent::ImePreedit(e.data));
+    };
+
     let oninputmousedown = move |e: MouseEvent| {
         if !display_placeholder {
             editable.process_event(&EditableEvent::MouseDown(e.data, 0));
@@ -296,10 +432,11 @@ pub fn Input(
         // + is focused
         // + it has not just being dragged
         // + a global click happened
+        // + it was not clicked
         if focus.is_focused() {
             if drag_origin.read().is_some() {
                 drag_origin.set(None);
-            } else {
+            } else if *status.read() != InputStatus::Hovering {
                 focus.request_unfocus();
             }
         }
@@ -308,15 +445,18 @@ pub fn Input(
     let a11y_id = focus.attribute();
     let cursor_reference = editable.cursor_attr();
     let highlights = editable.highlights_attr(0);
+    let carets = editable.carets_attr(0);
 
-    let (background, cursor_char) = if focus.is_focused() {
-        (
-            hover_background,
-            editable.editor().read().cursor_pos().to_string(),
-        )
+    let preedit = editable.preedit().read().clone();
+    let is_composing = focus.is_focused() && !preedit.is_empty();
+
+    let show_reveal_toggle = reveal_toggle && matches!(mode, InputMode::Hidden(_));
+    let mode = if show_reveal_toggle && revealed() {
+        InputMode::Shown
     } else {
-        (background, "none".to_string())
+        mode
     };
+
     let border = if focus.is_focused_with_keyboard() {
         format!("2 inner {focus_border_fill}")
     } else {
@@ -329,28 +469,77 @@ pub fn Input(
         font_theme.color
     };
 
-    let text = match (mode, &*placeholder) {
-        (_, Some(placeholder)) if display_placeholder => Cow::Borrowed(placeholder.as_str()),
-        (InputMode::Hidden(ch), _) => Cow::Owned(ch.to_string().repeat(value.len())),
-        (InputMode::Shown, _) => Cow::Borrowed(value.as_str()),
+    let (text, preedit) = match (&mode, &*placeholder) {
+        (_, Some(placeholder)) if display_placeholder => {
+            (Cow::Borrowed(placeholder.as_str()), preedit)
+        }
+        // The text being composed is hidden too
+        (InputMode::Hidden(ch), _) => (
+            Cow::Owned(ch.to_string().repeat(value.chars().count())),
+            ch.to_string().repeat(preedit.chars().count()),
+        ),
+        (InputMode::Shown, _) => (Cow::Borrowed(value.as_str()), preedit),
+    };
+
+    // Split the text at the cursor to show the text being composed in between
+    let (text_before, text_after) = {
+        let editor = editable.editor().read();
+        let cursor_char_idx = editor.utf16_cu_to_char(editor.cursor_pos());
+        let split_at = text
+            .char_indices()
+            .nth(cursor_char_idx)
+            .map(|(i, _)| i)
+            .unwrap_or(text.len());
+        text.split_at(split_at)
+    };
+
+    let (background, cursor_char) = if focus.is_focused() {
+        // Show the cursor at the end of the text being composed
+        let mut cursor_pos = text_before.encode_utf16().count();
+        if is_composing {
+            cursor_pos += preedit.encode_utf16().count();
+        }
+        (hover_background, cursor_pos.to_string())
+    } else {
+        (background, "none".to_string())
+    };
+
+    let (paragraph_width, text_overflow) = if display_placeholder {
+        ("calc(100% - 20)", "ellipsis")
+    } else {
+        ("auto", "clip")
+    };
+
+    // Only lay the text and the reveal button in a row when the button is shown
+    let (direction, main_align, cross_align) = if show_reveal_toggle {
+        ("horizontal", "start", "center")
+    } else {
+        ("vertical", "center", "start")
+    };
+
+    let onrevealclick = move |e: MouseEvent| {
+        e.stop_propagation();
+        revealed.toggle();
     };
 
     rsx!(
         rect {
             width,
-            direction: "vertical",
+            direction,
             color: "{color}",
             background: "{background}",
             border,
             shadow: "{shadow}",
             corner_radius: "{corner_radius}",
             margin: "{margin}",
-            main_align: "center",
+            main_align,
+            cross_align,
             a11y_id,
             a11y_role: "text-input",
             a11y_auto_focus: "{auto_focus}",
             onkeydown,
             onkeyup,
+            onimepreedit,
             overflow: "clip",
             onmousedown: oninputmousedown,
             onmouseenter,
@@ -360,6 +549,7 @@ pub fn Input(
                 direction: "horizontal",
                 show_scrollbar: false,
                 paragraph {
+                    width: paragraph_width,
                     min_width: "calc(100% - 20)",
                     margin: "6 10",
                     onglobalclick,
@@ -371,9 +561,34 @@ pub fn Input(
                     cursor_mode: "editable",
                     cursor_color: "{color}",
                     max_lines: "1",
+                    text_overflow,
                     highlights,
-                    text {
-                        {text}
+                    carets,
+                    if is_composing {
+                        text {
+                            {text_before}
+                        }
+                        text {
+                            decoration: "underline",
+                            {preedit}
+                        }
+                        text {
+                            {text_after}
+                        }
+                    } else {
+                        text {
+                            "{text}"
+                        }
+                    }
+                }
+            }
+            if show_reveal_toggle {
+                rect {
+                    padding: "0 8 0 0",
+                    onmousedown: onrevealclick,
+                    EyeIcon {
+                        fill: "{color}",
+                        crossed: revealed(),
                     }
                 }
             }
@@ -434,6 +649,127 @@ mod test {
         assert_eq!(text.get(0).text(), Some("Hello, World"));
     }
 
+    #[tokio::test]
+    pub async fn soft_keyboard() {
+        fn input_app() -> Element {
+            let soft_keyboard = use_soft_keyboard();
+            let mut first = use_signal(String::new);
+            let mut second = use_signal(String::new);
+
+            rsx!(
+                rect {
+                    height: "100",
+                    Input {
+                        value: first,
+                        onchange: move |new_value| first.set(new_value),
+                    }
+                }
+                rect {
+                    height: "100",
+                    Input {
+                        value: second,
+                        onchange: move |new_value| second.set(new_value),
+                    }
+                }
+                label {
+                    "{soft_keyboard.is_visible()}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(input_app);
+        let label = utils.root().get(2);
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("false"));
+
+        // Focusing an input shows the keyboard
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (50., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("true"));
+
+        // It stays when the focus moves to another input
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (50., 125.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("true"));
+
+        // And it's hidden once no input is focused
+        utils.click_cursor((300., 400.)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(utils.focus_id(), ACCESSIBILITY_ROOT_ID);
+        assert_eq!(label.get(0).text(), Some("false"));
+    }
+
+    #[tokio::test]
+    pub async fn input_ime() {
+        fn input_app() -> Element {
+            let mut value = use_signal(|| "Hello, ".to_string());
+
+            rsx!(Input {
+                value,
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            })
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let paragraph = root.get(0).get(0).get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Focus the input in the end of the text
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (115., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The text being composed is shown after the cursor
+        utils.push_event(TestEvent::Ime {
+            name: EventName::ImePreedit,
+            text: "日本".to_string(),
+            cursor: Some((6, 6)),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(paragraph.children_ids().len(), 3);
+        assert_eq!(paragraph.get(0).get(0).text(), Some("Hello, "));
+        assert_eq!(paragraph.get(1).get(0).text(), Some("日本"));
+
+        // Commit the composed text
+        utils.push_event(TestEvent::Ime {
+            name: EventName::ImePreedit,
+            text: String::new(),
+            cursor: None,
+        });
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("日本".to_string()),
+            code: Code::Unidentified,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(paragraph.children_ids().len(), 1);
+        assert_eq!(paragraph.get(0).get(0).text(), Some("Hello, 日本"));
+    }
+
     #[tokio::test]
     pub async fn validate() {
         fn input_app() -> Element {
@@ -486,4 +822,400 @@ mod test {
         // Check that only "BC" was been written to the input.
         assert_eq!(text.get(0).text(), Some("ABC"));
     }
+
+    #[tokio::test]
+    pub async fn max_length() {
+        fn input_app() -> Element {
+            let mut value = use_signal(|| "A".to_string());
+
+            rsx!(Input {
+                value: value.read().clone(),
+                max_length: 3,
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            },)
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let text = root.get(0).get(0).get(0).get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Focus the input in the end of the text
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (115., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Commit a text longer than what fits
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("日本語".to_string()),
+            code: Code::Unidentified,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        // Check that it was cut
+        assert_eq!(text.get(0).text(), Some("A日本"));
+
+        // Try to write "B"
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("B".to_string()),
+            code: Code::Unidentified,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        // Check that nothing else was written
+        assert_eq!(text.get(0).text(), Some("A日本"));
+    }
+
+    #[tokio::test]
+    pub async fn max_length_exceeded() {
+        fn input_app() -> Element {
+            let mut value = use_signal(|| "ABCDE".to_string());
+
+            rsx!(Input {
+                value: value.read().clone(),
+                max_length: 3,
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            },)
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let text = root.get(0).get(0).get(0).get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Focus the input in the end of the text
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (115., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Delete a character from the text that is over the limit
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Backspace,
+            code: Code::Backspace,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        // Check that it was deleted
+        assert_eq!(text.get(0).text(), Some("ABCD"));
+
+        // Try to write "F"
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("F".to_string()),
+            code: Code::Unidentified,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        // Check that nothing was written
+        assert_eq!(text.get(0).text(), Some("ABCD"));
+    }
+
+    #[tokio::test]
+    pub async fn password() {
+        fn input_app() -> Element {
+            let mut value = use_signal(|| "pass".to_string());
+
+            rsx!(Input {
+                value: value.read().clone(),
+                mode: InputMode::new_password(),
+                reveal_toggle: true,
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            },)
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let paragraph = root.get(0).get(0).get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // The text is hidden
+        assert_eq!(paragraph.get(0).get(0).text(), Some("****"));
+
+        // Focus the input in the end of the text
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (115., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The text being composed is hidden too
+        utils.push_event(TestEvent::Ime {
+            name: EventName::ImePreedit,
+            text: "日本".to_string(),
+            cursor: Some((6, 6)),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(paragraph.get(0).get(0).text(), Some("****"));
+        assert_eq!(paragraph.get(1).get(0).text(), Some("**"));
+        assert_eq!(paragraph.state().cursor.position, Some(6));
+
+        utils.push_event(TestEvent::Ime {
+            name: EventName::ImePreedit,
+            text: String::new(),
+            cursor: None,
+        });
+        utils.wait_for_update().await;
+
+        // Reveal the text
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (137., 15.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(paragraph.get(0).get(0).text(), Some("pass"));
+        assert_ne!(utils.focus_id(), ACCESSIBILITY_ROOT_ID);
+    }
+
+    #[tokio::test]
+    pub async fn undo_redo() {
+        fn input_app() -> Element {
+            let mut value = use_signal(|| "A".to_string());
+
+            rsx!(Input {
+                value: value.read().clone(),
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            },)
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let text = root.get(0).get(0).get(0).get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Focus the input in the end of the text
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (115., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Write two words
+        for c in ['B', 'C', ' ', 'D'] {
+            utils.push_event(TestEvent::Keyboard {
+                name: EventName::KeyDown,
+                key: Key::Character(c.to_string()),
+                code: Code::Unidentified,
+                modifiers: Modifiers::default(),
+            });
+            utils.wait_for_update().await;
+        }
+        assert_eq!(text.get(0).text(), Some("ABC D"));
+
+        let meta_or_ctrl = if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        };
+
+        // Every word is undone at once
+        for expected in ["ABC", "A"] {
+            utils.push_event(TestEvent::Keyboard {
+                name: EventName::KeyDown,
+                key: Key::Character("z".to_string()),
+                code: Code::KeyZ,
+                modifiers: meta_or_ctrl,
+            });
+            utils.wait_for_update().await;
+            assert_eq!(text.get(0).text(), Some(expected));
+        }
+
+        // Redo
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("Z".to_string()),
+            code: Code::KeyZ,
+            modifiers: meta_or_ctrl | Modifiers::SHIFT,
+        });
+        utils.wait_for_update().await;
+        assert_eq!(text.get(0).text(), Some("ABC"));
+    }
+
+    #[tokio::test]
+    pub async fn shared_history() {
+        fn input_app() -> Element {
+            let mut history = use_init_shared_history(HistoryConfig::new());
+            let mut value = use_signal(|| "A".to_string());
+            let mut counter = use_signal(|| 0);
+
+            rsx!(
+                Input {
+                    value: value.read().clone(),
+                    onchange: move |new_value| {
+                        value.set(new_value);
+                    }
+                },
+                label {
+                    onglobalkeydown: move |e: KeyboardEvent| {
+                        if e.key == Key::F1 {
+                            history.execute(Box::new(Command::new(
+                                move || counter -= 1,
+                                move || counter += 1,
+                            )));
+                        } else {
+                            history.handle_keydown(&e);
+                        }
+                    },
+                    "{counter}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let text = root.get(0).get(0).get(0).get(0).get(0).get(0);
+        let counter = root.get(1);
+        utils.wait_for_update().await;
+
+        // Focus the input in the end of the text
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (115., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Changes of the Input and the app are mixed in the same history
+        for key in ["B", "F1", "C"] {
+            let key = if key == "F1" {
+                Key::F1
+            } else {
+                Key::Character(key.to_string())
+            };
+            utils.press(key).await;
+        }
+        assert_eq!(text.get(0).text(), Some("ABC"));
+        assert_eq!(counter.get(0).text(), Some("1"));
+
+        let meta_or_ctrl = if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        };
+
+        // Undone in the same order they were made
+        for (expected_text, expected_counter) in [("AB", "1"), ("AB", "0"), ("A", "0")] {
+            utils
+                .press_with_modifiers(Key::Character("z".to_string()), meta_or_ctrl)
+                .await;
+            assert_eq!(text.get(0).text(), Some(expected_text));
+            assert_eq!(counter.get(0).text(), Some(expected_counter));
+        }
+
+        utils
+            .press_with_modifiers(Key::Character("y".to_string()), meta_or_ctrl)
+            .await;
+        assert_eq!(text.get(0).text(), Some("AB"));
+        assert_eq!(counter.get(0).text(), Some("0"));
+    }
+
+    #[tokio::test]
+    pub async fn shared_history_unmounted() {
+        fn input_app() -> Element {
+            let mut history = use_init_shared_history(HistoryConfig::new());
+            let mut value = use_signal(|| "A".to_string());
+            let mut counter = use_signal(|| 0);
+            let mut show_input = use_signal(|| true);
+
+            rsx!(
+                label {
+                    onglobalkeydown: move |e: KeyboardEvent| {
+                        if e.key == Key::F1 {
+                            history.execute(Box::new(Command::new(
+                                move || counter -= 1,
+                                move || counter += 1,
+                            )));
+                        } else if e.key == Key::F2 {
+                            show_input.set(false);
+                        } else {
+                            history.handle_keydown(&e);
+                        }
+                    },
+                    "{counter} {history.undo_len()}"
+                }
+                if show_input() {
+                    Input {
+                        value: value.read().clone(),
+                        onchange: move |new_value| {
+                            value.set(new_value);
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let label = root.get(0);
+        utils.wait_for_update().await;
+
+        // Focus the input in the end of the text
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (115., 45.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        utils.press(Key::F1).await;
+        utils.press(Key::Character("B".to_string())).await;
+        assert_eq!(label.get(0).text(), Some("1 2"));
+
+        // The changes of the Input are forgotten once it's unmounted
+        utils.press(Key::F2).await;
+        assert_eq!(label.get(0).text(), Some("1 1"));
+
+        let meta_or_ctrl = if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        };
+
+        // So undoing doesn't touch the unmounted Input
+        utils
+            .press_with_modifiers(Key::Character("z".to_string()), meta_or_ctrl)
+            .await;
+        assert_eq!(label.get(0).text(), Some("0 0"));
+        utils
+            .press_with_modifiers(Key::Character("z".to_string()), meta_or_ctrl)
+            .await;
+        assert_eq!(label.get(0).text(), Some("0 0"));
+    }
 }
@@ -40,14 +40,22 @@ pub fn gallery() {}
 
 mod accordion;
 mod activable_route;
+mod animated_outlet;
 mod animated_position;
+mod animated_presence;
+mod animated_rect;
 mod animated_router;
 mod body;
 mod button;
+mod charts;
 mod checkbox;
+mod chip;
+mod choice_group;
 mod cursor_area;
 mod drag_drop;
+mod drawing_canvas;
 mod dropdown;
+mod emoji_picker;
 mod gesture_area;
 mod global_animated_position;
 mod graph;
@@ -57,6 +65,7 @@ mod image;
 mod input;
 mod link;
 mod loader;
+mod markdown;
 mod menu;
 mod native_container;
 mod native_router;
@@ -66,8 +75,12 @@ mod popup;
 mod progress_bar;
 mod radio;
 mod resizable_container;
+mod rich_text_editor;
 mod scroll_views;
 mod selectable_text;
+mod shader_view;
+mod shortcut_provider;
+mod show;
 mod sidebar;
 mod slider;
 mod snackbar;
@@ -75,22 +88,37 @@ mod svg;
 mod switch;
 mod table;
 mod tabs;
+#[cfg(feature = "terminal")]
+mod terminal;
 mod theme;
 mod tile;
 mod tooltip;
 mod tree;
+#[cfg(feature = "webview")]
+mod webview;
+mod window_caption_buttons;
 mod window_drag_area;
+mod window_resize_area;
+mod zoomable_view;
 
 pub use accordion::*;
 pub use activable_route::*;
+pub use animated_outlet::*;
 pub use animated_position::*;
+pub use animated_presence::*;
+pub use animated_rect::*;
 pub use animated_router::*;
 pub use body::*;
 pub use button::*;
+pub use charts::*;
 pub use checkbox::*;
+pub use chip::*;
+pub use choice_group::*;
 pub use cursor_area::*;
 pub use drag_drop::*;
+pub use drawing_canvas::*;
 pub use dropdown::*;
+pub use emoji_picker::*;
 pub use gesture_area::*;
 pub use global_animated_position::*;
 pub use graph::*;
@@ -99,6 +127,7 @@ pub use icons::*;
 pub use input::*;
 pub use link::*;
 pub use loader::*;
+pub use markdown::*;
 pub use menu::*;
 pub use native_container::*;
 pub use native_router::*;
@@ -108,16 +137,27 @@ pub use popup::*;
 pub use progress_bar::*;
 pub use radio::*;
 pub use resizable_container::*;
+pub use rich_text_editor::*;
 pub use scroll_views::*;
 pub use selectable_text::*;
+pub use shader_view::*;
+pub use shortcut_provider::*;
+pub use show::*;
 pub use sidebar::*;
 pub use slider::*;
 pub use snackbar::*;
 pub use switch::*;
 pub use table::*;
 pub use tabs::*;
+#[cfg(feature = "terminal")]
+pub use terminal::*;
 pub use theme::*;
 pub use tile::*;
 pub use tooltip::*;
 pub use tree::*;
+#[cfg(feature = "webview")]
+pub use webview::*;
+pub use window_caption_buttons::*;
 pub use window_drag_area::*;
+pub use window_resize_area::*;
+pub use zoomable_view::*;
@@ -5,14 +5,23 @@ use dioxus_router::prelude::{
     navigator,
     NavigationTarget,
 };
-use freya_core::platform::MouseButton;
+use freya_core::{
+    opener::OpenTarget,
+    platform::MouseButton,
+};
 use freya_elements::{
     self as dioxus_elements,
-    events::MouseEvent,
+    events::{
+        KeyboardEvent,
+        MouseEvent,
+    },
 };
 use freya_hooks::{
     use_applied_theme,
+    use_focus,
+    use_platform,
     LinkThemeWith,
+    UsePlatform,
 };
 
 use crate::{
@@ -34,10 +43,60 @@ pub enum LinkTooltip {
     Custom(String),
 }
 
+/// Open a link, external URLs are opened with the platform in the background, see [`UsePlatform::open_url`],
+/// and internal routes are pushed to the [`Router`](dioxus_router::components::Router),
+/// so it must be called from a descendant of it.
+///
+/// Returns whether the link could be opened. Failing to open a valid external URL is only logged.
+///
+/// This is useful to handle the `onlinkclick` event of a `paragraph`.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         paragraph {
+///             onlinkclick: |e: LinkClickEvent| {
+///                 open_link(e.link.as_str());
+///             },
+///             text {
+///                 link: "https://crates.io/crates/freya",
+///                 "Freya crates.io"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn open_link(to: impl Into<NavigationTarget>) -> bool {
+    match to.into() {
+        NavigationTarget::External(url) => {
+            if OpenTarget::Url(url.clone()).validate().is_err() {
+                return false;
+            }
+            let platform = UsePlatform::current();
+            spawn(async move {
+                platform.open_url(url).await.ok();
+            });
+            true
+        }
+        to => {
+            navigator().push(to);
+            true
+        }
+    }
+}
+
 /// Similar to [`Link`](dioxus_router::components::Link()), but you can use it in Freya.
 /// Both internal routes (dioxus-router) and external links are supported. When using internal routes
 /// make sure the Link is descendant of a [`Router`](dioxus_router::components::Router) component.
 ///
+/// The link is underlined when hovered, has the `link` accessibility role and can also be opened
+/// with the keyboard once focused.
+///
+/// For links inside a text use the `link` attribute of the `text` spans and [`open_link`] instead.
+///
 /// # Styling
 ///
 /// Inherits the [`LinkTheme`](freya_hooks::LinkTheme) theme.
@@ -111,6 +170,10 @@ pub fn Link(
 ) -> Element {
     let theme = use_applied_theme!(&theme, link);
     let mut is_hovering = use_signal(|| false);
+    let mut focus = use_focus();
+    let platform = use_platform();
+
+    let a11y_id = focus.attribute();
 
     let url = if let NavigationTarget::External(ref url) = to {
         Some(url.clone())
@@ -126,27 +189,39 @@ pub fn Link(
         is_hovering.set(false);
     };
 
+    let open = {
+        to_owned![to];
+        move || match to.clone() {
+            NavigationTarget::External(url) => {
+                spawn(async move {
+                    if platform.open_url(url).await.is_err() {
+                        if let Some(onerror) = onerror.as_ref() {
+                            onerror.call(());
+                        }
+                    }
+                });
+            }
+            to => {
+                open_link(to);
+            }
+        }
+    };
+
     let onclick = {
-        to_owned![url, to];
+        to_owned![open];
         move |event: MouseEvent| {
             if !matches!(event.trigger_button, Some(MouseButton::Left)) {
                 return;
             }
 
-            // Open the url if there is any
-            // otherwise change the dioxus router route
-            if let Some(url) = &url {
-                let res = open::that(url);
-
-                if let (Err(_), Some(onerror)) = (res, onerror.as_ref()) {
-                    onerror.call(());
-                }
+            focus.request_focus();
+            open();
+        }
+    };
 
-                // TODO(marc2332): Log unhandled errors
-            } else {
-                let router = navigator();
-                router.push(to.clone());
-            }
+    let onkeydown = move |event: KeyboardEvent| {
+        if focus.validate_keydown(&event) {
+            open();
         }
     };
 
@@ -167,7 +242,11 @@ pub fn Link(
             onmouseenter,
             onmouseleave,
             onclick,
+            onkeydown,
+            a11y_id,
+            a11y_role: "link",
             color: "{color}",
+            decoration: if *is_hovering.read() { "underline" },
             {children}
         }
     };
@@ -283,4 +362,30 @@ mod test {
         // Check route is Home
         assert_eq!(utils.root().get(2).get(0).text(), Some("Home"));
     }
+
+    #[tokio::test]
+    pub async fn external_link() {
+        fn link_app() -> Element {
+            rsx!(
+                Link {
+                    to: "https://crates.io/crates/freya",
+                    tooltip: LinkTooltip::None,
+                    label { "Freya crates.io" }
+                }
+            )
+        }
+
+        let mut utils = launch_test(link_app);
+        utils.wait_for_update().await;
+
+        utils.click_cursor((5., 5.)).await;
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            utils.opened(),
+            &[OpenTarget::Url(
+                "https://crates.io/crates/freya".to_string()
+            )]
+        );
+    }
 }
@@ -0,0 +1,827 @@
+use dioxus::prelude::*;
+use freya_core::custom_attributes::dynamic_bytes;
+use freya_elements::{
+    self as dioxus_elements,
+    events::LinkClickEvent,
+};
+use freya_hooks::{
+    use_applied_theme,
+    MarkdownTheme,
+    MarkdownThemeWith,
+    RichText,
+};
+use pulldown_cmark::{
+    Alignment,
+    CodeBlockKind,
+    Event,
+    Options,
+    Parser,
+    Tag,
+    TagEnd,
+};
+use reqwest::Url;
+
+use crate::{
+    open_link,
+    Checkbox,
+    NetworkImage,
+    Table,
+    TableBody,
+    TableCell,
+    TableHead,
+    TableRow,
+};
+
+/// Fenced or indented code block of a [`Markdown`] document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkdownCode {
+    /// Language of the block, e.g `rust` for a block fenced with ` ```rust `. Empty if unknown.
+    pub language: String,
+    /// Code of the block, without its last line break.
+    pub code: String,
+}
+
+/// Run of text with the same style.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct MarkdownSpan {
+    text: String,
+    bold: bool,
+    italic: bool,
+    strikethrough: bool,
+    code: bool,
+    link: Option<String>,
+}
+
+impl MarkdownSpan {
+    fn has_style_of(&self, other: &Self) -> bool {
+        self.bold == other.bold
+            && self.italic == other.italic
+            && self.strikethrough == other.strikethrough
+            && self.code == other.code
+            && self.link == other.link
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct MarkdownListItem {
+    /// Whether the task of the item is done, `None` if the item is not a task.
+    checked: Option<bool>,
+    blocks: Vec<MarkdownBlock>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum MarkdownBlock {
+    Paragraph(Vec<MarkdownSpan>),
+    Heading {
+        level: usize,
+        spans: Vec<MarkdownSpan>,
+    },
+    Code(MarkdownCode),
+    Quote(Vec<MarkdownBlock>),
+    List {
+        /// Number of the first item of ordered lists.
+        start: Option<u64>,
+        items: Vec<MarkdownListItem>,
+    },
+    Table {
+        alignments: Vec<Alignment>,
+        head: Vec<Vec<MarkdownSpan>>,
+        rows: Vec<Vec<Vec<MarkdownSpan>>>,
+    },
+    Image {
+        url: String,
+        alt: String,
+    },
+    Rule,
+}
+
+/// Block being parsed, with the blocks or items parsed inside it so far.
+enum MarkdownContainer {
+    Blocks(Vec<MarkdownBlock>),
+    List {
+        start: Option<u64>,
+        items: Vec<MarkdownListItem>,
+    },
+    Item(MarkdownListItem),
+    Table {
+        alignments: Vec<Alignment>,
+        head: Vec<Vec<MarkdownSpan>>,
+        rows: Vec<Vec<Vec<MarkdownSpan>>>,
+        row: Vec<Vec<MarkdownSpan>>,
+    },
+}
+
+/// Turns the events of [`pulldown_cmark`] into a tree of blocks.
+struct MarkdownParser {
+    containers: Vec<MarkdownContainer>,
+    /// Text of the current paragraph, heading or table cell.
+    spans: Vec<MarkdownSpan>,
+    /// Style of the text, nested styles are counted so they can be closed in any order.
+    bold: usize,
+    italic: usize,
+    strikethrough: usize,
+    links: Vec<String>,
+    code: Option<MarkdownCode>,
+    /// URL and alternative text of the current image.
+    image: Option<(String, String)>,
+}
+
+impl MarkdownParser {
+    fn new() -> Self {
+        Self {
+            containers: vec![MarkdownContainer::Blocks(Vec::new())],
+            spans: Vec::new(),
+            bold: 0,
+            italic: 0,
+            strikethrough: 0,
+            links: Vec::new(),
+            code: None,
+            image: None,
+        }
+    }
+
+    fn push_block(&mut self, block: MarkdownBlock) {
+        match self.containers.last_mut() {
+            Some(MarkdownContainer::Blocks(blocks)) => blocks.push(block),
+            Some(MarkdownContainer::Item(item)) => item.blocks.push(block),
+            _ => {}
+        }
+    }
+
+    /// Turn the pending text into a paragraph, e.g the text of the items of tight lists.
+    fn flush_spans(&mut self) {
+        if !self.spans.is_empty() {
+            let spans = std::mem::take(&mut self.spans);
+            self.push_block(MarkdownBlock::Paragraph(spans));
+        }
+    }
+
+    fn push_text(&mut self, text: &str, code: bool) {
+        if let Some(block) = &mut self.code {
+            block.code.push_str(text);
+            return;
+        }
+        if let Some((_, alt)) = &mut self.image {
+            alt.push_str(text);
+            return;
+        }
+
+        let span = MarkdownSpan {
+            text: text.to_string(),
+            bold: self.bold > 0,
+            italic: self.italic > 0,
+            strikethrough: self.strikethrough > 0,
+            code,
+            link: self.links.last().cloned(),
+        };
+        // Join the text with the same style, e.g split by line breaks
+        match self.spans.last_mut() {
+            Some(last) if last.has_style_of(&span) => last.text.push_str(&span.text),
+            _ => self.spans.push(span),
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph | Tag::Heading { .. } => self.flush_spans(),
+            Tag::CodeBlock(kind) => {
+                self.flush_spans();
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split(|ch: char| ch == ',' || ch.is_whitespace())
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                self.code = Some(MarkdownCode {
+                    language,
+                    code: String::new(),
+                });
+            }
+            Tag::BlockQuote(_) => {
+                self.flush_spans();
+                self.containers.push(MarkdownContainer::Blocks(Vec::new()));
+            }
+            Tag::List(start) => {
+                self.flush_spans();
+                self.containers.push(MarkdownContainer::List {
+                    start,
+                    items: Vec::new(),
+                });
+            }
+            Tag::Item => {
+                self.containers
+                    .push(MarkdownContainer::Item(MarkdownListItem {
+                        checked: None,
+                        blocks: Vec::new(),
+                    }));
+            }
+            Tag::Table(alignments) => {
+                self.flush_spans();
+                self.containers.push(MarkdownContainer::Table {
+                    alignments,
+                    head: Vec::new(),
+                    rows: Vec::new(),
+                    row: Vec::new(),
+                });
+            }
+            Tag::Emphasis => self.italic += 1,
+            Tag::Strong => self.bold += 1,
+            Tag::Strikethrough => self.strikethrough += 1,
+            Tag::Link { dest_url, .. } => self.links.push(dest_url.to_string()),
+            Tag::Image { dest_url, .. } => {
+                // Images are shown as blocks, splitting the paragraph they are in
+                self.flush_spans();
+                self.image = Some((dest_url.to_string(), String::new()));
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.flush_spans(),
+            TagEnd::Heading(level) => {
+                let spans = std::mem::take(&mut self.spans);
+                self.push_block(MarkdownBlock::Heading {
+                    level: level as usize,
+                    spans,
+                });
+            }
+            TagEnd::CodeBlock => {
+                if let Some(mut code) = self.code.take() {
+                    code.code.truncate(code.code.trim_end_matches('\n').len());
+                    self.push_block(MarkdownBlock::Code(code));
+                }
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush_spans();
+                if let Some(MarkdownContainer::Blocks(blocks)) = self.containers.pop() {
+                    self.push_block(MarkdownBlock::Quote(blocks));
+                }
+            }
+            TagEnd::List(_) => {
+                if let Some(MarkdownContainer::List { start, items }) = self.containers.pop() {
+                    self.push_block(MarkdownBlock::List { start, items });
+                }
+            }
+            TagEnd::Item => {
+                self.flush_spans();
+                if let Some(MarkdownContainer::Item(item)) = self.containers.pop() {
+                    if let Some(MarkdownContainer::List { items, .. }) = self.containers.last_mut()
+                    {
+                        items.push(item);
+                    }
+                }
+            }
+            TagEnd::TableCell => {
+                let spans = std::mem::take(&mut self.spans);
+                if let Some(MarkdownContainer::Table { row, .. }) = self.containers.last_mut() {
+                    row.push(spans);
+                }
+            }
+            TagEnd::TableHead => {
+                if let Some(MarkdownContainer::Table { head, row, .. }) = self.containers.last_mut()
+                {
+                    *head = std::mem::take(row);
+                }
+            }
+            TagEnd::TableRow => {
+                if let Some(MarkdownContainer::Table { rows, row, .. }) = self.containers.last_mut()
+                {
+                    rows.push(std::mem::take(row));
+                }
+            }
+            TagEnd::Table => {
+                if let Some(MarkdownContainer::Table {
+                    alignments,
+                    head,
+                    rows,
+                    ..
+                }) = self.containers.pop()
+                {
+                    self.push_block(MarkdownBlock::Table {
+                        alignments,
+                        head,
+                        rows,
+                    });
+                }
+            }
+            TagEnd::Emphasis => self.italic = self.italic.saturating_sub(1),
+            TagEnd::Strong => self.bold = self.bold.saturating_sub(1),
+            TagEnd::Strikethrough => self.strikethrough = self.strikethrough.saturating_sub(1),
+            TagEnd::Link => {
+                self.links.pop();
+            }
+            TagEnd::Image => {
+                if let Some((url, alt)) = self.image.take() {
+                    self.push_block(MarkdownBlock::Image { url, alt });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn parse(mut self, markdown: &str) -> Vec<MarkdownBlock> {
+        let options =
+            Options::ENABLE_TABLES | Options::ENABLE_TASKLISTS | Options::ENABLE_STRIKETHROUGH;
+        for event in Parser::new_ext(markdown, options) {
+            match event {
+                Event::Start(tag) => self.start(tag),
+                Event::End(tag) => self.end(tag),
+                Event::Text(text) | Event::InlineHtml(text) => self.push_text(&text, false),
+                Event::Code(text) => self.push_text(&text, true),
+                Event::SoftBreak => self.push_text(" ", false),
+                Event::HardBreak => self.push_text("\n", false),
+                Event::Rule => {
+                    self.flush_spans();
+                    self.push_block(MarkdownBlock::Rule);
+                }
+                Event::TaskListMarker(checked) => {
+                    if let Some(MarkdownContainer::Item(item)) = self.containers.last_mut() {
+                        item.checked = Some(checked);
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.flush_spans();
+
+        match self.containers.into_iter().next() {
+            Some(MarkdownContainer::Blocks(blocks)) => blocks,
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Parse a CommonMark document, with tables, task lists and strikethrough.
+fn parse_markdown(markdown: &str) -> Vec<MarkdownBlock> {
+    MarkdownParser::new().parse(markdown)
+}
+
+/// Font size of a heading relative to the base font size.
+fn heading_scale(level: usize) -> f32 {
+    match level {
+        1 => 2.,
+        2 => 1.6,
+        3 => 1.3,
+        4 => 1.15,
+        5 => 1.,
+        _ => 0.9,
+    }
+}
+
+/// Everything needed to render the blocks of a [`Markdown`] document.
+#[derive(Clone)]
+struct MarkdownRenderer {
+    theme: MarkdownTheme,
+    font_size: f32,
+    onlink: Option<EventHandler<String>>,
+    highlight_code: Option<Callback<MarkdownCode, Option<Vec<RichText>>>>,
+}
+
+impl MarkdownRenderer {
+    fn blocks(&self, blocks: &[MarkdownBlock]) -> Element {
+        rsx!({ blocks.iter().map(|block| self.block(block)) })
+    }
+
+    fn block(&self, block: &MarkdownBlock) -> Element {
+        match block {
+            MarkdownBlock::Paragraph(spans) => self.spans(spans, self.font_size, false),
+            MarkdownBlock::Heading { level, spans } => {
+                self.spans(spans, self.font_size * heading_scale(*level), true)
+            }
+            MarkdownBlock::Code(code) => self.code(code),
+            MarkdownBlock::Quote(blocks) => {
+                let MarkdownTheme {
+                    quote_border_fill,
+                    quote_color,
+                    ..
+                } = &self.theme;
+                rsx!(
+                    rect {
+                        width: "100%",
+                        padding: "4 0 4 12",
+                        spacing: "12",
+                        color: "{quote_color}",
+                        border: "0 0 0 3 inner {quote_border_fill}",
+                        {self.blocks(blocks)}
+                    }
+                )
+            }
+            MarkdownBlock::List { start, items } => rsx!(
+                rect {
+                    width: "100%",
+                    spacing: "6",
+                    for (i, item) in items.iter().enumerate() {
+                        rect {
+                            key: "{i}",
+                            width: "100%",
+                            direction: "horizontal",
+                            spacing: "8",
+                            if let Some(checked) = item.checked {
+                                Checkbox {
+                                    selected: checked
+                                }
+                            } else if let Some(start) = start {
+                                label {
+                                    font_size: "{self.font_size}",
+                                    "{start + i as u64}."
+                                }
+                            } else {
+                                label {
+                                    font_size: "{self.font_size}",
+                                    "•"
+                                }
+                            }
+                            rect {
+                                width: "fill",
+                                spacing: "6",
+                                {self.blocks(&item.blocks)}
+                            }
+                        }
+                    }
+                }
+            ),
+            MarkdownBlock::Table {
+                alignments,
+                head,
+                rows,
+            } => {
+                let text_align = |column: usize| match alignments.get(column) {
+                    Some(Alignment::Center) => "center",
+                    Some(Alignment::Right) => "right",
+                    _ => "left",
+                };
+                rsx!(
+                    Table {
+                        height: "auto",
+                        columns: head.len().max(1),
+                        TableHead {
+                            TableRow {
+                                for (column, cell) in head.iter().enumerate() {
+                                    TableCell {
+                                        key: "{column}",
+                                        height: "auto",
+                                        padding: "8 12",
+                                        rect {
+                                            width: "fill",
+                                            text_align: text_align(column),
+                                            {self.spans(cell, self.font_size, true)}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        TableBody {
+                            for (row_index, row) in rows.iter().enumerate() {
+                                TableRow {
+                                    key: "{row_index}",
+                                    for (column, cell) in row.iter().enumerate() {
+                                        TableCell {
+                                            key: "{column}",
+                                            height: "auto",
+                                            padding: "8 12",
+                                            rect {
+                                                width: "fill",
+                                                text_align: text_align(column),
+                                                {self.spans(cell, self.font_size, false)}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                )
+            }
+            MarkdownBlock::Image { url, alt } => rsx!(MarkdownImage {
+                url: url.clone(),
+                alt: alt.clone()
+            }),
+            MarkdownBlock::Rule => {
+                let MarkdownTheme { divider_fill, .. } = &self.theme;
+                rsx!(rect {
+                    width: "100%",
+                    height: "1",
+                    background: "{divider_fill}"
+                })
+            }
+        }
+    }
+
+    fn spans(&self, spans: &[MarkdownSpan], font_size: f32, bold: bool) -> Element {
+        let MarkdownTheme {
+            link_color,
+            code_background,
+            ..
+        } = &self.theme;
+        let onlink = self.onlink;
+        let onlinkclick = move |e: LinkClickEvent| {
+            if let Some(onlink) = onlink {
+                onlink.call(e.link.clone());
+            } else {
+                open_link(e.link.as_str());
+            }
+        };
+
+        rsx!(
+            paragraph {
+                width: "100%",
+                font_size: "{font_size}",
+                onlinkclick,
+                for span in spans {
+                    text {
+                        font_weight: if bold || span.bold { "bold" } else { "normal" },
+                        font_style: if span.italic { "italic" } else { "normal" },
+                        font_family: span.code.then_some("monospace"),
+                        background: span.code.then(|| code_background.to_string()),
+                        color: span.link.as_ref().map(|_| link_color.to_string()),
+                        decoration: if span.link.is_some() {
+                            Some("underline")
+                        } else if span.strikethrough {
+                            Some("line-through")
+                        } else {
+                            None
+                        },
+                        link: span.link.clone(),
+                        "{span.text}"
+                    }
+                }
+            }
+        )
+    }
+
+    fn code(&self, code: &MarkdownCode) -> Element {
+        let MarkdownTheme {
+            code_background, ..
+        } = &self.theme;
+        let lines = self
+            .highlight_code
+            .and_then(|highlight_code| highlight_code.call(code.clone()))
+            .unwrap_or_else(|| code.code.lines().map(RichText::new).collect());
+
+        rsx!(
+            rect {
+                width: "100%",
+                padding: "12",
+                corner_radius: "6",
+                background: "{code_background}",
+                font_family: "monospace",
+                font_size: "{self.font_size * 0.9}",
+                for (line_idx, line) in lines.iter().enumerate() {
+                    paragraph {
+                        key: "{line_idx}",
+                        width: "100%",
+                        for (segment, style) in line.segments() {
+                            text {
+                                color: style.color.clone(),
+                                font_weight: style.font_weight(),
+                                font_style: style.font_style(),
+                                "{segment}"
+                            }
+                        }
+                    }
+                }
+            }
+        )
+    }
+}
+
+/// Image of a [`Markdown`] document, remote images are fetched with [`NetworkImage`] and
+/// the rest are read from the disk.
+#[allow(non_snake_case)]
+#[component]
+fn MarkdownImage(url: String, alt: String) -> Element {
+    let fallback = rsx!(label { "{alt}" });
+    match url.parse::<Url>() {
+        Ok(remote_url) if matches!(remote_url.scheme(), "http" | "https") => rsx!(NetworkImage {
+            url: remote_url,
+            alt,
+            fallback
+        }),
+        _ => rsx!(LocalImage {
+            path: url,
+            alt,
+            fallback
+        }),
+    }
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn LocalImage(path: String, alt: String, fallback: Element) -> Element {
+    let image_bytes = use_resource(use_reactive!(|path| async move {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            tokio::fs::read(path).await.ok()
+        }
+        // There is no file system in the browser
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = path;
+            None::<Vec<u8>>
+        }
+    }));
+
+    match &*image_bytes.read_unchecked() {
+        Some(Some(bytes)) => {
+            let image_data = dynamic_bytes(bytes.clone());
+            rsx!(image {
+                image_data,
+                a11y_role: "image",
+                a11y_name: alt,
+                cache_key: "{path}",
+            })
+        }
+        Some(None) => fallback,
+        None => rsx!(rect {}),
+    }
+}
+
+/// Properties for the [`Markdown`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct MarkdownProps {
+    /// Theme override.
+    pub theme: Option<MarkdownThemeWith>,
+    /// CommonMark document to render, tables, task lists and strikethrough are supported too.
+    #[props(into)]
+    pub content: String,
+    /// Width of the document. Default to `100%`.
+    #[props(default = "100%".into())]
+    pub width: String,
+    /// Handler for clicks on links, with their URL.
+    /// Links are opened with [`open_link`] otherwise.
+    pub onlink: Option<EventHandler<String>>,
+    /// Highlight the code blocks, returning every line of the code as a [`RichText`](freya_hooks::RichText).
+    /// Code blocks are shown with no highlighting if `None` is returned.
+    ///
+    /// With the `use_syntax_highlighting` feature use
+    /// [`SyntaxLanguage::highlight`](freya_hooks::SyntaxLanguage::highlight) to highlight them.
+    pub highlight_code: Option<Callback<MarkdownCode, Option<Vec<RichText>>>>,
+}
+
+/// Render a [CommonMark](https://commonmark.org/) document, along with tables and task lists.
+///
+/// Code blocks are shown with a monospace font and can be highlighted with the `highlight_code` prop,
+/// images are loaded from the web or the disk and clicking links opens them.
+///
+/// # Styling
+/// Inherits the [`MarkdownTheme`](freya_hooks::MarkdownTheme) theme.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(Markdown {
+///         content: "# Freya\n\nA **native** GUI library for [Rust](https://www.rust-lang.org/).\n\n- [x] Tables\n- [ ] Math",
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn Markdown(
+    MarkdownProps {
+        theme,
+        content,
+        width,
+        onlink,
+        highlight_code,
+    }: MarkdownProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, markdown);
+    let blocks = use_memo(use_reactive(&content, |content| parse_markdown(&content)));
+
+    let renderer = MarkdownRenderer {
+        font_size: theme.font_size.parse().unwrap_or(16.),
+        theme,
+        onlink,
+        highlight_code,
+    };
+
+    rsx!(
+        rect {
+            width: "{width}",
+            spacing: "12",
+            color: "{renderer.theme.color}",
+            {renderer.blocks(&blocks.read())}
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_engine::prelude::Color;
+    use freya_testing::prelude::*;
+
+    use super::*;
+
+    fn text(text: &str) -> MarkdownSpan {
+        MarkdownSpan {
+            text: text.to_string(),
+            ..MarkdownSpan::default()
+        }
+    }
+
+    #[test]
+    pub fn parse() {
+        let blocks = parse_markdown(
+            "# Title\n\nSome **bold** text\nand [a link](https://freyaui.dev).\n\n- [x] Done\n- [ ] Todo\n\n> Quote\n\n```rust,ignore\nfn main() {}\n```\n\n| A | B |\n|:-:|--:|\n| 1 | 2 |\n\n---",
+        );
+
+        assert_eq!(
+            blocks,
+            vec![
+                MarkdownBlock::Heading {
+                    level: 1,
+                    spans: vec![text("Title")]
+                },
+                MarkdownBlock::Paragraph(vec![
+                    text("Some "),
+                    MarkdownSpan {
+                        bold: true,
+                        ..text("bold")
+                    },
+                    text(" text and "),
+                    MarkdownSpan {
+                        link: Some("https://freyaui.dev".to_string()),
+                        ..text("a link")
+                    },
+                    text("."),
+                ]),
+                MarkdownBlock::List {
+                    start: None,
+                    items: vec![
+                        MarkdownListItem {
+                            checked: Some(true),
+                            blocks: vec![MarkdownBlock::Paragraph(vec![text("Done")])]
+                        },
+                        MarkdownListItem {
+                            checked: Some(false),
+                            blocks: vec![MarkdownBlock::Paragraph(vec![text("Todo")])]
+                        },
+                    ]
+                },
+                MarkdownBlock::Quote(vec![MarkdownBlock::Paragraph(vec![text("Quote")])]),
+                MarkdownBlock::Code(MarkdownCode {
+                    language: "rust".to_string(),
+                    code: "fn main() {}".to_string()
+                }),
+                MarkdownBlock::Table {
+                    alignments: vec![Alignment::Center, Alignment::Right],
+                    head: vec![vec![text("A")], vec![text("B")]],
+                    rows: vec![vec![vec![text("1")], vec![text("2")]]],
+                },
+                MarkdownBlock::Rule,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    pub async fn markdown() {
+        fn markdown_app() -> Element {
+            rsx!(Markdown {
+                content: "# Title\n\n1. First\n2. Second\n\n```\nlet a = 1;\nlet b = 2;\n```",
+                highlight_code: |code: MarkdownCode| {
+                    (code.language.is_empty()).then(|| {
+                        code.code
+                            .lines()
+                            .map(|line| {
+                                RichText::default().with_span(
+                                    line,
+                                    SpanStyle {
+                                        color: Some("red".to_string()),
+                                        ..SpanStyle::default()
+                                    },
+                                )
+                            })
+                            .collect()
+                    })
+                }
+            })
+        }
+
+        let mut utils = launch_test(markdown_app);
+        utils.wait_for_update().await;
+        let root = utils.root().get(0);
+
+        let heading = root.get(0);
+        assert_eq!(heading.get(0).get(0).text(), Some("Title"));
+        assert_eq!(heading.state().font_style.font_size, 32.);
+
+        let list = root.get(1);
+        assert_eq!(list.get(0).get(0).get(0).text(), Some("1."));
+        assert_eq!(list.get(1).get(0).get(0).text(), Some("2."));
+        assert_eq!(
+            list.get(1).get(1).get(0).get(0).get(0).text(),
+            Some("Second")
+        );
+
+        let code = root.get(2);
+        assert_eq!(code.children_ids().len(), 2);
+        assert_eq!(code.get(1).get(0).get(0).text(), Some("let b = 2;"));
+        assert_eq!(code.get(1).get(0).state().font_style.color, Color::RED);
+    }
+}
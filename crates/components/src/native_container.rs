@@ -11,6 +11,7 @@ use freya_elements::{
 use freya_hooks::{
     use_init_native_platform,
     use_platform,
+    use_text_selection,
 };
 
 #[allow(non_snake_case)]
@@ -18,8 +19,19 @@ use freya_hooks::{
 pub fn NativeContainer(children: Element) -> Element {
     let mut native_platform = use_init_native_platform();
     let platform = use_platform();
+    let mut text_selection = use_text_selection();
 
     let onglobalkeydown = move |e: KeyboardEvent| {
+        let meta_or_ctrl = if cfg!(target_os = "macos") {
+            e.modifiers.meta()
+        } else {
+            e.modifiers.ctrl()
+        };
+        let is_c = matches!(&e.key, Key::Character(c) if c.eq_ignore_ascii_case("c"));
+        if is_c && meta_or_ctrl {
+            text_selection.copy();
+        }
+
         let allowed_to_navigate = native_platform.navigation_mark.peek().allowed();
         if e.key == Key::Tab && allowed_to_navigate {
             if e.modifiers.contains(Modifiers::SHIFT) {
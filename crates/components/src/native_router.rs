@@ -1,22 +1,43 @@
+use std::collections::HashMap;
+
 use dioxus::prelude::*;
-use dioxus_router::prelude::use_navigator;
+use dioxus_router::prelude::{
+    use_navigator,
+    use_route,
+    Routable,
+};
 use freya_elements::{
     self as dioxus_elements,
     events::{
+        Key,
+        KeyboardEvent,
+        Modifiers,
         MouseButton,
         PointerEvent,
         PointerType,
     },
 };
 
+use crate::{
+    ScrollConfig,
+    ScrollController,
+};
+
+/// Scroll positions of the routes visited so far, by route path.
+#[derive(Clone, Copy)]
+struct RouteScrollPositions(Signal<HashMap<String, (i32, i32)>>);
+
 /// Provides native functionalities for a dioxus-router.
 ///
 /// Features:
 /// - Navigate using back and forward buttons of the mouse.
+/// - Navigate using `Alt + Left` and `Alt + Right`, or the back and forward keys of the keyboard.
+/// - Restore the scroll position of the routes, see [use_route_scroll_controller].
 #[allow(non_snake_case)]
 #[component]
 pub fn NativeRouter(children: Element) -> Element {
     let router = use_navigator();
+    use_context_provider(|| RouteScrollPositions(Signal::new(HashMap::new())));
 
     let onpointerup = move |e: PointerEvent| {
         if let PointerType::Mouse { trigger_button } = e.data().get_pointer_type() {
@@ -28,14 +49,81 @@ pub fn NativeRouter(children: Element) -> Element {
         }
     };
 
+    let onglobalkeydown = move |e: KeyboardEvent| {
+        let alt = e.modifiers.contains(Modifiers::ALT);
+        match e.key {
+            Key::BrowserBack => router.go_back(),
+            Key::BrowserForward => router.go_forward(),
+            Key::ArrowLeft if alt => router.go_back(),
+            Key::ArrowRight if alt => router.go_forward(),
+            _ => {}
+        }
+    };
+
     rsx!(
         rect {
             onpointerup,
+            onglobalkeydown,
             {children}
         }
     )
 }
 
+/// Create a [ScrollController] that remembers its scroll position for every route,
+/// so when navigating back to a route its [ScrollView](crate::ScrollView) is scrolled like it was when left.
+///
+/// It must be descendant of a [NativeRouter], otherwise it's just a regular [ScrollController].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// # #[derive(Routable, Clone, PartialEq)]
+/// # pub enum Route {
+/// #     #[route("/")]
+/// #     Home,
+/// # }
+/// #[component]
+/// fn Home() -> Element {
+///     let scroll_controller = use_route_scroll_controller::<Route>(ScrollConfig::default);
+///
+///     rsx!(
+///         ScrollView {
+///             scroll_controller,
+///             for i in 0..100 {
+///                 label {
+///                     "Item {i}"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_route_scroll_controller<R: Routable + PartialEq + Clone>(
+    init: impl FnOnce() -> ScrollConfig,
+) -> ScrollController {
+    let route = use_route::<R>().to_string();
+    let positions = try_use_context::<RouteScrollPositions>();
+    let saved_position =
+        use_hook(|| positions.and_then(|positions| positions.0.peek().get(&route).copied()));
+
+    // Restored controllers must not scroll to the default position
+    let scroll_controller = use_hook(|| match saved_position {
+        Some((x, y)) => ScrollController::new(x, y, Vec::new()),
+        None => ScrollController::from_config(init()),
+    });
+
+    use_drop(move || {
+        if let Some(mut positions) = positions {
+            let position = (*scroll_controller.x().peek(), *scroll_controller.y().peek());
+            positions.0.write().insert(route, position);
+        }
+    });
+
+    scroll_controller
+}
+
 #[cfg(test)]
 mod test {
     use dioxus_router::prelude::{
@@ -132,5 +220,116 @@ mod test {
         utils.wait_for_update().await;
 
         assert_eq!(utils.root().get(0).get(1).get(0).text(), Some("B"));
+
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::ArrowLeft,
+            code: Code::ArrowLeft,
+            modifiers: Modifiers::ALT,
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(0).get(1).get(0).text(), Some("A"));
+
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::BrowserForward,
+            code: Code::BrowserForward,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(0).get(1).get(0).text(), Some("B"));
+    }
+
+    #[tokio::test]
+    pub async fn route_scroll_controller() {
+        #[derive(Routable, Clone, PartialEq)]
+        #[rustfmt::skip]
+        pub enum Route {
+            #[layout(App)]
+                #[route("/")]
+                Home,
+                #[route("/other")]
+                Other,
+        }
+
+        #[allow(non_snake_case)]
+        #[component]
+        fn Home() -> Element {
+            let scroll_controller = use_route_scroll_controller::<Route>(ScrollConfig::default);
+
+            rsx!(
+                ScrollView {
+                    height: "300",
+                    scroll_controller,
+                    for _ in 0..4 {
+                        rect {
+                            height: "200",
+                            width: "200",
+                        }
+                    }
+                }
+            )
+        }
+
+        #[allow(non_snake_case)]
+        #[component]
+        fn Other() -> Element {
+            rsx!(
+                label {
+                    "Other"
+                }
+            )
+        }
+
+        #[allow(non_snake_case)]
+        fn App() -> Element {
+            rsx!(
+                NativeRouter {
+                    Outlet::<Route> {  }
+                    Link {
+                        to: Route::Other,
+                        label {
+                            "Go to Other"
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(|| -> Element { rsx!(Router::<Route> {}) });
+        utils.wait_for_update().await;
+
+        let content = utils.root().get(0).get(0).get(0).get(0);
+        assert!(content.get(0).is_visible());
+
+        utils.push_event(TestEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 5.).into(),
+        });
+        utils.wait_for_update().await;
+
+        assert!(!content.get(0).is_visible());
+        assert!(content.get(1).is_visible());
+
+        // Leave the route
+        utils.click_cursor((5., 305.)).await;
+
+        assert_eq!(utils.root().get(0).get(0).get(0).text(), Some("Other"));
+
+        // Go back, the scroll position is restored
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseUp,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Back),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let content = utils.root().get(0).get(0).get(0).get(0);
+        assert!(!content.get(0).is_visible());
+        assert!(content.get(1).is_visible());
     }
 }
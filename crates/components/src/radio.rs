@@ -69,6 +69,9 @@ use freya_hooks::{
 pub fn Radio(
     /// Indicate whether this radio is selected or not.
     selected: bool,
+    /// Whether it can be focused by itself. Disabled in a [`RadioItem`](crate::RadioItem()), which is focused instead.
+    #[props(default = true)]
+    focusable: bool,
     /// Theme override.
     theme: Option<RadioThemeWith>,
 ) -> Element {
@@ -98,6 +101,7 @@ pub fn Radio(
     rsx!(
         rect {
             a11y_id: focus.attribute(),
+            a11y_focusable: "{focusable}",
             width: "18",
             height: "18",
             border,
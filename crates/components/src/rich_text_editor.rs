@@ -0,0 +1,384 @@
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use freya_core::platform::CursorIcon;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        keyboard::Code,
+        KeyboardData,
+        MouseEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_editable,
+    use_focus,
+    use_platform,
+    EditableConfig,
+    EditableEvent,
+    EditableMode,
+    InputTheme,
+    InputThemeWith,
+    RichText,
+    TextEditor,
+};
+
+/// Current status of the RichTextEditor.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum RichTextEditorStatus {
+    /// Default state.
+    #[default]
+    Idle,
+    /// Mouse is hovering the editor.
+    Hovering,
+}
+
+/// Properties for the [`RichTextEditor`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct RichTextEditorProps {
+    /// Theme override.
+    pub theme: Option<InputThemeWith>,
+    /// Current value of the RichTextEditor.
+    pub value: ReadOnlySignal<RichText>,
+    /// Handler for the `onchange` event.
+    pub onchange: EventHandler<RichText>,
+    #[props(default = "300".to_string())]
+    pub width: String,
+    #[props(default = "auto".to_string())]
+    pub height: String,
+    /// Background of the inline code spans.
+    #[props(default = "rgb(128, 128, 128, 50)".to_string())]
+    pub code_background: String,
+}
+
+/// Box to edit text with styled spans.
+///
+/// The style of the selected text, or of the text written next if there is no selection, can be toggled with
+/// `Ctrl + B` for bold, `Ctrl + I` for italic and `Ctrl + E` for inline code (`Cmd` in macOS). Other styles
+/// can be applied through [`RopeEditor::update_style`](freya_hooks::RopeEditor::update_style) with [`use_editable`].
+///
+/// # Styling
+/// Inherits the [`InputTheme`](freya_hooks::InputTheme) theme.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut value = use_signal(|| {
+///         RichText::new("Hello, ").with_span(
+///             "World",
+///             SpanStyle {
+///                 bold: true,
+///                 ..Default::default()
+///             },
+///         )
+///     });
+///
+///     rsx!(RichTextEditor {
+///         value,
+///         onchange: move |e| value.set(e)
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn RichTextEditor(
+    RichTextEditorProps {
+        theme,
+        value,
+        onchange,
+        width,
+        height,
+        code_background,
+    }: RichTextEditorProps,
+) -> Element {
+    let platform = use_platform();
+    let mut status = use_signal(RichTextEditorStatus::default);
+    let mut editable = use_editable(
+        || EditableConfig::new_rich(value()),
+        EditableMode::MultipleLinesSingleEditor,
+    );
+    let InputTheme {
+        border_fill,
+        focus_border_fill,
+        margin,
+        corner_radius,
+        font_theme,
+        shadow,
+        background,
+        hover_background,
+        ..
+    } = use_applied_theme!(&theme, input);
+    let mut focus = use_focus();
+    let mut drag_origin = use_signal(|| None);
+
+    if *value.read() != editable.editor().read().rich_text() {
+        editable.editor_mut().write().set_rich_text(&value.read());
+        editable.editor_mut().write().editor_history().clear();
+    }
+
+    use_drop(move || {
+        if *status.peek() == RichTextEditorStatus::Hovering {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    use_effect(move || {
+        if !focus.is_focused() {
+            editable.editor_mut().write().clear_selection();
+        }
+    });
+
+    let onkeydown = move |e: Event<KeyboardData>| {
+        e.stop_propagation();
+        let previous_text = editable.editor().peek().rich_text();
+
+        let meta_or_ctrl = if cfg!(target_os = "macos") {
+            e.modifiers.meta()
+        } else {
+            e.modifiers.ctrl()
+        };
+        match e.code {
+            Code::KeyB if meta_or_ctrl => editable.editor_mut().write().toggle_bold(),
+            Code::KeyI if meta_or_ctrl => editable.editor_mut().write().toggle_italic(),
+            Code::KeyE if meta_or_ctrl => editable.editor_mut().write().toggle_code(),
+            _ => editable.process_event(&EditableEvent::KeyDown(e.data)),
+        }
+
+        let text = editable.editor().peek().rich_text();
+        if text != previous_text {
+            onchange.call(text);
+        }
+    };
+
+    let onkeyup = move |e: Event<KeyboardData>| {
+        e.stop_propagation();
+        editable.process_event(&EditableEvent::KeyUp(e.data));
+    };
+
+    let onmousedown = move |e: MouseEvent| {
+        e.stop_propagation();
+        drag_origin.set(Some(e.get_screen_coordinates() - e.element_coordinates));
+        editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+        focus.request_focus();
+    };
+
+    let onglobalmousemove = move |mut e: MouseEvent| {
+        if focus.is_focused() {
+            if let Some(drag_origin) = drag_origin() {
+                let data = Rc::get_mut(&mut e.data).unwrap();
+                data.element_coordinates.x -= drag_origin.x;
+                data.element_coordinates.y -= drag_origin.y;
+                editable.process_event(&EditableEvent::MouseMove(e.data, 0));
+            }
+        }
+    };
+
+    let onmouseenter = move |_| {
+        platform.set_cursor(CursorIcon::Text);
+        *status.write() = RichTextEditorStatus::Hovering;
+    };
+
+    let onmouseleave = move |_| {
+        platform.set_cursor(CursorIcon::default());
+        *status.write() = RichTextEditorStatus::default();
+    };
+
+    let onglobalclick = move |_| {
+        match *status.read() {
+            RichTextEditorStatus::Idle if focus.is_focused() => {
+                editable.process_event(&EditableEvent::Click);
+            }
+            RichTextEditorStatus::Hovering => {
+                editable.process_event(&EditableEvent::Click);
+            }
+            _ => {}
+        };
+
+        // Unfocus the editor when this:
+        // + is focused
+        // + it has not just being dragged
+        // + a global click happened
+        if focus.is_focused() {
+            if drag_origin.read().is_some() {
+                drag_origin.set(None);
+            } else {
+                focus.request_unfocus();
+            }
+        }
+    };
+
+    let a11y_id = focus.attribute();
+    let cursor_reference = editable.cursor_attr();
+    let highlights = editable.highlights_attr(0);
+    let carets = editable.carets_attr(0);
+
+    let (background, cursor_char) = if focus.is_focused() {
+        let cursor_pos = editable.editor().read().cursor_pos();
+        (hover_background, cursor_pos.to_string())
+    } else {
+        (background, "none".to_string())
+    };
+    let border = if focus.is_focused_with_keyboard() {
+        format!("2 inner {focus_border_fill}")
+    } else {
+        format!("1 inner {border_fill}")
+    };
+    let color = font_theme.color;
+    let rich_text = editable.editor().read().rich_text();
+
+    rsx!(
+        rect {
+            width,
+            height,
+            color: "{color}",
+            background: "{background}",
+            border,
+            shadow: "{shadow}",
+            corner_radius: "{corner_radius}",
+            margin: "{margin}",
+            padding: "6 10",
+            a11y_id,
+            a11y_role: "text-input",
+            onkeydown,
+            onkeyup,
+            onmouseenter,
+            onmouseleave,
+            paragraph {
+                width: "100%",
+                onglobalclick,
+                onmousedown,
+                onglobalmousemove,
+                cursor_reference,
+                cursor_id: "0",
+                cursor_index: "{cursor_char}",
+                cursor_mode: "editable",
+                cursor_color: "{color}",
+                highlights,
+                carets,
+                if rich_text.text.is_empty() {
+                    text {
+                        ""
+                    }
+                }
+                for (segment, style) in rich_text.segments() {
+                    if style.code {
+                        text {
+                            color: style.color.as_deref().unwrap_or(&*color),
+                            font_weight: style.font_weight(),
+                            font_style: style.font_style(),
+                            font_family: "monospace",
+                            background: "{code_background}",
+                            "{segment}"
+                        }
+                    } else {
+                        text {
+                            color: style.color.as_deref().unwrap_or(&*color),
+                            font_weight: style.font_weight(),
+                            font_style: style.font_style(),
+                            "{segment}"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_engine::prelude::Weight;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn rich_text_editor() {
+        fn rich_text_editor_app() -> Element {
+            let mut value = use_signal(|| RichText::new("Hello"));
+
+            rsx!(RichTextEditor {
+                value,
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            })
+        }
+
+        let mut utils = launch_test(rich_text_editor_app);
+        let root = utils.root();
+        let paragraph = root.get(0).get(0);
+        utils.wait_for_update().await;
+
+        assert_eq!(paragraph.get(0).get(0).text(), Some("Hello"));
+
+        // Focus the editor at the end of the text
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (250., 15.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_ne!(utils.focus_id(), ACCESSIBILITY_ROOT_ID);
+
+        // Enable bold and write "!"
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("b".to_string()),
+            code: Code::KeyB,
+            modifiers: if cfg!(target_os = "macos") {
+                Modifiers::META
+            } else {
+                Modifiers::CONTROL
+            },
+        });
+        utils.wait_for_update().await;
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("!".to_string()),
+            code: Code::Digit1,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        // The new text is in its own bold span
+        assert_eq!(paragraph.children_ids().len(), 2);
+        assert_eq!(paragraph.get(0).get(0).text(), Some("Hello"));
+        assert_eq!(paragraph.get(1).get(0).text(), Some("!"));
+        assert_eq!(
+            paragraph.get(1).state().font_style.font_weight,
+            Weight::BOLD
+        );
+
+        // Removing a character keeps the styles of the rest of the text
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Backspace,
+            code: Code::Backspace,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(paragraph.children_ids().len(), 1);
+        assert_eq!(paragraph.get(0).get(0).text(), Some("Hello"));
+
+        // Undo brings back the bold text
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("z".to_string()),
+            code: Code::KeyZ,
+            modifiers: if cfg!(target_os = "macos") {
+                Modifiers::META
+            } else {
+                Modifiers::CONTROL
+            },
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(paragraph.children_ids().len(), 2);
+        assert_eq!(paragraph.get(1).get(0).text(), Some("!"));
+    }
+}
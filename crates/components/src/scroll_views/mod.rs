@@ -2,6 +2,7 @@ mod scroll_bar;
 mod scroll_thumb;
 mod scroll_view;
 mod use_scroll_controller;
+mod use_scroll_progress;
 mod virtual_scroll_view;
 
 use freya_elements::events::{
@@ -12,6 +13,7 @@ pub use scroll_bar::*;
 pub use scroll_thumb::*;
 pub use scroll_view::*;
 pub use use_scroll_controller::*;
+pub use use_scroll_progress::*;
 pub use virtual_scroll_view::*;
 
 // Holding alt while scrolling makes it 5x faster (VSCode behavior).
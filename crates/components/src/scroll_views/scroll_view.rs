@@ -12,7 +12,10 @@ use freya_hooks::{
     use_applied_theme,
     use_focus,
     use_node_from_signal,
+    use_platform_information,
+    use_soft_keyboard,
     ScrollBarThemeWith,
+    ScrollViewport,
 };
 
 use super::use_scroll_controller::ScrollController;
@@ -169,12 +172,62 @@ pub fn ScrollView(
         scroll_controller.unwrap_or_else(|| use_scroll_controller(ScrollConfig::default));
     let (mut scrolled_x, mut scrolled_y) = scroll_controller.into();
     let (node_ref, size) = use_node_from_signal(|| scroll_controller.layout());
+    use_context_provider(|| ScrollViewport(scroll_controller.layout().into()));
 
     let mut focus = use_focus();
     let applied_scrollbar_theme = use_applied_theme!(&scrollbar_theme, scroll_bar);
 
     scroll_controller.use_apply(size.inner.width, size.inner.height);
 
+    let soft_keyboard = use_soft_keyboard();
+    let platform_information = use_platform_information();
+    let focused_node = focus.focused_node();
+
+    // Scroll to keep the focused element above the on-screen keyboard
+    use_effect(move || {
+        let inset = soft_keyboard.inset();
+        let Some(bounds) = focused_node.read().bounds() else {
+            return;
+        };
+        if inset <= 0. {
+            return;
+        }
+
+        let information = *platform_information.peek();
+        let scale_factor = information.scale_factor as f32;
+        let (x0, x1) = (
+            bounds.x0 as f32 / scale_factor,
+            bounds.x1 as f32 / scale_factor,
+        );
+        let (y0, y1) = (
+            bounds.y0 as f32 / scale_factor,
+            bounds.y1 as f32 / scale_factor,
+        );
+
+        let layout = scroll_controller.layout().peek().clone();
+        let scroll_position = get_corrected_scroll_position(
+            layout.inner.height,
+            layout.area.height(),
+            *scrolled_y.peek() as f32,
+        );
+        let content_top = layout.area.min_y() + scroll_position;
+
+        // Only the elements inside this ScrollView
+        let is_inside = x0 >= layout.area.min_x()
+            && x1 <= layout.area.max_x()
+            && y0 >= content_top
+            && y1 <= content_top + layout.inner.height;
+        if !is_inside {
+            return;
+        }
+
+        let keyboard_top = information.viewport_size.height / scale_factor - inset;
+        let visible_bottom = layout.area.max_y().min(keyboard_top);
+        if y1 > visible_bottom {
+            scrolled_y.set((scroll_position - (y1 - visible_bottom)) as i32);
+        }
+    });
+
     let direction_is_vertical = direction == "vertical";
 
     let vertical_scrollbar_is_visible = is_scrollbar_visible(
@@ -499,6 +552,56 @@ mod test {
         assert!(content.get(3).is_visible()); // 4. 600 -> 800, 800 > 300
     }
 
+    #[tokio::test]
+    pub async fn scroll_view_soft_keyboard() {
+        #[allow(non_snake_case)]
+        fn Field() -> Element {
+            let mut focus = use_focus();
+
+            rsx!(rect {
+                height: "50",
+                width: "200",
+                a11y_id: focus.attribute(),
+                onclick: move |_| focus.request_focus(),
+            })
+        }
+
+        fn scroll_view_soft_keyboard_app() -> Element {
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "400",
+                        width: "200",
+                    }
+                    Field {}
+                    rect {
+                        height: "300",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_soft_keyboard_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        utils.click_cursor((5., 425.)).await;
+        utils.wait_for_update().await;
+
+        // The keyboard covers the focused field
+        utils.set_soft_keyboard(SoftKeyboard {
+            visible: true,
+            inset: 200.,
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The field is scrolled right above the keyboard
+        assert_eq!(content.get(1).layout().unwrap().area.max_y(), 300.);
+    }
+
     #[tokio::test]
     pub async fn scroll_view_scrollbar() {
         fn scroll_view_scrollbar_app() -> Element {
@@ -78,6 +78,28 @@ impl ScrollController {
         }
     }
 
+    /// Create a controller that starts in the positions of the given [ScrollConfig].
+    pub(crate) fn from_config(config: ScrollConfig) -> Self {
+        Self::new(
+            0,
+            0,
+            vec![
+                ScrollRequest {
+                    position: config.default_vertical_position,
+                    direction: ScrollDirection::Vertical,
+                    init: true,
+                    applied_by: HashSet::default(),
+                },
+                ScrollRequest {
+                    position: config.default_horizontal_position,
+                    direction: ScrollDirection::Horizontal,
+                    init: true,
+                    applied_by: HashSet::default(),
+                },
+            ],
+        )
+    }
+
     pub fn x(&self) -> Signal<i32> {
         self.x
     }
@@ -175,27 +197,7 @@ impl ScrollController {
 }
 
 pub fn use_scroll_controller(init: impl FnOnce() -> ScrollConfig) -> ScrollController {
-    use_hook(|| {
-        let config = init();
-        ScrollController::new(
-            0,
-            0,
-            vec![
-                ScrollRequest {
-                    position: config.default_vertical_position,
-                    direction: ScrollDirection::Vertical,
-                    init: true,
-                    applied_by: HashSet::default(),
-                },
-                ScrollRequest {
-                    position: config.default_horizontal_position,
-                    direction: ScrollDirection::Horizontal,
-                    init: true,
-                    applied_by: HashSet::default(),
-                },
-            ],
-        )
-    })
+    use_hook(|| ScrollController::from_config(init()))
 }
 
 #[cfg(test)]
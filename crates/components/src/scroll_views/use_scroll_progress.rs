@@ -0,0 +1,205 @@
+use dioxus::prelude::{
+    use_memo,
+    AttributeValue,
+    Memo,
+    Readable,
+};
+use freya_hooks::use_node_signal;
+
+use super::use_scroll_controller::ScrollController;
+
+/// Progress of a scroll, from `0.0` to `1.0` on each axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScrollProgress {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Get how far a value is between `start` and `end`, from `0.0` to `1.0`.
+fn progress_between(value: f32, start: f32, end: f32) -> f32 {
+    if end <= start {
+        0.
+    } else {
+        ((value - start) / (end - start)).clamp(0., 1.)
+    }
+}
+
+/// Track how far the content of a [ScrollView](crate::ScrollView) has been scrolled,
+/// from `0.0` at the start to `1.0` at the end. Useful for reading progress bars.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let scroll_controller = use_scroll_controller(ScrollConfig::default);
+///     let progress = use_scroll_progress(scroll_controller);
+///
+///     rsx!(
+///         rect {
+///             width: "{progress().y * 100.}%",
+///             height: "4",
+///             background: "rgb(0, 119, 182)",
+///         }
+///         ScrollView {
+///             scroll_controller,
+///             for i in 0..100 {
+///                 label {
+///                     "Paragraph {i}"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_scroll_progress(scroll_controller: ScrollController) -> Memo<ScrollProgress> {
+    use_memo(move || {
+        let layout = scroll_controller.layout().read().clone();
+        let (x, y) = (scroll_controller.x()(), scroll_controller.y()());
+        ScrollProgress {
+            x: progress_between(-x as f32, 0., layout.inner.width - layout.area.width()),
+            y: progress_between(-y as f32, 0., layout.inner.height - layout.area.height()),
+        }
+    })
+}
+
+/// Track how far a node has moved through the viewport of a [ScrollView](crate::ScrollView),
+/// from `0.0` when it starts entering from the end to `1.0` when it has fully left from the start.
+///
+/// Attach the returned reference to the node. Useful for parallax effects.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let scroll_controller = use_scroll_controller(ScrollConfig::default);
+///     let (reference, progress) = use_node_scroll_progress(scroll_controller);
+///
+///     rsx!(
+///         ScrollView {
+///             scroll_controller,
+///             rect {
+///                 height: "300",
+///             }
+///             rect {
+///                 reference,
+///                 width: "100%",
+///                 height: "200",
+///                 overflow: "clip",
+///                 rect {
+///                     offset_y: "{(progress().y - 0.5) * 100.}",
+///                     label {
+///                         "Parallax"
+///                     }
+///                 }
+///             }
+///             rect {
+///                 height: "300",
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_node_scroll_progress(
+    scroll_controller: ScrollController,
+) -> (AttributeValue, Memo<ScrollProgress>) {
+    let (reference, node_layout) = use_node_signal();
+
+    let progress = use_memo(move || {
+        let viewport = scroll_controller.layout().read().area;
+        let node = node_layout.read().area;
+        ScrollProgress {
+            x: progress_between(
+                viewport.max_x(),
+                node.min_x(),
+                node.max_x() + viewport.width(),
+            ),
+            y: progress_between(
+                viewport.max_y(),
+                node.min_y(),
+                node.max_y() + viewport.height(),
+            ),
+        }
+    });
+
+    (reference, progress)
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn scroll_progress() {
+        fn scroll_progress_app() -> Element {
+            let scroll_controller = use_scroll_controller(ScrollConfig::default);
+            let progress = use_scroll_progress(scroll_controller);
+            let (reference, node_progress) = use_node_scroll_progress(scroll_controller);
+
+            rsx!(
+                label {
+                    "{progress().y} {node_progress().y}"
+                }
+                ScrollView {
+                    scroll_controller,
+                    height: "400",
+                    rect {
+                        height: "400",
+                    }
+                    rect {
+                        reference,
+                        height: "200",
+                        width: "100%",
+                    }
+                    rect {
+                        height: "400",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_progress_app);
+        let root = utils.root();
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let progress = || {
+            root.get(0)
+                .get(0)
+                .text()
+                .unwrap()
+                .split(' ')
+                .map(|value| value.parse::<f32>().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        // Nothing scrolled, the node is right below the viewport
+        assert_eq!(progress(), vec![0., 0.]);
+
+        // Scroll to the middle, the node is in the middle of the viewport
+        utils.push_event(TestEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 100.).into(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(progress(), vec![0.5, 0.5]);
+
+        // Scroll to the end, the node is right above the viewport
+        utils.push_event(TestEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 100.).into(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(progress(), vec![1., 1.]);
+    }
+}
@@ -15,8 +15,9 @@ use freya_elements::{
 use freya_hooks::{
     use_applied_theme,
     use_focus,
-    use_node,
+    use_node_from_signal,
     ScrollBarThemeWith,
+    ScrollViewport,
 };
 
 use crate::{
@@ -218,7 +219,8 @@ pub fn VirtualScrollView<
     let mut scroll_controller =
         scroll_controller.unwrap_or_else(|| use_scroll_controller(ScrollConfig::default));
     let (mut scrolled_x, mut scrolled_y) = scroll_controller.into();
-    let (node_ref, size) = use_node();
+    let (node_ref, size) = use_node_from_signal(|| scroll_controller.layout());
+    use_context_provider(|| ScrollViewport(scroll_controller.layout().into()));
     let mut focus = use_focus();
     let applied_scrollbar_theme = use_applied_theme!(&scrollbar_theme, scroll_bar);
 
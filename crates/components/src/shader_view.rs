@@ -0,0 +1,240 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Instant,
+};
+
+use dioxus::prelude::*;
+use freya_core::custom_attributes::CanvasRunnerContext;
+use freya_elements::{
+    self as dioxus_elements,
+    events::MouseEvent,
+};
+use freya_engine::prelude::*;
+use freya_hooks::{
+    use_canvas_with_deps,
+    use_node_signal,
+    use_platform,
+    UniformValue,
+    UniformsBuilder,
+};
+use tokio::sync::mpsc::unbounded_channel;
+use torin::prelude::CursorPoint;
+
+/// Runtime effect compiled by the canvas runner.
+struct RuntimeEffectWrapper(RuntimeEffect);
+
+// SAFETY: The effect is only compiled and used inside the canvas runner, which is only called from the render thread.
+// Any other thread can at most drop the runner, which just releases a reference to the effect and Skia counts those atomically.
+unsafe impl Sync for RuntimeEffectWrapper {}
+unsafe impl Send for RuntimeEffectWrapper {}
+
+/// Properties for the [`ShaderView`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct ShaderViewProps {
+    /// SkSL source of the shader.
+    #[props(into)]
+    pub sksl: String,
+    /// Custom uniforms values.
+    #[props(default)]
+    pub uniforms: HashMap<String, UniformValue>,
+    /// Width of the ShaderView. Default `100%`.
+    #[props(default = "100%".into())]
+    pub width: String,
+    /// Height of the ShaderView. Default `100%`.
+    #[props(default = "100%".into())]
+    pub height: String,
+    /// Keep rendering new frames so `u_time` progresses. Default `true`.
+    #[props(default = true)]
+    pub animated: bool,
+}
+
+/// Draw an [SkSL](https://skia.org/docs/user/sksl/) runtime shader into the area of this component.
+///
+/// These uniforms are available to the shader if declared:
+/// - `uniform float u_time`: Seconds since the shader was compiled.
+/// - `uniform vec2 u_resolution`: Size of the component in physical pixels.
+/// - `uniform vec2 u_cursor`: Last cursor position over the component in physical pixels, relative to the component.
+///
+/// Any other declared uniform must be passed through `uniforms`, otherwise it will be zeroed.
+/// Coordinates passed to `main` are relative to the component.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// const SHADER: &str = "
+///     uniform vec2 u_resolution;
+///     uniform float u_time;
+///
+///     vec4 main(vec2 coords) {
+///         vec2 uv = coords / u_resolution;
+///         return vec4(uv.x, uv.y, abs(sin(u_time)), 1.0);
+///     }
+/// ";
+///
+/// fn app() -> Element {
+///     rsx!(ShaderView { sksl: SHADER })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn ShaderView(
+    ShaderViewProps {
+        sksl,
+        uniforms,
+        width,
+        height,
+        animated,
+    }: ShaderViewProps,
+) -> Element {
+    let platform = use_platform();
+    let (reference, size) = use_node_signal();
+    let mut ticker_task = use_signal::<Option<Task>>(|| None);
+    let custom_uniforms = use_hook(|| Arc::new(Mutex::new(HashMap::new())));
+    let cursor = use_hook(|| Arc::new(Mutex::new(CursorPoint::default())));
+
+    {
+        let custom_uniforms = custom_uniforms.clone();
+        use_effect(use_reactive!(|uniforms| {
+            *custom_uniforms.lock().unwrap() = uniforms;
+            platform.invalidate_drawing_area(size.peek().area);
+            platform.request_animation_frame();
+        }));
+    }
+
+    use_effect(use_reactive!(|animated| {
+        if let Some(task) = ticker_task.write().take() {
+            task.cancel();
+        }
+        if animated {
+            let mut ticker = platform.new_ticker();
+            ticker_task.set(Some(spawn(async move {
+                loop {
+                    ticker.tick().await;
+                    platform.invalidate_drawing_area(size.peek().area);
+                    platform.request_animation_frame();
+                }
+            })));
+        }
+    }));
+
+    // Compilation error of the shader, reported by the canvas runner
+    let mut error = use_signal(|| None::<String>);
+    let error_sender = use_hook(|| {
+        let (sender, mut receiver) = unbounded_channel::<Option<String>>();
+        spawn(async move {
+            while let Some(new_error) = receiver.recv().await {
+                if *error.peek() != new_error {
+                    error.set(new_error);
+                }
+            }
+        });
+        sender
+    });
+
+    // Draw again with the new shader
+    use_effect(use_reactive!(|sksl| {
+        let _ = sksl;
+        platform.invalidate_drawing_area(size.peek().area);
+        platform.request_animation_frame();
+    }));
+
+    let canvas = {
+        let custom_uniforms = custom_uniforms.clone();
+        let cursor = cursor.clone();
+        use_canvas_with_deps(&sksl, move |sksl| {
+            // Compiled the first time it is drawn, so the effect never leaves the render thread
+            let mut effect = None::<Option<RuntimeEffectWrapper>>;
+            let instant = Instant::now();
+            let custom_uniforms = custom_uniforms.clone();
+            let cursor = cursor.clone();
+            let error_sender = error_sender.clone();
+
+            move |ctx: &mut CanvasRunnerContext| {
+                let effect = effect.get_or_insert_with(|| {
+                    let compiled = RuntimeEffect::make_for_shader(&sksl, None);
+                    error_sender.send(compiled.as_ref().err().cloned()).ok();
+                    compiled.ok().map(RuntimeEffectWrapper)
+                });
+                let Some(RuntimeEffectWrapper(effect)) = effect else {
+                    return;
+                };
+
+                let mut builder = UniformsBuilder::default();
+                for (name, value) in custom_uniforms.lock().unwrap().iter() {
+                    builder.set(name, value.clone());
+                }
+                let cursor = *cursor.lock().unwrap();
+                builder.set(
+                    "u_resolution",
+                    UniformValue::FloatVec(vec![ctx.area.width(), ctx.area.height()]),
+                );
+                builder.set(
+                    "u_time",
+                    UniformValue::Float(instant.elapsed().as_secs_f32()),
+                );
+                builder.set(
+                    "u_cursor",
+                    UniformValue::FloatVec(vec![
+                        cursor.x as f32 * ctx.scale_factor,
+                        cursor.y as f32 * ctx.scale_factor,
+                    ]),
+                );
+
+                // Zero any uniform the shader declares but was not provided
+                for uniform in effect.uniforms() {
+                    if !builder.contains(uniform.name()) {
+                        builder.set(
+                            uniform.name(),
+                            UniformValue::FloatVec(vec![0.; uniform.size_in_bytes() / 4]),
+                        );
+                    }
+                }
+
+                let uniforms = Data::new_copy(&builder.build(effect));
+                let Some(shader) = effect.make_shader(uniforms, &[], None) else {
+                    return;
+                };
+
+                let mut paint = Paint::default();
+                paint.set_anti_alias(true);
+                paint.set_shader(shader);
+
+                ctx.canvas.save();
+                ctx.canvas.translate((ctx.area.min_x(), ctx.area.min_y()));
+                ctx.canvas.draw_rect(
+                    Rect::new(0., 0., ctx.area.width(), ctx.area.height()),
+                    &paint,
+                );
+                ctx.canvas.restore();
+            }
+        })
+    };
+
+    let onmousemove = move |e: MouseEvent| {
+        *cursor.lock().unwrap() = e.get_element_coordinates();
+        if !animated {
+            platform.invalidate_drawing_area(size.peek().area);
+            platform.request_animation_frame();
+        }
+    };
+
+    rsx!(
+        rect {
+            width,
+            height,
+            canvas_reference: canvas.attribute(),
+            reference,
+            onmousemove,
+            if let Some(error) = &*error.read() {
+                label {
+                    color: "rgb(220, 50, 50)",
+                    "{error}"
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,183 @@
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::KeyboardEvent,
+};
+use freya_hooks::{
+    use_init_shortcuts,
+    use_shortcut_area,
+};
+
+/// Provide the shortcuts registered with [use_shortcuts](freya_hooks::use_shortcuts) to its children,
+/// triggering them when their keys are pressed.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         ShortcutProvider {
+///             Counter { }
+///         }
+///     )
+/// }
+///
+/// #[component]
+/// fn Counter() -> Element {
+///     let shortcuts = use_shortcuts();
+///     let mut count = use_signal(|| 0);
+///
+///     use_hook(move || {
+///         shortcuts
+///             .register("increase", Shortcut::new("CmdOrCtrl+K"), move |_| count += 1)
+///             .ok();
+///     });
+///
+///     rsx!(label { "Pressed {count} times" })
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn ShortcutProvider(
+    /// The inner children for the ShortcutProvider
+    children: Element,
+) -> Element {
+    let (mut registry, area) = use_init_shortcuts();
+
+    let onkeydown = move |e: KeyboardEvent| registry.handle_focused(area, e);
+    let onglobalkeydown = move |e: KeyboardEvent| registry.handle_global(e);
+
+    rsx!(
+        rect {
+            onkeydown,
+            onglobalkeydown,
+            {children}
+        }
+    )
+}
+
+/// Limit the shortcuts registered with [ShortcutScope::Focused](freya_hooks::ShortcutScope::Focused)
+/// by its descendants to when the focused element is inside of it.
+///
+/// Needs a [ShortcutProvider] higher in the tree.
+#[allow(non_snake_case)]
+#[component]
+pub fn ShortcutArea(
+    /// The inner children for the ShortcutArea
+    children: Element,
+) -> Element {
+    let (mut registry, area) = use_shortcut_area();
+
+    let onkeydown = move |e: KeyboardEvent| registry.handle_focused(area, e);
+
+    rsx!(
+        rect {
+            onkeydown,
+            {children}
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn shortcut_provider() {
+        #[component]
+        fn Editor() -> Element {
+            let shortcuts = use_shortcuts();
+            let mut focus = use_focus();
+            let mut saved = use_signal(|| 0);
+
+            let error = use_hook(move || {
+                shortcuts
+                    .register(
+                        "save",
+                        Shortcut::new("Ctrl+S").scope(ShortcutScope::Focused),
+                        move |_| saved += 1,
+                    )
+                    .ok();
+                shortcuts
+                    .register(
+                        "duplicate",
+                        Shortcut::new("Ctrl+S").scope(ShortcutScope::Focused),
+                        |_| {},
+                    )
+                    .err()
+                    .map(|err| err.to_string())
+                    .unwrap_or_default()
+            });
+
+            rsx!(
+                rect {
+                    a11y_id: focus.attribute(),
+                    width: "100%",
+                    height: "50%",
+                    onclick: move |_| focus.request_focus(),
+                    label { "saved {saved}" }
+                    label { "{error}" }
+                }
+            )
+        }
+
+        #[component]
+        fn App() -> Element {
+            let shortcuts = use_shortcuts();
+            let mut global = use_signal(|| 0);
+
+            use_hook(move || {
+                shortcuts
+                    .register("global", Shortcut::new("Ctrl+S"), move |_| global += 1)
+                    .ok();
+            });
+
+            rsx!(
+                label { "global {global}" }
+                ShortcutArea {
+                    Editor {}
+                }
+            )
+        }
+
+        fn shortcut_provider_app() -> Element {
+            rsx!(
+                ShortcutProvider {
+                    App {}
+                }
+            )
+        }
+
+        let mut utils = launch_test(shortcut_provider_app);
+        utils.wait_for_update().await;
+
+        let root = utils.root().get(0);
+        let editor = root.get(1).get(0);
+        assert_eq!(
+            editor.get(1).get(0).text(),
+            Some("Keys already used by the shortcut 'save'")
+        );
+
+        let press_ctrl_s = || TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("s".to_string()),
+            code: Code::KeyS,
+            modifiers: Modifiers::CONTROL,
+        };
+
+        // Nothing is focused so only the global shortcut is triggered
+        utils.push_event(press_ctrl_s());
+        utils.wait_for_update().await;
+        assert_eq!(root.get(0).get(0).text(), Some("global 1"));
+        assert_eq!(editor.get(0).get(0).text(), Some("saved 0"));
+
+        // The focused shortcut takes over while the editor is focused
+        utils.click_cursor((10., 100.)).await;
+        utils.push_event(press_ctrl_s());
+        utils.wait_for_update().await;
+        assert_eq!(root.get(0).get(0).text(), Some("global 1"));
+        assert_eq!(editor.get(0).get(0).text(), Some("saved 1"));
+    }
+}
@@ -0,0 +1,113 @@
+use dioxus::prelude::*;
+use freya_hooks::{
+    use_responsive,
+    Breakpoint,
+};
+
+/// Only render its children when the window width is within the given [Breakpoint]s, see [use_responsive](freya_hooks::use_responsive).
+///
+/// `above` and `below` take the name of a breakpoint: `xs`, `sm`, `md`, `lg` or `xl`.
+/// The children are shown from the `above` breakpoint, included, up to the `below` one, excluded.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         rect {
+///             direction: "horizontal",
+///             Show {
+///                 above: "md",
+///                 fallback: rsx!(
+///                     label {
+///                         "☰"
+///                     }
+///                 ),
+///                 label {
+///                     "Sidebar"
+///                 }
+///             }
+///             label {
+///                 "Content"
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn Show(
+    /// Content shown within the breakpoints.
+    children: Element,
+    /// Show from this breakpoint, included.
+    above: Option<String>,
+    /// Show until this breakpoint, excluded.
+    below: Option<String>,
+    /// Content shown outside the breakpoints.
+    fallback: Option<Element>,
+) -> Element {
+    let breakpoint = use_responsive();
+    let above = above.and_then(|above| above.parse::<Breakpoint>().ok());
+    let below = below.and_then(|below| below.parse::<Breakpoint>().ok());
+
+    let breakpoint = breakpoint();
+    let is_above = match above {
+        Some(above) => breakpoint.is_above(above),
+        None => true,
+    };
+    let is_below = match below {
+        Some(below) => breakpoint.is_below(below),
+        None => true,
+    };
+
+    if is_above && is_below {
+        children
+    } else if let Some(fallback) = fallback {
+        fallback
+    } else {
+        rsx!({})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn show() {
+        fn show_app() -> Element {
+            rsx!(
+                Show {
+                    above: "md",
+                    below: "xl",
+                    fallback: rsx!(
+                        label {
+                            "Hidden"
+                        }
+                    ),
+                    label {
+                        "Shown"
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(show_app);
+        utils.wait_for_update().await;
+
+        // The default testing size is 500x500
+        let root = utils.root();
+        assert_eq!(root.get(0).get(0).text(), Some("Hidden"));
+
+        utils.resize((800., 500.).into());
+        utils.wait_for_update().await;
+        assert_eq!(root.get(0).get(0).text(), Some("Shown"));
+
+        // Excluded from `below`
+        utils.resize((1300., 500.).into());
+        utils.wait_for_update().await;
+        assert_eq!(root.get(0).get(0).text(), Some("Hidden"));
+    }
+}
@@ -1,3 +1,8 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
 use dioxus::prelude::*;
 use freya_core::platform::CursorIcon;
 use freya_elements::{
@@ -14,6 +19,7 @@ use freya_hooks::{
     use_platform,
     AnimColor,
     AnimNum,
+    AnimSpring,
     Ease,
     Function,
     OnDepsChange,
@@ -27,6 +33,9 @@ pub struct SwitchProps {
     pub theme: Option<SwitchThemeWith>,
     /// Whether the `Switch` is enabled or not.
     pub enabled: bool,
+    /// Whether the user can't toggle the `Switch`.
+    #[props(default = false)]
+    pub disabled: bool,
     /// Handler for the `ontoggled` event.
     pub ontoggled: EventHandler<()>,
 }
@@ -41,10 +50,85 @@ pub enum SwitchStatus {
     Hovering,
 }
 
+/// Offset of the thumb when the `Switch` is not enabled.
+const THUMB_START: f32 = 2.;
+/// Offset of the thumb when the `Switch` is enabled.
+const THUMB_END: f32 = 22.;
+/// Distance the cursor must move for a press to become a drag.
+const DRAG_THRESHOLD: f64 = 3.;
+/// Speed of the thumb, in pixels per second, above which releasing it commits the direction it was moving in.
+const FLING_VELOCITY: f32 = 200.;
+/// Fastest speed the thumb is released with, in pixels per second.
+const MAX_RELEASE_VELOCITY: f32 = 1500.;
+/// Time without movement after which the thumb is considered to be held still.
+const RELEASE_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Thumb being dragged by the user.
+#[derive(Clone, Copy)]
+struct ThumbDrag {
+    start_x: f64,
+    start_offset: f32,
+    offset: f32,
+    last_x: f64,
+    last_moved_at: Instant,
+    velocity: f32,
+    has_moved: bool,
+}
+
+impl ThumbDrag {
+    fn new(x: f64, offset: f32) -> Self {
+        Self {
+            start_x: x,
+            start_offset: offset,
+            offset,
+            last_x: x,
+            last_moved_at: Instant::now(),
+            velocity: 0.,
+            has_moved: false,
+        }
+    }
+
+    fn move_to(&mut self, x: f64) {
+        let now = Instant::now();
+        let elapsed = (now - self.last_moved_at).as_secs_f32();
+        if elapsed > 0. {
+            self.velocity = ((x - self.last_x) as f32 / elapsed)
+                .clamp(-MAX_RELEASE_VELOCITY, MAX_RELEASE_VELOCITY);
+        }
+        self.has_moved |= (x - self.start_x).abs() > DRAG_THRESHOLD;
+        self.offset = (self.start_offset + (x - self.start_x) as f32).clamp(THUMB_START, THUMB_END);
+        self.last_x = x;
+        self.last_moved_at = now;
+    }
+
+    /// Velocity of the thumb when released, none if it was held still.
+    fn release_velocity(&self) -> f32 {
+        if self.last_moved_at.elapsed() > RELEASE_TIMEOUT {
+            0.
+        } else {
+            self.velocity
+        }
+    }
+
+    /// Whether the `Switch` should be enabled once the thumb is released, a fast enough
+    /// movement wins over the distance.
+    fn is_enabled_on_release(&self) -> bool {
+        let velocity = self.release_velocity();
+        if velocity.abs() > FLING_VELOCITY {
+            velocity > 0.
+        } else {
+            self.offset > (THUMB_START + THUMB_END) / 2.
+        }
+    }
+}
+
 /// Display whether a state is `true` or `false`.
 /// Commonly used for enabled/disabled scenarios.
 /// Example: light/dark theme.
 ///
+/// It can be toggled by clicking it or by dragging its thumb, which settles with a spring once released.
+/// A `disabled` Switch can't be toggled nor focused.
+///
 /// # Styling
 ///
 /// Inherits the [`SwitchTheme`](freya_hooks::SwitchTheme) theme.
@@ -115,10 +199,6 @@ pub fn Switch(props: SwitchProps) -> Element {
     let animation = use_animation_with_dependencies(&theme, |conf, theme| {
         conf.on_deps_change(OnDepsChange::Finish);
         (
-            AnimNum::new(2., 22.)
-                .time(300)
-                .function(Function::Expo)
-                .ease(Ease::Out),
             AnimNum::new(14., 18.)
                 .time(300)
                 .function(Function::Expo)
@@ -133,6 +213,24 @@ pub fn Switch(props: SwitchProps) -> Element {
                 .ease(Ease::Out),
         )
     });
+    let mut drag = use_signal(|| None::<ThumbDrag>);
+    let mut released = use_hook(|| CopyValue::new(None::<(f32, f32)>));
+    let mut releases = use_signal(|| 0usize);
+    let thumb =
+        use_animation_with_dependencies(&(props.enabled, releases()), move |conf, (enabled, _)| {
+            conf.on_deps_change(OnDepsChange::Rerun);
+            conf.auto_start(true);
+            let offset = if enabled { THUMB_END } else { THUMB_START };
+            let spring = AnimSpring::new(offset, offset)
+                .stiffness(400.)
+                .damping(28.)
+                .precision(0.1);
+            let mut released = released;
+            match released.write().take() {
+                Some((offset, velocity)) => spring.start_from(offset, velocity),
+                None => spring,
+            }
+        });
     let platform = use_platform();
     let mut status = use_signal(SwitchStatus::default);
     let mut focus = use_focus();
@@ -145,8 +243,39 @@ pub fn Switch(props: SwitchProps) -> Element {
         }
     });
 
-    let onmousedown = |e: MouseEvent| {
+    let onmousedown = move |e: MouseEvent| {
         e.stop_propagation();
+        if props.disabled {
+            return;
+        }
+        focus.request_focus();
+        let offset = thumb.get().peek().read();
+        drag.set(Some(ThumbDrag::new(e.get_screen_coordinates().x, offset)));
+    };
+
+    let onglobalmousemove = move |e: MouseEvent| {
+        if drag.peek().is_none() {
+            return;
+        }
+        if let Some(drag) = drag.write().as_mut() {
+            drag.move_to(e.get_screen_coordinates().x);
+        }
+    };
+
+    let onglobalclick = move |_: MouseEvent| {
+        let Some(thumb_drag) = drag.write().take() else {
+            return;
+        };
+        if !thumb_drag.has_moved {
+            props.ontoggled.call(());
+            return;
+        }
+        // Settle the thumb from where it was released
+        released.set(Some((thumb_drag.offset, thumb_drag.release_velocity())));
+        releases += 1;
+        if thumb_drag.is_enabled_on_release() != props.enabled {
+            props.ontoggled.call(());
+        }
     };
 
     let onmouseleave = move |e: MouseEvent| {
@@ -157,29 +286,33 @@ pub fn Switch(props: SwitchProps) -> Element {
 
     let onmouseenter = move |e: MouseEvent| {
         e.stop_propagation();
+        if props.disabled {
+            return;
+        }
         *status.write() = SwitchStatus::Hovering;
         platform.set_cursor(CursorIcon::Pointer);
     };
 
-    let onclick = move |e: MouseEvent| {
+    let onclick = |e: MouseEvent| {
         e.stop_propagation();
-        focus.request_focus();
-        props.ontoggled.call(());
     };
 
     let onkeydown = move |e: KeyboardEvent| {
-        if focus.validate_keydown(&e) {
+        if !props.disabled && focus.validate_keydown(&e) {
             props.ontoggled.call(());
         }
     };
 
-    let (offset_x, size, background, circle) = &*animation.get().read_unchecked();
-    let offset_x = offset_x.read();
+    let offset_x = match *drag.read() {
+        Some(drag) => drag.offset,
+        None => thumb.get().read().read(),
+    };
+    let (size, background, circle) = &*animation.get().read_unchecked();
     let size = size.read();
     let background = background.read();
     let circle = circle.read();
 
-    let border = if focus.is_focused_with_keyboard() {
+    let border = if focus.is_focused_with_keyboard() && !props.disabled {
         if props.enabled {
             format!("2 inner {}", theme.enabled_focus_border_fill)
         } else {
@@ -206,12 +339,16 @@ pub fn Switch(props: SwitchProps) -> Element {
             corner_radius: "50",
             background: "{background}",
             border: "{border}",
+            opacity: if props.disabled { "0.5" },
             onmousedown,
+            onglobalmousemove,
+            onglobalclick,
             onmouseenter,
             onmouseleave,
             onkeydown,
             onclick,
             a11y_id,
+            a11y_focusable: if props.disabled { "false" },
             offset_x: "{offset_x}",
             main_align: "center",
             rect {
@@ -266,4 +403,123 @@ mod test {
         // Check if after clicking again it is now disabled
         assert_eq!(label.get(0).text(), Some("false"));
     }
+
+    #[tokio::test]
+    pub async fn switch_drag() {
+        fn switch_app() -> Element {
+            let mut enabled = use_signal(|| false);
+
+            rsx!(
+                Switch {
+                    enabled: *enabled.read(),
+                    ontoggled: move |_| {
+                        enabled.toggle();
+                    }
+                }
+                label {
+                    "{enabled}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(switch_app);
+        let root = utils.root();
+        let label = root.get(1);
+        utils.wait_for_update().await;
+
+        // Drag the thumb to the end
+        for (name, x) in [
+            (EventName::MouseDown, 10.),
+            (EventName::MouseMove, 25.),
+            (EventName::MouseMove, 40.),
+            (EventName::MouseUp, 40.),
+        ] {
+            utils.push_event(TestEvent::Mouse {
+                name,
+                cursor: (x, 12.).into(),
+                button: Some(MouseButton::Left),
+            });
+            utils.wait_for_update().await;
+        }
+        assert_eq!(label.get(0).text(), Some("true"));
+
+        // Dragging it a little bit back and forth doesn't toggle it
+        for (name, x) in [
+            (EventName::MouseDown, 35.),
+            (EventName::MouseMove, 30.),
+            (EventName::MouseMove, 35.),
+            (EventName::MouseUp, 36.),
+        ] {
+            utils.push_event(TestEvent::Mouse {
+                name,
+                cursor: (x, 12.).into(),
+                button: Some(MouseButton::Left),
+            });
+            utils.wait_for_update().await;
+        }
+        assert_eq!(label.get(0).text(), Some("true"));
+
+        // Drag it back to the start, releasing it outside the switch
+        for (name, x) in [
+            (EventName::MouseDown, 35.),
+            (EventName::MouseMove, 20.),
+            (EventName::MouseMove, 0.),
+            (EventName::MouseUp, 0.),
+        ] {
+            utils.push_event(TestEvent::Mouse {
+                name,
+                cursor: (x, 12.).into(),
+                button: Some(MouseButton::Left),
+            });
+            utils.wait_for_update().await;
+        }
+        assert_eq!(label.get(0).text(), Some("false"));
+    }
+
+    #[tokio::test]
+    pub async fn disabled_switch() {
+        fn switch_app() -> Element {
+            let mut enabled = use_signal(|| false);
+
+            rsx!(
+                Switch {
+                    enabled: *enabled.read(),
+                    disabled: true,
+                    ontoggled: move |_| {
+                        enabled.toggle();
+                    }
+                }
+                label {
+                    "{enabled}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(switch_app);
+        let root = utils.root();
+        let label = root.get(1);
+        utils.wait_for_update().await;
+
+        utils.click_cursor((15., 15.)).await;
+        assert_eq!(label.get(0).text(), Some("false"));
+
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (10., 12.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.move_cursor((40., 12.)).await;
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseUp,
+            cursor: (40., 12.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("false"));
+
+        // It can't be focused with the keyboard either
+        utils.press(Key::Tab).await;
+        utils.press(Key::Enter).await;
+        assert_eq!(label.get(0).text(), Some("false"));
+    }
 }
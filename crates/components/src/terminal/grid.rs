@@ -0,0 +1,667 @@
+use std::collections::VecDeque;
+
+use freya_engine::prelude::Color;
+use vte::{
+    Params,
+    Parser,
+    Perform,
+};
+
+/// Color of a [`TerminalCell`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TerminalColor {
+    /// Default foreground or background color of the theme.
+    #[default]
+    Default,
+    /// One of the 256 colors of the ANSI palette, the first 16 come from the theme.
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl TerminalColor {
+    /// Get the color, using the given 16 colors of the theme for the first ones of the palette.
+    pub fn resolve(&self, palette: &[Color], default: Color) -> Color {
+        match *self {
+            Self::Default => default,
+            Self::Indexed(index) if index < 16 => {
+                palette.get(index as usize).copied().unwrap_or(default)
+            }
+            // 6x6x6 color cube
+            Self::Indexed(index) if index < 232 => {
+                let index = index - 16;
+                let level = |value: u8| if value == 0 { 0 } else { value * 40 + 55 };
+                Color::from_rgb(level(index / 36), level(index / 6 % 6), level(index % 6))
+            }
+            // Grayscale ramp
+            Self::Indexed(index) => {
+                let level = (index - 232) * 10 + 8;
+                Color::from_rgb(level, level, level)
+            }
+            Self::Rgb(r, g, b) => Color::from_rgb(r, g, b),
+        }
+    }
+}
+
+/// Character of the terminal along with its style.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TerminalCell {
+    pub ch: char,
+    pub foreground: TerminalColor,
+    pub background: TerminalColor,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    /// Swap the foreground and background colors.
+    pub inverse: bool,
+}
+
+impl Default for TerminalCell {
+    fn default() -> Self {
+        Self {
+            ch: ' ',
+            foreground: TerminalColor::Default,
+            background: TerminalColor::Default,
+            bold: false,
+            italic: false,
+            underline: false,
+            inverse: false,
+        }
+    }
+}
+
+impl TerminalCell {
+    /// Empty cell with the colors of this one, used to clear the screen.
+    fn blank(&self) -> Self {
+        Self {
+            ch: ' ',
+            background: self.background,
+            ..Self::default()
+        }
+    }
+}
+
+/// Position of a cell, the line counts from the oldest line of the scrollback.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TerminalPoint {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Screen of a terminal along with the lines that scrolled out of it, updated by the ANSI escape
+/// sequences written to it.
+pub struct TerminalGrid {
+    columns: usize,
+    rows: usize,
+    /// Lines that scrolled out of the top of the screen, from the oldest.
+    scrollback: VecDeque<Vec<TerminalCell>>,
+    max_scrollback: usize,
+    screen: Vec<Vec<TerminalCell>>,
+    /// Screen and cursor of the main screen while the alternate screen is shown.
+    main_screen: Option<(Vec<Vec<TerminalCell>>, (usize, usize))>,
+    /// Row and column of the cursor in the screen.
+    cursor: (usize, usize),
+    saved_cursor: (usize, usize),
+    /// The cursor is after the last column, so the next character goes to the next line.
+    wrap_pending: bool,
+    cursor_visible: bool,
+    /// Style of the next written characters.
+    pen: TerminalCell,
+    title: Option<String>,
+    /// Bytes to send back to the program, e.g the answers to the queries of the cursor position.
+    responses: Vec<u8>,
+}
+
+impl TerminalGrid {
+    pub fn new(columns: usize, rows: usize, max_scrollback: usize) -> Self {
+        let (columns, rows) = (columns.max(1), rows.max(1));
+        Self {
+            columns,
+            rows,
+            scrollback: VecDeque::new(),
+            max_scrollback,
+            screen: vec![vec![TerminalCell::default(); columns]; rows],
+            main_screen: None,
+            cursor: (0, 0),
+            saved_cursor: (0, 0),
+            wrap_pending: false,
+            cursor_visible: true,
+            pen: TerminalCell::default(),
+            title: None,
+            responses: Vec::new(),
+        }
+    }
+
+    /// Process the output of a program.
+    pub fn advance(&mut self, parser: &mut Parser, bytes: &[u8]) {
+        for byte in bytes {
+            parser.advance(self, *byte);
+        }
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.columns, self.rows)
+    }
+
+    /// Change the number of columns and rows, lines that don't fit anymore go to the scrollback.
+    pub fn resize(&mut self, columns: usize, rows: usize) {
+        let (columns, rows) = (columns.max(1), rows.max(1));
+
+        for line in self.scrollback.iter_mut() {
+            line.resize(columns, TerminalCell::default());
+        }
+
+        // The main screen keeps its lines while the alternate screen is shown,
+        // so it is resized too and its top lines still go to the scrollback
+        if let Some((mut screen, mut cursor)) = self.main_screen.take() {
+            for line in resize_screen(&mut screen, &mut cursor, columns, rows) {
+                self.push_scrollback(line);
+            }
+            self.main_screen = Some((screen, cursor));
+        }
+
+        for line in resize_screen(&mut self.screen, &mut self.cursor, columns, rows) {
+            self.push_scrollback(line);
+        }
+
+        self.columns = columns;
+        self.rows = rows;
+        self.saved_cursor = (
+            self.saved_cursor.0.min(rows - 1),
+            self.saved_cursor.1.min(columns - 1),
+        );
+        self.wrap_pending = false;
+    }
+
+    /// Number of lines of the scrollback and the screen.
+    pub fn total_lines(&self) -> usize {
+        self.scrollback.len() + self.rows
+    }
+
+    /// Number of lines of the scrollback.
+    pub fn scrollback_lines(&self) -> usize {
+        self.scrollback.len()
+    }
+
+    /// Get a line of the scrollback or the screen, counting from the oldest line of the scrollback.
+    pub fn line(&self, line: usize) -> Option<&[TerminalCell]> {
+        if line < self.scrollback.len() {
+            self.scrollback.get(line).map(Vec::as_slice)
+        } else {
+            self.screen
+                .get(line - self.scrollback.len())
+                .map(Vec::as_slice)
+        }
+    }
+
+    /// Position of the cursor, if it is visible.
+    pub fn cursor(&self) -> Option<TerminalPoint> {
+        self.cursor_visible.then_some(TerminalPoint {
+            line: self.scrollback.len() + self.cursor.0,
+            column: self.cursor.1,
+        })
+    }
+
+    /// Title set by the program.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Take the bytes that must be sent back to the program.
+    pub fn take_responses(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.responses)
+    }
+
+    /// Get the text between two cells, both included, with the trailing spaces of every line removed.
+    pub fn text(&self, start: TerminalPoint, end: TerminalPoint) -> String {
+        let (start, end) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        (start.line..=end.line)
+            .filter_map(|line_index| {
+                let line = self.line(line_index)?;
+                let from = if line_index == start.line {
+                    start.column
+                } else {
+                    0
+                };
+                let to = if line_index == end.line {
+                    end.column + 1
+                } else {
+                    line.len()
+                };
+                let text = line
+                    .get(from.min(line.len())..to.min(line.len()))?
+                    .iter()
+                    .map(|cell| cell.ch)
+                    .collect::<String>();
+                Some(text.trim_end().to_string())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn push_scrollback(&mut self, line: Vec<TerminalCell>) {
+        // The alternate screen of full-screen programs has no scrollback
+        if self.main_screen.is_some() || self.max_scrollback == 0 {
+            return;
+        }
+        if self.scrollback.len() == self.max_scrollback {
+            self.scrollback.pop_front();
+        }
+        self.scrollback.push_back(line);
+    }
+
+    fn blank_line(&self) -> Vec<TerminalCell> {
+        vec![self.pen.blank(); self.columns]
+    }
+
+    /// Move the lines of the screen up, the top ones go to the scrollback.
+    fn scroll_up(&mut self, lines: usize) {
+        for _ in 0..lines.min(self.rows) {
+            let line = self.screen.remove(0);
+            self.push_scrollback(line);
+            self.screen.push(self.blank_line());
+        }
+    }
+
+    /// Move the lines of the screen from the given row down, the last ones are lost.
+    fn scroll_down(&mut self, from: usize, lines: usize) {
+        for _ in 0..lines.min(self.rows - from) {
+            self.screen.pop();
+            self.screen.insert(from, self.blank_line());
+        }
+    }
+
+    fn line_feed(&mut self) {
+        self.wrap_pending = false;
+        if self.cursor.0 + 1 == self.rows {
+            self.scroll_up(1);
+        } else {
+            self.cursor.0 += 1;
+        }
+    }
+
+    fn move_to(&mut self, row: usize, column: usize) {
+        self.cursor = (row.min(self.rows - 1), column.min(self.columns - 1));
+        self.wrap_pending = false;
+    }
+
+    fn clear_cells(&mut self, row: usize, columns: std::ops::Range<usize>) {
+        let blank = self.pen.blank();
+        let line = &mut self.screen[row];
+        let end = columns.end.min(line.len());
+        for cell in &mut line[columns.start.min(end)..end] {
+            *cell = blank;
+        }
+    }
+
+    fn set_alternate_screen(&mut self, enabled: bool) {
+        if enabled && self.main_screen.is_none() {
+            let screen = vec![self.blank_line(); self.rows];
+            let main_screen = std::mem::replace(&mut self.screen, screen);
+            self.main_screen = Some((main_screen, self.cursor));
+            self.move_to(0, 0);
+        } else if !enabled {
+            if let Some((screen, cursor)) = self.main_screen.take() {
+                self.screen = screen;
+                self.move_to(cursor.0, cursor.1);
+            }
+        }
+    }
+
+    fn set_graphic_rendition(&mut self, params: &Params) {
+        let mut params = params.iter();
+        if params.len() == 0 {
+            self.pen = TerminalCell::default();
+            return;
+        }
+
+        while let Some(param) = params.next() {
+            match param[0] {
+                0 => self.pen = TerminalCell::default(),
+                1 => self.pen.bold = true,
+                3 => self.pen.italic = true,
+                4 => self.pen.underline = true,
+                7 => self.pen.inverse = true,
+                22 => self.pen.bold = false,
+                23 => self.pen.italic = false,
+                24 => self.pen.underline = false,
+                27 => self.pen.inverse = false,
+                code @ 30..=37 => self.pen.foreground = TerminalColor::Indexed(code as u8 - 30),
+                38 => self.pen.foreground = extended_color(param, &mut params),
+                39 => self.pen.foreground = TerminalColor::Default,
+                code @ 40..=47 => self.pen.background = TerminalColor::Indexed(code as u8 - 40),
+                48 => self.pen.background = extended_color(param, &mut params),
+                49 => self.pen.background = TerminalColor::Default,
+                code @ 90..=97 => self.pen.foreground = TerminalColor::Indexed(code as u8 - 90 + 8),
+                code @ 100..=107 => {
+                    self.pen.background = TerminalColor::Indexed(code as u8 - 100 + 8)
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Parse a 256 colors (`38;5;n`) or true color (`38;2;r;g;b`) parameter, either separated
+/// by semicolons or colons.
+fn extended_color<'a>(
+    param: &[u16],
+    params: &mut impl Iterator<Item = &'a [u16]>,
+) -> TerminalColor {
+    let values = if param.len() > 1 {
+        param[1..].to_vec()
+    } else {
+        let kind = params.next().map(|param| param[0]);
+        let count = match kind {
+            Some(5) => 1,
+            Some(2) => 3,
+            _ => 0,
+        };
+        kind.into_iter()
+            .chain(params.take(count).map(|param| param[0]))
+            .collect()
+    };
+
+    match values.as_slice() {
+        [5, index, ..] => TerminalColor::Indexed(*index as u8),
+        [2, r, g, b, ..] => TerminalColor::Rgb(*r as u8, *g as u8, *b as u8),
+        _ => TerminalColor::Default,
+    }
+}
+
+impl Perform for TerminalGrid {
+    fn print(&mut self, ch: char) {
+        if self.wrap_pending {
+            self.cursor.1 = 0;
+            self.line_feed();
+        }
+        let (row, column) = self.cursor;
+        self.screen[row][column] = TerminalCell { ch, ..self.pen };
+        if column + 1 == self.columns {
+            self.wrap_pending = true;
+        } else {
+            self.cursor.1 += 1;
+        }
+    }
+
+    fn execute(&mut self, byte: u8) {
+        match byte {
+            // Backspace
+            0x08 => self.move_to(self.cursor.0, self.cursor.1.saturating_sub(1)),
+            // Tab
+            0x09 => self.move_to(self.cursor.0, (self.cursor.1 / 8 + 1) * 8),
+            // Line feed, vertical tab and form feed
+            0x0A..=0x0C => self.line_feed(),
+            // Carriage return
+            0x0D => self.move_to(self.cursor.0, 0),
+            _ => {}
+        }
+    }
+
+    fn csi_dispatch(&mut self, params: &Params, intermediates: &[u8], ignore: bool, action: char) {
+        if ignore {
+            return;
+        }
+
+        let values = params
+            .iter()
+            .map(|param| param[0] as usize)
+            .collect::<Vec<_>>();
+        // Get a parameter, counts and positions are never 0
+        let arg = |index: usize| values.get(index).copied().unwrap_or(0).max(1);
+        let (row, column) = self.cursor;
+
+        match (intermediates, action) {
+            ([], 'A') => self.move_to(row.saturating_sub(arg(0)), column),
+            ([], 'B' | 'e') => self.move_to(row + arg(0), column),
+            ([], 'C' | 'a') => self.move_to(row, column + arg(0)),
+            ([], 'D') => self.move_to(row, column.saturating_sub(arg(0))),
+            ([], 'E') => self.move_to(row + arg(0), 0),
+            ([], 'F') => self.move_to(row.saturating_sub(arg(0)), 0),
+            ([], 'G' | '`') => self.move_to(row, arg(0) - 1),
+            ([], 'd') => self.move_to(arg(0) - 1, column),
+            ([], 'H' | 'f') => self.move_to(arg(0) - 1, arg(1) - 1),
+            ([], 'J') => match values.first().copied().unwrap_or(0) {
+                0 => {
+                    self.clear_cells(row, column..self.columns);
+                    for row in row + 1..self.rows {
+                        self.clear_cells(row, 0..self.columns);
+                    }
+                }
+                1 => {
+                    for row in 0..row {
+                        self.clear_cells(row, 0..self.columns);
+                    }
+                    self.clear_cells(row, 0..column + 1);
+                }
+                mode => {
+                    for row in 0..self.rows {
+                        self.clear_cells(row, 0..self.columns);
+                    }
+                    if mode == 3 {
+                        self.scrollback.clear();
+                    }
+                }
+            },
+            ([], 'K') => match values.first().copied().unwrap_or(0) {
+                0 => self.clear_cells(row, column..self.columns),
+                1 => self.clear_cells(row, 0..column + 1),
+                _ => self.clear_cells(row, 0..self.columns),
+            },
+            ([], 'L') => self.scroll_down(row, arg(0)),
+            // Deleted lines never go to the scrollback
+            ([], 'M') => {
+                for _ in 0..arg(0).min(self.rows - row) {
+                    self.screen.remove(row);
+                    self.screen.push(self.blank_line());
+                }
+            }
+            ([], 'S') => self.scroll_up(arg(0)),
+            ([], 'T') => self.scroll_down(0, arg(0)),
+            ([], 'P') => {
+                let blank = self.pen.blank();
+                let line = &mut self.screen[row];
+                for _ in 0..arg(0).min(self.columns - column) {
+                    line.remove(column);
+                    line.push(blank);
+                }
+            }
+            ([], '@') => {
+                let blank = self.pen.blank();
+                let line = &mut self.screen[row];
+                for _ in 0..arg(0).min(self.columns - column) {
+                    line.pop();
+                    line.insert(column, blank);
+                }
+            }
+            ([], 'X') => self.clear_cells(row, column..column + arg(0)),
+            ([], 'm') => self.set_graphic_rendition(params),
+            ([], 's') => self.saved_cursor = self.cursor,
+            ([], 'u') => self.move_to(self.saved_cursor.0, self.saved_cursor.1),
+            // Report the position of the cursor
+            ([], 'n') if values.first() == Some(&6) => {
+                self.responses
+                    .extend(format!("\x1b[{};{}R", row + 1, column + 1).into_bytes());
+            }
+            ([b'?'], 'h' | 'l') => {
+                let enabled = action == 'h';
+                for mode in &values {
+                    match mode {
+                        25 => self.cursor_visible = enabled,
+                        47 | 1047 | 1049 => self.set_alternate_screen(enabled),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn esc_dispatch(&mut self, intermediates: &[u8], _ignore: bool, byte: u8) {
+        match (intermediates, byte) {
+            ([], b'7') => self.saved_cursor = self.cursor,
+            ([], b'8') => self.move_to(self.saved_cursor.0, self.saved_cursor.1),
+            ([], b'D') => self.line_feed(),
+            ([], b'E') => {
+                self.cursor.1 = 0;
+                self.line_feed();
+            }
+            ([], b'M') => {
+                if self.cursor.0 == 0 {
+                    self.scroll_down(0, 1);
+                } else {
+                    self.cursor.0 -= 1;
+                }
+            }
+            ([], b'c') => {
+                *self = Self::new(self.columns, self.rows, self.max_scrollback);
+            }
+            _ => {}
+        }
+    }
+
+    fn osc_dispatch(&mut self, params: &[&[u8]], _bell_terminated: bool) {
+        if let [b"0" | b"2", title, ..] = params {
+            self.title = Some(String::from_utf8_lossy(title).into_owned());
+        }
+    }
+}
+
+/// Resize the lines of a screen and keep the cursor in it, returning the top lines that don't fit anymore.
+fn resize_screen(
+    screen: &mut Vec<Vec<TerminalCell>>,
+    cursor: &mut (usize, usize),
+    columns: usize,
+    rows: usize,
+) -> Vec<Vec<TerminalCell>> {
+    for line in screen.iter_mut() {
+        line.resize(columns, TerminalCell::default());
+    }
+
+    let mut removed = Vec::new();
+    while screen.len() > rows {
+        if cursor.0 > 0 {
+            removed.push(screen.remove(0));
+            cursor.0 -= 1;
+        } else {
+            screen.pop();
+        }
+    }
+    while screen.len() < rows {
+        screen.push(vec![TerminalCell::default(); columns]);
+    }
+
+    *cursor = (cursor.0.min(rows - 1), cursor.1.min(columns - 1));
+    removed
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn grid_with(columns: usize, rows: usize, output: &str) -> TerminalGrid {
+        let mut grid = TerminalGrid::new(columns, rows, 100);
+        grid.advance(&mut Parser::new(), output.as_bytes());
+        grid
+    }
+
+    fn line_text(grid: &TerminalGrid, line: usize) -> String {
+        grid.line(line)
+            .unwrap()
+            .iter()
+            .map(|cell| cell.ch)
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    pub fn wrap_and_scrollback() {
+        let grid = grid_with(5, 2, "Hello World\r\nBye");
+
+        assert_eq!(grid.scrollback_lines(), 2);
+        assert_eq!(line_text(&grid, 0), "Hello");
+        assert_eq!(line_text(&grid, 1), " Worl");
+        assert_eq!(line_text(&grid, 2), "d");
+        assert_eq!(line_text(&grid, 3), "Bye");
+        assert_eq!(grid.cursor(), Some(TerminalPoint { line: 3, column: 3 }));
+    }
+
+    #[test]
+    pub fn colors_and_styles() {
+        let grid = grid_with(10, 1, "\x1b[1;31mA\x1b[38;5;200;48;2;1;2;3mB\x1b[0mC");
+        let line = grid.line(0).unwrap();
+
+        assert_eq!(line[0].foreground, TerminalColor::Indexed(1));
+        assert!(line[0].bold);
+        assert_eq!(line[1].foreground, TerminalColor::Indexed(200));
+        assert_eq!(line[1].background, TerminalColor::Rgb(1, 2, 3));
+        assert_eq!(
+            line[2],
+            TerminalCell {
+                ch: 'C',
+                ..TerminalCell::default()
+            }
+        );
+
+        assert_eq!(
+            TerminalColor::Indexed(196).resolve(&[], Color::BLACK),
+            Color::from_rgb(255, 0, 0)
+        );
+        assert_eq!(
+            TerminalColor::Indexed(232).resolve(&[], Color::BLACK),
+            Color::from_rgb(8, 8, 8)
+        );
+    }
+
+    #[test]
+    pub fn cursor_movement_and_erasing() {
+        let grid = grid_with(10, 3, "one\r\ntwo\r\nthree\x1b[2;2H\x1b[K\x1b[1;1H\x1b[2P");
+
+        assert_eq!(line_text(&grid, 0), "e");
+        assert_eq!(line_text(&grid, 1), "t");
+        assert_eq!(line_text(&grid, 2), "three");
+    }
+
+    #[test]
+    pub fn alternate_screen() {
+        let mut grid = grid_with(10, 2, "main\x1b[?1049hfull\x1b[?25l");
+        assert_eq!(line_text(&grid, 0), "full");
+        assert_eq!(grid.cursor(), None);
+
+        grid.advance(&mut Parser::new(), b"\x1b[?1049l");
+        assert_eq!(line_text(&grid, 0), "main");
+    }
+
+    #[test]
+    pub fn resize_alternate_screen() {
+        let mut grid = grid_with(10, 3, "one\r\ntwo\r\nthree\x1b[?1049hfull");
+        grid.resize(4, 2);
+
+        // The top line of the main screen goes to the scrollback
+        assert_eq!(grid.scrollback_lines(), 1);
+        assert_eq!(line_text(&grid, 0), "one");
+        assert_eq!(line_text(&grid, 1), "full");
+
+        grid.advance(&mut Parser::new(), b"\x1b[?1049l");
+        assert_eq!(line_text(&grid, 1), "two");
+        assert_eq!(line_text(&grid, 2), "thre");
+        assert_eq!(grid.cursor(), Some(TerminalPoint { line: 2, column: 3 }));
+    }
+
+    #[test]
+    pub fn selection_text() {
+        let grid = grid_with(10, 3, "first\r\nsecond\r\nthird");
+
+        assert_eq!(
+            grid.text(
+                TerminalPoint { line: 2, column: 2 },
+                TerminalPoint { line: 0, column: 3 }
+            ),
+            "st\nsecond\nthi"
+        );
+    }
+}
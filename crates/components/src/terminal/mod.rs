@@ -0,0 +1,838 @@
+mod grid;
+mod pty;
+
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use dioxus::prelude::*;
+use dioxus_clipboard::prelude::use_clipboard;
+use freya_core::{
+    custom_attributes::CanvasRunnerContext,
+    parsing::Parse,
+};
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        keyboard::{
+            Code,
+            Key,
+            Modifiers,
+        },
+        KeyboardData,
+        KeyboardEvent,
+        MouseEvent,
+        WheelEvent,
+    },
+};
+use freya_engine::prelude::*;
+use freya_hooks::{
+    use_applied_theme,
+    use_canvas_with_deps,
+    use_focus,
+    use_node_signal,
+    use_platform,
+    TerminalTheme,
+    TerminalThemeWith,
+};
+pub use grid::*;
+use pty::{
+    PtyOutput,
+    TerminalPty,
+};
+use tokio::sync::mpsc::unbounded_channel;
+
+use crate::charts::parse_palette;
+
+/// Lines scrolled with every step of the mouse wheel.
+const WHEEL_SCROLL_LINES: usize = 3;
+/// Glyphs kept in the cache, the least recently used ones are dropped after that.
+const MAX_CACHED_GLYPHS: usize = 4096;
+
+/// Screen of the terminal along with the parser of the output of the program.
+struct TerminalState {
+    grid: TerminalGrid,
+    parser: vte::Parser,
+    /// Logical size of a cell, measured when rendering.
+    cell_size: (f32, f32),
+}
+
+/// Colors and font of the terminal, resolved from its theme.
+#[derive(Clone, PartialEq)]
+struct TerminalStyle {
+    background: Color,
+    foreground: Color,
+    cursor: Color,
+    selection_background: Color,
+    palette: Vec<Color>,
+    font_size: f32,
+    font_family: String,
+}
+
+struct Glyph {
+    paragraph: Paragraph,
+    /// Value of [GlyphCache::uses] the last time it was drawn.
+    last_used: u64,
+}
+
+// SAFETY: Glyphs are only created and painted inside the canvas runner, which is only called from the render thread,
+// the component never locks the cache itself. Any other thread can at most drop the cache along with the last runner,
+// which only releases the references of the paragraph to the fonts and Skia counts those atomically.
+// The cache is behind a mutex, so the glyphs don't need to be `Sync`.
+unsafe impl Send for Glyph {}
+
+type GlyphKey = (char, (u8, u8, u8, u8), bool, bool);
+
+/// Laid out characters, so every character with the same style is only shaped once.
+#[derive(Default)]
+struct GlyphCache {
+    /// Physical font size and family of the cached glyphs.
+    font: (f32, String),
+    cell_size: Option<(f32, f32)>,
+    glyphs: HashMap<GlyphKey, Glyph>,
+    /// Number of glyphs drawn so far.
+    uses: u64,
+}
+
+impl GlyphCache {
+    /// Empty the cache if the font changed, e.g because the scale factor changed.
+    fn prepare(&mut self, font_size: f32, font_family: &str) {
+        if self.font.0 != font_size || self.font.1 != font_family {
+            self.font = (font_size, font_family.to_string());
+            self.cell_size = None;
+            self.glyphs.clear();
+            self.uses = 0;
+        }
+    }
+
+    fn paragraph(
+        &self,
+        font_collection: &FontCollection,
+        text: &str,
+        color: Color,
+        bold: bool,
+        italic: bool,
+    ) -> Paragraph {
+        let mut text_style = TextStyle::new();
+        text_style.set_color(color);
+        text_style.set_font_size(self.font.0);
+        text_style.set_font_families(&[self.font.1.as_str()]);
+        text_style.set_font_style(FontStyle::new(
+            if bold { Weight::BOLD } else { Weight::NORMAL },
+            Width::NORMAL,
+            if italic {
+                Slant::Italic
+            } else {
+                Slant::Upright
+            },
+        ));
+
+        let mut paragraph_style = ParagraphStyle::default();
+        paragraph_style.set_max_lines(1);
+        paragraph_style.set_text_style(&text_style);
+
+        let mut paragraph_builder =
+            ParagraphBuilder::new(&paragraph_style, font_collection.clone());
+        paragraph_builder.add_text(text);
+        let mut paragraph = paragraph_builder.build();
+        paragraph.layout(self.font.0 * 4.);
+        paragraph
+    }
+
+    /// Physical size of a cell, from the size of a character of the font.
+    fn cell_size(&mut self, font_collection: &FontCollection) -> (f32, f32) {
+        if let Some(cell_size) = self.cell_size {
+            return cell_size;
+        }
+        let paragraph = self.paragraph(font_collection, "M", Color::BLACK, false, false);
+        let cell_size = (
+            paragraph.max_intrinsic_width().max(1.),
+            paragraph.height().max(1.),
+        );
+        self.cell_size = Some(cell_size);
+        cell_size
+    }
+
+    fn glyph(
+        &mut self,
+        font_collection: &FontCollection,
+        ch: char,
+        color: Color,
+        bold: bool,
+        italic: bool,
+    ) -> &Paragraph {
+        let key = (
+            ch,
+            (color.a(), color.r(), color.g(), color.b()),
+            bold,
+            italic,
+        );
+        if !self.glyphs.contains_key(&key) {
+            if self.glyphs.len() >= MAX_CACHED_GLYPHS {
+                self.evict();
+            }
+            let paragraph = self.paragraph(
+                font_collection,
+                ch.encode_utf8(&mut [0; 4]),
+                color,
+                bold,
+                italic,
+            );
+            self.glyphs.insert(
+                key,
+                Glyph {
+                    paragraph,
+                    last_used: 0,
+                },
+            );
+        }
+        self.uses += 1;
+        let glyph = self.glyphs.get_mut(&key).unwrap();
+        glyph.last_used = self.uses;
+        &glyph.paragraph
+    }
+
+    /// Drop the least recently used quarter of the glyphs,
+    /// so colorful output doesn't make the cache grow forever.
+    fn evict(&mut self) {
+        let mut uses = self
+            .glyphs
+            .values()
+            .map(|glyph| glyph.last_used)
+            .collect::<Vec<_>>();
+        let evicted = (uses.len() / 4).max(1);
+        let (_, threshold, _) = uses.select_nth_unstable(evicted - 1);
+        let threshold = *threshold;
+        self.glyphs.retain(|_, glyph| glyph.last_used > threshold);
+    }
+}
+
+/// Translate a key press to the bytes that a terminal sends to the program.
+pub(crate) fn key_to_bytes(data: &KeyboardData) -> Option<Vec<u8>> {
+    let ctrl = data.modifiers.contains(Modifiers::CONTROL);
+    let alt = data.modifiers.contains(Modifiers::ALT);
+
+    let bytes: Vec<u8> = match &data.key {
+        Key::Character(text) if ctrl => {
+            let ch = text.chars().next()?.to_ascii_lowercase();
+            match ch {
+                'a'..='z' => vec![ch as u8 - b'a' + 1],
+                '@' | ' ' => vec![0],
+                '[' => vec![0x1b],
+                '\\' => vec![0x1c],
+                ']' => vec![0x1d],
+                _ => return None,
+            }
+        }
+        Key::Character(text) => text.as_bytes().to_vec(),
+        Key::Enter => b"\r".to_vec(),
+        Key::Backspace => b"\x7f".to_vec(),
+        Key::Tab => b"\t".to_vec(),
+        Key::Escape => b"\x1b".to_vec(),
+        Key::ArrowUp => b"\x1b[A".to_vec(),
+        Key::ArrowDown => b"\x1b[B".to_vec(),
+        Key::ArrowRight => b"\x1b[C".to_vec(),
+        Key::ArrowLeft => b"\x1b[D".to_vec(),
+        Key::Home => b"\x1b[H".to_vec(),
+        Key::End => b"\x1b[F".to_vec(),
+        Key::Insert => b"\x1b[2~".to_vec(),
+        Key::Delete => b"\x1b[3~".to_vec(),
+        Key::PageUp => b"\x1b[5~".to_vec(),
+        Key::PageDown => b"\x1b[6~".to_vec(),
+        Key::F1 => b"\x1bOP".to_vec(),
+        Key::F2 => b"\x1bOQ".to_vec(),
+        Key::F3 => b"\x1bOR".to_vec(),
+        Key::F4 => b"\x1bOS".to_vec(),
+        _ => return None,
+    };
+
+    // Alt sends the key after an escape
+    if alt {
+        Some([b"\x1b".as_slice(), &bytes].concat())
+    } else {
+        Some(bytes)
+    }
+}
+
+/// Properties for the [`Terminal`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct TerminalProps {
+    /// Theme override.
+    pub theme: Option<TerminalThemeWith>,
+    /// Program to run, the default shell of the user is used otherwise.
+    #[props(into)]
+    pub command: Option<String>,
+    /// Arguments of the program.
+    #[props(default)]
+    pub args: Vec<String>,
+    /// Directory where the program runs.
+    #[props(into)]
+    pub cwd: Option<PathBuf>,
+    /// Lines kept after they scroll out of the screen. Default to `10000`.
+    #[props(default = 10000)]
+    pub scrollback: usize,
+    /// Width of the terminal. Default to `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the terminal. Default to `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Handler for when the program exits, with its exit code if it is known.
+    pub onexit: Option<EventHandler<Option<u32>>>,
+    /// Handler for when the program changes the title of the terminal.
+    pub ontitle: Option<EventHandler<String>>,
+}
+
+/// Terminal emulator that runs a program, or the shell of the user, in a pseudo terminal.
+///
+/// It supports the ANSI colors, including the 256 colors palette and true colors, the alternate
+/// screen of full-screen programs and a scrollback that can be scrolled with the mouse wheel
+/// or `Shift + PageUp`/`Shift + PageDown`. Text can be selected with the mouse and copied with `Ctrl + Shift + C`,
+/// `Ctrl + Shift + V` pastes.
+///
+/// The program runs again when the `command`, `args` or `cwd` change and it is killed when the component is dropped.
+///
+/// Requires the `terminal` feature.
+///
+/// # Styling
+/// Inherits the [`TerminalTheme`](freya_hooks::TerminalTheme) theme.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(Terminal {
+///         command: "htop",
+///         onexit: |_| println!("Bye!")
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn Terminal(
+    TerminalProps {
+        theme,
+        command,
+        args,
+        cwd,
+        scrollback,
+        width,
+        height,
+        onexit,
+        ontitle,
+    }: TerminalProps,
+) -> Element {
+    let TerminalTheme {
+        background,
+        foreground,
+        cursor,
+        selection_background,
+        palette,
+        font_size,
+        font_family,
+    } = use_applied_theme!(&theme, terminal);
+    let platform = use_platform();
+    let mut focus = use_focus();
+    let mut clipboard = use_clipboard();
+    let (reference, size) = use_node_signal();
+    let mut version = use_signal(|| 0usize);
+    // Lines scrolled up from the bottom of the scrollback
+    let mut scroll_offset = use_signal(|| 0usize);
+    let mut selection = use_signal(|| None::<(TerminalPoint, TerminalPoint)>);
+    let mut selecting = use_signal(|| false);
+    let mut pty = use_signal(|| None::<TerminalPty>);
+    let mut output_task = use_signal(|| None::<Task>);
+
+    let state = use_hook(|| {
+        Arc::new(Mutex::new(TerminalState {
+            grid: TerminalGrid::new(80, 24, scrollback),
+            parser: vte::Parser::new(),
+            cell_size: (1., 1.),
+        }))
+    });
+
+    // The handlers are read by the task that receives the output of the program
+    let mut handlers = use_hook(|| CopyValue::new((onexit, ontitle)));
+    handlers.set((onexit, ontitle));
+
+    // Physical size of a cell, measured when rendering
+    let mut cell_size = use_signal(|| None::<(f32, f32)>);
+    let cell_size_sender = use_hook(|| {
+        let (sender, mut receiver) = unbounded_channel::<(f32, f32)>();
+        spawn(async move {
+            while let Some(size) = receiver.recv().await {
+                if *cell_size.peek() != Some(size) {
+                    cell_size.set(Some(size));
+                }
+            }
+        });
+        sender
+    });
+
+    // Fit the screen in the terminal and tell the program about its new size
+    {
+        let state = state.clone();
+        use_effect(move || {
+            let area = size.read().area;
+            let Some((cell_width, cell_height)) = cell_size() else {
+                return;
+            };
+            let columns = (area.width() / cell_width).floor() as usize;
+            let rows = (area.height() / cell_height).floor() as usize;
+            if columns == 0 || rows == 0 {
+                return;
+            }
+
+            let resized = {
+                let mut state = state.lock().unwrap();
+                let resized = state.grid.size() != (columns, rows);
+                if resized {
+                    state.grid.resize(columns, rows);
+                }
+                resized
+            };
+            if resized {
+                if let Some(pty) = &*pty.peek() {
+                    pty.resize(columns, rows);
+                }
+                version += 1;
+            }
+        });
+    }
+
+    {
+        let state = state.clone();
+        use_effect(use_reactive!(|command, args, cwd| {
+            if let Some(task) = output_task.take() {
+                task.cancel();
+            }
+
+            let terminal_size = {
+                let mut state = state.lock().unwrap();
+                let (columns, rows) = state.grid.size();
+                state.grid = TerminalGrid::new(columns, rows, scrollback);
+                state.parser = vte::Parser::new();
+                (columns, rows)
+            };
+            scroll_offset.set(0);
+            selection.set(None);
+
+            let (sender, mut receiver) = unbounded_channel();
+            match TerminalPty::spawn(
+                command.as_deref(),
+                &args,
+                cwd.as_ref(),
+                terminal_size,
+                sender,
+            ) {
+                Ok(new_pty) => pty.set(Some(new_pty)),
+                Err(err) => {
+                    tracing::error!("Failed to run the terminal program: {err}");
+                    pty.set(None);
+                    return;
+                }
+            }
+
+            let state = state.clone();
+            output_task.set(Some(spawn(async move {
+                while let Some(output) = receiver.recv().await {
+                    let (onexit, ontitle) = *handlers.peek();
+                    match output {
+                        PtyOutput::Data(bytes) => {
+                            let (responses, title) = {
+                                let mut state = state.lock().unwrap();
+                                let TerminalState { grid, parser, .. } = &mut *state;
+                                let prev_title = grid.title().map(str::to_string);
+                                grid.advance(parser, &bytes);
+                                let title = grid
+                                    .title()
+                                    .filter(|title| Some(*title) != prev_title.as_deref())
+                                    .map(str::to_string);
+                                (grid.take_responses(), title)
+                            };
+                            if !responses.is_empty() {
+                                if let Some(pty) = &mut *pty.write() {
+                                    pty.write(&responses);
+                                }
+                            }
+                            if let (Some(ontitle), Some(title)) = (ontitle, title) {
+                                ontitle.call(title);
+                            }
+                            version += 1;
+                        }
+                        PtyOutput::Exited(exit_code) => {
+                            if let Some(onexit) = onexit {
+                                onexit.call(exit_code);
+                            }
+                        }
+                    }
+                }
+            })));
+        }));
+    }
+
+    let glyphs = use_hook(|| Arc::new(Mutex::new(GlyphCache::default())));
+    let style = TerminalStyle {
+        background: Color::parse(&background).unwrap_or(Color::BLACK),
+        foreground: Color::parse(&foreground).unwrap_or(Color::WHITE),
+        cursor: Color::parse(&cursor).unwrap_or(Color::WHITE),
+        selection_background: Color::parse(&selection_background).unwrap_or(Color::GRAY),
+        palette: parse_palette(&palette),
+        font_size: font_size.parse().unwrap_or(14.),
+        font_family: font_family.to_string(),
+    };
+    let is_focused = focus.is_focused();
+
+    let canvas = use_canvas_with_deps(
+        &(version(), scroll_offset(), selection(), style, is_focused),
+        {
+            let state = state.clone();
+            move |(_, scroll_offset, selection, style, is_focused)| {
+                platform.invalidate_drawing_area(size.peek().area);
+                platform.request_animation_frame();
+                let state = state.clone();
+                let glyphs = glyphs.clone();
+                let cell_size_sender = cell_size_sender.clone();
+                move |ctx: &mut CanvasRunnerContext| {
+                    let mut state = state.lock().unwrap();
+                    let mut glyphs = glyphs.lock().unwrap();
+
+                    glyphs.prepare(style.font_size * ctx.scale_factor, &style.font_family);
+                    let (cell_width, cell_height) = glyphs.cell_size(ctx.font_collection);
+                    state.cell_size = (
+                        cell_width / ctx.scale_factor,
+                        cell_height / ctx.scale_factor,
+                    );
+                    cell_size_sender.send((cell_width, cell_height)).ok();
+
+                    let mut paint = Paint::default();
+                    paint.set_color(style.background);
+                    ctx.canvas.draw_rect(
+                        Rect::from_xywh(
+                            ctx.area.min_x(),
+                            ctx.area.min_y(),
+                            ctx.area.width(),
+                            ctx.area.height(),
+                        ),
+                        &paint,
+                    );
+
+                    let grid = &state.grid;
+                    let (_, rows) = grid.size();
+                    let first_line =
+                        grid.scrollback_lines() - scroll_offset.min(grid.scrollback_lines());
+                    let selection = selection.map(|(start, end)| (start.min(end), start.max(end)));
+
+                    for row in 0..rows {
+                        let line_index = first_line + row;
+                        let Some(line) = grid.line(line_index) else {
+                            break;
+                        };
+                        let y = ctx.area.min_y() + row as f32 * cell_height;
+
+                        for (column, cell) in line.iter().enumerate() {
+                            let x = ctx.area.min_x() + column as f32 * cell_width;
+                            let point = TerminalPoint {
+                                line: line_index,
+                                column,
+                            };
+                            let is_selected = selection
+                                .map(|(start, end)| start <= point && point <= end)
+                                .unwrap_or_default();
+
+                            let mut foreground =
+                                cell.foreground.resolve(&style.palette, style.foreground);
+                            let mut background =
+                                cell.background.resolve(&style.palette, style.background);
+                            if cell.inverse {
+                                std::mem::swap(&mut foreground, &mut background);
+                            }
+                            if is_selected {
+                                background = style.selection_background;
+                            }
+
+                            let cell_rect = Rect::from_xywh(x, y, cell_width, cell_height);
+                            if background != style.background {
+                                paint.set_color(background);
+                                ctx.canvas.draw_rect(cell_rect, &paint);
+                            }
+
+                            if cell.ch != ' ' {
+                                glyphs
+                                    .glyph(
+                                        ctx.font_collection,
+                                        cell.ch,
+                                        foreground,
+                                        cell.bold,
+                                        cell.italic,
+                                    )
+                                    .paint(ctx.canvas, (x, y));
+                            }
+
+                            if cell.underline {
+                                paint.set_color(foreground);
+                                let underline_y = y + cell_height - ctx.scale_factor;
+                                ctx.canvas.draw_line(
+                                    (x, underline_y),
+                                    (x + cell_width, underline_y),
+                                    &paint,
+                                );
+                            }
+                        }
+                    }
+
+                    if let Some(cursor) = grid
+                        .cursor()
+                        .filter(|cursor| (first_line..first_line + rows).contains(&cursor.line))
+                    {
+                        let x = ctx.area.min_x() + cursor.column as f32 * cell_width;
+                        let y = ctx.area.min_y() + (cursor.line - first_line) as f32 * cell_height;
+                        let mut paint = Paint::default();
+                        paint.set_anti_alias(true);
+                        paint.set_color(style.cursor);
+                        // A hollow cursor shows that the terminal is not focused
+                        if !is_focused {
+                            paint.set_style(PaintStyle::Stroke);
+                            paint.set_stroke_width(ctx.scale_factor);
+                        }
+                        ctx.canvas
+                            .draw_rect(Rect::from_xywh(x, y, cell_width, cell_height), &paint);
+                        if is_focused {
+                            if let Some(cell) = grid
+                                .line(cursor.line)
+                                .and_then(|line| line.get(cursor.column))
+                            {
+                                if cell.ch != ' ' {
+                                    glyphs
+                                        .glyph(
+                                            ctx.font_collection,
+                                            cell.ch,
+                                            style.background,
+                                            cell.bold,
+                                            cell.italic,
+                                        )
+                                        .paint(ctx.canvas, (x, y));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    );
+
+    let mut scroll_by = {
+        let state = state.clone();
+        move |lines: isize| {
+            let scrollback_lines = state.lock().unwrap().grid.scrollback_lines();
+            let offset =
+                (*scroll_offset.peek() as isize + lines).clamp(0, scrollback_lines as isize);
+            scroll_offset.set(offset as usize);
+        }
+    };
+
+    let onkeydown = {
+        let state = state.clone();
+        let mut scroll_by = scroll_by.clone();
+        move |e: KeyboardEvent| {
+            if !focus.is_focused() {
+                return;
+            }
+            e.stop_propagation();
+            let ctrl_shift = e
+                .data
+                .modifiers
+                .contains(Modifiers::CONTROL | Modifiers::SHIFT);
+            let shift = e.data.modifiers.contains(Modifiers::SHIFT);
+            let rows = state.lock().unwrap().grid.size().1 as isize;
+
+            match e.data.code {
+                Code::KeyC if ctrl_shift => {
+                    if let Some((start, end)) = *selection.peek() {
+                        let text = state.lock().unwrap().grid.text(start, end);
+                        clipboard.set(text).ok();
+                    }
+                }
+                Code::KeyV if ctrl_shift => {
+                    if let (Ok(text), Some(pty)) = (clipboard.get(), &mut *pty.write()) {
+                        pty.write(text.as_bytes());
+                    }
+                }
+                Code::PageUp if shift => scroll_by(rows),
+                Code::PageDown if shift => scroll_by(-rows),
+                _ => {
+                    if let Some(bytes) = key_to_bytes(&e.data) {
+                        if let Some(pty) = &mut *pty.write() {
+                            pty.write(&bytes);
+                        }
+                        scroll_offset.set(0);
+                        selection.set(None);
+                    }
+                }
+            }
+        }
+    };
+
+    // Tab is sent to the program instead of moving the focus
+    let onglobalkeydown = move |e: KeyboardEvent| {
+        if e.data.key == Key::Tab && focus.is_focused() {
+            focus.prevent_navigation();
+        }
+    };
+
+    let onwheel = move |e: WheelEvent| {
+        let lines = if e.get_delta_y() > 0. {
+            WHEEL_SCROLL_LINES as isize
+        } else {
+            -(WHEEL_SCROLL_LINES as isize)
+        };
+        scroll_by(lines);
+    };
+
+    // Cell under the mouse, counting from the oldest line of the scrollback
+    let point_at = {
+        let state = state.clone();
+        move |e: &MouseEvent| {
+            let coordinates = e.get_element_coordinates();
+            let state = state.lock().unwrap();
+            let (columns, rows) = state.grid.size();
+            let (cell_width, cell_height) = state.cell_size;
+            let first_line = state.grid.scrollback_lines()
+                - (*scroll_offset.peek()).min(state.grid.scrollback_lines());
+            TerminalPoint {
+                line: first_line + ((coordinates.y as f32 / cell_height) as usize).min(rows - 1),
+                column: ((coordinates.x as f32 / cell_width) as usize).min(columns - 1),
+            }
+        }
+    };
+
+    let onmousedown = {
+        let point_at = point_at.clone();
+        move |e: MouseEvent| {
+            focus.request_focus();
+            let point = point_at(&e);
+            selection.set(Some((point, point)));
+            selecting.set(true);
+        }
+    };
+
+    let onmousemove = move |e: MouseEvent| {
+        if *selecting.peek() {
+            let point = point_at(&e);
+            if let Some((start, _)) = *selection.peek() {
+                selection.set(Some((start, point)));
+            }
+        }
+    };
+
+    let onglobalmouseup = move |_| {
+        if *selecting.peek() {
+            selecting.set(false);
+            // A click without dragging selects nothing
+            if matches!(*selection.peek(), Some((start, end)) if start == end) {
+                selection.set(None);
+            }
+        }
+    };
+
+    rsx!(rect {
+        reference,
+        width,
+        height,
+        background,
+        a11y_id: focus.attribute(),
+        a11y_role: "terminal",
+        canvas_reference: canvas.attribute(),
+        onkeydown,
+        onglobalkeydown,
+        onwheel,
+        onmousedown,
+        onmousemove,
+        onglobalmouseup,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use freya_elements::events::{
+        keyboard::{
+            Code,
+            Key,
+            Modifiers,
+        },
+        KeyboardData,
+    };
+    use freya_engine::prelude::{
+        Color,
+        FontCollection,
+        FontMgr,
+    };
+
+    use super::{
+        key_to_bytes,
+        GlyphCache,
+        MAX_CACHED_GLYPHS,
+    };
+
+    #[test]
+    pub fn key_bytes() {
+        let key = |key: Key, modifiers: Modifiers| {
+            key_to_bytes(&KeyboardData::new(key, Code::Unidentified, modifiers))
+        };
+
+        assert_eq!(
+            key(Key::Character("a".to_string()), Modifiers::default()),
+            Some(b"a".to_vec())
+        );
+        assert_eq!(
+            key(Key::Character("c".to_string()), Modifiers::CONTROL),
+            Some(vec![3])
+        );
+        assert_eq!(
+            key(Key::Character("b".to_string()), Modifiers::ALT),
+            Some(b"\x1bb".to_vec())
+        );
+        assert_eq!(key(Key::Enter, Modifiers::default()), Some(b"\r".to_vec()));
+        assert_eq!(
+            key(Key::ArrowUp, Modifiers::default()),
+            Some(b"\x1b[A".to_vec())
+        );
+        assert_eq!(key(Key::Shift, Modifiers::SHIFT), None);
+    }
+
+    #[test]
+    pub fn glyph_cache() {
+        let mut font_collection = FontCollection::new();
+        font_collection.set_default_font_manager(FontMgr::default(), None);
+        let mut glyphs = GlyphCache::default();
+        glyphs.prepare(14., "Fira Sans");
+
+        // Every color is a different glyph, but the used ones are kept
+        for i in 0..MAX_CACHED_GLYPHS as u32 * 2 {
+            glyphs.glyph(&font_collection, 'a', Color::WHITE, false, false);
+            glyphs.glyph(
+                &font_collection,
+                'b',
+                Color::new(0xFF000000 | i),
+                false,
+                false,
+            );
+            assert!(glyphs.glyphs.len() <= MAX_CACHED_GLYPHS);
+        }
+        assert!(glyphs
+            .glyphs
+            .contains_key(&('a', (255, 255, 255, 255), false, false)));
+        assert!(!glyphs
+            .glyphs
+            .contains_key(&('b', (255, 0, 0, 0), false, false)));
+
+        // Changing the font empties it
+        glyphs.prepare(28., "Fira Sans");
+        assert!(glyphs.glyphs.is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+use std::{
+    io::{
+        Read,
+        Write,
+    },
+    path::PathBuf,
+};
+
+use portable_pty::{
+    native_pty_system,
+    ChildKiller,
+    CommandBuilder,
+    MasterPty,
+    PtySize,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Output of the program running in a [`TerminalPty`].
+pub(crate) enum PtyOutput {
+    Data(Vec<u8>),
+    /// The program exited, with its exit code if it is known.
+    Exited(Option<u32>),
+}
+
+/// Program running in a pseudo terminal.
+pub(crate) struct TerminalPty {
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    killer: Box<dyn ChildKiller + Send + Sync>,
+}
+
+impl TerminalPty {
+    /// Spawn a program, or the default shell of the user if there is no command.
+    /// The output is read in its own thread and sent to the given channel.
+    pub fn spawn(
+        command: Option<&str>,
+        args: &[String],
+        cwd: Option<&PathBuf>,
+        (columns, rows): (usize, usize),
+        sender: UnboundedSender<PtyOutput>,
+    ) -> Result<Self, String> {
+        let pair = native_pty_system()
+            .openpty(pty_size(columns, rows))
+            .map_err(|err| err.to_string())?;
+
+        let mut command = match command {
+            Some(command) => CommandBuilder::new(command),
+            None => CommandBuilder::new_default_prog(),
+        };
+        command.args(args);
+        if let Some(cwd) = cwd {
+            command.cwd(cwd);
+        }
+        command.env("TERM", "xterm-256color");
+
+        let mut child = pair
+            .slave
+            .spawn_command(command)
+            .map_err(|err| err.to_string())?;
+        let mut reader = pair
+            .master
+            .try_clone_reader()
+            .map_err(|err| err.to_string())?;
+        let writer = pair.master.take_writer().map_err(|err| err.to_string())?;
+        let killer = child.clone_killer();
+
+        std::thread::spawn(move || {
+            let mut buffer = [0; 8192];
+            loop {
+                match reader.read(&mut buffer) {
+                    Ok(0) | Err(_) => break,
+                    Ok(read) => {
+                        if sender
+                            .send(PtyOutput::Data(buffer[..read].to_vec()))
+                            .is_err()
+                        {
+                            return;
+                        }
+                    }
+                }
+            }
+            // The output is closed once the program exits
+            let exit_code = child.wait().ok().map(|status| status.exit_code());
+            sender.send(PtyOutput::Exited(exit_code)).ok();
+        });
+
+        Ok(Self {
+            master: pair.master,
+            writer,
+            killer,
+        })
+    }
+
+    /// Send input to the program.
+    pub fn write(&mut self, bytes: &[u8]) {
+        if let Err(err) = self
+            .writer
+            .write_all(bytes)
+            .and_then(|_| self.writer.flush())
+        {
+            tracing::error!("Failed to write to the terminal: {err}");
+        }
+    }
+
+    pub fn resize(&self, columns: usize, rows: usize) {
+        if let Err(err) = self.master.resize(pty_size(columns, rows)) {
+            tracing::error!("Failed to resize the terminal: {err}");
+        }
+    }
+}
+
+impl Drop for TerminalPty {
+    fn drop(&mut self) {
+        self.killer.kill().ok();
+    }
+}
+
+fn pty_size(columns: usize, rows: usize) -> PtySize {
+    PtySize {
+        rows: rows.max(1) as u16,
+        cols: columns.max(1) as u16,
+        pixel_width: 0,
+        pixel_height: 0,
+    }
+}
@@ -1,7 +1,9 @@
 use dioxus::prelude::*;
 use freya_hooks::{
     use_init_theme,
+    use_theme_transition,
     Theme,
+    ThemeTransition,
 };
 
 /// Properties for the [`ThemeProvider`] component.
@@ -9,14 +11,48 @@ use freya_hooks::{
 pub struct ThemeProviderProps {
     /// Theme to provide.
     pub theme: Option<Theme>,
+    /// Animate the colors when the theme is changed with `use_theme().set(...)`. Disabled by default.
+    pub transition: Option<ThemeTransition>,
     /// Inner children to provide a Theme to.
     pub children: Element,
 }
 
 /// Provides a `Theme` for all its children.
+///
+/// The theme can be swapped at runtime from any of its children with `use_theme().set(...)`.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         ThemeProvider {
+///             theme: LIGHT_THEME,
+///             transition: ThemeTransition::default(),
+///             ThemeSwitcher { }
+///         }
+///     )
+/// }
+///
+/// #[component]
+/// fn ThemeSwitcher() -> Element {
+///     let mut theme = use_theme();
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| theme.set(DARK_THEME),
+///             label {
+///                 "Dark"
+///             }
+///         }
+///     )
+/// }
+/// ```
 #[allow(non_snake_case)]
 pub fn ThemeProvider(props: ThemeProviderProps) -> Element {
-    use_init_theme(|| props.theme.unwrap_or_default());
+    let theme = use_init_theme(|| props.theme.unwrap_or_default());
+    use_theme_transition(theme, props.transition);
 
     rsx!({ props.children })
 }
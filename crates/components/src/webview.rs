@@ -0,0 +1,280 @@
+use dioxus::prelude::*;
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    webview::{
+        webview_message_script,
+        WebViewConfig,
+        WebViewContent,
+        WebViewEvent,
+        WebViewId,
+    },
+};
+use freya_elements as dioxus_elements;
+use freya_hooks::{
+    use_node_signal,
+    use_platform,
+    UsePlatform,
+};
+use tokio::sync::mpsc::unbounded_channel;
+
+/// Controls a [`WebView`], see [`use_webview_controller`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct WebViewController {
+    id: WebViewId,
+    platform: UsePlatform,
+}
+
+impl WebViewController {
+    /// Get the identifier of the webview.
+    pub fn id(&self) -> WebViewId {
+        self.id
+    }
+
+    /// Run a script in the page.
+    pub fn evaluate_script(&self, script: impl Into<String>) {
+        self.platform
+            .send(EventLoopMessage::EvaluateWebViewScript(
+                self.id,
+                script.into(),
+            ))
+            .ok();
+    }
+
+    /// Send a message to the `window.freya.onmessage` handler of the page.
+    pub fn post_message(&self, message: &str) {
+        self.evaluate_script(webview_message_script(message));
+    }
+}
+
+/// Create a [`WebViewController`] to be passed to a [`WebView`].
+pub fn use_webview_controller() -> WebViewController {
+    use_hook(|| WebViewController {
+        id: WebViewId::new(),
+        platform: UsePlatform::current(),
+    })
+}
+
+/// Properties for the [`WebView`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct WebViewProps {
+    /// Custom controller to run scripts and send messages to the page.
+    pub controller: Option<WebViewController>,
+    /// URL of the page to load.
+    #[props(into)]
+    pub url: Option<String>,
+    /// HTML of the page to load, takes precedence over the `url`.
+    #[props(into)]
+    pub html: Option<String>,
+    /// Width of the webview. Default to `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the webview. Default to `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Allow opening the developer tools of the page. Default to `false`.
+    #[props(default = false)]
+    pub devtools: bool,
+    /// Show the app behind the transparent areas of the page. Default to `false`.
+    #[props(default = false)]
+    pub transparent: bool,
+    /// Handler for the messages sent by the page with `window.freya.postMessage(message)`.
+    pub onmessage: Option<EventHandler<String>>,
+    /// Handler for when a page has been loaded, with its URL.
+    pub onload: Option<EventHandler<String>>,
+}
+
+/// Embed a native webview in the area of this element, for pages such as OAuth flows or docs.
+///
+/// The webview is a native view placed over the window, so it is always shown on top of the app
+/// and follows the element when it is moved, resized or scrolled, but it is not clipped by its parents.
+///
+/// Pages can exchange string messages with the app, `window.freya.postMessage(message)` calls the `onmessage`
+/// handler and [`WebViewController::post_message`] calls the `window.freya.onmessage` function of the page.
+///
+/// Requires the `webview` feature. Supported on Windows, macOS and Linux with X11, where GTK is required.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let controller = use_webview_controller();
+///
+///     rsx!(WebView {
+///         controller,
+///         html: "<button onclick=\"window.freya.postMessage('Hi')\">Greet</button>",
+///         onmessage: move |message: String| {
+///             controller.post_message(&format!("{message} from Freya!"));
+///         }
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn WebView(
+    WebViewProps {
+        controller,
+        url,
+        html,
+        width,
+        height,
+        devtools,
+        transparent,
+        onmessage,
+        onload,
+    }: WebViewProps,
+) -> Element {
+    let controller = controller.unwrap_or_else(use_webview_controller);
+    let platform = use_platform();
+    let (reference, size) = use_node_signal();
+    let mut created = use_signal(|| false);
+
+    let content = match (html, url) {
+        (Some(html), _) => WebViewContent::Html(html),
+        (None, Some(url)) => WebViewContent::Url(url),
+        (None, None) => WebViewContent::Html(String::new()),
+    };
+
+    // The handlers are read by the task that receives the events of the webview
+    let mut handlers = use_hook(|| CopyValue::new((onmessage, onload)));
+    handlers.set((onmessage, onload));
+
+    let sender = use_hook(|| {
+        let (sender, mut receiver) = unbounded_channel();
+        spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                let (onmessage, onload) = *handlers.peek();
+                match event {
+                    WebViewEvent::Message(message) => {
+                        if let Some(onmessage) = onmessage {
+                            onmessage.call(message);
+                        }
+                    }
+                    WebViewEvent::LoadFinished(url) => {
+                        if let Some(onload) = onload {
+                            onload.call(url);
+                        }
+                    }
+                    WebViewEvent::LoadStarted(_) => {}
+                }
+            }
+        });
+        sender
+    });
+
+    // Create the webview once the element has a size and keep it over the element
+    let initial_config = WebViewConfig::new(content.clone())
+        .with_devtools(devtools)
+        .with_transparent(transparent);
+    use_effect(move || {
+        let area = size.read().area;
+        let id = controller.id();
+        if *created.peek() {
+            let area = (!area.size.is_empty()).then_some(area);
+            platform
+                .send(EventLoopMessage::SetWebViewArea(id, area))
+                .ok();
+        } else if !area.size.is_empty() {
+            platform
+                .send(EventLoopMessage::CreateWebView {
+                    id,
+                    config: initial_config.clone(),
+                    area,
+                    sender: sender.clone(),
+                })
+                .ok();
+            created.set(true);
+        }
+    });
+
+    use_effect(use_reactive!(|content| {
+        if *created.peek() {
+            platform
+                .send(EventLoopMessage::SetWebViewContent(
+                    controller.id(),
+                    content,
+                ))
+                .ok();
+        }
+    }));
+
+    use_drop(move || {
+        platform
+            .send(EventLoopMessage::RemoveWebView(controller.id()))
+            .ok();
+    });
+
+    rsx!(rect {
+        reference,
+        width,
+        height,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn webview() {
+        fn webview_app() -> Element {
+            let controller = use_webview_controller();
+            let mut url = use_signal(|| "https://freyaui.dev".to_string());
+            let mut message = use_signal(String::new);
+
+            rsx!(
+                label {
+                    height: "20",
+                    "{message}"
+                }
+                WebView {
+                    controller,
+                    url: url(),
+                    height: "200",
+                    onmessage: move |new_message: String| {
+                        controller.post_message("Pong");
+                        message.set(new_message);
+                    }
+                }
+                rect {
+                    width: "100",
+                    height: "20",
+                    onclick: move |_| url.set("https://crates.io".to_string())
+                }
+            )
+        }
+
+        let mut utils = launch_test(webview_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Created over the area of the element
+        let webview = &utils.webviews()[0];
+        let id = webview.id;
+        assert_eq!(
+            webview.content,
+            WebViewContent::Url("https://freyaui.dev".to_string())
+        );
+        assert_eq!(
+            webview.area,
+            Some(Area::new((0., 20.).into(), (500., 200.).into()))
+        );
+
+        // Messages go both ways
+        utils.send_webview_event(id, WebViewEvent::Message("Ping".to_string()));
+        utils.wait_for_update().await;
+        assert_eq!(utils.root().get(0).get(0).text(), Some("Ping"));
+        assert_eq!(
+            utils.webviews()[0].scripts,
+            vec![webview_message_script("Pong")]
+        );
+
+        // Load another page
+        utils.click_cursor((10., 230.)).await;
+        utils.wait_for_update().await;
+        assert_eq!(
+            utils.webviews()[0].content,
+            WebViewContent::Url("https://crates.io".to_string())
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        MouseEvent,
+        PointerEvent,
+        PointerType,
+    },
+    MouseButton,
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_platform,
+    use_platform_information,
+    CaptionButtonTheme,
+    CaptionButtonThemeWith,
+};
+
+/// Action of a window caption button.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CaptionButtonKind {
+    Minimize,
+    Maximize,
+    Close,
+}
+
+/// Minimize, maximize (or restore) and close buttons for custom titlebars of undecorated windows.
+///
+/// Clicking them doesn't drag the window when placed inside a [WindowDragArea](crate::WindowDragArea).
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         rect {
+///             direction: "horizontal",
+///             width: "100%",
+///             rect {
+///                 width: "fill",
+///                 WindowDragArea {
+///                     label {
+///                         "My App"
+///                     }
+///                 }
+///             }
+///             WindowCaptionButtons { }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn WindowCaptionButtons(
+    /// Theme override.
+    theme: Option<CaptionButtonThemeWith>,
+    /// Show the minimize button. Defaults to `true`.
+    #[props(default = true)]
+    minimize: bool,
+    /// Show the maximize button. Defaults to `true`.
+    #[props(default = true)]
+    maximize: bool,
+    /// Show the close button. Defaults to `true`.
+    #[props(default = true)]
+    close: bool,
+) -> Element {
+    rsx!(
+        rect {
+            direction: "horizontal",
+            if minimize {
+                CaptionButton {
+                    kind: CaptionButtonKind::Minimize,
+                    theme: theme.clone(),
+                }
+            }
+            if maximize {
+                CaptionButton {
+                    kind: CaptionButtonKind::Maximize,
+                    theme: theme.clone(),
+                }
+            }
+            if close {
+                CaptionButton {
+                    kind: CaptionButtonKind::Close,
+                    theme,
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn CaptionButton(kind: CaptionButtonKind, theme: Option<CaptionButtonThemeWith>) -> Element {
+    let CaptionButtonTheme {
+        background,
+        hover_background,
+        close_hover_background,
+        icon_fill,
+        close_hover_icon_fill,
+        width,
+        height,
+    } = use_applied_theme!(&theme, caption_button);
+    let platform = use_platform();
+    let platform_information = use_platform_information();
+    let mut is_hovering = use_signal(|| false);
+
+    let is_maximized = platform_information.read().is_maximized;
+
+    let onmousedown = move |e: MouseEvent| {
+        // Don't drag the window from the buttons
+        e.stop_propagation();
+    };
+
+    let onpointerup = move |e: PointerEvent| {
+        let PointerType::Mouse {
+            trigger_button: Some(MouseButton::Left),
+        } = e.data.pointer_type
+        else {
+            return;
+        };
+        match kind {
+            CaptionButtonKind::Minimize => platform.set_minimize_window(true),
+            CaptionButtonKind::Maximize => platform.toggle_maximize_window(),
+            CaptionButtonKind::Close => platform.exit(),
+        }
+    };
+
+    let (background, icon_fill) = match (is_hovering(), kind) {
+        (true, CaptionButtonKind::Close) => (close_hover_background, close_hover_icon_fill),
+        (true, _) => (hover_background, icon_fill),
+        (false, _) => (background, icon_fill),
+    };
+
+    let (a11y_name, icon) = match kind {
+        CaptionButtonKind::Minimize => ("Minimize", r#"<path d="M0 5.5H10" />"#),
+        CaptionButtonKind::Maximize if is_maximized => (
+            "Restore",
+            r#"<path d="M2.5 2.5V0.5H9.5V7.5H7.5" /><rect x="0.5" y="2.5" width="7" height="7" />"#,
+        ),
+        CaptionButtonKind::Maximize => (
+            "Maximize",
+            r#"<rect x="0.5" y="0.5" width="9" height="9" />"#,
+        ),
+        CaptionButtonKind::Close => ("Close", r#"<path d="M0 0L10 10M10 0L0 10" />"#),
+    };
+
+    rsx!(
+        rect {
+            onmousedown,
+            onpointerup,
+            onmouseenter: move |_| is_hovering.set(true),
+            onmouseleave: move |_| is_hovering.set(false),
+            a11y_role: "button",
+            a11y_name,
+            width: "{width}",
+            height: "{height}",
+            background: "{background}",
+            main_align: "center",
+            cross_align: "center",
+            svg {
+                width: "10",
+                height: "10",
+                svg_content: r#"
+                    <svg viewBox="0 0 10 10" fill="none" stroke="{icon_fill}" stroke-width="1" xmlns="http://www.w3.org/2000/svg">
+                        {icon}
+                    </svg>
+                "#
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn window_caption_buttons() {
+        fn window_caption_buttons_app() -> Element {
+            rsx!(WindowCaptionButtons {})
+        }
+
+        let mut utils = launch_test(window_caption_buttons_app);
+        utils.wait_for_update().await;
+
+        let buttons = utils.root().get(0);
+        assert_eq!(buttons.children_ids().len(), 3);
+        assert_eq!(
+            buttons.get(0).layout().unwrap().area.size,
+            (46., 32.).into()
+        );
+        assert_eq!(
+            buttons.get(2).layout().unwrap().area.origin,
+            (92., 0.).into()
+        );
+    }
+}
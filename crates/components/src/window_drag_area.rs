@@ -1,3 +1,8 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
 use dioxus::prelude::*;
 use freya_core::platform::MouseButton;
 use freya_elements::{
@@ -6,8 +11,16 @@ use freya_elements::{
 };
 use freya_hooks::use_platform;
 
+/// Maximum time between two clicks for them to be considered a double click.
+const DOUBLE_CLICK_DURATION: Duration = Duration::from_millis(500);
+
 /// Allow dragging the window when the cursor drag this component with a left mouse click.
 ///
+/// Double clicking it maximizes or restores the window, like the titlebar of decorated windows.
+/// Dragging is done by the operating system so it keeps its behaviors, such as snapping the window to the edges of the screen.
+///
+/// Wrap interactive children with [WindowDragExclusion] so they don't start dragging the window.
+///
 /// # Example
 ///
 /// ```no_run
@@ -29,13 +42,28 @@ use freya_hooks::use_platform;
 pub fn WindowDragArea(
     /// The inner children for the WindowDragArea
     children: Element,
+    /// Maximize or restore the window with a double click. Defaults to `true`.
+    #[props(default = true)]
+    maximize_on_double_click: bool,
 ) -> Element {
     let platform = use_platform();
+    let mut last_click = use_signal::<Option<Instant>>(|| None);
 
     let onmousedown = move |e: MouseEvent| {
         if let Some(MouseButton::Left) = e.trigger_button {
             e.stop_propagation();
-            platform.drag_window();
+
+            let now = Instant::now();
+            let is_double_click = last_click
+                .take()
+                .is_some_and(|last_click| now - last_click <= DOUBLE_CLICK_DURATION);
+
+            if maximize_on_double_click && is_double_click {
+                platform.toggle_maximize_window();
+            } else {
+                last_click.set(Some(now));
+                platform.drag_window();
+            }
         }
     };
 
@@ -46,3 +74,45 @@ pub fn WindowDragArea(
         }
     )
 }
+
+/// Prevent its children from dragging the window when they are inside a [WindowDragArea],
+/// e.g for buttons in a custom titlebar.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         WindowDragArea {
+///             label {
+///                 "My App"
+///             }
+///             WindowDragExclusion {
+///                 Button {
+///                     label {
+///                         "Settings"
+///                     }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn WindowDragExclusion(
+    /// The inner children for the WindowDragExclusion
+    children: Element,
+) -> Element {
+    let onmousedown = move |e: MouseEvent| {
+        e.stop_propagation();
+    };
+
+    rsx!(
+        rect {
+            onmousedown,
+            {children}
+        }
+    )
+}
@@ -0,0 +1,224 @@
+use dioxus::prelude::*;
+use freya_core::platform::{
+    CursorIcon,
+    MouseButton,
+    ResizeDirection,
+};
+use freya_elements::{
+    self as dioxus_elements,
+    events::MouseEvent,
+};
+use freya_hooks::{
+    use_platform,
+    use_platform_information,
+};
+
+/// Every edge and corner of the window, with the cursor shown when hovering them.
+const HANDLES: [(ResizeDirection, CursorIcon); 8] = [
+    (ResizeDirection::North, CursorIcon::NResize),
+    (ResizeDirection::South, CursorIcon::SResize),
+    (ResizeDirection::West, CursorIcon::WResize),
+    (ResizeDirection::East, CursorIcon::EResize),
+    (ResizeDirection::NorthWest, CursorIcon::NwResize),
+    (ResizeDirection::NorthEast, CursorIcon::NeResize),
+    (ResizeDirection::SouthWest, CursorIcon::SwResize),
+    (ResizeDirection::SouthEast, CursorIcon::SeResize),
+];
+
+/// Position and size of the handle placed in the given edge or corner.
+struct HandleLayout {
+    top: Option<String>,
+    right: Option<String>,
+    bottom: Option<String>,
+    left: Option<String>,
+    width: String,
+    height: String,
+}
+
+impl HandleLayout {
+    fn new(direction: ResizeDirection, size: f32) -> Self {
+        let (top, bottom) = match direction {
+            ResizeDirection::North | ResizeDirection::NorthWest | ResizeDirection::NorthEast => {
+                (Some("0".to_string()), None)
+            }
+            ResizeDirection::South | ResizeDirection::SouthWest | ResizeDirection::SouthEast => {
+                (None, Some("0".to_string()))
+            }
+            _ => (Some(size.to_string()), None),
+        };
+        let (left, right) = match direction {
+            ResizeDirection::West | ResizeDirection::NorthWest | ResizeDirection::SouthWest => {
+                (Some("0".to_string()), None)
+            }
+            ResizeDirection::East | ResizeDirection::NorthEast | ResizeDirection::SouthEast => {
+                (None, Some("0".to_string()))
+            }
+            _ => (Some(size.to_string()), None),
+        };
+        let width = match direction {
+            ResizeDirection::North | ResizeDirection::South => {
+                format!("calc(100% - {})", size * 2.)
+            }
+            _ => size.to_string(),
+        };
+        let height = match direction {
+            ResizeDirection::West | ResizeDirection::East => format!("calc(100% - {})", size * 2.),
+            _ => size.to_string(),
+        };
+
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+            width,
+            height,
+        }
+    }
+}
+
+/// Allow resizing an undecorated window by dragging its edges and corners.
+///
+/// It should wrap the whole app. The edges are disabled while the window is maximized or in fullscreen.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn main() {
+///     launch_cfg(app, LaunchConfig::<()>::new().with_decorations(false))
+/// }
+///
+/// fn app() -> Element {
+///     rsx!(
+///         WindowResizeArea {
+///             WindowDragArea {
+///                 label {
+///                     "My App"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn WindowResizeArea(
+    /// The inner children for the WindowResizeArea
+    children: Element,
+    /// Thickness of the edges that can be dragged. Defaults to `6`.
+    #[props(default = 6.)]
+    handle_size: f32,
+) -> Element {
+    let platform_information = use_platform_information();
+    let information = platform_information();
+    let is_resizable = !information.is_maximized && !information.is_fullscreen;
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            {children}
+            if is_resizable {
+                for (direction, icon) in HANDLES {
+                    WindowResizeHandle {
+                        key: "{direction:?}",
+                        direction,
+                        icon,
+                        handle_size,
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn WindowResizeHandle(direction: ResizeDirection, icon: CursorIcon, handle_size: f32) -> Element {
+    let platform = use_platform();
+    let mut is_hovering = use_signal(|| false);
+
+    let HandleLayout {
+        top,
+        right,
+        bottom,
+        left,
+        width,
+        height,
+    } = HandleLayout::new(direction, handle_size);
+
+    use_drop(move || {
+        if *is_hovering.peek() {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let onmouseenter = move |_| {
+        is_hovering.set(true);
+        platform.set_cursor(icon);
+    };
+
+    let onmouseleave = move |_| {
+        is_hovering.set(false);
+        platform.set_cursor(CursorIcon::default());
+    };
+
+    let onmousedown = move |e: MouseEvent| {
+        if let Some(MouseButton::Left) = e.trigger_button {
+            e.stop_propagation();
+            platform.start_resize(direction);
+        }
+    };
+
+    rsx!(rect {
+        position: "absolute",
+        position_top: top,
+        position_right: right,
+        position_bottom: bottom,
+        position_left: left,
+        width,
+        height,
+        layer: "-999",
+        onmouseenter,
+        onmouseleave,
+        onmousedown,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn window_resize_area() {
+        fn window_resize_area_app() -> Element {
+            rsx!(WindowResizeArea {
+                handle_size: 10.,
+                rect {
+                    width: "100%",
+                    height: "100%",
+                }
+            })
+        }
+
+        let mut utils = launch_test(window_resize_area_app);
+        utils.wait_for_update().await;
+
+        let area = utils.root().get(0);
+        // The content and a handle for every edge and corner
+        assert_eq!(area.children_ids().len(), 9);
+
+        let north = area.get(1).layout().unwrap();
+        assert_eq!(north.area.origin, (10., 0.).into());
+        assert_eq!(north.area.size, (480., 10.).into());
+
+        let south_east = area.get(8).layout().unwrap();
+        assert_eq!(south_east.area.origin, (490., 490.).into());
+
+        // Hovering an edge shows the resize cursor
+        utils.move_cursor((250., 5.)).await;
+        assert_eq!(utils.cursor_icon(), CursorIcon::NResize);
+    }
+}
@@ -0,0 +1,464 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        keyboard::Key,
+        touch::TouchPhase,
+        KeyboardEvent,
+        MouseEvent,
+        TouchEvent,
+        WheelEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_node_signal,
+    use_platform_information,
+    ZoomableViewTheme,
+    ZoomableViewThemeWith,
+};
+use torin::prelude::CursorPoint;
+
+/// Wheel distance needed to double or halve the zoom.
+const ZOOM_WHEEL_DELTA: f32 = 300.;
+/// Size of the longest side of the mini-map.
+const MINIMAP_SIZE: f32 = 120.;
+/// Space between the mini-map and the corner of the view.
+const MINIMAP_MARGIN: f32 = 8.;
+
+/// Pan and zoom of the content of a [`ZoomableView`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ZoomState {
+    zoom: f32,
+    /// Offset of the content before being zoomed.
+    pan: (f32, f32),
+}
+
+impl ZoomState {
+    /// Zoom to `new_zoom` keeping the content under `point` in place.
+    /// `center` is the center of the view, where the zoom is applied from.
+    fn zoom_at(self, new_zoom: f32, point: (f32, f32), center: (f32, f32)) -> Self {
+        let factor = 1. / new_zoom - 1. / self.zoom;
+        Self {
+            zoom: new_zoom,
+            pan: (
+                self.pan.0 + (point.0 - center.0) * factor,
+                self.pan.1 + (point.1 - center.1) * factor,
+            ),
+        }
+    }
+
+    /// Move the content by the given distance in the screen.
+    fn pan_by(self, (x, y): (f32, f32)) -> Self {
+        Self {
+            zoom: self.zoom,
+            pan: (self.pan.0 + x / self.zoom, self.pan.1 + y / self.zoom),
+        }
+    }
+
+    /// Area of the content visible in a view of the given size, as `(x, y, width, height)`.
+    fn visible_area(&self, (width, height): (f32, f32)) -> (f32, f32, f32, f32) {
+        let (center_x, center_y) = (width / 2., height / 2.);
+        (
+            center_x - center_x / self.zoom - self.pan.0,
+            center_y - center_y / self.zoom - self.pan.1,
+            width / self.zoom,
+            height / self.zoom,
+        )
+    }
+}
+
+/// Properties for the [`ZoomableView`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct ZoomableViewProps {
+    /// Theme override.
+    pub theme: Option<ZoomableViewThemeWith>,
+    /// Width of the ZoomableView. Default to `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the ZoomableView. Default to `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Smallest zoom allowed. Default to `0.25`.
+    #[props(default = 0.25)]
+    pub min_zoom: f32,
+    /// Biggest zoom allowed. Default to `4`.
+    #[props(default = 4.)]
+    pub max_zoom: f32,
+    /// Show a mini-map of the content and the visible area in the bottom right corner.
+    /// Clicking on it moves the view. Default to `false`.
+    #[props(default = false)]
+    pub show_minimap: bool,
+    /// Inner children for the ZoomableView.
+    pub children: Element,
+}
+
+/// Container that can be panned and zoomed like a map.
+///
+/// - Drag with the mouse or a finger to pan, or use the wheel.
+/// - Use the wheel while pressing `Ctrl` or pinch with two fingers to zoom.
+///
+/// The children can still be interacted with, even while zoomed.
+///
+/// # Styling
+/// Inherits the [`ZoomableViewTheme`](freya_hooks::ZoomableViewTheme) theme.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         ZoomableView {
+///             show_minimap: true,
+///             rect {
+///                 width: "800",
+///                 height: "800",
+///                 background: "rgb(200, 220, 240)",
+///                 Button {
+///                     label { "Click me" }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn ZoomableView(
+    ZoomableViewProps {
+        theme,
+        width,
+        height,
+        min_zoom,
+        max_zoom,
+        show_minimap,
+        children,
+    }: ZoomableViewProps,
+) -> Element {
+    let mut state = use_signal(|| ZoomState {
+        zoom: 1f32.clamp(min_zoom, max_zoom),
+        pan: (0., 0.),
+    });
+    let mut clicking_ctrl = use_signal(|| false);
+    let mut cursor = use_signal(|| None::<CursorPoint>);
+    let mut dragging = use_signal(|| false);
+    let mut fingers = use_signal(HashMap::<u64, CursorPoint>::new);
+    let mut moving_minimap = use_signal(|| false);
+    let (reference, size) = use_node_signal();
+    let (content_reference, content_size) = use_node_signal();
+    let platform_information = use_platform_information();
+    let ZoomableViewTheme {
+        minimap_background,
+        minimap_border_fill,
+        minimap_content,
+        minimap_viewport,
+    } = use_applied_theme!(&theme, zoomable_view);
+
+    let view_center = move || {
+        let area = size.peek().area;
+        (area.width() / 2., area.height() / 2.)
+    };
+
+    // Position relative to the view
+    let local_point = move |point: CursorPoint| {
+        let area = size.peek().area;
+        (point.x as f32 - area.min_x(), point.y as f32 - area.min_y())
+    };
+
+    let onwheel = move |e: WheelEvent| {
+        let current = *state.peek();
+        if *clicking_ctrl.peek() {
+            // Zoom where the cursor is
+            let zoom = (current.zoom * 2f32.powf(e.get_delta_y() as f32 / ZOOM_WHEEL_DELTA))
+                .clamp(min_zoom, max_zoom);
+            let center = view_center();
+            let point = cursor.peek().map(local_point).unwrap_or(center);
+            state.set(current.zoom_at(zoom, point, center));
+        } else {
+            state.set(current.pan_by((e.get_delta_x() as f32, e.get_delta_y() as f32)));
+        }
+        e.stop_propagation();
+    };
+
+    let onmousedown = move |e: MouseEvent| {
+        *cursor.write() = Some(e.get_screen_coordinates());
+        dragging.set(true);
+    };
+
+    let onmousemove = move |e: MouseEvent| {
+        let new_cursor = e.get_screen_coordinates();
+        let previous_cursor = cursor.write().replace(new_cursor);
+        if let (true, Some(previous_cursor)) = (*dragging.peek(), previous_cursor) {
+            let delta = new_cursor - previous_cursor;
+            let current = *state.peek();
+            state.set(current.pan_by((delta.x as f32, delta.y as f32)));
+        }
+    };
+
+    let onglobalclick = move |_: MouseEvent| {
+        dragging.set(false);
+        moving_minimap.set(false);
+    };
+
+    let ontouch = move |e: TouchEvent| {
+        let scale_factor = platform_information.peek().scale_factor;
+        let location = e.get_screen_coordinates() / scale_factor;
+        let finger_id = e.get_finger_id();
+
+        match e.get_touch_phase() {
+            TouchPhase::Started => {
+                fingers.write().insert(finger_id, location);
+            }
+            TouchPhase::Moved => {
+                let previous_fingers = fingers.peek().clone();
+                let Some(previous) = previous_fingers.get(&finger_id) else {
+                    return;
+                };
+                fingers.write().insert(finger_id, location);
+
+                let current = *state.peek();
+                match previous_fingers.len() {
+                    1 => {
+                        let delta = location - *previous;
+                        state.set(current.pan_by((delta.x as f32, delta.y as f32)));
+                    }
+                    2 => {
+                        // Pinch around the middle of both fingers
+                        let Some(other) = previous_fingers
+                            .iter()
+                            .find_map(|(id, location)| (*id != finger_id).then_some(*location))
+                        else {
+                            return;
+                        };
+                        let previous_distance = previous.distance_to(other) as f32;
+                        if previous_distance <= 0. {
+                            return;
+                        }
+                        let distance = location.distance_to(other) as f32;
+                        let zoom =
+                            (current.zoom * distance / previous_distance).clamp(min_zoom, max_zoom);
+
+                        let previous_middle = previous.lerp(other, 0.5);
+                        let middle = location.lerp(other, 0.5);
+                        let delta = middle - previous_middle;
+                        state.set(
+                            current
+                                .zoom_at(zoom, local_point(previous_middle), view_center())
+                                .pan_by((delta.x as f32, delta.y as f32)),
+                        );
+                    }
+                    _ => {}
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                fingers.write().remove(&finger_id);
+            }
+        }
+    };
+
+    let onglobalkeydown = move |e: KeyboardEvent| {
+        if e.key == Key::Control {
+            clicking_ctrl.set(true);
+        }
+    };
+
+    let onglobalkeyup = move |e: KeyboardEvent| {
+        if e.key == Key::Control {
+            clicking_ctrl.set(false);
+        }
+    };
+
+    let ZoomState { zoom, pan } = *state.read();
+    let view_size = size.read().area.size;
+    let content_size = content_size.read().area.size;
+
+    // Scale the content down so its longest side fits in the mini-map
+    let minimap_scale = MINIMAP_SIZE / content_size.width.max(content_size.height).max(1.);
+    let (visible_x, visible_y, visible_width, visible_height) = state
+        .read()
+        .visible_area((view_size.width, view_size.height));
+
+    // Center the view in the clicked point of the mini-map
+    let mut move_to_minimap_point = move |e: MouseEvent| {
+        e.stop_propagation();
+        let point = e.get_element_coordinates();
+        let view_size = size.peek().area.size;
+        let current = *state.peek();
+        state.set(ZoomState {
+            zoom: current.zoom,
+            pan: (
+                view_size.width / 2. - point.x as f32 / minimap_scale,
+                view_size.height / 2. - point.y as f32 / minimap_scale,
+            ),
+        });
+    };
+
+    let onminimapmousedown = move |e: MouseEvent| {
+        moving_minimap.set(true);
+        move_to_minimap_point(e);
+    };
+
+    let onminimapmousemove = move |e: MouseEvent| {
+        if *moving_minimap.peek() {
+            move_to_minimap_point(e);
+        }
+    };
+
+    rsx!(
+        rect {
+            reference,
+            width: "{width}",
+            height: "{height}",
+            overflow: "clip",
+            onwheel,
+            onmousedown,
+            onglobalmousemove: onmousemove,
+            onglobalclick,
+            ontouchstart: ontouch,
+            ontouchmove: ontouch,
+            ontouchend: ontouch,
+            ontouchcancel: ontouch,
+            onglobalkeydown,
+            onglobalkeyup,
+            rect {
+                width: "100%",
+                height: "100%",
+                scale: "{zoom}",
+                offset_x: "{pan.0}",
+                offset_y: "{pan.1}",
+                rect {
+                    reference: content_reference,
+                    {children}
+                }
+            }
+            if show_minimap {
+                rect {
+                    position: "absolute",
+                    position_right: "{MINIMAP_MARGIN}",
+                    position_bottom: "{MINIMAP_MARGIN}",
+                    width: "{content_size.width * minimap_scale}",
+                    height: "{content_size.height * minimap_scale}",
+                    background: "{minimap_background}",
+                    border: "1 outer {minimap_border_fill}",
+                    overflow: "clip",
+                    onmousedown: onminimapmousedown,
+                    onmousemove: onminimapmousemove,
+                    rect {
+                        width: "100%",
+                        height: "100%",
+                        background: "{minimap_content}",
+                        opacity: "0.6",
+                    }
+                    rect {
+                        position: "absolute",
+                        position_left: "{visible_x * minimap_scale}",
+                        position_top: "{visible_y * minimap_scale}",
+                        width: "{visible_width * minimap_scale}",
+                        height: "{visible_height * minimap_scale}",
+                        border: "2 inner {minimap_viewport}",
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use super::ZoomState;
+
+    #[test]
+    pub fn zoom_state() {
+        let state = ZoomState {
+            zoom: 1.,
+            pan: (0., 0.),
+        };
+
+        // The content under the zoomed point stays in place
+        let zoomed = state.zoom_at(2., (0., 0.), (200., 200.));
+        assert_eq!(zoomed.pan, (100., 100.));
+        assert_eq!(zoomed.visible_area((400., 400.)), (0., 0., 200., 200.));
+
+        // Panning is slower the bigger the zoom
+        let panned = zoomed.pan_by((40., -20.));
+        assert_eq!(panned.pan, (120., 90.));
+    }
+
+    #[tokio::test]
+    pub async fn zoomable_view() {
+        fn zoomable_view_app() -> Element {
+            let mut clicks = use_signal(|| 0);
+            rsx!(
+                ZoomableView {
+                    width: "400",
+                    height: "400",
+                    rect {
+                        width: "100",
+                        height: "100",
+                        onclick: move |_| clicks += 1,
+                    }
+                }
+                label {
+                    "{clicks}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(zoomable_view_app);
+        utils.wait_for_update().await;
+        let label = utils.root().get(1).get(0);
+
+        // Out of the content
+        utils.click_cursor((150., 150.)).await;
+        assert_eq!(label.text(), Some("0"));
+
+        // Zoom twice from the top left corner
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Control,
+            code: Code::ControlLeft,
+            modifiers: Modifiers::CONTROL,
+        });
+        utils.wait_for_update().await;
+        utils.move_cursor((0., 0.)).await;
+        utils.push_event(TestEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., 300.).into(),
+            cursor: (0., 0.).into(),
+        });
+        utils.wait_for_update().await;
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyUp,
+            key: Key::Control,
+            code: Code::ControlLeft,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        // The zoomed content can be clicked where it is rendered
+        utils.click_cursor((150., 150.)).await;
+        assert_eq!(label.text(), Some("1"));
+        utils.click_cursor((250., 250.)).await;
+        assert_eq!(label.text(), Some("1"));
+
+        // Pan 40 pixels to the right
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (300., 300.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.move_cursor((340., 300.)).await;
+        utils.click_cursor((340., 300.)).await;
+
+        utils.click_cursor((20., 100.)).await;
+        assert_eq!(label.text(), Some("1"));
+        utils.click_cursor((230., 100.)).await;
+        assert_eq!(label.text(), Some("2"));
+    }
+}
@@ -43,6 +43,10 @@ use crate::{
         DioxusDOM,
         DioxusNode,
     },
+    events::{
+        paragraph_range_area,
+        paragraph_spans,
+    },
     states::{
         AccessibilityNodeState,
         FontStyleState,
@@ -68,6 +72,8 @@ pub struct AccessibilityDirtyNodes {
     pub requested_focus: Option<AccessibilityFocusStrategy>,
     pub added_or_updated: FxHashSet<NodeId>,
     pub removed: FxHashMap<NodeId, NodeId>,
+    /// Nodes whose children were inserted or reordered.
+    pub children_changed: FxHashSet<NodeId>,
 }
 
 impl AccessibilityDirtyNodes {
@@ -83,10 +89,15 @@ impl AccessibilityDirtyNodes {
         self.removed.insert(node_id, parent_id);
     }
 
+    pub fn invalidate_children(&mut self, node_id: NodeId) {
+        self.children_changed.insert(node_id);
+    }
+
     pub fn clear(&mut self) {
         self.requested_focus.take();
         self.added_or_updated.clear();
         self.removed.clear();
+        self.children_changed.clear();
     }
 }
 
@@ -112,6 +123,8 @@ pub const ACCESSIBILITY_ROOT_ID: AccessibilityId = AccessibilityId(0);
 
 pub struct AccessibilityTree {
     pub map: FxHashMap<AccessibilityId, NodeId>,
+    /// Reverse of `map`, so removed nodes can be forgotten without walking the whole tree.
+    accessibility_ids: FxHashMap<NodeId, AccessibilityId>,
     // Current focused Accessibility Node.
     pub focused_id: AccessibilityId,
 }
@@ -121,6 +134,26 @@ impl AccessibilityTree {
         Self {
             focused_id,
             map: FxHashMap::default(),
+            accessibility_ids: FxHashMap::default(),
+        }
+    }
+
+    /// Register a Node, returning `true` if it wasn't known with the same ID.
+    fn register(&mut self, accessibility_id: AccessibilityId, node_id: NodeId) -> bool {
+        let previous_id = self.accessibility_ids.insert(node_id, accessibility_id);
+        if previous_id == Some(accessibility_id) {
+            return false;
+        }
+        if let Some(previous_id) = previous_id {
+            self.map.remove(&previous_id);
+        }
+        self.map.insert(accessibility_id, node_id);
+        true
+    }
+
+    fn unregister(&mut self, node_id: &NodeId) {
+        if let Some(accessibility_id) = self.accessibility_ids.remove(node_id) {
+            self.map.remove(&accessibility_id);
         }
     }
 
@@ -130,7 +163,7 @@ impl AccessibilityTree {
 
     /// Initialize the Accessibility Tree
     pub fn init(
-        &self,
+        &mut self,
         rdom: &DioxusDOM,
         layout: &Torin<NodeId>,
         dirty_nodes: &mut AccessibilityDirtyNodes,
@@ -145,7 +178,7 @@ impl AccessibilityTree {
             }
 
             let accessibility_id = node_ref.get_accessibility_id();
-            let layout_node = layout.get(node_ref.id());
+            let layout_node = Self::layout_node(&node_ref, layout);
 
             // Layout nodes might not exist yet when the app is lauched
             if let Some((accessibility_id, layout_node)) = accessibility_id.zip(layout_node) {
@@ -153,12 +186,12 @@ impl AccessibilityTree {
                 let accessibility_node =
                     Self::create_node(&node_ref, layout_node, &node_accessibility_state);
                 nodes.push((accessibility_id, accessibility_node));
+                self.register(accessibility_id, node_ref.id());
             }
 
-            if let Some(tag) = node_ref.node_type().tag() {
-                if *tag == TagName::Paragraph || *tag == TagName::Label {
-                    return false;
-                }
+            // Only the links of paragraphs are exposed
+            if node_ref.node_type().tag() == Some(&TagName::Label) {
+                return false;
             }
 
             true
@@ -191,6 +224,12 @@ impl AccessibilityTree {
             .drain()
             .collect::<FxHashSet<_>>();
 
+        let _span = crate::profile_span!(
+            "accessibility",
+            removed = removed_ids.len(),
+            added_or_updated = added_or_updated_ids.len()
+        );
+
         #[cfg(debug_assertions)]
         if !removed_ids.is_empty() || !added_or_updated_ids.is_empty() {
             tracing::info!(
@@ -201,40 +240,58 @@ impl AccessibilityTree {
         }
 
         // Remove all the removed nodes from the update list
-        for (node_id, _) in removed_ids.iter() {
+        for node_id in removed_ids.keys() {
             added_or_updated_ids.remove(node_id);
-            self.map.retain(|_, id| id != node_id);
+            self.unregister(node_id);
         }
 
         // Mark the parent of the removed nodes as updated
-        for (_, parent_id) in removed_ids.iter() {
+        for parent_id in removed_ids.values() {
             if !removed_ids.contains_key(parent_id) {
                 added_or_updated_ids.insert(*parent_id);
             }
         }
 
-        // Mark the ancestors as modified
+        // Mark the nodes with inserted or reordered children as updated
+        for node_id in dirty_nodes.children_changed.drain() {
+            if !removed_ids.contains_key(&node_id) {
+                added_or_updated_ids.insert(node_id);
+            }
+        }
+
+        // Register the new nodes, only their parents need to be updated
+        // to include them as children, the rest of ancestors stay untouched
         for node_id in added_or_updated_ids.clone() {
-            let node_ref = rdom.get(node_id).unwrap();
-            let node_ref_parent = node_ref.parent_id().unwrap_or(rdom.root_id());
-            added_or_updated_ids.insert(node_ref_parent);
-            self.map
-                .insert(node_ref.get_accessibility_id().unwrap(), node_id);
+            let Some(node_ref) = rdom.get(node_id) else {
+                added_or_updated_ids.remove(&node_id);
+                continue;
+            };
+            let Some(accessibility_id) = node_ref.get_accessibility_id() else {
+                continue;
+            };
+            if self.register(accessibility_id, node_id) {
+                let node_ref_parent = node_ref.parent_id().unwrap_or(rdom.root_id());
+                added_or_updated_ids.insert(node_ref_parent);
+            }
         }
 
         // Create the updated nodes
         let mut nodes = Vec::new();
         for node_id in added_or_updated_ids {
-            let node_ref = rdom.get(node_id).unwrap();
+            let Some(node_ref) = rdom.get(node_id) else {
+                continue;
+            };
             let node_accessibility_state = node_ref.get::<AccessibilityNodeState>();
-            let layout_node = layout.get(node_id);
+            let layout_node = Self::layout_node(&node_ref, layout);
 
-            if let Some((node_accessibility_state, layout_node)) =
-                node_accessibility_state.as_ref().zip(layout_node)
+            if let Some(((node_accessibility_state, layout_node), accessibility_id)) =
+                node_accessibility_state
+                    .as_ref()
+                    .zip(layout_node)
+                    .zip(node_ref.get_accessibility_id())
             {
                 let accessibility_node =
                     Self::create_node(&node_ref, layout_node, node_accessibility_state);
-                let accessibility_id = node_ref.get_accessibility_id().unwrap();
 
                 nodes.push((accessibility_id, accessibility_node));
             }
@@ -255,24 +312,16 @@ impl AccessibilityTree {
         (
             TreeUpdate {
                 nodes,
-                tree: Some(Tree::new(ACCESSIBILITY_ROOT_ID)),
+                // The tree only needs to be sent when initializing
+                tree: None,
                 focus: self.focused_id,
             },
             node_id,
         )
     }
 
-    /// Focus a Node given the strategy.
-    pub fn focus_node_with_strategy(
-        &mut self,
-        stragegy: AccessibilityFocusStrategy,
-        rdom: &DioxusDOM,
-    ) {
-        if let AccessibilityFocusStrategy::Node(id) = stragegy {
-            self.focused_id = id;
-            return;
-        }
-
+    /// Get the focusable Nodes in the order they are navigated with the keyboard.
+    pub fn focus_order(rdom: &DioxusDOM) -> Vec<(AccessibilityId, NodeId)> {
         let mut nodes = Vec::new();
 
         rdom.traverse_depth_first_advanced(|node_ref| {
@@ -285,7 +334,7 @@ impl AccessibilityTree {
             if let Some(accessibility_id) = accessibility_id {
                 let accessibility_state = node_ref.get::<AccessibilityNodeState>().unwrap();
                 if accessibility_state.a11y_focusable.is_enabled() {
-                    nodes.push(accessibility_id)
+                    nodes.push((accessibility_id, node_ref.id()))
                 }
             }
 
@@ -298,6 +347,25 @@ impl AccessibilityTree {
             true
         });
 
+        nodes
+    }
+
+    /// Focus a Node given the strategy.
+    pub fn focus_node_with_strategy(
+        &mut self,
+        stragegy: AccessibilityFocusStrategy,
+        rdom: &DioxusDOM,
+    ) {
+        if let AccessibilityFocusStrategy::Node(id) = stragegy {
+            self.focused_id = id;
+            return;
+        }
+
+        let nodes = Self::focus_order(rdom)
+            .into_iter()
+            .map(|(accessibility_id, _)| accessibility_id)
+            .collect::<Vec<_>>();
+
         let node_index = nodes
             .iter()
             .position(|accessibility_id| *accessibility_id == self.focused_id);
@@ -332,6 +400,16 @@ impl AccessibilityTree {
         tracing::info!("Focused {:?} node.", self.focused_id);
     }
 
+    /// Get the layout node of the given node.
+    /// `text` spans are not laid out on their own, so they get the one of their `paragraph`.
+    fn layout_node<'a>(node_ref: &DioxusNode, layout: &'a Torin<NodeId>) -> Option<&'a LayoutNode> {
+        if node_ref.node_type().tag() == Some(&TagName::Text) {
+            layout.get(node_ref.parent_id()?)
+        } else {
+            layout.get(node_ref.id())
+        }
+    }
+
     /// Create an accessibility node
     pub fn create_node(
         node_ref: &DioxusNode,
@@ -360,8 +438,21 @@ impl AccessibilityTree {
         let children = node_ref.get_accessibility_children();
         builder.set_children(children);
 
-        // Set the area
-        let area = layout_node.area.to_f64();
+        // Set the area, `text` spans only take the part of their paragraph where they are rendered
+        let area = if node_type.tag() == Some(&TagName::Text) {
+            node_ref
+                .parent()
+                .and_then(|paragraph| {
+                    let (_, range) = paragraph_spans(&paragraph)
+                        .into_iter()
+                        .find(|(span, _)| span.id() == node_ref.id())?;
+                    paragraph_range_area(&paragraph, layout_node, range)
+                })
+                .unwrap_or(layout_node.area)
+        } else {
+            layout_node.area
+        }
+        .to_f64();
         builder.set_bounds(Rect {
             x0: area.min_x(),
             x1: area.max_x(),
@@ -370,7 +461,11 @@ impl AccessibilityTree {
         });
 
         if let NodeType::Element(node) = &*node_type {
-            if matches!(node.tag, TagName::Label | TagName::Paragraph) && builder.name().is_none() {
+            if matches!(
+                node.tag,
+                TagName::Label | TagName::Paragraph | TagName::Text
+            ) && builder.name().is_none()
+            {
                 if let Some(inner_text) = node_ref.get_inner_texts() {
                     builder.set_name(inner_text);
                 }
@@ -440,13 +535,15 @@ impl AccessibilityTree {
         builder.set_text_align(match font_style_state.text_align {
             TextAlign::Center => accesskit::TextAlign::Center,
             TextAlign::Justify => accesskit::TextAlign::Justify,
-            // TODO: change representation of `Start` and `End` once RTL text/writing modes are supported.
-            TextAlign::Left | TextAlign::Start => accesskit::TextAlign::Left,
-            TextAlign::Right | TextAlign::End => accesskit::TextAlign::Right,
+            _ if font_style_state.is_left_aligned() => accesskit::TextAlign::Left,
+            _ => accesskit::TextAlign::Right,
         });
 
-        // TODO: Adjust this once text direction support other than RTL is properly added
-        builder.set_text_direction(TextDirection::LeftToRight);
+        builder.set_text_direction(if font_style_state.writing_direction.is_rtl() {
+            TextDirection::RightToLeft
+        } else {
+            TextDirection::LeftToRight
+        });
 
         // Set italic property for italic/oblique font slants
         match font_style_state.font_slant {
@@ -0,0 +1,28 @@
+use std::path::PathBuf;
+
+/// Name of the app, taken from the name of its executable.
+pub fn app_name() -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|path| Some(path.file_stem()?.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "freya".to_string())
+}
+
+/// Folder with the given name inside the config folder of the platform, e.g. `~/.config/my-app` in Linux.
+pub fn app_config_dir(app_name: &str) -> PathBuf {
+    dirs::config_dir().unwrap_or_default().join(app_name)
+}
+
+/// Name of the file where the data saved with the given key is stored,
+/// replacing the characters other than letters, numbers, `-`, `_` and `.` with `_`.
+pub fn key_file_name(key: &str) -> String {
+    key.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
@@ -26,6 +26,8 @@ use torin::geometry::{
 };
 use uuid::Uuid;
 
+use crate::design_tokens::DesignTokens;
+
 /// Image Reference
 #[derive(Clone, Debug)]
 pub struct ImageReference(pub Arc<Mutex<Option<Bytes>>>);
@@ -46,12 +48,15 @@ impl Display for ImageReference {
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct NodeReferenceLayout {
     pub area: Area,
+    /// Same as `area` but relative to the inner area of the parent Node.
+    pub local_area: Area,
     pub inner: Size2D,
 }
 
 impl NodeReferenceLayout {
     pub fn div(&mut self, rhs: f32) {
         self.area = self.area.div(rhs);
+        self.local_area = self.local_area.div(rhs);
         self.inner = self.inner.div(rhs);
     }
 }
@@ -84,19 +89,102 @@ pub struct CanvasRunnerContext<'a> {
     pub font_collection: &'a mut FontCollection,
     pub area: Area,
     pub scale_factor: f32,
+    /// Whether this is the first time this runner is rendered
+    /// or the area or scale factor changed since the last render.
+    pub dirty: bool,
 }
 
 pub type CanvasRunner = dyn FnMut(&mut CanvasRunnerContext) + Sync + Send + 'static;
 
+/// Rendering state of a [CanvasReference], kept across frames.
+#[derive(Default)]
+pub struct CanvasRenderState {
+    pub last_area: Option<Area>,
+    pub last_scale_factor: f32,
+    /// Recorded output of a cached [CanvasRunner].
+    pub picture: Option<Picture>,
+}
+
+impl CanvasRenderState {
+    /// Check whether the given area or scale factor are different from the last render.
+    pub fn is_dirty(&self, area: &Area, scale_factor: f32) -> bool {
+        self.last_area.as_ref() != Some(area) || self.last_scale_factor != scale_factor
+    }
+}
+
 /// Canvas Reference
 #[derive(Clone)]
 pub struct CanvasReference {
     pub runner: Arc<Mutex<CanvasRunner>>,
+    pub render_state: Arc<Mutex<CanvasRenderState>>,
+    /// Record the runner output into a [Picture] and replay it
+    /// until the runner, area or scale factor change.
+    pub cache: bool,
+}
+
+impl CanvasReference {
+    pub fn new(runner: Arc<Mutex<CanvasRunner>>) -> Self {
+        Self {
+            runner,
+            render_state: Arc::default(),
+            cache: false,
+        }
+    }
+
+    pub fn with_cache(mut self, cache: bool) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Run the canvas runner, replaying the cached [Picture] when possible.
+    pub fn render(
+        &self,
+        canvas: &Canvas,
+        font_collection: &mut FontCollection,
+        area: Area,
+        scale_factor: f32,
+    ) {
+        let mut render_state = self.render_state.lock().unwrap();
+        let dirty = render_state.is_dirty(&area, scale_factor);
+        render_state.last_area = Some(area);
+        render_state.last_scale_factor = scale_factor;
+
+        if !self.cache {
+            let mut ctx = CanvasRunnerContext {
+                canvas,
+                font_collection,
+                area,
+                scale_factor,
+                dirty,
+            };
+            (self.runner.lock().unwrap())(&mut ctx);
+            return;
+        }
+
+        if dirty || render_state.picture.is_none() {
+            let mut recorder = PictureRecorder::new();
+            let bounds = Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+            let recording_canvas = recorder.begin_recording(bounds, None);
+            let mut ctx = CanvasRunnerContext {
+                canvas: recording_canvas,
+                font_collection,
+                area,
+                scale_factor,
+                dirty,
+            };
+            (self.runner.lock().unwrap())(&mut ctx);
+            render_state.picture = recorder.finish_recording_as_picture(Some(&bounds));
+        }
+
+        if let Some(picture) = &render_state.picture {
+            canvas.draw_picture(picture, None, None);
+        }
+    }
 }
 
 impl PartialEq for CanvasReference {
     fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.runner, &other.runner)
+        Arc::ptr_eq(&self.runner, &other.runner) && self.cache == other.cache
     }
 }
 
@@ -149,7 +237,9 @@ pub enum CustomAttributeValues {
     ImageReference(ImageReference),
     AccessibilityId(AccessibilityId),
     TextHighlights(Vec<(usize, usize)>),
+    TextCarets(Vec<usize>),
     Canvas(CanvasReference),
+    DesignTokens(Arc<DesignTokens>),
 }
 
 impl Debug for CustomAttributeValues {
@@ -161,7 +251,9 @@ impl Debug for CustomAttributeValues {
             Self::ImageReference(_) => f.debug_tuple("ImageReference").finish(),
             Self::AccessibilityId(_) => f.debug_tuple("AccessibilityId").finish(),
             Self::TextHighlights(_) => f.debug_tuple("TextHighlights").finish(),
+            Self::TextCarets(_) => f.debug_tuple("TextCarets").finish(),
             Self::Canvas(_) => f.debug_tuple("Canvas").finish(),
+            Self::DesignTokens(_) => f.debug_tuple("DesignTokens").finish(),
         }
     }
 }
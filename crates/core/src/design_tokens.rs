@@ -0,0 +1,129 @@
+use std::{
+    borrow::Cow,
+    sync::Arc,
+};
+
+use dioxus_core::AttributeValue;
+use rustc_hash::FxHashMap;
+
+use crate::custom_attributes::CustomAttributeValues;
+
+/// Prefix of the tokens in attribute values, e.g `token(surface-2)`.
+const TOKEN_PREFIX: &str = "token(";
+
+/// Named values that can be referenced from any attribute with `token(name)`,
+/// e.g `background: "token(surface-2)"`.
+///
+/// They are provided to an element and its descendants with the `design_tokens` attribute.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DesignTokens {
+    tokens: FxHashMap<String, String>,
+}
+
+impl DesignTokens {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a token.
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Add or replace a token.
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.tokens.insert(name.into(), value.into());
+    }
+
+    /// Add or replace the tokens of `other`.
+    pub fn extend(&mut self, other: &DesignTokens) {
+        self.tokens.extend(
+            other
+                .tokens
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone())),
+        );
+    }
+
+    /// Remove a token.
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        self.tokens.remove(name)
+    }
+
+    /// Get the value of a token.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.tokens.get(name).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Use these tokens as the `design_tokens` attribute of an element.
+    pub fn attribute(&self) -> AttributeValue {
+        AttributeValue::any_value(CustomAttributeValues::DesignTokens(Arc::new(self.clone())))
+    }
+
+    /// Check whether the given attribute value references any token.
+    pub fn uses_tokens(value: &str) -> bool {
+        value.contains(TOKEN_PREFIX)
+    }
+
+    /// Replace every `token(name)` in the given attribute value with the value of the token.
+    /// Values of tokens are used as they are, so they can't reference other tokens.
+    ///
+    /// Returns `None` if any of the referenced tokens is missing.
+    pub fn resolve<'a>(&self, value: &'a str) -> Option<Cow<'a, str>> {
+        if !Self::uses_tokens(value) {
+            return Some(Cow::Borrowed(value));
+        }
+
+        let mut resolved = String::with_capacity(value.len());
+        let mut rest = value;
+        while let Some(start) = rest.find(TOKEN_PREFIX) {
+            resolved.push_str(&rest[..start]);
+            rest = &rest[start + TOKEN_PREFIX.len()..];
+            let end = rest.find(')')?;
+            resolved.push_str(self.get(rest[..end].trim())?);
+            rest = &rest[end + 1..];
+        }
+        resolved.push_str(rest);
+
+        Some(Cow::Owned(resolved))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DesignTokens;
+
+    #[test]
+    fn resolve_tokens() {
+        let tokens = DesignTokens::new()
+            .with("surface-2", "rgb(20, 20, 20)")
+            .with("space", "8");
+
+        assert_eq!(tokens.resolve("red").as_deref(), Some("red"));
+        assert_eq!(
+            tokens.resolve("token(surface-2)").as_deref(),
+            Some("rgb(20, 20, 20)")
+        );
+        assert_eq!(
+            tokens.resolve("token(space) token( space )").as_deref(),
+            Some("8 8")
+        );
+        assert_eq!(
+            tokens
+                .resolve("1 inner token(surface-2), 2 outer red")
+                .as_deref(),
+            Some("1 inner rgb(20, 20, 20), 2 outer red")
+        );
+        assert_eq!(tokens.resolve("token(missing)"), None);
+        assert_eq!(tokens.resolve("token(surface-2"), None);
+    }
+}
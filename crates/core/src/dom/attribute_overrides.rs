@@ -0,0 +1,156 @@
+use freya_native_core::{
+    attributes::AttributeName,
+    node::{
+        NodeType,
+        OwnedAttributeValue,
+    },
+    real_dom::{
+        NodeImmutable,
+        NodeTypeMut,
+    },
+    NodeId,
+};
+use rustc_hash::FxHashMap;
+
+use super::DioxusDOM;
+use crate::custom_attributes::CustomAttributeValues;
+
+/// Value written over an attribute of a Node, e.g. by the Devtools to prototype styles without recompiling.
+#[derive(Clone)]
+struct AttributeOverride {
+    value: String,
+    /// Value set by the app, restored once the override is removed.
+    original: Option<OwnedAttributeValue<CustomAttributeValues>>,
+}
+
+/// Attributes of Nodes whose values are replaced with others that take precedence over those set by the app.
+#[derive(Clone, Default)]
+pub struct AttributeOverrides {
+    nodes: FxHashMap<NodeId, FxHashMap<AttributeName, AttributeOverride>>,
+}
+
+impl AttributeOverrides {
+    /// Get the overridden attributes of the given Node and their values.
+    pub fn get(&self, node_id: NodeId) -> Vec<(AttributeName, String)> {
+        self.nodes
+            .get(&node_id)
+            .map(|attributes| {
+                attributes
+                    .iter()
+                    .map(|(attribute, attribute_override)| {
+                        (*attribute, attribute_override.value.clone())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Override the value of an attribute of a Node.
+    pub(crate) fn insert(
+        &mut self,
+        rdom: &mut DioxusDOM,
+        node_id: NodeId,
+        attribute: AttributeName,
+        value: String,
+    ) {
+        let Some(mut node) = rdom.get_mut(node_id) else {
+            return;
+        };
+        let NodeTypeMut::Element(mut element) = node.node_type_mut() else {
+            return;
+        };
+
+        let attributes = self.nodes.entry(node_id).or_default();
+        let original = element.set_attribute(attribute, value.clone());
+        let original = match attributes.remove(&attribute) {
+            // Keep the value of the app and not the one of the previous override
+            Some(previous) => previous.original,
+            None => original,
+        };
+        attributes.insert(attribute, AttributeOverride { value, original });
+    }
+
+    /// Remove the override of an attribute of a Node, restoring the value set by the app.
+    pub(crate) fn remove(
+        &mut self,
+        rdom: &mut DioxusDOM,
+        node_id: NodeId,
+        attribute: AttributeName,
+    ) {
+        let Some(attributes) = self.nodes.get_mut(&node_id) else {
+            return;
+        };
+        let Some(attribute_override) = attributes.remove(&attribute) else {
+            return;
+        };
+        if attributes.is_empty() {
+            self.nodes.remove(&node_id);
+        }
+
+        let Some(mut node) = rdom.get_mut(node_id) else {
+            return;
+        };
+        if let NodeTypeMut::Element(mut element) = node.node_type_mut() {
+            if let Some(original) = attribute_override.original {
+                element.set_attribute(attribute, original);
+            } else {
+                element.remove_attribute(&attribute);
+            }
+        }
+    }
+
+    /// Remove all the overrides, restoring the values set by the app.
+    pub(crate) fn clear(&mut self, rdom: &mut DioxusDOM) {
+        let overrides = self
+            .nodes
+            .iter()
+            .flat_map(|(node_id, attributes)| {
+                attributes.keys().map(|attribute| (*node_id, *attribute))
+            })
+            .collect::<Vec<_>>();
+        for (node_id, attribute) in overrides {
+            self.remove(rdom, node_id, attribute);
+        }
+    }
+
+    /// Write the overrides again over the attributes that the app changed since the last time,
+    /// and forget about those of removed Nodes.
+    pub(crate) fn apply(&mut self, rdom: &mut DioxusDOM) {
+        self.nodes.retain(|node_id, attributes| {
+            let changed_attributes = {
+                let Some(node) = rdom.get(*node_id) else {
+                    return false;
+                };
+                let node_type = node.node_type();
+                let NodeType::Element(element) = &*node_type else {
+                    return false;
+                };
+                attributes
+                    .iter()
+                    .filter(|(attribute, attribute_override)| {
+                        let value = element.attributes.get(attribute);
+                        value.and_then(|value| value.as_text())
+                            != Some(attribute_override.value.as_str())
+                    })
+                    .map(|(attribute, _)| *attribute)
+                    .collect::<Vec<_>>()
+            };
+
+            if changed_attributes.is_empty() {
+                return true;
+            }
+
+            let mut node = rdom.get_mut(*node_id).unwrap();
+            let NodeTypeMut::Element(mut element) = node.node_type_mut() else {
+                return false;
+            };
+            for attribute in changed_attributes {
+                let attribute_override = attributes.get_mut(&attribute).unwrap();
+                attribute_override.original =
+                    element.set_attribute(attribute, attribute_override.value.clone());
+            }
+
+            true
+        });
+    }
+}
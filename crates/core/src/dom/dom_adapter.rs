@@ -9,7 +9,10 @@ use torin::prelude::*;
 
 use crate::{
     dom::DioxusDOM,
-    states::LayoutState,
+    states::{
+        FontStyleState,
+        LayoutState,
+    },
 };
 
 /// RealDOM adapter for Torin.
@@ -39,6 +42,7 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
             .unwrap_or_default();
 
         let mut layout = node.get::<LayoutState>()?.clone();
+        let writing_direction = node.get::<FontStyleState>()?.writing_direction;
 
         // The root node expands by default
         if *node_id == self.rdom.root_id() {
@@ -67,6 +71,7 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
             content: layout.content,
             contains_text,
             spacing: layout.spacing,
+            writing_direction,
         };
 
         node.scale_if_needed(self.scale_factor);
@@ -15,6 +15,7 @@ use std::{
 
 use dioxus_core::VirtualDom;
 use freya_native_core::{
+    attributes::AttributeName,
     prelude::{
         DioxusState,
         State,
@@ -30,6 +31,7 @@ use torin::prelude::*;
 
 use super::{
     mutations_writer::MutationsWriter,
+    AttributeOverrides,
     CompositorDirtyNodes,
     ImagesCache,
     ParagraphElements,
@@ -42,15 +44,26 @@ use crate::{
     custom_attributes::CustomAttributeValues,
     elements::ParagraphElement,
     event_loop_messages::TextGroupMeasurement,
+    events::{
+        DomEvent,
+        HitTestIndex,
+        PlatformEvent,
+        TextOverflowState,
+        TextSelection,
+    },
     layers::Layers,
     render::{
         CompositorCache,
         CompositorDirtyArea,
+        ParagraphCache,
+        RasterCache,
     },
     states::{
         AccessibilityNodeState,
+        CacheState,
         CanvasState,
         CursorState,
+        DesignTokensState,
         FontStyleState,
         ImageState,
         LayerState,
@@ -60,6 +73,7 @@ use crate::{
         TransformState,
         ViewportState,
     },
+    types::NativePlatformSender,
 };
 
 pub type DioxusDOM = RealDom<CustomAttributeValues>;
@@ -138,14 +152,22 @@ pub struct FreyaDOM {
     compositor_dirty_nodes: Arc<Mutex<CompositorDirtyNodes>>,
     compositor_dirty_area: Arc<Mutex<CompositorDirtyArea>>,
     compositor_cache: Arc<Mutex<CompositorCache>>,
+    raster_cache: Arc<Mutex<RasterCache>>,
     accessibility_dirty_nodes: Arc<Mutex<AccessibilityDirtyNodes>>,
     accessibility_generator: Arc<AccessibilityGenerator>,
     images_cache: Arc<Mutex<ImagesCache>>,
+    paragraph_cache: Arc<Mutex<ParagraphCache>>,
+    hit_test_index: Arc<Mutex<HitTestIndex>>,
+    text_selection: TextSelection,
+    text_overflow: TextOverflowState,
+    attribute_overrides: AttributeOverrides,
+    updated_nodes: Vec<NodeId>,
 }
 
 impl Default for FreyaDOM {
     fn default() -> Self {
         let mut rdom = RealDom::<CustomAttributeValues>::new([
+            DesignTokensState::to_type_erased(),
             CursorState::to_type_erased(),
             FontStyleState::to_type_erased(),
             CanvasState::to_type_erased(),
@@ -157,6 +179,7 @@ impl Default for FreyaDOM {
             LayerState::to_type_erased(),
             SvgState::to_type_erased(),
             ImageState::to_type_erased(),
+            CacheState::to_type_erased(),
         ]);
         let dioxus_integration_state = DioxusState::create(&mut rdom);
         Self {
@@ -168,9 +191,16 @@ impl Default for FreyaDOM {
             compositor_dirty_nodes: Arc::default(),
             compositor_dirty_area: Arc::default(),
             compositor_cache: Arc::default(),
+            raster_cache: Arc::default(),
             accessibility_dirty_nodes: Arc::default(),
             accessibility_generator: Arc::default(),
             images_cache: Arc::default(),
+            paragraph_cache: Arc::default(),
+            hit_test_index: Arc::default(),
+            text_selection: TextSelection::default(),
+            text_overflow: TextOverflowState::default(),
+            attribute_overrides: AttributeOverrides::default(),
+            updated_nodes: Vec::new(),
         }
     }
 }
@@ -200,6 +230,10 @@ impl FreyaDOM {
         self.compositor_cache.lock().unwrap()
     }
 
+    pub fn raster_cache(&self) -> MutexGuard<RasterCache> {
+        self.raster_cache.lock().unwrap()
+    }
+
     pub fn accessibility_dirty_nodes(&self) -> MutexGuard<AccessibilityDirtyNodes> {
         self.accessibility_dirty_nodes.lock().unwrap()
     }
@@ -212,6 +246,89 @@ impl FreyaDOM {
         self.images_cache.lock().unwrap()
     }
 
+    pub fn paragraph_cache(&self) -> MutexGuard<ParagraphCache> {
+        self.paragraph_cache.lock().unwrap()
+    }
+
+    pub fn hit_test_index(&self) -> MutexGuard<HitTestIndex> {
+        self.hit_test_index.lock().unwrap()
+    }
+
+    pub fn text_selection(&self) -> &TextSelection {
+        &self.text_selection
+    }
+
+    /// Update the [TextSelection] with the given event and share the new selected text with the components.
+    ///
+    /// Returns whether the selected text changed.
+    pub fn process_text_selection(
+        &mut self,
+        event: &PlatformEvent,
+        scale_factor: f64,
+        platform_sender: &NativePlatformSender,
+    ) -> bool {
+        let mut text_selection = std::mem::take(&mut self.text_selection);
+        let changed = text_selection.process_event(self, event, scale_factor);
+        self.text_selection = text_selection;
+        if changed {
+            let selected_text = self.text_selection.text(self);
+            platform_sender.send_modify(|state| {
+                state.selected_text = selected_text;
+            });
+        }
+        changed
+    }
+
+    /// Check which texts listening to `ontextoverflow` started or stopped overflowing since the last layout.
+    pub fn process_text_overflow(&mut self) -> Vec<DomEvent> {
+        let mut text_overflow = std::mem::take(&mut self.text_overflow);
+        let events = text_overflow.process_layout(self);
+        self.text_overflow = text_overflow;
+        events
+    }
+
+    /// Drop the paragraphs and images painted with the previous fonts and reset the layout,
+    /// so it is measured again with the new ones, e.g. after registering a font.
+    pub fn invalidate_fonts(&self) {
+        self.paragraph_cache().clear();
+        self.raster_cache().clear();
+        self.layout().reset();
+    }
+
+    /// Nodes whose state changed in the last [FreyaDOM::render_mutations].
+    pub fn updated_nodes(&self) -> &[NodeId] {
+        &self.updated_nodes
+    }
+
+    pub fn attribute_overrides(&self) -> &AttributeOverrides {
+        &self.attribute_overrides
+    }
+
+    /// Override the value of an attribute of a Node over the one set by the app,
+    /// it will be updated in the next [FreyaDOM::render_mutations].
+    pub fn set_attribute_override(
+        &mut self,
+        node_id: NodeId,
+        attribute: AttributeName,
+        value: String,
+    ) {
+        self.attribute_overrides
+            .insert(&mut self.rdom, node_id, attribute, value);
+    }
+
+    /// Remove the override of an attribute of a Node and go back to the value set by the app,
+    /// it will be updated in the next [FreyaDOM::render_mutations].
+    pub fn remove_attribute_override(&mut self, node_id: NodeId, attribute: AttributeName) {
+        self.attribute_overrides
+            .remove(&mut self.rdom, node_id, attribute);
+    }
+
+    /// Remove all the attribute overrides and go back to the values set by the app,
+    /// they will be updated in the next [FreyaDOM::render_mutations].
+    pub fn clear_attribute_overrides(&mut self) {
+        self.attribute_overrides.clear(&mut self.rdom);
+    }
+
     /// Create the initial DOM from the given Mutations
     pub fn init_dom(&mut self, vdom: &mut VirtualDom, scale_factor: f32) {
         // Build the RealDOM
@@ -241,10 +358,14 @@ impl FreyaDOM {
         ctx.insert(self.images_cache.clone());
 
         self.rdom.update_state(ctx);
+
+        self.hit_test_index().invalidate();
     }
 
     /// Process the given mutations from the [`VirtualDOM`](dioxus_core::VirtualDom).
     pub fn render_mutations(&mut self, vdom: &mut VirtualDom, scale_factor: f32) -> (bool, bool) {
+        let _span = crate::profile_span!("update_dom");
+
         // Update the RealDOM
         vdom.render_immediate(&mut MutationsWriter {
             native_writer: self
@@ -261,6 +382,9 @@ impl FreyaDOM {
             images_cache: &mut self.images_cache.lock().unwrap(),
         });
 
+        // Overrides take precedence over the changes made by the app
+        self.attribute_overrides.apply(&mut self.rdom);
+
         // Update the Nodes states
         let mut ctx = SendAnyMap::new();
         ctx.insert(self.torin.clone());
@@ -276,8 +400,12 @@ impl FreyaDOM {
         let diff = self.rdom.update_state(ctx);
 
         let must_repaint = !diff.is_empty();
+        self.updated_nodes = diff.keys().copied().collect();
         let must_relayout = !self.layout().get_dirty_nodes().is_empty();
 
+        // Nodes might have been added, removed or moved to other layers
+        self.hit_test_index().invalidate();
+
         #[cfg(debug_assertions)]
         if !diff.is_empty() {
             tracing::info!(
@@ -1,3 +1,4 @@
+pub mod attribute_overrides;
 pub mod compositor_dirty_nodes;
 pub mod dom_adapter;
 pub mod doms;
@@ -5,6 +6,7 @@ pub mod images_cache;
 mod mutations_writer;
 pub mod paragraphs;
 
+pub use attribute_overrides::*;
 pub use compositor_dirty_nodes::*;
 pub use dom_adapter::*;
 pub use doms::*;
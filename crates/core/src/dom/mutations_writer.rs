@@ -132,6 +132,20 @@ impl<'a> MutationsWriter<'a> {
         // Remove from layout
         self.layout.remove(node_id, &mut dom_adapter, true);
     }
+
+    /// Mark as changed the accessibility children of the parent of `anchor_id` and
+    /// of the current parents of the last `m` nodes in the stack, as they are about to be inserted next to it.
+    fn invalidate_accessibility_parents(&mut self, anchor_id: NodeId, m: usize) {
+        let rdom = &self.native_writer.rdom;
+        let stack = &self.native_writer.state.stack;
+        let parents = std::iter::once(anchor_id)
+            .chain(stack[stack.len() - m..].iter().copied())
+            .filter_map(|node_id| rdom.get(node_id)?.parent_id());
+        for parent_id in parents {
+            self.accessibility_dirty_nodes
+                .invalidate_children(parent_id);
+        }
+    }
 }
 
 impl<'a> WriteMutations for MutationsWriter<'a> {
@@ -170,16 +184,16 @@ impl<'a> WriteMutations for MutationsWriter<'a> {
 
     fn insert_nodes_after(&mut self, id: dioxus_core::ElementId, m: usize) {
         if m > 0 {
-            self.layout.invalidate_with_reason(
-                self.native_writer.state.element_to_node_id(id),
-                DirtyReason::Reorder,
-            );
+            let anchor_id = self.native_writer.state.element_to_node_id(id);
+            self.layout
+                .invalidate_with_reason(anchor_id, DirtyReason::Reorder);
             let new_nodes =
                 &self.native_writer.state.stack[self.native_writer.state.stack.len() - m..];
             for new in new_nodes {
                 self.layout
                     .invalidate_with_reason(*new, DirtyReason::Reorder);
             }
+            self.invalidate_accessibility_parents(anchor_id, m);
 
             self.native_writer.insert_nodes_after(id, m);
         }
@@ -187,16 +201,16 @@ impl<'a> WriteMutations for MutationsWriter<'a> {
 
     fn insert_nodes_before(&mut self, id: dioxus_core::ElementId, m: usize) {
         if m > 0 {
-            self.layout.invalidate_with_reason(
-                self.native_writer.state.element_to_node_id(id),
-                DirtyReason::Reorder,
-            );
+            let anchor_id = self.native_writer.state.element_to_node_id(id);
+            self.layout
+                .invalidate_with_reason(anchor_id, DirtyReason::Reorder);
             let new_nodes =
                 &self.native_writer.state.stack[self.native_writer.state.stack.len() - m..];
             for new in new_nodes {
                 self.layout
                     .invalidate_with_reason(*new, DirtyReason::Reorder);
             }
+            self.invalidate_accessibility_parents(anchor_id, m);
 
             self.native_writer.insert_nodes_before(id, m);
         }
@@ -7,6 +7,7 @@ use crate::{
         DioxusNode,
         ImagesCache,
     },
+    fonts::ResolvedFonts,
     render::{
         get_or_create_image,
         ImageData,
@@ -28,7 +29,7 @@ impl ElementUtils for ImageElement {
         canvas: &Canvas,
         _font_collection: &mut FontCollection,
         _font_manager: &FontMgr,
-        _default_fonts: &[String],
+        _default_fonts: &ResolvedFonts,
         images_cache: &mut ImagesCache,
         _scale_factor: f32,
     ) {
@@ -14,8 +14,16 @@ use crate::{
         DioxusNode,
         ImagesCache,
     },
-    elements::paragraph::CachedParagraph,
-    render::align_main_align_paragraph,
+    elements::paragraph::{
+        CachedParagraph,
+        CachedStrokeParagraph,
+    },
+    fonts::ResolvedFonts,
+    render::{
+        align_main_align_paragraph,
+        draw_text_selection,
+        paint_paragraph,
+    },
     states::{
         FontStyleState,
         StyleState,
@@ -32,23 +40,21 @@ impl ElementUtils for LabelElement {
         canvas: &Canvas,
         _font_collection: &mut FontCollection,
         _font_manager: &FontMgr,
-        _default_fonts: &[String],
+        _default_fonts: &ResolvedFonts,
         _images_cache: &mut ImagesCache,
         _scale_factor: f32,
     ) {
-        let paragraph = &layout_node
-            .data
-            .as_ref()
-            .unwrap()
-            .get::<CachedParagraph>()
-            .unwrap()
-            .0;
+        let data = layout_node.data.as_ref().unwrap();
+        let paragraph = &data.get::<CachedParagraph>().unwrap().0;
+        let stroke = data.get::<CachedStrokeParagraph>().map(|stroke| &stroke.0);
         let area = layout_node.visible_area();
 
         let x = area.min_x();
         let y = area.min_y() + align_main_align_paragraph(node_ref, &area, paragraph);
 
-        paragraph.paint(canvas, (x, y));
+        draw_text_selection(&area, paragraph, canvas, node_ref);
+
+        paint_paragraph(&area, paragraph, stroke, canvas, node_ref, (x, y));
     }
 
     fn clip(
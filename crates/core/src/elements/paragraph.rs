@@ -28,11 +28,16 @@ use crate::{
         ImagesCache,
     },
     event_loop_messages::TextGroupMeasurement,
+    fonts::ResolvedFonts,
     render::{
         align_main_align_paragraph,
         create_paragraph,
         draw_cursor,
         draw_cursor_highlights,
+        draw_match_highlights,
+        draw_spelling_errors,
+        draw_text_selection,
+        paint_paragraph,
         ParagraphData,
     },
     states::{
@@ -52,6 +57,14 @@ pub struct CachedParagraph(pub Paragraph);
 unsafe impl Send for CachedParagraph {}
 unsafe impl Sync for CachedParagraph {}
 
+/// Paragraph drawing the `text_stroke` of a `label` or `paragraph`, see [CachedParagraph].
+pub struct CachedStrokeParagraph(pub Paragraph);
+
+/// # Safety
+/// Same as [CachedParagraph].
+unsafe impl Send for CachedStrokeParagraph {}
+unsafe impl Sync for CachedStrokeParagraph {}
+
 pub struct ParagraphElement;
 
 impl ParagraphElement {
@@ -131,28 +144,39 @@ impl ElementUtils for ParagraphElement {
         canvas: &Canvas,
         font_collection: &mut FontCollection,
         _font_manager: &FontMgr,
-        default_fonts: &[String],
+        default_fonts: &ResolvedFonts,
         _images_cache: &mut ImagesCache,
         scale_factor: f32,
     ) {
         let area = layout_node.visible_area();
         let node_cursor_state = &*node_ref.get::<CursorState>().unwrap();
 
-        let paint = |paragraph: &Paragraph| {
+        let paint = |paragraph: &Paragraph, stroke: Option<&Paragraph>| {
             let x = area.min_x();
             let y = area.min_y() + align_main_align_paragraph(node_ref, &area, paragraph);
 
+            // Draw the search matches if specified
+            draw_match_highlights(&area, paragraph, canvas, node_ref);
+
             // Draw the highlights if specified
             draw_cursor_highlights(&area, paragraph, canvas, node_ref);
 
+            // Draw the selected text if any
+            draw_text_selection(&area, paragraph, canvas, node_ref);
+
             // Draw a cursor if specified
             draw_cursor(&area, paragraph, canvas, node_ref);
 
-            paragraph.paint(canvas, (x, y));
+            paint_paragraph(&area, paragraph, stroke, canvas, node_ref, (x, y));
+
+            // Draw the spelling errors over the text if specified
+            draw_spelling_errors(&area, paragraph, canvas, node_ref);
         };
 
         if node_cursor_state.position.is_some() {
-            let ParagraphData { paragraph, .. } = create_paragraph(
+            let ParagraphData {
+                paragraph, stroke, ..
+            } = create_paragraph(
                 node_ref,
                 &area.size,
                 font_collection,
@@ -160,16 +184,12 @@ impl ElementUtils for ParagraphElement {
                 default_fonts,
                 scale_factor,
             );
-            paint(&paragraph);
+            paint(&paragraph, stroke.as_ref());
         } else {
-            let paragraph = &layout_node
-                .data
-                .as_ref()
-                .unwrap()
-                .get::<CachedParagraph>()
-                .unwrap()
-                .0;
-            paint(paragraph);
+            let data = layout_node.data.as_ref().unwrap();
+            let paragraph = &data.get::<CachedParagraph>().unwrap().0;
+            let stroke = data.get::<CachedStrokeParagraph>().map(|stroke| &stroke.0);
+            paint(paragraph, stroke);
         };
     }
 
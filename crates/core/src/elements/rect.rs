@@ -13,11 +13,11 @@ use torin::{
 
 use super::utils::ElementUtils;
 use crate::{
-    custom_attributes::CanvasRunnerContext,
     dom::{
         DioxusNode,
         ImagesCache,
     },
+    fonts::ResolvedFonts,
     render::{
         border_shape,
         render_border,
@@ -91,7 +91,7 @@ impl ElementUtils for RectElement {
         canvas: &Canvas,
         font_collection: &mut FontCollection,
         _font_manager: &FontMgr,
-        _default_fonts: &[String],
+        _default_fonts: &ResolvedFonts,
         _images_cache: &mut ImagesCache,
         scale_factor: f32,
     ) {
@@ -157,13 +157,7 @@ impl ElementUtils for RectElement {
         // Canvas reference
         let references = node_ref.get::<CanvasState>().unwrap();
         if let Some(canvas_ref) = &references.canvas_ref {
-            let mut ctx = CanvasRunnerContext {
-                canvas,
-                font_collection,
-                area,
-                scale_factor,
-            };
-            (canvas_ref.runner.lock().unwrap())(&mut ctx);
+            canvas_ref.render(canvas, font_collection, area, scale_factor);
         }
     }
 
@@ -8,6 +8,7 @@ use crate::{
         DioxusNode,
         ImagesCache,
     },
+    fonts::ResolvedFonts,
     states::{
         FontStyleState,
         SvgState,
@@ -24,7 +25,7 @@ impl ElementUtils for SvgElement {
         canvas: &Canvas,
         _font_collection: &mut FontCollection,
         font_manager: &FontMgr,
-        _default_fonts: &[String],
+        _default_fonts: &ResolvedFonts,
         _images_cache: &mut ImagesCache,
         _scale_factor: f32,
     ) {
@@ -23,6 +23,7 @@ use crate::{
         DioxusNode,
         ImagesCache,
     },
+    fonts::ResolvedFonts,
     states::{
         StyleState,
         TransformState,
@@ -58,7 +59,7 @@ pub trait ElementUtils {
         canvas: &Canvas,
         font_collection: &mut FontCollection,
         font_manager: &FontMgr,
-        default_fonts: &[String],
+        default_fonts: &ResolvedFonts,
         images_cache: &mut ImagesCache,
         scale_factor: f32,
     );
@@ -229,7 +230,7 @@ impl ElementUtils for ElementWithUtils {
         canvas: &Canvas,
         font_collection: &mut FontCollection,
         font_manager: &FontMgr,
-        default_fonts: &[String],
+        default_fonts: &ResolvedFonts,
         images_cache: &mut ImagesCache,
         scale_factor: f32,
     ) {
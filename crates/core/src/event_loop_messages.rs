@@ -1,3 +1,18 @@
+use std::{
+    any::Any,
+    ffi::OsString,
+};
+
+use bytes::Bytes;
+use freya_engine::prelude::Color;
+use freya_native_core::{
+    attributes::AttributeName,
+    NodeId,
+};
+use tokio::sync::{
+    mpsc::UnboundedSender,
+    oneshot,
+};
 use torin::prelude::{
     Area,
     CursorPoint,
@@ -10,7 +25,55 @@ use winit::window::{
 
 use crate::{
     accessibility::AccessibilityFocusStrategy,
+    custom_attributes::NodeReference,
     events::PlatformEvent,
+    fonts::{
+        FontError,
+        FontFallbacks,
+        FontSource,
+    },
+    global_shortcuts::{
+        GlobalShortcut,
+        GlobalShortcutError,
+        GlobalShortcutState,
+    },
+    native_menu::{
+        NativeMenu,
+        NativeSubmenu,
+    },
+    native_tray::{
+        NativeTrayEvent,
+        NativeTrayIcon,
+    },
+    open_requests::OpenRequest,
+    opener::{
+        OpenError,
+        OpenTarget,
+    },
+    platform::CursorImage,
+    platform_state::{
+        FramePacing,
+        FrameStats,
+        SoftKeyboard,
+        TaskbarProgress,
+        WindowEffect,
+    },
+    printing::{
+        PrintError,
+        PrintOptions,
+        Printer,
+    },
+    render::{
+        CapturedFrame,
+        ExportFormat,
+        ExportTarget,
+    },
+    webview::{
+        WebViewConfig,
+        WebViewContent,
+        WebViewEvent,
+        WebViewId,
+    },
 };
 
 pub struct TextGroupMeasurement {
@@ -34,16 +97,137 @@ pub enum EventLoopMessage {
     RemeasureTextGroup(TextGroupMeasurement),
     /// Change the cursor icon
     SetCursorIcon(CursorIcon),
+    /// Change the cursor to an image
+    SetCustomCursor(CursorImage),
     /// Accessibility Window Event
     Accessibility(accesskit_winit::WindowEvent),
     /// Focus with the given strategy
     FocusAccessibilityNode(AccessibilityFocusStrategy),
     /// Close the whole app
     ExitApp,
+    /// Close the window, or hide it if it's configured to be hidden to the tray on close
+    CloseWindow,
+    /// Another instance of the app was launched and forwarded its arguments, see `LaunchConfig::single_instance`
+    SecondInstance {
+        args: Vec<OsString>,
+        /// Links and files in the arguments.
+        open_requests: Vec<OpenRequest>,
+    },
     /// Callback to access the Window.
     WithWindow(Box<dyn FnOnce(&Window) + Send + Sync>),
     /// Raw platform event, this are low level events.
     PlatformEvent(PlatformEvent),
+    /// Custom message for the plugins, see [PluginHandle::send_message](crate::plugins::PluginHandle::send_message).
+    PluginMessage(Box<dyn Any + Send + Sync>),
+    /// Rasterize the node with the given reference into a PNG image.
+    SnapshotNode {
+        reference: NodeReference,
+        scale: f32,
+        sender: oneshot::Sender<Option<Bytes>>,
+    },
+    /// Encode the last rendered frame of the window into a PNG image.
+    CaptureWindow(oneshot::Sender<Option<Bytes>>),
+    /// Render the window or a node into a PDF or SVG document.
+    Export {
+        target: ExportTarget,
+        format: ExportFormat,
+        sender: oneshot::Sender<Option<Bytes>>,
+    },
+    /// Get the printers installed in the system.
+    GetPrinters(oneshot::Sender<Vec<Printer>>),
+    /// Print the window or a node and get the number of printed pages.
+    Print {
+        target: ExportTarget,
+        options: PrintOptions,
+        sender: oneshot::Sender<Result<usize, PrintError>>,
+    },
+    /// Open a link or a file, or show a file in the file manager.
+    Open {
+        target: OpenTarget,
+        sender: oneshot::Sender<Result<(), OpenError>>,
+    },
+    /// Send every rendered frame to the given channel, or stop if `None`.
+    SetFrameCapture(Option<UnboundedSender<CapturedFrame>>),
+    /// Change how frames are paced and presented.
+    SetFramePacing(FramePacing),
+    /// Change the frame rate limit while the window is in the background, see [FramePacing::background_max_fps].
+    SetBackgroundMaxFps(Option<u32>),
+    /// Get the rendering statistics.
+    GetFrameStats(oneshot::Sender<FrameStats>),
+    /// Override the value of an attribute of a Node over the one set by the app,
+    /// or remove the override if `None`.
+    SetAttributeOverride {
+        node_id: NodeId,
+        attribute: AttributeName,
+        value: Option<String>,
+    },
+    /// Remove all the attribute overrides.
+    ClearAttributeOverrides,
+    /// Paint the bounds, margins, paddings and alignments of every node over the app.
+    SetDebugLayout(bool),
+    /// The accent color of the operating system has been read.
+    AccentColorChanged(Option<Color>),
+    /// Replace the native menu of the window, or remove it if `None`.
+    SetNativeMenu(Option<NativeMenu>),
+    /// Check or uncheck an item of the native menu.
+    SetNativeMenuItemChecked(String, bool),
+    /// Enable or disable an item of the native menu.
+    SetNativeMenuItemEnabled(String, bool),
+    /// An item of the native menu or the tray menu has been activated.
+    NativeMenuItemActivated(String),
+    /// Replace the icon of the system tray, or remove it if `None`.
+    SetNativeTrayIcon(Option<NativeTrayIcon>),
+    /// Replace the tooltip of the system tray.
+    SetNativeTrayTooltip(Option<String>),
+    /// Replace the menu of the system tray.
+    SetNativeTrayMenu(Option<NativeSubmenu>),
+    /// The system tray icon has been interacted with.
+    NativeTrayEvent(NativeTrayEvent),
+    /// Register a global shortcut in the operating system, replacing the one with the same id.
+    RegisterGlobalShortcut(
+        GlobalShortcut,
+        oneshot::Sender<Result<(), GlobalShortcutError>>,
+    ),
+    /// Unregister the global shortcut with the given id.
+    UnregisterGlobalShortcut(String),
+    /// The global shortcut with the given native id has been triggered.
+    GlobalShortcutTriggered(u32, GlobalShortcutState),
+    /// Show the progress in the taskbar icon of the window, or hide it if `None`.
+    SetTaskbarProgress(Option<TaskbarProgress>),
+    /// Change the effect shown behind the transparent areas of the window, or remove it if `None`.
+    SetWindowEffect(Option<WindowEffect>),
+    /// Show or hide the on-screen keyboard.
+    SetSoftKeyboardVisible(bool),
+    /// The on-screen keyboard has been shown, hidden or resized.
+    ///
+    /// Sent by the platform integrations that know the area covered by the keyboard.
+    SoftKeyboardChanged(SoftKeyboard),
+    /// Register a font, optionally under another family, and get the family it can be used with.
+    RegisterFont(
+        FontSource,
+        Option<String>,
+        oneshot::Sender<Result<String, FontError>>,
+    ),
+    /// Replace the chains of fallback fonts.
+    SetFontFallbacks(FontFallbacks),
+    /// Get the families of the registered fonts and the fonts installed in the system.
+    GetFontFamilies(oneshot::Sender<Vec<String>>),
+    /// Create a native webview over the given area of the window, in logical pixels.
+    /// Its events are sent to the given channel.
+    CreateWebView {
+        id: WebViewId,
+        config: WebViewConfig,
+        area: Area,
+        sender: UnboundedSender<WebViewEvent>,
+    },
+    /// Move and resize a webview, or hide it if `None`.
+    SetWebViewArea(WebViewId, Option<Area>),
+    /// Load other content in a webview.
+    SetWebViewContent(WebViewId, WebViewContent),
+    /// Run a script in the page of a webview.
+    EvaluateWebViewScript(WebViewId, String),
+    /// Remove a webview.
+    RemoveWebView(WebViewId),
 }
 
 impl From<accesskit_winit::Event> for EventLoopMessage {
@@ -0,0 +1,104 @@
+use freya_native_core::{
+    real_dom::NodeImmutable,
+    NodeId,
+};
+use torin::prelude::CursorPoint;
+use winit::window::CursorIcon;
+
+use super::link_at;
+use crate::{
+    dom::FreyaDOM,
+    elements::{
+        ElementUtils,
+        ElementUtilsResolver,
+    },
+    states::{
+        StyleState,
+        TransformState,
+        ViewportState,
+    },
+};
+
+/// Get the topmost node under the given point, considering the clipping of its viewports.
+pub fn topmost_node_at(fdom: &FreyaDOM, cursor: CursorPoint, scale_factor: f64) -> Option<NodeId> {
+    let layout = fdom.layout();
+    let rdom = fdom.rdom();
+    let layers = fdom.layers();
+    let mut hit_test_index = fdom.hit_test_index();
+    hit_test_index.update(&layers, &layout, rdom);
+
+    // Walk from the top to the bottom until a node is hit
+    'candidates: for (_, entry) in hit_test_index.query(cursor.to_f32()).into_iter().rev() {
+        let (Some(node), Some(layout_node)) = (rdom.get(entry.node_id), layout.get(entry.node_id))
+        else {
+            continue;
+        };
+        let node_type = node.node_type();
+        let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
+            continue;
+        };
+
+        // Make sure the cursor is inside the node area, as if it wasn't scaled
+        let transform_state = node.get::<TransformState>().unwrap();
+        let node_cursor = transform_state.untransform_point(cursor, &layout);
+        if !element_utils.is_point_inside_area(
+            &node_cursor,
+            &node,
+            layout_node,
+            scale_factor as f32,
+        ) {
+            continue;
+        }
+
+        // Make sure the cursor is inside all the inherited viewports of the node
+        let node_viewports = node.get::<ViewportState>().unwrap();
+        for node_id in &node_viewports.viewports {
+            let node_ref = rdom.get(*node_id).unwrap();
+            let node_type = node_ref.node_type();
+            let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
+                continue;
+            };
+            let layout_node = layout.get(*node_id).unwrap();
+            let transform_state = node_ref.get::<TransformState>().unwrap();
+            if !element_utils.is_point_inside_area(
+                &transform_state.untransform_point(cursor, &layout),
+                &node_ref,
+                layout_node,
+                scale_factor as f32,
+            ) {
+                continue 'candidates;
+            }
+        }
+
+        return Some(entry.node_id);
+    }
+
+    None
+}
+
+/// Get the cursor icon of the topmost node under the given point,
+/// which is the one set with the `cursor` attribute in the node or in its closest ancestor.
+/// Links in paragraphs use the pointer icon.
+pub fn cursor_icon_at(
+    fdom: &FreyaDOM,
+    cursor: CursorPoint,
+    scale_factor: f64,
+) -> Option<CursorIcon> {
+    let node_id = topmost_node_at(fdom, cursor, scale_factor)?;
+
+    if link_at(fdom, node_id, cursor).is_some() {
+        return Some(CursorIcon::Pointer);
+    }
+
+    // The closest node with a cursor icon wins
+    let rdom = fdom.rdom();
+    let mut head = rdom.get(node_id);
+    while let Some(node) = head {
+        if let Some(cursor_icon) = node.get::<StyleState>().unwrap().cursor {
+            return Some(cursor_icon);
+        }
+        head = node.parent();
+    }
+
+    None
+}
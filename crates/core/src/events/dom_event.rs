@@ -7,9 +7,13 @@ use freya_elements::events::{
     pointer::PointerType,
     ErasedEventData,
     FileData,
+    ImeData,
     KeyboardData,
+    LinkClickData,
+    LinkHoverData,
     MouseData,
     PointerData,
+    TextOverflowData,
     TouchData,
     WheelData,
 };
@@ -46,6 +50,9 @@ impl Ord for DomEvent {
 }
 
 impl DomEvent {
+    /// Create an event for the given node.
+    /// `node_area` is where the node is rendered and `node_scale` how much it is scaled,
+    /// so the element coordinates are measured in the units of the node.
     pub fn new(
         PotentialEvent {
             node_id,
@@ -54,6 +61,7 @@ impl DomEvent {
             ..
         }: PotentialEvent,
         node_area: Option<Area>,
+        (node_scale_x, node_scale_y): (f32, f32),
         scale_factor: f64,
     ) -> Self {
         let bubbles = name.does_bubble();
@@ -61,10 +69,10 @@ impl DomEvent {
         match data {
             PlatformEventData::Mouse { cursor, button, .. } => {
                 let screen_coordinates = cursor / scale_factor;
-                let element_x =
-                    (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
-                let element_y =
-                    (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+                let element_x = (cursor.x - node_area.unwrap_or_default().min_x() as f64)
+                    / (scale_factor * node_scale_x as f64);
+                let element_y = (cursor.y - node_area.unwrap_or_default().min_y() as f64)
+                    / (scale_factor * node_scale_y as f64);
 
                 let event_data = if name.is_pointer() {
                     DomEventData::Pointer(PointerData::new(
@@ -106,6 +114,12 @@ impl DomEvent {
                 data: DomEventData::Keyboard(KeyboardData::new(key.clone(), code, modifiers)),
                 bubbles,
             },
+            PlatformEventData::Ime { text, cursor } => Self {
+                node_id,
+                name,
+                data: DomEventData::Ime(ImeData { text, cursor }),
+                bubbles,
+            },
             PlatformEventData::Touch {
                 location,
                 finger_id,
@@ -113,8 +127,10 @@ impl DomEvent {
                 force,
                 ..
             } => {
-                let element_x = location.x - node_area.unwrap_or_default().min_x() as f64;
-                let element_y = location.y - node_area.unwrap_or_default().min_y() as f64;
+                let element_x = (location.x - node_area.unwrap_or_default().min_x() as f64)
+                    / node_scale_x as f64;
+                let element_y = (location.y - node_area.unwrap_or_default().min_y() as f64)
+                    / node_scale_y as f64;
 
                 let event_data = if name.is_pointer() {
                     DomEventData::Pointer(PointerData::new(
@@ -166,6 +182,10 @@ pub enum DomEventData {
     Touch(TouchData),
     Pointer(PointerData),
     File(FileData),
+    Ime(ImeData),
+    TextOverflow(TextOverflowData),
+    LinkClick(LinkClickData),
+    LinkHover(LinkHoverData),
 }
 
 impl DomEventData {
@@ -177,6 +197,10 @@ impl DomEventData {
             DomEventData::Touch(t) => Rc::new(ErasedEventData::new(Box::new(t))),
             DomEventData::Pointer(p) => Rc::new(ErasedEventData::new(Box::new(p))),
             DomEventData::File(fd) => Rc::new(ErasedEventData::new(Box::new(fd))),
+            DomEventData::Ime(i) => Rc::new(ErasedEventData::new(Box::new(i))),
+            DomEventData::TextOverflow(t) => Rc::new(ErasedEventData::new(Box::new(t))),
+            DomEventData::LinkClick(l) => Rc::new(ErasedEventData::new(Box::new(l))),
+            DomEventData::LinkHover(l) => Rc::new(ErasedEventData::new(Box::new(l))),
         }
     }
 }
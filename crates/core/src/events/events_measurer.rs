@@ -4,7 +4,6 @@ use freya_native_core::{
     tree::TreeRef,
     NodeId,
 };
-use itertools::sorted;
 
 use super::{
     PlatformEventData,
@@ -26,6 +25,7 @@ use crate::{
     },
     states::{
         StyleState,
+        TransformState,
         ViewportState,
     },
     types::{
@@ -46,6 +46,12 @@ pub fn process_events(
 
     focus_id: Option<NodeId>,
 ) {
+    let span = crate::profile_span!(
+        "process_events",
+        platform_events = events.len(),
+        dom_events = tracing::field::Empty
+    );
+
     // Get potential events that could be emitted based on the elements layout and viewports
     let potential_events = measure_potential_event_listeners(events, fdom, scale_factor, focus_id);
 
@@ -71,6 +77,11 @@ pub fn process_events(
     measure_global_events(fdom, events, &mut dom_events, scale_factor);
     dom_events.extend(collateral_global_events);
 
+    // Get the clicks on links and the hovered links
+    dom_events.extend(nodes_state.process_links(fdom, events, scale_factor));
+
+    span.record("dom_events", dom_events.len());
+
     // Send all the events
     event_emitter.send(dom_events).unwrap();
 
@@ -118,6 +129,7 @@ pub fn measure_global_events(
                     data: data.clone(),
                 },
                 None,
+                (1., 1.),
                 scale_factor,
             );
             dom_events.push(event)
@@ -137,85 +149,107 @@ pub fn measure_potential_event_listeners(
     let layout = fdom.layout();
     let rdom = fdom.rdom();
     let layers = fdom.layers();
+    let mut hit_test_index = fdom.hit_test_index();
+    hit_test_index.update(&layers, &layout, rdom);
+
+    // Only the nodes under any of the pointers or focused can be targeted
+    let mut candidates = events
+        .iter()
+        .filter_map(|PlatformEvent { data, .. }| match data {
+            PlatformEventData::Mouse { cursor, .. } => Some(cursor),
+            PlatformEventData::Wheel { cursor, .. } => Some(cursor),
+            PlatformEventData::Touch { location, .. } => Some(location),
+            PlatformEventData::File { cursor, .. } => Some(cursor),
+            _ => None,
+        })
+        .flat_map(|cursor| hit_test_index.query(cursor.to_f32()))
+        .chain(focus_id.and_then(|focus_id| hit_test_index.get(&focus_id)))
+        .map(|(position, entry)| (position, entry.layer, entry.node_id))
+        .collect::<Vec<_>>();
+    candidates.sort_unstable_by_key(|(position, ..)| *position);
+    candidates.dedup_by_key(|(position, ..)| *position);
 
     // Walk layer by layer from the bottom to the top
-    for (layer, layer_nodes) in sorted(layers.iter()) {
-        for node_id in layer_nodes.iter() {
-            let Some(layout_node) = layout.get(*node_id) else {
+    for (_, layer, node_id) in &candidates {
+        let Some(layout_node) = layout.get(*node_id) else {
+            continue;
+        };
+        'events: for PlatformEvent { name, data } in events {
+            let cursor = match data {
+                PlatformEventData::Mouse { cursor, .. } => cursor,
+                PlatformEventData::Wheel { cursor, .. } => cursor,
+                PlatformEventData::Touch { location, .. } => location,
+                PlatformEventData::File { cursor, .. } => cursor,
+                PlatformEventData::Keyboard { .. } | PlatformEventData::Ime { .. }
+                    if focus_id == Some(*node_id) =>
+                {
+                    let potential_event = PotentialEvent {
+                        node_id: *node_id,
+                        layer: Some(*layer),
+                        name: *name,
+                        data: data.clone(),
+                    };
+                    potential_events
+                        .entry(*name)
+                        .or_default()
+                        .push(potential_event);
+                    continue;
+                }
+                _ => continue,
+            };
+
+            let node = rdom.get(*node_id).unwrap();
+            let node_type = node.node_type();
+
+            let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
                 continue;
             };
-            'events: for PlatformEvent { name, data } in events {
-                let cursor = match data {
-                    PlatformEventData::Mouse { cursor, .. } => cursor,
-                    PlatformEventData::Wheel { cursor, .. } => cursor,
-                    PlatformEventData::Touch { location, .. } => location,
-                    PlatformEventData::File { cursor, .. } => cursor,
-                    PlatformEventData::Keyboard { .. } if focus_id == Some(*node_id) => {
-                        let potential_event = PotentialEvent {
-                            node_id: *node_id,
-                            layer: Some(*layer),
-                            name: *name,
-                            data: data.clone(),
-                        };
-                        potential_events
-                            .entry(*name)
-                            .or_default()
-                            .push(potential_event);
-                        continue;
-                    }
-                    _ => continue,
-                };
 
-                let node = rdom.get(*node_id).unwrap();
-                let node_type = node.node_type();
+            // Make sure the cursor is inside the node area, as if it wasn't scaled
+            let transform_state = node.get::<TransformState>().unwrap();
+            let node_cursor = transform_state.untransform_point(*cursor, &layout);
+            if !element_utils.is_point_inside_area(
+                &node_cursor,
+                &node,
+                layout_node,
+                scale_factor as f32,
+            ) {
+                continue;
+            }
+
+            let node = rdom.get(*node_id).unwrap();
+            let node_viewports = node.get::<ViewportState>().unwrap();
 
+            // Make sure the cursor is inside all the inherited viewports of the node
+            for node_id in &node_viewports.viewports {
+                let node_ref = rdom.get(*node_id).unwrap();
+                let node_type = node_ref.node_type();
                 let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
                     continue;
                 };
-
-                // Make sure the cursor is inside the node area
+                let layout_node = layout.get(*node_id).unwrap();
+                let transform_state = node_ref.get::<TransformState>().unwrap();
                 if !element_utils.is_point_inside_area(
-                    cursor,
-                    &node,
+                    &transform_state.untransform_point(*cursor, &layout),
+                    &node_ref,
                     layout_node,
                     scale_factor as f32,
                 ) {
-                    continue;
-                }
-
-                let node = rdom.get(*node_id).unwrap();
-                let node_viewports = node.get::<ViewportState>().unwrap();
-
-                // Make sure the cursor is inside all the inherited viewports of the node
-                for node_id in &node_viewports.viewports {
-                    let node_ref = rdom.get(*node_id).unwrap();
-                    let node_type = node_ref.node_type();
-                    let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
-                        continue;
-                    };
-                    let layout_node = layout.get(*node_id).unwrap();
-                    if !element_utils.is_point_inside_area(
-                        cursor,
-                        &node_ref,
-                        layout_node,
-                        scale_factor as f32,
-                    ) {
-                        continue 'events;
-                    }
+                    continue 'events;
                 }
+            }
 
-                let potential_event = PotentialEvent {
-                    node_id: *node_id,
-                    layer: Some(*layer),
-                    name: *name,
-                    data: data.clone(),
-                };
+            let potential_event = PotentialEvent {
+                node_id: *node_id,
+                layer: Some(*layer),
+                name: *name,
+                data: data.clone(),
+            };
 
-                potential_events
-                    .entry(*name)
-                    .or_insert_with(Vec::new)
-                    .push(potential_event);
-            }
+            potential_events
+                .entry(*name)
+                .or_insert_with(Vec::new)
+                .push(potential_event);
         }
     }
 
@@ -281,9 +315,11 @@ fn measure_dom_events(
                     };
 
                     let layout_node = layout.get(*node_id).unwrap();
+                    let transform_state = node.get::<TransformState>().unwrap();
                     let dom_event = DomEvent::new(
                         potential_event,
-                        Some(layout_node.visible_area()),
+                        Some(transform_state.transform_area(layout_node.visible_area(), &layout)),
+                        transform_state.total_scale(),
                         scale_factor,
                     );
                     dom_events.push(dom_event);
@@ -0,0 +1,236 @@
+use freya_native_core::{
+    real_dom::NodeImmutable,
+    NodeId,
+};
+use itertools::sorted;
+use rustc_hash::FxHashMap;
+use torin::{
+    geometry::Area,
+    prelude::Point2D,
+    torin::Torin,
+};
+
+use crate::{
+    dom::DioxusDOM,
+    layers::Layers,
+    states::TransformState,
+};
+
+/// Maximum amount of entries in a quadrant before it is subdivided.
+const QUADRANT_CAPACITY: usize = 16;
+
+/// Maximum depth of the quadtree.
+const MAX_DEPTH: usize = 10;
+
+/// Node that can be hit by a pointer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HitTestEntry {
+    pub node_id: NodeId,
+    pub layer: i16,
+    pub area: Area,
+}
+
+/// Region quadtree of areas, each area is stored in the smallest quadrant that fully contains it.
+#[derive(Default, Debug)]
+struct Quadrant {
+    bounds: Area,
+    /// Indexes of the areas that don't fit in any child quadrant.
+    items: Vec<usize>,
+    children: Option<Box<[Quadrant; 4]>>,
+}
+
+impl Quadrant {
+    fn build(bounds: Area, items: Vec<usize>, areas: &[Area], depth: usize) -> Self {
+        if items.len() <= QUADRANT_CAPACITY || depth == MAX_DEPTH {
+            return Self {
+                bounds,
+                items,
+                children: None,
+            };
+        }
+
+        let half = bounds.size / 2.;
+        let quadrants_bounds = [
+            Area::new(bounds.origin, half),
+            Area::new(
+                bounds.origin + Point2D::new(half.width, 0.).to_vector(),
+                half,
+            ),
+            Area::new(
+                bounds.origin + Point2D::new(0., half.height).to_vector(),
+                half,
+            ),
+            Area::new(bounds.origin + half.to_vector(), half),
+        ];
+
+        let mut quadrants_items: [Vec<usize>; 4] = Default::default();
+        let mut remaining_items = Vec::new();
+        for item in items {
+            let area = &areas[item];
+            match quadrants_bounds
+                .iter()
+                .position(|quadrant_bounds| quadrant_bounds.contains_rect(area))
+            {
+                Some(quadrant) => quadrants_items[quadrant].push(item),
+                None => remaining_items.push(item),
+            }
+        }
+
+        // Subdividing is pointless if every area overlaps multiple quadrants
+        if quadrants_items.iter().all(Vec::is_empty) {
+            return Self {
+                bounds,
+                items: remaining_items,
+                children: None,
+            };
+        }
+
+        let [top_left, top_right, bottom_left, bottom_right] = quadrants_items;
+        let [top_left_bounds, top_right_bounds, bottom_left_bounds, bottom_right_bounds] =
+            quadrants_bounds;
+        Self {
+            bounds,
+            items: remaining_items,
+            children: Some(Box::new([
+                Self::build(top_left_bounds, top_left, areas, depth + 1),
+                Self::build(top_right_bounds, top_right, areas, depth + 1),
+                Self::build(bottom_left_bounds, bottom_left, areas, depth + 1),
+                Self::build(bottom_right_bounds, bottom_right, areas, depth + 1),
+            ])),
+        }
+    }
+
+    fn query(&self, point: Point2D, areas: &[Area], result: &mut Vec<usize>) {
+        let mut quadrant = Some(self);
+        while let Some(current) = quadrant.take() {
+            result.extend(
+                current
+                    .items
+                    .iter()
+                    .filter(|item| areas[**item].contains(point)),
+            );
+            quadrant = current
+                .children
+                .as_ref()
+                .and_then(|children| children.iter().find(|child| child.bounds.contains(point)));
+        }
+    }
+}
+
+/// Spatial index of the layout areas of the nodes, used to find what nodes
+/// are under a pointer without iterating over all of them.
+/// Areas of scaled nodes are indexed where they are rendered.
+///
+/// It is rebuilt lazily after being invalidated by layout or DOM changes.
+#[derive(Default, Debug)]
+pub struct HitTestIndex {
+    /// Entries in painting order, bottom layers first.
+    entries: Vec<HitTestEntry>,
+    areas: Vec<Area>,
+    positions: FxHashMap<NodeId, usize>,
+    root: Quadrant,
+    is_valid: bool,
+}
+
+impl HitTestIndex {
+    /// Mark the index as outdated, it will be rebuilt on next [HitTestIndex::update].
+    pub fn invalidate(&mut self) {
+        self.is_valid = false;
+    }
+
+    /// Rebuild the index from the given layers and layout if it is outdated.
+    pub fn update(&mut self, layers: &Layers, layout: &Torin<NodeId>, rdom: &DioxusDOM) {
+        if self.is_valid {
+            return;
+        }
+
+        self.entries.clear();
+        self.positions.clear();
+        for (layer, layer_nodes) in sorted(layers.iter()) {
+            for node_id in layer_nodes {
+                let (Some(layout_node), Some(node)) = (layout.get(*node_id), rdom.get(*node_id))
+                else {
+                    continue;
+                };
+                let transform_state = node.get::<TransformState>().unwrap();
+                self.positions.insert(*node_id, self.entries.len());
+                self.entries.push(HitTestEntry {
+                    node_id: *node_id,
+                    layer: *layer,
+                    area: transform_state.transform_area(layout_node.area, layout),
+                });
+            }
+        }
+
+        self.areas = self.entries.iter().map(|entry| entry.area).collect();
+        let bounds = self
+            .areas
+            .iter()
+            .copied()
+            .reduce(|bounds, area| bounds.union(&area))
+            .unwrap_or_default();
+        self.root = Quadrant::build(bounds, (0..self.areas.len()).collect(), &self.areas, 0);
+        self.is_valid = true;
+    }
+
+    /// Get the entries whose area contains the given point, in painting order.
+    /// Each entry comes with its position in painting order.
+    pub fn query(&self, point: Point2D) -> Vec<(usize, &HitTestEntry)> {
+        let mut result = Vec::new();
+        self.root.query(point, &self.areas, &mut result);
+        result.sort_unstable();
+        result
+            .into_iter()
+            .map(|position| (position, &self.entries[position]))
+            .collect()
+    }
+
+    /// Get the entry of the given node, with its position in painting order.
+    pub fn get(&self, node_id: &NodeId) -> Option<(usize, &HitTestEntry)> {
+        let position = *self.positions.get(node_id)?;
+        Some((position, &self.entries[position]))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use torin::{
+        geometry::Area,
+        prelude::{
+            Point2D,
+            Size2D,
+        },
+    };
+
+    use super::Quadrant;
+
+    #[test]
+    fn quadtree_query() {
+        // A grid of 20x20 cells of 10x10 and a full size background
+        let mut areas = vec![Area::new(Point2D::new(0., 0.), Size2D::new(200., 200.))];
+        for y in 0..20 {
+            for x in 0..20 {
+                areas.push(Area::new(
+                    Point2D::new(x as f32 * 10., y as f32 * 10.),
+                    Size2D::new(10., 10.),
+                ));
+            }
+        }
+        let quadtree = Quadrant::build(areas[0], (0..areas.len()).collect(), &areas, 0);
+        assert!(quadtree.children.is_some());
+
+        let query = |x: f32, y: f32| {
+            let mut result = Vec::new();
+            quadtree.query(Point2D::new(x, y), &areas, &mut result);
+            result.sort_unstable();
+            result
+        };
+
+        assert_eq!(query(5., 5.), vec![0, 1]);
+        assert_eq!(query(195., 5.), vec![0, 20]);
+        // Right in the middle
+        assert_eq!(query(100., 100.), vec![0, 211]);
+        assert_eq!(query(99.5, 99.5), vec![0, 190]);
+        assert_eq!(query(250., 5.), Vec::<usize>::new());
+    }
+}
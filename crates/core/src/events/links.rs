@@ -0,0 +1,212 @@
+use std::ops::Range;
+
+use freya_elements::events::{
+    LinkClickData,
+    LinkHoverData,
+};
+use freya_engine::prelude::*;
+use freya_native_core::{
+    attributes::AttributeName,
+    node::{
+        ElementNode,
+        NodeType,
+    },
+    real_dom::NodeImmutable,
+    tags::TagName,
+    NodeId,
+};
+use rustc_hash::FxHashMap;
+use torin::prelude::{
+    Area,
+    CursorPoint,
+    LayoutNode,
+};
+
+use super::{
+    topmost_node_at,
+    DomEvent,
+    DomEventData,
+    EventName,
+    PlatformEvent,
+    PlatformEventData,
+};
+use crate::{
+    dom::{
+        DioxusNode,
+        FreyaDOM,
+    },
+    elements::CachedParagraph,
+    render::align_main_align_paragraph,
+    states::TransformState,
+    types::EventsQueue,
+};
+
+/// Get the `text` spans of a `paragraph` along with their range in the text,
+/// measured in UTF-16 code units like the glyphs of the paragraph.
+pub fn paragraph_spans<'a>(paragraph: &'a DioxusNode) -> Vec<(DioxusNode<'a>, Range<usize>)> {
+    let mut spans = Vec::new();
+    let mut start = 0;
+    for span in paragraph.children() {
+        if span.node_type().tag() != Some(&TagName::Text) {
+            continue;
+        }
+        let len = span
+            .children()
+            .first()
+            .and_then(|text| {
+                text.node_type()
+                    .text()
+                    .map(|text| text.encode_utf16().count())
+            })
+            .unwrap_or_default();
+        let end = start + len;
+        spans.push((span, start..end));
+        start = end;
+    }
+    spans
+}
+
+/// Get the area where the given range of text of a measured `paragraph` is rendered, relative to its layout.
+pub fn paragraph_range_area(
+    paragraph: &DioxusNode,
+    layout_node: &LayoutNode,
+    range: Range<usize>,
+) -> Option<Area> {
+    let cached_paragraph = &layout_node.data.as_ref()?.get::<CachedParagraph>()?.0;
+    let area = layout_node.visible_area();
+    let offset_y = align_main_align_paragraph(paragraph, &area, cached_paragraph);
+
+    cached_paragraph
+        .get_rects_for_range(range, RectHeightStyle::Max, RectWidthStyle::Tight)
+        .iter()
+        .map(|text_box| {
+            let rect = text_box.rect;
+            Area::new(
+                (area.min_x() + rect.left, area.min_y() + offset_y + rect.top).into(),
+                (rect.width(), rect.height()).into(),
+            )
+        })
+        .reduce(|bounds, area| bounds.union(&area))
+}
+
+/// Get the `link` of the `text` span under the given point in a `paragraph`, if any.
+pub fn link_at(fdom: &FreyaDOM, node_id: NodeId, cursor: CursorPoint) -> Option<String> {
+    let rdom = fdom.rdom();
+    let layout = fdom.layout();
+    let node = rdom.get(node_id)?;
+    if node.node_type().tag() != Some(&TagName::Paragraph) {
+        return None;
+    }
+    let layout_node = layout.get(node_id)?;
+    let paragraph = &layout_node.data.as_ref()?.get::<CachedParagraph>()?.0;
+
+    // Map the cursor to where it would be if the paragraph wasn't transformed
+    let transform_state = node.get::<TransformState>().unwrap();
+    let cursor = transform_state.untransform_point(cursor, &layout);
+
+    let area = layout_node.visible_area();
+    let x = cursor.x as f32 - area.min_x();
+    let y = cursor.y as f32 - area.min_y() - align_main_align_paragraph(&node, &area, paragraph);
+    let position = paragraph.get_glyph_position_at_coordinate((x as i32, y as i32));
+    let position = position.position as usize;
+
+    let (span, range) = paragraph_spans(&node)
+        .into_iter()
+        .find(|(_, range)| range.contains(&position))?;
+    let span_type = span.node_type();
+    let NodeType::Element(ElementNode { attributes, .. }) = &*span_type else {
+        return None;
+    };
+    let link = attributes.get(&AttributeName::Link)?.as_text()?;
+
+    // The closest glyph might be far away, e.g when clicking after the end of a line
+    let is_over_text = paragraph
+        .get_rects_for_range(range, RectHeightStyle::Max, RectWidthStyle::Tight)
+        .iter()
+        .any(|text_box| {
+            let rect = text_box.rect;
+            x >= rect.left && x <= rect.right && y >= rect.top && y <= rect.bottom
+        });
+
+    is_over_text.then(|| link.to_string())
+}
+
+/// Create a `linkclick` event for every click on a link of a `paragraph` listening to `onlinkclick`,
+/// and a `linkhover` event every time the hovered link of a `paragraph` listening to `onlinkhover` changes.
+///
+/// `hovered_links` keeps the link hovered in every `paragraph` across calls.
+pub fn measure_link_events(
+    fdom: &FreyaDOM,
+    events: &EventsQueue,
+    hovered_links: &mut FxHashMap<NodeId, String>,
+    scale_factor: f64,
+) -> Vec<DomEvent> {
+    let mut dom_events = Vec::new();
+    let rdom = fdom.rdom();
+
+    for PlatformEvent { name, data } in events {
+        match (name, data) {
+            (EventName::MouseUp, PlatformEventData::Mouse { cursor, .. })
+            | (
+                EventName::TouchEnd,
+                PlatformEventData::Touch {
+                    location: cursor, ..
+                },
+            ) => {
+                let Some(node_id) = topmost_node_at(fdom, *cursor, scale_factor) else {
+                    continue;
+                };
+                if !rdom.is_node_listening(&node_id, &EventName::LinkClick) {
+                    continue;
+                }
+                let Some(link) = link_at(fdom, node_id, *cursor) else {
+                    continue;
+                };
+
+                dom_events.push(DomEvent {
+                    name: EventName::LinkClick,
+                    node_id,
+                    data: DomEventData::LinkClick(LinkClickData { link }),
+                    bubbles: false,
+                });
+            }
+            (name, PlatformEventData::Mouse { cursor, .. }) if name.was_cursor_moved() => {
+                let hovered = topmost_node_at(fdom, *cursor, scale_factor)
+                    .filter(|node_id| rdom.is_node_listening(node_id, &EventName::LinkHover))
+                    .and_then(|node_id| Some((node_id, link_at(fdom, node_id, *cursor)?)));
+
+                // Leave the previously hovered links
+                hovered_links.retain(|node_id, link| {
+                    let is_hovered = hovered.as_ref().is_some_and(|(hovered_id, hovered_link)| {
+                        hovered_id == node_id && hovered_link == link
+                    });
+                    if !is_hovered && rdom.contains(*node_id) {
+                        dom_events.push(DomEvent {
+                            name: EventName::LinkHover,
+                            node_id: *node_id,
+                            data: DomEventData::LinkHover(LinkHoverData { link: None }),
+                            bubbles: false,
+                        });
+                    }
+                    is_hovered
+                });
+
+                // Enter the new hovered link
+                if let Some((node_id, link)) = hovered {
+                    if !hovered_links.contains_key(&node_id) {
+                        hovered_links.insert(node_id, link.clone());
+                        dom_events.push(DomEvent {
+                            name: EventName::LinkHover,
+                            node_id,
+                            data: DomEventData::LinkHover(LinkHoverData { link: Some(link) }),
+                            bubbles: false,
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    dom_events
+}
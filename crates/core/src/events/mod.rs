@@ -1,12 +1,22 @@
+pub mod cursor_icon;
 pub mod dom_event;
 pub mod events_measurer;
+pub mod hit_test;
+pub mod links;
 pub mod nodes_state;
 pub mod platform_event;
 pub mod potential_event;
+pub mod text_overflow;
+pub mod text_selection;
 
+pub use cursor_icon::*;
 pub use dom_event::*;
 pub use events_measurer::*;
 pub use freya_native_core::events::*;
+pub use hit_test::*;
+pub use links::*;
 pub use nodes_state::*;
 pub use platform_event::*;
 pub use potential_event::*;
+pub use text_overflow::*;
+pub use text_selection::*;
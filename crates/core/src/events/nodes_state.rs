@@ -13,12 +13,16 @@ use crate::{
     dom::FreyaDOM,
     events::{
         is_node_parent_of,
+        measure_link_events,
         DomEvent,
         PlatformEvent,
         PotentialEvent,
     },
     states::StyleState,
-    types::PotentialEvents,
+    types::{
+        EventsQueue,
+        PotentialEvents,
+    },
     values::Fill,
 };
 
@@ -32,9 +36,20 @@ struct NodeMetadata {
 pub struct NodesState {
     pressed_nodes: FxHashMap<NodeId, NodeMetadata>,
     hovered_nodes: FxHashMap<NodeId, NodeMetadata>,
+    hovered_links: FxHashMap<NodeId, String>,
 }
 
 impl NodesState {
+    /// Get the clicks on links and the changes of the hovered links of the paragraphs.
+    pub fn process_links(
+        &mut self,
+        fdom: &FreyaDOM,
+        events: &EventsQueue,
+        scale_factor: f64,
+    ) -> Vec<DomEvent> {
+        measure_link_events(fdom, events, &mut self.hovered_links, scale_factor)
+    }
+
     /// Update the node states given the new events and suggest potential collateral new events
     pub fn process_collateral(
         &mut self,
@@ -39,6 +39,11 @@ pub enum PlatformEventData {
         code: Code,
         modifiers: Modifiers,
     },
+    /// An IME event.
+    Ime {
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
     /// A Touch event.
     Touch {
         location: CursorPoint,
@@ -0,0 +1,63 @@
+use freya_elements::events::TextOverflowData;
+use freya_native_core::{
+    prelude::NodeImmutable,
+    NodeId,
+};
+use rustc_hash::FxHashMap;
+
+use super::{
+    DomEvent,
+    DomEventData,
+    EventName,
+};
+use crate::{
+    dom::FreyaDOM,
+    elements::CachedParagraph,
+    render::paragraph_overflows,
+};
+
+/// Keeps track of which texts listening to `ontextoverflow` are overflowing.
+#[derive(Default, Debug)]
+pub struct TextOverflowState {
+    overflows: FxHashMap<NodeId, bool>,
+}
+
+impl TextOverflowState {
+    /// Measure the texts listening to `ontextoverflow` after the layout was processed.
+    ///
+    /// Returns an event for every text that started or stopped overflowing,
+    /// and for the texts that were measured for the first time.
+    pub fn process_layout(&mut self, fdom: &FreyaDOM) -> Vec<DomEvent> {
+        let layout = fdom.layout();
+        let listeners = fdom.rdom().get_listeners(&EventName::TextOverflow);
+        let mut events = Vec::new();
+
+        self.overflows
+            .retain(|node_id, _| listeners.iter().any(|node| node.id() == *node_id));
+
+        for node in listeners {
+            let Some(layout_node) = layout.get(node.id()) else {
+                continue;
+            };
+            let Some(CachedParagraph(paragraph)) = layout_node
+                .data
+                .as_ref()
+                .and_then(|data| data.get::<CachedParagraph>())
+            else {
+                continue;
+            };
+            let overflows = paragraph_overflows(paragraph, &layout_node.visible_area());
+
+            if self.overflows.insert(node.id(), overflows) != Some(overflows) {
+                events.push(DomEvent {
+                    name: EventName::TextOverflow,
+                    node_id: node.id(),
+                    data: DomEventData::TextOverflow(TextOverflowData { overflows }),
+                    bubbles: false,
+                });
+            }
+        }
+
+        events
+    }
+}
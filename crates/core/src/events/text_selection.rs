@@ -0,0 +1,316 @@
+use freya_native_core::{
+    exports::shipyard::Component,
+    node::{
+        ElementNode,
+        NodeType,
+    },
+    real_dom::NodeImmutable,
+    tags::TagName,
+    tree::TreeRef,
+    NodeId,
+};
+use torin::prelude::CursorPoint;
+use winit::event::MouseButton;
+
+use super::{
+    topmost_node_at,
+    EventName,
+    PlatformEvent,
+    PlatformEventData,
+};
+use crate::{
+    dom::{
+        DioxusDOM,
+        DioxusNode,
+        FreyaDOM,
+    },
+    elements::CachedParagraph,
+    render::align_main_align_paragraph,
+    states::StyleState,
+    values::TextSelectionMode,
+};
+
+/// Range of a `label` or `paragraph` selected with [TextSelection], in UTF-16 code units.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextSelectionHighlight(pub Option<(usize, usize)>);
+
+/// Position inside the text of a node, in UTF-16 code units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TextPosition {
+    node_id: NodeId,
+    offset: usize,
+}
+
+/// Text selected by dragging the mouse over the `label`s and `paragraph`s with `text_selection` enabled.
+///
+/// The selection can span all the selectable texts inside the outermost ancestor with `text_selection: "enabled"`.
+#[derive(Default, Debug)]
+pub struct TextSelection {
+    root: Option<NodeId>,
+    anchor: Option<TextPosition>,
+    is_dragging: bool,
+    /// Selected range of each node, in document order.
+    selected: Vec<(NodeId, usize, usize)>,
+}
+
+impl TextSelection {
+    /// Update the selection with a mouse event.
+    ///
+    /// Returns whether the selected text changed.
+    pub fn process_event(
+        &mut self,
+        fdom: &mut FreyaDOM,
+        event: &PlatformEvent,
+        scale_factor: f64,
+    ) -> bool {
+        match (event.name, &event.data) {
+            (
+                EventName::MouseDown,
+                PlatformEventData::Mouse {
+                    cursor,
+                    button: Some(MouseButton::Left),
+                },
+            ) => self.press(fdom, *cursor, scale_factor),
+            (EventName::MouseMove, PlatformEventData::Mouse { cursor, .. }) if self.is_dragging => {
+                self.drag(fdom, *cursor, scale_factor)
+            }
+            (EventName::MouseUp, _) => {
+                self.is_dragging = false;
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Check whether there is no text selected.
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    /// Get the selected text, the texts of different nodes are separated by new lines.
+    pub fn text(&self, fdom: &FreyaDOM) -> Option<String> {
+        let rdom = fdom.rdom();
+        let texts = self
+            .selected
+            .iter()
+            .filter_map(|(node_id, from, to)| {
+                let node = rdom.get(*node_id)?;
+                Some(utf16_slice(&node_text(&node), *from, *to).to_owned())
+            })
+            .collect::<Vec<_>>();
+
+        (!texts.is_empty()).then(|| texts.join("\n"))
+    }
+
+    /// Remove the selection.
+    ///
+    /// Returns whether there was any text selected.
+    pub fn clear(&mut self, fdom: &mut FreyaDOM) -> bool {
+        self.root = None;
+        self.anchor = None;
+        self.is_dragging = false;
+        self.set_selected(fdom, Vec::new())
+    }
+
+    /// Start a new selection if the cursor is over a selectable text, otherwise clear it.
+    fn press(&mut self, fdom: &mut FreyaDOM, cursor: CursorPoint, scale_factor: f64) -> bool {
+        let start = topmost_node_at(fdom, cursor, scale_factor).and_then(|node_id| {
+            let root = selection_root(fdom.rdom(), node_id)?;
+            let offset = text_offset_at(fdom, node_id, cursor)?;
+            Some((root, TextPosition { node_id, offset }))
+        });
+
+        let Some((root, anchor)) = start else {
+            return self.clear(fdom);
+        };
+
+        self.root = Some(root);
+        self.anchor = Some(anchor);
+        self.is_dragging = true;
+        self.set_selected(fdom, Vec::new())
+    }
+
+    /// Select from the anchor to the text under the cursor.
+    fn drag(&mut self, fdom: &mut FreyaDOM, cursor: CursorPoint, scale_factor: f64) -> bool {
+        let (Some(root), Some(anchor)) = (self.root, self.anchor) else {
+            return false;
+        };
+
+        let nodes = selectable_texts(fdom.rdom(), root);
+        let Some(anchor_index) = nodes.iter().position(|node_id| *node_id == anchor.node_id) else {
+            return false;
+        };
+
+        let focus_index = topmost_node_at(fdom, cursor, scale_factor)
+            .and_then(|node_id| nodes.iter().position(|id| *id == node_id))
+            .unwrap_or_else(|| {
+                // Use the last text that starts above the cursor when it's not over any
+                let layout = fdom.layout();
+                nodes
+                    .iter()
+                    .rposition(|node_id| {
+                        layout
+                            .get(*node_id)
+                            .is_some_and(|layout_node| layout_node.area.min_y() <= cursor.y as f32)
+                    })
+                    .unwrap_or_default()
+            });
+        let Some(focus_offset) = text_offset_at(fdom, nodes[focus_index], cursor) else {
+            return false;
+        };
+
+        let (start, end) = {
+            let anchor = (anchor_index, anchor.offset);
+            let focus = (focus_index, focus_offset);
+            if anchor <= focus {
+                (anchor, focus)
+            } else {
+                (focus, anchor)
+            }
+        };
+
+        let selected = {
+            let rdom = fdom.rdom();
+            (start.0..=end.0)
+                .filter_map(|index| {
+                    let node_id = nodes[index];
+                    let from = if index == start.0 { start.1 } else { 0 };
+                    let to = if index == end.0 {
+                        end.1
+                    } else {
+                        let node = rdom.get(node_id)?;
+                        node_text(&node).encode_utf16().count()
+                    };
+                    (from != to).then_some((node_id, from, to))
+                })
+                .collect()
+        };
+
+        self.set_selected(fdom, selected)
+    }
+
+    fn set_selected(&mut self, fdom: &mut FreyaDOM, selected: Vec<(NodeId, usize, usize)>) -> bool {
+        if self.selected == selected {
+            return false;
+        }
+
+        let previous = std::mem::replace(&mut self.selected, selected);
+
+        let rdom = fdom.rdom_mut();
+        for (node_id, ..) in &previous {
+            if let Some(mut node) = rdom.get_mut(*node_id) {
+                node.insert(TextSelectionHighlight(None));
+            }
+        }
+        for (node_id, from, to) in &self.selected {
+            if let Some(mut node) = rdom.get_mut(*node_id) {
+                node.insert(TextSelectionHighlight(Some((*from, *to))));
+            }
+        }
+
+        let mut compositor_dirty_nodes = fdom.compositor_dirty_nodes();
+        for (node_id, ..) in previous.iter().chain(&self.selected) {
+            compositor_dirty_nodes.invalidate(*node_id);
+        }
+
+        true
+    }
+}
+
+fn is_text(node: &DioxusNode) -> bool {
+    matches!(
+        &*node.node_type(),
+        NodeType::Element(ElementNode {
+            tag: TagName::Label | TagName::Paragraph,
+            ..
+        })
+    )
+}
+
+/// Get the outermost ancestor that enables the selection of the given text, if it's selectable.
+fn selection_root(rdom: &DioxusDOM, node_id: NodeId) -> Option<NodeId> {
+    let node = rdom.get(node_id)?;
+    if !is_text(&node) {
+        return None;
+    }
+
+    let mut root = None;
+    let mut head = Some(node);
+    while let Some(node) = head {
+        match node.get::<StyleState>().unwrap().text_selection {
+            Some(TextSelectionMode::Enabled) => root = Some(node.id()),
+            Some(TextSelectionMode::Disabled) => break,
+            None => {}
+        }
+        head = node.parent();
+    }
+
+    root
+}
+
+/// Get the selectable texts inside the given root, in document order.
+fn selectable_texts(rdom: &DioxusDOM, root: NodeId) -> Vec<NodeId> {
+    let mut texts = Vec::new();
+    let mut stack = vec![root];
+    let tree = rdom.tree_ref();
+    while let Some(node_id) = stack.pop() {
+        stack.extend(tree.children_ids(node_id).into_iter().rev());
+
+        if selection_root(rdom, node_id) == Some(root) {
+            texts.push(node_id);
+        }
+    }
+    texts
+}
+
+/// Get the position in the text of a node that is closest to the cursor.
+fn text_offset_at(fdom: &FreyaDOM, node_id: NodeId, cursor: CursorPoint) -> Option<usize> {
+    let rdom = fdom.rdom();
+    let layout = fdom.layout();
+    let node = rdom.get(node_id)?;
+    let layout_node = layout.get(node_id)?;
+    let paragraph = &layout_node.data.as_ref()?.get::<CachedParagraph>()?.0;
+
+    let area = layout_node.visible_area();
+    let y = area.min_y() + align_main_align_paragraph(&node, &area, paragraph);
+
+    let position = paragraph.get_glyph_position_at_coordinate((
+        (cursor.x as f32 - area.min_x()) as i32,
+        (cursor.y as f32 - y) as i32,
+    ));
+
+    Some(position.position as usize)
+}
+
+/// Get the text of a `label` or `paragraph`.
+fn node_text(node: &DioxusNode) -> String {
+    node.children()
+        .iter()
+        .filter_map(|child| match &*child.node_type() {
+            NodeType::Text(text) => Some(text.clone()),
+            NodeType::Element(ElementNode {
+                tag: TagName::Text, ..
+            }) => child
+                .children()
+                .first()
+                .and_then(|text| text.node_type().text().map(str::to_owned)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Slice a text with a range of UTF-16 code units.
+fn utf16_slice(text: &str, from: usize, to: usize) -> &str {
+    let byte_index = |offset: usize| {
+        let mut units = 0;
+        text.char_indices()
+            .find_map(|(index, char)| {
+                let found = units >= offset;
+                units += char.len_utf16();
+                found.then_some(index)
+            })
+            .unwrap_or(text.len())
+    };
+
+    &text[byte_index(from)..byte_index(to)]
+}
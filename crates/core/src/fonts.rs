@@ -0,0 +1,460 @@
+use std::{
+    fmt,
+    fs,
+    ops::{
+        Deref,
+        Range,
+    },
+    path::PathBuf,
+};
+
+use bytes::Bytes;
+use freya_engine::prelude::{
+    FontCollection,
+    FontMgr,
+    FontStyle,
+    TypefaceFontProvider,
+};
+
+use crate::style::emoji_fonts;
+
+/// Where a font registered at runtime is loaded from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FontSource {
+    /// Data of a font file, e.g downloaded or embedded with `include_bytes!`.
+    Bytes(Bytes),
+    /// Path of a font file.
+    File(PathBuf),
+    /// Family of a font installed in the system, e.g `Noto Sans CJK JP`.
+    System(String),
+}
+
+/// Reason why a font couldn't be registered.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FontError {
+    /// The font file could not be read.
+    Io(PathBuf, String),
+    /// The data is not a supported font.
+    InvalidData,
+    /// There is no font with the given family installed in the system.
+    NotFound(String),
+    /// The renderer is not running, e.g. because the app is closing.
+    Unavailable,
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "Failed to read font file {path:?}: {err}"),
+            Self::InvalidData => f.write_str("Invalid font data"),
+            Self::NotFound(family) => write!(f, "Font family '{family}' not found"),
+            Self::Unavailable => f.write_str("Fonts can't be registered"),
+        }
+    }
+}
+
+impl std::error::Error for FontError {}
+
+/// Writing system, or group of characters, that usually needs its own fonts.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FontScript {
+    Emoji,
+    /// Chinese characters, also used in Japanese and Korean.
+    Han,
+    Japanese,
+    Korean,
+    Arabic,
+    Hebrew,
+    Devanagari,
+    Thai,
+    /// Any other group of characters, identified by a name.
+    Custom(String),
+}
+
+impl FontScript {
+    /// Script of a character, `None` for the characters shared by every script like spaces,
+    /// punctuation or latin letters.
+    ///
+    /// Chinese characters are [FontScript::Han], even when they are part of a Japanese or Korean text.
+    pub fn of(character: char) -> Option<Self> {
+        match character as u32 {
+            0x1F000..=0x1FAFF | 0x2600..=0x27BF => Some(Self::Emoji),
+            0x3040..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F => Some(Self::Japanese),
+            0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF => Some(Self::Korean),
+            0x2E80..=0x2FDF
+            | 0x3005
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xF900..=0xFAFF
+            | 0x20000..=0x3134F => Some(Self::Han),
+            0x0600..=0x06FF
+            | 0x0750..=0x077F
+            | 0x08A0..=0x08FF
+            | 0xFB50..=0xFDFF
+            | 0xFE70..=0xFEFF => Some(Self::Arabic),
+            0x0590..=0x05FF | 0xFB1D..=0xFB4F => Some(Self::Hebrew),
+            0x0900..=0x097F => Some(Self::Devanagari),
+            0x0E00..=0x0E7F => Some(Self::Thai),
+            _ => None,
+        }
+    }
+}
+
+/// Ordered chains of font families used for the characters that the font families
+/// of a text and the default fonts don't have, e.g emojis or CJK characters.
+///
+/// Every run of text tries the chain of its [FontScript] first, and then the other chains in order.
+///
+/// The default chains use the emoji and CJK fonts usually installed in every platform.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontFallbacks {
+    chains: Vec<(FontScript, Vec<String>)>,
+}
+
+impl Default for FontFallbacks {
+    fn default() -> Self {
+        let (han, japanese, korean): (&[&str], &[&str], &[&str]) = if cfg!(target_os = "windows") {
+            (
+                &["Microsoft YaHei", "Microsoft JhengHei"],
+                &["Yu Gothic UI", "Meiryo"],
+                &["Malgun Gothic"],
+            )
+        } else if cfg!(any(target_os = "macos", target_os = "ios")) {
+            (
+                &["PingFang SC", "PingFang TC"],
+                &["Hiragino Sans"],
+                &["Apple SD Gothic Neo"],
+            )
+        } else {
+            (
+                &["Noto Sans CJK SC", "Noto Sans CJK TC"],
+                &["Noto Sans CJK JP"],
+                &["Noto Sans CJK KR"],
+            )
+        };
+
+        // Emojis are the characters most commonly missing in text fonts
+        Self::empty()
+            .with_fallback(FontScript::Emoji, emoji_fonts())
+            .with_fallback(FontScript::Han, han.iter().copied())
+            .with_fallback(FontScript::Japanese, japanese.iter().copied())
+            .with_fallback(FontScript::Korean, korean.iter().copied())
+    }
+}
+
+impl FontFallbacks {
+    /// Fallbacks without any chain.
+    pub fn empty() -> Self {
+        Self { chains: Vec::new() }
+    }
+
+    /// Replace the chain of the given script.
+    pub fn with_fallback(
+        mut self,
+        script: FontScript,
+        families: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.set(script, families);
+        self
+    }
+
+    /// Replace the chain of the given script, chains keep the order in which they were first set.
+    pub fn set(
+        &mut self,
+        script: FontScript,
+        families: impl IntoIterator<Item = impl Into<String>>,
+    ) {
+        let families = families.into_iter().map(Into::into).collect();
+        if let Some((_, chain)) = self.chains.iter_mut().find(|(s, _)| *s == script) {
+            *chain = families;
+        } else {
+            self.chains.push((script, families));
+        }
+    }
+
+    /// Remove the chain of the given script.
+    pub fn remove(&mut self, script: &FontScript) {
+        self.chains.retain(|(s, _)| s != script);
+    }
+
+    /// Chain of the given script.
+    pub fn get(&self, script: &FontScript) -> Option<&[String]> {
+        self.chains
+            .iter()
+            .find(|(s, _)| s == script)
+            .map(|(_, chain)| chain.as_slice())
+    }
+
+    /// Families of every chain in order, without duplicates.
+    pub fn families(&self) -> Vec<String> {
+        let mut families = Vec::new();
+        for family in self.chains.iter().flat_map(|(_, chain)| chain) {
+            if !families.contains(family) {
+                families.push(family.clone());
+            }
+        }
+        families
+    }
+}
+
+/// Fonts used when the font families of a text don't have a character, the default fonts
+/// followed by the fallback chains.
+///
+/// Dereferences to the fonts used for the text of any script, but every run of text should
+/// use the fonts of its script from [ResolvedFonts::runs], so that e.g Japanese text
+/// is rendered with Japanese fonts instead of Chinese ones.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResolvedFonts {
+    fonts: Vec<String>,
+    /// Fonts of every script with a chain, where the chain of the script comes first.
+    scripts: Vec<(FontScript, Vec<String>)>,
+}
+
+impl ResolvedFonts {
+    /// Resolve the fonts of every script with a chain.
+    pub fn new(default_fonts: Vec<String>, fallbacks: &FontFallbacks) -> Self {
+        let resolve = |first: Option<&FontScript>| {
+            let mut fonts = default_fonts.clone();
+            let first_chain = fallbacks
+                .chains
+                .iter()
+                .filter(|(script, _)| Some(script) == first);
+            let other_chains = fallbacks
+                .chains
+                .iter()
+                .filter(|(script, _)| Some(script) != first);
+            for family in first_chain.chain(other_chains).flat_map(|(_, chain)| chain) {
+                if !fonts.contains(family) {
+                    fonts.push(family.clone());
+                }
+            }
+            fonts
+        };
+
+        Self {
+            fonts: resolve(None),
+            scripts: fallbacks
+                .chains
+                .iter()
+                .map(|(script, _)| (script.clone(), resolve(Some(script))))
+                .collect(),
+        }
+    }
+
+    /// Fonts for text written in the given script.
+    pub fn for_script(&self, script: Option<&FontScript>) -> &[String] {
+        self.scripts
+            .iter()
+            .find(|(s, _)| Some(s) == script)
+            .map_or(&self.fonts, |(_, fonts)| fonts)
+    }
+
+    /// Split the text in runs of the same script, along with the fonts of each run.
+    ///
+    /// Chinese characters use the Japanese fonts if the text has any kana, and the Korean fonts if it has any hangul.
+    /// There is always at least one run, even for an empty text.
+    pub fn runs<'a>(&'a self, text: &str) -> Vec<(Range<usize>, &'a [String])> {
+        if self.scripts.is_empty() {
+            return vec![(0..text.len(), self.fonts.as_slice())];
+        }
+
+        let scripts = text.chars().map(FontScript::of).collect::<Vec<_>>();
+        let han_script = if scripts.contains(&Some(FontScript::Japanese)) {
+            FontScript::Japanese
+        } else if scripts.contains(&Some(FontScript::Korean)) {
+            FontScript::Korean
+        } else {
+            FontScript::Han
+        };
+
+        let mut runs: Vec<(Range<usize>, Option<FontScript>)> = Vec::new();
+        for ((index, character), script) in text.char_indices().zip(scripts) {
+            let end = index + character.len_utf8();
+            let script = script.map(|script| {
+                if script == FontScript::Han {
+                    han_script.clone()
+                } else {
+                    script
+                }
+            });
+            match runs.last_mut() {
+                // Characters shared by every script stay in the current run
+                Some((range, run_script)) if script.is_none() || script == *run_script => {
+                    range.end = end;
+                }
+                // Text starting with shared characters takes the script of the first one that isn't
+                Some((range, run_script @ None)) => {
+                    range.end = end;
+                    *run_script = script;
+                }
+                _ => runs.push((index..end, script)),
+            }
+        }
+
+        if runs.is_empty() {
+            return vec![(0..0, self.fonts.as_slice())];
+        }
+
+        runs.into_iter()
+            .map(|(range, script)| (range, self.for_script(script.as_ref())))
+            .collect()
+    }
+}
+
+impl Deref for ResolvedFonts {
+    type Target = [String];
+
+    fn deref(&self) -> &Self::Target {
+        &self.fonts
+    }
+}
+
+/// Fonts of the app, the ones embedded at launch and the ones registered at runtime,
+/// along with the default fonts and their fallbacks.
+pub struct FontRegistry {
+    provider: TypefaceFontProvider,
+    system_mgr: FontMgr,
+    font_mgr: FontMgr,
+    default_fonts: Vec<String>,
+    fallbacks: FontFallbacks,
+    resolved_fonts: ResolvedFonts,
+    registered: Vec<String>,
+}
+
+impl FontRegistry {
+    /// Create the registry and the font collection with the system fonts and the embedded fonts.
+    pub fn new(
+        embedded_fonts: &[(&str, &[u8])],
+        default_fonts: Vec<String>,
+        fallbacks: FontFallbacks,
+    ) -> (Self, FontCollection) {
+        let system_mgr = FontMgr::default();
+        let mut provider = TypefaceFontProvider::new();
+        let mut registered = Vec::new();
+
+        for (font_name, font_data) in embedded_fonts {
+            let typeface = system_mgr.new_from_data(font_data, None).unwrap();
+            provider.register_typeface(typeface, Some(font_name));
+            registered.push(font_name.to_string());
+        }
+
+        // The provider is shared with the font manager, so fonts registered later are found too
+        let font_mgr: FontMgr = provider.clone().into();
+        let mut font_collection = FontCollection::new();
+        font_collection.set_default_font_manager(system_mgr.clone(), None);
+        font_collection.set_dynamic_font_manager(font_mgr.clone());
+        // Characters missing in every family are still looked up in the system fonts
+        font_collection.enable_font_fallback();
+
+        let mut registry = Self {
+            provider,
+            system_mgr,
+            font_mgr,
+            default_fonts,
+            fallbacks,
+            resolved_fonts: ResolvedFonts::default(),
+            registered,
+        };
+        registry.resolve_fonts();
+
+        (registry, font_collection)
+    }
+
+    /// Manager of the embedded fonts and the fonts registered at runtime.
+    pub fn font_mgr(&self) -> &FontMgr {
+        &self.font_mgr
+    }
+
+    /// Fonts used when the font families of a text don't have a character,
+    /// the default fonts followed by the fallbacks.
+    pub fn default_fonts(&self) -> &ResolvedFonts {
+        &self.resolved_fonts
+    }
+
+    /// Chains of fallback fonts.
+    pub fn fallbacks(&self) -> &FontFallbacks {
+        &self.fallbacks
+    }
+
+    /// Replace the chains of fallback fonts.
+    pub fn set_fallbacks(&mut self, fallbacks: FontFallbacks) {
+        self.fallbacks = fallbacks;
+        self.resolve_fonts();
+    }
+
+    /// Register a font, returning the family it can be used with.
+    ///
+    /// The family is the `alias` if given, otherwise the family of the font.
+    /// Registering a font with the family of another one adds it to that family, e.g for the bold variant.
+    pub fn register(
+        &mut self,
+        source: FontSource,
+        alias: Option<&str>,
+        font_collection: &mut FontCollection,
+    ) -> Result<String, FontError> {
+        let typeface = match source {
+            FontSource::Bytes(data) => self
+                .system_mgr
+                .new_from_data(&data, None)
+                .ok_or(FontError::InvalidData)?,
+            FontSource::File(path) => {
+                let data = fs::read(&path).map_err(|err| FontError::Io(path, err.to_string()))?;
+                self.system_mgr
+                    .new_from_data(&data, None)
+                    .ok_or(FontError::InvalidData)?
+            }
+            FontSource::System(family) => self
+                .system_mgr
+                .match_family_style(&family, FontStyle::normal())
+                // Some platforms match any family with their default font
+                .filter(|typeface| typeface.family_name().eq_ignore_ascii_case(&family))
+                .ok_or(FontError::NotFound(family))?,
+        };
+
+        let family = alias
+            .map(str::to_string)
+            .unwrap_or_else(|| typeface.family_name());
+        self.provider.register_typeface(typeface, Some(&family));
+        if !self.registered.contains(&family) {
+            self.registered.push(family.clone());
+        }
+
+        // Paragraphs cache the fonts resolved for every family
+        font_collection.clear_caches();
+
+        Ok(family)
+    }
+
+    /// Families of the embedded fonts and the fonts registered at runtime.
+    pub fn registered_families(&self) -> &[String] {
+        &self.registered
+    }
+
+    /// Families of the registered fonts and the fonts installed in the system, sorted.
+    pub fn families(&self) -> Vec<String> {
+        let mut families = self
+            .registered
+            .iter()
+            .cloned()
+            .chain(self.system_mgr.family_names())
+            .collect::<Vec<_>>();
+        families.sort();
+        families.dedup();
+        families
+    }
+
+    /// Whether there is a registered or installed font with the given family.
+    pub fn is_available(&self, family: &str) -> bool {
+        self.registered
+            .iter()
+            .any(|f| f.eq_ignore_ascii_case(family))
+            || self
+                .system_mgr
+                .family_names()
+                .any(|f| f.eq_ignore_ascii_case(family))
+    }
+
+    fn resolve_fonts(&mut self) {
+        self.resolved_fonts = ResolvedFonts::new(self.default_fonts.clone(), &self.fallbacks);
+    }
+}
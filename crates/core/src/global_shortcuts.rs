@@ -0,0 +1,128 @@
+use std::fmt;
+
+use crate::native_menu::parse_accelerator;
+
+/// Keyboard shortcut registered in the operating system,
+/// triggered even when the window is not focused or is hidden.
+///
+/// The accelerator uses the same format as the items of the native menu, e.g `CmdOrCtrl+Shift+Space` or `MediaPlayPause`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlobalShortcut {
+    pub id: String,
+    pub accelerator: String,
+}
+
+impl GlobalShortcut {
+    pub fn new(id: impl Into<String>, accelerator: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            accelerator: accelerator.into(),
+        }
+    }
+
+    /// Whether both shortcuts are triggered by the same keys.
+    pub fn conflicts_with(&self, other: &GlobalShortcut) -> bool {
+        match (
+            parse_accelerator(&self.accelerator),
+            parse_accelerator(&other.accelerator),
+        ) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// Whether the keys of a [GlobalShortcut] have been pressed or released.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GlobalShortcutState {
+    Pressed,
+    Released,
+}
+
+/// Event sent when a [GlobalShortcut] is triggered.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlobalShortcutEvent {
+    pub id: String,
+    pub state: GlobalShortcutState,
+}
+
+/// Reason why a [GlobalShortcut] couldn't be registered.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GlobalShortcutError {
+    /// The accelerator couldn't be parsed.
+    InvalidAccelerator(String),
+    /// Another shortcut of the app, with the given id, already uses the same keys.
+    Conflict(String),
+    /// The operating system rejected it, usually because another application already uses the same keys.
+    RegistrationFailed(String),
+    /// The platform doesn't support global shortcuts, e.g. Wayland.
+    Unsupported,
+}
+
+impl fmt::Display for GlobalShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidAccelerator(accelerator) => {
+                write!(f, "Invalid accelerator '{accelerator}'")
+            }
+            Self::Conflict(id) => write!(f, "Keys already used by the shortcut '{id}'"),
+            Self::RegistrationFailed(reason) => write!(f, "Registration failed: {reason}"),
+            Self::Unsupported => f.write_str("Global shortcuts are not supported"),
+        }
+    }
+}
+
+impl std::error::Error for GlobalShortcutError {}
+
+/// Shortcuts registered by the app.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GlobalShortcuts {
+    pub shortcuts: Vec<GlobalShortcut>,
+}
+
+impl GlobalShortcuts {
+    /// Find a shortcut by its id.
+    pub fn get(&self, id: &str) -> Option<&GlobalShortcut> {
+        self.shortcuts.iter().find(|shortcut| shortcut.id == id)
+    }
+
+    /// Check that the shortcut is valid and that no other shortcut uses the same keys.
+    /// A shortcut with the same id is not considered a conflict as it would be replaced.
+    pub fn check(&self, shortcut: &GlobalShortcut) -> Result<(), GlobalShortcutError> {
+        if parse_accelerator(&shortcut.accelerator).is_none() {
+            return Err(GlobalShortcutError::InvalidAccelerator(
+                shortcut.accelerator.clone(),
+            ));
+        }
+        match self
+            .shortcuts
+            .iter()
+            .find(|other| other.id != shortcut.id && other.conflicts_with(shortcut))
+        {
+            Some(other) => Err(GlobalShortcutError::Conflict(other.id.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Add a shortcut without checking it, replacing the one with the same id.
+    pub fn insert(&mut self, shortcut: GlobalShortcut) {
+        self.remove(&shortcut.id);
+        self.shortcuts.push(shortcut);
+    }
+
+    /// Check and add a shortcut, replacing the one with the same id.
+    pub fn register(&mut self, shortcut: GlobalShortcut) -> Result<(), GlobalShortcutError> {
+        self.check(&shortcut)?;
+        self.insert(shortcut);
+        Ok(())
+    }
+
+    /// Remove a shortcut by its id.
+    pub fn remove(&mut self, id: &str) -> Option<GlobalShortcut> {
+        let index = self
+            .shortcuts
+            .iter()
+            .position(|shortcut| shortcut.id == id)?;
+        Some(self.shortcuts.remove(index))
+    }
+}
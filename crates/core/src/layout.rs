@@ -1,11 +1,15 @@
 use freya_engine::prelude::*;
-use freya_native_core::prelude::NodeImmutable;
+use freya_native_core::{
+    prelude::NodeImmutable,
+    tags::TagName,
+};
 use itertools::Itertools;
 use torin::geometry::Area;
 
 use crate::{
     accessibility::NodeAccessibility,
     dom::*,
+    fonts::ResolvedFonts,
     render::{
         Compositor,
         SkiaMeasurer,
@@ -18,11 +22,19 @@ pub fn process_layout(
     area: Area,
     font_collection: &mut FontCollection,
     scale_factor: f32,
-    default_fonts: &[String],
+    default_fonts: &ResolvedFonts,
 ) {
+    let span = crate::profile_span!(
+        "layout",
+        dirty_nodes = tracing::field::Empty,
+        nodes = tracing::field::Empty
+    );
     {
         let rdom = fdom.rdom();
         let mut images_cache = fdom.images_cache();
+        let mut paragraph_cache = fdom.paragraph_cache();
+        // Shaped paragraphs are no longer valid with different scale factor or fonts
+        paragraph_cache.prepare(scale_factor, default_fonts);
         let mut dom_adapter = DioxusDOMAdapter::new(rdom, scale_factor);
         let skia_measurer = SkiaMeasurer::new(
             rdom,
@@ -30,9 +42,11 @@ pub fn process_layout(
             default_fonts,
             scale_factor,
             &mut images_cache,
+            &mut paragraph_cache,
         );
 
         let mut layout = fdom.layout();
+        span.record("dirty_nodes", layout.dirty.len());
 
         // Finds the best Node from where to start measuring
         layout.find_best_root(&mut dom_adapter);
@@ -56,6 +70,11 @@ pub fn process_layout(
                     if node.get_accessibility_id().is_some() {
                         dirty_accessibility_tree.add_or_update(node_id);
                     }
+                } else if node.node_type().tag() == Some(&TagName::Text)
+                    && node.get_accessibility_id().is_some()
+                {
+                    // Links are placed where they are rendered in their paragraph
+                    dirty_accessibility_tree.add_or_update(node_id);
                 }
                 // Continue iterating in the children of this node
                 buffer.extend(node.child_ids());
@@ -65,5 +84,10 @@ pub fn process_layout(
 
         // Measure the layout
         layout.measure(root_id, area, &mut Some(skia_measurer), &mut dom_adapter);
+
+        // Areas used for hit-testing might have changed
+        fdom.hit_test_index().invalidate();
+
+        span.record("nodes", layout.size());
     }
 }
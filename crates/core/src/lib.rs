@@ -1,18 +1,29 @@
 pub mod accessibility;
+pub mod app_dirs;
 pub mod custom_attributes;
+pub mod design_tokens;
 pub mod dom;
 pub mod elements;
 pub mod event_loop_messages;
 pub mod events;
+pub mod fonts;
+pub mod global_shortcuts;
 pub mod layers;
 pub mod layout;
+pub mod native_menu;
+pub mod native_tray;
 pub mod node;
+pub mod open_requests;
+pub mod opener;
 pub mod parsing;
 pub mod platform;
 pub mod platform_state;
 pub mod plugins;
+pub mod printing;
+pub mod profiling;
 pub mod render;
 pub mod states;
 pub mod style;
 pub mod types;
 pub mod values;
+pub mod webview;
@@ -0,0 +1,263 @@
+use std::str::FromStr;
+
+use keyboard_types::{
+    Key,
+    Modifiers,
+};
+
+/// Menu bar of the window, rendered by the operating system.
+///
+/// It is shown as the application menu on macOS and as the window menu bar on Windows.
+///
+/// Linux is not supported: the native menu bars there are GTK widgets that can only be attached
+/// to GTK windows, and Freya windows are not. The menu is not shown on Linux and other platforms,
+/// but the accelerators of its items keep working, so apps that need a visible menu there
+/// can render their own with the `Menu` components.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NativeMenu {
+    pub submenus: Vec<NativeSubmenu>,
+}
+
+impl NativeMenu {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a submenu to the bar.
+    pub fn with_submenu(mut self, submenu: NativeSubmenu) -> Self {
+        self.submenus.push(submenu);
+        self
+    }
+
+    /// Find an item by its id.
+    pub fn item(&self, id: &str) -> Option<&NativeMenuItem> {
+        self.submenus.iter().find_map(|submenu| submenu.item(id))
+    }
+
+    /// Find an item by its id, mutably.
+    pub fn item_mut(&mut self, id: &str) -> Option<&mut NativeMenuItem> {
+        self.submenus
+            .iter_mut()
+            .find_map(|submenu| submenu.item_mut(id))
+    }
+
+    /// Find the enabled item whose accelerator matches the given key and modifiers.
+    pub fn item_by_shortcut(&self, key: &Key, modifiers: Modifiers) -> Option<&NativeMenuItem> {
+        self.submenus
+            .iter()
+            .filter(|submenu| submenu.enabled)
+            .find_map(|submenu| submenu.item_by_shortcut(key, modifiers))
+    }
+}
+
+/// Entry of a [NativeSubmenu].
+#[derive(Clone, Debug, PartialEq)]
+pub enum NativeMenuEntry {
+    Item(NativeMenuItem),
+    Separator,
+    Submenu(NativeSubmenu),
+}
+
+/// Group of entries shown under a label of the [NativeMenu].
+#[derive(Clone, Debug, PartialEq)]
+pub struct NativeSubmenu {
+    pub label: String,
+    pub enabled: bool,
+    pub entries: Vec<NativeMenuEntry>,
+}
+
+impl NativeSubmenu {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            enabled: true,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Enable or disable the whole submenu. Defaults to `true`.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Add an item.
+    pub fn with_item(mut self, item: NativeMenuItem) -> Self {
+        self.entries.push(NativeMenuEntry::Item(item));
+        self
+    }
+
+    /// Add a separator line.
+    pub fn with_separator(mut self) -> Self {
+        self.entries.push(NativeMenuEntry::Separator);
+        self
+    }
+
+    /// Add a nested submenu.
+    pub fn with_submenu(mut self, submenu: NativeSubmenu) -> Self {
+        self.entries.push(NativeMenuEntry::Submenu(submenu));
+        self
+    }
+
+    /// Find an item by its id, including nested submenus.
+    pub fn item(&self, id: &str) -> Option<&NativeMenuItem> {
+        self.entries.iter().find_map(|entry| match entry {
+            NativeMenuEntry::Item(item) if item.id == id => Some(item),
+            NativeMenuEntry::Submenu(submenu) => submenu.item(id),
+            _ => None,
+        })
+    }
+
+    /// Find an item by its id, including nested submenus, mutably.
+    pub fn item_mut(&mut self, id: &str) -> Option<&mut NativeMenuItem> {
+        self.entries.iter_mut().find_map(|entry| match entry {
+            NativeMenuEntry::Item(item) if item.id == id => Some(item),
+            NativeMenuEntry::Submenu(submenu) => submenu.item_mut(id),
+            _ => None,
+        })
+    }
+
+    fn item_by_shortcut(&self, key: &Key, modifiers: Modifiers) -> Option<&NativeMenuItem> {
+        self.entries.iter().find_map(|entry| match entry {
+            NativeMenuEntry::Item(item)
+                if item.enabled && item.matches_shortcut(key, modifiers) =>
+            {
+                Some(item)
+            }
+            NativeMenuEntry::Submenu(submenu) if submenu.enabled => {
+                submenu.item_by_shortcut(key, modifiers)
+            }
+            _ => None,
+        })
+    }
+}
+
+/// Item of a [NativeSubmenu], activating it sends a [NativeMenuEvent] with its id.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NativeMenuItem {
+    pub id: String,
+    pub label: String,
+    pub enabled: bool,
+    /// Whether the item is checked, `None` if it is not checkable.
+    pub checked: Option<bool>,
+    /// Keyboard shortcut, e.g `CmdOrCtrl+Shift+S`.
+    pub accelerator: Option<String>,
+}
+
+impl NativeMenuItem {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            enabled: true,
+            checked: None,
+            accelerator: None,
+        }
+    }
+
+    /// Enable or disable the item. Defaults to `true`.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Make the item checkable, toggling its check every time it is activated.
+    pub fn checkable(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Set the keyboard shortcut of the item, made of modifiers and a key separated by `+`.
+    ///
+    /// Supported modifiers are `Ctrl`, `Shift`, `Alt`, `Super` and `CmdOrCtrl`,
+    /// which is `Super` (Command) on macOS and `Ctrl` elsewhere.
+    pub fn accelerator(mut self, accelerator: impl Into<String>) -> Self {
+        self.accelerator = Some(accelerator.into());
+        self
+    }
+
+    /// Check or uncheck the item, only if it is checkable.
+    pub fn set_checked(&mut self, checked: bool) {
+        if self.checked.is_some() {
+            self.checked = Some(checked);
+        }
+    }
+
+    /// Toggle the check of the item if it is checkable and create the event that notifies its activation.
+    ///
+    /// `native_checked` is the check state of the native item, which already toggles by itself.
+    pub fn activate(&mut self, native_checked: Option<bool>) -> NativeMenuEvent {
+        self.checked = native_checked.or(self.checked.map(|checked| !checked));
+        NativeMenuEvent {
+            id: self.id.clone(),
+            checked: self.checked,
+        }
+    }
+
+    /// Check whether the accelerator of this item matches the given key and modifiers.
+    pub fn matches_shortcut(&self, key: &Key, modifiers: Modifiers) -> bool {
+        self.accelerator
+            .as_deref()
+            .is_some_and(|accelerator| accelerator_matches(accelerator, key, modifiers))
+    }
+}
+
+/// Event sent when an item of the [NativeMenu] is activated.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NativeMenuEvent {
+    /// Id of the activated item.
+    pub id: String,
+    /// New check state of the item, `None` if it is not checkable.
+    pub checked: Option<bool>,
+}
+
+/// Parse an accelerator like `CmdOrCtrl+Shift+S` into its modifiers and key.
+pub fn parse_accelerator(accelerator: &str) -> Option<(Modifiers, Key)> {
+    let mut modifiers = Modifiers::empty();
+    let mut parts = accelerator.split('+').map(str::trim).peekable();
+
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            let key = match part {
+                "" => return None,
+                part if part.chars().count() == 1 => Key::Character(part.to_lowercase()),
+                "Space" => Key::Character(" ".to_string()),
+                "Plus" => Key::Character("+".to_string()),
+                "Esc" => Key::Escape,
+                "Del" => Key::Delete,
+                "Up" => Key::ArrowUp,
+                "Down" => Key::ArrowDown,
+                "Left" => Key::ArrowLeft,
+                "Right" => Key::ArrowRight,
+                part => Key::from_str(part).ok()?,
+            };
+            return Some((modifiers, key));
+        }
+
+        modifiers |= match part.to_lowercase().as_str() {
+            "ctrl" | "control" => Modifiers::CONTROL,
+            "shift" => Modifiers::SHIFT,
+            "alt" | "option" => Modifiers::ALT,
+            "super" | "cmd" | "command" | "meta" => Modifiers::META,
+            "cmdorctrl" | "commandorcontrol" if cfg!(target_os = "macos") => Modifiers::META,
+            "cmdorctrl" | "commandorcontrol" => Modifiers::CONTROL,
+            _ => return None,
+        };
+    }
+
+    None
+}
+
+/// Check whether an accelerator like `CmdOrCtrl+S` matches the given key and modifiers.
+pub fn accelerator_matches(accelerator: &str, key: &Key, modifiers: Modifiers) -> bool {
+    parse_accelerator(accelerator).is_some_and(|(accelerator_modifiers, accelerator_key)| {
+        accelerator_modifiers == modifiers && keys_match(&accelerator_key, key)
+    })
+}
+
+fn keys_match(accelerator_key: &Key, key: &Key) -> bool {
+    match (accelerator_key, key) {
+        (Key::Character(a), Key::Character(b)) => a.eq_ignore_ascii_case(b),
+        (a, b) => a == b,
+    }
+}
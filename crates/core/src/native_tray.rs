@@ -0,0 +1,74 @@
+use winit::event::MouseButton;
+
+use crate::native_menu::{
+    NativeMenuEvent,
+    NativeSubmenu,
+};
+
+/// Image of the [NativeTray] icon, in RGBA pixels.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NativeTrayIcon {
+    pub rgba: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl NativeTrayIcon {
+    pub fn from_rgba(rgba: Vec<u8>, width: u32, height: u32) -> Self {
+        Self {
+            rgba,
+            width,
+            height,
+        }
+    }
+}
+
+/// Icon shown in the system tray (notification area on Windows, menu bar extras on macOS).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NativeTray {
+    pub icon: Option<NativeTrayIcon>,
+    pub tooltip: Option<String>,
+    /// Menu shown when the icon is right clicked, the label of the submenu is not shown.
+    pub menu: Option<NativeSubmenu>,
+    /// Hide the window instead of closing the app when it is closed.
+    /// Left clicking the icon shows it again.
+    pub hide_on_close: bool,
+}
+
+impl NativeTray {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_icon(mut self, icon: NativeTrayIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn with_menu(mut self, menu: NativeSubmenu) -> Self {
+        self.menu = Some(menu);
+        self
+    }
+
+    /// Hide the window to the tray instead of closing the app. Defaults to `false`.
+    pub fn with_hide_on_close(mut self, hide_on_close: bool) -> Self {
+        self.hide_on_close = hide_on_close;
+        self
+    }
+}
+
+/// Event sent by the [NativeTray].
+#[derive(Clone, Debug, PartialEq)]
+pub enum NativeTrayEvent {
+    /// The icon has been clicked.
+    Click(MouseButton),
+    /// The icon has been double clicked.
+    DoubleClick(MouseButton),
+    /// An item of the tray menu has been activated.
+    MenuItem(NativeMenuEvent),
+}
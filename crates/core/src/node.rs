@@ -114,11 +114,11 @@ impl NodeState {
             ("margin", AttributeType::Measures(self.size.margin)),
             ("position", AttributeType::Position(&self.size.position)),
             (
-                "main_alignment",
+                "main_align",
                 AttributeType::Alignment(&self.size.main_alignment),
             ),
             (
-                "cross_alignment",
+                "cross_align",
                 AttributeType::Alignment(&self.size.cross_alignment),
             ),
             {
@@ -0,0 +1,48 @@
+use std::{
+    ffi::OsString,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+/// Something the app was asked to open, either when launched or, with `LaunchConfig::single_instance`,
+/// by launching it again while it was running.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OpenRequest {
+    /// Link with one of the URL schemes of the app, e.g. `myapp://settings`, see `LaunchConfig::with_url_scheme`.
+    Url(String),
+    /// File opened with the app, e.g. from the file manager.
+    File(PathBuf),
+}
+
+impl OpenRequest {
+    /// Get the requests in the given command line arguments, without the program name.
+    ///
+    /// Arguments that are neither a link with one of the given schemes nor an existing file, like flags, are ignored.
+    /// The arguments don't need to be valid UTF-8, like the names of some files.
+    pub fn from_args(args: impl IntoIterator<Item = OsString>, schemes: &[String]) -> Vec<Self> {
+        args.into_iter()
+            .filter_map(|arg| {
+                let url = arg.to_str().filter(|arg| {
+                    arg.split_once("://").is_some_and(|(scheme, _)| {
+                        schemes
+                            .iter()
+                            .any(|app_scheme| app_scheme.eq_ignore_ascii_case(scheme))
+                    })
+                });
+                if let Some(url) = url {
+                    Some(Self::Url(url.to_string()))
+                } else if Path::new(&arg).exists() {
+                    Some(Self::File(PathBuf::from(arg)))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// [OpenRequest]s in the command line arguments the app was launched with.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LaunchOpenRequests(pub Vec<OpenRequest>);
@@ -0,0 +1,74 @@
+use std::{
+    fmt,
+    path::PathBuf,
+};
+
+/// Something to open with the platform, see `UsePlatform::open_url`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OpenTarget {
+    /// Link opened with the default browser, or the app registered for its scheme, e.g. `mailto:`.
+    Url(String),
+    /// File or folder opened with its default app.
+    Path(PathBuf),
+    /// File or folder shown selected in the file manager.
+    Reveal(PathBuf),
+}
+
+impl OpenTarget {
+    /// Check that the link has a scheme, so it's not confused with a relative path, and that the paths exist.
+    pub fn validate(&self) -> Result<(), OpenError> {
+        match self {
+            Self::Url(url) => {
+                let is_valid = url.split_once(':').is_some_and(|(scheme, _)| {
+                    scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                        && scheme
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+                });
+                if is_valid {
+                    Ok(())
+                } else {
+                    Err(OpenError::InvalidUrl(url.clone()))
+                }
+            }
+            Self::Path(path) | Self::Reveal(path) => {
+                if path.exists() {
+                    Ok(())
+                } else {
+                    Err(OpenError::NotFound(path.clone()))
+                }
+            }
+        }
+    }
+}
+
+/// Error when opening an [OpenTarget].
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpenError {
+    /// The link has no scheme, like `https:`.
+    InvalidUrl(String),
+    /// The file or folder doesn't exist.
+    NotFound(PathBuf),
+    /// The operating system failed to open it.
+    Failed(String),
+    /// Opening links and files is not supported in this platform.
+    Unsupported,
+    /// The renderer is not running, e.g. because the app is closing.
+    Unavailable,
+}
+
+impl fmt::Display for OpenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidUrl(url) => write!(f, "'{url}' is not a valid URL"),
+            Self::NotFound(path) => write!(f, "{path:?} doesn't exist"),
+            Self::Failed(err) => write!(f, "Failed to open: {err}"),
+            Self::Unsupported => {
+                f.write_str("Opening links and files is not supported in this platform")
+            }
+            Self::Unavailable => f.write_str("The platform can't open links and files"),
+        }
+    }
+}
+
+impl std::error::Error for OpenError {}
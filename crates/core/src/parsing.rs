@@ -1,8 +1,14 @@
 use std::str::CharIndices;
 
-use freya_native_core::prelude::OwnedAttributeView;
+use freya_native_core::{
+    node::OwnedAttributeValue,
+    prelude::OwnedAttributeView,
+};
 
-use crate::custom_attributes::CustomAttributeValues;
+use crate::{
+    custom_attributes::CustomAttributeValues,
+    design_tokens::DesignTokens,
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ParseError;
@@ -33,6 +39,27 @@ pub trait ParseAttribute: Sized {
         #[cfg(not(debug_assertions))]
         self.parse_attribute(attr).ok();
     }
+
+    /// Like [ParseAttribute::parse_safe] but replacing the `token(name)` references first.
+    /// Attributes referencing missing tokens are ignored.
+    fn parse_with_tokens(
+        &mut self,
+        attr: OwnedAttributeView<CustomAttributeValues>,
+        design_tokens: &DesignTokens,
+    ) {
+        match attr.value.as_text() {
+            Some(text) if DesignTokens::uses_tokens(text) => {
+                if let Some(resolved) = design_tokens.resolve(text) {
+                    let value = OwnedAttributeValue::Text(resolved.into_owned());
+                    self.parse_safe(OwnedAttributeView {
+                        attribute: attr.attribute,
+                        value: &value,
+                    });
+                }
+            }
+            _ => self.parse_safe(attr),
+        }
+    }
 }
 
 pub trait ExtSplit {
@@ -0,0 +1,20 @@
+/// Custom cursor made from an image, see `UsePlatform::set_custom_cursor`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CursorImage {
+    pub rgba: Vec<u8>,
+    pub width: u16,
+    pub height: u16,
+    /// Point of the image that is placed at the position of the pointer, in pixels.
+    pub hotspot: (u16, u16),
+}
+
+impl CursorImage {
+    pub fn from_rgba(rgba: Vec<u8>, width: u16, height: u16, hotspot: (u16, u16)) -> Self {
+        Self {
+            rgba,
+            width,
+            height,
+            hotspot,
+        }
+    }
+}
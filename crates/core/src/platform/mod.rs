@@ -1,9 +1,11 @@
+mod cursor;
 mod keyboard;
 mod mouse;
 mod pointer;
 mod touch;
 mod window;
 
+pub use cursor::*;
 pub use keyboard::*;
 pub use mouse::*;
 pub use pointer::*;
@@ -1,9 +1,16 @@
 pub use winit::{
+    dpi::{
+        LogicalPosition,
+        LogicalSize,
+    },
     event_loop::EventLoopProxy,
     window::{
         CursorIcon,
         Fullscreen,
+        ResizeDirection,
+        UserAttentionType,
         Window,
         WindowAttributes,
+        WindowLevel,
     },
 };
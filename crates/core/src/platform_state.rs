@@ -1,9 +1,24 @@
+use std::{
+    collections::VecDeque,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
 use accesskit::{
     Node as AccessibilityNode,
     NodeId as AccessibilityId,
 };
-use torin::prelude::Size2D;
-use winit::window::Window;
+use freya_engine::prelude::Color;
+use torin::prelude::{
+    Point2D,
+    Size2D,
+};
+use winit::{
+    monitor::MonitorHandle,
+    window::Window,
+};
 
 /// State consumed by components and updated by the platform.
 #[derive(Clone, Debug)]
@@ -11,9 +26,35 @@ pub struct NativePlatformState {
     pub focused_accessibility_id: AccessibilityId,
     pub focused_accessibility_node: AccessibilityNode,
     pub preferred_theme: PreferredTheme,
+    /// Accent color chosen by the user in the operating system, if supported.
+    pub accent_color: Option<Color>,
     pub navigation_mode: NavigationMode,
     pub information: PlatformInformation,
     pub scale_factor: f64,
+    pub monitors: Monitors,
+    pub soft_keyboard: SoftKeyboard,
+    /// Text selected with the `text_selection` attribute, if any.
+    pub selected_text: Option<String>,
+    pub is_window_focused: bool,
+    /// Whether the app is in the background, e.g. in Android, where nothing is rendered.
+    pub is_suspended: bool,
+    /// When the user last used the mouse, keyboard or touch screen, with a precision of [USER_INPUT_PRECISION].
+    pub last_user_input: Instant,
+}
+
+/// How often [NativePlatformState::last_user_input] is updated at most while the user interacts with the app.
+pub const USER_INPUT_PRECISION: Duration = Duration::from_millis(100);
+
+impl NativePlatformState {
+    /// Update the time of the last user input, returning whether it changed, see [USER_INPUT_PRECISION].
+    pub fn notify_user_input(&mut self) -> bool {
+        let now = Instant::now();
+        let changed = now - self.last_user_input >= USER_INPUT_PRECISION;
+        if changed {
+            self.last_user_input = now;
+        }
+        changed
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
@@ -24,6 +65,20 @@ pub enum PreferredTheme {
 
     /// Use the dark variant.
     Dark,
+
+    /// Follow the variant preferred by the operating system.
+    /// The platform never reports this variant, it only reports [PreferredTheme::Light] or [PreferredTheme::Dark].
+    Auto,
+}
+
+impl PreferredTheme {
+    /// Resolve [PreferredTheme::Auto] into the variant preferred by the operating system.
+    pub fn resolve(self, system_theme: PreferredTheme) -> PreferredTheme {
+        match self {
+            Self::Auto => system_theme,
+            theme => theme,
+        }
+    }
 }
 
 impl From<winit::window::Theme> for PreferredTheme {
@@ -50,6 +105,8 @@ pub struct PlatformInformation {
     pub is_minimized: bool,
     pub is_maximized: bool,
     pub is_fullscreen: bool,
+    /// Ratio between the physical pixels of the `viewport_size` and the logical pixels used by the components.
+    pub scale_factor: f64,
 }
 
 impl PlatformInformation {
@@ -60,6 +117,7 @@ impl PlatformInformation {
             is_minimized: winit.is_minimized().unwrap_or_default(),
             is_maximized: winit.is_maximized(),
             is_fullscreen: winit.fullscreen().is_some(),
+            scale_factor: winit.scale_factor(),
         }
     }
 
@@ -68,12 +126,316 @@ impl PlatformInformation {
         is_minimized: bool,
         is_maximized: bool,
         is_fullscreen: bool,
+        scale_factor: f64,
     ) -> Self {
         Self {
             viewport_size,
             is_minimized,
             is_maximized,
             is_fullscreen,
+            scale_factor,
+        }
+    }
+}
+
+/// Information about a monitor (display) connected to the device.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MonitorInfo {
+    pub name: Option<String>,
+    /// Size in physical pixels.
+    pub size: Size2D,
+    /// Position of the top-left corner in the desktop, in physical pixels.
+    pub position: Point2D,
+    pub scale_factor: f64,
+    /// Refresh rate in hertz, if known.
+    pub refresh_rate: Option<f64>,
+}
+
+impl MonitorInfo {
+    pub fn from_winit(monitor: &MonitorHandle) -> Self {
+        let size = monitor.size();
+        let position = monitor.position();
+        Self {
+            name: monitor.name(),
+            size: Size2D::new(size.width as f32, size.height as f32),
+            position: Point2D::new(position.x as f32, position.y as f32),
+            scale_factor: monitor.scale_factor(),
+            refresh_rate: monitor
+                .refresh_rate_millihertz()
+                .map(|millihertz| millihertz as f64 / 1000.),
+        }
+    }
+}
+
+/// Monitors connected to the device.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Monitors {
+    /// Every available monitor.
+    pub monitors: Vec<MonitorInfo>,
+    /// Index in [Monitors::monitors] of the monitor where the window is, if known.
+    pub current: Option<usize>,
+    /// Index in [Monitors::monitors] of the primary monitor, if known.
+    pub primary: Option<usize>,
+}
+
+impl Monitors {
+    pub fn from_winit(winit: &Window) -> Self {
+        let handles = winit.available_monitors().collect::<Vec<_>>();
+        let index_of = |monitor: Option<MonitorHandle>| {
+            let monitor = monitor?;
+            handles.iter().position(|handle| *handle == monitor)
+        };
+        Self {
+            current: index_of(winit.current_monitor()),
+            primary: index_of(winit.primary_monitor()),
+            monitors: handles.iter().map(MonitorInfo::from_winit).collect(),
+        }
+    }
+
+    /// Get the monitor where the window is, if known.
+    pub fn current(&self) -> Option<&MonitorInfo> {
+        self.monitors.get(self.current?)
+    }
+
+    /// Get the primary monitor, if known.
+    pub fn primary(&self) -> Option<&MonitorInfo> {
+        self.monitors.get(self.primary?)
+    }
+}
+
+/// How frames are paced and presented.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FramePacing {
+    /// Wait for the display refresh before presenting a frame. Default to `true`.
+    pub vsync: bool,
+    /// Maximum amount of frames rendered per second, `None` for no limit. Default to `None`.
+    pub max_fps: Option<u32>,
+    /// Skip the requested frames in which nothing changed. Default to `false`.
+    pub render_on_change: bool,
+    /// Maximum amount of frames rendered per second while the window is unfocused or minimized,
+    /// `None` to render them like the rest. Animations are also paused while minimized if set. Default to `None`.
+    pub background_max_fps: Option<u32>,
+}
+
+impl Default for FramePacing {
+    fn default() -> Self {
+        Self {
+            vsync: true,
+            max_fps: None,
+            render_on_change: false,
+            background_max_fps: None,
+        }
+    }
+}
+
+impl FramePacing {
+    /// Minimum time between the start of two frames, if limited.
+    /// The [FramePacing::background_max_fps] is used instead while the window is in the background.
+    pub fn frame_interval(&self, is_background: bool) -> Option<Duration> {
+        let max_fps = match self.background_max_fps {
+            Some(background_max_fps) if is_background => Some(background_max_fps),
+            _ => self.max_fps,
+        };
+        max_fps
+            .filter(|max_fps| *max_fps > 0)
+            .map(|max_fps| Duration::from_secs_f64(1.0 / max_fps as f64))
+    }
+}
+
+/// Progress shown in the taskbar icon of the window.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TaskbarProgress {
+    /// Progress between `0.0` and `1.0`.
+    Normal(f64),
+    /// Paused progress between `0.0` and `1.0`.
+    Paused(f64),
+    /// Failed progress between `0.0` and `1.0`.
+    Error(f64),
+    /// Progress of unknown length.
+    Indeterminate,
+}
+
+/// Effect shown behind the transparent areas of the window, see `LaunchConfig::with_window_effect`.
+///
+/// Each effect is only supported by some platforms, the rest ignore it.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum WindowEffect {
+    /// macOS vibrancy with the given material.
+    Vibrancy(VibrancyMaterial),
+    /// Windows 10 and 11 acrylic, a blur tinted with the given RGBA color.
+    Acrylic { tint: Option<(u8, u8, u8, u8)> },
+    /// Windows 11 Mica, tinted with the wallpaper. `dark` forces a theme instead of following the system one.
+    Mica { dark: Option<bool> },
+    /// Windows 11 Mica variant used by tabbed windows.
+    Tabbed { dark: Option<bool> },
+    /// Blur behind the window, on KDE Plasma and Windows 7 and 10.
+    Blur,
+}
+
+/// Material of the macOS vibrancy, matching the semantic uses of `NSVisualEffectMaterial`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum VibrancyMaterial {
+    Titlebar,
+    Selection,
+    Menu,
+    Popover,
+    #[default]
+    Sidebar,
+    HeaderView,
+    Sheet,
+    WindowBackground,
+    HudWindow,
+    FullScreenUI,
+    Tooltip,
+    ContentBackground,
+    UnderWindowBackground,
+    UnderPageBackground,
+}
+
+/// State of the on-screen keyboard of touch platforms.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct SoftKeyboard {
+    /// Whether the keyboard is shown.
+    pub visible: bool,
+    /// Height of the bottom of the window covered by the keyboard, in logical pixels.
+    pub inset: f32,
+}
+
+impl SoftKeyboard {
+    /// Height covered by the keyboard, or `0.0` if it is hidden.
+    pub fn visible_inset(&self) -> f32 {
+        if self.visible {
+            self.inset
+        } else {
+            0.
+        }
+    }
+}
+
+/// Phase of a frame, see [FrameTimings].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FramePhase {
+    PollVDOM,
+    Layout,
+    Paint,
+    Present,
+}
+
+/// Time spent in every phase of a frame.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct FrameTimings {
+    /// Running the components and applying their changes to the DOM, since the previous frame.
+    pub poll_vdom: Duration,
+    /// Measuring the layout.
+    pub layout: Duration,
+    /// Painting the DOM into the surface.
+    pub paint: Duration,
+    /// Flushing the drawing commands and presenting the frame.
+    pub present: Duration,
+}
+
+impl FrameTimings {
+    /// Time spent in the given phase.
+    pub fn get(&self, phase: FramePhase) -> Duration {
+        match phase {
+            FramePhase::PollVDOM => self.poll_vdom,
+            FramePhase::Layout => self.layout,
+            FramePhase::Paint => self.paint,
+            FramePhase::Present => self.present,
         }
     }
+
+    fn get_mut(&mut self, phase: FramePhase) -> &mut Duration {
+        match phase {
+            FramePhase::PollVDOM => &mut self.poll_vdom,
+            FramePhase::Layout => &mut self.layout,
+            FramePhase::Paint => &mut self.paint,
+            FramePhase::Present => &mut self.present,
+        }
+    }
+}
+
+/// Percentiles of the duration of the recent frames.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct FrameDurationPercentiles {
+    pub p50: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+}
+
+/// Rendering statistics of the platform.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct FrameStats {
+    /// Frames rendered and presented.
+    pub rendered_frames: u64,
+    /// Requested frames that were skipped because nothing changed.
+    pub skipped_frames: u64,
+    /// Time spent rendering the last frame.
+    pub last_frame_duration: Duration,
+    /// Time spent in every phase of the last frame.
+    pub last_frame_timings: FrameTimings,
+    /// Percentiles of the duration of the last [FrameStatsRecorder::HISTORY] frames.
+    pub frame_duration_percentiles: FrameDurationPercentiles,
+    /// Frames rendered in the last second.
+    pub fps: usize,
+}
+
+/// Keeps track of the [FrameStats].
+#[derive(Default)]
+pub struct FrameStatsRecorder {
+    stats: FrameStats,
+    recent_frames: VecDeque<Instant>,
+    recent_durations: VecDeque<Duration>,
+    pending_timings: FrameTimings,
+}
+
+impl FrameStatsRecorder {
+    /// Amount of frames used to calculate the percentiles.
+    pub const HISTORY: usize = 120;
+
+    /// Add the time spent in a phase to the frame being rendered.
+    pub fn record_phase(&mut self, phase: FramePhase, duration: Duration) {
+        *self.pending_timings.get_mut(phase) += duration;
+    }
+
+    /// Register a rendered frame that started at the given instant.
+    pub fn record_rendered(&mut self, started_at: Instant) {
+        let now = Instant::now();
+        self.stats.rendered_frames += 1;
+        self.stats.last_frame_duration = now - started_at;
+        self.stats.last_frame_timings = std::mem::take(&mut self.pending_timings);
+        self.recent_frames.push_back(now);
+        while let Some(frame) = self.recent_frames.front() {
+            if now.duration_since(*frame) > Duration::from_secs(1) {
+                self.recent_frames.pop_front();
+            } else {
+                break;
+            }
+        }
+        self.stats.fps = self.recent_frames.len();
+
+        self.recent_durations
+            .push_back(self.stats.last_frame_duration);
+        if self.recent_durations.len() > Self::HISTORY {
+            self.recent_durations.pop_front();
+        }
+        let mut durations = self.recent_durations.iter().copied().collect::<Vec<_>>();
+        durations.sort();
+        let percentile = |p: usize| durations[(durations.len() * p / 100).min(durations.len() - 1)];
+        self.stats.frame_duration_percentiles = FrameDurationPercentiles {
+            p50: percentile(50),
+            p95: percentile(95),
+            p99: percentile(99),
+        };
+    }
+
+    /// Register a skipped frame.
+    pub fn record_skipped(&mut self) {
+        self.stats.skipped_frames += 1;
+    }
+
+    /// Get the current [FrameStats].
+    pub fn stats(&self) -> FrameStats {
+        self.stats
+    }
 }
@@ -1,3 +1,5 @@
+use std::any::Any;
+
 use freya_engine::prelude::{
     Canvas,
     FontCollection,
@@ -13,6 +15,8 @@ use crate::{
     dom::FreyaDOM,
     event_loop_messages::EventLoopMessage,
     events::PlatformEvent,
+    platform_state::FrameStats,
+    render::RenderedLayer,
 };
 
 #[derive(Clone)]
@@ -38,6 +42,15 @@ impl PluginHandle {
     pub fn send_event_loop_event(&self, event: EventLoopMessage) {
         self.proxy.send_event(event).ok();
     }
+
+    /// Send a custom message to the plugins through the event loop, received as [PluginEvent::Message].
+    ///
+    /// Useful to notify the plugins from other threads or from the app itself.
+    pub fn send_message(&self, message: impl Any + Send + Sync) {
+        self.proxy
+            .send_event(EventLoopMessage::PluginMessage(Box::new(message)))
+            .ok();
+    }
 }
 
 /// Manages all loaded plugins.
@@ -51,6 +64,18 @@ impl PluginsManager {
         self.plugins.push(Box::new(plugin))
     }
 
+    /// Whether no plugin has been loaded.
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    /// Whether any plugin draws between the layers, see [FreyaPlugin::draws_between_layers].
+    pub fn draws_between_layers(&self) -> bool {
+        self.plugins
+            .iter()
+            .any(|plugin| plugin.draws_between_layers())
+    }
+
     pub fn send(&mut self, event: PluginEvent, handle: PluginHandle) {
         for plugin in &mut self.plugins {
             plugin.on_event(&event, handle.clone())
@@ -77,12 +102,23 @@ pub enum PluginEvent<'a> {
         freya_dom: &'a FreyaDOM,
     },
 
+    /// After painting a layer, before painting the layers on top of it.
+    ///
+    /// Only emitted if [FreyaPlugin::draws_between_layers] is `true` for any plugin.
+    AfterRenderLayer(RenderedLayer<'a>),
+
     /// Before starting to measure the layout.
     StartedMeasuringLayout(&'a Torin<NodeId>),
 
     /// After measuring the layout.
     FinishedMeasuringLayout(&'a Torin<NodeId>),
 
+    /// The layout and the layers have been updated, they can be read from the DOM.
+    AfterLayout {
+        freya_dom: &'a FreyaDOM,
+        scale_factor: f32,
+    },
+
     /// Before starting to process the queued events.
     StartedMeasuringEvents,
 
@@ -92,10 +128,36 @@ pub enum PluginEvent<'a> {
     StartedUpdatingDOM,
 
     FinishedUpdatingDOM,
+
+    /// A frame has been presented, with the updated statistics.
+    FramePresented(&'a FrameStats),
+
+    /// An event received from the platform, before it is processed by the app.
+    PlatformEvent(&'a PlatformEvent),
+
+    /// A message sent with [PluginHandle::send_message], every plugin receives it.
+    Message(&'a (dyn Any + Send + Sync)),
+}
+
+impl PluginEvent<'_> {
+    /// Get the message sent with [PluginHandle::send_message] if it is of the given type.
+    pub fn message<T: Any>(&self) -> Option<&T> {
+        match self {
+            Self::Message(message) => message.downcast_ref(),
+            _ => None,
+        }
+    }
 }
 
 /// Skeleton for Freya plugins.
 pub trait FreyaPlugin {
     /// React on events emitted by Freya.
     fn on_event(&mut self, event: &PluginEvent, handle: PluginHandle);
+
+    /// Whether the plugin draws between the layers with [PluginEvent::AfterRenderLayer].
+    ///
+    /// The whole window is painted again in every frame while this is `true`.
+    fn draws_between_layers(&self) -> bool {
+        false
+    }
 }
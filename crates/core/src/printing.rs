@@ -0,0 +1,219 @@
+use std::fmt;
+
+use crate::values::PageBreak;
+
+/// Resolution used for the parts of the UI that can't be printed as vectors, like blurs or shaders.
+pub const PRINT_DPI: f32 = 300.;
+
+/// Points, the unit of the pages, per logical pixel. Logical pixels are 1/96 of an inch and points 1/72.
+pub const POINTS_PER_PIXEL: f32 = 72. / 96.;
+
+/// Printer installed in the system.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Printer {
+    pub name: String,
+    /// Whether this is the printer used when none is specified.
+    pub is_default: bool,
+}
+
+/// Size of a page in points (1/72 of an inch).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PageSize {
+    pub width: f32,
+    pub height: f32,
+}
+
+impl PageSize {
+    pub const A4: Self = Self::new(595., 842.);
+    pub const A5: Self = Self::new(420., 595.);
+    pub const LETTER: Self = Self::new(612., 792.);
+    pub const LEGAL: Self = Self::new(612., 1008.);
+
+    pub const fn new(width: f32, height: f32) -> Self {
+        Self { width, height }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PageOrientation {
+    #[default]
+    Portrait,
+    Landscape,
+}
+
+/// Options of a print job.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrintOptions {
+    /// Name of the printer, the default printer is used otherwise.
+    pub printer: Option<String>,
+    /// Name of the job shown in the printing queue.
+    pub job_name: String,
+    /// Defaults to `1`.
+    pub copies: u32,
+    /// Defaults to [PageSize::A4].
+    pub page_size: PageSize,
+    pub orientation: PageOrientation,
+    /// Space between the edges of the pages and the content, in points. Defaults to `36` (half an inch).
+    pub margin: f32,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            printer: None,
+            job_name: "Freya".to_string(),
+            copies: 1,
+            page_size: PageSize::A4,
+            orientation: PageOrientation::Portrait,
+            margin: 36.,
+        }
+    }
+}
+
+impl PrintOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_printer(mut self, printer: impl Into<String>) -> Self {
+        self.printer = Some(printer.into());
+        self
+    }
+
+    pub fn with_job_name(mut self, job_name: impl Into<String>) -> Self {
+        self.job_name = job_name.into();
+        self
+    }
+
+    pub fn with_copies(mut self, copies: u32) -> Self {
+        self.copies = copies.max(1);
+        self
+    }
+
+    pub fn with_page_size(mut self, page_size: PageSize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    pub fn with_orientation(mut self, orientation: PageOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = margin.max(0.);
+        self
+    }
+
+    /// Size of the pages in points, rotated for [PageOrientation::Landscape].
+    pub fn page_size(&self) -> PageSize {
+        match self.orientation {
+            PageOrientation::Portrait => self.page_size,
+            PageOrientation::Landscape => {
+                PageSize::new(self.page_size.height, self.page_size.width)
+            }
+        }
+    }
+
+    /// Size of the pages without the margins, in points.
+    pub fn printable_size(&self) -> PageSize {
+        let page_size = self.page_size();
+        PageSize::new(
+            (page_size.width - self.margin * 2.).max(1.),
+            (page_size.height - self.margin * 2.).max(1.),
+        )
+    }
+}
+
+/// Error when printing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrintError {
+    /// The node is not mounted or has an empty size.
+    NothingToPrint,
+    /// There is no printer with the given name.
+    PrinterNotFound(String),
+    /// The operating system failed to print the document.
+    Failed(String),
+    /// Printing is not supported in this platform.
+    Unsupported,
+    /// The renderer is not running, e.g. because the app is closing.
+    Unavailable,
+}
+
+impl fmt::Display for PrintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NothingToPrint => f.write_str("There is nothing to print"),
+            Self::PrinterNotFound(name) => write!(f, "Printer '{name}' not found"),
+            Self::Failed(err) => write!(f, "Failed to print: {err}"),
+            Self::Unsupported => f.write_str("Printing is not supported in this platform"),
+            Self::Unavailable => f.write_str("The UI can't be printed"),
+        }
+    }
+}
+
+impl std::error::Error for PrintError {}
+
+/// Vertical bounds of an element with a [PageBreak] other than [PageBreak::Auto].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PageBreakHint {
+    pub top: f32,
+    pub bottom: f32,
+    pub page_break: PageBreak,
+}
+
+/// Split content of the given height into pages, returning the vertical range of every page.
+///
+/// Pages are split where they are full, before and after the elements that ask for it,
+/// and before the elements that avoid being split as long as they fit in a page.
+pub fn paginate(content_height: f32, page_height: f32, hints: &[PageBreakHint]) -> Vec<(f32, f32)> {
+    // Ignore breaks that would create empty pages
+    const MIN_PAGE_HEIGHT: f32 = 1.;
+
+    let mut pages = Vec::new();
+    let mut start = 0.;
+    loop {
+        let mut end = start + page_height;
+
+        let forced_break = hints
+            .iter()
+            .filter_map(|hint| match hint.page_break {
+                PageBreak::Before => Some(hint.top),
+                PageBreak::After => Some(hint.bottom),
+                _ => None,
+            })
+            .filter(|y| *y > start + MIN_PAGE_HEIGHT && *y < end && *y < content_height)
+            .fold(None, |min: Option<f32>, y| {
+                Some(min.map_or(y, |min| min.min(y)))
+            });
+
+        if let Some(forced_break) = forced_break {
+            end = forced_break;
+        } else if end < content_height {
+            let avoided_split = hints
+                .iter()
+                .filter(|hint| {
+                    hint.page_break == PageBreak::Avoid
+                        && hint.top > start + MIN_PAGE_HEIGHT
+                        && hint.top < end
+                        && hint.bottom > end
+                        && hint.bottom - hint.top <= page_height
+                })
+                .map(|hint| hint.top)
+                .fold(None, |min: Option<f32>, y| {
+                    Some(min.map_or(y, |min| min.min(y)))
+                });
+            if let Some(top) = avoided_split {
+                end = top;
+            }
+        }
+
+        if end >= content_height {
+            pages.push((start, content_height));
+            break;
+        }
+        pages.push((start, end));
+        start = end;
+    }
+    pages
+}
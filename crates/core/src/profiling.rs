@@ -0,0 +1,45 @@
+//! Instrumentation of the phases of a frame with [tracing] spans, so they can be inspected
+//! with profilers such as Tracy or Perfetto through their `tracing` subscribers.
+//!
+//! The spans are only created with the `profiling` feature, otherwise [profile_span!](crate::profile_span)
+//! compiles to nothing.
+
+pub use tracing;
+
+/// Placeholder of a span when the `profiling` feature is disabled.
+pub struct ProfileSpan;
+
+impl ProfileSpan {
+    /// Ignore the value, see [tracing::Span::record].
+    pub fn record<V>(&self, _field: &str, _value: V) -> &Self {
+        self
+    }
+}
+
+/// Enter a span at the `info` level that is exited when the returned guard is dropped.
+///
+/// Fields that are only known later, like the amount of processed nodes,
+/// can be declared as `tracing::field::Empty` and recorded with `record`.
+///
+/// ```rust,ignore
+/// let span = profile_span!("layout", nodes = tracing::field::Empty);
+/// span.record("nodes", 10);
+/// ```
+#[cfg(feature = "profiling")]
+#[macro_export]
+macro_rules! profile_span {
+    ($($args:tt)*) => {
+        $crate::profiling::tracing::info_span!($($args)*).entered()
+    };
+}
+
+/// Enter a span at the `info` level that is exited when the returned guard is dropped.
+///
+/// Does nothing because the `profiling` feature is disabled.
+#[cfg(not(feature = "profiling"))]
+#[macro_export]
+macro_rules! profile_span {
+    ($($args:tt)*) => {
+        $crate::profiling::ProfileSpan
+    };
+}
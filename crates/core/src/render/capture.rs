@@ -0,0 +1,52 @@
+use bytes::Bytes;
+use freya_engine::prelude::{
+    AlphaType,
+    ColorType,
+    EncodedImageFormat,
+    ImageInfo,
+    Surface,
+};
+
+/// Raw pixels of a rendered frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapturedFrame {
+    pub width: u32,
+    pub height: u32,
+    /// Premultiplied RGBA8888 pixels, row by row.
+    pub pixels: Bytes,
+}
+
+/// Encode the current content of the surface as a PNG image.
+pub fn capture_surface_png(surface: &mut Surface) -> Option<Bytes> {
+    let image = surface.image_snapshot();
+    let mut context = surface.direct_context();
+    let data = image.encode(context.as_mut(), EncodedImageFormat::PNG, None)?;
+    Some(Bytes::copy_from_slice(&data))
+}
+
+/// Read the raw pixels of the current content of the surface.
+pub fn capture_surface_frame(surface: &mut Surface) -> Option<CapturedFrame> {
+    let (width, height) = (surface.width(), surface.height());
+    if width <= 0 || height <= 0 {
+        return None;
+    }
+
+    let info = ImageInfo::new(
+        (width, height),
+        ColorType::RGBA8888,
+        AlphaType::Premul,
+        None,
+    );
+    let row_bytes = width as usize * 4;
+    let mut pixels = vec![0; row_bytes * height as usize];
+
+    if !surface.read_pixels(&info, &mut pixels, row_bytes, (0, 0)) {
+        return None;
+    }
+
+    Some(CapturedFrame {
+        width: width as u32,
+        height: height as u32,
+        pixels: Bytes::from(pixels),
+    })
+}
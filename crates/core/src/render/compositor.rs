@@ -315,6 +315,11 @@ impl Compositor {
         dirty_layers
     }
 
+    /// Check if the next frame will be fully rendered.
+    pub fn is_full_render(&self) -> bool {
+        self.full_render
+    }
+
     /// Reset the compositor, thus causing a full render in the next frame.
     pub fn reset(&mut self) {
         self.full_render = true;
@@ -0,0 +1,86 @@
+use std::collections::VecDeque;
+
+use torin::prelude::Area;
+
+/// Maximum amount of frames to remember. Drivers rarely use more than triple buffering.
+const MAX_TRACKED_FRAMES: usize = 4;
+
+/// Keeps the damaged areas of the latest frames, so only the outdated
+/// regions of a reused back buffer need to be presented again.
+#[derive(Default, Debug, Clone)]
+pub struct DamageTracker {
+    /// Most recent frames first. `None` means nothing was damaged.
+    history: VecDeque<Option<Area>>,
+}
+
+impl DamageTracker {
+    /// Register the damaged area of a new frame.
+    pub fn push(&mut self, damage: Option<Area>) {
+        self.history.push_front(damage);
+        self.history.truncate(MAX_TRACKED_FRAMES);
+    }
+
+    /// Forget all the history, forcing the next frames to be fully presented.
+    pub fn reset(&mut self) {
+        self.history.clear();
+    }
+
+    /// Get the region that must be presented given the age of the back buffer,
+    /// including the latest registered frame.
+    ///
+    /// Returns `None` when the whole surface must be presented, which happens
+    /// when the age is unknown (`0`) or older than the tracked history.
+    /// Returns `Some(None)` when nothing needs to be presented.
+    pub fn region(&self, buffer_age: u32) -> Option<Option<Area>> {
+        let buffer_age = buffer_age as usize;
+        if buffer_age == 0 || buffer_age > self.history.len() {
+            return None;
+        }
+
+        Some(self.history.iter().take(buffer_age).fold(
+            None,
+            |region: Option<Area>, damage| match (region, damage) {
+                (Some(region), Some(damage)) => Some(region.union(damage)),
+                (region, damage) => region.or(*damage),
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use torin::prelude::Area;
+
+    use super::DamageTracker;
+
+    #[test]
+    fn damage_region() {
+        let mut tracker = DamageTracker::default();
+
+        // Unknown history
+        assert_eq!(tracker.region(1), None);
+
+        tracker.push(Some(Area::new((0., 0.).into(), (10., 10.).into())));
+        tracker.push(None);
+        tracker.push(Some(Area::new((20., 20.).into(), (10., 10.).into())));
+
+        // Unknown buffer age
+        assert_eq!(tracker.region(0), None);
+
+        assert_eq!(
+            tracker.region(1),
+            Some(Some(Area::new((20., 20.).into(), (10., 10.).into())))
+        );
+        assert_eq!(
+            tracker.region(3),
+            Some(Some(Area::new((0., 0.).into(), (30., 30.).into())))
+        );
+
+        // Older than the history
+        assert_eq!(tracker.region(4), None);
+
+        tracker.reset();
+        tracker.push(None);
+        assert_eq!(tracker.region(1), Some(None));
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+
+use freya_engine::prelude::{
+    pdf,
+    svg,
+    FontCollection,
+    FontMgr,
+    Rect,
+};
+use freya_native_core::NodeId;
+
+use super::{
+    find_node_by_reference,
+    render_subtree,
+};
+use crate::{
+    custom_attributes::NodeReference,
+    dom::FreyaDOM,
+    fonts::ResolvedFonts,
+};
+
+/// Vector format to export the UI to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExportFormat {
+    /// Single page PDF document with the size of the exported node.
+    Pdf,
+    Svg,
+}
+
+impl ExportFormat {
+    /// File extension of the format, without the dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Pdf => "pdf",
+            Self::Svg => "svg",
+        }
+    }
+}
+
+/// What to export, see [ExportFormat].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExportTarget {
+    /// The whole content of the window.
+    Window,
+    /// The node with the given reference attached and all its descendants.
+    Node(NodeReference),
+}
+
+impl ExportTarget {
+    /// Find the node to export.
+    pub fn find_node(&self, fdom: &FreyaDOM) -> Option<NodeId> {
+        match self {
+            Self::Window => Some(fdom.rdom().root_id()),
+            Self::Node(reference) => find_node_by_reference(fdom, reference),
+        }
+    }
+}
+
+impl From<NodeReference> for ExportTarget {
+    fn from(reference: NodeReference) -> Self {
+        Self::Node(reference)
+    }
+}
+
+/// Error when exporting the UI.
+#[derive(Debug)]
+pub enum ExportError {
+    /// The node is not mounted or has an empty size.
+    NothingToExport,
+    /// The exported document could not be written.
+    Io(std::io::Error),
+    /// The renderer is not running, e.g. because the app is closing.
+    Unavailable,
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NothingToExport => f.write_str("There is nothing to export"),
+            Self::Io(err) => write!(f, "Failed to write the exported document: {err}"),
+            Self::Unavailable => f.write_str("The UI can't be exported"),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+/// Render the given node and all its descendants into a vector document.
+///
+/// Instead of rasterizing, the drawing commands are replayed into the PDF or SVG backends,
+/// so text and shapes keep their quality when printed or zoomed. The document is measured in logical pixels.
+pub fn export_node(
+    fdom: &FreyaDOM,
+    node_id: NodeId,
+    format: ExportFormat,
+    scale_factor: f32,
+    font_collection: &mut FontCollection,
+    font_manager: &FontMgr,
+    default_fonts: &ResolvedFonts,
+) -> Option<Vec<u8>> {
+    let area = fdom.layout().get(node_id)?.visible_area();
+    let ratio = 1. / scale_factor;
+    let (width, height) = (area.width() * ratio, area.height() * ratio);
+    if width <= 0. || height <= 0. {
+        return None;
+    }
+
+    let render = |canvas: &freya_engine::prelude::Canvas, font_collection: &mut FontCollection| {
+        canvas.scale((ratio, ratio));
+        canvas.translate((-area.min_x(), -area.min_y()));
+        render_subtree(
+            canvas,
+            fdom,
+            node_id,
+            area,
+            scale_factor,
+            font_collection,
+            font_manager,
+            default_fonts,
+        );
+    };
+
+    match format {
+        ExportFormat::Pdf => {
+            let mut output = Vec::new();
+            let document = pdf::new_document(&mut output, None);
+            let mut page = document.begin_page((width, height), None);
+            render(page.canvas(), font_collection);
+            page.end_page().close();
+            Some(output)
+        }
+        ExportFormat::Svg => {
+            let canvas = svg::Canvas::new(Rect::from_wh(width, height), None::<svg::canvas::Flags>);
+            render(&canvas, font_collection);
+            Some(canvas.end().to_vec())
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use freya_engine::prelude::FontCollection;
+use freya_native_core::{
+    node::ElementNode,
+    prelude::NodeType,
+    real_dom::NodeImmutable,
+    tags::TagName,
+    tree::TreeRef,
+    NodeId,
+};
+use torin::prelude::{
+    Area,
+    SendAnyMap,
+};
+
+use super::{
+    create_paragraph,
+    get_cursor_rect,
+    ParagraphCache,
+    ParagraphCacheKey,
+    ParagraphData,
+};
+use crate::{
+    dom::FreyaDOM,
+    elements::CachedParagraph,
+    fonts::ResolvedFonts,
+    states::{
+        CursorState,
+        LayoutState,
+    },
+};
+
+/// Get the area where the IME candidate window should be placed for the focused node.
+///
+/// This is the cursor of the first paragraph with a cursor inside the focused node,
+/// or the whole focused node if there is none.
+///
+/// The paragraph is only shaped again when its text or style change, it is kept in `paragraph_cache` otherwise.
+pub fn ime_cursor_area(
+    fdom: &FreyaDOM,
+    focused_node_id: NodeId,
+    paragraph_cache: &mut ParagraphCache,
+    font_collection: &mut FontCollection,
+    default_fonts: &ResolvedFonts,
+    scale_factor: f32,
+) -> Option<Area> {
+    let rdom = fdom.rdom();
+    let layout = fdom.layout();
+    let focused_area = layout.get(focused_node_id)?.visible_area();
+
+    let mut stack = vec![focused_node_id];
+    let tree = rdom.tree_ref();
+    while let Some(node_id) = stack.pop() {
+        stack.extend(tree.children_ids(node_id).into_iter().rev());
+
+        let Some(node_ref) = rdom.get(node_id) else {
+            continue;
+        };
+        let NodeType::Element(ElementNode {
+            tag: TagName::Paragraph,
+            ..
+        }) = &*node_ref.node_type()
+        else {
+            continue;
+        };
+        if node_ref.get::<CursorState>().unwrap().position.is_none() {
+            continue;
+        }
+        let Some(layout_node) = layout.get(node_id) else {
+            continue;
+        };
+
+        let area = layout_node.visible_area();
+        let inner_sized = node_ref.get::<LayoutState>().unwrap().width.inner_sized();
+        let key = ParagraphCacheKey::from_node(&node_ref, inner_sized, &area.size);
+        let data = match key.as_ref().and_then(|key| paragraph_cache.get(key)) {
+            Some((_, data)) => data,
+            None => {
+                let ParagraphData {
+                    paragraph, size, ..
+                } = create_paragraph(
+                    &node_ref,
+                    &area.size,
+                    font_collection,
+                    true,
+                    default_fonts,
+                    scale_factor,
+                );
+                let mut map = SendAnyMap::new();
+                map.insert(CachedParagraph(paragraph));
+                let data = Arc::new(map);
+                if let Some(key) = key {
+                    paragraph_cache.insert(key, size, data.clone());
+                }
+                data
+            }
+        };
+        let paragraph = &data.get::<CachedParagraph>().unwrap().0;
+        if let Some(cursor_rect) = get_cursor_rect(&area, paragraph, &node_ref) {
+            return Some(Area::new(
+                (cursor_rect.left, cursor_rect.top).into(),
+                (cursor_rect.width(), cursor_rect.height()).into(),
+            ));
+        }
+    }
+
+    Some(focused_area)
+}
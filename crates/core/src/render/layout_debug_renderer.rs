@@ -0,0 +1,137 @@
+use freya_engine::prelude::*;
+use torin::{
+    geometry::Area,
+    prelude::{
+        Alignment,
+        Direction,
+        LayoutNode,
+    },
+};
+
+use crate::states::LayoutState;
+
+const MARGIN_COLOR: (u8, u8, u8, u8) = (90, 246, 178, 107);
+const PADDING_COLOR: (u8, u8, u8, u8) = (90, 147, 196, 125);
+const BOUNDS_COLOR: (u8, u8, u8, u8) = (255, 111, 168, 220);
+const MAIN_ALIGN_COLOR: (u8, u8, u8, u8) = (200, 255, 0, 200);
+const CROSS_ALIGN_COLOR: (u8, u8, u8, u8) = (200, 0, 200, 255);
+
+/// Paint the bounds, the margin, the padding and the alignment guides of the given node,
+/// like the layout inspector of a browser.
+pub fn render_layout_debug(
+    canvas: &Canvas,
+    layout_node: &LayoutNode,
+    layout_state: &LayoutState,
+    has_children: bool,
+) {
+    let visible_area = layout_node.visible_area();
+    let inner_area = &layout_node.inner_area;
+
+    fill_gaps(canvas, &layout_node.area, &visible_area, MARGIN_COLOR);
+    fill_gaps(canvas, &visible_area, inner_area, PADDING_COLOR);
+
+    let mut paint = paint(BOUNDS_COLOR);
+    paint.set_style(PaintStyle::Stroke);
+    paint.set_stroke_width(1.0);
+    canvas.draw_rect(to_rect(&visible_area), &paint);
+
+    // Alignments only affect the children
+    if !has_children {
+        return;
+    }
+
+    let (x, y) = (
+        (inner_area.min_x(), inner_area.max_x()),
+        (inner_area.min_y(), inner_area.max_y()),
+    );
+    let (main_axis, cross_axis) = match layout_state.direction {
+        Direction::Vertical => (y, x),
+        Direction::Horizontal => (x, y),
+    };
+
+    let main_paint = paint_line(MAIN_ALIGN_COLOR);
+    for position in alignment_guides(&layout_state.main_alignment, main_axis) {
+        draw_guide(
+            canvas,
+            &layout_state.direction,
+            position,
+            cross_axis,
+            &main_paint,
+        );
+    }
+
+    let cross_direction = match layout_state.direction {
+        Direction::Vertical => Direction::Horizontal,
+        Direction::Horizontal => Direction::Vertical,
+    };
+    let cross_paint = paint_line(CROSS_ALIGN_COLOR);
+    for position in alignment_guides(&layout_state.cross_alignment, cross_axis) {
+        draw_guide(canvas, &cross_direction, position, main_axis, &cross_paint);
+    }
+}
+
+/// Positions in the axis where the content is aligned to,
+/// nothing for the start as that's where it would be anyway.
+fn alignment_guides(alignment: &Alignment, (start, end): (f32, f32)) -> Vec<f32> {
+    match alignment {
+        Alignment::Start => Vec::new(),
+        Alignment::Center => vec![(start + end) / 2.0],
+        Alignment::End => vec![end],
+        Alignment::SpaceBetween | Alignment::SpaceEvenly | Alignment::SpaceAround => {
+            vec![start, end]
+        }
+    }
+}
+
+/// Draw a guide perpendicular to the given axis direction.
+fn draw_guide(
+    canvas: &Canvas,
+    direction: &Direction,
+    position: f32,
+    (start, end): (f32, f32),
+    paint: &Paint,
+) {
+    match direction {
+        Direction::Vertical => canvas.draw_line((start, position), (end, position), paint),
+        Direction::Horizontal => canvas.draw_line((position, start), (position, end), paint),
+    };
+}
+
+/// Fill the space between an area and another one inside of it.
+fn fill_gaps(canvas: &Canvas, outer: &Area, inner: &Area, color: (u8, u8, u8, u8)) {
+    let paint = paint(color);
+    let gaps = [
+        // Top
+        (outer.min_x(), outer.min_y(), outer.max_x(), inner.min_y()),
+        // Bottom
+        (outer.min_x(), inner.max_y(), outer.max_x(), outer.max_y()),
+        // Left
+        (outer.min_x(), inner.min_y(), inner.min_x(), inner.max_y()),
+        // Right
+        (inner.max_x(), inner.min_y(), outer.max_x(), inner.max_y()),
+    ];
+    for (left, top, right, bottom) in gaps {
+        if right > left && bottom > top {
+            canvas.draw_rect(Rect::new(left, top, right, bottom), &paint);
+        }
+    }
+}
+
+fn paint((a, r, g, b): (u8, u8, u8, u8)) -> Paint {
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_style(PaintStyle::Fill);
+    paint.set_color(Color::from_argb(a, r, g, b));
+    paint
+}
+
+fn paint_line(color: (u8, u8, u8, u8)) -> Paint {
+    let mut paint = paint(color);
+    paint.set_style(PaintStyle::Stroke);
+    paint.set_stroke_width(1.0);
+    paint
+}
+
+fn to_rect(area: &Area) -> Rect {
+    Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y())
+}
@@ -1,10 +1,27 @@
+pub mod capture;
 pub mod compositor;
+pub mod damage;
+pub mod export;
+pub mod ime;
+mod layout_debug_renderer;
+pub mod paragraph_cache;
 pub mod pipeline;
+pub mod print;
+pub mod raster_cache;
 pub mod skia_measurer;
+pub mod snapshot;
 pub mod utils;
 mod wireframe_renderer;
 
+pub use capture::*;
 pub use compositor::*;
+pub use damage::*;
+pub use export::*;
+pub use ime::*;
+pub use paragraph_cache::*;
 pub use pipeline::*;
+pub use print::*;
+pub use raster_cache::*;
 pub use skia_measurer::*;
+pub use snapshot::*;
 pub use utils::*;
@@ -0,0 +1,264 @@
+use std::{
+    hash::{
+        Hash,
+        Hasher,
+    },
+    sync::Arc,
+};
+
+use freya_native_core::{
+    prelude::{
+        ElementNode,
+        NodeType,
+    },
+    real_dom::NodeImmutable,
+    tags::TagName,
+};
+use rustc_hash::FxHashMap;
+use torin::prelude::{
+    SendAnyMap,
+    Size2D,
+};
+
+use crate::{
+    dom::DioxusNode,
+    fonts::ResolvedFonts,
+    states::FontStyleState,
+};
+
+/// Amount of shaped paragraphs kept by default.
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// Everything that affects the shaping of a `label` or `paragraph`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParagraphCacheKey {
+    pub tag: TagName,
+    /// Style of the element itself.
+    pub font_style: FontStyleState,
+    /// Text of every span, with the style of the span if any.
+    pub spans: Vec<(String, Option<FontStyleState>)>,
+    /// Width the paragraph is laid out with, `None` when it doesn't depend on the available width.
+    pub width: Option<f32>,
+    /// Whether the width of the element is sized by its content.
+    pub inner_sized: bool,
+}
+
+impl ParagraphCacheKey {
+    /// Create the key of a `label` or `paragraph` element, `inner_sized` tells whether its width is sized by its content.
+    pub fn from_node(node: &DioxusNode, inner_sized: bool, area_size: &Size2D) -> Option<Self> {
+        let tag = *node.node_type().tag()?;
+        let font_style = (*node.get::<FontStyleState>()?).clone();
+
+        let spans = node
+            .children()
+            .iter()
+            .filter_map(|child| match &*child.node_type() {
+                NodeType::Text(text) if tag == TagName::Label => Some((text.clone(), None)),
+                NodeType::Element(ElementNode {
+                    tag: TagName::Text, ..
+                }) if tag == TagName::Paragraph => {
+                    let text_nodes = child.children();
+                    let text = match &*text_nodes.first()?.node_type() {
+                        NodeType::Text(text) => text.clone(),
+                        _ => String::new(),
+                    };
+                    Some((
+                        text,
+                        child.get::<FontStyleState>().map(|state| (*state).clone()),
+                    ))
+                }
+                _ => None,
+            })
+            .collect();
+
+        // Single lines aligned at the start and without ellipsis are laid out with an infinite width
+        let width = if font_style.is_unbounded_single_line() {
+            None
+        } else {
+            Some(area_size.width)
+        };
+
+        Some(Self {
+            tag,
+            font_style,
+            spans,
+            width,
+            inner_sized,
+        })
+    }
+}
+
+// Font styles only use finite floats, so the equality is reflexive
+impl Eq for ParagraphCacheKey {}
+
+impl Hash for ParagraphCacheKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // The styles are left out, equal keys still produce equal hashes
+        self.tag.hash(state);
+        for (text, _) in &self.spans {
+            text.hash(state);
+        }
+        self.width.map(f32::to_bits).hash(state);
+        self.inner_sized.hash(state);
+    }
+}
+
+struct ParagraphCacheEntry {
+    size: Size2D,
+    data: Arc<SendAnyMap>,
+    last_used: u64,
+}
+
+/// LRU cache of shaped paragraphs, so unchanged texts don't need to be shaped again in every layout pass.
+///
+/// All the entries are dropped when the scale factor or the fonts change.
+pub struct ParagraphCache {
+    entries: FxHashMap<ParagraphCacheKey, ParagraphCacheEntry>,
+    capacity: usize,
+    tick: u64,
+    scale_factor: f32,
+    default_fonts: ResolvedFonts,
+}
+
+impl Default for ParagraphCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl ParagraphCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            capacity: capacity.max(1),
+            tick: 0,
+            scale_factor: 0.,
+            default_fonts: ResolvedFonts::default(),
+        }
+    }
+
+    /// Drop all the entries if the given scale factor or fonts differ from the ones used to shape them.
+    pub fn prepare(&mut self, scale_factor: f32, default_fonts: &ResolvedFonts) {
+        if self.scale_factor != scale_factor || self.default_fonts != *default_fonts {
+            self.clear();
+            self.scale_factor = scale_factor;
+            self.default_fonts = default_fonts.clone();
+        }
+    }
+
+    /// Drop all the entries, e.g. when new fonts are registered.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&mut self, key: &ParagraphCacheKey) -> Option<(Size2D, Arc<SendAnyMap>)> {
+        self.tick += 1;
+        let entry = self.entries.get_mut(key)?;
+        entry.last_used = self.tick;
+        Some((entry.size, entry.data.clone()))
+    }
+
+    pub fn insert(&mut self, key: ParagraphCacheKey, size: Size2D, data: Arc<SendAnyMap>) {
+        self.tick += 1;
+        self.entries.insert(
+            key,
+            ParagraphCacheEntry {
+                size,
+                data,
+                last_used: self.tick,
+            },
+        );
+
+        if self.entries.len() > self.capacity {
+            self.evict();
+        }
+    }
+
+    /// Drop the least recently used entries until only three quarters of the capacity are used,
+    /// so evicting doesn't happen on every insertion.
+    fn evict(&mut self) {
+        let keep = (self.capacity * 3 / 4).max(1);
+        let mut ticks = self
+            .entries
+            .values()
+            .map(|entry| entry.last_used)
+            .collect::<Vec<_>>();
+        let drop = ticks.len() - keep;
+        let (_, threshold, _) = ticks.select_nth_unstable(drop - 1);
+        let threshold = *threshold;
+        self.entries.retain(|_, entry| entry.last_used > threshold);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use freya_native_core::tags::TagName;
+    use torin::prelude::Size2D;
+
+    use super::{
+        ParagraphCache,
+        ParagraphCacheKey,
+    };
+    use crate::{
+        fonts::ResolvedFonts,
+        states::FontStyleState,
+    };
+
+    fn key(text: &str) -> ParagraphCacheKey {
+        ParagraphCacheKey {
+            tag: TagName::Label,
+            font_style: FontStyleState::default(),
+            spans: vec![(text.to_string(), None)],
+            width: Some(100.),
+            inner_sized: false,
+        }
+    }
+
+    #[test]
+    fn paragraph_cache() {
+        let mut cache = ParagraphCache::new(4);
+        cache.prepare(1., &ResolvedFonts::default());
+
+        for text in ["a", "b", "c", "d"] {
+            cache.insert(key(text), Size2D::new(10., 10.), Arc::default());
+        }
+        assert_eq!(cache.len(), 4);
+
+        // Different style or constraints
+        let mut styled = key("a");
+        styled.font_style.font_size = 20.;
+        assert!(cache.get(&styled).is_none());
+        let mut narrow = key("a");
+        narrow.width = Some(50.);
+        assert!(cache.get(&narrow).is_none());
+
+        // Use "a" so it's not the least recently used anymore
+        assert!(cache.get(&key("a")).is_some());
+
+        // Overflow, the least recently used are dropped
+        cache.insert(key("e"), Size2D::new(10., 10.), Arc::default());
+        assert_eq!(cache.len(), 3);
+        assert!(cache.get(&key("a")).is_some());
+        assert!(cache.get(&key("b")).is_none());
+        assert!(cache.get(&key("c")).is_none());
+        assert!(cache.get(&key("e")).is_some());
+
+        // Same scale factor and fonts
+        cache.prepare(1., &ResolvedFonts::default());
+        assert_eq!(cache.len(), 3);
+
+        // New scale factor
+        cache.prepare(2., &ResolvedFonts::default());
+        assert!(cache.is_empty());
+    }
+}
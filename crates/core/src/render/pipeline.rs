@@ -1,4 +1,7 @@
+use std::ops::Range;
+
 use freya_engine::prelude::{
+    Canvas,
     ClipOp,
     Color,
     FontCollection,
@@ -26,10 +29,15 @@ use torin::prelude::{
 };
 
 use super::{
+    layout_debug_renderer,
+    subtree_nodes,
     wireframe_renderer,
     Compositor,
     CompositorCache,
     CompositorDirtyArea,
+    DamageTracker,
+    RasterCache,
+    RasterCacheEntry,
 };
 use crate::{
     dom::{
@@ -42,13 +50,28 @@ use crate::{
         ElementUtils,
         ElementUtilsResolver,
     },
+    fonts::ResolvedFonts,
     layers::Layers,
     states::{
+        CacheState,
+        LayoutState,
         TransformState,
         ViewportState,
     },
 };
 
+/// Layer that has just been painted, see [RenderPipeline::layer_hook].
+pub struct RenderedLayer<'a> {
+    pub layer: i16,
+    /// Canvas where the layers are painted.
+    pub canvas: &'a Canvas,
+    pub font_collection: &'a FontCollection,
+    pub rdom: &'a DioxusDOM,
+    pub layout: &'a Torin<NodeId>,
+    pub layers: &'a Layers,
+    pub scale_factor: f32,
+}
+
 /// Runs the full rendering cycle.
 pub struct RenderPipeline<'a> {
     pub rdom: &'a DioxusDOM,
@@ -57,6 +80,7 @@ pub struct RenderPipeline<'a> {
     pub compositor_dirty_nodes: &'a mut CompositorDirtyNodes,
     pub compositor_dirty_area: &'a mut CompositorDirtyArea,
     pub compositor_cache: &'a mut CompositorCache,
+    pub raster_cache: &'a mut RasterCache,
     pub surface: &'a mut Surface,
     pub dirty_surface: &'a mut Surface,
     pub compositor: &'a mut Compositor,
@@ -67,13 +91,32 @@ pub struct RenderPipeline<'a> {
     pub background: Color,
     pub scale_factor: f32,
     pub selected_node: Option<NodeId>,
-    pub default_fonts: &'a [String],
+    /// Paint the bounds, margins, paddings and alignments of every node on top.
+    pub debug_layout: bool,
+    pub default_fonts: &'a ResolvedFonts,
+    pub damage_tracker: &'a mut DamageTracker,
+    /// Age of the back buffer of `surface`, `0` if unknown.
+    pub buffer_age: u32,
+    /// Called after painting every layer, only the layers with dirty nodes are painted.
+    pub layer_hook: Option<&'a mut dyn FnMut(RenderedLayer)>,
 }
 
 impl RenderPipeline<'_> {
-    pub fn run(&mut self) {
+    /// Render and copy the result into `surface`.
+    ///
+    /// Returns the area of `surface` that was updated, or `None` if it was fully updated.
+    pub fn run(&mut self) -> Option<Area> {
+        let span = crate::profile_span!(
+            "paint",
+            layers = tracing::field::Empty,
+            painted = tracing::field::Empty
+        );
         let mut dirty_layers = Layers::default();
 
+        // Drop the rasterized subtrees whose content changed
+        self.raster_cache
+            .invalidate(self.compositor_dirty_nodes.take_invalidated(), self.rdom);
+
         // Process what nodes need to be rendered
         let rendering_layers = self.compositor.run(
             self.compositor_dirty_nodes,
@@ -86,6 +129,9 @@ impl RenderPipeline<'_> {
             self.scale_factor,
         );
 
+        // Paint rasterized subtrees all at once
+        let rendering_layers = RasterCache::group_layers(rendering_layers, self.rdom);
+
         #[cfg(feature = "fade-cached-incremental-areas")]
         {
             // Slowly fade into white non-rerendered areas
@@ -113,9 +159,12 @@ impl RenderPipeline<'_> {
         // Round the area out to prevent float pixels issues
         self.compositor_dirty_area.round_out();
 
+        let dirty_area = self.compositor_dirty_area.take();
+        self.damage_tracker.push(dirty_area);
+
         // Clear using the the background only, but only the dirty
         // area in which it will render the intersected nodes again
-        if let Some(dirty_area) = self.compositor_dirty_area.take() {
+        if let Some(dirty_area) = dirty_area {
             #[cfg(debug_assertions)]
             tracing::info!("Marked {dirty_area:?} as dirty area");
 
@@ -132,12 +181,12 @@ impl RenderPipeline<'_> {
             self.dirty_surface.canvas().clear(self.background);
         }
 
-        #[cfg(debug_assertions)]
-        // Counter of painted nodes for debugging purposes
+        // Counter of painted nodes for debugging and profiling purposes
         let mut painted = 0;
+        span.record("layers", rendering_layers.len());
 
         // Render the dirty nodes
-        for (_, nodes) in sorted(rendering_layers.iter()) {
+        for (layer, nodes) in sorted(rendering_layers.iter()) {
             'elements: for node_id in sorted(nodes) {
                 let node_ref = self.rdom.get(*node_id).unwrap();
                 let node_viewports = node_ref.get::<ViewportState>().unwrap();
@@ -152,15 +201,30 @@ impl RenderPipeline<'_> {
                         }
                     }
 
-                    // Render the element
-                    self.render(node_ref, layout_node);
-
-                    #[cfg(debug_assertions)]
-                    {
-                        painted += 1;
+                    // Render the element, or its whole subtree if rasterized
+                    let is_rasterized =
+                        node_ref.get::<CacheState>().unwrap().cached_by == Some(*node_id);
+                    if is_rasterized {
+                        self.render_rasterized(node_ref);
+                    } else {
+                        self.render(node_ref, layout_node);
                     }
+
+                    painted += 1;
                 }
             }
+
+            if let Some(layer_hook) = &mut self.layer_hook {
+                layer_hook(RenderedLayer {
+                    layer: *layer,
+                    canvas: self.dirty_surface.canvas(),
+                    font_collection: &*self.font_collection,
+                    rdom: self.rdom,
+                    layout: self.layout,
+                    layers: self.layers,
+                    scale_factor: self.scale_factor,
+                });
+            }
         }
 
         if let Some(selected_node) = &self.selected_node {
@@ -172,6 +236,12 @@ impl RenderPipeline<'_> {
             }
         }
 
+        if self.debug_layout {
+            self.render_layout_debug();
+        }
+
+        span.record("painted", painted);
+
         #[cfg(debug_assertions)]
         {
             if painted > 0 {
@@ -179,92 +249,98 @@ impl RenderPipeline<'_> {
             }
         }
 
-        // Copy the dirty canvas into the main canvas
         self.dirty_surface.canvas().restore();
-        self.surface.canvas().clear(self.background);
-        self.dirty_surface.draw(
-            self.surface.canvas(),
-            (0, 0),
-            SamplingOptions::default(),
-            None,
-        );
+
+        // The wireframe and the layout debug overlay are not tracked as damage
+        let damage = if self.selected_node.is_some() || self.debug_layout {
+            None
+        } else {
+            self.damage_tracker.region(self.buffer_age)
+        };
+
+        // Copy the dirty canvas into the main canvas, only
+        // the outdated region if the back buffer is reused
+        let canvas = self.surface.canvas();
+        canvas.save();
+        match damage {
+            Some(Some(damage)) => {
+                canvas.clip_rect(
+                    Rect::new(
+                        damage.min_x(),
+                        damage.min_y(),
+                        damage.max_x(),
+                        damage.max_y(),
+                    ),
+                    ClipOp::Intersect,
+                    false,
+                );
+            }
+            Some(None) => {
+                // The back buffer is already up to date
+                canvas.clip_rect(Rect::new_empty(), ClipOp::Intersect, false);
+            }
+            None => {}
+        }
+        canvas.clear(self.background);
+        self.dirty_surface
+            .draw(canvas, (0, 0), SamplingOptions::default(), None);
+        canvas.restore();
 
         self.compositor_dirty_nodes.clear();
+
+        damage.map(|damage| damage.unwrap_or_default())
     }
 
-    pub fn render(&mut self, node_ref: DioxusNode, layout_node: &LayoutNode) {
-        let dirty_canvas = self.dirty_surface.canvas();
-        let node_type = &*node_ref.node_type();
-        if let NodeType::Element(ElementNode { tag, .. }) = node_type {
-            let Some(element_utils) = tag.utils() else {
+    /// Paint the layout of every visible node over the app.
+    pub fn render_layout_debug(&mut self) {
+        let canvas = self.dirty_surface.canvas();
+        self.rdom.traverse_depth_first(|node_ref| {
+            let Some(layout_node) = self.layout.get(node_ref.id()) else {
                 return;
             };
-
-            let initial_layer = dirty_canvas.save();
-
-            // Clip all elements with their corresponding viewports
-            let node_viewports = node_ref.get::<ViewportState>().unwrap();
-
-            // Clip the element itself if non-children content can overflow, like an image in case of `image`
-            // or text in the case of `label` or `paragraph`
-            if *tag == TagName::Paragraph || *tag == TagName::Label || *tag == TagName::Image {
-                element_utils.clip(layout_node, &node_ref, dirty_canvas, self.scale_factor);
-            }
-
-            for node_id in &node_viewports.viewports {
-                let node_ref = self.rdom.get(*node_id).unwrap();
-                let node_type = node_ref.node_type();
-                let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
-                    continue;
-                };
-                let layout_node = self.layout.get(*node_id).unwrap();
-                element_utils.clip(layout_node, &node_ref, dirty_canvas, self.scale_factor);
+            if !node_ref.node_type().is_element() {
+                return;
             }
-
-            let node_transform = &*node_ref.get::<TransformState>().unwrap();
-
-            // Pass rotate effect to children
-            for (id, rotate_degs) in &node_transform.rotations {
-                let layout_node = self.layout.get(*id).unwrap();
-                let area = layout_node.visible_area();
-                let mut matrix = Matrix::new_identity();
-                matrix.set_rotate(
-                    *rotate_degs,
-                    Some(Point {
-                        x: area.min_x() + area.width() / 2.0,
-                        y: area.min_y() + area.height() / 2.0,
-                    }),
-                );
-                dirty_canvas.concat(&matrix);
+            let node_viewports = node_ref.get::<ViewportState>().unwrap();
+            for viewport_id in &node_viewports.viewports {
+                let viewport = self.layout.get(*viewport_id).unwrap().visible_area();
+                if !viewport.intersects(&layout_node.area) {
+                    return;
+                }
             }
+            let layout_state = node_ref.get::<LayoutState>().unwrap();
+            let has_children = node_ref
+                .children()
+                .iter()
+                .any(|child| self.layout.get(child.id()).is_some());
+            layout_debug_renderer::render_layout_debug(
+                canvas,
+                layout_node,
+                &layout_state,
+                has_children,
+            );
+        });
+    }
 
-            // Apply inherited opacity effects
-            for opacity in &node_transform.opacities {
-                dirty_canvas.save_layer_alpha_f(
-                    Rect::new(
-                        self.canvas_area.min_x(),
-                        self.canvas_area.min_y(),
-                        self.canvas_area.max_x(),
-                        self.canvas_area.max_y(),
-                    ),
-                    *opacity,
-                );
-            }
+    /// Render a node and its descendants through their rasterized image,
+    /// rasterizing them again if anything changed.
+    pub fn render_rasterized(&mut self, node_ref: DioxusNode) {
+        let node_id = node_ref.id();
+        let nodes = subtree_nodes(self.rdom, self.layout, node_id);
 
-            // Apply inherited scale effects
-            for (id, scale_x, scale_y) in &node_transform.scales {
-                let layout_node = self.layout.get(*id).unwrap();
-                let area = layout_node.visible_area();
-                let center = area.center();
-                dirty_canvas.translate((center.x, center.y));
-                dirty_canvas.scale((*scale_x, *scale_y));
-                dirty_canvas.translate((-center.x, -center.y));
-            }
+        let is_valid = self
+            .raster_cache
+            .get(&node_id)
+            .map(|entry| entry.is_valid(&nodes, self.scale_factor))
+            .unwrap_or_default();
 
-            element_utils.render(
-                layout_node,
-                &node_ref,
-                dirty_canvas,
+        if !is_valid {
+            let entry = RasterCacheEntry::rasterize(
+                self.dirty_surface,
+                self.rdom,
+                self.layout,
+                node_id,
+                nodes.clone(),
                 self.font_collection,
                 self.font_manager,
                 self.default_fonts,
@@ -272,7 +348,285 @@ impl RenderPipeline<'_> {
                 self.scale_factor,
             );
 
-            dirty_canvas.restore_to_count(initial_layer);
+            let Some(entry) = entry else {
+                // Too big to be rasterized, so just paint it as usual
+                self.raster_cache.remove(&node_id);
+                for node in nodes {
+                    let node_ref = self.rdom.get(node.node_id).unwrap();
+                    if let Some(layout_node) = self.layout.get(node.node_id) {
+                        self.render(node_ref, layout_node);
+                    }
+                }
+                return;
+            };
+
+            self.raster_cache.insert(node_id, entry);
+        }
+
+        if let Some(entry) = self.raster_cache.get(&node_id) {
+            entry.draw(
+                self.dirty_surface.canvas(),
+                self.rdom,
+                self.layout,
+                self.canvas_area,
+                &node_ref,
+                self.scale_factor,
+            );
+        }
+    }
+
+    pub fn render(&mut self, node_ref: DioxusNode, layout_node: &LayoutNode) {
+        render_node(
+            self.dirty_surface.canvas(),
+            self.rdom,
+            self.layout,
+            self.canvas_area,
+            node_ref,
+            layout_node,
+            self.font_collection,
+            self.font_manager,
+            self.default_fonts,
+            self.images_cache,
+            self.scale_factor,
+        );
+    }
+}
+
+/// Amount of clips and effects inherited by a node, such as viewports or opacities.
+///
+/// Effects are inherited in order from the root, so a range of them can be used to
+/// only apply those coming from outside or from inside of a subtree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InheritedEffects {
+    pub viewports: usize,
+    pub rotations: usize,
+    pub opacities: usize,
+    pub scales: usize,
+}
+
+impl InheritedEffects {
+    /// No effects.
+    pub const NONE: Self = Self {
+        viewports: 0,
+        rotations: 0,
+        opacities: 0,
+        scales: 0,
+    };
+
+    /// Every effect, no matter how many are inherited.
+    pub const ALL: Self = Self {
+        viewports: usize::MAX,
+        rotations: usize::MAX,
+        opacities: usize::MAX,
+        scales: usize::MAX,
+    };
+
+    /// Effects that the given node inherits from its ancestors.
+    pub fn from_ancestors(node_ref: &DioxusNode) -> Self {
+        let viewports = node_ref.get::<ViewportState>().unwrap().viewports.len();
+        let Some(parent) = node_ref.parent() else {
+            return Self {
+                viewports,
+                ..Self::NONE
+            };
+        };
+        let parent_transform = parent.get::<TransformState>().unwrap();
+        Self {
+            viewports,
+            rotations: parent_transform.rotations.len(),
+            opacities: parent_transform.opacities.len(),
+            scales: parent_transform.scales.len(),
         }
     }
+
+    /// Only keep the transforms within the given range.
+    pub fn filter_transform(range: &Range<Self>, transform: &TransformState) -> TransformState {
+        TransformState {
+            node_id: transform.node_id,
+            opacities: slice(
+                &transform.opacities,
+                range.start.opacities,
+                range.end.opacities,
+            )
+            .to_vec(),
+            rotations: slice(
+                &transform.rotations,
+                range.start.rotations,
+                range.end.rotations,
+            )
+            .to_vec(),
+            scales: slice(&transform.scales, range.start.scales, range.end.scales).to_vec(),
+        }
+    }
+}
+
+fn slice<T>(items: &[T], start: usize, end: usize) -> &[T] {
+    let end = end.min(items.len());
+    &items[start.min(end)..end]
+}
+
+/// Render a single node with its inherited clips and effects.
+#[allow(clippy::too_many_arguments)]
+pub fn render_node(
+    canvas: &Canvas,
+    rdom: &DioxusDOM,
+    layout: &Torin<NodeId>,
+    canvas_area: Area,
+    node_ref: DioxusNode,
+    layout_node: &LayoutNode,
+    font_collection: &mut FontCollection,
+    font_manager: &FontMgr,
+    default_fonts: &ResolvedFonts,
+    images_cache: &mut ImagesCache,
+    scale_factor: f32,
+) {
+    render_node_with_effects(
+        canvas,
+        rdom,
+        layout,
+        canvas_area,
+        node_ref,
+        layout_node,
+        font_collection,
+        font_manager,
+        default_fonts,
+        images_cache,
+        scale_factor,
+        InheritedEffects::NONE..InheritedEffects::ALL,
+    )
+}
+
+/// Render a single node only applying the given range of its inherited clips and effects.
+#[allow(clippy::too_many_arguments)]
+pub fn render_node_with_effects(
+    canvas: &Canvas,
+    rdom: &DioxusDOM,
+    layout: &Torin<NodeId>,
+    canvas_area: Area,
+    node_ref: DioxusNode,
+    layout_node: &LayoutNode,
+    font_collection: &mut FontCollection,
+    font_manager: &FontMgr,
+    default_fonts: &ResolvedFonts,
+    images_cache: &mut ImagesCache,
+    scale_factor: f32,
+    effects: Range<InheritedEffects>,
+) {
+    let node_type = &*node_ref.node_type();
+    if let NodeType::Element(ElementNode { tag, .. }) = node_type {
+        let Some(element_utils) = tag.utils() else {
+            return;
+        };
+
+        let initial_layer = canvas.save();
+
+        // Clip the element itself if non-children content can overflow, like an image in case of `image`
+        // or text in the case of `label` or `paragraph`
+        if *tag == TagName::Paragraph || *tag == TagName::Label || *tag == TagName::Image {
+            element_utils.clip(layout_node, &node_ref, canvas, scale_factor);
+        }
+
+        apply_inherited_effects(
+            canvas,
+            rdom,
+            layout,
+            canvas_area,
+            &node_ref,
+            scale_factor,
+            effects,
+        );
+
+        element_utils.render(
+            layout_node,
+            &node_ref,
+            canvas,
+            font_collection,
+            font_manager,
+            default_fonts,
+            images_cache,
+            scale_factor,
+        );
+
+        canvas.restore_to_count(initial_layer);
+    }
+}
+
+/// Apply the given range of clips and effects inherited by a node to the canvas.
+pub fn apply_inherited_effects(
+    canvas: &Canvas,
+    rdom: &DioxusDOM,
+    layout: &Torin<NodeId>,
+    canvas_area: Area,
+    node_ref: &DioxusNode,
+    scale_factor: f32,
+    effects: Range<InheritedEffects>,
+) {
+    // Clip all elements with their corresponding viewports
+    let node_viewports = node_ref.get::<ViewportState>().unwrap();
+
+    for node_id in slice(
+        &node_viewports.viewports,
+        effects.start.viewports,
+        effects.end.viewports,
+    ) {
+        let node_ref = rdom.get(*node_id).unwrap();
+        let node_type = node_ref.node_type();
+        let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
+            continue;
+        };
+        let layout_node = layout.get(*node_id).unwrap();
+        element_utils.clip(layout_node, &node_ref, canvas, scale_factor);
+    }
+
+    let node_transform = &*node_ref.get::<TransformState>().unwrap();
+
+    // Pass rotate effect to children
+    for (id, rotate_degs) in slice(
+        &node_transform.rotations,
+        effects.start.rotations,
+        effects.end.rotations,
+    ) {
+        let layout_node = layout.get(*id).unwrap();
+        let area = layout_node.visible_area();
+        let mut matrix = Matrix::new_identity();
+        matrix.set_rotate(
+            *rotate_degs,
+            Some(Point {
+                x: area.min_x() + area.width() / 2.0,
+                y: area.min_y() + area.height() / 2.0,
+            }),
+        );
+        canvas.concat(&matrix);
+    }
+
+    // Apply inherited opacity effects
+    for opacity in slice(
+        &node_transform.opacities,
+        effects.start.opacities,
+        effects.end.opacities,
+    ) {
+        canvas.save_layer_alpha_f(
+            Rect::new(
+                canvas_area.min_x(),
+                canvas_area.min_y(),
+                canvas_area.max_x(),
+                canvas_area.max_y(),
+            ),
+            *opacity,
+        );
+    }
+
+    // Apply inherited scale effects
+    for (id, scale_x, scale_y) in slice(
+        &node_transform.scales,
+        effects.start.scales,
+        effects.end.scales,
+    ) {
+        let layout_node = layout.get(*id).unwrap();
+        let area = layout_node.visible_area();
+        let center = area.center();
+        canvas.translate((center.x, center.y));
+        canvas.scale((*scale_x, *scale_y));
+        canvas.translate((-center.x, -center.y));
+    }
 }
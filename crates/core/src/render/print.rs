@@ -0,0 +1,140 @@
+use freya_engine::prelude::{
+    pdf,
+    ClipOp,
+    FontCollection,
+    FontMgr,
+    Picture,
+    PictureRecorder,
+    Rect,
+};
+use freya_native_core::{
+    real_dom::NodeImmutable,
+    tree::TreeRef,
+    NodeId,
+};
+
+use super::render_subtree;
+use crate::{
+    dom::FreyaDOM,
+    fonts::ResolvedFonts,
+    printing::{
+        paginate,
+        PageBreakHint,
+        PrintOptions,
+        POINTS_PER_PIXEL,
+        PRINT_DPI,
+    },
+    states::StyleState,
+    values::PageBreak,
+};
+
+/// Document ready to be sent to a printer.
+pub struct PrintDocument {
+    /// PDF with all the pages.
+    pub data: Vec<u8>,
+    /// Drawing commands of every page in points, for the print systems that don't accept PDFs.
+    pub page_pictures: Vec<Picture>,
+    pub pages: usize,
+}
+
+/// Render the given node and all its descendants into a paginated PDF document.
+///
+/// The content is scaled from logical pixels to points and shrunk if it's wider than the pages,
+/// then split vertically following the `page_break` attributes of the nodes.
+pub fn print_node(
+    fdom: &FreyaDOM,
+    node_id: NodeId,
+    options: &PrintOptions,
+    scale_factor: f32,
+    font_collection: &mut FontCollection,
+    font_manager: &FontMgr,
+    default_fonts: &ResolvedFonts,
+) -> Option<PrintDocument> {
+    let rdom = fdom.rdom();
+    let layout = fdom.layout();
+    let area = layout.get(node_id)?.visible_area();
+    if area.width() <= 0. || area.height() <= 0. {
+        return None;
+    }
+
+    let page_size = options.page_size();
+    let printable_size = options.printable_size();
+
+    // Points per physical pixel
+    let scale = (POINTS_PER_PIXEL / scale_factor).min(printable_size.width / area.width());
+
+    // Collect the content height and the pagination hints of the subtree, relative to the node
+    let mut content_height = area.height();
+    let mut hints = Vec::new();
+    let mut stack = vec![node_id];
+    let tree = rdom.tree_ref();
+    while let Some(id) = stack.pop() {
+        stack.extend(tree.children_ids(id));
+        let Some(layout_node) = layout.get(id) else {
+            continue;
+        };
+        let top = (layout_node.area.min_y() - area.min_y()) * scale;
+        let bottom = (layout_node.area.max_y() - area.min_y()) * scale;
+        content_height = content_height.max(bottom / scale);
+
+        let page_break = rdom
+            .get(id)
+            .and_then(|node| node.get::<StyleState>().map(|style| style.page_break))
+            .unwrap_or_default();
+        if page_break != PageBreak::Auto {
+            hints.push(PageBreakHint {
+                top,
+                bottom,
+                page_break,
+            });
+        }
+    }
+
+    let pages = paginate(content_height * scale, printable_size.height, &hints);
+
+    let metadata = pdf::Metadata {
+        title: options.job_name.clone(),
+        raster_dpi: PRINT_DPI,
+        ..Default::default()
+    };
+    let page_rect = Rect::from_wh(page_size.width, page_size.height);
+    let mut data = Vec::new();
+    let mut document = pdf::new_document(&mut data, Some(&metadata));
+    let mut page_pictures = Vec::with_capacity(pages.len());
+    for (start, end) in &pages {
+        let mut recorder = PictureRecorder::new();
+        let canvas = recorder.begin_recording(page_rect, None);
+        canvas.translate((options.margin, options.margin));
+        canvas.clip_rect(
+            Rect::from_wh(printable_size.width, end - start),
+            ClipOp::Intersect,
+            true,
+        );
+        canvas.translate((0., -start));
+        canvas.scale((scale, scale));
+        canvas.translate((-area.min_x(), -area.min_y()));
+        render_subtree(
+            canvas,
+            fdom,
+            node_id,
+            area,
+            scale_factor,
+            font_collection,
+            font_manager,
+            default_fonts,
+        );
+        let picture = recorder.finish_recording_as_picture(Some(&page_rect))?;
+
+        let mut page = document.begin_page((page_size.width, page_size.height), None);
+        page.canvas().draw_picture(&picture, None, None);
+        document = page.end_page();
+        page_pictures.push(picture);
+    }
+    document.close();
+
+    Some(PrintDocument {
+        data,
+        page_pictures,
+        pages: pages.len(),
+    })
+}
@@ -0,0 +1,338 @@
+use std::{
+    borrow::Cow,
+    ops::Range,
+};
+
+use freya_engine::prelude::{
+    Canvas,
+    Color,
+    FontCollection,
+    FontMgr,
+    Image,
+    Surface,
+};
+use freya_native_core::{
+    real_dom::NodeImmutable,
+    tree::TreeRef,
+    NodeId,
+};
+use rustc_hash::{
+    FxHashMap,
+    FxHashSet,
+};
+use torin::prelude::{
+    Area,
+    Torin,
+};
+
+use super::{
+    apply_inherited_effects,
+    render_node_with_effects,
+    Compositor,
+    InheritedEffects,
+};
+use crate::{
+    dom::{
+        DioxusDOM,
+        DioxusNode,
+        ImagesCache,
+    },
+    elements::ElementUtils,
+    fonts::ResolvedFonts,
+    layers::Layers,
+    states::{
+        CacheState,
+        LayerState,
+        StyleState,
+        TransformState,
+    },
+};
+
+/// Subtrees bigger than this in any axis are painted as usual.
+const MAX_RASTER_SIZE: f32 = 8192.;
+
+/// A node of a rasterized subtree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubtreeNode {
+    pub node_id: NodeId,
+    pub layer: i16,
+    /// Layout area relative to the root of the subtree.
+    pub area: Area,
+}
+
+/// Collect the visible nodes of a subtree in painting order.
+pub fn subtree_nodes(
+    rdom: &DioxusDOM,
+    layout: &Torin<NodeId>,
+    root_id: NodeId,
+) -> Vec<SubtreeNode> {
+    let Some(root_area) = layout.get(root_id).map(|layout_node| layout_node.area) else {
+        return Vec::new();
+    };
+    let tree = rdom.tree_ref();
+    let mut nodes = Vec::new();
+    let mut stack = vec![root_id];
+    while let Some(node_id) = stack.pop() {
+        stack.extend(tree.children_ids(node_id));
+        let (Some(node), Some(layout_node)) = (rdom.get(node_id), layout.get(node_id)) else {
+            continue;
+        };
+        if !node.node_type().is_visible_element() {
+            continue;
+        }
+        nodes.push(SubtreeNode {
+            node_id,
+            layer: node.get::<LayerState>().unwrap().layer,
+            area: layout_node.area.translate(-root_area.origin.to_vector()),
+        });
+    }
+    nodes.sort_by_key(|node| (node.layer, node.node_id));
+    nodes
+}
+
+/// A subtree rasterized into an image.
+pub struct RasterCacheEntry {
+    image: Image,
+    /// Bounds of the image relative to the root of the subtree.
+    bounds: Area,
+    nodes: Vec<SubtreeNode>,
+    scale_factor: f32,
+}
+
+impl RasterCacheEntry {
+    /// Rasterize the given subtree nodes into a new surface compatible with `surface`,
+    /// only applying the effects inherited from inside the subtree.
+    #[allow(clippy::too_many_arguments)]
+    pub fn rasterize(
+        surface: &mut Surface,
+        rdom: &DioxusDOM,
+        layout: &Torin<NodeId>,
+        root_id: NodeId,
+        nodes: Vec<SubtreeNode>,
+        font_collection: &mut FontCollection,
+        font_manager: &FontMgr,
+        default_fonts: &ResolvedFonts,
+        images_cache: &mut ImagesCache,
+        scale_factor: f32,
+    ) -> Option<Self> {
+        let root_ref = rdom.get(root_id)?;
+        let root_area = layout.get(root_id)?.area;
+        let effects = InheritedEffects::from_ancestors(&root_ref)..InheritedEffects::ALL;
+
+        // Area covered by the subtree, including shadows, borders and similar
+        let bounds = nodes
+            .iter()
+            .filter_map(|node| {
+                Compositor::with_utils(
+                    node.node_id,
+                    layout,
+                    rdom,
+                    |node_ref, utils, layout_node| {
+                        let style_state = node_ref.get::<StyleState>().unwrap();
+                        let transform_state = InheritedEffects::filter_transform(
+                            &effects,
+                            &node_ref.get::<TransformState>().unwrap(),
+                        );
+                        utils.drawing_area(
+                            layout_node,
+                            &node_ref,
+                            layout,
+                            scale_factor,
+                            &style_state,
+                            &transform_state,
+                        )
+                    },
+                )
+            })
+            .reduce(|bounds, area| bounds.union(&area))?
+            .round_out();
+
+        if bounds.is_empty()
+            || bounds.width() > MAX_RASTER_SIZE
+            || bounds.height() > MAX_RASTER_SIZE
+        {
+            return None;
+        }
+
+        let mut raster_surface =
+            surface.new_surface_with_dimensions((bounds.width() as i32, bounds.height() as i32))?;
+        let canvas = raster_surface.canvas();
+        canvas.clear(Color::TRANSPARENT);
+        canvas.translate((-bounds.min_x(), -bounds.min_y()));
+
+        for node in &nodes {
+            let (Some(node_ref), Some(layout_node)) =
+                (rdom.get(node.node_id), layout.get(node.node_id))
+            else {
+                continue;
+            };
+            render_node_with_effects(
+                canvas,
+                rdom,
+                layout,
+                bounds,
+                node_ref,
+                layout_node,
+                font_collection,
+                font_manager,
+                default_fonts,
+                images_cache,
+                scale_factor,
+                effects.clone(),
+            );
+        }
+
+        #[cfg(debug_assertions)]
+        tracing::info!(
+            "Rasterized {} nodes of the subtree of {root_id:?} into {bounds:?}",
+            nodes.len()
+        );
+
+        Some(Self {
+            image: raster_surface.image_snapshot(),
+            bounds: bounds.translate(-root_area.origin.to_vector()),
+            nodes,
+            scale_factor,
+        })
+    }
+
+    /// Check if this image still represents the given subtree nodes.
+    pub fn is_valid(&self, nodes: &[SubtreeNode], scale_factor: f32) -> bool {
+        self.scale_factor == scale_factor && self.nodes == nodes
+    }
+
+    /// Draw the image with the effects that the root inherits from its ancestors.
+    pub fn draw(
+        &self,
+        canvas: &Canvas,
+        rdom: &DioxusDOM,
+        layout: &Torin<NodeId>,
+        canvas_area: Area,
+        root_ref: &DioxusNode,
+        scale_factor: f32,
+    ) {
+        let Some(root_area) = layout
+            .get(root_ref.id())
+            .map(|layout_node| layout_node.area)
+        else {
+            return;
+        };
+        let effects: Range<InheritedEffects> =
+            InheritedEffects::NONE..InheritedEffects::from_ancestors(root_ref);
+
+        let initial_layer = canvas.save();
+        apply_inherited_effects(
+            canvas,
+            rdom,
+            layout,
+            canvas_area,
+            root_ref,
+            scale_factor,
+            effects,
+        );
+        // Snap to the pixel grid to keep the image sharp
+        canvas.draw_image(
+            &self.image,
+            (
+                (root_area.min_x() + self.bounds.min_x()).round(),
+                (root_area.min_y() + self.bounds.min_y()).round(),
+            ),
+            None,
+        );
+        canvas.restore_to_count(initial_layer);
+    }
+}
+
+/// Rasterized subtrees of the nodes with `cache: "raster"`, by their root.
+///
+/// An image is reused until any node of its subtree is invalidated or its layout changes relative to the root,
+/// so moving the whole subtree around, e.g. when scrolling, does not require painting it again.
+#[derive(Default)]
+pub struct RasterCache(FxHashMap<NodeId, RasterCacheEntry>);
+
+impl RasterCache {
+    pub fn get(&self, node_id: &NodeId) -> Option<&RasterCacheEntry> {
+        self.0.get(node_id)
+    }
+
+    pub fn insert(&mut self, node_id: NodeId, entry: RasterCacheEntry) {
+        self.0.insert(node_id, entry);
+    }
+
+    pub fn remove(&mut self, node_id: &NodeId) {
+        self.0.remove(node_id);
+    }
+
+    /// Drop all the images, e.g. when new fonts are registered.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Drop the images of the subtrees containing any of the invalidated nodes,
+    /// and of the nodes that are no longer a rasterized root.
+    pub fn invalidate(&mut self, invalidated: impl IntoIterator<Item = NodeId>, rdom: &DioxusDOM) {
+        if self.0.is_empty() {
+            return;
+        }
+
+        for node_id in invalidated {
+            self.0.remove(&node_id);
+            let cached_by = rdom
+                .get(node_id)
+                .and_then(|node| node.get::<CacheState>().and_then(|state| state.cached_by));
+            if let Some(cached_by) = cached_by {
+                self.0.remove(&cached_by);
+            }
+        }
+
+        self.0.retain(|node_id, _| {
+            rdom.get(*node_id)
+                .and_then(|node| {
+                    node.get::<CacheState>()
+                        .map(|state| state.cached_by == Some(*node_id))
+                })
+                .unwrap_or_default()
+        });
+    }
+
+    /// Replace the nodes inside rasterized subtrees with their root,
+    /// so they are painted all at once in the layer of the root.
+    pub fn group_layers<'a>(layers: &'a Layers, rdom: &DioxusDOM) -> Cow<'a, Layers> {
+        let cached_by = |node_id: &NodeId| {
+            rdom.get(*node_id)
+                .and_then(|node| node.get::<CacheState>().and_then(|state| state.cached_by))
+        };
+
+        let any_cached = layers
+            .values()
+            .flatten()
+            .any(|node_id| cached_by(node_id).is_some());
+        if !any_cached {
+            return Cow::Borrowed(layers);
+        }
+
+        let mut grouped = Layers::default();
+        let mut roots = FxHashSet::default();
+        for (layer, nodes) in layers.iter() {
+            for node_id in nodes {
+                let (node_id, layer) = match cached_by(node_id) {
+                    Some(root_id) => {
+                        if !roots.insert(root_id) {
+                            continue;
+                        }
+                        let Some(root_layer) = rdom
+                            .get(root_id)
+                            .and_then(|root| root.get::<LayerState>().map(|state| state.layer))
+                        else {
+                            continue;
+                        };
+                        (root_id, root_layer)
+                    }
+                    None => (*node_id, *layer),
+                };
+                grouped.entry(layer).or_default().push(node_id);
+            }
+        }
+        Cow::Owned(grouped)
+    }
+}
@@ -23,11 +23,17 @@ use super::{
     create_paragraph,
     get_or_create_image,
     ImageData,
+    ParagraphCache,
+    ParagraphCacheKey,
 };
 use crate::{
     custom_attributes::NodeReferenceLayout,
     dom::*,
-    elements::CachedParagraph,
+    elements::{
+        CachedParagraph,
+        CachedStrokeParagraph,
+    },
+    fonts::ResolvedFonts,
     render::ParagraphData,
     states::LayoutState,
 };
@@ -36,18 +42,20 @@ use crate::{
 pub struct SkiaMeasurer<'a> {
     pub font_collection: &'a FontCollection,
     pub rdom: &'a DioxusDOM,
-    pub default_fonts: &'a [String],
+    pub default_fonts: &'a ResolvedFonts,
     pub scale_factor: f32,
     pub images_cache: &'a mut ImagesCache,
+    pub paragraph_cache: &'a mut ParagraphCache,
 }
 
 impl<'a> SkiaMeasurer<'a> {
     pub fn new(
         rdom: &'a DioxusDOM,
         font_collection: &'a FontCollection,
-        default_fonts: &'a [String],
+        default_fonts: &'a ResolvedFonts,
         scale_factor: f32,
         images_cache: &'a mut ImagesCache,
+        paragraph_cache: &'a mut ParagraphCache,
     ) -> Self {
         Self {
             font_collection,
@@ -55,6 +63,7 @@ impl<'a> SkiaMeasurer<'a> {
             default_fonts,
             scale_factor,
             images_cache,
+            paragraph_cache,
         }
     }
 }
@@ -70,31 +79,48 @@ impl<'a> LayoutMeasurer<NodeId> for SkiaMeasurer<'a> {
         let node_type = node.node_type();
 
         match &*node_type {
-            NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Label => {
-                let ParagraphData { paragraph, size } = create_label(
-                    &node,
-                    torin_node,
-                    area_size,
-                    self.font_collection,
-                    self.default_fonts,
-                    self.scale_factor,
-                );
-                let mut map = SendAnyMap::new();
-                map.insert(CachedParagraph(paragraph));
-                Some((size, Arc::new(map)))
-            }
-            NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Paragraph => {
-                let ParagraphData { paragraph, size } = create_paragraph(
-                    &node,
-                    area_size,
-                    self.font_collection,
-                    false,
-                    self.default_fonts,
-                    self.scale_factor,
-                );
+            NodeType::Element(ElementNode { tag, .. })
+                if tag == &TagName::Label || tag == &TagName::Paragraph =>
+            {
+                let key =
+                    ParagraphCacheKey::from_node(&node, torin_node.width.inner_sized(), area_size);
+                if let Some(cached) = key.as_ref().and_then(|key| self.paragraph_cache.get(key)) {
+                    return Some(cached);
+                }
+
+                let ParagraphData {
+                    paragraph,
+                    size,
+                    stroke,
+                } = if tag == &TagName::Label {
+                    create_label(
+                        &node,
+                        torin_node,
+                        area_size,
+                        self.font_collection,
+                        self.default_fonts,
+                        self.scale_factor,
+                    )
+                } else {
+                    create_paragraph(
+                        &node,
+                        area_size,
+                        self.font_collection,
+                        false,
+                        self.default_fonts,
+                        self.scale_factor,
+                    )
+                };
                 let mut map = SendAnyMap::new();
                 map.insert(CachedParagraph(paragraph));
-                Some((size, Arc::new(map)))
+                if let Some(stroke) = stroke {
+                    map.insert(CachedStrokeParagraph(stroke));
+                }
+                let data = Arc::new(map);
+                if let Some(key) = key {
+                    self.paragraph_cache.insert(key, size, data.clone());
+                }
+                Some((size, data))
             }
             NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Image => {
                 let Some(ImageData { size, .. }) =
@@ -128,13 +154,20 @@ impl<'a> LayoutMeasurer<NodeId> for SkiaMeasurer<'a> {
             .unwrap_or_default()
     }
 
-    fn notify_layout_references(&self, node_id: NodeId, area: Area, inner_sizes: Size2D) {
+    fn notify_layout_references(
+        &self,
+        node_id: NodeId,
+        area: Area,
+        parent_area: Area,
+        inner_sizes: Size2D,
+    ) {
         let node = self.rdom.get(node_id).unwrap();
         let size_state = &*node.get::<LayoutState>().unwrap();
 
         if let Some(reference) = &size_state.node_ref {
             let mut node_layout = NodeReferenceLayout {
                 area,
+                local_area: area.translate(-parent_area.origin.to_vector()),
                 inner: inner_sizes,
             };
             node_layout.div(self.scale_factor);
@@ -0,0 +1,137 @@
+use freya_engine::prelude::{
+    raster_n32_premul,
+    Canvas,
+    Color,
+    Data,
+    EncodedImageFormat,
+    FontCollection,
+    FontMgr,
+};
+use freya_native_core::{
+    real_dom::NodeImmutable,
+    tree::TreeRef,
+    NodeId,
+};
+use itertools::sorted;
+use rustc_hash::FxHashSet;
+use torin::prelude::Area;
+
+use super::render_node;
+use crate::{
+    custom_attributes::NodeReference,
+    dom::FreyaDOM,
+    fonts::ResolvedFonts,
+    states::LayoutState,
+};
+
+/// Find the [NodeId] of the node that has the given [NodeReference] attached.
+pub fn find_node_by_reference(fdom: &FreyaDOM, reference: &NodeReference) -> Option<NodeId> {
+    let mut found = None;
+    fdom.rdom().traverse_depth_first_advanced(|node| {
+        if found.is_some() {
+            return false;
+        }
+        if let Some(layout) = node.get::<LayoutState>() {
+            if layout.node_ref.as_ref() == Some(reference) {
+                found = Some(node.id());
+            }
+        }
+        true
+    });
+    found
+}
+
+/// Rasterize the given node and all its descendants into a PNG image.
+///
+/// The output size is the logical size of the node multiplied by `scale`,
+/// so a `scale` of `2.0` produces an image twice as big as the node in logical pixels.
+pub fn snapshot_node(
+    fdom: &FreyaDOM,
+    node_id: NodeId,
+    scale: f32,
+    scale_factor: f32,
+    font_collection: &mut FontCollection,
+    font_manager: &FontMgr,
+    default_fonts: &ResolvedFonts,
+) -> Option<Data> {
+    let area = fdom.layout().get(node_id)?.visible_area();
+    let ratio = scale / scale_factor;
+    let width = (area.width() * ratio).ceil() as i32;
+    let height = (area.height() * ratio).ceil() as i32;
+    if width <= 0 || height <= 0 {
+        return None;
+    }
+
+    let mut surface = raster_n32_premul((width, height))?;
+    let canvas = surface.canvas();
+    canvas.clear(Color::TRANSPARENT);
+    canvas.scale((ratio, ratio));
+    canvas.translate((-area.min_x(), -area.min_y()));
+
+    render_subtree(
+        canvas,
+        fdom,
+        node_id,
+        area,
+        scale_factor,
+        font_collection,
+        font_manager,
+        default_fonts,
+    );
+
+    let image = surface.image_snapshot();
+    let mut context = surface.direct_context();
+    image.encode(context.as_mut(), EncodedImageFormat::PNG, None)
+}
+
+/// Render the given node and all its descendants into the canvas, in the order of their layers.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn render_subtree(
+    canvas: &Canvas,
+    fdom: &FreyaDOM,
+    node_id: NodeId,
+    area: Area,
+    scale_factor: f32,
+    font_collection: &mut FontCollection,
+    font_manager: &FontMgr,
+    default_fonts: &ResolvedFonts,
+) {
+    let rdom = fdom.rdom();
+    let layout = fdom.layout();
+    let layers = fdom.layers();
+    let mut images_cache = fdom.images_cache();
+
+    // Collect the subtree of the node
+    let mut subtree = FxHashSet::default();
+    let mut stack = vec![node_id];
+    let tree = rdom.tree_ref();
+    while let Some(id) = stack.pop() {
+        subtree.insert(id);
+        stack.extend(tree.children_ids(id));
+    }
+
+    for (_, nodes) in sorted(layers.iter()) {
+        for node_id in sorted(nodes) {
+            if !subtree.contains(node_id) {
+                continue;
+            }
+            let Some(layout_node) = layout.get(*node_id) else {
+                continue;
+            };
+            let node_ref = rdom.get(*node_id).unwrap();
+            render_node(
+                canvas,
+                rdom,
+                &layout,
+                area,
+                node_ref,
+                layout_node,
+                font_collection,
+                font_manager,
+                default_fonts,
+                &mut images_cache,
+                scale_factor,
+            );
+        }
+    }
+}
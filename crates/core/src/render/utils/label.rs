@@ -8,9 +8,13 @@ use torin::{
     prelude::Size2D,
 };
 
-use super::ParagraphData;
+use super::{
+    paragraph::add_text_runs,
+    ParagraphData,
+};
 use crate::{
     dom::*,
+    fonts::ResolvedFonts,
     states::FontStyleState,
 };
 
@@ -19,54 +23,83 @@ pub fn create_label(
     torin_node: &Node,
     area_size: &Size2D,
     font_collection: &FontCollection,
-    default_font_family: &[String],
+    default_font_family: &ResolvedFonts,
     scale_factor: f32,
 ) -> ParagraphData {
+    let _span = crate::profile_span!("shape_label", node = ?node.id());
     let font_style = &*node.get::<FontStyleState>().unwrap();
 
-    let mut paragraph_style = ParagraphStyle::default();
-    paragraph_style.set_text_align(font_style.text_align);
-    paragraph_style.set_max_lines(font_style.max_lines);
-    paragraph_style.set_replace_tab_characters(true);
-    paragraph_style.set_text_height_behavior(font_style.text_height);
+    let mut paragraph = build_label(
+        node,
+        font_style,
+        font_collection,
+        default_font_family,
+        scale_factor,
+        false,
+    );
+    paragraph.layout(if font_style.is_unbounded_single_line() {
+        f32::MAX
+    } else {
+        area_size.width + 1.0
+    });
+
+    // Relayout the paragraph so that its aligned based on its longest width
+    if !font_style.is_left_aligned() && torin_node.width.inner_sized() {
+        paragraph.layout(paragraph.longest_line() + 1.);
+    }
+
+    // Lay out the stroke with the same width the text ended up using
+    let stroke = font_style.text_stroke.map(|_| {
+        let mut stroke = build_label(
+            node,
+            font_style,
+            font_collection,
+            default_font_family,
+            scale_factor,
+            true,
+        );
+        stroke.layout(paragraph.max_width());
+        stroke
+    });
 
-    if let Some(ellipsis) = font_style.text_overflow.get_ellipsis() {
-        paragraph_style.set_ellipsis(ellipsis);
+    ParagraphData {
+        size: Size2D::new(paragraph.longest_line(), paragraph.height()),
+        paragraph,
+        stroke,
     }
+}
+
+/// Build the text of a `label`, or only its `text_stroke` if `stroke` is `true`.
+fn build_label(
+    node: &DioxusNode,
+    font_style: &FontStyleState,
+    font_collection: &FontCollection,
+    default_font_family: &ResolvedFonts,
+    scale_factor: f32,
+    stroke: bool,
+) -> Paragraph {
+    let mut paragraph_style = font_style.paragraph_style();
 
-    let text_style =
-        font_style.text_style(default_font_family, scale_factor, font_style.text_height);
+    let text_style = if stroke {
+        font_style.stroke_text_style(default_font_family, scale_factor, font_style.text_height)
+    } else {
+        font_style.text_style(default_font_family, scale_factor, font_style.text_height)
+    };
     paragraph_style.set_text_style(&text_style);
 
     let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
 
     for child in node.children() {
         if let NodeType::Text(text) = &*child.node_type() {
-            paragraph_builder.add_text(text);
-        }
-    }
-
-    let mut paragraph = paragraph_builder.build();
-    paragraph.layout(
-        if font_style.max_lines == Some(1) && font_style.text_align == TextAlign::default() {
-            f32::MAX
-        } else {
-            area_size.width + 1.0
-        },
-    );
-
-    // Relayout the paragraph so that its aligned based on its longest width
-    match font_style.text_align {
-        TextAlign::Center | TextAlign::Justify | TextAlign::Right | TextAlign::End
-            if torin_node.width.inner_sized() =>
-        {
-            paragraph.layout(paragraph.longest_line() + 1.);
+            add_text_runs(&mut paragraph_builder, text, default_font_family, |fonts| {
+                if stroke {
+                    font_style.stroke_text_style(fonts, scale_factor, font_style.text_height)
+                } else {
+                    font_style.text_style(fonts, scale_factor, font_style.text_height)
+                }
+            });
         }
-        _ => {}
     }
 
-    ParagraphData {
-        size: Size2D::new(paragraph.longest_line(), paragraph.height()),
-        paragraph,
-    }
+    paragraph_builder.build()
 }
@@ -13,17 +13,26 @@ use torin::prelude::{
 
 use crate::{
     dom::DioxusNode,
+    events::TextSelectionHighlight,
+    fonts::ResolvedFonts,
     states::{
         CursorState,
         FontStyleState,
         LayoutState,
+        StyleState,
+    },
+    values::{
+        Fill,
+        HighlightMode,
+        TextOverflow,
     },
-    values::HighlightMode,
 };
 
 pub struct ParagraphData {
     pub paragraph: Paragraph,
     pub size: Size2D,
+    /// Paragraph only drawing the `text_stroke` of the text, if any.
+    pub stroke: Option<Paragraph>,
 }
 
 /// Compose a new SkParagraph
@@ -32,25 +41,66 @@ pub fn create_paragraph(
     area_size: &Size2D,
     font_collection: &FontCollection,
     is_rendering: bool,
-    default_font_family: &[String],
+    default_font_family: &ResolvedFonts,
     scale_factor: f32,
 ) -> ParagraphData {
+    let _span = crate::profile_span!("shape_paragraph", node = ?node.id());
     let font_style = &*node.get::<FontStyleState>().unwrap();
 
-    let mut paragraph_style = ParagraphStyle::default();
-    paragraph_style.set_text_align(font_style.text_align);
-    paragraph_style.set_max_lines(font_style.max_lines);
-    paragraph_style.set_replace_tab_characters(true);
-    paragraph_style.set_text_height_behavior(font_style.text_height);
+    let layout_width = if font_style.is_unbounded_single_line() {
+        f32::MAX
+    } else {
+        area_size.width + 1.0
+    };
+
+    let build = |stroke: bool| {
+        let mut paragraph = build_paragraph(
+            node,
+            font_collection,
+            is_rendering,
+            default_font_family,
+            scale_factor,
+            stroke,
+        );
+        paragraph.layout(layout_width);
+        paragraph
+    };
 
-    if let Some(ellipsis) = font_style.text_overflow.get_ellipsis() {
-        paragraph_style.set_ellipsis(ellipsis);
+    let paragraph = build(false);
+
+    let width = if font_style.is_left_aligned() {
+        paragraph.longest_line()
+    } else {
+        paragraph.max_width()
+    };
+
+    ParagraphData {
+        size: Size2D::new(width, paragraph.height()),
+        paragraph,
+        stroke: has_text_stroke(node).then(|| build(true)),
     }
+}
+
+/// Build the text of a `paragraph`, or only its `text_stroke` if `stroke` is `true`.
+fn build_paragraph(
+    node: &DioxusNode,
+    font_collection: &FontCollection,
+    is_rendering: bool,
+    default_font_family: &ResolvedFonts,
+    scale_factor: f32,
+    stroke: bool,
+) -> Paragraph {
+    let font_style = &*node.get::<FontStyleState>().unwrap();
+
+    let paragraph_style = font_style.paragraph_style();
 
     let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
 
-    let text_style =
-        font_style.text_style(default_font_family, scale_factor, font_style.text_height);
+    let text_style = if stroke {
+        font_style.stroke_text_style(default_font_family, scale_factor, font_style.text_height)
+    } else {
+        font_style.text_style(default_font_family, scale_factor, font_style.text_height)
+    };
     paragraph_builder.push_style(&text_style);
 
     for text_span in node.children() {
@@ -62,15 +112,38 @@ pub fn create_paragraph(
             let text_node = *text_nodes.first().unwrap();
             let text_node_type = &*text_node.node_type();
             let text_font_style = text_span.get::<FontStyleState>().unwrap();
-            let text_style = text_font_style.text_style(
-                default_font_family,
-                scale_factor,
-                font_style.text_height,
-            );
-            paragraph_builder.push_style(&text_style);
+            let background = text_span.get::<StyleState>().unwrap().background;
+
+            let text_style = |fonts: &[String]| {
+                if stroke {
+                    text_font_style.stroke_text_style(fonts, scale_factor, font_style.text_height)
+                } else {
+                    let mut text_style =
+                        text_font_style.text_style(fonts, scale_factor, font_style.text_height);
+
+                    // Draw the background of the span if any
+                    if let Fill::Color(background) = background {
+                        if background != Color::TRANSPARENT {
+                            let mut paint = Paint::default();
+                            paint.set_anti_alias(true);
+                            paint.set_color(background);
+                            text_style.set_background_paint(&paint);
+                        }
+                    }
+
+                    text_style
+                }
+            };
 
             if let NodeType::Text(text) = text_node_type {
-                paragraph_builder.add_text(text);
+                add_text_runs(
+                    &mut paragraph_builder,
+                    text,
+                    default_font_family,
+                    text_style,
+                );
+            } else {
+                paragraph_builder.push_style(&text_style(default_font_family));
             }
         }
     }
@@ -80,26 +153,39 @@ pub fn create_paragraph(
         paragraph_builder.add_text(" ");
     }
 
-    let mut paragraph = paragraph_builder.build();
-    paragraph.layout(
-        if font_style.max_lines == Some(1) && font_style.text_align == TextAlign::default() {
-            f32::MAX
-        } else {
-            area_size.width + 1.0
-        },
-    );
-
-    let width = match font_style.text_align {
-        TextAlign::Start | TextAlign::Left => paragraph.longest_line(),
-        _ => paragraph.max_width(),
-    };
+    paragraph_builder.build()
+}
 
-    ParagraphData {
-        size: Size2D::new(width, paragraph.height()),
-        paragraph,
+/// Add a text in runs of the same script, each one with the fallback fonts of its script.
+pub(crate) fn add_text_runs(
+    paragraph_builder: &mut ParagraphBuilder,
+    text: &str,
+    default_fonts: &ResolvedFonts,
+    text_style: impl Fn(&[String]) -> TextStyle,
+) {
+    for (range, fonts) in default_fonts.runs(text) {
+        paragraph_builder.push_style(&text_style(fonts));
+        paragraph_builder.add_text(&text[range]);
     }
 }
 
+/// Check whether a `label` or `paragraph`, or any of its `text` spans, has a `text_stroke`.
+fn has_text_stroke(node: &DioxusNode) -> bool {
+    let has_stroke =
+        |node: &DioxusNode| node.get::<FontStyleState>().unwrap().text_stroke.is_some();
+
+    has_stroke(node)
+        || node.children().iter().any(|child| {
+            matches!(
+                &*child.node_type(),
+                NodeType::Element(ElementNode {
+                    tag: TagName::Text,
+                    ..
+                })
+            ) && has_stroke(child)
+        })
+}
+
 pub fn draw_cursor_highlights(
     area: &Area,
     paragraph: &Paragraph,
@@ -145,38 +231,263 @@ pub fn draw_cursor_highlights(
     Some(())
 }
 
-pub fn draw_cursor(
+/// Check whether the text of a paragraph doesn't fit in the given area or was clamped with `max_lines`.
+pub fn paragraph_overflows(paragraph: &Paragraph, area: &Area) -> bool {
+    paragraph.did_exceed_max_lines()
+        || paragraph.longest_line() > area.width() + 1.0
+        || paragraph.height() > area.height() + 1.0
+}
+
+/// Paint a paragraph on top of its stroke paragraph if any,
+/// fading out the end of its last visible line if it overflows and `text_overflow` is `fade`.
+pub fn paint_paragraph(
     area: &Area,
     paragraph: &Paragraph,
+    stroke: Option<&Paragraph>,
     canvas: &Canvas,
     node_ref: &DioxusNode,
-) -> Option<()> {
-    let node_cursor_state = &*node_ref.get::<CursorState>().unwrap();
+    (x, y): (f32, f32),
+) {
+    let font_style = node_ref.get::<FontStyleState>().unwrap();
+    let fade_rect =
+        if font_style.text_overflow == TextOverflow::Fade && paragraph_overflows(paragraph, area) {
+            get_fade_rect(area, paragraph, (x, y))
+        } else {
+            None
+        };
 
-    let cursor = node_cursor_state.position?;
-    let cursor_color = node_cursor_state.color;
-    let cursor_position = cursor as usize;
+    let paint = || {
+        if let Some(stroke) = stroke {
+            stroke.paint(canvas, (x, y));
+        }
+        paragraph.paint(canvas, (x, y));
+    };
 
-    let cursor_rects = paragraph.get_rects_for_range(
-        cursor_position..cursor_position + 1,
-        RectHeightStyle::Tight,
-        RectWidthStyle::Tight,
+    let Some(fade_rect) = fade_rect else {
+        paint();
+        return;
+    };
+
+    canvas.save_layer_alpha_f(
+        Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+        1.0,
     );
-    let cursor_rect = cursor_rects.first()?;
 
-    let rect =
-        align_highlights_and_cursor_paragraph(node_ref, area, paragraph, cursor_rect, Some(1.0));
+    paint();
+
+    // Only keep the text under the opaque part of the gradient
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_blend_mode(BlendMode::DstIn);
+    paint.set_shader(Shader::linear_gradient(
+        (
+            Point::new(fade_rect.left, fade_rect.top),
+            Point::new(fade_rect.right, fade_rect.top),
+        ),
+        GradientShaderColors::Colors(&[Color::BLACK, Color::TRANSPARENT]),
+        None,
+        TileMode::Clamp,
+        None,
+        None,
+    ));
+    canvas.draw_rect(fade_rect, &paint);
+
+    canvas.restore();
+}
+
+/// Get the area to fade out at the end of the last visible line of a paragraph.
+fn get_fade_rect(area: &Area, paragraph: &Paragraph, (x, y): (f32, f32)) -> Option<Rect> {
+    let lines = paragraph.get_line_metrics();
+    let line = lines
+        .iter()
+        .rev()
+        .find(|line| y + (line.baseline + line.descent) as f32 <= area.max_y() + 1.0)
+        .or(lines.first())?;
+
+    let top = y + (line.baseline - line.ascent) as f32;
+    let bottom = y + (line.baseline + line.descent) as f32;
+    let right = (x + (line.left + line.width) as f32).min(area.max_x());
+    let fade_width = ((bottom - top) * 2.0).min(right - area.min_x());
+
+    Some(Rect::new(right - fade_width, top, right, bottom))
+}
+
+/// Draw the ranges of the `match_highlights` attribute, if any.
+pub fn draw_match_highlights(
+    area: &Area,
+    paragraph: &Paragraph,
+    canvas: &Canvas,
+    node_ref: &DioxusNode,
+) -> Option<()> {
+    let node_cursor_state = &*node_ref.get::<CursorState>().unwrap();
+
+    if node_cursor_state.match_highlights.is_empty() {
+        return None;
+    }
 
     let mut paint = Paint::default();
     paint.set_anti_alias(true);
     paint.set_style(PaintStyle::Fill);
-    paint.set_color(cursor_color);
+    paint.set_color(node_cursor_state.match_highlight_color);
+
+    for (from, to) in node_cursor_state.match_highlights.iter() {
+        let match_rects = paragraph.get_rects_for_range(
+            *from..*to,
+            RectHeightStyle::Tight,
+            RectWidthStyle::Tight,
+        );
+        for match_rect in match_rects {
+            let rect =
+                align_highlights_and_cursor_paragraph(node_ref, area, paragraph, &match_rect, None);
+            canvas.draw_rect(rect, &paint);
+        }
+    }
 
-    canvas.draw_rect(rect, &paint);
+    Some(())
+}
+
+/// Draw a wavy underline below the ranges of the `spelling_errors` attribute, if any.
+pub fn draw_spelling_errors(
+    area: &Area,
+    paragraph: &Paragraph,
+    canvas: &Canvas,
+    node_ref: &DioxusNode,
+) -> Option<()> {
+    let node_cursor_state = &*node_ref.get::<CursorState>().unwrap();
+
+    if node_cursor_state.spelling_errors.is_empty() {
+        return None;
+    }
+
+    // Height and length of every wave
+    const AMPLITUDE: f32 = 1.5;
+    const WAVELENGTH: f32 = 4.0;
+
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_style(PaintStyle::Stroke);
+    paint.set_stroke_width(1.0);
+    paint.set_color(node_cursor_state.spelling_error_color);
+
+    let top = area.min_y() + align_main_align_paragraph(node_ref, area, paragraph);
+
+    for (from, to) in node_cursor_state.spelling_errors.iter() {
+        let error_rects = paragraph.get_rects_for_range(
+            *from..*to,
+            RectHeightStyle::Tight,
+            RectWidthStyle::Tight,
+        );
+        for error_rect in error_rects {
+            let left = area.min_x() + error_rect.rect.left;
+            let right = area.min_x() + error_rect.rect.right;
+            let y = top + error_rect.rect.bottom - AMPLITUDE;
+
+            let mut path = Path::new();
+            path.move_to((left, y));
+            let mut x = left;
+            let mut up = true;
+            while x < right {
+                x = (x + WAVELENGTH / 2.).min(right);
+                let offset = if up { -AMPLITUDE } else { AMPLITUDE };
+                path.line_to((x, y + offset));
+                up = !up;
+            }
+            canvas.draw_path(&path, &paint);
+        }
+    }
 
     Some(())
 }
 
+/// Draw the range selected with the `text_selection` attribute, if any.
+pub fn draw_text_selection(
+    area: &Area,
+    paragraph: &Paragraph,
+    canvas: &Canvas,
+    node_ref: &DioxusNode,
+) -> Option<()> {
+    let TextSelectionHighlight(Some((from, to))) = *node_ref.get::<TextSelectionHighlight>()?
+    else {
+        return None;
+    };
+    let highlight_color = node_ref.get::<CursorState>().unwrap().highlight_color;
+
+    let selection_rects =
+        paragraph.get_rects_for_range(from..to, RectHeightStyle::Tight, RectWidthStyle::Tight);
+    for selection_rect in selection_rects {
+        let rect =
+            align_highlights_and_cursor_paragraph(node_ref, area, paragraph, &selection_rect, None);
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Fill);
+        paint.set_color(highlight_color);
+
+        canvas.draw_rect(rect, &paint);
+    }
+
+    Some(())
+}
+
+pub fn draw_cursor(
+    area: &Area,
+    paragraph: &Paragraph,
+    canvas: &Canvas,
+    node_ref: &DioxusNode,
+) -> Option<()> {
+    let cursor_state = node_ref.get::<CursorState>().unwrap();
+    let cursor_position = cursor_state.position?;
+
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_style(PaintStyle::Fill);
+    paint.set_color(cursor_state.color);
+
+    // The additional carets are only drawn along with the main cursor
+    for position in cursor_state
+        .carets
+        .iter()
+        .copied()
+        .chain([cursor_position as usize])
+    {
+        if let Some(rect) = get_caret_rect(area, paragraph, node_ref, position) {
+            canvas.draw_rect(rect, &paint);
+        }
+    }
+
+    Some(())
+}
+
+/// Get the rect where the cursor of a paragraph is drawn, if it has one.
+pub fn get_cursor_rect(area: &Area, paragraph: &Paragraph, node_ref: &DioxusNode) -> Option<Rect> {
+    let cursor = node_ref.get::<CursorState>().unwrap().position?;
+
+    get_caret_rect(area, paragraph, node_ref, cursor as usize)
+}
+
+/// Get the rect where a cursor in the given position of a paragraph is drawn.
+fn get_caret_rect(
+    area: &Area,
+    paragraph: &Paragraph,
+    node_ref: &DioxusNode,
+    cursor_position: usize,
+) -> Option<Rect> {
+    let cursor_rects = paragraph.get_rects_for_range(
+        cursor_position..cursor_position + 1,
+        RectHeightStyle::Tight,
+        RectWidthStyle::Tight,
+    );
+    let cursor_rect = cursor_rects.first()?;
+
+    Some(align_highlights_and_cursor_paragraph(
+        node_ref,
+        area,
+        paragraph,
+        cursor_rect,
+        Some(1.0),
+    ))
+}
+
 /// Align the Y axis of the highlights and cursor of a paragraph
 pub fn align_highlights_and_cursor_paragraph(
     node: &DioxusNode,
@@ -49,6 +49,7 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    states::DesignTokensState,
     values::Focusable,
 };
 
@@ -88,6 +89,13 @@ impl ParseAttribute for AccessibilityNodeState {
                     self.a11y_auto_focus = attr.parse().unwrap_or_default()
                 }
             }
+            AttributeName::Link => {
+                if let OwnedAttributeValue::Text(link) = attr.value {
+                    if let Some(builder) = self.builder.as_mut() {
+                        builder.set_url(link.clone());
+                    }
+                }
+            }
             AttributeName::A11yMemberOf => {
                 if let OwnedAttributeValue::Custom(CustomAttributeValues::AccessibilityId(id)) =
                     attr.value
@@ -330,7 +338,7 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (DesignTokensState,);
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
         .with_attrs(AttributeMaskBuilder::Some(&[
@@ -403,13 +411,14 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
             AttributeName::A11yHasPopup,
             AttributeName::A11yListStyle,
             AttributeName::A11yVerticalOffset,
+            AttributeName::Link,
         ]))
         .with_tag();
 
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
@@ -430,16 +439,23 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
                     TagName::Rect => Some(NodeBuilder::new(Role::GenericContainer)),
                     TagName::Svg => Some(NodeBuilder::new(Role::GraphicsObject)),
                     TagName::Root => Some(NodeBuilder::new(Role::Window)),
+                    // Only the links are exposed for now
                     // TODO: make this InlineTextBox and supply computed text span properties
-                    TagName::Text => None,
+                    TagName::Text => node_view
+                        .attributes()
+                        .into_iter()
+                        .flatten()
+                        .any(|attr| attr.attribute == AttributeName::Link)
+                        .then(|| NodeBuilder::new(Role::Link)),
                 }
             }),
             ..Default::default()
         };
 
+        let (DesignTokensState { design_tokens },) = node;
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
-                accessibility.parse_safe(attr);
+                accessibility.parse_with_tokens(attr, design_tokens);
             }
         }
 
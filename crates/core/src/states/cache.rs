@@ -0,0 +1,122 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use freya_native_core::{
+    attributes::AttributeName,
+    exports::shipyard::Component,
+    node_ref::NodeView,
+    prelude::{
+        AttributeMaskBuilder,
+        Dependancy,
+        NodeMaskBuilder,
+        State,
+    },
+    NodeId,
+    SendAnyMap,
+};
+use freya_native_core_macro::partial_derive_state;
+
+use crate::{
+    custom_attributes::CustomAttributeValues,
+    dom::CompositorDirtyNodes,
+    parsing::{
+        Parse,
+        ParseAttribute,
+        ParseError,
+    },
+    states::DesignTokensState,
+    values::CacheMode,
+};
+
+#[derive(Default, PartialEq, Clone, Debug, Component)]
+pub struct CacheState {
+    pub node_id: NodeId,
+    pub mode: CacheMode,
+    /// The outermost node, including this one, whose subtree is rasterized.
+    pub cached_by: Option<NodeId>,
+}
+
+impl ParseAttribute for CacheState {
+    fn parse_attribute(
+        &mut self,
+        attr: freya_native_core::prelude::OwnedAttributeView<CustomAttributeValues>,
+    ) -> Result<(), ParseError> {
+        #[allow(clippy::single_match)]
+        match attr.attribute {
+            AttributeName::Cache => {
+                if let Some(value) = attr.value.as_text() {
+                    self.mode = CacheMode::parse(value)?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for CacheState {
+    type ParentDependencies = (Self,);
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = (DesignTokensState,);
+
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
+        .with_attrs(AttributeMaskBuilder::Some(&[AttributeName::Cache]))
+        .with_tag();
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        context: &SendAnyMap,
+    ) -> bool {
+        if !node_view.node_type().is_visible_element() {
+            return false;
+        }
+
+        let root_id = context.get::<NodeId>().unwrap();
+        let compositor_dirty_nodes = context.get::<Arc<Mutex<CompositorDirtyNodes>>>().unwrap();
+
+        let mut cache_state = CacheState {
+            node_id: node_view.node_id(),
+            ..Default::default()
+        };
+
+        let (DesignTokensState { design_tokens },) = node;
+        if let Some(attributes) = node_view.attributes() {
+            for attr in attributes {
+                cache_state.parse_with_tokens(attr, design_tokens);
+            }
+        }
+
+        cache_state.cached_by =
+            parent
+                .and_then(|(p,)| p.cached_by)
+                .or(if cache_state.mode == CacheMode::Raster {
+                    Some(cache_state.node_id)
+                } else {
+                    None
+                });
+
+        let changed = cache_state != *self;
+
+        let is_orphan = node_view.height() == 0 && node_view.node_id() != *root_id;
+
+        if changed && !is_orphan {
+            compositor_dirty_nodes
+                .lock()
+                .unwrap()
+                .invalidate(node_view.node_id());
+        }
+
+        *self = cache_state;
+        changed
+    }
+}
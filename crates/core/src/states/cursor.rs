@@ -39,6 +39,7 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    states::DesignTokensState,
     values::{
         CursorMode,
         HighlightMode,
@@ -52,7 +53,12 @@ pub struct CursorState {
     pub mode: CursorMode,
     pub cursor_id: Option<usize>,
     pub highlights: Option<Vec<(usize, usize)>>,
+    pub carets: Vec<usize>,
+    pub match_highlights: Vec<(usize, usize)>,
     pub highlight_color: Color,
+    pub match_highlight_color: Color,
+    pub spelling_errors: Vec<(usize, usize)>,
+    pub spelling_error_color: Color,
     pub highlight_mode: HighlightMode,
     pub cursor_ref: Option<CursorReference>,
 }
@@ -65,7 +71,12 @@ impl Default for CursorState {
             mode: CursorMode::None,
             cursor_id: None,
             highlights: None,
+            carets: Vec::new(),
+            match_highlights: Vec::new(),
             highlight_color: Color::from_rgb(87, 108, 188),
+            match_highlight_color: Color::from_argb(120, 234, 179, 8),
+            spelling_errors: Vec::new(),
+            spelling_error_color: Color::from_rgb(220, 38, 38),
             highlight_mode: HighlightMode::default(),
             cursor_ref: None,
         }
@@ -107,11 +118,39 @@ impl ParseAttribute for CursorState {
                     self.highlights = Some(highlights.clone());
                 }
             }
+            AttributeName::Carets => {
+                if let Some(CustomAttributeValues::TextCarets(carets)) = attr.value.as_custom() {
+                    self.carets.clone_from(carets);
+                }
+            }
+            AttributeName::MatchHighlights => {
+                if let Some(CustomAttributeValues::TextHighlights(highlights)) =
+                    attr.value.as_custom()
+                {
+                    self.match_highlights.clone_from(highlights);
+                }
+            }
             AttributeName::HighlightColor => {
                 if let Some(value) = attr.value.as_text() {
                     self.highlight_color = Color::parse(value)?;
                 }
             }
+            AttributeName::MatchHighlightColor => {
+                if let Some(value) = attr.value.as_text() {
+                    self.match_highlight_color = Color::parse(value)?;
+                }
+            }
+            AttributeName::SpellingErrors => {
+                if let Some(CustomAttributeValues::TextHighlights(errors)) = attr.value.as_custom()
+                {
+                    self.spelling_errors.clone_from(errors);
+                }
+            }
+            AttributeName::SpellingErrorColor => {
+                if let Some(value) = attr.value.as_text() {
+                    self.spelling_error_color = Color::parse(value)?;
+                }
+            }
             AttributeName::HighlightMode => {
                 if let Some(value) = attr.value.as_text() {
                     self.highlight_mode = HighlightMode::parse(value)?;
@@ -138,7 +177,7 @@ impl State<CustomAttributeValues> for CursorState {
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (DesignTokensState,);
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
         .with_attrs(AttributeMaskBuilder::Some(&[
@@ -147,7 +186,12 @@ impl State<CustomAttributeValues> for CursorState {
             AttributeName::CursorMode,
             AttributeName::CursorId,
             AttributeName::Highlights,
+            AttributeName::Carets,
+            AttributeName::MatchHighlights,
             AttributeName::HighlightColor,
+            AttributeName::MatchHighlightColor,
+            AttributeName::SpellingErrors,
+            AttributeName::SpellingErrorColor,
             AttributeName::HighlightMode,
             AttributeName::CursorReference,
         ]))
@@ -160,7 +204,7 @@ impl State<CustomAttributeValues> for CursorState {
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
@@ -170,9 +214,10 @@ impl State<CustomAttributeValues> for CursorState {
         let compositor_dirty_nodes = context.get::<Arc<Mutex<CompositorDirtyNodes>>>().unwrap();
         let mut cursor = parent.map(|(p,)| p.clone()).unwrap_or_default();
 
+        let (DesignTokensState { design_tokens },) = node;
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
-                cursor.parse_safe(attr);
+                cursor.parse_with_tokens(attr, design_tokens);
             }
         }
         let changed = &cursor != self;
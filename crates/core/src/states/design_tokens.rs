@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use freya_native_core::{
+    attributes::AttributeName,
+    exports::shipyard::Component,
+    node::OwnedAttributeValue,
+    node_ref::NodeView,
+    prelude::{
+        AttributeMaskBuilder,
+        Dependancy,
+        NodeMaskBuilder,
+        State,
+    },
+    SendAnyMap,
+};
+use freya_native_core_macro::partial_derive_state;
+
+use crate::{
+    custom_attributes::CustomAttributeValues,
+    design_tokens::DesignTokens,
+};
+
+/// Design tokens available to the attributes of a Node,
+/// the ones provided by the Node itself along with the inherited from its ancestors.
+#[derive(Default, PartialEq, Clone, Debug, Component)]
+pub struct DesignTokensState {
+    pub design_tokens: Arc<DesignTokens>,
+}
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for DesignTokensState {
+    type ParentDependencies = (Self,);
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = ();
+
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
+        .with_attrs(AttributeMaskBuilder::Some(&[AttributeName::DesignTokens]));
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _context: &SendAnyMap,
+    ) -> bool {
+        let mut design_tokens = parent
+            .map(|(parent,)| parent.design_tokens.clone())
+            .unwrap_or_default();
+
+        if let Some(attributes) = node_view.attributes() {
+            for attr in attributes {
+                if let OwnedAttributeValue::Custom(CustomAttributeValues::DesignTokens(tokens)) =
+                    attr.value
+                {
+                    // Tokens of the Node replace the inherited ones with the same name
+                    if design_tokens.is_empty() {
+                        design_tokens = tokens.clone();
+                    } else {
+                        let mut merged = (*design_tokens).clone();
+                        merged.extend(tokens);
+                        design_tokens = Arc::new(merged);
+                    }
+                }
+            }
+        }
+
+        let design_tokens_state = DesignTokensState { design_tokens };
+        let changed = &design_tokens_state != self;
+
+        *self = design_tokens_state;
+        changed
+    }
+}
@@ -18,7 +18,10 @@ use freya_native_core::{
     SendAnyMap,
 };
 use freya_native_core_macro::partial_derive_state;
-use torin::torin::Torin;
+use torin::{
+    direction::WritingDirection,
+    torin::Torin,
+};
 
 use crate::{
     custom_attributes::CustomAttributeValues,
@@ -29,9 +32,12 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    states::DesignTokensState,
     values::{
+        FontVariation,
         TextHeight,
         TextOverflow,
+        TextStroke,
     },
 };
 
@@ -39,22 +45,64 @@ use crate::{
 pub struct FontStyleState {
     pub color: Color,
     pub text_shadows: Arc<[TextShadow]>,
+    pub text_stroke: Option<TextStroke>,
     pub font_family: Arc<[String]>,
     pub font_size: f32,
     pub font_slant: Slant,
     pub font_weight: Weight,
     pub font_width: Width,
+    pub font_variations: Arc<[FontVariation]>,
     pub line_height: Option<f32>,
     pub decoration: Decoration,
     pub word_spacing: f32,
     pub letter_spacing: f32,
     pub text_align: TextAlign,
+    pub writing_direction: WritingDirection,
     pub max_lines: Option<usize>,
     pub text_overflow: TextOverflow,
     pub text_height: TextHeightBehavior,
 }
 
 impl FontStyleState {
+    /// Paragraph style with the alignment, direction, lines and ellipsis of the text.
+    pub fn paragraph_style(&self) -> ParagraphStyle {
+        let mut paragraph_style = ParagraphStyle::default();
+        paragraph_style.set_text_align(self.text_align);
+        paragraph_style.set_text_direction(if self.writing_direction.is_rtl() {
+            TextDirection::RTL
+        } else {
+            TextDirection::LTR
+        });
+        paragraph_style.set_max_lines(self.max_lines);
+        paragraph_style.set_replace_tab_characters(true);
+        paragraph_style.set_text_height_behavior(self.text_height);
+
+        if let Some(ellipsis) = self.text_overflow.get_ellipsis() {
+            paragraph_style.set_ellipsis(ellipsis);
+        }
+
+        paragraph_style
+    }
+
+    /// Whether the text is a single line that starts from the left and is never truncated,
+    /// so it can be laid out with an infinite width.
+    pub fn is_unbounded_single_line(&self) -> bool {
+        self.max_lines == Some(1)
+            && self.text_align == TextAlign::default()
+            && !self.writing_direction.is_rtl()
+            && self.text_overflow.get_ellipsis().is_none()
+    }
+
+    /// Whether the text starts from the left edge of the paragraph.
+    pub fn is_left_aligned(&self) -> bool {
+        match self.text_align {
+            TextAlign::Left => true,
+            TextAlign::Start => !self.writing_direction.is_rtl(),
+            TextAlign::End => self.writing_direction.is_rtl(),
+            _ => false,
+        }
+    }
+
     pub fn text_style(
         &self,
         default_font_family: &[String],
@@ -79,6 +127,19 @@ impl FontStyleState {
             .set_word_spacing(self.word_spacing)
             .set_letter_spacing(self.letter_spacing);
 
+        if !self.font_variations.is_empty() {
+            let coordinates = self
+                .font_variations
+                .iter()
+                .map(FontVariation::coordinate)
+                .collect::<Vec<_>>();
+            let font_arguments =
+                FontArguments::new().set_variation_design_position(VariationPosition {
+                    coordinates: &coordinates,
+                });
+            text_style.set_font_arguments(&font_arguments);
+        }
+
         if paragraph_text_height.needs_custom_height() {
             text_style.set_height_override(true);
             text_style.set_half_leading(true);
@@ -98,6 +159,32 @@ impl FontStyleState {
 
         text_style
     }
+
+    /// Same as [FontStyleState::text_style] but only drawing the `text_stroke`, if any.
+    pub fn stroke_text_style(
+        &self,
+        default_font_family: &[String],
+        scale_factor: f32,
+        paragraph_text_height: TextHeightBehavior,
+    ) -> TextStyle {
+        let mut text_style =
+            self.text_style(default_font_family, scale_factor, paragraph_text_height);
+
+        let paint = if let Some(text_stroke) = self.text_stroke {
+            text_stroke.paint(scale_factor)
+        } else {
+            let mut paint = Paint::default();
+            paint.set_color(Color::TRANSPARENT);
+            paint
+        };
+        text_style.set_foreground_paint(&paint);
+
+        // Shadows and decorations are already drawn by the text itself
+        text_style.reset_shadows();
+        text_style.set_decoration_type(TextDecoration::NO_DECORATION);
+
+        text_style
+    }
 }
 
 impl Default for FontStyleState {
@@ -105,11 +192,13 @@ impl Default for FontStyleState {
         Self {
             color: Color::BLACK,
             text_shadows: Arc::default(),
+            text_stroke: None,
             font_family: Arc::default(),
             font_size: 16.0,
             font_weight: Weight::NORMAL,
             font_slant: Slant::Upright,
             font_width: Width::NORMAL,
+            font_variations: Arc::default(),
             line_height: None,
             word_spacing: 0.0,
             letter_spacing: 0.0,
@@ -118,6 +207,7 @@ impl Default for FontStyleState {
                 ..Decoration::default()
             },
             text_align: TextAlign::default(),
+            writing_direction: WritingDirection::default(),
             max_lines: None,
             text_overflow: TextOverflow::default(),
             text_height: TextHeightBehavior::DisableAll,
@@ -148,6 +238,11 @@ impl ParseAttribute for FontStyleState {
                         .collect();
                 }
             }
+            AttributeName::TextStroke => {
+                if let Some(value) = attr.value.as_text() {
+                    self.text_stroke = Some(TextStroke::parse(value)?);
+                }
+            }
             AttributeName::FontFamily => {
                 if let Some(value) = attr.value.as_text() {
                     let families = value.split(',');
@@ -175,6 +270,14 @@ impl ParseAttribute for FontStyleState {
                     }
                 }
             }
+            AttributeName::WritingDirection => {
+                if let Some(value) = attr.value.as_text() {
+                    self.writing_direction = match value {
+                        "rtl" => WritingDirection::Rtl,
+                        _ => WritingDirection::Ltr,
+                    };
+                }
+            }
             AttributeName::MaxLines => {
                 if let Some(value) = attr.value.as_text() {
                     if let Ok(max_lines) = value.parse() {
@@ -182,6 +285,17 @@ impl ParseAttribute for FontStyleState {
                     }
                 }
             }
+            AttributeName::LineClamp => {
+                if let Some(value) = attr.value.as_text() {
+                    if let Ok(max_lines) = value.parse() {
+                        self.max_lines = Some(max_lines);
+                        // Keep a different overflow if specified, e.g `fade`
+                        if self.text_overflow == TextOverflow::Clip {
+                            self.text_overflow = TextOverflow::Ellipsis;
+                        }
+                    }
+                }
+            }
             AttributeName::TextOverflow => {
                 let value = attr.value.as_text();
                 if let Some(value) = value {
@@ -211,6 +325,15 @@ impl ParseAttribute for FontStyleState {
                     }
                 }
             }
+            AttributeName::FontVariation => {
+                if let Some(value) = attr.value.as_text() {
+                    self.font_variations = value
+                        .split(',')
+                        .filter(|chunk| !chunk.trim().is_empty())
+                        .map(FontVariation::parse)
+                        .collect::<Result<_, _>>()?;
+                }
+            }
             AttributeName::Decoration => {
                 if let Some(value) = attr.value.as_text() {
                     if let Ok(decoration) = TextDecoration::parse(value) {
@@ -271,20 +394,24 @@ impl State<CustomAttributeValues> for FontStyleState {
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (DesignTokensState,);
 
     const NODE_MASK: NodeMaskBuilder<'static> =
         NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
             AttributeName::Color,
             AttributeName::TextAlign,
+            AttributeName::WritingDirection,
             AttributeName::TextShadow,
+            AttributeName::TextStroke,
             AttributeName::FontSize,
             AttributeName::FontFamily,
             AttributeName::LineHeight,
             AttributeName::MaxLines,
+            AttributeName::LineClamp,
             AttributeName::FontStyle,
             AttributeName::FontWeight,
             AttributeName::FontWidth,
+            AttributeName::FontVariation,
             AttributeName::WordSpacing,
             AttributeName::LetterSpacing,
             AttributeName::Decoration,
@@ -297,7 +424,7 @@ impl State<CustomAttributeValues> for FontStyleState {
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
@@ -308,9 +435,10 @@ impl State<CustomAttributeValues> for FontStyleState {
 
         let mut font_style = parent.map(|(v,)| v.clone()).unwrap_or_default();
 
+        let (DesignTokensState { design_tokens },) = node;
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
-                font_style.parse_safe(attr);
+                font_style.parse_with_tokens(attr, design_tokens);
             }
         }
 
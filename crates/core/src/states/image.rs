@@ -40,6 +40,7 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    states::DesignTokensState,
     values::{
         AspectRatio,
         ImageCover,
@@ -110,7 +111,7 @@ impl State<CustomAttributeValues> for ImageState {
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (DesignTokensState,);
 
     const NODE_MASK: NodeMaskBuilder<'static> =
         NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
@@ -129,16 +130,17 @@ impl State<CustomAttributeValues> for ImageState {
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
     ) -> bool {
         let mut image = ImageState::default();
 
+        let (DesignTokensState { design_tokens },) = node;
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
-                image.parse_safe(attr)
+                image.parse_with_tokens(attr, design_tokens)
             }
         }
 
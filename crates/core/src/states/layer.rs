@@ -25,6 +25,7 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    states::DesignTokensState,
 };
 
 #[derive(Default, PartialEq, Clone, Debug, Component)]
@@ -60,7 +61,7 @@ impl State<CustomAttributeValues> for LayerState {
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (DesignTokensState,);
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
         .with_attrs(AttributeMaskBuilder::Some(&[AttributeName::Layer]))
@@ -69,7 +70,7 @@ impl State<CustomAttributeValues> for LayerState {
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
@@ -87,9 +88,10 @@ impl State<CustomAttributeValues> for LayerState {
             layer_for_children: inherited_layer,
         };
 
+        let (DesignTokensState { design_tokens },) = node;
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
-                layer_state.parse_safe(attr);
+                layer_state.parse_with_tokens(attr, design_tokens);
             }
         }
 
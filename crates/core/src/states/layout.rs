@@ -32,6 +32,7 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    states::DesignTokensState,
 };
 
 #[derive(Default, Clone, Debug, Component, PartialEq)]
@@ -200,7 +201,7 @@ impl State<CustomAttributeValues> for LayoutState {
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (DesignTokensState,);
 
     const NODE_MASK: NodeMaskBuilder<'static> =
         NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
@@ -232,7 +233,7 @@ impl State<CustomAttributeValues> for LayoutState {
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
@@ -246,9 +247,10 @@ impl State<CustomAttributeValues> for LayoutState {
             ..Default::default()
         };
 
+        let (DesignTokensState { design_tokens },) = node;
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
-                layout.parse_safe(attr);
+                layout.parse_with_tokens(attr, design_tokens);
             }
         }
 
@@ -1,6 +1,8 @@
 mod accessibility;
+mod cache;
 mod canvas;
 mod cursor;
+mod design_tokens;
 mod font_style;
 mod image;
 mod layer;
@@ -11,8 +13,10 @@ mod transform;
 mod viewport;
 
 pub use accessibility::*;
+pub use cache::*;
 pub use canvas::*;
 pub use cursor::*;
+pub use design_tokens::*;
 pub use font_style::*;
 pub use image::*;
 pub use layer::*;
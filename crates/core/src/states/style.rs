@@ -13,9 +13,13 @@ use freya_native_core::{
         NodeMaskBuilder,
         State,
     },
+    tags::TagName,
+    NodeId,
     SendAnyMap,
 };
 use freya_native_core_macro::partial_derive_state;
+use torin::torin::Torin;
+use winit::window::CursorIcon;
 
 use crate::{
     custom_attributes::CustomAttributeValues,
@@ -26,13 +30,16 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    states::DesignTokensState,
     values::{
         parse_alpha,
         Border,
         CornerRadius,
         Fill,
         OverflowMode,
+        PageBreak,
         Shadow,
+        TextSelectionMode,
     },
 };
 
@@ -44,6 +51,12 @@ pub struct StyleState {
     pub shadows: Arc<[Shadow]>,
     pub corner_radius: CornerRadius,
     pub overflow: OverflowMode,
+    /// Cursor icon shown while the mouse is over the node or its children.
+    pub cursor: Option<CursorIcon>,
+    /// Whether the text of the node and its children can be selected, inherited if `None`.
+    pub text_selection: Option<TextSelectionMode>,
+    /// Where the pages can be split when printing the node.
+    pub page_break: PageBreak,
 }
 
 impl ParseAttribute for StyleState {
@@ -108,6 +121,27 @@ impl ParseAttribute for StyleState {
                     self.overflow = OverflowMode::parse(value)?;
                 }
             }
+            AttributeName::Cursor => {
+                if let Some(value) = attr.value.as_text() {
+                    if value == "auto" {
+                        return Ok(());
+                    }
+                    self.cursor = Some(CursorIcon::parse(value)?);
+                }
+            }
+            AttributeName::TextSelection => {
+                if let Some(value) = attr.value.as_text() {
+                    if value == "auto" {
+                        return Ok(());
+                    }
+                    self.text_selection = Some(TextSelectionMode::parse(value)?);
+                }
+            }
+            AttributeName::PageBreak => {
+                if let Some(value) = attr.value.as_text() {
+                    self.page_break = PageBreak::parse(value)?;
+                }
+            }
 
             _ => {}
         }
@@ -122,10 +156,10 @@ impl State<CustomAttributeValues> for StyleState {
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (DesignTokensState,);
 
-    const NODE_MASK: NodeMaskBuilder<'static> =
-        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
+        .with_attrs(AttributeMaskBuilder::Some(&[
             AttributeName::Background,
             AttributeName::BackgroundOpacity,
             AttributeName::Layer,
@@ -137,21 +171,26 @@ impl State<CustomAttributeValues> for StyleState {
             AttributeName::ImageData,
             AttributeName::Overflow,
             AttributeName::ImageCacheKey,
-        ]));
+            AttributeName::Cursor,
+            AttributeName::TextSelection,
+            AttributeName::PageBreak,
+        ]))
+        .with_tag();
 
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
     ) -> bool {
         let mut style = StyleState::default();
 
+        let (DesignTokensState { design_tokens },) = node;
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
-                style.parse_safe(attr)
+                style.parse_with_tokens(attr, design_tokens)
             }
         }
 
@@ -167,6 +206,12 @@ impl State<CustomAttributeValues> for StyleState {
                 .lock()
                 .unwrap()
                 .invalidate(node_view.node_id());
+
+            // The background of text spans is part of the paragraph, so it needs to be measured again
+            if node_view.tag() == Some(&TagName::Text) && node_view.height() > 0 {
+                let torin_layout = context.get::<Arc<Mutex<Torin<NodeId>>>>().unwrap();
+                torin_layout.lock().unwrap().invalidate(node_view.node_id());
+            }
         }
 
         *self = style;
@@ -17,6 +17,14 @@ use freya_native_core::{
     SendAnyMap,
 };
 use freya_native_core_macro::partial_derive_state;
+use torin::{
+    geometry::Area,
+    prelude::{
+        CursorPoint,
+        Point2D,
+    },
+    torin::Torin,
+};
 
 use crate::{
     custom_attributes::CustomAttributeValues,
@@ -25,6 +33,7 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    states::DesignTokensState,
 };
 
 #[derive(Default, Clone, Debug, Component, PartialEq)]
@@ -35,6 +44,88 @@ pub struct TransformState {
     pub scales: Vec<(NodeId, f32, f32)>,
 }
 
+impl TransformState {
+    /// Map a point of the screen to where it would be in this node if it wasn't rotated or scaled,
+    /// so it can be compared with the layout areas of the node and its children.
+    pub fn untransform_point(&self, point: CursorPoint, layout: &Torin<NodeId>) -> CursorPoint {
+        // Rotations are applied before the scales and from the outermost ancestor,
+        // so they are undone in the same order
+        let point = self.rotations.iter().fold(point, |point, (id, rotation)| {
+            let Some(layout_node) = layout.get(*id) else {
+                return point;
+            };
+            let center = layout_node.visible_area().center().cast::<f64>();
+            let (sin, cos) = (-*rotation as f64).to_radians().sin_cos();
+            let (x, y) = (point.x - center.x, point.y - center.y);
+            CursorPoint::new(center.x + x * cos - y * sin, center.y + x * sin + y * cos)
+        });
+
+        self.scales
+            .iter()
+            .fold(point, |point, (id, scale_x, scale_y)| {
+                let Some(layout_node) = layout.get(*id) else {
+                    return point;
+                };
+                let center = layout_node.visible_area().center().cast::<f64>();
+                CursorPoint::new(
+                    center.x + (point.x - center.x) / (*scale_x as f64).max(f64::EPSILON),
+                    center.y + (point.y - center.y) / (*scale_y as f64).max(f64::EPSILON),
+                )
+            })
+    }
+
+    /// Map an area of this node to the area containing it once it's scaled and rotated.
+    pub fn transform_area(&self, area: Area, layout: &Torin<NodeId>) -> Area {
+        let area = self
+            .scales
+            .iter()
+            .rev()
+            .fold(area, |area, (id, scale_x, scale_y)| {
+                let Some(layout_node) = layout.get(*id) else {
+                    return area;
+                };
+                let center = layout_node.visible_area().center();
+                let scale = |point: Point2D| {
+                    Point2D::new(
+                        center.x + (point.x - center.x) * scale_x,
+                        center.y + (point.y - center.y) * scale_y,
+                    )
+                };
+                Area::from_points([scale(area.min()), scale(area.max())])
+            });
+
+        self.rotations
+            .iter()
+            .rev()
+            .fold(area, |area, (id, rotation)| {
+                let Some(layout_node) = layout.get(*id) else {
+                    return area;
+                };
+                let center = layout_node.visible_area().center();
+                let (sin, cos) = rotation.to_radians().sin_cos();
+                let rotate = |point: Point2D| {
+                    let (x, y) = (point.x - center.x, point.y - center.y);
+                    Point2D::new(center.x + x * cos - y * sin, center.y + x * sin + y * cos)
+                };
+                Area::from_points([
+                    rotate(area.min()),
+                    rotate(area.max()),
+                    rotate(Point2D::new(area.max_x(), area.min_y())),
+                    rotate(Point2D::new(area.min_x(), area.max_y())),
+                ])
+            })
+    }
+
+    /// Horizontal and vertical scales of this node relative to the screen.
+    pub fn total_scale(&self) -> (f32, f32) {
+        self.scales
+            .iter()
+            .fold((1., 1.), |(x, y), (_, scale_x, scale_y)| {
+                (x * scale_x, y * scale_y)
+            })
+    }
+}
+
 impl ParseAttribute for TransformState {
     fn parse_attribute(
         &mut self,
@@ -89,7 +180,7 @@ impl State<CustomAttributeValues> for TransformState {
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (DesignTokensState,);
 
     const NODE_MASK: NodeMaskBuilder<'static> =
         NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
@@ -103,7 +194,7 @@ impl State<CustomAttributeValues> for TransformState {
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
@@ -117,9 +208,10 @@ impl State<CustomAttributeValues> for TransformState {
             ..inherited_transform
         };
 
+        let (DesignTokensState { design_tokens },) = node;
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
-                transform_state.parse_safe(attr);
+                transform_state.parse_with_tokens(attr, design_tokens);
             }
         }
 
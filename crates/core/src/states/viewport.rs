@@ -20,6 +20,7 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    states::DesignTokensState,
     values::OverflowMode,
 };
 
@@ -55,7 +56,7 @@ impl State<CustomAttributeValues> for ViewportState {
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (DesignTokensState,);
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
         .with_attrs(AttributeMaskBuilder::Some(&[AttributeName::Overflow]))
@@ -64,7 +65,7 @@ impl State<CustomAttributeValues> for ViewportState {
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         _context: &SendAnyMap,
@@ -78,9 +79,10 @@ impl State<CustomAttributeValues> for ViewportState {
             ..Default::default()
         };
 
+        let (DesignTokensState { design_tokens },) = node;
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
-                viewports_state.parse_safe(attr)
+                viewports_state.parse_with_tokens(attr, design_tokens)
             }
         }
 
@@ -10,3 +10,24 @@ pub fn default_fonts() -> Vec<String> {
     }
     fonts
 }
+
+/// Color emoji fonts, the one of the platform first followed by the most common ones.
+pub fn emoji_fonts() -> Vec<String> {
+    let mut fonts = vec![
+        "Noto Color Emoji".to_string(),
+        "Apple Color Emoji".to_string(),
+        "Segoe UI Emoji".to_string(),
+        "Twemoji".to_string(),
+        "JoyPixels".to_string(),
+    ];
+    let platform_font = if cfg!(target_os = "windows") {
+        2
+    } else if cfg!(any(target_os = "macos", target_os = "ios")) {
+        1
+    } else {
+        0
+    };
+    let font = fonts.remove(platform_font);
+    fonts.insert(0, font);
+    fonts
+}
@@ -1,11 +1,15 @@
+use std::ffi::OsString;
+
 pub use accesskit::{
     Node as AccessibilityNode,
     NodeId as AccessibilityId,
+    Role as AccessibilityRole,
 };
 use freya_native_core::events::EventName;
 use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 use tokio::sync::{
+    broadcast,
     mpsc::{
         UnboundedReceiver,
         UnboundedSender,
@@ -19,6 +23,10 @@ use crate::{
         PlatformEvent,
         PotentialEvent,
     },
+    global_shortcuts::GlobalShortcutEvent,
+    native_menu::NativeMenuEvent,
+    native_tray::NativeTrayEvent,
+    open_requests::OpenRequest,
     platform_state::NativePlatformState,
 };
 
@@ -28,6 +36,24 @@ pub type NativePlatformSender = watch::Sender<NativePlatformState>;
 /// Receive updates by the platform
 pub type NativePlatformReceiver = watch::Receiver<NativePlatformState>;
 
+/// Send the events of the native menu to the VirtualDOM
+pub type NativeMenuEventSender = broadcast::Sender<NativeMenuEvent>;
+
+/// Send the events of the system tray to the VirtualDOM
+pub type NativeTrayEventSender = broadcast::Sender<NativeTrayEvent>;
+
+/// Send the triggered global shortcuts to the VirtualDOM
+pub type GlobalShortcutEventSender = broadcast::Sender<GlobalShortcutEvent>;
+
+/// Send the requests to close the window to the VirtualDOM, only while it has subscribers
+pub type CloseRequestSender = broadcast::Sender<()>;
+
+/// Send the requests to open links and files received while running to the VirtualDOM
+pub type OpenRequestSender = broadcast::Sender<OpenRequest>;
+
+/// Send the arguments forwarded by other instances of the app to the VirtualDOM
+pub type SecondInstanceSender = broadcast::Sender<Vec<OsString>>;
+
 /// Emit events to the VirtualDOM
 pub type EventEmitter = UnboundedSender<Vec<DomEvent>>;
 
@@ -0,0 +1,31 @@
+use std::fmt;
+
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub enum CacheMode {
+    #[default]
+    None,
+    Raster,
+}
+
+impl Parse for CacheMode {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Ok(match value {
+            "raster" => CacheMode::Raster,
+            _ => CacheMode::None,
+        })
+    }
+}
+
+impl fmt::Display for CacheMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            CacheMode::Raster => "raster",
+            CacheMode::None => "none",
+        })
+    }
+}
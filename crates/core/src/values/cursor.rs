@@ -1,5 +1,7 @@
 use std::fmt;
 
+use winit::window::CursorIcon;
+
 use crate::parsing::{
     Parse,
     ParseError,
@@ -28,3 +30,9 @@ impl fmt::Display for CursorMode {
         })
     }
 }
+
+impl Parse for CursorIcon {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        value.parse().map_err(|_| ParseError)
+    }
+}
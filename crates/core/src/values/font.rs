@@ -87,6 +87,8 @@ pub enum TextOverflow {
     #[default]
     Clip,
     Ellipsis,
+    /// Fade out the end of the last visible line.
+    Fade,
     Custom(String),
 }
 
@@ -95,6 +97,7 @@ impl TextOverflow {
         match self {
             Self::Clip => None,
             Self::Ellipsis => Some("…"),
+            Self::Fade => None,
             Self::Custom(custom) => Some(custom),
         }
     }
@@ -103,6 +106,7 @@ impl TextOverflow {
         match self {
             TextOverflow::Clip => "clip".to_string(),
             TextOverflow::Ellipsis => "ellipsis".to_string(),
+            TextOverflow::Fade => "fade".to_string(),
             TextOverflow::Custom(text_overflow) => text_overflow.to_string(),
         }
     }
@@ -112,6 +116,7 @@ impl Parse for TextOverflow {
     fn parse(value: &str) -> Result<Self, ParseError> {
         Ok(match value {
             "ellipsis" => TextOverflow::Ellipsis,
+            "fade" => TextOverflow::Fade,
             "clip" => TextOverflow::Clip,
             value => TextOverflow::Custom(value.to_string()),
         })
@@ -0,0 +1,52 @@
+use std::fmt;
+
+use freya_engine::prelude::*;
+
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+/// Value of an axis of a variable font, e.g `wght=650`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FontVariation {
+    /// Four letters tag of the axis, e.g `wght`, `wdth` or `slnt`.
+    pub axis: [u8; 4],
+    pub value: f32,
+}
+
+impl FontVariation {
+    /// Tag of the axis as text.
+    pub fn axis(&self) -> &str {
+        std::str::from_utf8(&self.axis).unwrap_or_default()
+    }
+
+    /// Coordinate of the axis, as used by the font arguments of a text style.
+    pub fn coordinate(&self) -> Coordinate {
+        let [a, b, c, d] = self.axis.map(char::from);
+        Coordinate {
+            axis: FourByteTag::from_chars(a, b, c, d),
+            value: self.value,
+        }
+    }
+}
+
+impl Parse for FontVariation {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let (axis, value) = value.split_once('=').ok_or(ParseError)?;
+        let axis: [u8; 4] = axis.trim().as_bytes().try_into().map_err(|_| ParseError)?;
+        if !axis.iter().all(u8::is_ascii_alphanumeric) {
+            return Err(ParseError);
+        }
+        Ok(FontVariation {
+            axis,
+            value: value.trim().parse::<f32>().map_err(|_| ParseError)?,
+        })
+    }
+}
+
+impl fmt::Display for FontVariation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.axis(), self.value)
+    }
+}
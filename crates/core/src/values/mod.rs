@@ -2,6 +2,7 @@ mod accessibility;
 mod alignment;
 mod aspect_ratio;
 mod border;
+mod cache;
 mod color;
 mod content;
 mod corner_radius;
@@ -10,34 +11,43 @@ mod decoration;
 mod fill;
 mod focusable;
 mod font;
+mod font_variation;
 mod gaps;
 mod gradient;
 mod highlight;
 mod image_cover;
 mod overflow;
+mod page_break;
 mod paint;
 mod position;
 mod sampling;
 mod shadow;
 mod size;
 mod text_height;
+mod text_selection;
 mod text_shadow;
+mod text_stroke;
 mod visible_size;
 
 pub use aspect_ratio::*;
 pub use border::*;
+pub use cache::*;
 pub use color::*;
 pub use corner_radius::*;
 pub use cursor::*;
 pub use fill::*;
 pub use focusable::*;
 pub use font::*;
+pub use font_variation::*;
 pub use gradient::*;
 pub use highlight::*;
 pub use image_cover::*;
 pub use overflow::*;
+pub use page_break::*;
 pub use paint::*;
 pub use sampling::*;
 pub use shadow::*;
 pub use size::*;
 pub use text_height::*;
+pub use text_selection::*;
+pub use text_stroke::*;
@@ -0,0 +1,30 @@
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+/// Hint of where the pages can be split when printing, see the `page_break` attribute.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PageBreak {
+    /// Split the pages wherever they are full.
+    #[default]
+    Auto,
+    /// Start a new page before the element.
+    Before,
+    /// Start a new page after the element.
+    After,
+    /// Move the element to the next page instead of splitting it, unless it doesn't fit in a page.
+    Avoid,
+}
+
+impl Parse for PageBreak {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "auto" => Ok(PageBreak::Auto),
+            "before" => Ok(PageBreak::Before),
+            "after" => Ok(PageBreak::After),
+            "avoid" => Ok(PageBreak::Avoid),
+            _ => Err(ParseError),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use torin::scaled::Scaled;
 
 use super::Fill;
@@ -75,6 +77,19 @@ impl Parse for Shadow {
     }
 }
 
+impl fmt::Display for Shadow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.position == ShadowPosition::Inset {
+            f.write_str("inset ")?;
+        }
+        write!(
+            f,
+            "{} {} {} {} {}",
+            self.x, self.y, self.blur, self.spread, self.fill
+        )
+    }
+}
+
 impl Scaled for Shadow {
     fn scale(&mut self, scale_factor: f32) {
         self.x *= scale_factor;
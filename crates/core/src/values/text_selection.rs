@@ -0,0 +1,22 @@
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextSelectionMode {
+    /// The text can be selected.
+    Enabled,
+    /// The text can't be selected, even if an ancestor enables it.
+    Disabled,
+}
+
+impl Parse for TextSelectionMode {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "enabled" => Ok(TextSelectionMode::Enabled),
+            "disabled" => Ok(TextSelectionMode::Disabled),
+            _ => Err(ParseError),
+        }
+    }
+}
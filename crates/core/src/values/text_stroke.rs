@@ -0,0 +1,41 @@
+use freya_engine::prelude::*;
+
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+/// Outline drawn around the glyphs of a text.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextStroke {
+    pub width: f32,
+    pub color: Color,
+}
+
+impl TextStroke {
+    /// Paint used to draw the stroke. The stroke is drawn behind the text,
+    /// so it's twice as wide in order to be fully visible around the glyphs.
+    pub fn paint(&self, scale_factor: f32) -> Paint {
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Stroke);
+        paint.set_stroke_width(self.width * 2.0 * scale_factor);
+        paint.set_color(self.color);
+        paint
+    }
+}
+
+impl Parse for TextStroke {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let mut stroke_values = value.split_ascii_whitespace();
+        Ok(TextStroke {
+            width: stroke_values
+                .next()
+                .ok_or(ParseError)?
+                .parse::<f32>()
+                .map_err(|_| ParseError)?,
+            color: Color::parse(stroke_values.collect::<Vec<&str>>().join(" ").as_str())
+                .map_err(|_| ParseError)?,
+        })
+    }
+}
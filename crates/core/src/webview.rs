@@ -0,0 +1,105 @@
+use std::sync::atomic::{
+    AtomicU64,
+    Ordering,
+};
+
+/// Identifier of a native webview embedded in the window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WebViewId(u64);
+
+impl WebViewId {
+    /// Create a new unique identifier.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Content loaded by a webview.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WebViewContent {
+    Url(String),
+    Html(String),
+}
+
+/// Configuration of a native webview embedded in the window.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WebViewConfig {
+    pub content: WebViewContent,
+    /// Allow opening the developer tools of the page. Defaults to `false`.
+    pub devtools: bool,
+    /// Show the window behind the transparent areas of the page. Defaults to `false`.
+    pub transparent: bool,
+    pub user_agent: Option<String>,
+}
+
+impl WebViewConfig {
+    pub fn new(content: WebViewContent) -> Self {
+        Self {
+            content,
+            devtools: false,
+            transparent: false,
+            user_agent: None,
+        }
+    }
+
+    pub fn with_devtools(mut self, devtools: bool) -> Self {
+        self.devtools = devtools;
+        self
+    }
+
+    pub fn with_transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
+    }
+
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+}
+
+/// Event sent by a webview.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WebViewEvent {
+    /// The page sent a message with `window.freya.postMessage(message)`.
+    Message(String),
+    /// The page with the given URL started loading.
+    LoadStarted(String),
+    /// The page with the given URL finished loading.
+    LoadFinished(String),
+}
+
+/// Script run before every page is loaded, it exposes a `window.freya` object to exchange messages with the app:
+///
+/// - `window.freya.postMessage(message)` sends a string to the app.
+/// - `window.freya.onmessage` is called with the strings sent by the app.
+pub const WEBVIEW_INIT_SCRIPT: &str = r#"
+window.freya = {
+    onmessage: null,
+    postMessage: (message) => window.ipc.postMessage(String(message)),
+};
+"#;
+
+/// Script that calls the `window.freya.onmessage` handler of a page with the given message.
+pub fn webview_message_script(message: &str) -> String {
+    let mut literal = String::with_capacity(message.len() + 2);
+    literal.push('"');
+    for ch in message.chars() {
+        match ch {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            // Line and paragraph separators are line breaks in older JavaScript engines
+            '\u{2028}' => literal.push_str("\\u2028"),
+            '\u{2029}' => literal.push_str("\\u2029"),
+            ch if ch.is_control() => literal.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => literal.push(ch),
+        }
+    }
+    literal.push('"');
+
+    format!("window.freya && window.freya.onmessage && window.freya.onmessage({literal});")
+}
@@ -0,0 +1,9 @@
+use freya_core::app_dirs::key_file_name;
+
+#[test]
+fn key_file_names() {
+    assert_eq!(key_file_name("settings"), "settings");
+    assert_eq!(key_file_name("main-window_2.v1"), "main-window_2.v1");
+    assert_eq!(key_file_name("user/settings:dark"), "user_settings_dark");
+    assert_eq!(key_file_name("ñandú"), "ñandú");
+}
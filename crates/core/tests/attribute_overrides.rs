@@ -0,0 +1,66 @@
+use freya::prelude::*;
+use freya_native_core::attributes::AttributeName;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn attribute_overrides() {
+    fn attribute_overrides_app() -> Element {
+        let mut width = use_signal(|| 100);
+
+        rsx!(rect {
+            onclick: move |_| width += 50,
+            width: "{width}",
+            height: "50",
+            background: "red",
+        })
+    }
+
+    let mut utils = launch_test(attribute_overrides_app);
+    utils.wait_for_update().await;
+
+    let rect_id = utils.root().get(0).id();
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 100.);
+
+    // Override an attribute set by the app
+    utils
+        .sdom()
+        .get_mut()
+        .set_attribute_override(rect_id, AttributeName::Width, "200".to_string());
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 200.);
+    assert_eq!(
+        utils.sdom().get().attribute_overrides().get(rect_id),
+        vec![(AttributeName::Width, "200".to_string())]
+    );
+
+    // The override takes precedence over the changes of the app
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 200.);
+
+    // Removing the override goes back to the latest value set by the app
+    utils
+        .sdom()
+        .get_mut()
+        .remove_attribute_override(rect_id, AttributeName::Width);
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 150.);
+    assert!(utils
+        .sdom()
+        .get()
+        .attribute_overrides()
+        .get(rect_id)
+        .is_empty());
+
+    // Override an attribute not set by the app
+    utils.sdom().get_mut().set_attribute_override(
+        rect_id,
+        AttributeName::Padding,
+        "10".to_string(),
+    );
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).state().size.padding.top(), 10.);
+
+    utils.sdom().get_mut().clear_attribute_overrides();
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).state().size.padding.top(), 0.);
+}
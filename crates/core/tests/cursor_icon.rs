@@ -0,0 +1,88 @@
+use freya::prelude::*;
+use freya_core::platform::{
+    CursorIcon,
+    CursorImage,
+};
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn cursor_attribute() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                height: "50%",
+                width: "100%",
+                cursor: "pointer",
+                rect {
+                    height: "50%",
+                    width: "100%",
+                    cursor: "text",
+                }
+                label {
+                    "Inherited"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    // Initial cursor
+    assert_eq!(utils.cursor_icon(), CursorIcon::default());
+
+    // The closest node with a cursor wins
+    utils.move_cursor((100., 50.)).await;
+    assert_eq!(utils.cursor_icon(), CursorIcon::Text);
+
+    // Children without a cursor use the one of their parent
+    utils.move_cursor((5., 130.)).await;
+    assert_eq!(utils.cursor_icon(), CursorIcon::Pointer);
+
+    // Going back to the default one when leaving the nodes with a cursor
+    utils.move_cursor((100., 300.)).await;
+    assert_eq!(utils.cursor_icon(), CursorIcon::default());
+}
+
+#[tokio::test]
+pub async fn cursor_attribute_keeps_manual_cursor() {
+    fn app() -> Element {
+        let platform = use_platform();
+
+        rsx!(
+            rect {
+                height: "50%",
+                width: "100%",
+                cursor: "pointer",
+            }
+            rect {
+                height: "50%",
+                width: "100%",
+                onclick: move |_| platform.set_custom_cursor(CursorImage::from_rgba(
+                    vec![255; 4 * 4 * 4],
+                    4,
+                    4,
+                    (1, 1),
+                )),
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    utils.click_cursor((100., 300.)).await;
+    assert_eq!(
+        utils
+            .custom_cursor()
+            .map(|cursor_image| cursor_image.hotspot),
+        Some((1, 1))
+    );
+
+    // Moving outside of the nodes with a cursor keeps the custom cursor
+    utils.move_cursor((100., 350.)).await;
+    assert!(utils.custom_cursor().is_some());
+
+    // Hovering a node with a cursor replaces it
+    utils.move_cursor((100., 100.)).await;
+    assert_eq!(utils.cursor_icon(), CursorIcon::Pointer);
+    assert!(utils.custom_cursor().is_none());
+}
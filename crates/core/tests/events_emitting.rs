@@ -604,3 +604,63 @@ pub async fn filedrop_events() {
     );
     assert_eq!(root.get(0).style().background, Fill::Color(Color::BLUE));
 }
+
+#[tokio::test]
+pub async fn ime_events() {
+    fn ime_events_app() -> Element {
+        let mut focus = use_focus();
+        let mut preedit = use_signal(String::new);
+        let mut committed = use_signal(String::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                a11y_id: focus.attribute(),
+                onclick: move |_| focus.request_focus(),
+                onimepreedit: move |e| preedit.set(e.text.clone()),
+                onimecommit: move |e| committed.set(e.text.clone()),
+                label {
+                    "Preedit: {preedit}"
+                }
+                label {
+                    "Committed: {committed}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(ime_events_app);
+
+    let root = utils.root();
+
+    // IME events only go to the focused element
+    utils.push_event(TestEvent::Ime {
+        name: EventName::ImePreedit,
+        text: "にほ".to_string(),
+        cursor: Some((6, 6)),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("Preedit: "));
+
+    utils.click_cursor((5., 5.)).await;
+
+    utils.push_event(TestEvent::Ime {
+        name: EventName::ImePreedit,
+        text: "にほ".to_string(),
+        cursor: Some((6, 6)),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("Preedit: にほ"));
+
+    utils.push_event(TestEvent::Ime {
+        name: EventName::ImeCommit,
+        text: "日本".to_string(),
+        cursor: None,
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(0).get(1).get(0).text(), Some("Committed: 日本"));
+}
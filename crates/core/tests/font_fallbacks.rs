@@ -0,0 +1,84 @@
+use freya_core::fonts::{
+    FontFallbacks,
+    FontScript,
+    ResolvedFonts,
+};
+
+fn resolved_fonts() -> ResolvedFonts {
+    let fallbacks = FontFallbacks::empty()
+        .with_fallback(FontScript::Emoji, ["Emoji"])
+        .with_fallback(FontScript::Han, ["Chinese"])
+        .with_fallback(FontScript::Japanese, ["Japanese"])
+        .with_fallback(FontScript::Korean, ["Korean"]);
+    ResolvedFonts::new(vec!["Default".to_string()], &fallbacks)
+}
+
+fn runs<'a>(fonts: &'a ResolvedFonts, text: &'a str) -> Vec<(&'a str, &'a str)> {
+    fonts
+        .runs(text)
+        .into_iter()
+        .map(|(range, fonts)| (&text[range], fonts[1].as_str()))
+        .collect()
+}
+
+#[test]
+fn font_script() {
+    assert_eq!(FontScript::of('a'), None);
+    assert_eq!(FontScript::of(' '), None);
+    assert_eq!(FontScript::of('の'), Some(FontScript::Japanese));
+    assert_eq!(FontScript::of('カ'), Some(FontScript::Japanese));
+    assert_eq!(FontScript::of('한'), Some(FontScript::Korean));
+    assert_eq!(FontScript::of('語'), Some(FontScript::Han));
+    assert_eq!(FontScript::of('😀'), Some(FontScript::Emoji));
+    assert_eq!(FontScript::of('ש'), Some(FontScript::Hebrew));
+}
+
+#[test]
+fn resolved_fonts_order() {
+    let fonts = resolved_fonts();
+
+    // Without a script, the chains keep their order
+    assert_eq!(
+        &*fonts,
+        ["Default", "Emoji", "Chinese", "Japanese", "Korean"]
+    );
+
+    // The chain of the script comes first, after the default fonts
+    assert_eq!(
+        fonts.for_script(Some(&FontScript::Japanese)),
+        ["Default", "Japanese", "Emoji", "Chinese", "Korean"]
+    );
+    assert_eq!(
+        fonts.for_script(Some(&FontScript::Thai)),
+        ["Default", "Emoji", "Chinese", "Japanese", "Korean"]
+    );
+}
+
+#[test]
+fn japanese_text() {
+    let fonts = resolved_fonts();
+
+    // Chinese characters of a Japanese text use the Japanese fonts
+    assert_eq!(
+        runs(&fonts, "日本語のテキスト"),
+        [("日本語のテキスト", "Japanese")]
+    );
+    assert_eq!(
+        runs(&fonts, "Hello, 世界です!"),
+        [("Hello, 世界です!", "Japanese")]
+    );
+
+    // Otherwise they use the Chinese fonts
+    assert_eq!(runs(&fonts, "中文文本"), [("中文文本", "Chinese")]);
+    assert_eq!(runs(&fonts, "漢字 한국어"), [("漢字 한국어", "Korean")]);
+
+    // Every script gets its own run
+    assert_eq!(
+        runs(&fonts, "こんにちは😀 world"),
+        [("こんにちは", "Japanese"), ("😀 world", "Emoji")]
+    );
+
+    // Text without any script uses the fonts of every script
+    assert_eq!(runs(&fonts, "Hello"), [("Hello", "Emoji")]);
+    assert_eq!(runs(&fonts, ""), [("", "Emoji")]);
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use freya_core::platform_state::FramePacing;
+
+#[test]
+fn background_frame_interval() {
+    let frame_pacing = FramePacing::default();
+    assert_eq!(frame_pacing.frame_interval(false), None);
+    assert_eq!(frame_pacing.frame_interval(true), None);
+
+    let frame_pacing = FramePacing {
+        max_fps: Some(50),
+        background_max_fps: Some(5),
+        ..FramePacing::default()
+    };
+    assert_eq!(
+        frame_pacing.frame_interval(false),
+        Some(Duration::from_millis(20))
+    );
+    assert_eq!(
+        frame_pacing.frame_interval(true),
+        Some(Duration::from_millis(200))
+    );
+
+    // The regular limit applies in the background if there is no specific one
+    let frame_pacing = FramePacing {
+        max_fps: Some(50),
+        ..FramePacing::default()
+    };
+    assert_eq!(
+        frame_pacing.frame_interval(true),
+        Some(Duration::from_millis(20))
+    );
+}
@@ -0,0 +1,52 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use freya_core::platform_state::{
+    FramePhase,
+    FrameStatsRecorder,
+};
+
+#[test]
+fn frame_phases() {
+    let mut recorder = FrameStatsRecorder::default();
+
+    recorder.record_phase(FramePhase::PollVDOM, Duration::from_millis(1));
+    recorder.record_phase(FramePhase::PollVDOM, Duration::from_millis(2));
+    recorder.record_phase(FramePhase::Layout, Duration::from_millis(4));
+    recorder.record_phase(FramePhase::Paint, Duration::from_millis(5));
+    recorder.record_rendered(Instant::now());
+
+    let timings = recorder.stats().last_frame_timings;
+    assert_eq!(timings.poll_vdom, Duration::from_millis(3));
+    assert_eq!(timings.layout, Duration::from_millis(4));
+    assert_eq!(timings.get(FramePhase::Paint), Duration::from_millis(5));
+    assert_eq!(timings.present, Duration::ZERO);
+
+    // The phases are reset for the next frame
+    recorder.record_phase(FramePhase::Present, Duration::from_millis(1));
+    recorder.record_rendered(Instant::now());
+
+    let timings = recorder.stats().last_frame_timings;
+    assert_eq!(timings.layout, Duration::ZERO);
+    assert_eq!(timings.present, Duration::from_millis(1));
+}
+
+#[test]
+fn frame_duration_percentiles() {
+    let mut recorder = FrameStatsRecorder::default();
+
+    for i in 0..100 {
+        let started_at = Instant::now() - Duration::from_millis(i);
+        recorder.record_rendered(started_at);
+    }
+
+    let stats = recorder.stats();
+    let percentiles = stats.frame_duration_percentiles;
+    assert_eq!(stats.rendered_frames, 100);
+    assert!(percentiles.p50 >= Duration::from_millis(50));
+    assert!(percentiles.p50 < Duration::from_millis(60));
+    assert!(percentiles.p95 >= Duration::from_millis(95));
+    assert!(percentiles.p99 >= percentiles.p95);
+}
@@ -0,0 +1,39 @@
+use freya::prelude::*;
+
+#[test]
+fn register_global_shortcuts() {
+    let mut shortcuts = GlobalShortcuts::default();
+
+    assert_eq!(
+        shortcuts.register(GlobalShortcut::new("show", "Ctrl+Shift+Space")),
+        Ok(())
+    );
+    assert_eq!(
+        shortcuts.register(GlobalShortcut::new("play", "MediaPlayPause")),
+        Ok(())
+    );
+
+    // Same keys in a different order
+    assert_eq!(
+        shortcuts.register(GlobalShortcut::new("toggle", "Shift+Ctrl+Space")),
+        Err(GlobalShortcutError::Conflict("show".to_string()))
+    );
+    assert_eq!(
+        shortcuts.register(GlobalShortcut::new("hide", "Ctrl+Hyper+H")),
+        Err(GlobalShortcutError::InvalidAccelerator(
+            "Ctrl+Hyper+H".to_string()
+        ))
+    );
+    assert_eq!(shortcuts.shortcuts.len(), 2);
+
+    // Registering the same id replaces the shortcut
+    assert_eq!(
+        shortcuts.register(GlobalShortcut::new("show", "Ctrl+Shift+S")),
+        Ok(())
+    );
+    assert_eq!(shortcuts.get("show").unwrap().accelerator, "Ctrl+Shift+S");
+    assert_eq!(shortcuts.shortcuts.len(), 2);
+
+    assert!(shortcuts.remove("play").is_some());
+    assert!(shortcuts.get("play").is_none());
+}
@@ -0,0 +1,179 @@
+use freya::prelude::*;
+use freya_core::platform::CursorIcon;
+use freya_engine::prelude::TextDecoration;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn onlinkclick() {
+    fn onlinkclick_app() -> Element {
+        let mut clicked = use_signal(String::new);
+
+        rsx!(
+            paragraph {
+                onlinkclick: move |e: LinkClickEvent| clicked.set(e.link.clone()),
+                text {
+                    "Hello "
+                }
+                text {
+                    link: "https://freyaui.dev",
+                    "World"
+                }
+            }
+            label {
+                "{clicked}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(onlinkclick_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+
+    // Clicking outside of a link does nothing
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(root.get(1).get(0).text(), Some(""));
+
+    // Clicking after the end of the text does nothing
+    utils.click_cursor((150., 5.)).await;
+    assert_eq!(root.get(1).get(0).text(), Some(""));
+
+    // Clicking the link
+    utils.click_cursor((60., 5.)).await;
+    assert_eq!(root.get(1).get(0).text(), Some("https://freyaui.dev"));
+}
+
+#[tokio::test]
+pub async fn link_cursor_icon() {
+    fn link_cursor_icon_app() -> Element {
+        rsx!(
+            paragraph {
+                text {
+                    "Hello "
+                }
+                text {
+                    link: "https://freyaui.dev",
+                    "World"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(link_cursor_icon_app);
+    utils.wait_for_update().await;
+
+    utils.move_cursor((5., 5.)).await;
+    assert_eq!(utils.cursor_icon(), CursorIcon::default());
+
+    // Hovering a link shows a pointer
+    utils.move_cursor((60., 5.)).await;
+    assert_eq!(utils.cursor_icon(), CursorIcon::Pointer);
+}
+
+#[tokio::test]
+pub async fn onlinkhover() {
+    fn onlinkhover_app() -> Element {
+        let mut hovered = use_signal(|| None);
+
+        rsx!(
+            paragraph {
+                onlinkhover: move |e: LinkHoverEvent| hovered.set(e.link.clone()),
+                text {
+                    "Hello "
+                }
+                text {
+                    link: "https://freyaui.dev",
+                    decoration: if hovered().is_some() { "underline" },
+                    "World"
+                }
+            }
+            label {
+                "{hovered:?}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(onlinkhover_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(root.get(1).get(0).text(), Some("None"));
+
+    // Hovering the link
+    utils.move_cursor((60., 5.)).await;
+    assert_eq!(
+        root.get(1).get(0).text(),
+        Some("Some(\"https://freyaui.dev\")")
+    );
+    assert_eq!(
+        root.get(0).get(1).state().font_style.decoration.ty,
+        TextDecoration::UNDERLINE
+    );
+
+    // Leaving the link
+    utils.move_cursor((5., 5.)).await;
+    assert_eq!(root.get(1).get(0).text(), Some("None"));
+}
+
+#[tokio::test]
+pub async fn scaled_link() {
+    fn scaled_link_app() -> Element {
+        let mut clicked = use_signal(String::new);
+
+        rsx!(
+            paragraph {
+                width: "200",
+                height: "50",
+                scale: "2",
+                onlinkclick: move |e: LinkClickEvent| clicked.set(e.link.clone()),
+                text {
+                    "Hello "
+                }
+                text {
+                    link: "https://freyaui.dev",
+                    "World"
+                }
+            }
+            label {
+                "{clicked}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(scaled_link_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+
+    // Clicking where the link is rendered, which is over "Hello " in the unscaled paragraph
+    utils.click_cursor((20., 5.)).await;
+    assert_eq!(root.get(1).get(0).text(), Some("https://freyaui.dev"));
+}
+
+#[tokio::test]
+pub async fn link_accessibility() {
+    fn link_accessibility_app() -> Element {
+        rsx!(
+            paragraph {
+                text {
+                    "Hello "
+                }
+                text {
+                    link: "https://freyaui.dev",
+                    "World"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(link_accessibility_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let links = root.find_all_by_role(Role::Link);
+    assert_eq!(links.len(), 1);
+    let link = &links[0];
+    let builder = link.state().accessibility.builder.clone().unwrap();
+    assert_eq!(builder.url(), Some("https://freyaui.dev"));
+    assert!(link.state().accessibility.a11y_id.is_some());
+}
@@ -0,0 +1,51 @@
+use freya::prelude::*;
+use freya_core::native_menu::parse_accelerator;
+
+#[test]
+fn parse_accelerators() {
+    assert_eq!(
+        parse_accelerator("Ctrl+Shift+S"),
+        Some((
+            Modifiers::CONTROL | Modifiers::SHIFT,
+            Key::Character("s".to_string())
+        ))
+    );
+    assert_eq!(parse_accelerator("Alt+F4"), Some((Modifiers::ALT, Key::F4)));
+    assert_eq!(
+        parse_accelerator("Super+Space"),
+        Some((Modifiers::META, Key::Character(" ".to_string())))
+    );
+    assert_eq!(parse_accelerator("Ctrl+"), None);
+    assert_eq!(parse_accelerator("Hyper+S"), None);
+}
+
+#[test]
+fn find_items_by_shortcut() {
+    let menu = NativeMenu::new()
+        .with_submenu(
+            NativeSubmenu::new("File")
+                .with_item(NativeMenuItem::new("save", "Save").accelerator("Ctrl+S"))
+                .with_item(
+                    NativeMenuItem::new("close", "Close")
+                        .accelerator("Ctrl+W")
+                        .enabled(false),
+                ),
+        )
+        .with_submenu(
+            NativeSubmenu::new("Edit").with_submenu(
+                NativeSubmenu::new("Find")
+                    .with_item(NativeMenuItem::new("find", "Find").accelerator("Ctrl+F")),
+            ),
+        );
+
+    let shortcut = |key: &str, modifiers| {
+        menu.item_by_shortcut(&Key::Character(key.to_string()), modifiers)
+            .map(|item| item.id.as_str())
+    };
+
+    assert_eq!(shortcut("S", Modifiers::CONTROL), Some("save"));
+    assert_eq!(shortcut("s", Modifiers::CONTROL | Modifiers::SHIFT), None);
+    assert_eq!(shortcut("f", Modifiers::CONTROL), Some("find"));
+    // Disabled items are ignored
+    assert_eq!(shortcut("w", Modifiers::CONTROL), None);
+}
@@ -0,0 +1,29 @@
+use std::{
+    ffi::OsString,
+    path::PathBuf,
+};
+
+use freya_core::open_requests::OpenRequest;
+
+#[test]
+fn open_requests_from_args() {
+    let file = env!("CARGO_MANIFEST_DIR").to_string() + "/tests/freya.png";
+    let args = [
+        "--verbose",
+        "myapp://settings/theme",
+        "MyApp://about",
+        "https://freyaui.dev",
+        &file,
+        "missing.png",
+    ]
+    .map(OsString::from);
+
+    assert_eq!(
+        OpenRequest::from_args(args, &["myapp".to_string()]),
+        vec![
+            OpenRequest::Url("myapp://settings/theme".to_string()),
+            OpenRequest::Url("MyApp://about".to_string()),
+            OpenRequest::File(PathBuf::from(&file)),
+        ]
+    );
+}
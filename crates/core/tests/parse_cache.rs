@@ -0,0 +1,16 @@
+use freya_core::{
+    parsing::Parse,
+    values::CacheMode,
+};
+
+#[test]
+fn parse_cache_raster() {
+    let raster = CacheMode::parse("raster");
+    assert_eq!(raster, Ok(CacheMode::Raster));
+}
+
+#[test]
+fn parse_cache_fallback() {
+    let fallback = CacheMode::parse("hello!!");
+    assert_eq!(fallback, Ok(CacheMode::None));
+}
@@ -0,0 +1,19 @@
+use freya_core::{
+    parsing::Parse,
+    platform::CursorIcon,
+};
+
+#[test]
+fn parse_cursor_icon() {
+    let pointer = CursorIcon::parse("pointer");
+    assert_eq!(pointer, Ok(CursorIcon::Pointer));
+
+    let not_allowed = CursorIcon::parse("not-allowed");
+    assert_eq!(not_allowed, Ok(CursorIcon::NotAllowed));
+}
+
+#[test]
+fn parse_cursor_icon_invalid() {
+    let invalid = CursorIcon::parse("hello!!");
+    assert!(invalid.is_err());
+}
@@ -0,0 +1,44 @@
+use freya_core::{
+    parsing::Parse,
+    values::FontVariation,
+};
+
+#[test]
+fn parse_font_variation() {
+    let font_variation = FontVariation::parse("wght=650");
+    assert_eq!(
+        font_variation,
+        Ok(FontVariation {
+            axis: *b"wght",
+            value: 650.0,
+        })
+    );
+}
+
+#[test]
+fn parse_font_variation_with_spaces() {
+    let font_variation = FontVariation::parse(" slnt = -10.5 ");
+    assert_eq!(
+        font_variation,
+        Ok(FontVariation {
+            axis: *b"slnt",
+            value: -10.5,
+        })
+    );
+    assert_eq!(font_variation.unwrap().to_string(), "slnt=-10.5");
+}
+
+#[test]
+fn parse_custom_font_variation() {
+    let font_variation = FontVariation::parse("GRAD=88").unwrap();
+    assert_eq!(font_variation.axis(), "GRAD");
+    assert_eq!(font_variation.value, 88.0);
+}
+
+#[test]
+fn invalid_font_variation() {
+    assert!(FontVariation::parse("wght").is_err());
+    assert!(FontVariation::parse("weight=400").is_err());
+    assert!(FontVariation::parse("wght=bold").is_err());
+    assert!(FontVariation::parse("wg-t=400").is_err());
+}
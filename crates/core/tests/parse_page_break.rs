@@ -0,0 +1,17 @@
+use freya_core::{
+    parsing::Parse,
+    values::PageBreak,
+};
+
+#[test]
+fn parse_page_break() {
+    assert_eq!(PageBreak::parse("auto"), Ok(PageBreak::Auto));
+    assert_eq!(PageBreak::parse("before"), Ok(PageBreak::Before));
+    assert_eq!(PageBreak::parse("after"), Ok(PageBreak::After));
+    assert_eq!(PageBreak::parse("avoid"), Ok(PageBreak::Avoid));
+}
+
+#[test]
+fn parse_page_break_invalid() {
+    assert!(PageBreak::parse("always").is_err());
+}
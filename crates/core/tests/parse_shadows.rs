@@ -85,3 +85,15 @@ fn parse_gradient_shadow() {
         })
     );
 }
+
+#[test]
+fn display_shadow() {
+    for value in [
+        "1 2 50 25 rgb(255, 0, 0, 255)",
+        "inset 1 2 50 0 linear-gradient(0deg, rgb(255, 0, 0, 255) 0%, rgb(0, 0, 255, 255) 100%)",
+    ] {
+        let shadow = Shadow::parse(value).unwrap();
+        assert_eq!(shadow.to_string(), value);
+        assert_eq!(Shadow::parse(&shadow.to_string()), Ok(shadow));
+    }
+}
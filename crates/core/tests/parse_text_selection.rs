@@ -0,0 +1,19 @@
+use freya_core::{
+    parsing::Parse,
+    values::TextSelectionMode,
+};
+
+#[test]
+fn parse_text_selection() {
+    let enabled = TextSelectionMode::parse("enabled");
+    assert_eq!(enabled, Ok(TextSelectionMode::Enabled));
+
+    let disabled = TextSelectionMode::parse("disabled");
+    assert_eq!(disabled, Ok(TextSelectionMode::Disabled));
+}
+
+#[test]
+fn parse_text_selection_invalid() {
+    let invalid = TextSelectionMode::parse("selectable");
+    assert!(invalid.is_err());
+}
@@ -0,0 +1,35 @@
+use freya_core::{
+    parsing::Parse,
+    values::TextStroke,
+};
+use freya_engine::prelude::*;
+
+#[test]
+fn parse_text_stroke() {
+    let text_stroke = TextStroke::parse("2 rgb(255, 0, 0)");
+    assert_eq!(
+        text_stroke,
+        Ok(TextStroke {
+            width: 2.0,
+            color: Color::RED,
+        })
+    );
+}
+
+#[test]
+fn parse_text_stroke_with_alpha() {
+    let text_stroke = TextStroke::parse("1.5 rgb(0, 0, 0, 128)");
+    assert_eq!(
+        text_stroke,
+        Ok(TextStroke {
+            width: 1.5,
+            color: Color::from_argb(128, 0, 0, 0),
+        })
+    );
+}
+
+#[test]
+fn invalid_text_stroke() {
+    assert!(TextStroke::parse("red").is_err());
+    assert!(TextStroke::parse("2").is_err());
+}
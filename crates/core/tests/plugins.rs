@@ -0,0 +1,104 @@
+use freya::prelude::*;
+use freya_core::plugins::{
+    FreyaPlugin,
+    PluginEvent,
+    PluginHandle,
+    PluginsManager,
+};
+use freya_testing::prelude::*;
+
+#[derive(Debug, PartialEq)]
+struct Zoom(f32);
+
+struct DummyPlugin {
+    draws_between_layers: bool,
+}
+
+impl FreyaPlugin for DummyPlugin {
+    fn on_event(&mut self, _event: &PluginEvent, _handle: PluginHandle) {}
+
+    fn draws_between_layers(&self) -> bool {
+        self.draws_between_layers
+    }
+}
+
+#[test]
+fn plugin_event_message() {
+    let zoom = Zoom(2.);
+    let event = PluginEvent::Message(&zoom);
+    assert_eq!(event.message::<Zoom>(), Some(&Zoom(2.)));
+    assert_eq!(event.message::<String>(), None);
+
+    assert_eq!(PluginEvent::StartedUpdatingDOM.message::<Zoom>(), None);
+}
+
+#[test]
+fn plugins_draw_between_layers() {
+    let mut plugins = PluginsManager::default();
+    assert!(!plugins.draws_between_layers());
+
+    plugins.add_plugin(DummyPlugin {
+        draws_between_layers: false,
+    });
+    assert!(!plugins.draws_between_layers());
+
+    plugins.add_plugin(DummyPlugin {
+        draws_between_layers: true,
+    });
+    assert!(plugins.draws_between_layers());
+}
+
+#[tokio::test]
+async fn send_plugin_message() {
+    fn app() -> Element {
+        let platform = use_platform();
+
+        rsx!(rect {
+            width: "100%",
+            height: "100%",
+            onclick: move |_| platform.send_plugin_message(Zoom(1.5)),
+        })
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    assert!(utils.plugin_messages::<Zoom>().is_empty());
+
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.plugin_messages::<Zoom>(), vec![&Zoom(1.5)]);
+    // Messages of other types are not mixed
+    assert!(utils.plugin_messages::<String>().is_empty());
+}
+
+#[tokio::test]
+async fn render_layers_in_order() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                rect {
+                    layer: "-50",
+                    width: "50",
+                    height: "50",
+                }
+                rect {
+                    layer: "50",
+                    width: "50",
+                    height: "50",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let layers = utils.rendered_layers();
+    // Every layer is painted once, from the bottom to the top
+    assert!(layers.len() >= 3);
+    assert!(layers.windows(2).all(|pair| pair[0] < pair[1]));
+}
@@ -0,0 +1,76 @@
+use freya_core::{
+    printing::{
+        paginate,
+        PageBreakHint,
+        PageOrientation,
+        PageSize,
+        PrintOptions,
+    },
+    values::PageBreak,
+};
+
+#[test]
+fn paginate_full_pages() {
+    assert_eq!(paginate(50., 100., &[]), vec![(0., 50.)]);
+    assert_eq!(
+        paginate(250., 100., &[]),
+        vec![(0., 100.), (100., 200.), (200., 250.)]
+    );
+}
+
+#[test]
+fn paginate_forced_breaks() {
+    let hints = [
+        PageBreakHint {
+            top: 30.,
+            bottom: 60.,
+            page_break: PageBreak::Before,
+        },
+        PageBreakHint {
+            top: 60.,
+            bottom: 80.,
+            page_break: PageBreak::After,
+        },
+        // Breaking at the start of the content would leave an empty page
+        PageBreakHint {
+            top: 0.,
+            bottom: 10.,
+            page_break: PageBreak::Before,
+        },
+    ];
+    assert_eq!(
+        paginate(150., 100., &hints),
+        vec![(0., 30.), (30., 80.), (80., 150.)]
+    );
+}
+
+#[test]
+fn paginate_avoid_splitting() {
+    let hints = [
+        PageBreakHint {
+            top: 80.,
+            bottom: 120.,
+            page_break: PageBreak::Avoid,
+        },
+        // Too tall to fit in a page, so it's split anyway
+        PageBreakHint {
+            top: 130.,
+            bottom: 260.,
+            page_break: PageBreak::Avoid,
+        },
+    ];
+    assert_eq!(
+        paginate(260., 100., &hints),
+        vec![(0., 80.), (80., 180.), (180., 260.)]
+    );
+}
+
+#[test]
+fn print_options_page_size() {
+    let options = PrintOptions::new()
+        .with_page_size(PageSize::LETTER)
+        .with_orientation(PageOrientation::Landscape)
+        .with_margin(36.);
+    assert_eq!(options.page_size(), PageSize::new(792., 612.));
+    assert_eq!(options.printable_size(), PageSize::new(720., 540.));
+}
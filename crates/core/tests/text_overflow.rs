@@ -0,0 +1,72 @@
+use freya::prelude::*;
+use freya_core::values::TextOverflow;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn line_clamp() {
+    fn line_clamp_app() -> Element {
+        rsx!(
+            label {
+                line_clamp: "2",
+                "Hello, World!"
+            }
+            label {
+                line_clamp: "2",
+                text_overflow: "fade",
+                "Hello, World!"
+            }
+        )
+    }
+
+    let mut utils = launch_test(line_clamp_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+
+    let clamped = root.get(0).state().font_style;
+    assert_eq!(clamped.max_lines, Some(2));
+    assert_eq!(clamped.text_overflow, TextOverflow::Ellipsis);
+
+    let faded = root.get(1).state().font_style;
+    assert_eq!(faded.max_lines, Some(2));
+    assert_eq!(faded.text_overflow, TextOverflow::Fade);
+}
+
+#[tokio::test]
+pub async fn ontextoverflow() {
+    fn ontextoverflow_app() -> Element {
+        let mut text = use_signal(|| "Some text that is too long to fit".to_string());
+        let mut overflows = use_signal(|| None);
+
+        rsx!(
+            rect {
+                onclick: move |_| text.set("Short".to_string()),
+                label {
+                    width: "100",
+                    line_clamp: "1",
+                    ontextoverflow: move |e: TextOverflowEvent| overflows.set(Some(e.overflows)),
+                    "{text}"
+                }
+                label {
+                    "{overflows:?}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(ontextoverflow_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+
+    // The text is truncated
+    assert_eq!(root.get(1).get(0).text(), Some("Some(true)"));
+
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The text fits now
+    assert_eq!(root.get(1).get(0).text(), Some("Some(false)"));
+}
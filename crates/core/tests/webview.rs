@@ -0,0 +1,21 @@
+use freya_core::webview::{
+    webview_message_script,
+    WebViewId,
+};
+
+#[test]
+fn unique_webview_ids() {
+    assert_ne!(WebViewId::new(), WebViewId::new());
+}
+
+#[test]
+fn escape_webview_messages() {
+    assert_eq!(
+        webview_message_script("Hello"),
+        r#"window.freya && window.freya.onmessage && window.freya.onmessage("Hello");"#
+    );
+    assert_eq!(
+        webview_message_script("{\"a\": \"b\\c\"}\n\u{2028}\u{0}"),
+        r#"window.freya && window.freya.onmessage && window.freya.onmessage("{\"a\": \"b\\c\"}\n\u2028\u0000");"#
+    );
+}
@@ -0,0 +1,43 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+use torin::direction::WritingDirection;
+
+#[tokio::test]
+pub async fn writing_direction() {
+    fn writing_direction_app() -> Element {
+        rsx!(
+            rect {
+                width: "300",
+                height: "100",
+                direction: "horizontal",
+                writing_direction: "rtl",
+                padding: "0 10 0 20",
+                rect {
+                    width: "100",
+                    height: "100",
+                }
+                label {
+                    "مرحبا"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(writing_direction_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+
+    // The paddings are mirrored and the children are placed from the right
+    let first = root.get(0).area().unwrap();
+    assert_eq!(first.min_x(), 180.);
+    assert_eq!(first.width(), 100.);
+    let second = root.get(1).area().unwrap();
+    assert_eq!(second.max_x(), 180.);
+
+    // The writing direction is inherited
+    assert_eq!(
+        root.get(1).state().font_style.writing_direction,
+        WritingDirection::Rtl
+    );
+}
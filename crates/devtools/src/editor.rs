@@ -0,0 +1,136 @@
+use dioxus::prelude::*;
+use freya_components::*;
+use freya_core::event_loop_messages::EventLoopMessage;
+use freya_elements as dioxus_elements;
+use freya_hooks::use_platform;
+use freya_native_core::{
+    attributes::AttributeName,
+    NodeId,
+};
+
+/// Attribute being edited in the [AttributeEditor].
+#[derive(Clone, PartialEq, Default)]
+pub struct EditedAttribute {
+    pub name: String,
+    pub value: String,
+}
+
+/// Override the attributes of a Node in the app, to prototype styles without recompiling.
+#[allow(non_snake_case)]
+#[component]
+pub fn AttributeEditor(
+    node_id: NodeId,
+    edited_attribute: Signal<EditedAttribute>,
+    attribute_overrides: Vec<(AttributeName, String)>,
+) -> Element {
+    let platform = use_platform();
+
+    let is_unknown = {
+        let edited = edited_attribute.read();
+        !edited.name.is_empty() && edited.name.parse::<AttributeName>().is_err()
+    };
+
+    let apply = move |_| {
+        let EditedAttribute { name, value } = edited_attribute();
+        if let Ok(attribute) = name.parse() {
+            platform
+                .send(EventLoopMessage::SetAttributeOverride {
+                    node_id,
+                    attribute,
+                    value: Some(value),
+                })
+                .ok();
+        }
+    };
+
+    let reset_all = move |_| {
+        platform
+            .send(EventLoopMessage::ClearAttributeOverrides)
+            .ok();
+    };
+
+    rsx!(
+        rect {
+            width: "fill",
+            padding: "8 16",
+            spacing: "6",
+            rect {
+                direction: "horizontal",
+                cross_align: "center",
+                spacing: "6",
+                Input {
+                    width: "120",
+                    placeholder: "attribute",
+                    value: edited_attribute.read().name.clone(),
+                    onchange: move |name| edited_attribute.write().name = name,
+                }
+                Input {
+                    width: "fill",
+                    placeholder: "value",
+                    value: edited_attribute.read().value.clone(),
+                    onchange: move |value| edited_attribute.write().value = value,
+                }
+                Button {
+                    onpress: apply,
+                    label {
+                        "Apply"
+                    }
+                }
+            }
+            if is_unknown {
+                label {
+                    font_size: "13",
+                    color: "rgb(255, 100, 100)",
+                    "Unknown attribute"
+                }
+            }
+            if !attribute_overrides.is_empty() {
+                rect {
+                    direction: "horizontal",
+                    width: "fill",
+                    main_align: "space-between",
+                    cross_align: "center",
+                    label {
+                        font_size: "13",
+                        color: "rgb(180, 180, 180)",
+                        "Overrides"
+                    }
+                    Button {
+                        onpress: reset_all,
+                        label {
+                            "Reset all"
+                        }
+                    }
+                }
+                for (attribute, value) in attribute_overrides {
+                    rect {
+                        key: "{attribute.as_str()}",
+                        direction: "horizontal",
+                        width: "fill",
+                        main_align: "space-between",
+                        cross_align: "center",
+                        label {
+                            font_size: "14",
+                            color: "rgb(252, 181, 172)",
+                            "{attribute.as_str()}: {value}"
+                        }
+                        Button {
+                            onpress: move |_| {
+                                platform
+                                    .send(EventLoopMessage::SetAttributeOverride {
+                                        node_id,
+                                        attribute,
+                                        value: None,
+                                    })
+                                    .ok();
+                            },
+                            label {
+                                "Reset"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
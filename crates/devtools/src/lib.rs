@@ -23,22 +23,28 @@ use freya_hooks::{
 use freya_native_core::NodeId;
 use freya_winit::devtools::{
     DevtoolsReceiver,
+    DevtoolsScope,
     HoveredNode,
+    TimelineReceiver,
 };
 use state::{
     DevtoolsChannel,
     DevtoolsState,
 };
 
+mod editor;
 mod hooks;
 mod node;
 mod property;
+pub mod remote;
 mod state;
 mod tabs;
 
 use tabs::{
+    accessibility::*,
     layout::*,
     style::*,
+    timeline::*,
     tree::*,
 };
 
@@ -46,6 +52,7 @@ use tabs::{
 pub fn with_devtools(
     root: fn() -> Element,
     devtools_receiver: DevtoolsReceiver,
+    timeline_receiver: TimelineReceiver,
     hovered_node: HoveredNode,
 ) -> VirtualDom {
     VirtualDom::new_with_props(
@@ -53,6 +60,7 @@ pub fn with_devtools(
         AppWithDevtoolsProps {
             root,
             devtools_receiver,
+            timeline_receiver,
             hovered_node,
         },
     )
@@ -62,6 +70,7 @@ pub fn with_devtools(
 struct AppWithDevtoolsProps {
     root: fn() -> Element,
     devtools_receiver: DevtoolsReceiver,
+    timeline_receiver: TimelineReceiver,
     hovered_node: HoveredNode,
 }
 
@@ -76,6 +85,7 @@ fn AppWithDevtools(props: AppWithDevtoolsProps) -> Element {
     #[allow(non_snake_case)]
     let Root = props.root;
     let devtools_receiver = props.devtools_receiver;
+    let timeline_receiver = props.timeline_receiver;
     let hovered_node = props.hovered_node;
 
     rsx!(
@@ -97,6 +107,7 @@ fn AppWithDevtools(props: AppWithDevtoolsProps) -> Element {
                         ThemeProvider {
                             DevTools {
                                 devtools_receiver,
+                                timeline_receiver,
                                 hovered_node
                             }
                         }
@@ -110,6 +121,7 @@ fn AppWithDevtools(props: AppWithDevtoolsProps) -> Element {
 #[derive(Props, Clone)]
 pub struct DevToolsProps {
     devtools_receiver: DevtoolsReceiver,
+    timeline_receiver: TimelineReceiver,
     hovered_node: HoveredNode,
 }
 
@@ -121,10 +133,12 @@ impl PartialEq for DevToolsProps {
 
 #[allow(non_snake_case)]
 pub fn DevTools(props: DevToolsProps) -> Element {
+    use_context_provider(|| DevtoolsScope);
     use_init_theme(|| DARK_THEME);
     use_init_radio_station::<DevtoolsState, DevtoolsChannel>(|| DevtoolsState {
         hovered_node: props.hovered_node.clone(),
         devtools_receiver: props.devtools_receiver.clone(),
+        timeline_receiver: props.timeline_receiver.clone(),
         devtools_tree: HashSet::default(),
     });
 
@@ -157,6 +171,28 @@ pub fn DevtoolsBar() -> Element {
                     }
                 }
             }
+            Link {
+                to: Route::AccessibilityInspector { },
+                ActivableRoute {
+                    route: Route::AccessibilityInspector { },
+                    Tab {
+                        label {
+                            "Accessibility"
+                        }
+                    }
+                }
+            }
+            Link {
+                to: Route::EventsTimeline { },
+                ActivableRoute {
+                    route: Route::EventsTimeline { },
+                    Tab {
+                        label {
+                            "Events"
+                        }
+                    }
+                }
+            }
         }
 
         NativeRouter {
@@ -181,6 +217,10 @@ pub enum Route {
                 #[end_layout]
             #[end_nest]
         #[end_layout]
+        #[route("/accessibility")]
+        AccessibilityInspector {},
+        #[route("/events")]
+        EventsTimeline {},
     #[end_layout]
     #[route("/..route")]
     PageNotFound { },
@@ -0,0 +1,328 @@
+use std::{
+    collections::VecDeque,
+    io::{
+        self,
+        BufRead,
+        BufReader,
+        Write,
+    },
+    net::TcpStream,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use dioxus::prelude::*;
+use freya_components::*;
+use freya_core::custom_attributes::dynamic_bytes;
+use freya_elements as dioxus_elements;
+use freya_hooks::{
+    theme_with,
+    ButtonThemeWith,
+};
+use tokio::sync::mpsc::{
+    unbounded_channel,
+    UnboundedReceiver,
+};
+
+use super::protocol::{
+    decode_message,
+    encode_message,
+    ClientMessage,
+    RemoteArea,
+    RemoteEvent,
+    RemoteNode,
+    ServerMessage,
+};
+
+/// Maximum amount of events kept in the log.
+const MAX_EVENTS: usize = 200;
+
+/// Connection to an app inspected with the [RemoteDevtoolsPlugin](super::RemoteDevtoolsPlugin).
+#[derive(Clone)]
+pub struct RemoteDevtoolsClient {
+    stream: Arc<Mutex<TcpStream>>,
+}
+
+impl RemoteDevtoolsClient {
+    /// Connect to the app listening in the given address,
+    /// the received messages are sent to the returned channel until it disconnects.
+    pub fn connect(address: &str) -> io::Result<(Self, UnboundedReceiver<ServerMessage>)> {
+        let stream = TcpStream::connect(address)?;
+        let reader = stream.try_clone()?;
+        let (sender, receiver) = unbounded_channel();
+
+        thread::spawn(move || {
+            for line in BufReader::new(reader).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if let Some(message) = decode_message(&line) {
+                    if sender.send(message).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok((
+            Self {
+                stream: Arc::new(Mutex::new(stream)),
+            },
+            receiver,
+        ))
+    }
+
+    /// Send a message to the inspected app.
+    pub fn send(&self, message: &ClientMessage) -> io::Result<()> {
+        self.stream
+            .lock()
+            .unwrap()
+            .write_all(encode_message(message).as_bytes())
+    }
+}
+
+/// Devtools of an app running in another process, connected through the [RemoteDevtoolsPlugin](super::RemoteDevtoolsPlugin).
+///
+/// Shows the Nodes of the app, the layout and attributes of the selected one, the received events and screenshots of the window.
+/// It connects again every second while the app is not reachable.
+///
+/// ```rust,ignore
+/// use freya::prelude::*;
+/// # use freya_devtools::remote::*;
+/// fn app() -> Element {
+///     rsx!(RemoteDevTools {
+///         address: DEFAULT_REMOTE_DEVTOOLS_ADDRESS.to_string()
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn RemoteDevTools(address: String) -> Element {
+    let mut client = use_signal::<Option<RemoteDevtoolsClient>>(|| None);
+    let mut nodes = use_signal(Vec::<RemoteNode>::new);
+    let mut events = use_signal(VecDeque::<RemoteEvent>::new);
+    let mut screenshot = use_signal(|| None);
+    let mut selected_node = use_signal::<Option<String>>(|| None);
+
+    use_hook(move || {
+        spawn(async move {
+            loop {
+                let connection = tokio::task::spawn_blocking({
+                    let address = address.clone();
+                    move || RemoteDevtoolsClient::connect(&address)
+                })
+                .await;
+
+                let Ok(Ok((new_client, mut receiver))) = connection else {
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    continue;
+                };
+
+                client.set(Some(new_client));
+
+                while let Some(message) = receiver.recv().await {
+                    match message {
+                        ServerMessage::Nodes(new_nodes) => nodes.set(new_nodes),
+                        ServerMessage::Event(event) => {
+                            let mut events = events.write();
+                            if events.len() == MAX_EVENTS {
+                                events.pop_front();
+                            }
+                            events.push_back(event);
+                        }
+                        ServerMessage::Screenshot(png) => {
+                            screenshot.set(Some(dynamic_bytes(png)));
+                        }
+                    }
+                }
+
+                client.set(None);
+            }
+        });
+    });
+
+    let is_connected = client.read().is_some();
+    let selected = selected_node
+        .read()
+        .as_ref()
+        .and_then(|id| nodes.read().iter().find(|node| &node.id == id).cloned());
+
+    let request_screenshot = move |_| {
+        if let Some(client) = &*client.read() {
+            client.send(&ClientMessage::RequestScreenshot).ok();
+        }
+    };
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            background: "rgb(40, 40, 40)",
+            color: "white",
+            content: "flex",
+            rect {
+                direction: "horizontal",
+                width: "fill",
+                padding: "8",
+                cross_align: "center",
+                main_align: "space-between",
+                label {
+                    if is_connected {
+                        "Connected, {nodes.read().len()} nodes"
+                    } else {
+                        "Waiting for the app..."
+                    }
+                }
+                Button {
+                    onpress: request_screenshot,
+                    label {
+                        "Screenshot"
+                    }
+                }
+            }
+            ResizableContainer {
+                direction: "horizontal",
+                ResizablePanel {
+                    initial_size: 50.,
+                    ResizableContainer {
+                        direction: "vertical",
+                        ResizablePanel {
+                            initial_size: 65.,
+                            ScrollView {
+                                for node in nodes.read().iter() {
+                                    RemoteNodeElement {
+                                        key: "{node.id}",
+                                        node: node.clone(),
+                                        is_selected: selected_node.read().as_ref() == Some(&node.id),
+                                        onselected: move |id| selected_node.set(Some(id)),
+                                    }
+                                }
+                            }
+                        }
+                        ResizableHandle { }
+                        ResizablePanel {
+                            initial_size: 35.,
+                            ScrollView {
+                                for (i, event) in events.read().iter().enumerate().rev() {
+                                    label {
+                                        key: "{i}",
+                                        font_size: "13",
+                                        max_lines: "1",
+                                        text_overflow: "ellipsis",
+                                        padding: "2 8",
+                                        "{event.name} {event.data}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                ResizableHandle { }
+                ResizablePanel {
+                    initial_size: 50.,
+                    ScrollView {
+                        if let Some(node) = selected {
+                            RemoteNodeInspector {
+                                node
+                            }
+                        }
+                        if let Some(screenshot) = screenshot.read().clone() {
+                            image {
+                                width: "fill",
+                                image_data: screenshot,
+                                aspect_ratio: "min",
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn RemoteNodeElement(
+    node: RemoteNode,
+    is_selected: bool,
+    onselected: EventHandler<String>,
+) -> Element {
+    let background = if is_selected {
+        "rgb(25, 25, 25)"
+    } else {
+        "none"
+    };
+    let margin_left = node.height * 10;
+    let id = node.id.clone();
+
+    rsx!(
+        OutlineButton {
+            theme: theme_with!(ButtonTheme {
+                width: "100%".into(),
+                height: "27".into(),
+                border_fill: "none".into(),
+                background: background.into(),
+                hover_background: "rgb(30, 30, 30)".into(),
+            }),
+            onpress: move |_| onselected.call(id.clone()),
+            label {
+                offset_x: "{margin_left}",
+                font_size: "14",
+                color: "white",
+                "{node.tag}, id: {node.id}"
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn RemoteNodeInspector(node: RemoteNode) -> Element {
+    let RemoteArea {
+        x,
+        y,
+        width,
+        height,
+    } = node.area;
+    let inner_area = node.inner_area;
+
+    rsx!(
+        rect {
+            width: "fill",
+            padding: "8",
+            spacing: "4",
+            label {
+                font_size: "16",
+                "{node.tag}, id: {node.id}"
+            }
+            label {
+                "Area: {x},{y} {width}x{height}"
+            }
+            label {
+                "Inner area: {inner_area.x},{inner_area.y} {inner_area.width}x{inner_area.height}"
+            }
+            for (name, value) in node.attributes {
+                paragraph {
+                    key: "{name}{value}",
+                    width: "fill",
+                    text {
+                        color: "rgb(102, 163, 217)",
+                        "{name}"
+                    }
+                    text {
+                        color: "rgb(215, 215, 215)",
+                        ": "
+                    }
+                    text {
+                        color: "rgb(252,181,172)",
+                        "{value}"
+                    }
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,12 @@
+//! Inspect apps running in other processes, even release builds.
+//!
+//! The app listens for connections with the [RemoteDevtoolsPlugin] and the
+//! [RemoteDevTools] component shows what it receives.
+
+mod client;
+pub mod protocol;
+mod server;
+
+pub use client::*;
+pub use protocol::DEFAULT_REMOTE_DEVTOOLS_ADDRESS;
+pub use server::*;
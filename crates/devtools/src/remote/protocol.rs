@@ -0,0 +1,235 @@
+use freya_core::{
+    dom::FreyaDOM,
+    events::PlatformEvent,
+    node::get_node_state,
+};
+use freya_native_core::prelude::NodeImmutable;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use torin::geometry::Area;
+
+use crate::{
+    tabs::style::editable_value,
+    NodeIdSerializer,
+};
+
+/// Address where the remote devtools listen by default.
+pub const DEFAULT_REMOTE_DEVTOOLS_ADDRESS: &str = "127.0.0.1:7878";
+
+/// Area of a Node, in logical pixels.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub struct RemoteArea {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl RemoteArea {
+    fn new(area: &Area, scale_factor: f32) -> Self {
+        Self {
+            x: area.min_x() / scale_factor,
+            y: area.min_y() / scale_factor,
+            width: area.width() / scale_factor,
+            height: area.height() / scale_factor,
+        }
+    }
+}
+
+/// Node of the inspected app.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RemoteNode {
+    /// Serialized [NodeId](freya_native_core::NodeId), see [NodeIdSerializer].
+    pub id: String,
+    pub parent_id: Option<String>,
+    pub children_len: usize,
+    pub tag: String,
+    pub height: u16,
+    pub area: RemoteArea,
+    pub inner_area: RemoteArea,
+    /// Computed attributes and their values.
+    pub attributes: Vec<(String, String)>,
+}
+
+/// Event received by the inspected app.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RemoteEvent {
+    pub name: String,
+    pub data: String,
+}
+
+impl From<&PlatformEvent> for RemoteEvent {
+    fn from(event: &PlatformEvent) -> Self {
+        let name: &str = event.name.into();
+        Self {
+            name: name.to_string(),
+            data: format!("{:?}", event.data),
+        }
+    }
+}
+
+/// Message sent from the inspected app to the remote devtools.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum ServerMessage {
+    /// The Nodes of the app changed.
+    Nodes(Vec<RemoteNode>),
+    /// The app received an event.
+    Event(RemoteEvent),
+    /// PNG screenshot of the window, sent as base64.
+    Screenshot(#[serde(with = "base64_bytes")] Vec<u8>),
+}
+
+/// Message sent from the remote devtools to the inspected app.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum ClientMessage {
+    /// Ask for a [ServerMessage::Nodes].
+    RequestNodes,
+    /// Ask for a [ServerMessage::Screenshot].
+    RequestScreenshot,
+}
+
+/// (De)serialize bytes as a base64 string, much shorter than a JSON array of numbers.
+mod base64_bytes {
+    use base64::{
+        engine::general_purpose::STANDARD,
+        Engine,
+    };
+    use serde::{
+        de::Error,
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        STANDARD.decode(encoded).map_err(D::Error::custom)
+    }
+}
+
+/// Encode a message as a line of JSON.
+pub fn encode_message(message: &impl Serialize) -> String {
+    let mut line = serde_json::to_string(message).unwrap_or_default();
+    line.push('\n');
+    line
+}
+
+/// Decode a line of JSON into a message.
+pub fn decode_message<'a, T: Deserialize<'a>>(line: &'a str) -> Option<T> {
+    serde_json::from_str(line).ok()
+}
+
+/// Get the Nodes of the DOM that have a layout.
+pub fn remote_nodes(fdom: &FreyaDOM, scale_factor: f32) -> Vec<RemoteNode> {
+    let rdom = fdom.rdom();
+    let layout = fdom.layout();
+
+    let mut nodes = Vec::new();
+
+    rdom.traverse_depth_first(|node| {
+        let Some(layout_node) = layout.get(node.id()) else {
+            return;
+        };
+        let Some(tag) = node.node_type().tag().copied() else {
+            return;
+        };
+        let state = get_node_state(&node);
+        nodes.push(RemoteNode {
+            id: node.id().serialize(),
+            parent_id: node.parent_id().map(|parent_id| parent_id.serialize()),
+            children_len: node
+                .children()
+                .iter()
+                .filter(|node| layout.get(node.id()).is_some())
+                .count(),
+            tag: tag.to_string(),
+            height: node.height(),
+            area: RemoteArea::new(&layout_node.area, scale_factor),
+            inner_area: RemoteArea::new(&layout_node.inner_area, scale_factor),
+            attributes: state
+                .attributes()
+                .iter()
+                .map(|(name, attribute)| (name.to_string(), editable_value(attribute)))
+                .collect(),
+        });
+    });
+
+    nodes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_server_messages() {
+        let messages = [
+            ServerMessage::Nodes(vec![RemoteNode {
+                id: "1-0".to_string(),
+                parent_id: None,
+                children_len: 0,
+                tag: "rect".to_string(),
+                height: 1,
+                area: RemoteArea {
+                    x: 10.,
+                    y: 20.,
+                    width: 100.,
+                    height: 50.,
+                },
+                inner_area: RemoteArea::default(),
+                attributes: vec![("background".to_string(), "red".to_string())],
+            }]),
+            ServerMessage::Event(RemoteEvent {
+                name: "click".to_string(),
+                data: "Mouse".to_string(),
+            }),
+            ServerMessage::Screenshot(vec![137, 80, 78, 71, 0, 255]),
+        ];
+
+        for message in messages {
+            let line = encode_message(&message);
+            // Every message is a single line
+            assert_eq!(line.matches('\n').count(), 1);
+            assert!(line.ends_with('\n'));
+            assert_eq!(
+                decode_message::<ServerMessage>(line.trim_end()),
+                Some(message)
+            );
+        }
+    }
+
+    #[test]
+    fn encode_screenshot_as_base64() {
+        let line = encode_message(&ServerMessage::Screenshot(b"png".to_vec()));
+        assert_eq!(line, "{\"Screenshot\":\"cG5n\"}\n");
+
+        // Invalid base64 is rejected
+        assert_eq!(
+            decode_message::<ServerMessage>("{\"Screenshot\":\"*\"}"),
+            None
+        );
+    }
+
+    #[test]
+    fn encode_client_messages() {
+        for message in [
+            ClientMessage::RequestNodes,
+            ClientMessage::RequestScreenshot,
+        ] {
+            let line = encode_message(&message);
+            assert_eq!(
+                decode_message::<ClientMessage>(line.trim_end()),
+                Some(message)
+            );
+        }
+
+        assert_eq!(decode_message::<ClientMessage>("not json"), None);
+        assert_eq!(decode_message::<ClientMessage>("\"Unknown\""), None);
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    io::{
+        self,
+        BufRead,
+        BufReader,
+        Write,
+    },
+    net::{
+        Shutdown,
+        TcpListener,
+        TcpStream,
+        ToSocketAddrs,
+    },
+    sync::{
+        mpsc::{
+            self,
+            SyncSender,
+        },
+        Arc,
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    plugins::{
+        FreyaPlugin,
+        PluginEvent,
+        PluginHandle,
+    },
+};
+use tokio::sync::oneshot;
+
+use super::protocol::{
+    decode_message,
+    encode_message,
+    remote_nodes,
+    ClientMessage,
+    RemoteEvent,
+    ServerMessage,
+};
+
+/// Lines waiting to be sent to a client before it's considered too slow and disconnected.
+const CLIENT_QUEUE_LEN: usize = 64;
+
+/// Time to wait for a client to receive a line before disconnecting it.
+const CLIENT_WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Connected client, its lines are written by its own thread so a slow client doesn't block the app.
+struct Client {
+    sender: SyncSender<Arc<str>>,
+}
+
+impl Client {
+    fn new(mut stream: TcpStream) -> Self {
+        let (sender, receiver) = mpsc::sync_channel::<Arc<str>>(CLIENT_QUEUE_LEN);
+        thread::spawn(move || {
+            stream.set_write_timeout(Some(CLIENT_WRITE_TIMEOUT)).ok();
+            for line in receiver {
+                if stream.write_all(line.as_bytes()).is_err() {
+                    break;
+                }
+            }
+            // Also stops the thread reading the requests of the client
+            stream.shutdown(Shutdown::Both).ok();
+        });
+        Self { sender }
+    }
+}
+
+#[derive(Default)]
+struct Connections {
+    clients: Vec<Client>,
+    handle: Option<PluginHandle>,
+}
+
+impl Connections {
+    /// Queue a line for every client, forgetting about the disconnected ones and the ones that fell behind.
+    fn broadcast(&mut self, line: &str) {
+        let line = Arc::<str>::from(line);
+        self.clients
+            .retain(|client| client.sender.try_send(line.clone()).is_ok());
+    }
+}
+
+/// Request of a client, forwarded to the plugin through the event loop.
+struct RemoteRequest(ClientMessage);
+
+/// Plugin that lets remote devtools inspect the app over TCP, even in release builds.
+///
+/// The Nodes, their layout, the received events and screenshots of the window are sent
+/// as lines of JSON to the connected clients, like the [RemoteDevTools](super::RemoteDevTools) component.
+///
+/// With the `remote-devtools` feature of `freya`, it's also enabled by
+/// setting the `FREYA_REMOTE_DEVTOOLS` environment variable to the address where to listen.
+///
+/// ```rust,ignore
+/// use freya::prelude::*;
+/// # use freya_devtools::remote::*;
+/// # fn app() -> Element { rsx!(rect {}) }
+/// fn main() {
+///     launch_cfg(
+///         app,
+///         LaunchConfig::<()>::new()
+///             .with_plugin(RemoteDevtoolsPlugin::bind(DEFAULT_REMOTE_DEVTOOLS_ADDRESS).unwrap()),
+///     );
+/// }
+/// ```
+pub struct RemoteDevtoolsPlugin {
+    connections: Arc<Mutex<Connections>>,
+    /// Whether the Nodes changed since they were sent.
+    nodes_changed: bool,
+    scale_factor: f32,
+}
+
+impl RemoteDevtoolsPlugin {
+    /// Listen for remote devtools in the given address.
+    pub fn bind(address: impl ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        let connections = Arc::new(Mutex::new(Connections::default()));
+
+        thread::spawn({
+            let connections = connections.clone();
+            move || {
+                for stream in listener.incoming().flatten() {
+                    let Ok(reader) = stream.try_clone() else {
+                        continue;
+                    };
+
+                    {
+                        let mut connections = connections.lock().unwrap();
+                        connections.clients.push(Client::new(stream));
+                        // Render again so the Nodes are sent to the new client
+                        if let Some(handle) = &connections.handle {
+                            handle.send_message(RemoteRequest(ClientMessage::RequestNodes));
+                        }
+                    }
+
+                    let connections = connections.clone();
+                    thread::spawn(move || {
+                        for line in BufReader::new(reader).lines() {
+                            let Ok(line) = line else {
+                                break;
+                            };
+                            let Some(message) = decode_message::<ClientMessage>(&line) else {
+                                continue;
+                            };
+                            if let Some(handle) = &connections.lock().unwrap().handle {
+                                handle.send_message(RemoteRequest(message));
+                            }
+                        }
+                    });
+                }
+            }
+        });
+
+        Ok(Self {
+            connections,
+            nodes_changed: true,
+            scale_factor: 1.0,
+        })
+    }
+
+    fn broadcast(&self, message: &ServerMessage) {
+        let mut connections = self.connections.lock().unwrap();
+        if !connections.clients.is_empty() {
+            connections.broadcast(&encode_message(message));
+        }
+    }
+}
+
+impl FreyaPlugin for RemoteDevtoolsPlugin {
+    fn on_event(&mut self, event: &PluginEvent, handle: PluginHandle) {
+        match event {
+            PluginEvent::WindowCreated(_) => {
+                self.connections.lock().unwrap().handle = Some(handle);
+            }
+            PluginEvent::FinishedUpdatingDOM | PluginEvent::FinishedMeasuringLayout(_) => {
+                self.nodes_changed = true;
+            }
+            PluginEvent::AfterLayout { scale_factor, .. } => {
+                self.scale_factor = *scale_factor;
+            }
+            PluginEvent::AfterRender { freya_dom, .. } if self.nodes_changed => {
+                let mut connections = self.connections.lock().unwrap();
+                // Nobody is listening, so there is no need to collect the Nodes yet
+                if connections.clients.is_empty() {
+                    return;
+                }
+                self.nodes_changed = false;
+                connections.broadcast(&encode_message(&ServerMessage::Nodes(remote_nodes(
+                    freya_dom,
+                    self.scale_factor,
+                ))));
+            }
+            PluginEvent::PlatformEvent(platform_event) => {
+                self.broadcast(&ServerMessage::Event(RemoteEvent::from(*platform_event)));
+            }
+            PluginEvent::Message(_) => {
+                let Some(RemoteRequest(request)) = event.message() else {
+                    return;
+                };
+                match request {
+                    ClientMessage::RequestNodes => {
+                        self.nodes_changed = true;
+                        handle.send_event_loop_event(EventLoopMessage::RequestFullRerender);
+                    }
+                    ClientMessage::RequestScreenshot => {
+                        let (sender, receiver) = oneshot::channel();
+                        handle.send_event_loop_event(EventLoopMessage::CaptureWindow(sender));
+
+                        let connections = self.connections.clone();
+                        thread::spawn(move || {
+                            if let Ok(Some(png)) = receiver.blocking_recv() {
+                                let line = encode_message(&ServerMessage::Screenshot(png.to_vec()));
+                                connections.lock().unwrap().broadcast(&line);
+                            }
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
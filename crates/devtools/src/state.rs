@@ -5,11 +5,13 @@ use freya_native_core::prelude::NodeId;
 use freya_winit::devtools::{
     DevtoolsReceiver,
     HoveredNode,
+    TimelineReceiver,
 };
 
 pub struct DevtoolsState {
     pub(crate) hovered_node: HoveredNode,
     pub(crate) devtools_receiver: DevtoolsReceiver,
+    pub(crate) timeline_receiver: TimelineReceiver,
     pub(crate) devtools_tree: HashSet<NodeId>,
 }
 
@@ -17,6 +19,7 @@ pub struct DevtoolsState {
 pub enum DevtoolsChannel {
     Global,
     UpdatedDOM,
+    UpdatedTimeline,
 }
 
 impl RadioChannel<DevtoolsState> for DevtoolsChannel {}
@@ -0,0 +1,273 @@
+use accesskit::Role;
+use dioxus::prelude::*;
+use dioxus_radio::prelude::*;
+use dioxus_router::hooks::use_navigator;
+use freya_components::*;
+use freya_core::{
+    accessibility::AccessibilityFocusStrategy,
+    event_loop_messages::EventLoopMessage,
+    types::AccessibilityId,
+};
+use freya_elements as dioxus_elements;
+use freya_hooks::use_platform;
+use freya_native_core::NodeId;
+use freya_winit::devtools::{
+    AccessibilityNodeInfo,
+    NodeInfo,
+};
+
+use crate::{
+    state::DevtoolsChannel,
+    NodeIdSerializer,
+    Route,
+};
+
+/// Node of the accessibility tree, as shown in the [AccessibilityInspector].
+#[derive(Clone, PartialEq)]
+struct AccessibilityItem {
+    node_id: NodeId,
+    height: u16,
+    info: AccessibilityNodeInfo,
+    is_missing_label: bool,
+}
+
+/// Roles that assistive technologies can't describe without a name.
+fn requires_name(info: &AccessibilityNodeInfo) -> bool {
+    info.is_focusable
+        || matches!(
+            info.role,
+            Role::Button
+                | Role::CheckBox
+                | Role::Switch
+                | Role::RadioButton
+                | Role::Link
+                | Role::TextInput
+                | Role::MultilineTextInput
+                | Role::Slider
+                | Role::ComboBox
+                | Role::MenuItem
+                | Role::Tab
+                | Role::Image
+        )
+}
+
+fn has_name(info: &AccessibilityNodeInfo) -> bool {
+    info.name
+        .as_ref()
+        .is_some_and(|name| !name.trim().is_empty())
+}
+
+/// Get the Nodes that are meaningful to assistive technologies, flagging those missing a name.
+///
+/// Controls can also be named by the text of their descendants, except images.
+fn accessibility_items(nodes: &[NodeInfo]) -> Vec<AccessibilityItem> {
+    nodes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, node)| {
+            let info = node.accessibility.clone()?;
+            if info.role == Role::GenericContainer && !info.is_focusable {
+                return None;
+            }
+            let is_missing_label = requires_name(&info) && !has_name(&info) && {
+                let named_descendant = nodes[i + 1..]
+                    .iter()
+                    .take_while(|descendant| descendant.height > node.height)
+                    .filter_map(|descendant| descendant.accessibility.as_ref())
+                    .any(|descendant| {
+                        matches!(descendant.role, Role::Label | Role::Paragraph)
+                            && has_name(descendant)
+                    });
+                info.role == Role::Image || !named_descendant
+            };
+            Some(AccessibilityItem {
+                node_id: node.id,
+                height: node.height,
+                info,
+                is_missing_label,
+            })
+        })
+        .collect()
+}
+
+/// Accessibility tree of the app, to audit the roles, names and focus order of the Nodes.
+#[allow(non_snake_case)]
+#[component]
+pub fn AccessibilityInspector() -> Element {
+    let mut radio = use_radio(DevtoolsChannel::UpdatedDOM);
+    let platform = use_platform();
+    let focused_id = use_context::<Signal<AccessibilityId>>();
+    let mut simulated_focus = use_signal::<Option<AccessibilityId>>(|| None);
+
+    use_hook(move || {
+        spawn(async move {
+            let mut devtools_receiver = radio.read().devtools_receiver.clone();
+            while devtools_receiver.changed().await.is_ok() {
+                radio.write_channel(DevtoolsChannel::UpdatedDOM);
+            }
+        });
+    });
+
+    let items = accessibility_items(&radio.read().devtools_receiver.borrow());
+    let missing_labels = items.iter().filter(|item| item.is_missing_label).count();
+    let min_height = items
+        .iter()
+        .map(|item| item.height)
+        .min()
+        .unwrap_or_default();
+
+    // Focusing from the Devtools would move the focus to its own buttons,
+    // so the simulated navigation remembers where it was
+    let mut navigate = move |strategy: AccessibilityFocusStrategy| {
+        // Same order followed when pressing Tab in the app
+        let focus_order = accessibility_items(&radio.read().devtools_receiver.borrow())
+            .into_iter()
+            .filter(|item| item.info.is_focusable)
+            .map(|item| (item.node_id, item.info.id))
+            .collect::<Vec<_>>();
+        let position = (*simulated_focus.peek())
+            .and_then(|id| focus_order.iter().position(|(_, a11y_id)| *a11y_id == id));
+        let last = focus_order.len().saturating_sub(1);
+        let next = match (strategy, position) {
+            (AccessibilityFocusStrategy::Backward, Some(position)) if position > 0 => position - 1,
+            (AccessibilityFocusStrategy::Backward, _) => last,
+            (_, Some(position)) if position < last => position + 1,
+            _ => 0,
+        };
+        let Some((node_id, a11y_id)) = focus_order.get(next).copied() else {
+            return;
+        };
+        simulated_focus.set(Some(a11y_id));
+        if let Some(hovered_node) = radio.read().hovered_node.as_ref() {
+            hovered_node.lock().unwrap().replace(node_id);
+        }
+        platform
+            .send(EventLoopMessage::FocusAccessibilityNode(
+                AccessibilityFocusStrategy::Node(a11y_id),
+            ))
+            .ok();
+        platform.send(EventLoopMessage::RequestFullRerender).ok();
+    };
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            padding: "6 0 0 0",
+            content: "flex",
+            rect {
+                direction: "horizontal",
+                width: "fill",
+                padding: "0 10",
+                main_align: "space-between",
+                cross_align: "center",
+                label {
+                    "{items.len()} nodes, {missing_labels} missing labels"
+                }
+                rect {
+                    direction: "horizontal",
+                    spacing: "6",
+                    Button {
+                        onpress: move |_| navigate(AccessibilityFocusStrategy::Backward),
+                        label {
+                            "Previous"
+                        }
+                    }
+                    Button {
+                        onpress: move |_| navigate(AccessibilityFocusStrategy::Forward),
+                        label {
+                            "Next"
+                        }
+                    }
+                }
+            }
+            ScrollView {
+                height: "flex(1)",
+                show_scrollbar: true,
+                for item in items {
+                    AccessibilityRow {
+                        key: "{item.node_id:?}",
+                        is_focused: item.info.id == *focused_id.read() || Some(item.info.id) == *simulated_focus.read(),
+                        margin_left: (item.height - min_height) as f32 * 10.,
+                        item,
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn AccessibilityRow(item: AccessibilityItem, is_focused: bool, margin_left: f32) -> Element {
+    let navigator = use_navigator();
+    let platform = use_platform();
+    let radio = use_radio(DevtoolsChannel::UpdatedDOM);
+    let AccessibilityItem {
+        node_id,
+        info,
+        is_missing_label,
+        ..
+    } = item;
+
+    let onclick = move |_| {
+        if let Some(hovered_node) = radio.read().hovered_node.as_ref() {
+            hovered_node.lock().unwrap().replace(node_id);
+            platform.send(EventLoopMessage::RequestFullRerender).ok();
+        }
+        navigator.push(Route::NodeInspectorStyle {
+            node_id: node_id.serialize(),
+        });
+    };
+
+    let background = if is_focused {
+        "rgb(25, 25, 25)"
+    } else {
+        "none"
+    };
+    let role = format!("{:?}", info.role);
+
+    rsx!(
+        rect {
+            onclick,
+            width: "fill",
+            padding: "4 10",
+            background,
+            direction: "horizontal",
+            cross_align: "center",
+            spacing: "8",
+            label {
+                offset_x: "{margin_left}",
+                font_size: "14",
+                color: "rgb(102, 163, 217)",
+                "{role}"
+            }
+            if let Some(name) = info.name {
+                label {
+                    offset_x: "{margin_left}",
+                    font_size: "14",
+                    max_lines: "1",
+                    text_overflow: "ellipsis",
+                    color: "rgb(252, 181, 172)",
+                    "\"{name}\""
+                }
+            }
+            if info.is_focusable {
+                label {
+                    offset_x: "{margin_left}",
+                    font_size: "13",
+                    color: "rgb(160, 160, 160)",
+                    "focusable"
+                }
+            }
+            if is_missing_label {
+                label {
+                    offset_x: "{margin_left}",
+                    font_size: "13",
+                    color: "rgb(255, 100, 100)",
+                    "Missing label"
+                }
+            }
+        }
+    )
+}
@@ -1,3 +1,5 @@
+pub mod accessibility;
 pub mod layout;
 pub mod style;
+pub mod timeline;
 pub mod tree;
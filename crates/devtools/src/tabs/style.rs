@@ -10,6 +10,10 @@ use freya_elements::{
 use freya_native_core::NodeId;
 
 use crate::{
+    editor::{
+        AttributeEditor,
+        EditedAttribute,
+    },
     hooks::use_node_info,
     property::{
         BorderProperty,
@@ -26,88 +30,88 @@ use crate::{
 #[component]
 pub fn NodeInspectorStyle(node_id: String) -> Element {
     let node_id = NodeId::deserialize(&node_id);
+    let mut edited_attribute = use_signal(EditedAttribute::default);
     let Some(node) = use_node_info(node_id) else {
         return Ok(VNode::placeholder());
     };
 
     rsx!(
-        ScrollView {
-            show_scrollbar: true,
-            height : "fill",
+        rect {
             width: "fill",
-            {node.state.attributes().into_iter().enumerate().filter_map(|(i, (name, attr))| {
-                let background = if i % 2 == 0 {
-                    "rgb(255, 255, 255, 0.1)"
-                } else {
-                    "transparent"
-                };
+            height: "fill",
+            content: "flex",
+            AttributeEditor {
+                node_id,
+                edited_attribute,
+                attribute_overrides: node.attribute_overrides,
+            }
+            ScrollView {
+                show_scrollbar: true,
+                height : "flex(1)",
+                width: "fill",
+                {node.state.attributes().into_iter().enumerate().filter_map(|(i, (name, attr))| {
+                    let background = if i % 2 == 0 {
+                        "rgb(255, 255, 255, 0.1)"
+                    } else {
+                        "transparent"
+                    };
 
-                let el = match attr {
-                    AttributeType::Measure(measure) => {
-                        rsx!{
-                            Property {
-                                key: "{i}",
-                                name: "{name}",
-                                value: measure.to_string()
-                            }
-                        }
-                    }
-                    AttributeType::OptionalMeasure(measure) => {
-                        rsx!{
-                            Property {
-                                key: "{i}",
-                                name: "{name}",
-                                value: measure.map(|measure| measure.to_string()).unwrap_or_else(|| "inherit".to_string())
-                            }
-                        }
-                    }
-                    AttributeType::Measures(measures) => {
-                        rsx!{
-                            Property {
-                                key: "{i}",
-                                name: "{name}",
-                                value: measures.pretty()
-                            }
-                        }
-                    }
-                    AttributeType::CornerRadius(radius) => {
-                        rsx!{
-                            Property {
-                                key: "{i}",
-                                name: "{name}",
-                                value: radius.pretty()
-                            }
-                        }
-                    }
-                    AttributeType::Size(size) => {
-                        rsx!{
-                            Property {
-                                key: "{i}",
-                                name: "{name}",
-                                value: size.pretty()
-                            }
-                        }
-                    }
-                    AttributeType::VisibleSize(visible_size) => {
-                        rsx!{
-                            Property {
-                                key: "{i}",
-                                name: "{name}",
-                                value: visible_size.pretty()
-                            }
-                        }
-                    }
-                    AttributeType::Color(fill) => {
-                        rsx!{
-                            ColorProperty {
-                                key: "{i}",
-                                name: "{name}",
-                                fill: fill.clone()
-                            }
-                        }
-                    }
-                    AttributeType::OptionalColor(fill) => {
-                        if let Some(fill) = fill {
+                    let el = match &attr {
+                        AttributeType::Measure(measure) => {
+                            rsx!{
+                                Property {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    value: measure.to_string()
+                                }
+                            }
+                        }
+                        AttributeType::OptionalMeasure(measure) => {
+                            rsx!{
+                                Property {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    value: measure.map(|measure| measure.to_string()).unwrap_or_else(|| "inherit".to_string())
+                                }
+                            }
+                        }
+                        AttributeType::Measures(measures) => {
+                            rsx!{
+                                Property {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    value: measures.pretty()
+                                }
+                            }
+                        }
+                        AttributeType::CornerRadius(radius) => {
+                            rsx!{
+                                Property {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    value: radius.pretty()
+                                }
+                            }
+                        }
+                        AttributeType::Size(size) => {
+                            rsx!{
+                                Property {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    value: size.pretty()
+                                }
+                            }
+                        }
+                        AttributeType::VisibleSize(visible_size) => {
+                            rsx!{
+                                Property {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    value: visible_size.pretty()
+                                }
+                            }
+                        }
+                        AttributeType::Color(fill) => {
                             rsx!{
                                 ColorProperty {
                                     key: "{i}",
@@ -115,121 +119,178 @@ pub fn NodeInspectorStyle(node_id: String) -> Element {
                                     fill: fill.clone()
                                 }
                             }
-                        } else {
-                            return None;
-                        }
-                    }
-                    AttributeType::Gradient(fill) => {
-                        rsx!{
-                            GradientProperty {
-                                key: "{i}",
-                                name: "{name}",
-                                fill: fill.clone()
-                            }
-                        }
-                    }
-                    AttributeType::Border(border) => {
-                        rsx!{
-                            BorderProperty {
-                                key: "{i}",
-                                name: "{name}",
-                                border: border.clone()
-                            }
-                        }
-                    }
-                    AttributeType::Text(text) => {
-                        rsx!{
-                            Property {
-                                key: "{i}",
-                                name: "{name}",
-                                value: text.to_string()
-                            }
-                        }
-                    }
-                    AttributeType::Direction(direction) => {
-                        rsx!{
-                            Property {
-                                key: "{i}",
-                                name: "{name}",
-                                value: direction.pretty()
-                            }
-                        }
-                    }
-                    AttributeType::Position(position) => {
-                        rsx!{
-                            Property {
-                                key: "{i}",
-                                name: "{name}",
-                                value: position.pretty()
-                            }
-                        }
-                    }
-                    AttributeType::Content(content) => {
-                        rsx!{
-                            Property {
-                                key: "{i}",
-                                name: "{name}",
-                                value: content.pretty()
-                            }
-                        }
-                    }
-                    AttributeType::Alignment(alignment) => {
-                        rsx!{
-                            Property {
-                                key: "{i}",
-                                name: "{name}",
-                                value: alignment.pretty()
-                            }
-                        }
-                    }
-                    AttributeType::Shadow(shadow) => {
-                        rsx!{
-                            ShadowProperty {
-                                key: "{i}",
-                                name: "{name}",
-                                shadow: shadow.clone()
-                            }
-                        }
-                    }
-                    AttributeType::TextShadow(text_shadow) => {
-                        rsx!{
-                            TextShadowProperty {
-                                key: "{i}",
-                                name: "{name}",
-                                text_shadow: *text_shadow
-                            }
-                        }
-                    }
-                    AttributeType::TextAlignment(text_align) => {
-                        rsx!{
-                            Property {
-                                key: "{i}",
-                                name: "{name}",
-                                value: text_align.pretty()
-                            }
-                        }
-                    }
-                    AttributeType::TextOverflow(text_overflow) => {
-                        rsx!{
-                            Property {
-                                key: "{i}",
-                                name: "{name}",
-                                value: text_overflow.pretty()
-                            }
-                        }
-                    }
-                };
+                        }
+                        AttributeType::OptionalColor(fill) => {
+                            if let Some(fill) = fill {
+                                rsx!{
+                                    ColorProperty {
+                                        key: "{i}",
+                                        name: "{name}",
+                                        fill: fill.clone()
+                                    }
+                                }
+                            } else {
+                                return None;
+                            }
+                        }
+                        AttributeType::Gradient(fill) => {
+                            rsx!{
+                                GradientProperty {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    fill: fill.clone()
+                                }
+                            }
+                        }
+                        AttributeType::Border(border) => {
+                            rsx!{
+                                BorderProperty {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    border: border.clone()
+                                }
+                            }
+                        }
+                        AttributeType::Text(text) => {
+                            rsx!{
+                                Property {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    value: text.to_string()
+                                }
+                            }
+                        }
+                        AttributeType::Direction(direction) => {
+                            rsx!{
+                                Property {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    value: direction.pretty()
+                                }
+                            }
+                        }
+                        AttributeType::Position(position) => {
+                            rsx!{
+                                Property {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    value: position.pretty()
+                                }
+                            }
+                        }
+                        AttributeType::Content(content) => {
+                            rsx!{
+                                Property {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    value: content.pretty()
+                                }
+                            }
+                        }
+                        AttributeType::Alignment(alignment) => {
+                            rsx!{
+                                Property {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    value: alignment.pretty()
+                                }
+                            }
+                        }
+                        AttributeType::Shadow(shadow) => {
+                            rsx!{
+                                ShadowProperty {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    shadow: shadow.clone()
+                                }
+                            }
+                        }
+                        AttributeType::TextShadow(text_shadow) => {
+                            rsx!{
+                                TextShadowProperty {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    text_shadow: **text_shadow
+                                }
+                            }
+                        }
+                        AttributeType::TextAlignment(text_align) => {
+                            rsx!{
+                                Property {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    value: text_align.pretty()
+                                }
+                            }
+                        }
+                        AttributeType::TextOverflow(text_overflow) => {
+                            rsx!{
+                                Property {
+                                    key: "{i}",
+                                    name: "{name}",
+                                    value: text_overflow.pretty()
+                                }
+                            }
+                        }
+                    };
 
 
 
-                Some(rsx!(
-                    rect {
-                        background,
-                        padding: "5 16",
-                        {el}
-                    }
-                ))
-            })}
+                    let edit = EditedAttribute {
+                        name: name.to_string(),
+                        value: editable_value(&attr),
+                    };
+
+                    Some(rsx!(
+                        rect {
+                            background,
+                            padding: "5 16",
+                            onclick: move |_| edited_attribute.set(edit.clone()),
+                            {el}
+                        }
+                    ))
+                })}
+            }
         }
     )
 }
+
+/// Value of an attribute as it would be written in the element.
+pub(crate) fn editable_value(attribute: &AttributeType) -> String {
+    match attribute {
+        AttributeType::Measure(measure) => measure.to_string(),
+        AttributeType::OptionalMeasure(measure) => measure
+            .map(|measure| measure.to_string())
+            .unwrap_or_default(),
+        AttributeType::Measures(measures) => measures.pretty(),
+        AttributeType::CornerRadius(radius) => radius.to_string(),
+        AttributeType::Size(size) => size.pretty(),
+        AttributeType::VisibleSize(visible_size) => visible_size.pretty(),
+        AttributeType::Color(fill) | AttributeType::Gradient(fill) => fill.to_string(),
+        AttributeType::OptionalColor(fill) => fill
+            .as_ref()
+            .map(|fill| fill.to_string())
+            .unwrap_or_default(),
+        AttributeType::Border(border) => border.to_string(),
+        AttributeType::Text(text) => text.clone(),
+        AttributeType::Direction(direction) => direction.pretty(),
+        AttributeType::Position(position) => position.pretty(),
+        AttributeType::Content(content) => content.pretty(),
+        AttributeType::Alignment(alignment) => alignment.pretty(),
+        AttributeType::Shadow(shadow) => shadow.to_string(),
+        AttributeType::TextShadow(text_shadow) => {
+            let color = text_shadow.color.to_rgb();
+            format!(
+                "{} {} {} rgb({}, {}, {})",
+                text_shadow.offset.x,
+                text_shadow.offset.y,
+                text_shadow.blur_sigma,
+                color.r,
+                color.g,
+                color.b
+            )
+        }
+        AttributeType::TextAlignment(text_align) => text_align.pretty(),
+        AttributeType::TextOverflow(text_overflow) => text_overflow.pretty(),
+    }
+}
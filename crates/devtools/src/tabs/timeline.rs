@@ -0,0 +1,187 @@
+use std::time::Instant;
+
+use dioxus::prelude::*;
+use dioxus_radio::prelude::*;
+use dioxus_router::hooks::use_navigator;
+use freya_components::*;
+use freya_elements as dioxus_elements;
+use freya_native_core::NodeId;
+use freya_winit::devtools::{
+    TimelineEntry,
+    TimelineEntryKind,
+};
+
+use crate::{
+    state::DevtoolsChannel,
+    NodeIdSerializer,
+    Route,
+};
+
+/// Events received by the app, the handlers they ran, the signals they wrote
+/// and the components and Nodes updated afterwards.
+#[allow(non_snake_case)]
+#[component]
+pub fn EventsTimeline() -> Element {
+    let mut radio = use_radio(DevtoolsChannel::UpdatedTimeline);
+    let mut cleared_at = use_signal::<Option<Instant>>(|| None);
+
+    use_hook(move || {
+        spawn(async move {
+            let mut timeline_receiver = radio.read().timeline_receiver.clone();
+            while timeline_receiver.changed().await.is_ok() {
+                radio.write_channel(DevtoolsChannel::UpdatedTimeline);
+            }
+        });
+    });
+
+    let entries = {
+        let state = radio.read();
+        let timeline = state.timeline_receiver.borrow();
+        let cleared_at = *cleared_at.read();
+        timeline
+            .iter()
+            .filter(|entry| cleared_at.map_or(true, |cleared_at| entry.time > cleared_at))
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    let started_at = entries.first().map(|entry| entry.time);
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            padding: "6 0 0 0",
+            content: "flex",
+            rect {
+                direction: "horizontal",
+                width: "fill",
+                padding: "0 10",
+                main_align: "space-between",
+                cross_align: "center",
+                label {
+                    "{entries.len()} entries"
+                }
+                Button {
+                    onpress: move |_| cleared_at.set(Some(Instant::now())),
+                    label {
+                        "Clear"
+                    }
+                }
+            }
+            ScrollView {
+                height: "flex(1)",
+                show_scrollbar: true,
+                for (i, entry) in entries.into_iter().enumerate().rev() {
+                    TimelineRow {
+                        key: "{i}",
+                        elapsed: started_at.map(|started_at| entry.time.duration_since(started_at).as_millis()).unwrap_or_default(),
+                        entry,
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn TimelineRow(entry: TimelineEntry, elapsed: u128) -> Element {
+    let (color, title, nodes) = match entry.kind {
+        TimelineEntryKind::PlatformEvent { name, hit_nodes } => {
+            let name: &str = name.into();
+            let title = if hit_nodes.is_empty() {
+                format!("{name} received")
+            } else {
+                format!("{name} received, hit")
+            };
+            ("rgb(102, 163, 217)", title, hit_nodes)
+        }
+        TimelineEntryKind::HandlerRan { name, node_id } => {
+            let name: &str = name.into();
+            ("rgb(63, 255, 0)", format!("on{name} ran in"), vec![node_id])
+        }
+        TimelineEntryKind::SignalWritten { location } => (
+            "rgb(255, 196, 0)",
+            format!("Signal written at {location}"),
+            Vec::new(),
+        ),
+        TimelineEntryKind::ComponentRendered { name } => {
+            ("rgb(200, 140, 255)", format!("{name} rendered"), Vec::new())
+        }
+        TimelineEntryKind::NodesUpdated(nodes) => (
+            "rgb(252, 181, 172)",
+            format!("{} nodes updated", nodes.len()),
+            nodes,
+        ),
+    };
+
+    rsx!(
+        rect {
+            width: "fill",
+            padding: "4 10",
+            direction: "horizontal",
+            cross_align: "center",
+            spacing: "8",
+            label {
+                width: "60",
+                font_size: "13",
+                color: "rgb(160, 160, 160)",
+                "+{elapsed}ms"
+            }
+            rect {
+                width: "8",
+                height: "8",
+                corner_radius: "8",
+                background: "{color}",
+            }
+            label {
+                font_size: "14",
+                "{title}"
+            }
+            for node_id in nodes.into_iter().take(8) {
+                NodeLink {
+                    key: "{node_id:?}",
+                    node_id
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn NodeLink(node_id: NodeId) -> Element {
+    let navigator = use_navigator();
+    let radio = use_radio(DevtoolsChannel::UpdatedDOM);
+    let tag = radio
+        .read()
+        .devtools_receiver
+        .borrow()
+        .iter()
+        .find(|node| node.id == node_id)
+        .map(|node| node.tag.to_string());
+    let id = node_id.index();
+
+    let onclick = move |_| {
+        navigator.push(Route::NodeInspectorStyle {
+            node_id: node_id.serialize(),
+        });
+    };
+
+    rsx!(
+        rect {
+            onclick,
+            padding: "2 6",
+            corner_radius: "6",
+            background: "rgb(60, 60, 60)",
+            label {
+                font_size: "13",
+                if let Some(tag) = tag {
+                    "{tag} {id}"
+                } else {
+                    "removed {id}"
+                }
+            }
+        }
+    )
+}
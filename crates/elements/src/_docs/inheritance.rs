@@ -8,12 +8,16 @@
 //! - `font_style`
 //! - `font_weight`
 //! - `font_width`
+//! - `font_variation`
 //! - `line_height`
 //! - `align`
 //! - `max_lines`
+//! - `line_clamp`
 //! - `letter_spacing`
 //! - `word_spacing`
 //! - `decoration`
 //! - `decoration_style`
 //! - `decoration_color`
 //! - `text_shadow`
+//! - `text_stroke`
+//! - `writing_direction`
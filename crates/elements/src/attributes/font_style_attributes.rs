@@ -187,6 +187,31 @@ def_attribute!(
     /// ```
     font_width,
 
+    /// Set the axes of a variable font, e.g. its weight, width or slant, with any value they support.
+    ///
+    /// Syntax: `<axis>=<value>`, where the axis is a four letters tag like `wght`, `wdth`, `slnt`, `ital`, `opsz`
+    /// or one defined by the font. Multiple axes are separated by commas.
+    ///
+    /// ⚠️ Only fonts with the given axes will be affected.
+    ///
+    /// It can be animated with `AnimFontVariation`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         label {
+    ///             font_family: "Inter",
+    ///             font_variation: "wght=650, slnt=-10",
+    ///             "Hello, variable World!"
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    font_variation,
+
     /// You can change the alignment of the text using the `text_align` attribute.
     ///
     /// Accepted values:
@@ -236,6 +261,8 @@ def_attribute!(
     ///
     /// Syntax: `<x> <y> <size> <color>`
     ///
+    /// Multiple shadows can be specified by separating them with commas.
+    ///
     /// ### Example
     ///
     /// ```rust, no_run
@@ -251,6 +278,27 @@ def_attribute!(
     /// ```
     text_shadow,
 
+    /// Draw an outline around the glyphs of a text.
+    ///
+    /// Syntax: `<width> <color>`
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         label {
+    ///             font_size: "50",
+    ///             color: "white",
+    ///             text_stroke: "2 black",
+    ///             "Hello, World!"
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    text_stroke,
+
     /// Determines the amount of lines that the text can have. It has unlimited lines by default.
     ///
     /// ### Example
@@ -271,6 +319,26 @@ def_attribute!(
     /// ```
     max_lines,
 
+    /// Clamp the text to the given number of lines, showing an ellipsis at the end of the last visible line.
+    /// It's a shorthand for [`max_lines`](#max_lines) with [`text_overflow: "ellipsis"`](#text_overflow),
+    /// unless a different `text_overflow` like `fade` is specified.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         label {
+    ///             width: "150",
+    ///             line_clamp: "2",
+    ///             "Looooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooong text"
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    line_clamp,
+
     /// Specify the decoration in a text.
     ///
     /// Accepted values:
@@ -346,6 +414,7 @@ def_attribute!(
     ///
     /// - `clip` (default): Simply cut the text.
     /// - `ellipsis`: Show `…`.
+    /// - `fade`: Fade out the end of the last visible line.
     /// - `[custom-value]: Show a custom value.
     ///
     /// ### Ellipsis example
@@ -363,6 +432,21 @@ def_attribute!(
     /// }
     /// ```
     ///
+    /// ### Fade example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         label {
+    ///             line_clamp: "1",
+    ///             text_overflow: "fade",
+    ///             "Looooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooooong text"
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    ///
     /// ### Custom value example
     ///
     /// ```rust, no_run
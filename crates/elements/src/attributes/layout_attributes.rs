@@ -383,6 +383,38 @@ def_attribute!(
     /// ```
     direction,
 
+    /// Specify the horizontal order in which the inner elements are laid out and text is written.
+    /// It's inherited by the inner elements.
+    ///
+    /// Accepted values:
+    ///
+    /// - `ltr` (default): Left to right.
+    /// - `rtl`: Right to left, the `main_align`, `cross_align`, horizontal paddings, margins and `offset_x`
+    ///   are mirrored, so for example the vertical scrollbar of a `ScrollView` is placed on the left.
+    ///
+    /// Texts are always shaped with Unicode BiDi, `writing_direction` is the base direction of their paragraphs.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             writing_direction: "rtl",
+    ///             direction: "horizontal",
+    ///             label {
+    ///                 "مرحبا"
+    ///             }
+    ///             label {
+    ///                 "بالعالم"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    writing_direction,
+
     /// Specify how you want the automatic (e.g `width: auto`) bounds in the cross axis to be constrained for the inner elements.
     ///
     /// Accepted values:
@@ -5,6 +5,8 @@ def_attribute!(
     ///
     /// You can learn about the syntax of this attribute in [`Color Syntax`](crate::_docs::color_syntax).
     ///
+    /// `text` spans only support plain colors, which are drawn behind their glyphs.
+    ///
     /// ### Example
     ///
     /// ```rust, no_run
@@ -14,6 +16,12 @@ def_attribute!(
     ///         rect {
     ///             background: "red"
     ///         }
+    ///         paragraph {
+    ///             text {
+    ///                 background: "rgb(230, 230, 230)",
+    ///                 "inline code"
+    ///             }
+    ///         }
     ///     )
     /// }
     /// ```
@@ -202,4 +210,82 @@ def_attribute!(
     /// }
     /// ```
     corner_smoothing,
+
+    /// Specify the cursor icon shown while the mouse is over the element, using the CSS names, e.g `pointer`, `text` or `not-allowed`.
+    ///
+    /// It applies to the children too, unless they specify another one. Use `auto` for the default behavior.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             cursor: "pointer",
+    ///             onclick: |_| println!("Clicked!"),
+    ///             label {
+    ///                 "Click me"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    cursor,
+
+    /// Specify where the pages can be split when the element is printed.
+    ///
+    /// Accepted values:
+    ///
+    /// - `auto` (default): Split the pages wherever they are full.
+    /// - `before`: Start a new page before the element.
+    /// - `after`: Start a new page after the element.
+    /// - `avoid`: Move the element to the next page instead of splitting it, unless it's taller than a page.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             page_break: "after",
+    ///             label {
+    ///                 "Cover"
+    ///             }
+    ///         }
+    ///         rect {
+    ///             page_break: "avoid",
+    ///             label {
+    ///                 "This summary is never split between two pages"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    page_break,
+
+    /// Provide the named values referenced with `token(name)` by the attributes of this element and its descendants.
+    /// Tokens provided by an ancestor are inherited, and replaced if provided again with the same name.
+    ///
+    /// They are usually created with `use_init_design_tokens`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             design_tokens: DesignTokens::new().with("surface-2", "rgb(235, 235, 235)").attribute(),
+    ///             rect {
+    ///                 background: "token(surface-2)",
+    ///                 width: "100",
+    ///                 height: "100",
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    design_tokens,
 );
@@ -11,8 +11,72 @@ def_attribute!(
     cursor_id,
     /// Defines a highlights attribute. For more information, see `use_editable`.
     highlights,
+    /// Defines a carets attribute, the positions of additional cursors. For more information, see `use_editable`.
+    carets,
+    /// Defines a match_highlights attribute, the ranges found by a search. For more information, see `use_text_search`.
+    match_highlights,
     /// Defines a highlight_color attribute. For more information, see `use_editable`.
     highlight_color,
+    /// Defines a match_highlight_color attribute. For more information, see `use_text_search`.
+    match_highlight_color,
+    /// Defines a spelling_errors attribute, the misspelled ranges drawn with a wavy underline. For more information, see `use_spellcheck`.
+    spelling_errors,
+    /// Defines a spelling_error_color attribute. For more information, see `use_spellcheck`.
+    spelling_error_color,
     /// Defines a highlight_mode attribute. For more information, see `use_editable`.
     highlight_mode,
+
+    /// Allow selecting the text of the `label`s and `paragraph`s by dragging the mouse over them,
+    /// the selected text can be copied with `Ctrl+C` (`Cmd+C` in macOS).
+    ///
+    /// It applies to the children too. When used in a container the selection can span all its texts,
+    /// use `disabled` to exclude some of them.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             text_selection: "enabled",
+    ///             label {
+    ///                 "Select me"
+    ///             }
+    ///             label {
+    ///                 "And me too"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    text_selection,
+
+    /// Turn a `text` span into a link. Clicking it fires the `onlinkclick` event of its `paragraph`
+    /// with the value of this attribute, and the cursor changes to a pointer when hovering it.
+    /// Hovering it fires the `onlinkhover` event of its `paragraph`, which can be used to style it.
+    ///
+    /// The span is exposed to assistive technologies with the `link` role.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         paragraph {
+    ///             onlinkclick: |e: LinkClickEvent| open_link(e.link.as_str()),
+    ///             text {
+    ///                 "Made with "
+    ///             }
+    ///             text {
+    ///                 link: "https://github.com/marc2332/freya",
+    ///                 decoration: "underline",
+    ///                 "Freya"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    link,
 );
@@ -72,4 +72,37 @@ def_attribute!(
     /// ```
     scale,
 
+    /// Hint how this element and its descendants should be painted.
+    ///
+    /// Accepted values:
+    /// - `none` (default): Paint every node as usual.
+    /// - `raster`: Rasterize the element and its descendants into a texture the first time they are painted,
+    ///   and reuse it in later frames as long as nothing inside changes. Moving the element, e.g. by scrolling, does not
+    ///   invalidate the texture.
+    ///
+    /// Useful for complex subtrees that rarely change, like the items of a long list.
+    /// Descendants are painted together with the element, so `layer` only orders them within this subtree.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         ScrollView {
+    ///             for i in 0..1000 {
+    ///                 rect {
+    ///                     key: "{i}",
+    ///                     cache: "raster",
+    ///                     label {
+    ///                         "Item {i}"
+    ///                     }
+    ///                 }
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    cache,
+
 );
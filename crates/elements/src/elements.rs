@@ -40,6 +40,7 @@ def_element!(
 
         // Children layout
         direction,
+        writing_direction,
         content,
         main_align,
         cross_align,
@@ -55,6 +56,10 @@ def_element!(
         shadow,
         corner_radius,
         corner_smoothing,
+        cursor,
+        text_selection,
+        page_break,
+        design_tokens,
 
         // Font style
         color,
@@ -63,10 +68,13 @@ def_element!(
         font_style,
         font_weight,
         font_width,
+        font_variation,
         text_align,
         line_height,
         text_shadow,
+        text_stroke,
         max_lines,
+        line_clamp,
         decoration,
         decoration_style,
         decoration_color,
@@ -79,6 +87,7 @@ def_element!(
         rotate,
         opacity,
         scale,
+        cache,
 
         // Reference
         canvas_reference,
@@ -195,10 +204,14 @@ def_element!(
         font_style,
         font_weight,
         font_width,
+        font_variation,
         text_align,
+        writing_direction,
         line_height,
         text_shadow,
+        text_stroke,
         max_lines,
+        line_clamp,
         decoration,
         decoration_style,
         decoration_color,
@@ -207,6 +220,11 @@ def_element!(
         word_spacing,
         text_height,
 
+        // Style
+        cursor,
+        text_selection,
+        page_break,
+
         // Transform
         rotate,
         opacity,
@@ -331,10 +349,14 @@ def_element!(
         font_style,
         font_weight,
         font_width,
+        font_variation,
         text_align,
+        writing_direction,
         line_height,
         text_shadow,
+        text_stroke,
         max_lines,
+        line_clamp,
         decoration,
         decoration_style,
         decoration_color,
@@ -343,6 +365,11 @@ def_element!(
         word_spacing,
         text_height,
 
+        // Style
+        cursor,
+        text_selection,
+        page_break,
+
         // Transform
         rotate,
         opacity,
@@ -353,7 +380,12 @@ def_element!(
         cursor_mode,
         cursor_id,
         highlights,
+        carets,
+        match_highlights,
         highlight_color,
+        match_highlight_color,
+        spelling_errors,
+        spelling_error_color,
         highlight_mode,
         cursor_reference,
 
@@ -429,6 +461,9 @@ def_element!(
     };
     /// `text` element is simply a text span used for the `paragraph` element.
     text {
+        // Style
+        background,
+
         // Font style
         color,
         font_size,
@@ -436,14 +471,19 @@ def_element!(
         font_style,
         font_weight,
         font_width,
+        font_variation,
         text_align,
         line_height,
         text_shadow,
+        text_stroke,
         decoration,
         decoration_style,
         decoration_color,
         letter_spacing,
         word_spacing,
+
+        // Links
+        link,
     };
     /// `image` element let's you show an image.
     ///
@@ -484,6 +524,7 @@ def_element!(
 
         // Children layout
         direction,
+        writing_direction,
         content,
         main_align,
         cross_align,
@@ -492,6 +533,9 @@ def_element!(
         offset_x,
         offset_y,
 
+        // Style
+        cursor,
+
         // Transform
         rotate,
         opacity,
@@ -614,6 +658,9 @@ def_element!(
         position_left,
         layer,
 
+        // Style
+        cursor,
+
         // Transform
         rotate,
         opacity,
@@ -1,7 +1,9 @@
 pub mod file;
+pub mod ime;
 pub mod keyboard;
 pub mod mouse;
 pub mod pointer;
+pub mod text;
 pub mod touch;
 pub mod wheel;
 
@@ -9,9 +11,11 @@ use std::any::Any;
 
 use dioxus_core::Event;
 pub use file::*;
+pub use ime::*;
 pub use keyboard::*;
 pub use mouse::*;
 pub use pointer::*;
+pub use text::*;
 pub use touch::*;
 pub use wheel::*;
 
@@ -20,6 +24,10 @@ pub type MouseEvent = Event<MouseData>;
 pub type WheelEvent = Event<WheelData>;
 pub type TouchEvent = Event<TouchData>;
 pub type PointerEvent = Event<PointerData>;
+pub type ImeEvent = Event<ImeData>;
+pub type TextOverflowEvent = Event<TextOverflowData>;
+pub type LinkClickEvent = Event<LinkClickData>;
+pub type LinkHoverEvent = Event<LinkHoverData>;
 
 /// A platform specific event.
 #[doc(hidden)]
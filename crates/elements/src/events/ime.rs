@@ -0,0 +1,62 @@
+use crate::{
+    events::ErasedEventData,
+    impl_event,
+};
+
+impl_event! [
+    ImeData;
+
+    /// The `imepreedit` event fires in the currently focused element while the user is composing text with an IME,
+    /// for example when typing Chinese, Japanese or Korean. An empty text means the composition was cleared.
+    ///
+    /// Event Data: [`ImeData`](crate::events::ImeData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             onimepreedit: |e| println!("Composing: {}", e.text)
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onimepreedit
+
+    /// The `imecommit` event fires in the currently focused element when the user confirms the text composed with an IME.
+    ///
+    /// The committed text is also sent as a `keydown` event with a [`Key::Character`](crate::events::Key::Character).
+    ///
+    /// Event Data: [`ImeData`](crate::events::ImeData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             onimecommit: |e| println!("Committed: {}", e.text)
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onimecommit
+];
+
+/// Data of an IME event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImeData {
+    /// Text being composed or committed.
+    pub text: String,
+    /// Byte range of the cursor inside the composed text, if it should be shown.
+    pub cursor: Option<(usize, usize)>,
+}
+
+impl From<&ErasedEventData> for ImeData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<ImeData>().cloned().unwrap()
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    events::ErasedEventData,
+    impl_event,
+};
+impl_event! [
+    TextOverflowData;
+
+    /// The `textoverflow` event fires when the text of a `label` or `paragraph` starts or stops overflowing,
+    /// e.g because it's clamped with `max_lines` or doesn't fit its size. It also fires once the text is first measured.
+    ///
+    /// Event Data: [`TextOverflowData`](crate::events::TextOverflowData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let mut is_truncated = use_signal(|| false);
+    ///
+    ///     rsx!(
+    ///         label {
+    ///             width: "100",
+    ///             line_clamp: "1",
+    ///             ontextoverflow: move |e: TextOverflowEvent| is_truncated.set(e.overflows),
+    ///             "Some text that might not fit"
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    ontextoverflow
+];
+
+/// Data of a TextOverflow event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextOverflowData {
+    /// Whether the text overflows.
+    pub overflows: bool,
+}
+
+impl From<&ErasedEventData> for TextOverflowData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<TextOverflowData>().cloned().unwrap()
+    }
+}
+
+impl_event! [
+    LinkClickData;
+
+    /// The `linkclick` event fires when a `text` span with a `link` attribute is clicked inside a `paragraph`.
+    ///
+    /// Event Data: [`LinkClickData`](crate::events::LinkClickData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         paragraph {
+    ///             onlinkclick: |e: LinkClickEvent| println!("Clicked {}", e.link),
+    ///             text {
+    ///                 "Read the "
+    ///             }
+    ///             text {
+    ///                 link: "https://freyaui.dev",
+    ///                 color: "rgb(0, 119, 182)",
+    ///                 "docs"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onlinkclick
+];
+
+/// Data of a LinkClick event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkClickData {
+    /// Value of the `link` attribute of the clicked span.
+    pub link: String,
+}
+
+impl From<&ErasedEventData> for LinkClickData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<LinkClickData>().cloned().unwrap()
+    }
+}
+
+impl_event! [
+    LinkHoverData;
+
+    /// The `linkhover` event fires when the cursor starts or stops hovering a `text` span with a `link` attribute inside a `paragraph`.
+    /// Useful to style the hovered link.
+    ///
+    /// Event Data: [`LinkHoverData`](crate::events::LinkHoverData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let mut hovered = use_signal(|| None);
+    ///
+    ///     rsx!(
+    ///         paragraph {
+    ///             onlinkhover: move |e: LinkHoverEvent| hovered.set(e.link.clone()),
+    ///             text {
+    ///                 "Read the "
+    ///             }
+    ///             text {
+    ///                 link: "https://freyaui.dev",
+    ///                 color: "rgb(0, 119, 182)",
+    ///                 decoration: if hovered().as_deref() == Some("https://freyaui.dev") { "underline" },
+    ///                 "docs"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onlinkhover
+];
+
+/// Data of a LinkHover event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkHoverData {
+    /// Value of the `link` attribute of the hovered span, or `None` if no link is hovered anymore.
+    pub link: Option<String>,
+}
+
+impl From<&ErasedEventData> for LinkHoverData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<LinkHoverData>().cloned().unwrap()
+    }
+}
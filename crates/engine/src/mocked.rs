@@ -7,6 +7,7 @@
 use std::ops::*;
 
 use bitflags::bitflags;
+#[cfg(not(target_arch = "wasm32"))]
 use glutin::context::PossiblyCurrentContext;
 
 #[derive(Default, Debug)]
@@ -445,6 +446,10 @@ impl TextStyle {
         unimplemented!("This is mocked")
     }
 
+    pub fn set_foreground_paint(&mut self, _paint: &Paint) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn clear_foreground_color(&mut self) -> &mut Self {
         unimplemented!("This is mocked")
     }
@@ -461,6 +466,10 @@ impl TextStyle {
         unimplemented!("This is mocked")
     }
 
+    pub fn set_background_paint(&mut self, _paint: &Paint) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn decoration(&self) -> &Decoration {
         unimplemented!("This is mocked")
     }
@@ -493,6 +502,13 @@ impl TextStyle {
         unimplemented!("This is mocked")
     }
 
+    pub fn set_font_arguments<'fa>(
+        &mut self,
+        _arguments: impl Into<Option<&'fa FontArguments<'fa, 'fa>>>,
+    ) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn shadows(&self) -> &[TextShadow] {
         unimplemented!("This is mocked")
     }
@@ -632,6 +648,12 @@ pub enum TextHeightBehavior {
 
 pub struct Typeface;
 
+impl Typeface {
+    pub fn family_name(&self) -> String {
+        unimplemented!("This is mocked")
+    }
+}
+
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum TextBaseline {
     Alphabetic = 0,
@@ -668,6 +690,10 @@ impl Paint {
         unimplemented!("This is mocked")
     }
 
+    pub fn set_stroke_cap(&mut self, _cap: PaintCap) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn set_mask_filter(&mut self, _mask_filter: impl Into<Option<MaskFilter>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
@@ -679,12 +705,57 @@ pub enum PaintStyle {
     StrokeAndFill = 2,
 }
 
+pub enum PaintCap {
+    Butt = 0,
+    Round = 1,
+    Square = 2,
+}
+
 pub struct FontStyle;
 
 impl FontStyle {
     pub fn new(_weight: Weight, _width: Width, _slant: Slant) -> Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn normal() -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FourByteTag(u32);
+
+impl FourByteTag {
+    pub fn from_chars(_a: char, _b: char, _c: char, _d: char) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coordinate {
+    pub axis: FourByteTag,
+    pub value: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct VariationPosition<'a> {
+    pub coordinates: &'a [Coordinate],
+}
+
+pub struct FontArguments<'vp, 'p>(std::marker::PhantomData<(&'vp (), &'p ())>);
+
+impl<'vp, 'p> FontArguments<'vp, 'p> {
+    pub fn new() -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_variation_design_position(
+        self,
+        _position: VariationPosition<'vp>,
+    ) -> FontArguments<'vp, 'p> {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[derive(Default, Clone)]
@@ -698,10 +769,23 @@ impl FontMgr {
     ) -> Option<Typeface> {
         unimplemented!("This is mocked")
     }
+
+    pub fn match_family_style(
+        &self,
+        _family_name: impl AsRef<str>,
+        _style: FontStyle,
+    ) -> Option<Typeface> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn family_names(&self) -> std::vec::IntoIter<String> {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct FontFeature;
 
+#[derive(Clone)]
 pub struct TypefaceFontProvider;
 
 impl TypefaceFontProvider {
@@ -743,6 +827,14 @@ impl FontCollection {
     pub fn set_dynamic_font_manager(&mut self, _font_manager: impl Into<Option<FontMgr>>) {
         unimplemented!("This is mocked")
     }
+
+    pub fn clear_caches(&mut self) {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn enable_font_fallback(&mut self) {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct Paragraph;
@@ -1043,6 +1135,15 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn draw_image(
+        &self,
+        _image: impl AsRef<Image>,
+        _left_top: impl Into<Point>,
+        _paint: Option<&Paint>,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn draw_image_nine(
         &self,
         _image: Image,
@@ -1092,6 +1193,17 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn draw_arc(
+        &self,
+        _oval: impl AsRef<Rect>,
+        _start_angle: f32,
+        _sweep_angle: f32,
+        _use_center: bool,
+        _paint: &Paint,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn clip_path(
         &self,
         _path: &Path,
@@ -1132,6 +1244,55 @@ impl Canvas {
     pub fn save_layer_alpha_f(&self, bounds: impl Into<Option<Rect>>, alpha: f32) -> usize {
         unimplemented!("This is mocked")
     }
+
+    pub fn direct_context(&self) -> Option<DirectContext> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn draw_picture(
+        &self,
+        picture: impl AsRef<Picture>,
+        matrix: Option<&Matrix>,
+        paint: Option<&Paint>,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Clone)]
+pub struct Picture;
+
+impl AsRef<Picture> for Picture {
+    fn as_ref(&self) -> &Picture {
+        self
+    }
+}
+
+impl Picture {
+    pub fn cull_rect(&self) -> Rect {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Default)]
+pub struct PictureRecorder;
+
+impl PictureRecorder {
+    pub fn new() -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn begin_recording(
+        &mut self,
+        bounds: impl AsRef<Rect>,
+        bbh_factory: Option<()>,
+    ) -> &Canvas {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn finish_recording_as_picture(&mut self, cull_rect: Option<&Rect>) -> Option<Picture> {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub enum SrcRectConstraint {
@@ -1202,7 +1363,21 @@ pub enum RectWidthStyle {
     Max,
 }
 
-pub struct LineMetrics;
+pub struct LineMetrics {
+    pub start_index: usize,
+    pub end_index: usize,
+    pub end_excluding_whitespaces: usize,
+    pub end_including_newline: usize,
+    pub hard_break: bool,
+    pub ascent: f64,
+    pub descent: f64,
+    pub unscaled_ascent: f64,
+    pub height: f64,
+    pub width: f64,
+    pub left: f64,
+    pub baseline: f64,
+    pub line_number: usize,
+}
 
 impl LineMetrics {
     pub fn get_style_metrics(&self, range: Range<usize>) -> Vec<(usize, &StyleMetrics)> {
@@ -1236,6 +1411,19 @@ pub struct PositionWithAffinity {
 pub struct RuntimeEffect;
 
 impl RuntimeEffect {
+    pub fn make_for_shader(_sksl: impl AsRef<str>, _options: Option<()>) -> Result<Self, String> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn make_shader(
+        &self,
+        _uniforms: impl Into<Data>,
+        _children: &[()],
+        _local_matrix: Option<&Matrix>,
+    ) -> Option<Shader> {
+        unimplemented!("This is mocked")
+    }
+
     pub fn uniforms(&self) -> &[Uniform] {
         unimplemented!("This is mocked")
     }
@@ -1260,6 +1448,10 @@ impl Uniform {
     pub fn name(&self) -> &str {
         unimplemented!("This is mocked")
     }
+
+    pub fn size_in_bytes(&self) -> usize {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(i32)]
@@ -1293,6 +1485,18 @@ impl Rect {
         unimplemented!("This is mocked")
     }
 
+    pub fn new_empty() -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn from_xywh(_x: f32, _y: f32, _w: f32, _h: f32) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn from_wh(_w: f32, _h: f32) -> Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn x(&self) -> f32 {
         unimplemented!("This is mocked")
     }
@@ -1309,6 +1513,10 @@ impl Rect {
         unimplemented!("This is mocked")
     }
 
+    pub fn center_x(&self) -> f32 {
+        unimplemented!("This is mocked")
+    }
+
     pub fn with_outset(&self, _delta: impl Into<Point>) -> Self {
         unimplemented!("This is mocked")
     }
@@ -1332,6 +1540,17 @@ impl Image {
         unimplemented!("This is mocked")
     }
 
+    pub fn from_texture(
+        _context: &mut RecordingContext,
+        _backend_texture: &BackendTexture,
+        _origin: SurfaceOrigin,
+        _color_type: ColorType,
+        _alpha_type: AlphaType,
+        _color_space: impl Into<Option<ColorSpace>>,
+    ) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
+
     pub fn width(&self) -> i32 {
         unimplemented!("This is mocked")
     }
@@ -1348,6 +1567,23 @@ impl Image {
     ) -> Option<Data> {
         unimplemented!("This is mocked")
     }
+
+    pub fn read_pixels(
+        &self,
+        dst_info: &ImageInfo,
+        pixels: &mut [u8],
+        dst_row_bytes: usize,
+        src: impl Into<IPoint>,
+        caching_hint: CachingHint,
+    ) -> bool {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CachingHint {
+    Allow,
+    Disallow,
 }
 
 pub struct Data;
@@ -1629,12 +1865,38 @@ pub enum BlurStyle {
 
 pub mod svg {
     use super::{
-        Canvas,
+        Canvas as SkiaCanvas,
         Color,
+        Data,
         LocalResourceProvider,
+        Rect,
         Size,
     };
 
+    pub mod canvas {
+        pub struct Flags;
+    }
+
+    pub struct Canvas;
+
+    impl Canvas {
+        pub fn new(_bounds: Rect, _flags: Option<canvas::Flags>) -> Self {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn end(self) -> Data {
+            unimplemented!("This is mocked")
+        }
+    }
+
+    impl std::ops::Deref for Canvas {
+        type Target = SkiaCanvas;
+
+        fn deref(&self) -> &Self::Target {
+            unimplemented!("This is mocked")
+        }
+    }
+
     pub enum LengthUnit {
         Percentage,
     }
@@ -1698,7 +1960,7 @@ pub mod svg {
             unimplemented!("This is mocked")
         }
 
-        pub fn render(&self, _canvas: &Canvas) {
+        pub fn render(&self, _canvas: &SkiaCanvas) {
             unimplemented!("This is mocked")
         }
 
@@ -1708,6 +1970,48 @@ pub mod svg {
     }
 }
 
+pub mod pdf {
+    use super::{
+        Canvas,
+        Rect,
+        Size,
+    };
+
+    #[derive(Default)]
+    pub struct Metadata {
+        pub title: String,
+        pub creator: String,
+        pub raster_dpi: f32,
+    }
+
+    pub struct Document;
+
+    impl Document {
+        pub fn begin_page(self, _size: impl Into<Size>, _content: Option<&Rect>) -> Self {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn canvas(&mut self) -> &Canvas {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn end_page(self) -> Self {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn close(self) {
+            unimplemented!("This is mocked")
+        }
+    }
+
+    pub fn new_document(
+        _writer: &mut impl std::io::Write,
+        _metadata: Option<&Metadata>,
+    ) -> Document {
+        unimplemented!("This is mocked")
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, PartialEq, Default, Debug)]
 pub struct Size;
@@ -1739,6 +2043,7 @@ impl Surface {
         unimplemented!("This is mocked")
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn swap_buffers(&self, _: &PossiblyCurrentContext) {
         unimplemented!("This is mocked")
     }
@@ -1767,6 +2072,45 @@ impl Surface {
     pub fn new_surface_with_dimensions(&mut self, dim: impl Into<ISize>) -> Option<Self> {
         unimplemented!("This is mocked")
     }
+
+    pub fn width(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn height(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn read_pixels(
+        &mut self,
+        dst_info: &ImageInfo,
+        dst_pixels: &mut [u8],
+        dst_row_bytes: usize,
+        src: impl Into<IPoint>,
+    ) -> bool {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub struct ImageInfo;
+
+impl ImageInfo {
+    pub fn new(
+        dimensions: impl Into<ISize>,
+        ct: ColorType,
+        at: AlphaType,
+        cs: impl Into<Option<ColorSpace>>,
+    ) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub struct IPoint;
+
+impl From<(i32, i32)> for IPoint {
+    fn from(source: (i32, i32)) -> Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct ISize;
@@ -1783,6 +2127,16 @@ pub struct ColorSpace;
 #[repr(i32)]
 pub enum ColorType {
     RGBA8888 = 4,
+    BGRA8888 = 6,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(i32)]
+pub enum AlphaType {
+    Unknown = 0,
+    Opaque = 1,
+    Premul = 2,
+    Unpremul = 3,
 }
 
 pub struct SurfaceProps;
@@ -1849,6 +2203,45 @@ impl DirectContext {
     pub fn abandon(&self) {
         unimplemented!("This is mocked")
     }
+
+    pub fn reset(&mut self, _backend_state: Option<u32>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Mipmapped {
+    No,
+    Yes,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct TextureInfo {
+    pub target: u32,
+    pub id: u32,
+    pub format: u32,
+    pub protected: Protected,
+}
+
+pub struct BackendTexture;
+
+pub mod backend_textures {
+    use super::{
+        BackendTexture,
+        ISize,
+        Mipmapped,
+        TextureInfo,
+    };
+
+    pub unsafe fn make_gl(
+        _dimensions: impl Into<ISize>,
+        _mipmapped: Mipmapped,
+        _gl_info: TextureInfo,
+        _label: impl AsRef<str>,
+    ) -> BackendTexture {
+        unimplemented!("This is mocked")
+    }
 }
 
 use std::ffi::c_void;
@@ -1990,3 +2383,11 @@ impl LocalResourceProvider {
 pub fn raster_n32_premul(size: impl Into<ISize>) -> Option<Surface> {
     unimplemented!("This is mocked")
 }
+
+pub fn raster_from_data(
+    info: &ImageInfo,
+    pixels: impl Into<Data>,
+    row_bytes: usize,
+) -> Option<Image> {
+    unimplemented!("This is mocked")
+}
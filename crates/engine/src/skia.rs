@@ -1,5 +1,9 @@
 pub use skia_safe::{
     canvas::SaveLayerRec,
+    font_arguments::{
+        variation_position::Coordinate,
+        VariationPosition,
+    },
     font_style::{
         Slant,
         Weight,
@@ -7,15 +11,20 @@ pub use skia_safe::{
     },
     gpu::{
         backend_render_targets,
+        backend_textures,
         direct_contexts,
         gl::{
             Format,
             FramebufferInfo,
             Interface,
+            TextureInfo,
         },
         surfaces::wrap_backend_render_target,
         BackendRenderTarget,
+        BackendTexture,
         DirectContext,
+        Mipmapped,
+        Protected,
         RecordingContext,
         SurfaceOrigin,
     },
@@ -26,6 +35,7 @@ pub use skia_safe::{
     },
     images::raster_from_data,
     path::ArcSize,
+    pdf,
     resources::LocalResourceProvider,
     rrect::Corner,
     runtime_effect::Uniform,
@@ -59,9 +69,11 @@ pub use skia_safe::{
         TypefaceFontProvider,
     },
     wrapper::PointerWrapper,
+    AlphaType,
     Bitmap,
     BlendMode,
     BlurStyle,
+    CachingHint,
     Canvas,
     ClipOp,
     Color,
@@ -74,6 +86,7 @@ pub use skia_safe::{
     FontArguments,
     FontMgr,
     FontStyle,
+    FourByteTag,
     IPoint,
     IRect,
     Image,
@@ -82,10 +95,13 @@ pub use skia_safe::{
     Matrix,
     MipmapMode,
     Paint,
+    PaintCap,
     PaintStyle,
     Path,
     PathDirection,
     PathFillType,
+    Picture,
+    PictureRecorder,
     Pixmap,
     Point,
     RRect,
@@ -8,3 +8,21 @@
 //! [dependencies]
 //! freya = { .., features = ["devtools"] }
 //! ```
+//!
+//! ## Layout debugging
+//!
+//! The bounds, margins, paddings and alignments of every element can be painted over the app,
+//! even without the devtools, with [`LaunchConfig::with_debug_layout`](crate::prelude::LaunchConfig::with_debug_layout) or by pressing `Ctrl+Shift+L` in debug builds.
+//!
+//! ```rust,no_run
+//! # use freya::prelude::*;
+//! # fn app() -> Element { rsx!(rect {}) }
+//! fn main() {
+//!     launch_cfg(app, LaunchConfig::<()>::new().with_debug_layout(true));
+//! }
+//! ```
+//!
+//! - Margins are painted in orange.
+//! - Paddings are painted in green.
+//! - The bounds are outlined in blue.
+//! - Guides are drawn where the children are aligned to, magenta for `main_align` and cyan for `cross_align`.
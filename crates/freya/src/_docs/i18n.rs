@@ -1,12 +1,18 @@
 //! # i18n
 //!
-//! You may add i18n (localization) support to your Freya app by using the [**dioxus-i18n**](https://github.com/dioxus-community/dioxus-i18n) crate.
+//! You may add i18n (localization) support to your Freya app by enabling the `use_i18n` feature.
+//! Messages are written in [Fluent](https://projectfluent.org/), which handles plurals and number formatting
+//! according to the rules of every language.
 //!
 //! ```fluent
 //! # en-US.ftl
 //!
 //! hello_world = Hello, World!
 //! hello = Hello, {$name}!
+//! apples = {$count ->
+//!     [one] One apple
+//!    *[other] {NUMBER($count)} apples
+//! }
 //! ```
 //!
 //!
@@ -15,12 +21,14 @@
 //!
 //! hello_world = Hola, Mundo!
 //! hello = Hola, {$name}!
+//! apples = {$count ->
+//!     [one] Una manzana
+//!    *[other] {NUMBER($count)} manzanas
+//! }
 //! ```
 //!
 //! ```rust
 //! # use freya::prelude::*;
-//! # use dioxus_i18n::{prelude::*, t};
-//! # use dioxus_i18n::unic_langid::langid;
 //!
 //! // main.rs
 //!
@@ -32,7 +40,7 @@
 //! #[allow(non_snake_case)]
 //! fn Body() -> Element {
 //!     // Access to the i18n state
-//!     let mut i18n = i18n();
+//!     let mut i18n = use_i18n();
 //!
 //!     // Update the current language
 //!     let change_to_english = move |_| i18n.set_language(langid!("en-US"));
@@ -59,6 +67,7 @@
 //!             // Get and subscribe to these messages
 //!             label { { t!("hello_world") } }
 //!             label { { t!("hello", name: "Dioxus") } }
+//!             label { { t!("apples", count: 3) } }
 //!         }
 //!     )
 //! }
@@ -76,6 +85,8 @@
 //!                 langid!("es-ES"),
 //!                 "./es-ES.ftl",
 //!             ))
+//!             // Start with the language of the OS if there are messages for it
+//!             .with_os_language()
 //!     });
 //!
 //!     rsx!(Body {})
@@ -1,7 +1,11 @@
 //! # Native Router
 //!
 //! Even though Freya supports Dioxus Router, there are certain integrations that it does not provide, such as as back and forward navigation with the mouse buttons.
-//! For things like this exists `NativeRouter`, a thin wrapper component that adds these missing integrations.
+//! For things like this exists `NativeRouter`, a thin wrapper component that adds these missing integrations:
+//! - Back and forward navigation with the mouse buttons, `Alt + Left` / `Alt + Right` and the back and forward keys.
+//! - Scroll position restoration per route, by creating the scroll controllers with `use_route_scroll_controller`.
+//!
+//! To animate the transitions between routes, wrap the content in an `AnimatedRouter` and use an `AnimatedOutlet` instead of an `Outlet`.
 //!
 //! You simply need to wrap your `Router` content inside the `NativeRouter` component.
 //!
@@ -71,6 +71,90 @@
 //! }
 //! ```
 //!
+//! ## Switching themes
+//!
+//! The provided theme can be swapped at runtime from any component with [use_theme](freya_hooks::use_theme), every component using it will be updated.
+//! Pass a [ThemeTransition](freya_hooks::ThemeTransition) to the `ThemeProvider` to animate the colors instead of swapping them at once.
+//!
+//! ### Example
+//!
+//! ```rust
+//! # use freya::prelude::*;
+//! fn app() -> Element {
+//!     rsx!(
+//!         ThemeProvider {
+//!             theme: LIGHT_THEME,
+//!             transition: ThemeTransition::default(),
+//!             ThemeSwitcher {}
+//!         }
+//!     )
+//! }
+//!
+//! #[component]
+//! fn ThemeSwitcher() -> Element {
+//!     let mut theme = use_theme();
+//!     let is_dark = theme.read().name == "dark";
+//!
+//!     rsx!(
+//!         Switch {
+//!             enabled: is_dark,
+//!             ontoggled: move |_| {
+//!                 if is_dark {
+//!                     theme.set(LIGHT_THEME)
+//!                 } else {
+//!                     theme.set(DARK_THEME)
+//!                 }
+//!             }
+//!         }
+//!     )
+//! }
+//! ```
+//!
+//! ## Following the system theme
+//!
+//! [use_init_theme_with_preference](freya_hooks::use_init_theme_with_preference) provides the light or dark theme depending on a [PreferredTheme](freya_core::platform_state::PreferredTheme).
+//! With `PreferredTheme::Auto` the theme is kept in sync with the operating system while the app is running.
+//! The accent color of the operating system can be read with [use_accent_color](freya_hooks::use_accent_color).
+//!
+//! ## Theme files
+//!
+//! With the `theme_files` feature enabled, themes can also be loaded from JSON, TOML or RON files with `use_init_theme_from_file`.
+//! In debug builds the file is watched and every change is applied right away, without recompiling.
+//!
+//! ```toml
+//! base = "dark"
+//!
+//! [colors]
+//! primary = "rgb(240, 100, 50)"
+//!
+//! [components.button]
+//! padding = "8 16"
+//! ```
+//!
+//! ## Design tokens
+//!
+//! Attributes can also reference named values with `token(name)`, which are provided with [use_init_design_tokens](freya_hooks::use_init_design_tokens)
+//! to an element and its descendants through the `design_tokens` attribute.
+//! Changing a token updates every element referencing it.
+//!
+//! ### Example
+//!
+//! ```rust
+//! # use freya::prelude::*;
+//! fn app() -> Element {
+//!     let tokens =
+//!         use_init_design_tokens(|| DesignTokens::new().with("surface-2", "rgb(235, 235, 235)"));
+//!
+//!     rsx!(rect {
+//!         design_tokens: tokens.read().attribute(),
+//!         background: "token(surface-2)",
+//!         label {
+//!             "Hello, World!"
+//!         }
+//!     })
+//! }
+//! ```
+//!
 //! ## `theme` prop
 //!
 //! Most of the components also support being tweaked via their `theme` prop and with the help of the `theme_with` macro.
@@ -1,6 +1,11 @@
 use dioxus_core::Element;
 use freya_winit::{
+    FreyaRenderer,
+    HeadlessRenderer,
     LaunchConfig,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use freya_winit::{
     WindowConfig,
     WinitRenderer,
 };
@@ -35,6 +40,7 @@ use freya_winit::{
 ///     )
 /// }
 /// ```
+#[cfg(not(target_arch = "wasm32"))]
 pub fn launch(app: AppComponent) {
     launch_cfg(
         app,
@@ -80,6 +86,7 @@ pub fn launch(app: AppComponent) {
 ///     )
 /// }
 /// ```
+#[cfg(not(target_arch = "wasm32"))]
 pub fn launch_with_title(app: AppComponent, title: &'static str) {
     launch_cfg(
         app,
@@ -123,6 +130,7 @@ pub fn launch_with_title(app: AppComponent, title: &'static str) {
 ///     )
 /// }
 /// ```
+#[cfg(not(target_arch = "wasm32"))]
 pub fn launch_with_props(app: AppComponent, title: &'static str, (width, height): (f64, f64)) {
     launch_cfg(
         app,
@@ -177,10 +185,23 @@ pub fn launch_with_props(app: AppComponent, title: &'static str, (width, height)
 ///     )
 /// }
 /// ```
+#[cfg(not(target_arch = "wasm32"))]
 pub fn launch_cfg<T: 'static + Clone>(app: AppComponent, config: LaunchConfig<T>) {
     #[cfg(feature = "performance-overlay")]
     let config = config.with_plugin(crate::plugins::PerformanceOverlayPlugin::default());
 
+    #[cfg(feature = "remote-devtools")]
+    let config = match std::env::var("FREYA_REMOTE_DEVTOOLS") {
+        Ok(address) => match freya_devtools::remote::RemoteDevtoolsPlugin::bind(&address) {
+            Ok(plugin) => config.with_plugin(plugin),
+            Err(err) => {
+                tracing::error!("Failed to listen for remote devtools in {address}: {err}");
+                config
+            }
+        },
+        Err(_) => config,
+    };
+
     use freya_core::dom::{
         FreyaDOM,
         SafeDOM,
@@ -189,21 +210,6 @@ pub fn launch_cfg<T: 'static + Clone>(app: AppComponent, config: LaunchConfig<T>
     let fdom = FreyaDOM::default();
     let sdom = SafeDOM::new(fdom);
 
-    #[cfg(feature = "tracing-subscriber")]
-    {
-        use tracing_subscriber::{
-            fmt,
-            prelude::__tracing_subscriber_SubscriberExt,
-            util::SubscriberInitExt,
-            EnvFilter,
-        };
-
-        tracing_subscriber::registry()
-            .with(fmt::layer())
-            .with(EnvFilter::from_default_env())
-            .init();
-    }
-
     let (vdom, devtools, hovered_node) = {
         #[cfg(feature = "devtools")]
         #[cfg(debug_assertions)]
@@ -218,7 +224,12 @@ pub fn launch_cfg<T: 'static + Clone>(app: AppComponent, config: LaunchConfig<T>
 
             let hovered_node = Some(Arc::new(Mutex::new(None)));
             let (devtools, devtools_receiver) = Devtools::new();
-            let vdom = with_devtools(app, devtools_receiver.clone(), hovered_node.clone());
+            let vdom = with_devtools(
+                app,
+                devtools_receiver.clone(),
+                devtools.timeline(),
+                hovered_node.clone(),
+            );
             (vdom, Some(devtools), hovered_node)
         }
 
@@ -228,6 +239,28 @@ pub fn launch_cfg<T: 'static + Clone>(app: AppComponent, config: LaunchConfig<T>
             (vdom, None, None)
         }
     };
+
+    #[cfg(feature = "tracing-subscriber")]
+    {
+        use tracing_subscriber::{
+            fmt,
+            prelude::__tracing_subscriber_SubscriberExt,
+            util::SubscriberInitExt,
+            EnvFilter,
+            Layer,
+        };
+
+        tracing_subscriber::registry()
+            .with(fmt::layer().with_filter(EnvFilter::from_default_env()))
+            .with(devtools.as_ref().map(|devtools| devtools.tracing_layer()))
+            .init();
+    }
+
+    #[cfg(not(feature = "tracing-subscriber"))]
+    if let Some(devtools) = &devtools {
+        devtools.init_tracing();
+    }
+
     #[cfg(not(feature = "custom-tokio-rt"))]
     {
         let rt = tokio::runtime::Builder::new_multi_thread()
@@ -243,9 +276,204 @@ pub fn launch_cfg<T: 'static + Clone>(app: AppComponent, config: LaunchConfig<T>
     WinitRenderer::launch(vdom, sdom, config, devtools, hovered_node);
 }
 
-#[cfg(any(not(feature = "devtools"), not(debug_assertions)))]
+/// Launch the app in Android, from the `android_main` entrypoint of the native activity.
+///
+/// The window covers the whole screen, so the size and decorations of the config are ignored.
+/// Touches are also sent as mouse events and the app is suspended and resumed along with the activity.
+///
+/// # Example
+/// ```rust,ignore
+/// # use freya::prelude::*;
+/// #[no_mangle]
+/// fn android_main(android_app: AndroidApp) {
+///     launch_android(android_app, app, LaunchConfig::<()>::new());
+/// }
+///
+/// fn app() -> Element {
+///    rsx!(
+///         label {
+///             "Hello World!"
+///         }
+///     )
+/// }
+/// ```
+#[cfg(target_os = "android")]
+pub fn launch_android<T: 'static + Clone>(
+    android_app: freya_winit::AndroidApp,
+    app: AppComponent,
+    config: LaunchConfig<T>,
+) {
+    use winit::platform::android::EventLoopBuilderExtAndroid;
+
+    launch_cfg(
+        app,
+        config.with_event_loop_builder(move |event_loop_builder| {
+            event_loop_builder.with_android_app(android_app);
+        }),
+    )
+}
+
+/// Launch the app in a `<canvas>` of a web page, for the `wasm32` target.
+///
+/// The canvas is appended to the `<body>` with the size of the config, use [LaunchConfig::with_window_attributes]
+/// to render into an existing canvas instead. Plugins, menus, tray icons and other desktop features are not available.
+///
+/// There is no Tokio runtime in the browser, so hooks and components that wait on Tokio timers don't work yet.
+///
+/// # Example
+/// ```rust,ignore
+/// # use freya::prelude::*;
+/// fn main() {
+///     launch_web(app, LaunchConfig::<()>::new().with_size(800.0, 600.0));
+/// }
+///
+/// fn app() -> Element {
+///    rsx!(
+///         label {
+///             "Hello World!"
+///         }
+///     )
+/// }
+/// ```
+#[cfg(target_arch = "wasm32")]
+pub fn launch_web<T: 'static + Clone>(app: AppComponent, config: LaunchConfig<'static, T>) {
+    use freya_core::dom::{
+        FreyaDOM,
+        SafeDOM,
+    };
+
+    let sdom = SafeDOM::new(FreyaDOM::default());
+    let vdom = with_accessibility(app);
+
+    freya_winit::WebRenderer::launch(vdom, sdom, config)
+}
+
+/// Run the app without a window, rendering into CPU memory with the given scale factor.
+///
+/// Useful to generate screenshots or to run visual tests in CI, it must be used inside a Tokio runtime.
+///
+/// # Example
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// async fn screenshot() {
+///     let mut renderer = launch_headless(app, LaunchConfig::<()>::new().with_size(400.0, 300.0), 2.0);
+///     renderer.wait_for_update().await;
+///     renderer.save_png("./screenshot.png").unwrap();
+/// }
+///
+/// fn app() -> Element {
+///    rsx!(
+///         label {
+///             "Hello World!"
+///         }
+///     )
+/// }
+/// ```
+pub fn launch_headless<T: 'static + Clone>(
+    app: AppComponent,
+    config: LaunchConfig<T>,
+    scale_factor: f64,
+) -> HeadlessRenderer {
+    use freya_core::dom::{
+        FreyaDOM,
+        SafeDOM,
+    };
+
+    let sdom = SafeDOM::new(FreyaDOM::default());
+    let vdom = with_accessibility(app);
+
+    HeadlessRenderer::new(vdom, sdom, config, scale_factor)
+}
+
+/// Render a component without a window and print it, getting the number of printed pages.
+///
+/// The component is laid out with the width of the printable area of the pages,
+/// and split into pages following the `page_break` attribute of its elements.
+///
+/// # Example
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// async fn print_invoice() {
+///     let options = PrintOptions::new()
+///         .with_job_name("Invoice")
+///         .with_page_size(PageSize::LETTER);
+///     print_component(invoice, options).await.unwrap();
+/// }
+///
+/// fn invoice() -> Element {
+///    rsx!(
+///         label {
+///             "Invoice #1"
+///         }
+///     )
+/// }
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn print_component(
+    app: AppComponent,
+    options: freya_core::printing::PrintOptions,
+) -> Result<usize, freya_core::printing::PrintError> {
+    use freya_core::printing::POINTS_PER_PIXEL;
+
+    let printable_size = options.printable_size();
+    let config = LaunchConfig::<()>::new().with_size(
+        (printable_size.width / POINTS_PER_PIXEL) as f64,
+        (printable_size.height / POINTS_PER_PIXEL) as f64,
+    );
+    let mut renderer = launch_headless(app, config, 1.0);
+    renderer.wait_for_update().await;
+    renderer.print(&options)
+}
+
+/// Render the app inside a window owned by an existing winit application, like a game.
+///
+/// The host keeps its event loop, it forwards the window events with [FreyaRenderer::handle_event]
+/// and draws the UI into its canvas with [FreyaRenderer::render]. It must be used inside a Tokio runtime.
+///
+/// # Example
+/// ```rust,no_run
+/// # use std::sync::Arc;
+/// # use freya::prelude::*;
+/// # use freya_engine::prelude::Canvas;
+/// # use winit::{event::WindowEvent, window::Window};
+/// fn on_window_event(ui: &mut FreyaRenderer, event: &WindowEvent, canvas: &Canvas) {
+///     if let WindowEvent::RedrawRequested = event {
+///         // Draw the game first and then the UI on top
+///         ui.render(canvas);
+///     } else {
+///         ui.handle_event(event);
+///     }
+/// }
+///
+/// fn create_ui(window: Arc<Window>) -> FreyaRenderer {
+///     embed_app(window, app, LaunchConfig::<()>::new().with_transparency(true))
+/// }
+///
+/// fn app() -> Element {
+///    rsx!(
+///         label {
+///             "Hello World!"
+///         }
+///     )
+/// }
+/// ```
+pub fn embed_app<T: 'static + Clone>(
+    window: std::sync::Arc<winit::window::Window>,
+    app: AppComponent,
+    config: LaunchConfig<T>,
+) -> FreyaRenderer {
+    use freya_core::dom::{
+        FreyaDOM,
+        SafeDOM,
+    };
+
+    let sdom = SafeDOM::new(FreyaDOM::default());
+    let vdom = with_accessibility(app);
+
+    FreyaRenderer::new(window, vdom, sdom, config)
+}
+
 use dioxus_core::VirtualDom;
-#[cfg(any(not(feature = "devtools"), not(debug_assertions)))]
 fn with_accessibility(app: AppComponent) -> VirtualDom {
     use dioxus::prelude::Props;
     use dioxus_core::fc_to_builder;
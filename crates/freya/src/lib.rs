@@ -49,7 +49,17 @@
 //! ## Features flags
 //!
 //! - `devtools`: enables a side panel to inspect your App tree, styles and computed layout.
+//! - `remote-devtools`: lets apps launched with the `FREYA_REMOTE_DEVTOOLS` environment variable, e.g. `127.0.0.1:7878`,
+//!   be inspected from another process with the `RemoteDevTools` component, even in release builds.
 //! - `use_camera`: enables the `use_camera` hook.
+//! - `use_audio`: enables the `use_audio` hook.
+//! - `use_tts`: enables the `use_tts` hook to speak text with the text-to-speech engine of the platform.
+//! - `use_i18n`: enables the `use_init_i18n` and `use_i18n` hooks to translate apps with Fluent.
+//! - `use_syntax_highlighting`: enables the `use_syntax_highlighting` hook to highlight code with tree-sitter.
+//! - `theme_files`: enables the `use_init_theme_from_file` hook to load themes from JSON, TOML or RON files.
+//! - `use_persistent_state`: enables the `use_persistent_state` hook to remember values across app launches.
+//! - `profiling`: instruments the events, layout, text shaping, painting and accessibility with `tracing` spans,
+//!   so they can be inspected in Tracy or Perfetto by installing their `tracing` subscriber before launching the app.
 
 /// Freya docs.
 #[cfg(doc)]
@@ -94,6 +104,10 @@ pub use torin;
 
 pub mod plugins;
 
+/// Inspect apps running in other processes.
+#[cfg(feature = "remote-devtools")]
+pub use freya_devtools::remote as remote_devtools;
+
 /// Useful imports.
 pub mod prelude {
     pub use dioxus_core::{
@@ -112,9 +126,35 @@ pub mod prelude {
             static_bytes,
             CustomAttributeValues,
         },
+        design_tokens::DesignTokens,
+        fonts::{
+            FontError,
+            FontFallbacks,
+            FontScript,
+            FontSource,
+        },
+        global_shortcuts::*,
+        native_menu::*,
+        native_tray::*,
+        open_requests::*,
+        opener::*,
         platform::*,
         platform_state::*,
+        printing::{
+            PageBreakHint,
+            PageOrientation,
+            PageSize,
+            PrintError,
+            PrintOptions,
+            Printer,
+        },
+        render::{
+            ExportError,
+            ExportFormat,
+            ExportTarget,
+        },
         types::AccessibilityId,
+        webview::*,
     };
     pub use freya_elements::{
         self as dioxus_elements,
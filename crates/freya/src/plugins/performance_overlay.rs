@@ -1,14 +1,33 @@
-use std::time::{
-    Duration,
-    Instant,
+use std::{
+    collections::VecDeque,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
-use freya_core::plugins::{
-    FreyaPlugin,
-    PluginEvent,
-    PluginHandle,
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    events::{
+        EventName,
+        PlatformEventData,
+    },
+    platform_state::{
+        FramePhase,
+        FrameStats,
+    },
+    plugins::{
+        FreyaPlugin,
+        PluginEvent,
+        PluginHandle,
+    },
+};
+use freya_elements::events::keyboard::{
+    Key,
+    Modifiers,
 };
 use freya_engine::prelude::{
+    Canvas,
     Color,
     FontStyle,
     Paint,
@@ -23,163 +42,262 @@ use freya_engine::prelude::{
     Width,
 };
 
-#[derive(Default)]
-pub struct PerformanceOverlayPlugin {
-    frames: Vec<Instant>,
-    fps_historic: Vec<usize>,
-    max_fps: usize,
+/// Amount of frames shown in the graphs.
+const HISTORY: usize = 90;
 
-    started_render: Option<Instant>,
+/// Duration of a frame at 60 FPS.
+const FRAME_BUDGET: Duration = Duration::from_micros(16_667);
 
-    started_layout: Option<Instant>,
-    finished_layout: Option<Duration>,
+const PHASES: [(FramePhase, &str, (u8, u8, u8)); 4] = [
+    (FramePhase::PollVDOM, "Poll VDOM", (255, 196, 0)),
+    (FramePhase::Layout, "Layout", (0, 170, 255)),
+    (FramePhase::Paint, "Paint", (63, 255, 0)),
+    (FramePhase::Present, "Present", (255, 80, 200)),
+];
 
-    started_dom_updates: Option<Instant>,
-    finished_dom_updates: Option<Duration>,
+/// Overlay drawn on top of the app with the FPS, the duration of the frames and the time spent in every phase.
+///
+/// Toggle it with `Ctrl+Shift+F`.
+pub struct PerformanceOverlayPlugin {
+    visible: bool,
+    stats: FrameStats,
+    fps_historic: VecDeque<usize>,
+    frames_historic: VecDeque<FrameStats>,
 
     started_events: Option<Instant>,
     finished_events: Option<Duration>,
 }
 
+impl Default for PerformanceOverlayPlugin {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            stats: FrameStats::default(),
+            fps_historic: VecDeque::with_capacity(HISTORY),
+            frames_historic: VecDeque::with_capacity(HISTORY),
+            started_events: None,
+            finished_events: None,
+        }
+    }
+}
+
+impl PerformanceOverlayPlugin {
+    /// Start with the overlay hidden, it can still be shown with `Ctrl+Shift+F`.
+    pub fn hidden() -> Self {
+        Self {
+            visible: false,
+            ..Self::default()
+        }
+    }
+}
+
 impl FreyaPlugin for PerformanceOverlayPlugin {
-    fn on_event(&mut self, event: &PluginEvent, _handle: PluginHandle) {
+    fn on_event(&mut self, event: &PluginEvent, handle: PluginHandle) {
         match event {
-            PluginEvent::StartedMeasuringLayout(_) => self.started_layout = Some(Instant::now()),
-            PluginEvent::FinishedMeasuringLayout(_) => {
-                self.finished_layout = Some(self.started_layout.unwrap().elapsed())
+            PluginEvent::PlatformEvent(platform_event) => {
+                if let PlatformEventData::Keyboard {
+                    key: Key::Character(character),
+                    modifiers,
+                    ..
+                } = &platform_event.data
+                {
+                    if platform_event.name == EventName::KeyDown
+                        && character.eq_ignore_ascii_case("f")
+                        && modifiers.contains(Modifiers::CONTROL | Modifiers::SHIFT)
+                    {
+                        self.visible = !self.visible;
+                        handle.send_event_loop_event(EventLoopMessage::RequestFullRerender);
+                    }
+                }
             }
             PluginEvent::StartedMeasuringEvents => self.started_events = Some(Instant::now()),
             PluginEvent::FinishedMeasuringEvents => {
-                self.finished_events = Some(self.started_events.unwrap().elapsed())
+                self.finished_events = self.started_events.map(|started| started.elapsed())
             }
-            PluginEvent::StartedUpdatingDOM => self.started_dom_updates = Some(Instant::now()),
-            PluginEvent::FinishedUpdatingDOM => {
-                self.finished_dom_updates = Some(self.started_dom_updates.unwrap().elapsed())
+            PluginEvent::FramePresented(stats) => {
+                self.stats = **stats;
+                push_limited(&mut self.fps_historic, stats.fps);
+                push_limited(&mut self.frames_historic, **stats);
             }
-            PluginEvent::BeforeRender { .. } => self.started_render = Some(Instant::now()),
             PluginEvent::AfterRender {
                 canvas,
                 font_collection,
                 freya_dom,
-            } => {
-                let started_render = self.started_render.take().unwrap();
-                let finished_layout = self.finished_layout.unwrap();
-                let finished_events = self.finished_events.unwrap_or_default();
-                let finished_dom_updates = self.finished_dom_updates.unwrap();
-
+            } if self.visible => {
                 let rdom = freya_dom.rdom();
                 let layout = freya_dom.layout();
-
-                let now = Instant::now();
-
-                self.frames
-                    .retain(|frame| now.duration_since(*frame).as_millis() < 1000);
-
-                self.frames.push(now);
+                let stats = &self.stats;
+                let percentiles = &stats.frame_duration_percentiles;
 
                 // Render the texts
                 let mut paragraph_builder =
                     ParagraphBuilder::new(&ParagraphStyle::default(), *font_collection);
-                let mut text_style = TextStyle::default();
-                text_style.set_color(Color::from_rgb(63, 255, 0));
-                text_style.add_shadow(TextShadow::new(
-                    Color::from_rgb(60, 60, 60),
-                    (0.0, 1.0),
-                    1.0,
-                ));
-                paragraph_builder.push_style(&text_style);
-
-                // FPS
-                add_text(
-                    &mut paragraph_builder,
-                    format!("{} FPS\n", self.frames.len()),
-                    30.0,
-                );
 
-                self.fps_historic.push(self.frames.len());
-                if self.fps_historic.len() > 70 {
-                    self.fps_historic.remove(0);
-                }
-
-                // Rendering time
                 add_text(
                     &mut paragraph_builder,
-                    format!("Rendering: {}ms \n", started_render.elapsed().as_millis()),
-                    18.0,
-                );
-
-                // Layout time
-                add_text(
-                    &mut paragraph_builder,
-                    format!("Layout: {}ms \n", finished_layout.as_millis()),
-                    18.0,
+                    format!("{} FPS\n", stats.fps),
+                    30.0,
+                    (63, 255, 0),
                 );
-
-                // Events time
                 add_text(
                     &mut paragraph_builder,
-                    format!("Events: {}ms \n", finished_events.as_millis()),
-                    18.0,
+                    format!(
+                        "Frame: {} p50 {} p95 {} p99 \n",
+                        format_duration(percentiles.p50),
+                        format_duration(percentiles.p95),
+                        format_duration(percentiles.p99)
+                    ),
+                    16.0,
+                    (63, 255, 0),
                 );
-
-                // DOM updates time
+                for (phase, name, color) in PHASES {
+                    add_text(
+                        &mut paragraph_builder,
+                        format!(
+                            "{name}: {} \n",
+                            format_duration(stats.last_frame_timings.get(phase))
+                        ),
+                        16.0,
+                        color,
+                    );
+                }
                 add_text(
                     &mut paragraph_builder,
-                    format!("DOM Updates: {}ms \n", finished_dom_updates.as_millis()),
-                    18.0,
+                    format!(
+                        "Events: {} \n",
+                        format_duration(self.finished_events.unwrap_or_default())
+                    ),
+                    14.0,
+                    (25, 225, 35),
                 );
-
-                // DOM size
                 add_text(
                     &mut paragraph_builder,
-                    format!("{} DOM Nodes \n", rdom.tree_ref().len()),
+                    format!(
+                        "{} DOM Nodes, {} Layout Nodes \n",
+                        rdom.tree_ref().len(),
+                        layout.size()
+                    ),
                     14.0,
+                    (25, 225, 35),
                 );
-
-                // Layout size
                 add_text(
                     &mut paragraph_builder,
-                    format!("{} Layout Nodes \n", layout.size()),
-                    14.0,
+                    "Ctrl+Shift+F to hide".to_string(),
+                    12.0,
+                    (200, 200, 200),
                 );
 
                 let mut paragraph = paragraph_builder.build();
                 paragraph.layout(f32::MAX);
-                paragraph.paint(canvas, (5.0, 0.0));
 
                 let mut paint = Paint::default();
                 paint.set_anti_alias(true);
                 paint.set_style(PaintStyle::Fill);
-                paint.set_color(Color::from_argb(120, 255, 255, 255));
-
-                self.max_fps = self
-                    .max_fps
-                    .max(self.fps_historic.iter().max().copied().unwrap_or_default());
-                let start_x = 5.0;
-                let start_y = 170.0 + self.max_fps.max(60) as f32;
-
-                canvas.draw_rect(Rect::new(5., 150., 200., start_y), &paint);
-
-                for (i, fps) in self.fps_historic.iter().enumerate() {
-                    let mut paint = Paint::default();
-                    paint.set_anti_alias(true);
-                    paint.set_style(PaintStyle::Fill);
-                    paint.set_color(Color::from_rgb(63, 255, 0));
-                    paint.set_stroke_width(3.0);
-
-                    let x = start_x + (i * 2) as f32;
-                    let y = start_y - *fps as f32 + 2.0;
-                    canvas.draw_circle((x, y), 2.0, &paint);
-                }
+                paint.set_color(Color::from_argb(170, 20, 20, 20));
+                canvas.draw_rect(
+                    Rect::from_xywh(0., 0., 250., paragraph.height() + 160.),
+                    &paint,
+                );
+
+                paragraph.paint(canvas, (5.0, 0.0));
+
+                let graphs_y = paragraph.height() + 5.;
+                self.draw_fps_graph(canvas, Rect::from_xywh(5., graphs_y, 240., 70.));
+                self.draw_frames_graph(canvas, Rect::from_xywh(5., graphs_y + 80., 240., 70.));
             }
             _ => {}
         }
     }
 }
 
-fn add_text(paragraph_builder: &mut ParagraphBuilder, text: String, font_size: f32) {
+impl PerformanceOverlayPlugin {
+    /// Line of the FPS of the recent frames.
+    fn draw_fps_graph(&self, canvas: &Canvas, area: Rect) {
+        draw_graph_background(canvas, area);
+
+        let max_fps = self.fps_historic.iter().max().copied().unwrap_or_default();
+        let max_fps = max_fps.max(60) as f32;
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Fill);
+        paint.set_color(Color::from_rgb(63, 255, 0));
+
+        let step = area.width() / HISTORY as f32;
+        for (i, fps) in self.fps_historic.iter().enumerate() {
+            let x = area.left + i as f32 * step;
+            let y = area.bottom - *fps as f32 / max_fps * area.height();
+            canvas.draw_circle((x, y), 1.5, &paint);
+        }
+    }
+
+    /// Stacked bars of the time spent in every phase of the recent frames,
+    /// with a line marking the budget of a frame at 60 FPS.
+    fn draw_frames_graph(&self, canvas: &Canvas, area: Rect) {
+        draw_graph_background(canvas, area);
+
+        let max_duration = self
+            .frames_historic
+            .iter()
+            .map(|stats| stats.last_frame_duration)
+            .max()
+            .unwrap_or_default()
+            .max(FRAME_BUDGET * 2);
+        let height_of = |duration: Duration| {
+            duration.as_secs_f32() / max_duration.as_secs_f32() * area.height()
+        };
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Fill);
+
+        let step = area.width() / HISTORY as f32;
+        for (i, stats) in self.frames_historic.iter().enumerate() {
+            let x = area.left + i as f32 * step;
+            let mut y = area.bottom;
+            for (phase, _, (r, g, b)) in PHASES {
+                let height = height_of(stats.last_frame_timings.get(phase));
+                paint.set_color(Color::from_rgb(r, g, b));
+                canvas.draw_rect(Rect::from_xywh(x, y - height, step, height), &paint);
+                y -= height;
+            }
+        }
+
+        let budget_y = area.bottom - height_of(FRAME_BUDGET);
+        paint.set_style(PaintStyle::Stroke);
+        paint.set_stroke_width(1.);
+        paint.set_color(Color::from_rgb(255, 60, 60));
+        canvas.draw_line((area.left, budget_y), (area.right, budget_y), &paint);
+    }
+}
+
+fn draw_graph_background(canvas: &Canvas, area: Rect) {
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_style(PaintStyle::Fill);
+    paint.set_color(Color::from_argb(60, 255, 255, 255));
+    canvas.draw_rect(area, &paint);
+}
+
+fn push_limited<T>(historic: &mut VecDeque<T>, value: T) {
+    if historic.len() == HISTORY {
+        historic.pop_front();
+    }
+    historic.push_back(value);
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.1}ms", duration.as_secs_f32() * 1000.)
+}
+
+fn add_text(
+    paragraph_builder: &mut ParagraphBuilder,
+    text: String,
+    font_size: f32,
+    (r, g, b): (u8, u8, u8),
+) {
     let mut text_style = TextStyle::default();
-    text_style.set_color(Color::from_rgb(25, 225, 35));
+    text_style.set_color(Color::from_rgb(r, g, b));
     let font_style = FontStyle::new(Weight::BOLD, Width::EXPANDED, Slant::Upright);
     text_style.set_font_style(font_style);
     text_style.add_shadow(TextShadow::new(
@@ -1,5 +1,20 @@
+use std::{
+    ops::Range,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
 use ropey::Rope;
 
+use crate::{
+    TextEdit,
+    TextEdits,
+    TextSpan,
+    TextSpans,
+};
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum HistoryChange {
     InsertChar {
@@ -17,14 +32,107 @@ pub enum HistoryChange {
         len: usize,
         text: String,
     },
+    /// A change of a rich text along with the styles of the inserted or removed text.
+    WithSpans {
+        change: Box<HistoryChange>,
+        spans: Vec<TextSpan>,
+    },
+    /// The styles of a part of a rich text were changed.
+    SetStyle {
+        idx: usize,
+        len: usize,
+        before: Vec<TextSpan>,
+        after: Vec<TextSpan>,
+    },
+    /// Changes that are undone and redone together.
+    Batch(Vec<HistoryChange>),
 }
 
-#[derive(Default, Clone)]
+impl HistoryChange {
+    /// Get the last text change, without the styles.
+    fn last_text_change(&self) -> &HistoryChange {
+        match self {
+            Self::WithSpans { change, .. } => change.last_text_change(),
+            Self::Batch(changes) => changes
+                .last()
+                .map(|change| change.last_text_change())
+                .unwrap_or(self),
+            _ => self,
+        }
+    }
+
+    /// Check whether the given change continues this one, e.g writing the same word.
+    fn is_continued_by(&self, next: &HistoryChange) -> bool {
+        let (last, next) = (self.last_text_change(), next.last_text_change());
+        match (last, next) {
+            // Writing after the inserted text, until a new word is started
+            (
+                Self::InsertChar { idx, len, .. } | Self::InsertText { idx, len, .. },
+                Self::InsertChar { idx: next_idx, .. } | Self::InsertText { idx: next_idx, .. },
+            ) => {
+                let ends_word = last
+                    .inserted_text_edges()
+                    .is_some_and(|(_, last_ch)| !last_ch.is_whitespace());
+                let starts_space = next
+                    .inserted_text_edges()
+                    .is_some_and(|(first_ch, _)| first_ch.is_whitespace());
+                idx + len == *next_idx && !(ends_word && starts_space)
+            }
+            // Removing backwards or forwards from the same position
+            (
+                Self::Remove { idx, .. },
+                Self::Remove {
+                    idx: next_idx,
+                    len: next_len,
+                    ..
+                },
+            ) => next_idx + next_len == *idx || next_idx == idx,
+            // Replacing the removed text
+            (
+                Self::Remove { idx, .. },
+                Self::InsertChar { idx: next_idx, .. } | Self::InsertText { idx: next_idx, .. },
+            ) => idx == next_idx,
+            _ => false,
+        }
+    }
+
+    /// Get the first and last characters of an inserted text.
+    fn inserted_text_edges(&self) -> Option<(char, char)> {
+        match self {
+            Self::InsertChar { ch, .. } => Some((*ch, *ch)),
+            Self::InsertText { text, .. } => text.chars().next().zip(text.chars().last()),
+            _ => None,
+        }
+    }
+}
+
+/// Time in which consecutive changes are batched by default, see [EditorHistory::with_batch_timeout].
+pub const DEFAULT_HISTORY_BATCH_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Undo stack of a text editor.
+///
+/// Consecutive changes, like writing or removing a word, are batched together into a single change
+/// when they happen within the batch timeout.
+#[derive(Clone)]
 pub struct EditorHistory {
     pub changes: Vec<HistoryChange>,
     pub current_change: usize,
     // Incremental counter for every change.
     pub version: usize,
+    batch_timeout: Duration,
+    last_change_time: Option<Instant>,
+}
+
+impl Default for EditorHistory {
+    fn default() -> Self {
+        Self {
+            changes: Vec::new(),
+            current_change: 0,
+            version: 0,
+            batch_timeout: DEFAULT_HISTORY_BATCH_TIMEOUT,
+            last_change_time: None,
+        }
+    }
 }
 
 impl EditorHistory {
@@ -32,17 +140,54 @@ impl EditorHistory {
         Self::default()
     }
 
+    /// Specify the time in which consecutive changes are batched, `Duration::ZERO` disables the batching.
+    pub fn with_batch_timeout(mut self, batch_timeout: Duration) -> Self {
+        self.batch_timeout = batch_timeout;
+        self
+    }
+
     pub fn push_change(&mut self, change: HistoryChange) {
         if self.can_redo() {
             self.changes.drain(self.current_change..);
         }
 
-        self.changes.push(change);
+        let now = Instant::now();
+        let is_batched = self
+            .last_change_time
+            .is_some_and(|last_change_time| now - last_change_time < self.batch_timeout);
+        self.last_change_time = Some(now);
+
+        match self.changes.last_mut() {
+            Some(last_change) if is_batched && last_change.is_continued_by(&change) => {
+                if let HistoryChange::Batch(changes) = last_change {
+                    changes.push(change);
+                } else {
+                    let first_change =
+                        std::mem::replace(last_change, HistoryChange::Batch(Vec::new()));
+                    *last_change = HistoryChange::Batch(vec![first_change, change]);
+                }
+            }
+            _ => self.changes.push(change),
+        }
         self.current_change = self.changes.len();
 
         self.version += 1;
     }
 
+    /// Make the next change start a new batch.
+    pub fn end_batch(&mut self) {
+        self.last_change_time = None;
+    }
+
+    /// Merge the changes made since the given [EditorHistory::current_change] into a single change.
+    pub fn batch_since(&mut self, change: usize) {
+        if self.changes.len() > change + 1 {
+            let changes = self.changes.drain(change..).collect();
+            self.changes.push(HistoryChange::Batch(changes));
+            self.current_change = self.changes.len();
+        }
+    }
+
     pub fn current_change(&self) -> usize {
         self.current_change
     }
@@ -60,31 +205,48 @@ impl EditorHistory {
     }
 
     pub fn undo(&mut self, rope: &mut Rope) -> Option<usize> {
+        self.undo_change(rope, None, None)
+    }
+
+    /// Undo the last change of a rich text, restoring its styles too and recording the edits of the text.
+    pub fn undo_with_spans(
+        &mut self,
+        rope: &mut Rope,
+        spans: &mut TextSpans,
+        edits: &mut TextEdits,
+    ) -> Option<usize> {
+        self.undo_change(rope, Some(spans), Some(edits))
+    }
+
+    pub fn redo(&mut self, rope: &mut Rope) -> Option<usize> {
+        self.redo_change(rope, None, None)
+    }
+
+    /// Redo the last undone change of a rich text, restoring its styles too and recording the edits of the text.
+    pub fn redo_with_spans(
+        &mut self,
+        rope: &mut Rope,
+        spans: &mut TextSpans,
+        edits: &mut TextEdits,
+    ) -> Option<usize> {
+        self.redo_change(rope, Some(spans), Some(edits))
+    }
+
+    fn undo_change(
+        &mut self,
+        rope: &mut Rope,
+        spans: Option<&mut TextSpans>,
+        edits: Option<&mut TextEdits>,
+    ) -> Option<usize> {
         if !self.can_undo() {
             return None;
         }
 
+        self.end_batch();
+
         let last_change = self.changes.get(self.current_change - 1);
         if let Some(last_change) = last_change {
-            let idx_end = match last_change {
-                HistoryChange::Remove { idx, text, len } => {
-                    let start = rope.utf16_cu_to_char(*idx);
-                    rope.insert(start, text);
-                    *idx + len
-                }
-                HistoryChange::InsertChar { idx, len, .. } => {
-                    let start = rope.utf16_cu_to_char(*idx);
-                    let end = rope.utf16_cu_to_char(*idx + len);
-                    rope.remove(start..end);
-                    *idx
-                }
-                HistoryChange::InsertText { idx, len, .. } => {
-                    let start = rope.utf16_cu_to_char(*idx);
-                    let end = rope.utf16_cu_to_char(*idx + len);
-                    rope.remove(start..end);
-                    *idx
-                }
-            };
+            let idx_end = revert_change(last_change, rope, spans, edits);
             self.current_change -= 1;
             self.version += 1;
             Some(idx_end)
@@ -93,31 +255,21 @@ impl EditorHistory {
         }
     }
 
-    pub fn redo(&mut self, rope: &mut Rope) -> Option<usize> {
+    fn redo_change(
+        &mut self,
+        rope: &mut Rope,
+        spans: Option<&mut TextSpans>,
+        edits: Option<&mut TextEdits>,
+    ) -> Option<usize> {
         if !self.can_redo() {
             return None;
         }
 
+        self.end_batch();
+
         let next_change = self.changes.get(self.current_change);
         if let Some(next_change) = next_change {
-            let idx_end = match next_change {
-                HistoryChange::Remove { idx, len, .. } => {
-                    let start = rope.utf16_cu_to_char(*idx);
-                    let end = rope.utf16_cu_to_char(*idx + len);
-                    rope.remove(start..end);
-                    *idx
-                }
-                HistoryChange::InsertChar { idx, ch, len } => {
-                    let start = rope.utf16_cu_to_char(*idx);
-                    rope.insert_char(start, *ch);
-                    *idx + len
-                }
-                HistoryChange::InsertText { idx, text, len } => {
-                    let start = rope.utf16_cu_to_char(*idx);
-                    rope.insert(start, text);
-                    *idx + len
-                }
-            };
+            let idx_end = apply_change(next_change, rope, spans, edits);
             self.current_change += 1;
             self.version += 1;
             Some(idx_end)
@@ -136,11 +288,153 @@ impl EditorHistory {
         self.changes.clear();
         self.current_change = 0;
         self.version = 0;
+        self.end_batch();
+    }
+}
+
+/// Record the edit of the text before it's applied, if the edits are tracked.
+fn record_edit(edits: &mut Option<&mut TextEdits>, rope: &Rope, chars: Range<usize>, text: &str) {
+    if let Some(edits) = edits {
+        edits.push(TextEdit::new(rope, chars, text));
+    }
+}
+
+/// Revert a change, returning the position where the cursor should be placed.
+fn revert_change(
+    change: &HistoryChange,
+    rope: &mut Rope,
+    mut spans: Option<&mut TextSpans>,
+    mut edits: Option<&mut TextEdits>,
+) -> usize {
+    match change {
+        HistoryChange::Remove { idx, text, len } => {
+            let start = rope.utf16_cu_to_char(*idx);
+            record_edit(&mut edits, rope, start..start, text);
+            rope.insert(start, text);
+            if let Some(spans) = spans {
+                let style = spans.style_at(*idx);
+                spans.insert(*idx, *len, style);
+            }
+            *idx + len
+        }
+        HistoryChange::InsertChar { idx, len, .. } | HistoryChange::InsertText { idx, len, .. } => {
+            let start = rope.utf16_cu_to_char(*idx);
+            let end = rope.utf16_cu_to_char(*idx + len);
+            record_edit(&mut edits, rope, start..end, "");
+            rope.remove(start..end);
+            if let Some(spans) = spans {
+                spans.remove(*idx..*idx + len);
+            }
+            *idx
+        }
+        HistoryChange::WithSpans {
+            change,
+            spans: change_spans,
+        } => {
+            let idx_end = revert_change(change, rope, None, edits);
+            if let Some(spans) = spans.as_mut() {
+                match change.as_ref() {
+                    HistoryChange::Remove { idx, .. } => {
+                        spans.insert_spans(*idx, change_spans.clone())
+                    }
+                    HistoryChange::InsertChar { idx, len, .. }
+                    | HistoryChange::InsertText { idx, len, .. } => {
+                        spans.remove(*idx..*idx + len);
+                    }
+                    _ => {}
+                }
+            }
+            idx_end
+        }
+        HistoryChange::SetStyle {
+            idx, len, before, ..
+        } => {
+            if let Some(spans) = spans {
+                spans.replace(*idx..*idx + len, before.clone());
+            }
+            *idx + len
+        }
+        HistoryChange::Batch(changes) => changes.iter().rev().fold(0, |_, change| {
+            revert_change(change, rope, spans.as_deref_mut(), edits.as_deref_mut())
+        }),
+    }
+}
+
+/// Apply a change again, returning the position where the cursor should be placed.
+fn apply_change(
+    change: &HistoryChange,
+    rope: &mut Rope,
+    mut spans: Option<&mut TextSpans>,
+    mut edits: Option<&mut TextEdits>,
+) -> usize {
+    match change {
+        HistoryChange::Remove { idx, len, .. } => {
+            let start = rope.utf16_cu_to_char(*idx);
+            let end = rope.utf16_cu_to_char(*idx + len);
+            record_edit(&mut edits, rope, start..end, "");
+            rope.remove(start..end);
+            if let Some(spans) = spans {
+                spans.remove(*idx..*idx + len);
+            }
+            *idx
+        }
+        HistoryChange::InsertChar { idx, ch, len } => {
+            let start = rope.utf16_cu_to_char(*idx);
+            record_edit(&mut edits, rope, start..start, ch.encode_utf8(&mut [0; 4]));
+            rope.insert_char(start, *ch);
+            if let Some(spans) = spans {
+                let style = spans.style_at(*idx);
+                spans.insert(*idx, *len, style);
+            }
+            *idx + len
+        }
+        HistoryChange::InsertText { idx, text, len } => {
+            let start = rope.utf16_cu_to_char(*idx);
+            record_edit(&mut edits, rope, start..start, text);
+            rope.insert(start, text);
+            if let Some(spans) = spans {
+                let style = spans.style_at(*idx);
+                spans.insert(*idx, *len, style);
+            }
+            *idx + len
+        }
+        HistoryChange::WithSpans {
+            change,
+            spans: change_spans,
+        } => {
+            let idx_end = apply_change(change, rope, None, edits);
+            if let Some(spans) = spans.as_mut() {
+                match change.as_ref() {
+                    HistoryChange::Remove { idx, len, .. } => {
+                        spans.remove(*idx..*idx + len);
+                    }
+                    HistoryChange::InsertChar { idx, .. }
+                    | HistoryChange::InsertText { idx, .. } => {
+                        spans.insert_spans(*idx, change_spans.clone())
+                    }
+                    _ => {}
+                }
+            }
+            idx_end
+        }
+        HistoryChange::SetStyle {
+            idx, len, after, ..
+        } => {
+            if let Some(spans) = spans {
+                spans.replace(*idx..*idx + len, after.clone());
+            }
+            *idx + len
+        }
+        HistoryChange::Batch(changes) => changes.iter().fold(0, |_, change| {
+            apply_change(change, rope, spans.as_deref_mut(), edits.as_deref_mut())
+        }),
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use ropey::Rope;
 
     use super::{
@@ -246,4 +540,50 @@ mod test {
         });
         assert_eq!(history.any_pending_changes(), 0);
     }
+
+    #[test]
+    fn batches() {
+        let mut rope = Rope::new();
+        let mut history = EditorHistory::new().with_batch_timeout(Duration::from_secs(60));
+
+        let write = |rope: &mut Rope, history: &mut EditorHistory, idx: usize, ch: char| {
+            rope.insert_char(idx, ch);
+            history.push_change(HistoryChange::InsertChar { idx, ch, len: 1 });
+        };
+
+        // Write two words
+        for (idx, ch) in "Hello World".chars().enumerate() {
+            write(&mut rope, &mut history, idx, ch);
+        }
+        assert_eq!(rope.to_string(), "Hello World");
+
+        // Every word is undone at once
+        history.undo(&mut rope);
+        assert_eq!(rope.to_string(), "Hello");
+        history.undo(&mut rope);
+        assert_eq!(rope.to_string(), "");
+        assert!(!history.can_undo());
+
+        history.redo(&mut rope);
+        assert_eq!(rope.to_string(), "Hello");
+
+        // Changes after an undo or redo are not batched with the previous ones
+        write(&mut rope, &mut history, 5, '!');
+        history.undo(&mut rope);
+        assert_eq!(rope.to_string(), "Hello");
+
+        // Ending the batch manually
+        write(&mut rope, &mut history, 5, '!');
+        history.end_batch();
+        write(&mut rope, &mut history, 6, '!');
+        history.undo(&mut rope);
+        assert_eq!(rope.to_string(), "Hello!");
+
+        // No batching at all
+        let mut history = EditorHistory::new().with_batch_timeout(Duration::ZERO);
+        write(&mut rope, &mut history, 6, '?');
+        write(&mut rope, &mut history, 7, '?');
+        history.undo(&mut rope);
+        assert_eq!(rope.to_string(), "Hello!?");
+    }
 }
@@ -7,40 +7,120 @@ mod editor_history;
 mod rope_editor;
 mod shader_uniforms;
 mod text_editor;
+mod text_edits;
+mod text_spans;
 mod theming;
 
 mod use_activable_route;
 mod use_animation;
+mod use_animation_clock;
+mod use_app_lifecycle;
 mod use_asset_cacher;
+mod use_async_resource;
 mod use_canvas;
+mod use_debounce;
+mod use_design_tokens;
 mod use_editable;
+mod use_external_texture;
 mod use_focus;
+mod use_fonts;
+mod use_global_shortcuts;
+mod use_history;
 mod use_init_native_platform;
+mod use_menu;
+mod use_monitors;
 mod use_node;
+mod use_node_snapshot;
+mod use_open_requests;
 mod use_platform;
 mod use_platform_information;
 mod use_preferred_theme;
+mod use_responsive;
+mod use_second_instance;
+mod use_shortcuts;
+mod use_soft_keyboard;
+mod use_spellcheck;
+mod use_store;
+mod use_text_search;
+mod use_text_selection;
 mod use_theme;
+mod use_throttle;
+mod use_timer;
+mod use_transition;
+mod use_tray;
+mod use_visibility;
 
+#[cfg(feature = "use_audio")]
+mod use_audio;
 #[cfg(feature = "use_camera")]
 mod use_camera;
+#[cfg(feature = "use_i18n")]
+mod use_i18n;
+#[cfg(feature = "use_persistent_state")]
+mod use_persistent_state;
+#[cfg(feature = "use_syntax_highlighting")]
+mod use_syntax_highlighting;
+#[cfg(feature = "theme_files")]
+mod use_theme_file;
+#[cfg(feature = "use_tts")]
+mod use_tts;
 
 pub use editor_history::*;
 pub use rope_editor::*;
 pub use shader_uniforms::*;
 pub use text_editor::*;
+pub use text_edits::*;
+pub use text_spans::*;
 pub use theming::*;
 pub use use_activable_route::*;
 pub use use_animation::*;
+pub use use_animation_clock::*;
+pub use use_app_lifecycle::*;
 pub use use_asset_cacher::*;
+pub use use_async_resource::*;
+#[cfg(feature = "use_audio")]
+pub use use_audio::*;
 #[cfg(feature = "use_camera")]
 pub use use_camera::*;
 pub use use_canvas::*;
+pub use use_debounce::*;
+pub use use_design_tokens::*;
 pub use use_editable::*;
+pub use use_external_texture::*;
 pub use use_focus::*;
+pub use use_fonts::*;
+pub use use_global_shortcuts::*;
+pub use use_history::*;
+#[cfg(feature = "use_i18n")]
+pub use use_i18n::*;
 pub use use_init_native_platform::*;
+pub use use_menu::*;
+pub use use_monitors::*;
 pub use use_node::*;
+pub use use_node_snapshot::*;
+pub use use_open_requests::*;
+#[cfg(feature = "use_persistent_state")]
+pub use use_persistent_state::*;
 pub use use_platform::*;
 pub use use_platform_information::*;
 pub use use_preferred_theme::*;
+pub use use_responsive::*;
+pub use use_second_instance::*;
+pub use use_shortcuts::*;
+pub use use_soft_keyboard::*;
+pub use use_spellcheck::*;
+pub use use_store::*;
+#[cfg(feature = "use_syntax_highlighting")]
+pub use use_syntax_highlighting::*;
+pub use use_text_search::*;
+pub use use_text_selection::*;
 pub use use_theme::*;
+#[cfg(feature = "theme_files")]
+pub use use_theme_file::*;
+pub use use_throttle::*;
+pub use use_timer::*;
+pub use use_transition::*;
+pub use use_tray::*;
+#[cfg(feature = "use_tts")]
+pub use use_tts::*;
+pub use use_visibility::*;
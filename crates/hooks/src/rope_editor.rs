@@ -1,10 +1,18 @@
 use std::{
-    cmp::Ordering,
+    cmp::{
+        Ordering,
+        Reverse,
+    },
     fmt::Display,
     ops::Range,
 };
 
 use dioxus_clipboard::prelude::UseClipboard;
+use freya_elements::events::keyboard::{
+    Code,
+    Key,
+    Modifiers,
+};
 use ropey::iter::Lines;
 pub use ropey::Rope;
 
@@ -13,8 +21,39 @@ use crate::{
     EditableMode,
     EditorHistory,
     HistoryChange,
+    RichText,
+    SpanStyle,
+    TextEdit,
+    TextEdits,
+    TextSpans,
 };
 
+/// An additional cursor of a [RopeEditor], along with its own selection.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Caret {
+    pub cursor: TextCursor,
+    pub selected: Option<(usize, usize)>,
+}
+
+impl Caret {
+    /// Start of the selection, or the cursor position if there is no selection.
+    fn start(&self) -> usize {
+        self.selected
+            .map(|(from, to)| from.min(to))
+            .unwrap_or(self.cursor.pos())
+    }
+
+    /// Move the caret after a change in the text before it.
+    fn shift(&mut self, diff: isize) {
+        let shift = |pos: usize| pos.saturating_add_signed(diff);
+        self.cursor.set(shift(self.cursor.pos()));
+        if let Some((from, to)) = &mut self.selected {
+            *from = shift(*from);
+            *to = shift(*to);
+        }
+    }
+}
+
 /// TextEditor implementing a Rope
 pub struct RopeEditor {
     pub(crate) rope: Rope,
@@ -24,6 +63,10 @@ pub struct RopeEditor {
     pub(crate) selected: Option<(usize, usize)>,
     pub(crate) clipboard: UseClipboard,
     pub(crate) history: EditorHistory,
+    pub(crate) spans: TextSpans,
+    pub(crate) typing_style: Option<SpanStyle>,
+    pub(crate) carets: Vec<Caret>,
+    pub(crate) edits: TextEdits,
 }
 
 impl Display for RopeEditor {
@@ -43,6 +86,10 @@ impl RopeEditor {
         history: EditorHistory,
     ) -> Self {
         Self {
+            spans: TextSpans::new(text.encode_utf16().count()),
+            typing_style: None,
+            carets: Vec::new(),
+            edits: TextEdits::default(),
             rope: Rope::from_str(&text),
             cursor,
             identation,
@@ -56,6 +103,486 @@ impl RopeEditor {
     pub fn rope(&self) -> &Rope {
         &self.rope
     }
+
+    /// Latest edits of the text.
+    pub fn edits(&self) -> &TextEdits {
+        &self.edits
+    }
+
+    /// Styles of the text.
+    pub fn spans(&self) -> &TextSpans {
+        &self.spans
+    }
+
+    /// Get the text along with its styles.
+    pub fn rich_text(&self) -> RichText {
+        RichText {
+            text: self.rope.to_string(),
+            spans: self.spans.clone(),
+        }
+    }
+
+    /// Replace the text and its styles.
+    pub fn set_rich_text(&mut self, rich_text: &RichText) {
+        self.set(&rich_text.text);
+        self.spans = rich_text.spans.clone();
+        self.spans.fit(self.rope.len_utf16_cu());
+    }
+
+    /// Range of the selected text, or the cursor if there is no selection.
+    fn style_range(&self) -> Range<usize> {
+        self.get_selection_range()
+            .map(|(start, end)| start..end)
+            .unwrap_or(self.cursor_pos()..self.cursor_pos())
+    }
+
+    /// Check whether the selected text, or the text at the cursor if there is no selection, matches the predicate.
+    pub fn has_style(&self, f: impl Fn(&SpanStyle) -> bool) -> bool {
+        if let (true, Some(typing_style)) = (self.selected.is_none(), &self.typing_style) {
+            return f(typing_style);
+        }
+        self.spans.all(self.style_range(), f)
+    }
+
+    /// Change the style of the selected text.
+    /// If there is no selection, the style is used for the text inserted next at the cursor.
+    pub fn update_style(&mut self, f: impl Fn(&mut SpanStyle)) {
+        let range = self.style_range();
+        if range.is_empty() {
+            let mut style = self
+                .typing_style
+                .take()
+                .unwrap_or_else(|| self.spans.style_at(range.start));
+            f(&mut style);
+            self.typing_style = Some(style);
+            return;
+        }
+
+        let before = self.spans.slice(range.clone());
+        self.spans.update_style(range.clone(), f);
+        let after = self.spans.slice(range.clone());
+
+        if before != after {
+            self.history.push_change(HistoryChange::SetStyle {
+                idx: range.start,
+                len: range.len(),
+                before,
+                after,
+            });
+        }
+    }
+
+    /// Toggle the bold style, see [RopeEditor::update_style].
+    pub fn toggle_bold(&mut self) {
+        let bold = !self.has_style(|style| style.bold);
+        self.update_style(|style| style.bold = bold);
+    }
+
+    /// Toggle the italic style, see [RopeEditor::update_style].
+    pub fn toggle_italic(&mut self) {
+        let italic = !self.has_style(|style| style.italic);
+        self.update_style(|style| style.italic = italic);
+    }
+
+    /// Toggle the inline code style, see [RopeEditor::update_style].
+    pub fn toggle_code(&mut self) {
+        let code = !self.has_style(|style| style.code);
+        self.update_style(|style| style.code = code);
+    }
+
+    /// Change the color of the text, see [RopeEditor::update_style].
+    pub fn set_color(&mut self, color: Option<String>) {
+        self.update_style(|style| style.color.clone_from(&color));
+    }
+
+    /// Additional carets, besides the main cursor.
+    pub fn carets(&self) -> &[Caret] {
+        &self.carets
+    }
+
+    /// Keep the current cursor and selection as an additional caret,
+    /// so the main cursor can be moved somewhere else.
+    pub fn push_caret(&mut self) {
+        self.carets.push(Caret {
+            cursor: self.cursor.clone(),
+            selected: self.selected,
+        });
+    }
+
+    /// Remove the additional carets.
+    pub fn clear_carets(&mut self) {
+        self.carets.clear();
+    }
+
+    /// Select the same columns in every line between two positions, with a caret in each line.
+    /// The main cursor is placed in the line of `head`.
+    pub fn select_columns(&mut self, anchor: usize, head: usize) {
+        let (anchor_row, anchor_col) = self.row_and_col(anchor);
+        let (head_row, head_col) = self.row_and_col(head);
+
+        let rows = if anchor_row <= head_row {
+            (anchor_row..=head_row).collect::<Vec<_>>()
+        } else {
+            (head_row..=anchor_row).rev().collect()
+        };
+
+        let mut carets = rows
+            .into_iter()
+            .map(|row| {
+                let row_start = self.char_to_utf16_cu(self.line_to_char(row));
+                let row_len = self.line_len_without_break(row);
+                let from = row_start + anchor_col.min(row_len);
+                let to = row_start + head_col.min(row_len);
+                Caret {
+                    cursor: TextCursor::new(to),
+                    selected: (from != to).then_some((from, to)),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let main = carets.pop().unwrap_or_default();
+        self.cursor = main.cursor;
+        self.selected = main.selected;
+        self.carets = carets;
+    }
+
+    /// Visible selections of the main cursor and the additional carets from a given editor Id.
+    pub fn get_visible_selections(&self, editor_id: usize) -> Vec<(usize, usize)> {
+        self.selected
+            .iter()
+            .chain(
+                self.carets
+                    .iter()
+                    .filter_map(|caret| caret.selected.as_ref()),
+            )
+            .filter_map(|selected| self.visible_selection(*selected, editor_id))
+            .collect()
+    }
+
+    /// Positions of the additional carets that are visible from a given editor Id.
+    pub fn get_visible_carets(&self, editor_id: usize) -> Vec<usize> {
+        self.carets
+            .iter()
+            .filter_map(|caret| {
+                let pos = caret.cursor.pos();
+                if self.mode == EditableMode::SingleLineMultipleEditors {
+                    let (row, col) = self.row_and_col(pos);
+                    (row == editor_id).then_some(col)
+                } else {
+                    Some(pos)
+                }
+            })
+            .collect()
+    }
+
+    /// Find the occurrences of a text, as non-overlapping ranges in UTF-16 code units.
+    pub fn find(&self, query: &str, case_sensitive: bool) -> Vec<(usize, usize)> {
+        let query = query.chars().collect::<Vec<_>>();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let chars_eq =
+            |a: char, b: char| a == b || (!case_sensitive && a.to_lowercase().eq(b.to_lowercase()));
+
+        let chars = self.rope.chars().collect::<Vec<_>>();
+        let mut matches = Vec::new();
+        let mut char_idx = 0;
+        let mut utf16_idx = 0;
+        while char_idx + query.len() <= chars.len() {
+            let candidate = &chars[char_idx..char_idx + query.len()];
+            if candidate.iter().zip(&query).all(|(a, b)| chars_eq(*a, *b)) {
+                let len = candidate.iter().map(|ch| ch.len_utf16()).sum::<usize>();
+                matches.push((utf16_idx, utf16_idx + len));
+                char_idx += query.len();
+                utf16_idx += len;
+            } else {
+                utf16_idx += chars[char_idx].len_utf16();
+                char_idx += 1;
+            }
+        }
+        matches
+    }
+
+    /// Replace the given ranges with a text, as a single change in the history.
+    /// The cursor is placed after the last replacement.
+    pub fn replace_ranges(&mut self, ranges: &[(usize, usize)], text: &str) {
+        let mut ranges = ranges.to_vec();
+        ranges.sort_unstable();
+        let Some(&(_, last_to)) = ranges.last() else {
+            return;
+        };
+
+        self.clear_carets();
+        self.clear_selection();
+        self.history.end_batch();
+        let history_change = self.history.current_change();
+
+        // Replace from the end so the positions of the previous ranges remain valid
+        let mut text_len = 0;
+        for (from, to) in ranges.iter().rev() {
+            self.remove(*from..*to);
+            if !text.is_empty() {
+                text_len = self.insert(text, *from);
+            }
+        }
+
+        self.history.batch_since(history_change);
+        self.history.end_batch();
+
+        // The last range is moved by the length difference of the previous ones
+        let removed_len = ranges.iter().map(|(from, to)| to - from).sum::<usize>();
+        let inserted_len = text_len * ranges.len();
+        self.set_cursor_pos(last_to + inserted_len - removed_len);
+    }
+
+    /// Visible parts of the given ranges from a given editor Id.
+    pub fn get_visible_ranges(
+        &self,
+        ranges: &[(usize, usize)],
+        editor_id: usize,
+    ) -> Vec<(usize, usize)> {
+        ranges
+            .iter()
+            .filter_map(|range| self.visible_selection(*range, editor_id))
+            .collect()
+    }
+
+    /// Process a Keyboard event in the main cursor and in every additional caret, see [TextEditor::process_key].
+    ///
+    /// Selecting all the text, undoing, redoing and `Escape` remove the additional carets instead.
+    pub fn process_key_in_carets(
+        &mut self,
+        key: &Key,
+        code: &Code,
+        modifiers: &Modifiers,
+        allow_tabs: bool,
+        allow_changes: bool,
+        allow_clipboard: bool,
+    ) -> TextEvent {
+        let meta_or_ctrl = if cfg!(target_os = "macos") {
+            modifiers.meta()
+        } else {
+            modifiers.ctrl()
+        };
+
+        let removes_carets = match key {
+            Key::Escape => true,
+            Key::Character(_) => {
+                meta_or_ctrl && matches!(code, Code::KeyA | Code::KeyZ | Code::KeyY)
+            }
+            _ => false,
+        };
+
+        if removes_carets {
+            self.carets.clear();
+        }
+
+        if self.carets.is_empty() {
+            return self.process_key(
+                key,
+                code,
+                modifiers,
+                allow_tabs,
+                allow_changes,
+                allow_clipboard,
+            );
+        }
+
+        // The text of all the selections is copied at once
+        let copied_text =
+            (meta_or_ctrl && allow_clipboard && matches!(code, Code::KeyC | Code::KeyX))
+                .then(|| self.selected_texts().join("\n"));
+
+        let mut carets = self
+            .carets
+            .drain(..)
+            .map(|caret| (false, caret))
+            .collect::<Vec<_>>();
+        carets.push((
+            true,
+            Caret {
+                cursor: self.cursor.clone(),
+                selected: self.selected,
+            },
+        ));
+
+        // Edit from the end to the start, so every change only moves the carets already processed
+        carets.sort_by_key(|(_, caret)| Reverse(caret.start()));
+
+        // The edits of all the carets are undone at once
+        self.history.end_batch();
+        let history_change = self.history.current_change();
+
+        let mut event = TextEvent::empty();
+        let mut processed = Vec::<(bool, Caret)>::with_capacity(carets.len());
+        for (is_main, caret) in carets {
+            self.cursor = caret.cursor;
+            self.selected = caret.selected;
+
+            let len_before = self.len_utf16_cu();
+            event |= self.process_key(
+                key,
+                code,
+                modifiers,
+                allow_tabs,
+                allow_changes,
+                allow_clipboard,
+            );
+            let diff = self.len_utf16_cu() as isize - len_before as isize;
+
+            for (_, processed_caret) in &mut processed {
+                processed_caret.shift(diff);
+            }
+            processed.push((
+                is_main,
+                Caret {
+                    cursor: self.cursor.clone(),
+                    selected: self.selected,
+                },
+            ));
+        }
+
+        self.history.batch_since(history_change);
+        self.history.end_batch();
+
+        if let Some(copied_text) = copied_text {
+            self.clipboard.set(copied_text).ok();
+        }
+
+        let main_idx = processed
+            .iter()
+            .position(|(is_main, _)| *is_main)
+            .unwrap_or_default();
+        let (_, main) = processed.remove(main_idx);
+
+        // Carets that ended up in the same position are merged
+        let mut carets = processed
+            .into_iter()
+            .map(|(_, caret)| caret)
+            .filter(|caret| caret.cursor != main.cursor)
+            .collect::<Vec<_>>();
+        carets.reverse();
+        carets.dedup_by(|caret, prev| caret.cursor == prev.cursor);
+
+        self.cursor = main.cursor;
+        self.selected = main.selected;
+        self.carets = carets;
+
+        event
+    }
+
+    /// Selected text of the main cursor and the additional carets, in order.
+    fn selected_texts(&self) -> Vec<String> {
+        let mut selections = self
+            .carets
+            .iter()
+            .filter_map(|caret| caret.selected)
+            .chain(self.selected)
+            .map(|(from, to)| (from.min(to), from.max(to)))
+            .collect::<Vec<_>>();
+        selections.sort();
+
+        selections
+            .into_iter()
+            .filter_map(|(start, end)| {
+                let start = self.utf16_cu_to_char(start);
+                let end = self.utf16_cu_to_char(end);
+                Some(self.rope.get_slice(start..end)?.to_string())
+            })
+            .collect()
+    }
+
+    /// Row and column of a position.
+    fn row_and_col(&self, pos: usize) -> (usize, usize) {
+        let row = self.char_to_line(self.utf16_cu_to_char(pos));
+        let row_start = self.char_to_utf16_cu(self.line_to_char(row));
+        (row, pos - row_start)
+    }
+
+    /// Length of a line without its line break.
+    fn line_len_without_break(&self, row: usize) -> usize {
+        self.line(row)
+            .map(|line| {
+                line.text
+                    .trim_end_matches(['\n', '\r'])
+                    .encode_utf16()
+                    .count()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Visible part of a selection from a given editor Id.
+    fn visible_selection(
+        &self,
+        (selected_from, selected_to): (usize, usize),
+        editor_id: usize,
+    ) -> Option<(usize, usize)> {
+        if self.mode == EditableMode::SingleLineMultipleEditors {
+            let selected_from_row = self.char_to_line(self.utf16_cu_to_char(selected_from));
+            let selected_to_row = self.char_to_line(self.utf16_cu_to_char(selected_to));
+
+            let editor_row_idx = self.char_to_utf16_cu(self.line_to_char(editor_id));
+            let selected_from_row_idx = self.char_to_utf16_cu(self.line_to_char(selected_from_row));
+            let selected_to_row_idx = self.char_to_utf16_cu(self.line_to_char(selected_to_row));
+
+            let selected_from_col_idx = selected_from - selected_from_row_idx;
+            let selected_to_col_idx = selected_to - selected_to_row_idx;
+
+            // Between starting line and endling line
+            if (editor_id > selected_from_row && editor_id < selected_to_row)
+                || (editor_id < selected_from_row && editor_id > selected_to_row)
+            {
+                let len = self.line(editor_id).unwrap().utf16_len();
+                return Some((0, len));
+            }
+
+            let highlights = match selected_from_row.cmp(&selected_to_row) {
+                // Selection direction is from bottom -> top
+                Ordering::Greater => {
+                    if selected_from_row == editor_id {
+                        // Starting line
+                        Some((0, selected_from_col_idx))
+                    } else if selected_to_row == editor_id {
+                        // Ending line
+                        let len = self.line(selected_to_row).unwrap().utf16_len();
+                        Some((selected_to_col_idx, len))
+                    } else {
+                        None
+                    }
+                }
+                // Selection direction is from top -> bottom
+                Ordering::Less => {
+                    if selected_from_row == editor_id {
+                        // Starting line
+                        let len = self.line(selected_from_row).unwrap().utf16_len();
+                        Some((selected_from_col_idx, len))
+                    } else if selected_to_row == editor_id {
+                        // Ending line
+                        Some((0, selected_to_col_idx))
+                    } else {
+                        None
+                    }
+                }
+                Ordering::Equal if selected_from_row == editor_id => {
+                    // Starting and endline line are the same
+                    Some((selected_from - editor_row_idx, selected_to - editor_row_idx))
+                }
+                _ => None,
+            };
+
+            highlights
+        } else {
+            Some((selected_from, selected_to))
+        }
+    }
+
+    /// Style for text inserted in the given position.
+    fn insert_style(&mut self, idx: usize) -> SpanStyle {
+        self.typing_style
+            .take()
+            .unwrap_or_else(|| self.spans.style_at(idx))
+    }
 }
 
 impl TextEditor for RopeEditor {
@@ -70,15 +597,26 @@ impl TextEditor for RopeEditor {
         let idx_utf8 = self.utf16_cu_to_char(idx);
 
         let len_before_insert = self.rope.len_utf16_cu();
+        self.edits.push(TextEdit::new(
+            &self.rope,
+            idx_utf8..idx_utf8,
+            ch.encode_utf8(&mut [0; 4]),
+        ));
         self.rope.insert_char(idx_utf8, ch);
         let len_after_insert = self.rope.len_utf16_cu();
 
         let inserted_text_len = len_after_insert - len_before_insert;
 
-        self.history.push_change(HistoryChange::InsertChar {
-            idx,
-            ch,
-            len: inserted_text_len,
+        let style = self.insert_style(idx);
+        self.spans.insert(idx, inserted_text_len, style);
+
+        self.history.push_change(HistoryChange::WithSpans {
+            change: Box::new(HistoryChange::InsertChar {
+                idx,
+                ch,
+                len: inserted_text_len,
+            }),
+            spans: self.spans.slice(idx..idx + inserted_text_len),
         });
 
         inserted_text_len
@@ -88,15 +626,23 @@ impl TextEditor for RopeEditor {
         let idx_utf8 = self.utf16_cu_to_char(idx);
 
         let len_before_insert = self.rope.len_utf16_cu();
+        self.edits
+            .push(TextEdit::new(&self.rope, idx_utf8..idx_utf8, text));
         self.rope.insert(idx_utf8, text);
         let len_after_insert = self.rope.len_utf16_cu();
 
         let inserted_text_len = len_after_insert - len_before_insert;
 
-        self.history.push_change(HistoryChange::InsertText {
-            idx,
-            text: text.to_owned(),
-            len: inserted_text_len,
+        let style = self.insert_style(idx);
+        self.spans.insert(idx, inserted_text_len, style);
+
+        self.history.push_change(HistoryChange::WithSpans {
+            change: Box::new(HistoryChange::InsertText {
+                idx,
+                text: text.to_owned(),
+                len: inserted_text_len,
+            }),
+            spans: self.spans.slice(idx..idx + inserted_text_len),
         });
 
         inserted_text_len
@@ -108,15 +654,23 @@ impl TextEditor for RopeEditor {
         let text = self.rope.slice(range.clone()).to_string();
 
         let len_before_remove = self.rope.len_utf16_cu();
+        self.edits
+            .push(TextEdit::new(&self.rope, range.clone(), ""));
         self.rope.remove(range);
         let len_after_remove = self.rope.len_utf16_cu();
 
         let removed_text_len = len_before_remove - len_after_remove;
 
-        self.history.push_change(HistoryChange::Remove {
-            idx: range_utf16.end - removed_text_len,
-            text,
-            len: removed_text_len,
+        let idx = range_utf16.end - removed_text_len;
+        let spans = self.spans.remove(idx..range_utf16.end);
+
+        self.history.push_change(HistoryChange::WithSpans {
+            change: Box::new(HistoryChange::Remove {
+                idx,
+                text,
+                len: removed_text_len,
+            }),
+            spans,
         });
 
         removed_text_len
@@ -189,70 +743,17 @@ impl TextEditor for RopeEditor {
     }
 
     fn get_visible_selection(&self, editor_id: usize) -> Option<(usize, usize)> {
-        let (selected_from, selected_to) = self.selected?;
-
-        if self.mode == EditableMode::SingleLineMultipleEditors {
-            let selected_from_row = self.char_to_line(self.utf16_cu_to_char(selected_from));
-            let selected_to_row = self.char_to_line(self.utf16_cu_to_char(selected_to));
-
-            let editor_row_idx = self.char_to_utf16_cu(self.line_to_char(editor_id));
-            let selected_from_row_idx = self.char_to_utf16_cu(self.line_to_char(selected_from_row));
-            let selected_to_row_idx = self.char_to_utf16_cu(self.line_to_char(selected_to_row));
-
-            let selected_from_col_idx = selected_from - selected_from_row_idx;
-            let selected_to_col_idx = selected_to - selected_to_row_idx;
-
-            // Between starting line and endling line
-            if (editor_id > selected_from_row && editor_id < selected_to_row)
-                || (editor_id < selected_from_row && editor_id > selected_to_row)
-            {
-                let len = self.line(editor_id).unwrap().utf16_len();
-                return Some((0, len));
-            }
-
-            let highlights = match selected_from_row.cmp(&selected_to_row) {
-                // Selection direction is from bottom -> top
-                Ordering::Greater => {
-                    if selected_from_row == editor_id {
-                        // Starting line
-                        Some((0, selected_from_col_idx))
-                    } else if selected_to_row == editor_id {
-                        // Ending line
-                        let len = self.line(selected_to_row).unwrap().utf16_len();
-                        Some((selected_to_col_idx, len))
-                    } else {
-                        None
-                    }
-                }
-                // Selection direction is from top -> bottom
-                Ordering::Less => {
-                    if selected_from_row == editor_id {
-                        // Starting line
-                        let len = self.line(selected_from_row).unwrap().utf16_len();
-                        Some((selected_from_col_idx, len))
-                    } else if selected_to_row == editor_id {
-                        // Ending line
-                        Some((0, selected_to_col_idx))
-                    } else {
-                        None
-                    }
-                }
-                Ordering::Equal if selected_from_row == editor_id => {
-                    // Starting and endline line are the same
-                    Some((selected_from - editor_row_idx, selected_to - editor_row_idx))
-                }
-                _ => None,
-            };
-
-            highlights
-        } else {
-            Some((selected_from, selected_to))
-        }
+        self.visible_selection(self.selected?, editor_id)
     }
 
     fn set(&mut self, text: &str) {
+        self.edits
+            .push(TextEdit::new(&self.rope, 0..self.rope.len_chars(), text));
         self.rope.remove(0..);
         self.rope.insert(0, text);
+        self.spans = TextSpans::new(self.rope.len_utf16_cu());
+        self.typing_style = None;
+        self.carets.clear();
         if self.cursor_pos() > text.len() {
             self.set_cursor_pos(text.len());
         }
@@ -315,11 +816,13 @@ impl TextEditor for RopeEditor {
     }
 
     fn undo(&mut self) -> Option<usize> {
-        self.history.undo(&mut self.rope)
+        self.history
+            .undo_with_spans(&mut self.rope, &mut self.spans, &mut self.edits)
     }
 
     fn redo(&mut self) -> Option<usize> {
-        self.history.redo(&mut self.rope)
+        self.history
+            .redo_with_spans(&mut self.rope, &mut self.spans, &mut self.edits)
     }
 
     fn editor_history(&mut self) -> &mut EditorHistory {
@@ -9,6 +9,7 @@ pub struct UniformsBuilder {
 }
 
 /// Uniform value to be passed to a Shader.
+#[derive(Debug, Clone, PartialEq)]
 pub enum UniformValue {
     Float(f32),
     #[allow(dead_code)]
@@ -21,6 +22,11 @@ impl UniformsBuilder {
         self.uniforms.insert(name.to_string(), value);
     }
 
+    /// Check if a uniform value has been set.
+    pub fn contains(&self, name: &str) -> bool {
+        self.uniforms.contains_key(name)
+    }
+
     /// Build the uniform bytes.
     pub fn build(&self, shader: &RuntimeEffect) -> Vec<u8> {
         let mut values = Vec::new();
@@ -445,6 +445,16 @@ pub trait TextEditor {
                         }
                     }
 
+                    // Redo last change
+                    Code::KeyZ if meta_or_ctrl && modifiers.shift() && allow_changes => {
+                        let redo_result = self.redo();
+
+                        if let Some(idx) = redo_result {
+                            self.set_cursor_pos(idx);
+                            event.insert(TextEvent::TEXT_CHANGED);
+                        }
+                    }
+
                     // Undo last change
                     Code::KeyZ if meta_or_ctrl && allow_changes => {
                         let undo_result = self.undo();
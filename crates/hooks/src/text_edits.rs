@@ -0,0 +1,89 @@
+use std::{
+    collections::VecDeque,
+    ops::Range,
+};
+
+use ropey::Rope;
+
+/// Maximum number of edits remembered by [TextEdits].
+const MAX_EDITS: usize = 256;
+
+/// Replacement of a part of a text with another text, in bytes and in rows and byte columns
+/// like [tree-sitter](https://tree-sitter.github.io/tree-sitter/) expects them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TextEdit {
+    pub start_byte: usize,
+    pub old_end_byte: usize,
+    pub new_end_byte: usize,
+    /// Row and column of `start_byte`.
+    pub start_position: (usize, usize),
+    /// Row and column of `old_end_byte` before the edit.
+    pub old_end_position: (usize, usize),
+    /// Row and column of `new_end_byte` after the edit.
+    pub new_end_position: (usize, usize),
+}
+
+impl TextEdit {
+    /// Edit that replaces the given chars of a rope with a text, created before applying it.
+    pub(crate) fn new(rope: &Rope, chars: Range<usize>, text: &str) -> Self {
+        let position = |byte: usize| {
+            let row = rope.byte_to_line(byte);
+            (row, byte - rope.line_to_byte(row))
+        };
+
+        let start_byte = rope.char_to_byte(chars.start);
+        let old_end_byte = rope.char_to_byte(chars.end);
+        let start_position = position(start_byte);
+
+        let new_end_position = match text.rfind('\n') {
+            Some(last_break) => (
+                start_position.0 + text.matches('\n').count(),
+                text.len() - last_break - 1,
+            ),
+            None => (start_position.0, start_position.1 + text.len()),
+        };
+
+        Self {
+            start_byte,
+            old_end_byte,
+            new_end_byte: start_byte + text.len(),
+            start_position,
+            old_end_position: position(old_end_byte),
+            new_end_position,
+        }
+    }
+}
+
+/// Latest edits of the text of a [RopeEditor](crate::RopeEditor), so the work derived from the text,
+/// e.g syntax highlighting, can be updated incrementally.
+#[derive(Clone, Debug, Default)]
+pub struct TextEdits {
+    edits: VecDeque<TextEdit>,
+    version: u64,
+}
+
+impl TextEdits {
+    pub(crate) fn push(&mut self, edit: TextEdit) {
+        if self.edits.len() == MAX_EDITS {
+            self.edits.pop_front();
+        }
+        self.edits.push_back(edit);
+        self.version += 1;
+    }
+
+    /// Number of edits made to the text so far.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Edits made after the given version, in order.
+    ///
+    /// Returns `None` if they are not remembered anymore, so the text needs to be processed from scratch.
+    pub fn since(&self, version: u64) -> Option<impl Iterator<Item = &TextEdit>> {
+        let count = usize::try_from(self.version.checked_sub(version)?).ok()?;
+        if count > self.edits.len() {
+            return None;
+        }
+        Some(self.edits.iter().skip(self.edits.len() - count))
+    }
+}
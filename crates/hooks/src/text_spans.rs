@@ -0,0 +1,335 @@
+use std::ops::Range;
+
+/// Style of a run of rich text.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SpanStyle {
+    pub bold: bool,
+    pub italic: bool,
+    /// Inline code, shown with a monospace font.
+    pub code: bool,
+    /// Color of the text in any format supported by the `color` attribute, e.g `red`.
+    /// `None` uses the inherited color.
+    pub color: Option<String>,
+}
+
+impl SpanStyle {
+    /// Value for the `font_weight` attribute.
+    pub fn font_weight(&self) -> &'static str {
+        if self.bold {
+            "bold"
+        } else {
+            "normal"
+        }
+    }
+
+    /// Value for the `font_style` attribute.
+    pub fn font_style(&self) -> &'static str {
+        if self.italic {
+            "italic"
+        } else {
+            "upright"
+        }
+    }
+}
+
+/// A run of text with the same [SpanStyle], its length is in UTF-16 code units.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextSpan {
+    pub len: usize,
+    pub style: SpanStyle,
+}
+
+impl TextSpan {
+    pub fn new(len: usize, style: SpanStyle) -> Self {
+        Self { len, style }
+    }
+}
+
+/// Styles of a rich text, as consecutive [TextSpan]s that cover the whole text.
+///
+/// Adjacent spans with the same style are always merged and empty spans removed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextSpans {
+    spans: Vec<TextSpan>,
+}
+
+impl TextSpans {
+    /// Create the spans of a text of the given length with no style.
+    pub fn new(len: usize) -> Self {
+        Self::from_spans(vec![TextSpan::new(len, SpanStyle::default())])
+    }
+
+    /// Create from a list of spans.
+    pub fn from_spans(spans: Vec<TextSpan>) -> Self {
+        let mut text_spans = Self { spans };
+        text_spans.normalize();
+        text_spans
+    }
+
+    /// Get the list of spans.
+    pub fn spans(&self) -> &[TextSpan] {
+        &self.spans
+    }
+
+    /// Length of the styled text.
+    pub fn len(&self) -> usize {
+        self.spans.iter().map(|span| span.len).sum()
+    }
+
+    /// Check whether the styled text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Get the style that text inserted in the given position inherits,
+    /// which is the style of the previous character.
+    pub fn style_at(&self, idx: usize) -> SpanStyle {
+        let mut offset = 0;
+        for span in &self.spans {
+            offset += span.len;
+            if idx <= offset {
+                return span.style.clone();
+            }
+        }
+        self.spans
+            .last()
+            .map(|span| span.style.clone())
+            .unwrap_or_default()
+    }
+
+    /// Check whether all the text in the range matches the given predicate.
+    /// For empty ranges the inherited style of the position is checked.
+    pub fn all(&self, range: Range<usize>, f: impl Fn(&SpanStyle) -> bool) -> bool {
+        if range.is_empty() {
+            return f(&self.style_at(range.start));
+        }
+        self.slice(range).iter().all(|span| f(&span.style))
+    }
+
+    /// Get the spans of a range of the text.
+    pub fn slice(&self, range: Range<usize>) -> Vec<TextSpan> {
+        let mut spans = Vec::new();
+        let mut offset = 0;
+        for span in &self.spans {
+            let start = offset.max(range.start);
+            let end = (offset + span.len).min(range.end);
+            if start < end {
+                spans.push(TextSpan::new(end - start, span.style.clone()));
+            }
+            offset += span.len;
+        }
+        spans
+    }
+
+    /// Insert text with the given style.
+    pub fn insert(&mut self, idx: usize, len: usize, style: SpanStyle) {
+        self.insert_spans(idx, vec![TextSpan::new(len, style)]);
+    }
+
+    /// Insert styled text.
+    pub fn insert_spans(&mut self, idx: usize, spans: Vec<TextSpan>) {
+        let at = self.split_at(idx);
+        self.spans.splice(at..at, spans);
+        self.normalize();
+    }
+
+    /// Remove a range of the text, returning its spans.
+    pub fn remove(&mut self, range: Range<usize>) -> Vec<TextSpan> {
+        let start = self.split_at(range.start);
+        let end = self.split_at(range.end);
+        let removed = self.spans.drain(start..end).collect();
+        self.normalize();
+        removed
+    }
+
+    /// Change the style of a range of the text.
+    pub fn update_style(&mut self, range: Range<usize>, f: impl Fn(&mut SpanStyle)) {
+        let start = self.split_at(range.start);
+        let end = self.split_at(range.end);
+        for span in &mut self.spans[start..end] {
+            f(&mut span.style);
+        }
+        self.normalize();
+    }
+
+    /// Replace the styles of a range of the text, the new spans must have the same length.
+    pub fn replace(&mut self, range: Range<usize>, spans: Vec<TextSpan>) {
+        let idx = range.start;
+        self.remove(range);
+        self.insert_spans(idx, spans);
+    }
+
+    /// Make the spans cover a text of the given length,
+    /// text is removed from or added to the end with the last style.
+    pub fn fit(&mut self, len: usize) {
+        let current_len = self.len();
+        if current_len > len {
+            self.remove(len..current_len);
+        } else if current_len < len {
+            let style = self.style_at(current_len);
+            self.insert(current_len, len - current_len, style);
+        }
+    }
+
+    /// Split the span under the given position, returning the index of the span that starts there.
+    fn split_at(&mut self, idx: usize) -> usize {
+        let mut offset = 0;
+        for i in 0..self.spans.len() {
+            let len = self.spans[i].len;
+            if idx == offset {
+                return i;
+            }
+            if idx < offset + len {
+                let style = self.spans[i].style.clone();
+                self.spans[i].len = idx - offset;
+                self.spans
+                    .insert(i + 1, TextSpan::new(offset + len - idx, style));
+                return i + 1;
+            }
+            offset += len;
+        }
+        self.spans.len()
+    }
+
+    fn normalize(&mut self) {
+        self.spans.retain(|span| span.len > 0);
+        self.spans.dedup_by(|span, prev| {
+            if span.style == prev.style {
+                prev.len += span.len;
+                true
+            } else {
+                false
+            }
+        });
+    }
+}
+
+/// A text with styled spans, see [TextSpans].
+///
+/// # Example
+///
+/// ```rust
+/// # use freya_hooks::{RichText, SpanStyle};
+/// let text = RichText::default()
+///     .with_span("Hello, ", SpanStyle::default())
+///     .with_span(
+///         "World",
+///         SpanStyle {
+///             bold: true,
+///             ..Default::default()
+///         },
+///     );
+/// assert_eq!(text.text, "Hello, World");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RichText {
+    pub text: String,
+    pub spans: TextSpans,
+}
+
+impl RichText {
+    /// Create a text with no style.
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        Self {
+            spans: TextSpans::new(text.encode_utf16().count()),
+            text,
+        }
+    }
+
+    /// Append styled text.
+    pub fn with_span(mut self, text: &str, style: SpanStyle) -> Self {
+        let len = self.spans.len();
+        self.spans.insert(len, text.encode_utf16().count(), style);
+        self.text.push_str(text);
+        self
+    }
+
+    /// Iterate over the text of every span along with its style.
+    pub fn segments(&self) -> impl Iterator<Item = (&str, &SpanStyle)> {
+        let mut rest = self.text.as_str();
+        self.spans.spans().iter().map(move |span| {
+            let mut units = 0;
+            let split_at = rest
+                .char_indices()
+                .find_map(|(i, ch)| {
+                    let found = units >= span.len;
+                    units += ch.len_utf16();
+                    found.then_some(i)
+                })
+                .unwrap_or(rest.len());
+            let (segment, tail) = rest.split_at(split_at);
+            rest = tail;
+            (segment, &span.style)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        SpanStyle,
+        TextSpan,
+        TextSpans,
+    };
+
+    fn bold() -> SpanStyle {
+        SpanStyle {
+            bold: true,
+            ..SpanStyle::default()
+        }
+    }
+
+    #[test]
+    fn edit_spans() {
+        // "Hello World"
+        let mut spans = TextSpans::new(11);
+
+        // Make "World" bold
+        spans.update_style(6..11, |style| style.bold = true);
+        assert_eq!(
+            spans.spans(),
+            &[
+                TextSpan::new(6, SpanStyle::default()),
+                TextSpan::new(5, bold())
+            ]
+        );
+
+        // Text inserted at the end of a span inherits its style
+        assert_eq!(spans.style_at(11), bold());
+        spans.insert(11, 1, spans.style_at(11));
+        assert_eq!(spans.len(), 12);
+        assert_eq!(spans.spans().len(), 2);
+
+        // Removing across spans keeps the styles of the rest of the text
+        let removed = spans.remove(4..8);
+        assert_eq!(
+            removed,
+            vec![
+                TextSpan::new(2, SpanStyle::default()),
+                TextSpan::new(2, bold())
+            ]
+        );
+        assert_eq!(
+            spans.spans(),
+            &[
+                TextSpan::new(4, SpanStyle::default()),
+                TextSpan::new(4, bold())
+            ]
+        );
+
+        // Restore the removed text
+        spans.insert_spans(4, removed);
+        assert_eq!(
+            spans.spans(),
+            &[
+                TextSpan::new(6, SpanStyle::default()),
+                TextSpan::new(6, bold())
+            ]
+        );
+
+        // Same styles are merged
+        spans.update_style(0..6, |style| style.bold = true);
+        assert_eq!(spans.spans(), &[TextSpan::new(12, bold())]);
+    }
+}
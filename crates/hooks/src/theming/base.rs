@@ -80,6 +80,36 @@ pub(crate) const BASE_THEME: Theme = Theme {
         width: cow_borrowed!("auto"),
         height: cow_borrowed!("auto"),
     },
+    ghost_button: ButtonTheme {
+        background: cow_borrowed!("transparent"),
+        hover_background: cow_borrowed!("key(neutral_surface)"),
+        font_theme: FontTheme {
+            color: cow_borrowed!("key(color)"),
+        },
+        border_fill: cow_borrowed!("none"),
+        focus_border_fill: cow_borrowed!("key(focused_border)"),
+        shadow: cow_borrowed!("none"),
+        padding: cow_borrowed!("6 12"),
+        margin: cow_borrowed!("0"),
+        corner_radius: cow_borrowed!("6"),
+        width: cow_borrowed!("auto"),
+        height: cow_borrowed!("auto"),
+    },
+    danger_button: ButtonTheme {
+        background: cow_borrowed!("rgb(200, 50, 50)"),
+        hover_background: cow_borrowed!("rgb(170, 35, 35)"),
+        font_theme: FontTheme {
+            color: cow_borrowed!("white"),
+        },
+        border_fill: cow_borrowed!("none"),
+        focus_border_fill: cow_borrowed!("key(focused_primary_border)"),
+        shadow: cow_borrowed!("none"),
+        padding: cow_borrowed!("6 12"),
+        margin: cow_borrowed!("0"),
+        corner_radius: cow_borrowed!("6"),
+        width: cow_borrowed!("auto"),
+        height: cow_borrowed!("auto"),
+    },
     input: InputTheme {
         background: cow_borrowed!("key(neutral_surface)"),
         hover_background: cow_borrowed!("key(focused_surface)"),
@@ -171,6 +201,16 @@ pub(crate) const BASE_THEME: Theme = Theme {
         width: cow_borrowed!("100%"),
         height: cow_borrowed!("100%"),
     },
+    chart: ChartTheme {
+        width: cow_borrowed!("100%"),
+        height: cow_borrowed!("100%"),
+        background: cow_borrowed!("key(background)"),
+        color: cow_borrowed!("key(color)"),
+        grid_color: cow_borrowed!("key(surface)"),
+        palette: cow_borrowed!(
+            "rgb(59, 130, 246); rgb(239, 68, 68); rgb(34, 197, 94); rgb(234, 179, 8); rgb(168, 85, 247); rgb(20, 184, 166)"
+        ),
+    },
     icon: IconTheme {
         width: cow_borrowed!("10"),
         height: cow_borrowed!("10"),
@@ -205,6 +245,19 @@ pub(crate) const BASE_THEME: Theme = Theme {
         selected_icon_fill: cow_borrowed!("key(secondary)"),
         border_fill: cow_borrowed!("key(surface)"),
     },
+    chip: ChipTheme {
+        background: cow_borrowed!("key(neutral_surface)"),
+        hover_background: cow_borrowed!("key(focused_surface)"),
+        selected_background: cow_borrowed!("key(secondary)"),
+        border_fill: cow_borrowed!("key(surface)"),
+        focus_border_fill: cow_borrowed!("key(focused_border)"),
+        cross_fill: cow_borrowed!("key(solid)"),
+        padding: cow_borrowed!("4 10"),
+        corner_radius: cow_borrowed!("99"),
+        font_theme: FontTheme {
+            color: cow_borrowed!("key(color)"),
+        },
+    },
     menu_item: MenuItemTheme {
         hover_background: cow_borrowed!("key(focused_surface)"),
         corner_radius: cow_borrowed!("6"),
@@ -256,4 +309,46 @@ pub(crate) const BASE_THEME: Theme = Theme {
         background: cow_borrowed!("key(secondary_surface)"),
         hover_background: cow_borrowed!("key(surface)"),
     },
+    caption_button: CaptionButtonTheme {
+        background: cow_borrowed!("transparent"),
+        hover_background: cow_borrowed!("key(focused_surface)"),
+        close_hover_background: cow_borrowed!("rgb(196, 43, 28)"),
+        icon_fill: cow_borrowed!("key(color)"),
+        close_hover_icon_fill: cow_borrowed!("white"),
+        width: cow_borrowed!("46"),
+        height: cow_borrowed!("32"),
+    },
+    emoji_picker: EmojiPickerTheme {
+        background: cow_borrowed!("key(background)"),
+        border_fill: cow_borrowed!("key(surface)"),
+        hover_background: cow_borrowed!("key(focused_surface)"),
+        selected_background: cow_borrowed!("key(neutral_surface)"),
+        color: cow_borrowed!("key(color)"),
+        width: cow_borrowed!("320"),
+        height: cow_borrowed!("360"),
+    },
+    zoomable_view: ZoomableViewTheme {
+        minimap_background: cow_borrowed!("key(background)"),
+        minimap_border_fill: cow_borrowed!("key(surface)"),
+        minimap_content: cow_borrowed!("key(secondary_surface)"),
+        minimap_viewport: cow_borrowed!("key(primary)"),
+    },
+    markdown: MarkdownTheme {
+        color: cow_borrowed!("key(color)"),
+        link_color: cow_borrowed!("key(highlight_color)"),
+        font_size: cow_borrowed!("16"),
+        code_background: cow_borrowed!("key(neutral_surface)"),
+        quote_border_fill: cow_borrowed!("key(surface)"),
+        quote_color: cow_borrowed!("key(placeholder_color)"),
+        divider_fill: cow_borrowed!("key(surface)"),
+    },
+    terminal: TerminalTheme {
+        background: cow_borrowed!("rgb(30, 30, 30)"),
+        foreground: cow_borrowed!("rgb(204, 204, 204)"),
+        cursor: cow_borrowed!("rgb(204, 204, 204)"),
+        selection_background: cow_borrowed!("rgb(38, 79, 120)"),
+        palette: cow_borrowed!("rgb(0, 0, 0);rgb(205, 49, 49);rgb(13, 188, 121);rgb(229, 229, 16);rgb(36, 114, 200);rgb(188, 63, 188);rgb(17, 168, 205);rgb(229, 229, 229);rgb(102, 102, 102);rgb(241, 76, 76);rgb(35, 209, 139);rgb(245, 245, 67);rgb(59, 142, 234);rgb(214, 112, 214);rgb(41, 184, 219);rgb(229, 229, 229)"),
+        font_size: cow_borrowed!("14"),
+        font_family: cow_borrowed!("monospace"),
+    },
 };
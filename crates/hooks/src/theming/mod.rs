@@ -119,6 +119,27 @@ macro_rules! define_theme {
                         }
                     )*)?
                 }
+
+                #[doc = "Set the field with the given name, subtheme fields are separated by a dot, e.g `font_theme.color`. Returns `false` if there is no such field."]
+                pub fn set_field(&mut self, field: &str, value: $crate::Cow<'static, str>) -> bool {
+                    match field.split_once('.') {
+                        $($(
+                            Some((stringify!($subtheme_field_name), subtheme_field)) => {
+                                self.$subtheme_field_name.set_field(subtheme_field, value)
+                            }
+                        )*)?
+                        Some(_) => false,
+                        None => match field {
+                            $($(
+                                stringify!($cow_field_name) => {
+                                    self.$cow_field_name = value;
+                                    true
+                                }
+                            )*)?
+                            _ => false,
+                        },
+                    }
+                }
             }
         }
     };
@@ -367,6 +388,20 @@ define_theme! {
     }
 }
 
+define_theme! {
+    %[component]
+    pub Chart {
+        %[cows]
+        width: str,
+        height: str,
+        background: str,
+        color: str,
+        grid_color: str,
+        /// Colors of the series or slices without their own color, separated by `;`.
+        palette: str,
+    }
+}
+
 define_theme! {
     %[component]
     pub Graph {
@@ -470,6 +505,23 @@ define_theme! {
     }
 }
 
+define_theme! {
+    %[component]
+    pub Chip {
+        %[cows]
+        background: str,
+        hover_background: str,
+        selected_background: str,
+        border_fill: str,
+        focus_border_fill: str,
+        cross_fill: str,
+        padding: str,
+        corner_radius: str,
+        %[subthemes]
+        font_theme: FontTheme,
+    }
+}
+
 define_theme! {
     %[component]
     pub Popup {
@@ -512,6 +564,20 @@ define_theme! {
     }
 }
 
+define_theme! {
+    %[component]
+    pub CaptionButton {
+        %[cows]
+        background: str,
+        hover_background: str,
+        close_hover_background: str,
+        icon_fill: str,
+        close_hover_icon_fill: str,
+        width: str,
+        height: str,
+    }
+}
+
 define_theme! {
     %[component]
     pub ResizableHandle {
@@ -521,6 +587,63 @@ define_theme! {
     }
 }
 
+define_theme! {
+    %[component]
+    pub EmojiPicker {
+        %[cows]
+        background: str,
+        border_fill: str,
+        hover_background: str,
+        selected_background: str,
+        color: str,
+        width: str,
+        height: str,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub ZoomableView {
+        %[cows]
+        minimap_background: str,
+        minimap_border_fill: str,
+        /// Fill of the area of the content in the mini-map.
+        minimap_content: str,
+        /// Border of the visible area in the mini-map.
+        minimap_viewport: str,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub Markdown {
+        %[cows]
+        color: str,
+        link_color: str,
+        /// Base font size, headings are bigger.
+        font_size: str,
+        code_background: str,
+        quote_border_fill: str,
+        quote_color: str,
+        divider_fill: str,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub Terminal {
+        %[cows]
+        background: str,
+        foreground: str,
+        cursor: str,
+        selection_background: str,
+        /// The 16 ANSI colors, separated by `;`.
+        palette: str,
+        font_size: str,
+        font_family: str,
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ColorsSheet {
     pub primary: Cow<'static, str>,
@@ -544,6 +667,33 @@ pub struct ColorsSheet {
 }
 
 impl ColorsSheet {
+    /// Set the color with the given key, e.g `primary`. Returns `false` if there is no such color.
+    pub fn set(&mut self, key: &str, value: Cow<'static, str>) -> bool {
+        let color = match key {
+            "primary" => &mut self.primary,
+            "focused_primary_border" => &mut self.focused_primary_border,
+            "secondary" => &mut self.secondary,
+            "tertiary" => &mut self.tertiary,
+            "surface" => &mut self.surface,
+            "secondary_surface" => &mut self.secondary_surface,
+            "neutral_surface" => &mut self.neutral_surface,
+            "focused_surface" => &mut self.focused_surface,
+            "opposite_surface" => &mut self.opposite_surface,
+            "secondary_opposite_surface" => &mut self.secondary_opposite_surface,
+            "tertiary_opposite_surface" => &mut self.tertiary_opposite_surface,
+            "background" => &mut self.background,
+            "focused_border" => &mut self.focused_border,
+            "solid" => &mut self.solid,
+            "color" => &mut self.color,
+            "primary_color" => &mut self.primary_color,
+            "placeholder_color" => &mut self.placeholder_color,
+            "highlight_color" => &mut self.highlight_color,
+            _ => return false,
+        };
+        *color = value;
+        true
+    }
+
     pub fn resolve(&self, val: Cow<'static, str>) -> Cow<'static, str> {
         if val.starts_with("key") {
             let key_val = val.replace("key(", "").replace(")", "");
@@ -582,6 +732,8 @@ pub struct Theme {
     pub button: ButtonTheme,
     pub filled_button: ButtonTheme,
     pub outline_button: ButtonTheme,
+    pub ghost_button: ButtonTheme,
+    pub danger_button: ButtonTheme,
     pub switch: SwitchTheme,
     pub scroll_bar: ScrollBarTheme,
     pub slider: SliderTheme,
@@ -595,12 +747,14 @@ pub struct Theme {
     pub table: TableTheme,
     pub input: InputTheme,
     pub graph: GraphTheme,
+    pub chart: ChartTheme,
     pub icon: IconTheme,
     pub sidebar: SidebarTheme,
     pub sidebar_item: SidebarItemTheme,
     pub tile: TileTheme,
     pub radio: RadioTheme,
     pub checkbox: CheckboxTheme,
+    pub chip: ChipTheme,
     pub menu_item: MenuItemTheme,
     pub menu_container: MenuContainerTheme,
     pub snackbar: SnackBarTheme,
@@ -608,6 +762,11 @@ pub struct Theme {
     pub tab: TabTheme,
     pub bottom_tab: BottomTabTheme,
     pub resizable_handle: ResizableHandleTheme,
+    pub caption_button: CaptionButtonTheme,
+    pub emoji_picker: EmojiPickerTheme,
+    pub zoomable_view: ZoomableViewTheme,
+    pub markdown: MarkdownTheme,
+    pub terminal: TerminalTheme,
 }
 
 impl Default for Theme {
@@ -615,3 +774,67 @@ impl Default for Theme {
         LIGHT_THEME
     }
 }
+
+impl Theme {
+    /// Set a field of the theme of the given component, e.g `button` and `font_theme.color`.
+    /// Returns `false` if there is no such component or field.
+    pub fn set_component_field(
+        &mut self,
+        component: &str,
+        field: &str,
+        value: Cow<'static, str>,
+    ) -> bool {
+        macro_rules! set_field {
+            ($($component:ident),*) => {
+                match component {
+                    $(
+                        stringify!($component) => self.$component.set_field(field, value),
+                    )*
+                    _ => false,
+                }
+            };
+        }
+
+        set_field!(
+            body,
+            button,
+            filled_button,
+            outline_button,
+            ghost_button,
+            danger_button,
+            switch,
+            scroll_bar,
+            slider,
+            tooltip,
+            dropdown,
+            dropdown_item,
+            accordion,
+            loader,
+            link,
+            progress_bar,
+            table,
+            input,
+            graph,
+            chart,
+            icon,
+            sidebar,
+            sidebar_item,
+            tile,
+            radio,
+            checkbox,
+            chip,
+            menu_item,
+            menu_container,
+            snackbar,
+            popup,
+            tab,
+            bottom_tab,
+            resizable_handle,
+            caption_button,
+            emoji_picker,
+            zoomable_view,
+            markdown,
+            terminal
+        )
+    }
+}
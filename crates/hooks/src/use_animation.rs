@@ -23,12 +23,28 @@ use dioxus_signals::{
     Writable,
 };
 use easer::functions::*;
-use freya_core::parsing::Parse;
+use freya_core::{
+    parsing::{
+        ExtSplit,
+        Parse,
+    },
+    values::{
+        Border,
+        BorderWidth,
+        ConicGradient,
+        Fill,
+        FontVariation,
+        GradientStop,
+        LinearGradient,
+        RadialGradient,
+        Shadow,
+    },
+};
 use freya_engine::prelude::Color;
-use tokio::time::Instant;
 
 use crate::{
     use_platform,
+    AnimationClock,
     UsePlatform,
 };
 
@@ -264,6 +280,448 @@ impl AnimatedValue for AnimColor {
     }
 }
 
+/// Interpolate between two numbers.
+pub(crate) fn mix(origin: f32, destination: f32, progress: f32) -> f32 {
+    origin + (destination - origin) * progress
+}
+
+/// Interpolate between two colors, channel by channel.
+pub(crate) fn mix_color(origin: Color, destination: Color, progress: f32) -> Color {
+    let channel = |origin: u8, destination: u8| {
+        mix(origin as f32, destination as f32, progress).clamp(0., 255.) as u8
+    };
+    Color::from_argb(
+        channel(origin.a(), destination.a()),
+        channel(origin.r(), destination.r()),
+        channel(origin.g(), destination.g()),
+        channel(origin.b(), destination.b()),
+    )
+}
+
+/// Interpolate between two lists of gradient stops.
+/// The shortest list is padded by repeating its last stop.
+fn mix_stops(
+    origin: &[GradientStop],
+    destination: &[GradientStop],
+    progress: f32,
+) -> Vec<GradientStop> {
+    let stop_at = |stops: &[GradientStop], i: usize| {
+        stops.get(i).or(stops.last()).cloned().unwrap_or_default()
+    };
+    (0..origin.len().max(destination.len()))
+        .map(|i| {
+            let (origin, destination) = (stop_at(origin, i), stop_at(destination, i));
+            GradientStop {
+                color: mix_color(origin.color, destination.color, progress),
+                offset: mix(origin.offset, destination.offset, progress),
+            }
+        })
+        .collect()
+}
+
+/// Turn a solid color into a gradient like the given one, so both can be interpolated.
+fn solid_gradient(color: Color, like: &Fill) -> Fill {
+    let mut gradient = like.clone();
+    let stops = match &mut gradient {
+        Fill::Color(_) => return Fill::Color(color),
+        Fill::LinearGradient(gradient) => &mut gradient.stops,
+        Fill::RadialGradient(gradient) => &mut gradient.stops,
+        Fill::ConicGradient(gradient) => &mut gradient.stops,
+    };
+    for stop in stops.iter_mut() {
+        stop.color = color;
+    }
+    gradient
+}
+
+/// Interpolate between two fills.
+/// Gradients of different kinds can't be interpolated, so they switch halfway through.
+pub(crate) fn mix_fill(origin: &Fill, destination: &Fill, progress: f32) -> Fill {
+    match (origin, destination) {
+        (Fill::Color(origin), Fill::Color(destination)) => {
+            Fill::Color(mix_color(*origin, *destination, progress))
+        }
+        (Fill::Color(origin), destination) => {
+            mix_fill(&solid_gradient(*origin, destination), destination, progress)
+        }
+        (origin, Fill::Color(destination)) => {
+            mix_fill(origin, &solid_gradient(*destination, origin), progress)
+        }
+        (Fill::LinearGradient(origin), Fill::LinearGradient(destination)) => {
+            Fill::LinearGradient(Box::new(LinearGradient {
+                stops: mix_stops(&origin.stops, &destination.stops, progress),
+                angle: mix(origin.angle, destination.angle, progress),
+            }))
+        }
+        (Fill::RadialGradient(origin), Fill::RadialGradient(destination)) => {
+            Fill::RadialGradient(Box::new(RadialGradient {
+                stops: mix_stops(&origin.stops, &destination.stops, progress),
+            }))
+        }
+        (Fill::ConicGradient(origin), Fill::ConicGradient(destination)) => {
+            let snap = if progress < 0.5 { origin } else { destination };
+            Fill::ConicGradient(Box::new(ConicGradient {
+                stops: mix_stops(&origin.stops, &destination.stops, progress),
+                angle: match (origin.angle, destination.angle) {
+                    (Some(origin), Some(destination)) => Some(mix(origin, destination, progress)),
+                    _ => snap.angle,
+                },
+                angles: match (origin.angles, destination.angles) {
+                    (Some(origin), Some(destination)) => Some((
+                        mix(origin.0, destination.0, progress),
+                        mix(origin.1, destination.1, progress),
+                    )),
+                    _ => snap.angles,
+                },
+            }))
+        }
+        _ if progress < 0.5 => origin.clone(),
+        _ => destination.clone(),
+    }
+}
+
+/// Interpolate between two shadows.
+pub(crate) fn mix_shadow(origin: &Shadow, destination: &Shadow, progress: f32) -> Shadow {
+    Shadow {
+        position: if progress < 0.5 {
+            origin.position.clone()
+        } else {
+            destination.position.clone()
+        },
+        x: mix(origin.x, destination.x, progress),
+        y: mix(origin.y, destination.y, progress),
+        blur: mix(origin.blur, destination.blur, progress),
+        spread: mix(origin.spread, destination.spread, progress),
+        fill: mix_fill(&origin.fill, &destination.fill, progress),
+    }
+}
+
+/// Interpolate between two borders.
+pub(crate) fn mix_border(origin: &Border, destination: &Border, progress: f32) -> Border {
+    Border {
+        fill: mix_fill(&origin.fill, &destination.fill, progress),
+        width: BorderWidth {
+            top: mix(origin.width.top, destination.width.top, progress),
+            right: mix(origin.width.right, destination.width.right, progress),
+            bottom: mix(origin.width.bottom, destination.width.bottom, progress),
+            left: mix(origin.width.left, destination.width.left, progress),
+        },
+        alignment: if progress < 0.5 {
+            origin.alignment
+        } else {
+            destination.alignment
+        },
+    }
+}
+
+/// Interpolate between two lists of values.
+/// The shortest list is padded with a copy of the other list's value that has a transparent fill,
+/// so it fades in or out.
+fn mix_list<T: Clone>(
+    origin: &[T],
+    destination: &[T],
+    progress: f32,
+    mix_value: fn(&T, &T, f32) -> T,
+    transparent: fn(&T) -> T,
+) -> Vec<T> {
+    (0..origin.len().max(destination.len()))
+        .map(|i| match (origin.get(i), destination.get(i)) {
+            (Some(origin), Some(destination)) => mix_value(origin, destination, progress),
+            (Some(origin), None) => mix_value(origin, &transparent(origin), progress),
+            (None, Some(destination)) => {
+                mix_value(&transparent(destination), destination, progress)
+            }
+            (None, None) => unreachable!(),
+        })
+        .collect()
+}
+
+/// Parse a comma separated list of values, e.g `shadow` or `border`.
+/// Invalid values, like `none` or an empty string, are skipped.
+fn parse_list<T: Parse>(value: &str) -> Vec<T> {
+    value
+        .split_excluding_group(',', '(', ')')
+        .filter_map(|chunk| T::parse(chunk.trim()).ok())
+        .collect()
+}
+
+/// Animate a fill, a color or a gradient, e.g `background`.
+///
+/// Gradients interpolate their stops colors and offsets, and colors turn into gradients and vice versa.
+/// Gradients of different kinds (e.g linear and radial) are not interpolated but switched halfway through.
+#[derive(Clone, PartialEq)]
+pub struct AnimFill {
+    origin: Fill,
+    destination: Fill,
+    time: Duration,
+    ease: Ease,
+    function: Function,
+
+    value: Fill,
+}
+
+impl AnimFill {
+    pub fn new(origin: &str, destination: &str) -> Self {
+        let origin = Fill::parse(origin).unwrap();
+        Self {
+            destination: Fill::parse(destination).unwrap(),
+            time: Duration::default(),
+            ease: Ease::default(),
+            function: Function::default(),
+
+            value: origin.clone(),
+            origin,
+        }
+    }
+
+    /// Set the animation duration using milliseconds. Use `Self::duration` if you want to specify the duration in another form.
+    pub fn time(mut self, time: u64) -> Self {
+        self.time = Duration::from_millis(time);
+        self
+    }
+
+    /// Set the animation duration using milliseconds.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.time = duration;
+        self
+    }
+
+    /// Set the easing type. See `Ease` for all the types.
+    pub fn ease(mut self, ease: Ease) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Set the easing function. See `Function` for all the types.
+    pub fn function(mut self, function: Function) -> Self {
+        self.function = function;
+        self
+    }
+
+    /// Read the value of the [AnimFill] as a String.
+    pub fn read(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+impl AnimatedValue for AnimFill {
+    fn prepare(&mut self, direction: AnimDirection) {
+        match direction {
+            AnimDirection::Forward => self.value = self.origin.clone(),
+            AnimDirection::Reverse => {
+                self.value = self.destination.clone();
+            }
+        }
+    }
+
+    fn is_finished(&self, index: u128, direction: AnimDirection) -> bool {
+        match direction {
+            AnimDirection::Forward => {
+                index > self.time.as_millis() && self.value == self.destination
+            }
+            AnimDirection::Reverse => index > self.time.as_millis() && self.value == self.origin,
+        }
+    }
+
+    fn advance(&mut self, index: u128, direction: AnimDirection) {
+        let (origin, destination) = match direction {
+            AnimDirection::Forward => (&self.origin, &self.destination),
+            AnimDirection::Reverse => (&self.destination, &self.origin),
+        };
+        let index = index.min(self.time.as_millis());
+        self.value = if index == self.time.as_millis() {
+            destination.clone()
+        } else {
+            let progress = apply_value(0., 1., index, self.time, self.ease, self.function);
+            mix_fill(origin, destination, progress)
+        };
+    }
+
+    fn finish(&mut self, direction: AnimDirection) {
+        self.advance(self.time.as_millis(), direction);
+    }
+}
+
+/// Value of a comma separated list that can be animated with [AnimList].
+pub trait AnimListItem: Parse + Clone + PartialEq + fmt::Display + 'static {
+    /// Adapt the parsed lists to each other before animating them, they are kept as they are by default.
+    fn align_lists(origin: Vec<Self>, destination: Vec<Self>) -> (Vec<Self>, Vec<Self>) {
+        (origin, destination)
+    }
+
+    /// Interpolate between two lists of values.
+    fn mix_list(origin: &[Self], destination: &[Self], progress: f32) -> Vec<Self>;
+}
+
+impl AnimListItem for Shadow {
+    fn mix_list(origin: &[Self], destination: &[Self], progress: f32) -> Vec<Self> {
+        mix_list(origin, destination, progress, mix_shadow, |shadow| Shadow {
+            fill: Fill::Color(Color::TRANSPARENT),
+            ..shadow.clone()
+        })
+    }
+}
+
+impl AnimListItem for Border {
+    fn mix_list(origin: &[Self], destination: &[Self], progress: f32) -> Vec<Self> {
+        mix_list(origin, destination, progress, mix_border, |border| Border {
+            fill: Fill::Color(Color::TRANSPARENT),
+            ..border.clone()
+        })
+    }
+}
+
+impl AnimListItem for FontVariation {
+    /// Both lists get the axes that are only present in the other one, so they keep their value.
+    fn align_lists(origin: Vec<Self>, destination: Vec<Self>) -> (Vec<Self>, Vec<Self>) {
+        let find = |variations: &[Self], axis| variations.iter().find(|v| v.axis == axis).copied();
+        let mut axes = Vec::new();
+        for variation in origin.iter().chain(&destination) {
+            if !axes.contains(&variation.axis) {
+                axes.push(variation.axis);
+            }
+        }
+        axes.into_iter()
+            .filter_map(|axis| {
+                let origin_variation = find(&origin, axis);
+                let destination_variation = find(&destination, axis);
+                Some((
+                    origin_variation.or(destination_variation)?,
+                    destination_variation.or(origin_variation)?,
+                ))
+            })
+            .unzip()
+    }
+
+    fn mix_list(origin: &[Self], destination: &[Self], progress: f32) -> Vec<Self> {
+        origin
+            .iter()
+            .zip(destination)
+            .map(|(origin, destination)| FontVariation {
+                axis: origin.axis,
+                value: mix(origin.value, destination.value, progress),
+            })
+            .collect()
+    }
+}
+
+/// Animate a comma separated list of values, like [AnimShadow], [AnimBorder] or [AnimFontVariation].
+///
+/// Invalid values in the lists are skipped.
+#[derive(Clone, PartialEq)]
+pub struct AnimList<T: AnimListItem> {
+    origin: Vec<T>,
+    destination: Vec<T>,
+    time: Duration,
+    ease: Ease,
+    function: Function,
+
+    value: Vec<T>,
+}
+
+/// Animate a list of shadows, e.g `shadow`.
+///
+/// Offsets, blur, spread and fills are interpolated, see [AnimFill].
+/// If one of the lists is shorter, its missing shadows fade in or out.
+pub type AnimShadow = AnimList<Shadow>;
+
+/// Animate a list of borders, e.g `border`.
+///
+/// Widths and fills are interpolated, see [AnimFill].
+/// If one of the lists is shorter, its missing borders fade in or out.
+pub type AnimBorder = AnimList<Border>;
+
+/// Animate the axes of a variable font, e.g `font_variation`.
+///
+/// Axes present in both values are interpolated, the ones only present in one of them keep their value.
+pub type AnimFontVariation = AnimList<FontVariation>;
+
+impl<T: AnimListItem> AnimList<T> {
+    pub fn new(origin: &str, destination: &str) -> Self {
+        let (origin, destination) = T::align_lists(parse_list(origin), parse_list(destination));
+        Self {
+            destination,
+            time: Duration::default(),
+            ease: Ease::default(),
+            function: Function::default(),
+
+            value: origin.clone(),
+            origin,
+        }
+    }
+
+    /// Set the animation duration using milliseconds. Use `Self::duration` if you want to specify the duration in another form.
+    pub fn time(mut self, time: u64) -> Self {
+        self.time = Duration::from_millis(time);
+        self
+    }
+
+    /// Set the animation duration using milliseconds.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.time = duration;
+        self
+    }
+
+    /// Set the easing type. See `Ease` for all the types.
+    pub fn ease(mut self, ease: Ease) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Set the easing function. See `Function` for all the types.
+    pub fn function(mut self, function: Function) -> Self {
+        self.function = function;
+        self
+    }
+
+    /// Read the value of the [AnimList] as a String.
+    pub fn read(&self) -> String {
+        self.value
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl<T: AnimListItem> AnimatedValue for AnimList<T> {
+    fn prepare(&mut self, direction: AnimDirection) {
+        match direction {
+            AnimDirection::Forward => self.value = self.origin.clone(),
+            AnimDirection::Reverse => {
+                self.value = self.destination.clone();
+            }
+        }
+    }
+
+    fn is_finished(&self, index: u128, direction: AnimDirection) -> bool {
+        match direction {
+            AnimDirection::Forward => {
+                index > self.time.as_millis() && self.value == self.destination
+            }
+            AnimDirection::Reverse => index > self.time.as_millis() && self.value == self.origin,
+        }
+    }
+
+    fn advance(&mut self, index: u128, direction: AnimDirection) {
+        let (origin, destination) = match direction {
+            AnimDirection::Forward => (&self.origin, &self.destination),
+            AnimDirection::Reverse => (&self.destination, &self.origin),
+        };
+        let index = index.min(self.time.as_millis());
+        self.value = if index == self.time.as_millis() {
+            destination.clone()
+        } else {
+            let progress = apply_value(0., 1., index, self.time, self.ease, self.function);
+            T::mix_list(origin, destination, progress)
+        };
+    }
+
+    fn finish(&mut self, direction: AnimDirection) {
+        self.advance(self.time.as_millis(), direction);
+    }
+}
+
 /// Chain a sequence of animated values.
 #[derive(Clone)]
 pub struct AnimSequential<Animated: AnimatedValue, const N: usize> {
@@ -324,6 +782,12 @@ impl<Animated: AnimatedValue, const N: usize> AnimatedValue for AnimSequential<A
             value.finish(direction);
         }
     }
+
+    fn carry_over(&mut self, previous: &Self) {
+        for (value, previous) in self.values.iter_mut().zip(previous.values.iter()) {
+            value.carry_over(previous);
+        }
+    }
 }
 
 /// Animate a numeric value.
@@ -420,6 +884,369 @@ impl AnimatedValue for AnimNum {
     }
 }
 
+/// Default stiffness of [AnimSpring].
+const DEFAULT_SPRING_STIFFNESS: f32 = 170.;
+/// Default damping of [AnimSpring].
+const DEFAULT_SPRING_DAMPING: f32 = 26.;
+/// Default mass of [AnimSpring].
+const DEFAULT_SPRING_MASS: f32 = 1.;
+/// Default distance and speed under which an [AnimSpring] is considered at rest.
+const DEFAULT_SPRING_PRECISION: f32 = 0.01;
+
+/// Animate a numeric value with the physics of a damped spring, instead of a fixed duration.
+///
+/// When created again with [use_animation_with_dependencies] and [OnDepsChange::Rerun],
+/// the spring starts from the position and velocity of the previous one, so retargeting it keeps the motion smooth.
+#[derive(Clone, PartialEq)]
+pub struct AnimSpring {
+    origin: f32,
+    destination: f32,
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    precision: f32,
+    initial_velocity: f32,
+
+    start_value: f32,
+    start_velocity: f32,
+    carried_over: Option<(f32, f32)>,
+    has_started: bool,
+
+    value: f32,
+    velocity: f32,
+}
+
+impl AnimSpring {
+    pub fn new(origin: f32, destination: f32) -> Self {
+        Self {
+            origin,
+            destination,
+            stiffness: DEFAULT_SPRING_STIFFNESS,
+            damping: DEFAULT_SPRING_DAMPING,
+            mass: DEFAULT_SPRING_MASS,
+            precision: DEFAULT_SPRING_PRECISION,
+            initial_velocity: 0.,
+
+            start_value: origin,
+            start_velocity: 0.,
+            carried_over: None,
+            has_started: false,
+
+            value: origin,
+            velocity: 0.,
+        }
+    }
+
+    /// Set the stiffness of the spring, higher values make it faster.
+    pub fn stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness.max(f32::EPSILON);
+        self
+    }
+
+    /// Set the damping of the spring, lower values make it bounce more.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping.max(0.);
+        self
+    }
+
+    /// Set the mass of the spring, higher values make it slower.
+    pub fn mass(mut self, mass: f32) -> Self {
+        self.mass = mass.max(f32::EPSILON);
+        self
+    }
+
+    /// Set the initial velocity, in units per second.
+    pub fn velocity(mut self, velocity: f32) -> Self {
+        self.initial_velocity = velocity;
+        self
+    }
+
+    /// Start from the given position and velocity instead of continuing from the spring this one replaces,
+    /// e.g. to settle something the user has just released after dragging it.
+    pub fn start_from(mut self, value: f32, velocity: f32) -> Self {
+        self.carried_over = Some((value, velocity));
+        self
+    }
+
+    /// Set the distance and speed under which the spring is considered at rest.
+    pub fn precision(mut self, precision: f32) -> Self {
+        self.precision = precision.max(f32::EPSILON);
+        self
+    }
+
+    /// Read the value of the [AnimSpring] as a f32.
+    pub fn read(&self) -> f32 {
+        self.value
+    }
+
+    /// Read the current velocity of the [AnimSpring], in units per second.
+    pub fn read_velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    fn target(&self, direction: AnimDirection) -> f32 {
+        match direction {
+            AnimDirection::Forward => self.destination,
+            AnimDirection::Reverse => self.origin,
+        }
+    }
+
+    /// Position and velocity relative to the target after the given seconds,
+    /// using the analytic solution of the damped harmonic oscillator.
+    fn solve(&self, displacement: f32, velocity: f32, time: f32) -> (f32, f32) {
+        let angular_frequency = (self.stiffness / self.mass).sqrt();
+        let damping_ratio = self.damping / (2. * (self.stiffness * self.mass).sqrt());
+
+        if damping_ratio < 1. {
+            // Underdamped, it oscillates around the target
+            let damped_frequency = angular_frequency * (1. - damping_ratio.powi(2)).sqrt();
+            let decay = damping_ratio * angular_frequency;
+            let a = displacement;
+            let b = (velocity + decay * displacement) / damped_frequency;
+            let envelope = (-decay * time).exp();
+            let (sin, cos) = (damped_frequency * time).sin_cos();
+            let position = envelope * (a * cos + b * sin);
+            let velocity =
+                envelope * (-decay * (a * cos + b * sin) + damped_frequency * (b * cos - a * sin));
+            (position, velocity)
+        } else if damping_ratio == 1. {
+            // Critically damped
+            let b = velocity + angular_frequency * displacement;
+            let envelope = (-angular_frequency * time).exp();
+            let position = envelope * (displacement + b * time);
+            let velocity = envelope * (b - angular_frequency * (displacement + b * time));
+            (position, velocity)
+        } else {
+            // Overdamped
+            let root = (damping_ratio.powi(2) - 1.).sqrt();
+            let r1 = -angular_frequency * (damping_ratio - root);
+            let r2 = -angular_frequency * (damping_ratio + root);
+            let c2 = (velocity - r1 * displacement) / (r2 - r1);
+            let c1 = displacement - c2;
+            let (e1, e2) = ((r1 * time).exp(), (r2 * time).exp());
+            (c1 * e1 + c2 * e2, r1 * c1 * e1 + r2 * c2 * e2)
+        }
+    }
+}
+
+impl AnimatedValue for AnimSpring {
+    fn prepare(&mut self, direction: AnimDirection) {
+        let (value, velocity) = self.carried_over.take().unwrap_or_else(|| {
+            let from = match direction {
+                AnimDirection::Forward => self.origin,
+                AnimDirection::Reverse => self.destination,
+            };
+            (from, self.initial_velocity)
+        });
+        self.start_value = value;
+        self.start_velocity = velocity;
+        self.value = value;
+        self.velocity = velocity;
+        self.has_started = true;
+    }
+
+    fn is_finished(&self, _index: u128, direction: AnimDirection) -> bool {
+        self.value == self.target(direction) && self.velocity == 0.
+    }
+
+    fn advance(&mut self, index: u128, direction: AnimDirection) {
+        let target = self.target(direction);
+        let (displacement, velocity) = self.solve(
+            self.start_value - target,
+            self.start_velocity,
+            index as f32 / 1000.,
+        );
+
+        if displacement.abs() < self.precision && velocity.abs() < self.precision {
+            self.value = target;
+            self.velocity = 0.;
+        } else {
+            self.value = target + displacement;
+            self.velocity = velocity;
+        }
+    }
+
+    fn finish(&mut self, direction: AnimDirection) {
+        self.value = self.target(direction);
+        self.velocity = 0.;
+    }
+
+    fn carry_over(&mut self, previous: &Self) {
+        if previous.has_started && self.carried_over.is_none() {
+            self.carried_over = Some((previous.value, previous.velocity));
+        }
+    }
+}
+
+/// A segment of [AnimKeyframes].
+#[derive(Clone, PartialEq)]
+pub struct Keyframe {
+    value: f32,
+    time: Duration,
+    ease: Ease,
+    function: Function,
+}
+
+impl Keyframe {
+    /// Create a keyframe that reaches the given value.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            time: Duration::default(),
+            ease: Ease::default(),
+            function: Function::default(),
+        }
+    }
+
+    /// Set how long it takes to reach this keyframe from the previous one, in milliseconds.
+    pub fn time(mut self, time: u64) -> Self {
+        self.time = Duration::from_millis(time);
+        self
+    }
+
+    /// Set how long it takes to reach this keyframe from the previous one.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.time = duration;
+        self
+    }
+
+    /// Set the easing type of this segment. See `Ease` for all the types.
+    pub fn ease(mut self, ease: Ease) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Set the easing function of this segment. See `Function` for all the types.
+    pub fn function(mut self, function: Function) -> Self {
+        self.function = function;
+        self
+    }
+}
+
+/// Animate a numeric value through multiple keyframes, each with its own duration and easing.
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// let keyframes = AnimKeyframes::new(0.)
+///     .keyframe(Keyframe::new(100.).time(200).function(Function::Quad))
+///     .keyframe(Keyframe::new(50.).time(300).function(Function::Bounce));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct AnimKeyframes {
+    origin: f32,
+    keyframes: Vec<Keyframe>,
+
+    value: f32,
+}
+
+impl AnimKeyframes {
+    pub fn new(origin: f32) -> Self {
+        Self {
+            origin,
+            keyframes: Vec::new(),
+
+            value: origin,
+        }
+    }
+
+    /// Add a keyframe after the last one.
+    pub fn keyframe(mut self, keyframe: Keyframe) -> Self {
+        self.keyframes.push(keyframe);
+        self
+    }
+
+    /// Read the value of the [AnimKeyframes] as a f32.
+    pub fn read(&self) -> f32 {
+        self.value
+    }
+
+    fn total_time(&self) -> u128 {
+        self.keyframes
+            .iter()
+            .map(|keyframe| keyframe.time.as_millis())
+            .sum()
+    }
+
+    fn destination(&self) -> f32 {
+        self.keyframes
+            .last()
+            .map(|keyframe| keyframe.value)
+            .unwrap_or(self.origin)
+    }
+
+    fn start(&self, direction: AnimDirection) -> f32 {
+        match direction {
+            AnimDirection::Forward => self.origin,
+            AnimDirection::Reverse => self.destination(),
+        }
+    }
+
+    fn end(&self, direction: AnimDirection) -> f32 {
+        match direction {
+            AnimDirection::Forward => self.destination(),
+            AnimDirection::Reverse => self.origin,
+        }
+    }
+}
+
+impl AnimatedValue for AnimKeyframes {
+    fn prepare(&mut self, direction: AnimDirection) {
+        self.value = self.start(direction);
+    }
+
+    fn is_finished(&self, index: u128, direction: AnimDirection) -> bool {
+        index > self.total_time() && self.value == self.end(direction)
+    }
+
+    fn advance(&mut self, index: u128, direction: AnimDirection) {
+        let total_time = self.total_time();
+        let mut index = index.min(total_time);
+
+        // In reverse the segments are played from the last to the first one
+        let segments = self.keyframes.iter().enumerate().map(|(i, keyframe)| {
+            let from = if i == 0 {
+                self.origin
+            } else {
+                self.keyframes[i - 1].value
+            };
+            (from, keyframe)
+        });
+        let segments: Box<dyn Iterator<Item = (f32, &Keyframe)>> = match direction {
+            AnimDirection::Forward => Box::new(segments),
+            AnimDirection::Reverse => Box::new(segments.rev()),
+        };
+
+        let mut value = self.start(direction);
+        for (from, keyframe) in segments {
+            let (origin, destination) = match direction {
+                AnimDirection::Forward => (from, keyframe.value),
+                AnimDirection::Reverse => (keyframe.value, from),
+            };
+            let time = keyframe.time.as_millis();
+            if index >= time {
+                index -= time;
+                value = destination;
+                continue;
+            }
+            value = apply_value(
+                origin,
+                destination,
+                index,
+                keyframe.time,
+                keyframe.ease,
+                keyframe.function,
+            );
+            break;
+        }
+
+        self.value = value;
+    }
+
+    fn finish(&mut self, direction: AnimDirection) {
+        self.value = self.end(direction);
+    }
+}
+
 pub trait AnimatedValue: Clone + 'static {
     fn prepare(&mut self, direction: AnimDirection);
 
@@ -428,6 +1255,10 @@ pub trait AnimatedValue: Clone + 'static {
     fn advance(&mut self, index: u128, direction: AnimDirection);
 
     fn finish(&mut self, direction: AnimDirection);
+
+    /// Continue from the state of the value this one replaces, when the animation is recreated
+    /// because its dependencies changed. Does nothing by default.
+    fn carry_over(&mut self, _previous: &Self) {}
 }
 
 #[derive(Default, PartialEq, Clone)]
@@ -609,12 +1440,12 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
 
         let peek_has_run_yet = self.peek_has_run_yet();
         let mut ticker = platform.new_ticker();
+        let clock = AnimationClock::current();
 
         let animation_task = spawn(async move {
             platform.request_animation_frame();
 
-            let mut index = 0u128;
-            let mut prev_frame = Instant::now();
+            let mut started_at = clock.now();
 
             // Prepare the animations with the the proper direction
             value.write().prepare(direction);
@@ -635,15 +1466,13 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
 
                 platform.request_animation_frame();
 
-                index += prev_frame.elapsed().as_millis();
+                let index = (clock.now() - started_at).as_millis();
 
                 let is_finished = value.peek().is_finished(index, direction);
 
                 // Advance the animations
                 value.write().advance(index, direction);
 
-                prev_frame = Instant::now();
-
                 if is_finished {
                     if OnFinish::Reverse == on_finish {
                         // Toggle direction
@@ -651,7 +1480,7 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
                     }
                     match on_finish {
                         OnFinish::Restart | OnFinish::Reverse => {
-                            index = 0;
+                            started_at = clock.now();
 
                             // Restart the animation
                             value.write().prepare(direction);
@@ -752,6 +1581,31 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
 ///     })
 /// }
 /// ```
+///
+/// For natural motion use [`AnimSpring`], which has no fixed duration, or [`AnimKeyframes`] to go through multiple values with their own easing.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let animation = use_animation(|conf| {
+///         conf.auto_start(true);
+///         (
+///             AnimSpring::new(0., 100.).stiffness(200.).damping(10.),
+///             AnimKeyframes::new(0.)
+///                 .keyframe(Keyframe::new(100.).time(200).function(Function::Quad))
+///                 .keyframe(Keyframe::new(50.).time(300).function(Function::Bounce)),
+///         )
+///     });
+///
+///     let (width, height) = &*animation.get().read_unchecked();
+///
+///     rsx!(rect {
+///         width: "{width.read()}",
+///         height: "{height.read()}",
+///         background: "blue"
+///     })
+/// }
+/// ```
 pub fn use_animation<Animated: AnimatedValue>(
     run: impl 'static + Fn(&mut AnimConfiguration) -> Animated,
 ) -> UseAnimation<Animated> {
@@ -763,11 +1617,12 @@ pub fn use_animation<Animated: AnimatedValue>(
     let mut prev_value = use_signal::<Option<Signal<Animated>>>(|| None);
 
     let context = use_memo(move || {
+        let mut conf = AnimConfiguration::default();
+        let mut value = run(&mut conf);
         if let Some(prev_value) = prev_value.take() {
+            value.carry_over(&prev_value.peek());
             prev_value.manually_drop();
         }
-        let mut conf = AnimConfiguration::default();
-        let value = run(&mut conf);
         let value = Signal::new(value);
         prev_value.set(Some(value));
         AnimationContext { value, conf }
@@ -820,11 +1675,12 @@ where
     let mut prev_value = use_signal::<Option<Signal<Animated>>>(|| None);
 
     let context = use_memo(use_reactive(deps, move |deps| {
+        let mut conf = AnimConfiguration::default();
+        let mut value = run(&mut conf, deps);
         if let Some(prev_value) = prev_value.take() {
+            value.carry_over(&prev_value.peek());
             prev_value.manually_drop();
         }
-        let mut conf = AnimConfiguration::default();
-        let value = run(&mut conf, deps);
         let value = Signal::new(value);
         prev_value.set(Some(value));
         AnimationContext { value, conf }
@@ -902,6 +1758,17 @@ macro_rules! impl_tuple_call {
                         $type.finish(direction);
                     )*
                 }
+
+                #[allow(non_snake_case)]
+                fn carry_over(&mut self, previous: &Self) {
+                    let ($($type,)*) = self;
+                    paste::paste! {
+                        let ($([<previous_ $type>],)*) = previous;
+                        $(
+                            $type.carry_over([<previous_ $type>]);
+                        )*
+                    }
+                }
             }
         )*
     };
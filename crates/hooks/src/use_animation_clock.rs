@@ -0,0 +1,158 @@
+use std::{
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+use dioxus_core::prelude::{
+    provide_root_context,
+    try_consume_context,
+    use_hook,
+};
+use tokio::time::Instant;
+
+/// Duration of a frame when stepping with [AnimationClock::step_frame], 60 frames per second.
+const FRAME_DURATION: Duration = Duration::from_nanos(16_666_667);
+
+#[derive(Debug)]
+struct ClockState {
+    /// Clock time when it was last paused, resumed or scaled.
+    base_time: Duration,
+    /// Real time when it was last paused, resumed or scaled.
+    base_instant: Instant,
+    time_scale: f32,
+    is_paused: bool,
+}
+
+impl ClockState {
+    fn now(&self) -> Duration {
+        if self.is_paused {
+            self.base_time
+        } else {
+            self.base_time + self.base_instant.elapsed().mul_f32(self.time_scale)
+        }
+    }
+
+    /// Start counting again from the current time, so the next changes don't affect the past.
+    fn rebase(&mut self) {
+        self.base_time = self.now();
+        self.base_instant = Instant::now();
+    }
+}
+
+/// Clock that drives every animation of the app, see [`use_animation_clock`].
+///
+/// It can be paused, slowed down or sped up, and advanced frame by frame.
+#[derive(Clone, Debug)]
+pub struct AnimationClock {
+    state: Arc<Mutex<ClockState>>,
+}
+
+impl Default for AnimationClock {
+    fn default() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(ClockState {
+                base_time: Duration::ZERO,
+                base_instant: Instant::now(),
+                time_scale: 1.,
+                is_paused: false,
+            })),
+        }
+    }
+}
+
+impl PartialEq for AnimationClock {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl AnimationClock {
+    /// Get the clock of the app, one is created if there isn't any yet.
+    pub fn current() -> Self {
+        match try_consume_context() {
+            Some(clock) => clock,
+            None => provide_root_context(AnimationClock::default()),
+        }
+    }
+
+    /// Time elapsed in the clock since it was created.
+    pub fn now(&self) -> Duration {
+        self.state.lock().unwrap().now()
+    }
+
+    /// Stop the time, animations will stay still until resumed or stepped.
+    pub fn pause(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.rebase();
+        state.is_paused = true;
+    }
+
+    /// Resume the time after being paused.
+    pub fn resume(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.rebase();
+        state.is_paused = false;
+    }
+
+    /// Check whether the clock is paused.
+    pub fn is_paused(&self) -> bool {
+        self.state.lock().unwrap().is_paused
+    }
+
+    /// Set how fast the time goes, e.g `0.1` for slow motion. Defaults to `1.0`.
+    pub fn set_time_scale(&self, time_scale: f32) {
+        let mut state = self.state.lock().unwrap();
+        state.rebase();
+        state.time_scale = time_scale.max(0.);
+    }
+
+    /// Get how fast the time goes.
+    pub fn time_scale(&self) -> f32 {
+        self.state.lock().unwrap().time_scale
+    }
+
+    /// Move the time forward, mostly useful when paused.
+    pub fn step(&self, duration: Duration) {
+        self.state.lock().unwrap().base_time += duration;
+    }
+
+    /// Move the time forward a single frame, mostly useful when paused.
+    pub fn step_frame(&self) {
+        self.step(FRAME_DURATION);
+    }
+}
+
+/// Get the [AnimationClock] that drives every animation of the app.
+///
+/// Useful to debug animations by pausing them, stepping through them frame by frame with [AnimationClock::step_frame],
+/// or playing them in slow motion with [AnimationClock::set_time_scale].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let clock = use_animation_clock();
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| {
+///                 if clock.is_paused() {
+///                     clock.resume();
+///                 } else {
+///                     clock.pause();
+///                 }
+///             },
+///             label {
+///                 "Pause or resume animations"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_animation_clock() -> AnimationClock {
+    use_hook(AnimationClock::current)
+}
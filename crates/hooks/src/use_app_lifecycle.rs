@@ -0,0 +1,148 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use dioxus_core::prelude::{
+    spawn,
+    use_drop,
+    use_hook,
+    Task,
+};
+use dioxus_hooks::{
+    use_context,
+    use_effect,
+    use_memo,
+    use_signal,
+};
+use dioxus_signals::{
+    CopyValue,
+    Memo,
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::platform_state::NativePlatformState;
+use tokio::time::sleep_until;
+
+use crate::use_platform_information;
+
+/// Time without user input after which [use_app_lifecycle] considers the user idle.
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// State of the window kept in sync with the platform.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct WindowLifecycle {
+    is_focused: bool,
+    is_suspended: bool,
+}
+
+impl WindowLifecycle {
+    pub(crate) fn from_state(state: &NativePlatformState) -> Self {
+        Self {
+            is_focused: state.is_window_focused,
+            is_suspended: state.is_suspended,
+        }
+    }
+}
+
+/// When the user last used the mouse, keyboard or touch screen.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct LastUserInput(pub Instant);
+
+/// Whether the app is being used, see [use_app_lifecycle].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AppLifecycle {
+    pub is_focused: bool,
+    pub is_minimized: bool,
+    /// Whether the app is in the background, e.g. in Android.
+    pub is_suspended: bool,
+    /// Whether the user hasn't interacted with the app for a while.
+    pub is_idle: bool,
+}
+
+impl AppLifecycle {
+    /// Whether the window can be seen, e.g. to pause the animations otherwise.
+    pub fn is_visible(&self) -> bool {
+        !self.is_minimized && !self.is_suspended
+    }
+
+    /// Whether the window is visible, focused and the user is not idle.
+    pub fn is_active(&self) -> bool {
+        self.is_visible() && self.is_focused && !self.is_idle
+    }
+}
+
+/// Track whether the window is focused, minimized or in the background and whether the user is idle,
+/// e.g. to pause animations, mute audio or lock the app.
+///
+/// The user becomes idle after [DEFAULT_IDLE_TIMEOUT] without input, see [use_app_lifecycle_with_idle_timeout].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let lifecycle = use_app_lifecycle();
+///
+///     if lifecycle().is_idle {
+///         return rsx!(
+///             label {
+///                 "Locked"
+///             }
+///         );
+///     }
+///
+///     rsx!(
+///         label {
+///             "Focused: {lifecycle().is_focused}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_app_lifecycle() -> Memo<AppLifecycle> {
+    use_app_lifecycle_with_idle_timeout(DEFAULT_IDLE_TIMEOUT)
+}
+
+/// Same as [use_app_lifecycle] but considering the user idle after the given time without input.
+pub fn use_app_lifecycle_with_idle_timeout(idle_timeout: Duration) -> Memo<AppLifecycle> {
+    let window_lifecycle = use_context::<Signal<WindowLifecycle>>();
+    let last_user_input = use_context::<Signal<LastUserInput>>();
+    let platform_information = use_platform_information();
+    let mut is_idle = use_signal(|| false);
+    let mut idle_task = use_hook(|| CopyValue::new(None::<Task>));
+
+    use_effect(move || {
+        let LastUserInput(last_user_input) = *last_user_input.read();
+        if let Some(task) = idle_task.write().take() {
+            task.cancel();
+        }
+        if *is_idle.peek() {
+            is_idle.set(false);
+        }
+        let idle_at = last_user_input + idle_timeout;
+        idle_task.set(Some(spawn(async move {
+            sleep_until(idle_at.into()).await;
+            is_idle.set(true);
+        })));
+    });
+
+    use_drop(move || {
+        if let Some(task) = idle_task.write().take() {
+            task.cancel();
+        }
+    });
+
+    use_memo(move || {
+        let WindowLifecycle {
+            is_focused,
+            is_suspended,
+        } = *window_lifecycle.read();
+        AppLifecycle {
+            is_focused,
+            is_minimized: platform_information.read().is_minimized,
+            is_suspended,
+            is_idle: *is_idle.read(),
+        }
+    })
+}
@@ -0,0 +1,293 @@
+use std::{
+    future::Future,
+    time::Duration,
+};
+
+use dioxus_core::prelude::{
+    spawn,
+    use_drop,
+    use_hook,
+    Task,
+};
+use dioxus_hooks::{
+    use_effect,
+    use_signal,
+};
+use dioxus_signals::{
+    CopyValue,
+    ReadOnlySignal,
+    Readable,
+    Signal,
+    Writable,
+};
+use tokio::time::sleep;
+
+/// State of a [UseAsyncResource].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResourceState<T, E> {
+    /// The future is running, or waiting to be retried.
+    Loading,
+    /// The future succeeded.
+    Ready(T),
+    /// The future failed and there are no retries left.
+    Failed(E),
+}
+
+impl<T, E> ResourceState<T, E> {
+    pub fn is_loading(&self) -> bool {
+        matches!(self, Self::Loading)
+    }
+
+    /// Get the value if it's ready.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Self::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get the error if it failed.
+    pub fn error(&self) -> Option<&E> {
+        match self {
+            Self::Failed(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// How a [UseAsyncResource] retries a failed future, with an exponential backoff.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryOptions {
+    /// Times the future is run again after failing.
+    pub max_retries: u32,
+    /// Time waited before the first retry.
+    pub delay: Duration,
+    /// How much the delay grows after every retry.
+    pub backoff: f32,
+    /// Maximum time waited before a retry.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryOptions {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            delay: Duration::from_millis(500),
+            backoff: 2.0,
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn with_backoff(mut self, backoff: f32) -> Self {
+        self.backoff = backoff.max(1.0);
+        self
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Time waited before the given retry, starting from `0`.
+    pub fn delay_for(&self, retry: u32) -> Duration {
+        let growth = f64::from(self.backoff).powi(retry.try_into().unwrap_or(i32::MAX));
+        // The delay grows past what a Duration can hold after enough retries
+        Duration::try_from_secs_f64(self.delay.as_secs_f64() * growth)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+}
+
+/// Data loaded by a future, see [use_async_resource].
+pub struct UseAsyncResource<T: 'static, E: 'static> {
+    state: Signal<ResourceState<T, E>>,
+    retries: Signal<u32>,
+    restarts: Signal<usize>,
+}
+
+impl<T: 'static, E: 'static> Clone for UseAsyncResource<T, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static, E: 'static> Copy for UseAsyncResource<T, E> {}
+
+impl<T: 'static, E: 'static> PartialEq for UseAsyncResource<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<T: 'static, E: 'static> UseAsyncResource<T, E> {
+    /// Get a readable of the state.
+    pub fn state(&self) -> ReadOnlySignal<ResourceState<T, E>> {
+        self.state.into()
+    }
+
+    /// Whether the future is running, or waiting to be retried.
+    pub fn is_loading(&self) -> bool {
+        self.state.read().is_loading()
+    }
+
+    /// Times the future was retried since it last started.
+    pub fn retries(&self) -> u32 {
+        *self.retries.read()
+    }
+
+    /// Cancel the running future, if any, and run it again from the start.
+    pub fn restart(&mut self) {
+        *self.restarts.write() += 1;
+    }
+}
+
+impl<T: 'static + Clone, E: 'static> UseAsyncResource<T, E> {
+    /// Get the value if it's ready.
+    pub fn value(&self) -> Option<T> {
+        self.state.read().value().cloned()
+    }
+}
+
+/// Run a future and keep track of its result, see [use_async_resource_with_retry] to retry it when failing.
+///
+/// The future runs again when any signal read while creating it changes, cancelling the previous one.
+/// It's also cancelled when the component is dropped, so it never outlives it.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # async fn fetch_user(id: u32) -> Result<String, String> { Ok(id.to_string()) }
+/// fn app() -> Element {
+///     let mut user_id = use_signal(|| 1);
+///     let user = use_async_resource(move || fetch_user(user_id()));
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| user_id += 1,
+///             label { "Next user" }
+///         }
+///         match &*user.state().read() {
+///             ResourceState::Loading => rsx!(label { "Loading..." }),
+///             ResourceState::Ready(name) => rsx!(label { "{name}" }),
+///             ResourceState::Failed(err) => rsx!(label { "Failed: {err}" }),
+///         }
+///     )
+/// }
+/// ```
+pub fn use_async_resource<T, E, F>(generator: impl FnMut() -> F + 'static) -> UseAsyncResource<T, E>
+where
+    T: 'static,
+    E: 'static,
+    F: Future<Output = Result<T, E>> + 'static,
+{
+    use_async_resource_with_retry(RetryOptions::default(), generator)
+}
+
+/// Same as [use_async_resource], but running the future again when it fails,
+/// waiting longer between every retry as configured in the [RetryOptions].
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use freya::prelude::*;
+/// # async fn fetch_weather() -> Result<String, String> { Ok("Sunny".to_string()) }
+/// fn app() -> Element {
+///     let weather = use_async_resource_with_retry(
+///         RetryOptions::new()
+///             .with_max_retries(3)
+///             .with_delay(Duration::from_secs(1)),
+///         fetch_weather,
+///     );
+///
+///     rsx!(
+///         label {
+///             "{weather.value().unwrap_or_default()}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_async_resource_with_retry<T, E, F, G>(
+    options: RetryOptions,
+    generator: G,
+) -> UseAsyncResource<T, E>
+where
+    T: 'static,
+    E: 'static,
+    F: Future<Output = Result<T, E>> + 'static,
+    G: FnMut() -> F + 'static,
+{
+    let mut state = use_signal(|| ResourceState::Loading);
+    let mut retries = use_signal(|| 0);
+    let restarts = use_signal(|| 0);
+    let mut task = use_hook(|| CopyValue::new(None::<Task>));
+
+    // Keep the latest generator, so it doesn't use outdated values when run again
+    let mut latest_generator = use_hook(|| CopyValue::new(None::<G>));
+    latest_generator.set(Some(generator));
+    let mut generate = move || (latest_generator.write().as_mut().unwrap())();
+
+    use_effect(move || {
+        // Subscribe to the restarts and the signals read by the generator
+        restarts.read();
+        let future = generate();
+
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+        if !state.peek().is_loading() {
+            state.set(ResourceState::Loading);
+        }
+        retries.set(0);
+
+        task.set(Some(spawn(async move {
+            let mut result = future.await;
+            loop {
+                match result {
+                    Ok(value) => {
+                        state.set(ResourceState::Ready(value));
+                        break;
+                    }
+                    Err(error) => {
+                        let retry = *retries.peek();
+                        if retry >= options.max_retries {
+                            state.set(ResourceState::Failed(error));
+                            break;
+                        }
+                        sleep(options.delay_for(retry)).await;
+                        retries.set(retry + 1);
+                        result = generate().await;
+                    }
+                }
+            }
+            task.set(None);
+        })));
+    });
+
+    use_drop(move || {
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+    });
+
+    UseAsyncResource {
+        state,
+        retries,
+        restarts,
+    }
+}
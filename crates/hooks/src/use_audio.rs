@@ -0,0 +1,335 @@
+use std::{
+    io::Cursor,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        mpsc::{
+            channel,
+            Sender,
+        },
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use bytes::Bytes;
+use dioxus_core::prelude::{
+    spawn,
+    use_drop,
+};
+use dioxus_hooks::use_signal;
+use dioxus_signals::{
+    ReadOnlySignal,
+    Readable,
+    Signal,
+    Writable,
+};
+use rodio::{
+    source::Buffered,
+    Decoder,
+    OutputStream,
+    OutputStreamHandle,
+    Sink,
+    Source,
+};
+use tokio::sync::mpsc::unbounded_channel;
+
+/// Commands sent to the audio thread.
+enum AudioCommand {
+    Load(Bytes),
+    Play,
+    Pause,
+    Stop,
+    SetVolume(f32),
+    SetLooping(bool),
+    Close,
+}
+
+/// Errors that might happen while playing audio.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AudioError {
+    /// No output device could be opened.
+    OutputUnavailable(String),
+    /// The loaded bytes could not be decoded.
+    Decode(String),
+}
+
+impl std::fmt::Display for AudioError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutputUnavailable(err) => write!(f, "Audio output unavailable: {err}"),
+            Self::Decode(err) => write!(f, "Failed to decode audio: {err}"),
+        }
+    }
+}
+
+/// Handle to control the playback of a sound. Created with [use_audio].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseAudio {
+    sender: Signal<Sender<AudioCommand>>,
+    volume: Signal<f32>,
+    looping: Signal<bool>,
+    error: Signal<Option<AudioError>>,
+}
+
+impl UseAudio {
+    fn send(&self, command: AudioCommand) {
+        self.sender.peek().send(command).ok();
+    }
+
+    /// Load a new sound from its encoded bytes (wav, mp3, ogg or flac).
+    /// This will replace and stop the previous sound.
+    pub fn load(&self, bytes: impl Into<Bytes>) {
+        self.send(AudioCommand::Load(bytes.into()));
+    }
+
+    /// Start or resume the playback.
+    pub fn play(&self) {
+        self.send(AudioCommand::Play);
+    }
+
+    /// Pause the playback.
+    pub fn pause(&self) {
+        self.send(AudioCommand::Pause);
+    }
+
+    /// Stop the playback and rewind to the beginning.
+    pub fn stop(&self) {
+        self.send(AudioCommand::Stop);
+    }
+
+    /// Change the volume, where `1.0` is the original volume.
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume.set(volume);
+        self.send(AudioCommand::SetVolume(volume));
+    }
+
+    /// Enable or disable looping the sound, including the one already playing.
+    pub fn set_looping(&mut self, looping: bool) {
+        self.looping.set(looping);
+        self.send(AudioCommand::SetLooping(looping));
+    }
+
+    /// Get a [ReadOnlySignal] of the volume.
+    pub fn volume(&self) -> ReadOnlySignal<f32> {
+        self.volume.into()
+    }
+
+    /// Get a [ReadOnlySignal] of whether the sound is looping.
+    pub fn looping(&self) -> ReadOnlySignal<bool> {
+        self.looping.into()
+    }
+
+    /// Get a [ReadOnlySignal] of the last error, cleared once a sound is loaded successfully.
+    pub fn error(&self) -> ReadOnlySignal<Option<AudioError>> {
+        self.error.into()
+    }
+}
+
+type AudioSource = Buffered<Decoder<Cursor<Bytes>>>;
+
+/// Decode a sound, so it can be played many times.
+fn decode(bytes: Bytes) -> Result<AudioSource, AudioError> {
+    Decoder::new(Cursor::new(bytes))
+        .map(Source::buffered)
+        .map_err(|err| AudioError::Decode(err.to_string()))
+}
+
+fn build_sink(handle: &OutputStreamHandle, volume: f32) -> Result<Sink, AudioError> {
+    let sink =
+        Sink::try_new(handle).map_err(|err| AudioError::OutputUnavailable(err.to_string()))?;
+    sink.set_volume(volume);
+    sink.pause();
+    Ok(sink)
+}
+
+/// Create a paused sink with the sound queued from the beginning.
+fn queue(
+    handle: &OutputStreamHandle,
+    source: Option<&AudioSource>,
+    looping: &Arc<AtomicBool>,
+    volume: f32,
+) -> Result<Sink, AudioError> {
+    let sink = build_sink(handle, volume)?;
+    if let Some(source) = source {
+        sink.append(LoopingSource::new(source, looping.clone()));
+    }
+    Ok(sink)
+}
+
+/// Plays a sound from the beginning every time it ends, for as long as `looping` is enabled.
+///
+/// Checking the flag while playing lets the sound that is already playing start or stop looping.
+struct LoopingSource {
+    source: AudioSource,
+    current: AudioSource,
+    looping: Arc<AtomicBool>,
+}
+
+impl LoopingSource {
+    fn new(source: &AudioSource, looping: Arc<AtomicBool>) -> Self {
+        Self {
+            source: source.clone(),
+            current: source.clone(),
+            looping,
+        }
+    }
+}
+
+impl Iterator for LoopingSource {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        match self.current.next() {
+            None if self.looping.load(Ordering::Relaxed) => {
+                self.current = self.source.clone();
+                self.current.next()
+            }
+            sample => sample,
+        }
+    }
+}
+
+impl Source for LoopingSource {
+    fn current_frame_len(&self) -> Option<usize> {
+        self.current.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.current.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.current.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// Play sounds without dealing with audio devices or threads.
+///
+/// The audio output is owned by a dedicated thread that lives as long as the component that called this hook.
+///
+/// ## Usage
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// static CLICK: &[u8] = &[];
+///
+/// fn app() -> Element {
+///     let audio = use_audio();
+///
+///     use_hook(move || audio.load(CLICK));
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| {
+///                 audio.stop();
+///                 audio.play();
+///             },
+///             label { "Click" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_audio() -> UseAudio {
+    let mut error = use_signal(|| None);
+    let volume = use_signal(|| 1.0);
+    let looping = use_signal(|| false);
+    let sender = use_signal(move || {
+        let (sender, receiver) = channel::<AudioCommand>();
+        let (error_sender, mut error_receiver) = unbounded_channel::<Option<AudioError>>();
+
+        thread::spawn(move || {
+            let report = |err: AudioError| {
+                error_sender.send(Some(err)).ok();
+            };
+
+            let (_stream, handle) = match OutputStream::try_default() {
+                Ok(stream) => stream,
+                Err(err) => {
+                    report(AudioError::OutputUnavailable(err.to_string()));
+                    return;
+                }
+            };
+            let mut volume = 1.0;
+            let looping = Arc::new(AtomicBool::new(false));
+            let mut source: Option<AudioSource> = None;
+            let mut sink: Option<Sink> = None;
+
+            while let Ok(command) = receiver.recv() {
+                match command {
+                    AudioCommand::Load(bytes) => {
+                        source = None;
+                        match decode(bytes) {
+                            Ok(decoded) => {
+                                source = Some(decoded);
+                                error_sender.send(None).ok();
+                            }
+                            Err(err) => report(err),
+                        }
+                        sink = queue(&handle, source.as_ref(), &looping, volume)
+                            .map_err(report)
+                            .ok();
+                    }
+                    AudioCommand::Play => {
+                        // Queue the sound again once it finished playing
+                        if sink.as_ref().map_or(true, Sink::empty) {
+                            sink = queue(&handle, source.as_ref(), &looping, volume)
+                                .map_err(report)
+                                .ok();
+                        }
+                        if let Some(sink) = &sink {
+                            sink.play();
+                        }
+                    }
+                    AudioCommand::Pause => {
+                        if let Some(sink) = &sink {
+                            sink.pause();
+                        }
+                    }
+                    AudioCommand::Stop => {
+                        sink = queue(&handle, source.as_ref(), &looping, volume)
+                            .map_err(report)
+                            .ok();
+                    }
+                    AudioCommand::SetVolume(new_volume) => {
+                        volume = new_volume;
+                        if let Some(sink) = &sink {
+                            sink.set_volume(volume);
+                        }
+                    }
+                    AudioCommand::SetLooping(new_looping) => {
+                        looping.store(new_looping, Ordering::Relaxed);
+                    }
+                    AudioCommand::Close => break,
+                }
+            }
+        });
+
+        // Forward errors from the audio thread to the signal
+        spawn(async move {
+            while let Some(err) = error_receiver.recv().await {
+                error.set(err);
+            }
+        });
+
+        sender
+    });
+
+    use_drop(move || {
+        sender.peek().send(AudioCommand::Close).ok();
+    });
+
+    UseAudio {
+        sender,
+        volume,
+        looping,
+        error,
+    }
+}
@@ -26,20 +26,29 @@ pub struct UseCanvas {
     runner: Memo<UseCanvasRunner>,
 }
 
-#[derive(Clone)]
-pub struct UseCanvasRunner(pub Arc<Mutex<CanvasRunner>>);
+#[derive(Clone, PartialEq)]
+pub struct UseCanvasRunner(pub CanvasReference);
 
-impl PartialEq for UseCanvasRunner {
-    fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.0, &other.0)
+impl UseCanvasRunner {
+    fn new(runner: impl FnMut(&mut CanvasRunnerContext) + Sync + Send + 'static) -> Self {
+        let runner: Arc<Mutex<CanvasRunner>> = Arc::new(Mutex::new(runner));
+        Self(CanvasReference::new(runner))
     }
 }
 
 impl UseCanvas {
     pub fn attribute(&self) -> AttributeValue {
-        AttributeValue::any_value(CustomAttributeValues::Canvas(CanvasReference {
-            runner: self.runner.read().0.clone(),
-        }))
+        AttributeValue::any_value(CustomAttributeValues::Canvas(self.runner.read().0.clone()))
+    }
+
+    /// Record the rendered output and replay it in the next frames,
+    /// only running the rendering callback again when it changes or when the node area or scale factor change.
+    ///
+    /// Useful for static content that is expensive to draw.
+    pub fn cached_attribute(&self) -> AttributeValue {
+        AttributeValue::any_value(CustomAttributeValues::Canvas(
+            self.runner.read().0.clone().with_cache(true),
+        ))
     }
 }
 
@@ -61,6 +70,8 @@ impl UseCanvas {
 ///         move |ctx| {
 ///             // Draw using the canvas !
 ///             // use `curr`
+///             // `ctx.area` and `ctx.scale_factor` describe where to draw,
+///             // and `ctx.dirty` tells whether any of them changed since the last frame.
 ///         }
 ///     });
 ///
@@ -75,10 +86,12 @@ impl UseCanvas {
 ///     })
 /// }
 /// ```
+///
+/// Use [UseCanvas::cached_attribute] instead of [UseCanvas::attribute] to cache static content.
 pub fn use_canvas<T: FnMut(&mut CanvasRunnerContext) + Sync + Send + 'static>(
     mut renderer_cb: impl FnMut() -> T + 'static,
 ) -> UseCanvas {
-    let runner = use_memo(move || UseCanvasRunner(Arc::new(Mutex::new(renderer_cb()))));
+    let runner = use_memo(move || UseCanvasRunner::new(renderer_cb()));
 
     UseCanvas { runner }
 }
@@ -125,7 +138,7 @@ where
     D::Out: 'static,
 {
     let runner = use_memo(use_reactive(dependencies, move |dependencies| {
-        UseCanvasRunner(Arc::new(Mutex::new(renderer_cb(dependencies))))
+        UseCanvasRunner::new(renderer_cb(dependencies))
     }));
 
     UseCanvas { runner }
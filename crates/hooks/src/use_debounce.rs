@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{
+    spawn,
+    use_drop,
+    use_hook,
+    Callback,
+    Task,
+};
+use dioxus_hooks::{
+    use_callback,
+    use_effect,
+    use_signal,
+};
+use dioxus_signals::{
+    CopyValue,
+    ReadOnlySignal,
+    Readable,
+    Writable,
+};
+use tokio::time::sleep;
+
+/// Run a callback only once some time has passed since it was last requested, see [use_debounce].
+pub struct UseDebounce<T: 'static> {
+    callback: Callback<T>,
+    task: CopyValue<Option<Task>>,
+    duration: Duration,
+}
+
+impl<T: 'static> Clone for UseDebounce<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for UseDebounce<T> {}
+
+impl<T: 'static> PartialEq for UseDebounce<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.task == other.task && self.duration == other.duration
+    }
+}
+
+impl<T: 'static> UseDebounce<T> {
+    /// Run the callback with the given value once the duration passes without new requests,
+    /// forgetting about the pending one.
+    pub fn action(&mut self, value: T) {
+        self.cancel();
+
+        let callback = self.callback;
+        let mut task = self.task;
+        let duration = self.duration;
+        task.set(Some(spawn(async move {
+            sleep(duration).await;
+            task.set(None);
+            callback.call(value);
+        })));
+    }
+
+    /// Forget about the pending request, if any.
+    pub fn cancel(&mut self) {
+        if let Some(task) = self.task.write().take() {
+            task.cancel();
+        }
+    }
+
+    /// Whether there is a request waiting to be run.
+    pub fn is_pending(&self) -> bool {
+        self.task.read().is_some()
+    }
+}
+
+/// Delay running a callback until the given duration passes without it being requested again,
+/// e.g. to search once the user stops typing.
+///
+/// The time is measured with a tokio timer, so it can be controlled in the tests with `advance_time`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut value = use_signal(String::new);
+///     let mut query = use_signal(String::new);
+///     let mut search = use_debounce(Duration::from_millis(300), move |text: String| {
+///         query.set(text);
+///     });
+///
+///     rsx!(
+///         Input {
+///             value,
+///             onchange: move |text: String| {
+///                 value.set(text.clone());
+///                 search.action(text);
+///             }
+///         }
+///         label {
+///             "Searching {query}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_debounce<T: 'static>(
+    duration: Duration,
+    callback: impl FnMut(T) + 'static,
+) -> UseDebounce<T> {
+    let callback = use_callback(callback);
+    let task = use_hook(|| CopyValue::new(None::<Task>));
+
+    use_drop(move || {
+        if let Some(task) = task.write_unchecked().take() {
+            task.cancel();
+        }
+    });
+
+    UseDebounce {
+        callback,
+        task,
+        duration,
+    }
+}
+
+/// Get a copy of a signal that only follows its changes once the given duration passes without new ones.
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut value = use_signal(String::new);
+///     let query = use_debounced_signal(value.into(), Duration::from_millis(300));
+///
+///     rsx!(
+///         Input {
+///             value,
+///             onchange: move |text| value.set(text)
+///         }
+///         label {
+///             "Searching {query}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_debounced_signal<T: 'static + Clone>(
+    source: ReadOnlySignal<T>,
+    duration: Duration,
+) -> ReadOnlySignal<T> {
+    let mut debounced = use_signal(|| source.peek().clone());
+    let mut debounce = use_debounce(duration, move |value| debounced.set(value));
+    let mut is_first_run = use_hook(|| CopyValue::new(true));
+
+    use_effect(move || {
+        let value = source.read().clone();
+        // The initial value is already there
+        if *is_first_run.peek() {
+            is_first_run.set(false);
+            return;
+        }
+        debounce.action(value);
+    });
+
+    debounced.into()
+}
@@ -0,0 +1,46 @@
+use dioxus_hooks::{
+    use_context,
+    use_context_provider,
+};
+use dioxus_signals::Signal;
+use freya_core::design_tokens::DesignTokens;
+
+/// Provide the [`DesignTokens`] referenced by attributes with `token(name)`.
+///
+/// The tokens only apply to the element they are given to with the `design_tokens` attribute and its descendants,
+/// so different parts of the app can provide their own. They are resolved in the same frame they are set.
+///
+/// Any change made to the returned signal will update the elements using the changed tokens.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut tokens = use_init_design_tokens(|| {
+///         DesignTokens::new()
+///             .with("surface-2", "rgb(235, 235, 235)")
+///             .with("spacing", "8")
+///     });
+///
+///     rsx!(
+///         rect {
+///             design_tokens: tokens.read().attribute(),
+///             background: "token(surface-2)",
+///             padding: "token(spacing)",
+///             onclick: move |_| tokens.write().insert("surface-2", "rgb(30, 30, 30)"),
+///             label {
+///                 "Hello, World!"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_init_design_tokens(tokens_cb: impl FnOnce() -> DesignTokens) -> Signal<DesignTokens> {
+    use_context_provider(|| Signal::new(tokens_cb()))
+}
+
+/// Access the [`DesignTokens`] provided with [`use_init_design_tokens`] by the closest ancestor component.
+pub fn use_design_tokens() -> Signal<DesignTokens> {
+    use_context::<Signal<DesignTokens>>()
+}
@@ -1,4 +1,7 @@
-use std::rc::Rc;
+use std::{
+    rc::Rc,
+    time::Duration,
+};
 
 use dioxus_clipboard::prelude::{
     use_clipboard,
@@ -27,7 +30,10 @@ use freya_core::{
 };
 use freya_elements::events::{
     Code,
+    ImeData,
+    Key,
     KeyboardData,
+    Modifiers,
     MouseData,
 };
 use tokio::sync::mpsc::unbounded_channel;
@@ -37,11 +43,14 @@ use uuid::Uuid;
 use crate::{
     use_platform,
     EditorHistory,
+    RichText,
     RopeEditor,
     TextCursor,
     TextEditor,
     TextEvent,
+    TextSpans,
     UsePlatform,
+    DEFAULT_HISTORY_BATCH_TIMEOUT,
 };
 
 /// Events emitted to the [`UseEditable`].
@@ -51,6 +60,7 @@ pub enum EditableEvent {
     MouseDown(Rc<MouseData>, usize),
     KeyDown(Rc<KeyboardData>),
     KeyUp(Rc<KeyboardData>),
+    ImePreedit(Rc<ImeData>),
 }
 
 /// How the editable content must behave.
@@ -85,13 +95,19 @@ pub enum TextDragging {
         cursor: usize,
         dist: Option<CursorPoint>,
     },
+    /// Select the same columns in multiple lines, started with `Alt` pressed.
+    Column {
+        src: CursorPoint,
+        /// Position where the selection started, once measured.
+        anchor: Option<usize>,
+    },
 }
 
 impl TextDragging {
     pub fn has_cursor_coords(&self) -> bool {
         match self {
             Self::None => false,
-            Self::FromPointToPoint { .. } => true,
+            Self::FromPointToPoint { .. } | Self::Column { .. } => true,
             Self::FromCursorToPoint { dist, .. } => dist.is_some(),
         }
     }
@@ -109,7 +125,7 @@ impl TextDragging {
     pub fn get_cursor_coords(&self) -> Option<CursorPoint> {
         match self {
             Self::None => None,
-            Self::FromPointToPoint { src } => Some(*src),
+            Self::FromPointToPoint { src } | Self::Column { src, .. } => Some(*src),
             Self::FromCursorToPoint { dist, clicked, .. } => {
                 if *clicked {
                     *dist
@@ -127,6 +143,8 @@ pub struct UseEditable {
     pub(crate) editor: Signal<RopeEditor>,
     pub(crate) cursor_reference: Signal<CursorReference>,
     pub(crate) dragging: Signal<TextDragging>,
+    pub(crate) preedit: Signal<String>,
+    pub(crate) modifiers: Signal<Modifiers>,
     pub(crate) platform: UsePlatform,
     pub(crate) allow_tabs: bool,
     pub(crate) allow_changes: bool,
@@ -142,15 +160,20 @@ impl UseEditable {
         mode: EditableMode,
     ) -> Self {
         let text_id = Uuid::new_v4();
-        let mut editor = Signal::new(RopeEditor::new(
+        let mut rope_editor = RopeEditor::new(
             config.content,
             config.cursor,
             config.identation,
             mode,
             clipboard,
-            EditorHistory::new(),
-        ));
-        let dragging = Signal::new(TextDragging::None);
+            EditorHistory::new().with_batch_timeout(config.history_batch_timeout),
+        );
+        if let Some(mut spans) = config.spans {
+            spans.fit(rope_editor.len_utf16_cu());
+            rope_editor.spans = spans;
+        }
+        let mut editor = Signal::new(rope_editor);
+        let mut dragging = Signal::new(TextDragging::None);
         let (cursor_sender, mut cursor_receiver) = unbounded_channel::<CursorLayoutResponse>();
         let cursor_reference = CursorReference {
             text_id,
@@ -165,6 +188,18 @@ impl UseEditable {
                         let mut text_editor = editor.write();
                         let new_cursor = text_editor.measure_new_cursor(position, id);
 
+                        // Anchor the column selection where it started
+                        let column_src = match *dragging.peek() {
+                            TextDragging::Column { src, anchor: None } => Some(src),
+                            _ => None,
+                        };
+                        if let Some(src) = column_src {
+                            dragging.set(TextDragging::Column {
+                                src,
+                                anchor: Some(new_cursor.pos()),
+                            });
+                        }
+
                         // Only update and clear the selection if the cursor has changed
                         if *text_editor.cursor() != new_cursor {
                             *text_editor.cursor_mut() = new_cursor;
@@ -180,6 +215,18 @@ impl UseEditable {
                     }
                     // Update the text selections calculated by the layout
                     CursorLayoutResponse::TextSelection { from, to, id } => {
+                        let column_anchor = match &*dragging.peek() {
+                            TextDragging::Column { anchor, .. } => *anchor,
+                            _ => None,
+                        };
+
+                        // Select the same columns from the anchor line to the cursor line
+                        if let Some(anchor) = column_anchor {
+                            let head = editor.peek().measure_new_cursor(to, id).pos();
+                            editor.write().select_columns(anchor, head);
+                            continue;
+                        }
+
                         let current_cursor = editor.peek().cursor().clone();
                         let current_selection = editor.peek().get_selection();
 
@@ -211,6 +258,8 @@ impl UseEditable {
             editor,
             cursor_reference: Signal::new(cursor_reference.clone()),
             dragging,
+            preedit: Signal::new(String::new()),
+            modifiers: Signal::new(Modifiers::empty()),
             platform,
             allow_tabs: config.allow_tabs,
             allow_changes: config.allow_changes,
@@ -228,6 +277,11 @@ impl UseEditable {
         &mut self.editor
     }
 
+    /// Text being composed with an IME, to be shown at the cursor until it's committed.
+    pub fn preedit(&self) -> &Signal<String> {
+        &self.preedit
+    }
+
     /// Create a cursor attribute.
     pub fn cursor_attr(&self) -> AttributeValue {
         AttributeValue::any_value(CustomAttributeValues::CursorReference(
@@ -238,21 +292,65 @@ impl UseEditable {
     /// Create a highlights attribute.
     pub fn highlights_attr(&self, editor_id: usize) -> AttributeValue {
         AttributeValue::any_value(CustomAttributeValues::TextHighlights(
-            self.editor
-                .read()
-                .get_visible_selection(editor_id)
-                .map(|v| vec![v])
-                .unwrap_or_default(),
+            self.editor.read().get_visible_selections(editor_id),
         ))
     }
 
+    /// Create a carets attribute, with the additional cursors.
+    pub fn carets_attr(&self, editor_id: usize) -> AttributeValue {
+        AttributeValue::any_value(CustomAttributeValues::TextCarets(
+            self.editor.read().get_visible_carets(editor_id),
+        ))
+    }
+
+    /// Undo the last change, see [EditorHistory].
+    pub fn undo(&mut self) {
+        let mut editor = self.editor.write();
+        editor.clear_carets();
+        editor.clear_selection();
+        if let Some(idx) = editor.undo() {
+            editor.set_cursor_pos(idx);
+        }
+    }
+
+    /// Redo the last undone change, see [EditorHistory].
+    pub fn redo(&mut self) {
+        let mut editor = self.editor.write();
+        editor.clear_carets();
+        editor.clear_selection();
+        if let Some(idx) = editor.redo() {
+            editor.set_cursor_pos(idx);
+        }
+    }
+
     /// Process a [`EditableEvent`] event.
+    ///
+    /// Clicking with `Ctrl` pressed (`Cmd` in macOS) adds a cursor, and dragging with `Alt` pressed selects the same columns in multiple lines.
     pub fn process_event(&mut self, edit_event: &EditableEvent) {
         let res = match edit_event {
             EditableEvent::MouseDown(e, id) => {
                 let coords = e.get_element_coordinates();
-
-                self.dragging.write().set_cursor_coords(coords);
+                let modifiers = *self.modifiers.peek();
+                let meta_or_ctrl = if cfg!(target_os = "macos") {
+                    Modifiers::META
+                } else {
+                    Modifiers::CONTROL
+                };
+
+                if modifiers.contains(Modifiers::ALT) {
+                    self.editor.write().clear_carets();
+                    *self.dragging.write() = TextDragging::Column {
+                        src: coords,
+                        anchor: None,
+                    };
+                } else {
+                    if modifiers.contains(meta_or_ctrl) {
+                        self.editor.write().push_caret();
+                    } else {
+                        self.editor.write().clear_carets();
+                    }
+                    self.dragging.write().set_cursor_coords(coords);
+                }
                 self.editor.write().clear_selection();
 
                 Some((*id, Some(coords), None))
@@ -279,6 +377,10 @@ impl UseEditable {
                 None
             }
             EditableEvent::KeyDown(e) => {
+                if let Some(modifier) = modifier_of_key(&e.key) {
+                    self.modifiers.write().insert(modifier);
+                }
+
                 match e.code {
                     // Handle dragging
                     Code::ShiftLeft => {
@@ -303,7 +405,7 @@ impl UseEditable {
                     }
                     // Handle editing
                     _ => {
-                        let event = self.editor.write().process_key(
+                        let event = self.editor.write().process_key_in_carets(
                             &e.key,
                             &e.code,
                             &e.modifiers,
@@ -320,6 +422,10 @@ impl UseEditable {
                 None
             }
             EditableEvent::KeyUp(e) => {
+                if let Some(modifier) = modifier_of_key(&e.key) {
+                    self.modifiers.write().remove(modifier);
+                }
+
                 if e.code == Code::ShiftLeft {
                     if let TextDragging::FromCursorToPoint { shift, .. } =
                         &mut *self.dragging.write()
@@ -330,6 +436,13 @@ impl UseEditable {
                     *self.dragging.write() = TextDragging::None;
                 }
 
+                None
+            }
+            EditableEvent::ImePreedit(e) => {
+                if self.allow_changes && *self.preedit.peek() != e.text {
+                    self.preedit.set(e.text.clone());
+                }
+
                 None
             }
         };
@@ -349,14 +462,26 @@ impl UseEditable {
     }
 }
 
+/// Modifier that is held with the given key.
+fn modifier_of_key(key: &Key) -> Option<Modifiers> {
+    match key {
+        Key::Control => Some(Modifiers::CONTROL),
+        Key::Alt => Some(Modifiers::ALT),
+        Key::Meta | Key::Super => Some(Modifiers::META),
+        _ => None,
+    }
+}
+
 /// Create a configuration for a [`UseEditable`].
 pub struct EditableConfig {
     pub(crate) content: String,
+    pub(crate) spans: Option<TextSpans>,
     pub(crate) cursor: TextCursor,
     pub(crate) identation: u8,
     pub(crate) allow_tabs: bool,
     pub(crate) allow_changes: bool,
     pub(crate) allow_clipboard: bool,
+    pub(crate) history_batch_timeout: Duration,
 }
 
 impl EditableConfig {
@@ -364,14 +489,23 @@ impl EditableConfig {
     pub fn new(content: String) -> Self {
         Self {
             content,
+            spans: None,
             cursor: TextCursor::default(),
             identation: 4,
             allow_tabs: false,
             allow_changes: true,
             allow_clipboard: true,
+            history_batch_timeout: DEFAULT_HISTORY_BATCH_TIMEOUT,
         }
     }
 
+    /// Create a [`EditableConfig`] for a text with styled spans.
+    pub fn new_rich(rich_text: RichText) -> Self {
+        let mut config = Self::new(rich_text.text);
+        config.spans = Some(rich_text.spans);
+        config
+    }
+
     /// Specify a custom initial cursor position.
     pub fn with_cursor(mut self, pos: usize) -> Self {
         self.cursor = TextCursor::new(pos);
@@ -401,6 +535,12 @@ impl EditableConfig {
         self.allow_clipboard = allow_clipboard;
         self
     }
+
+    /// Specify the time in which consecutive changes are undone together, see [EditorHistory::with_batch_timeout].
+    pub fn with_history_batch_timeout(mut self, history_batch_timeout: Duration) -> Self {
+        self.history_batch_timeout = history_batch_timeout;
+        self
+    }
 }
 
 /// Hook to create an editable text. For manual creation use [UseEditable::new_in_hook].
@@ -0,0 +1,202 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use dioxus_core::{
+    prelude::spawn,
+    use_hook,
+};
+use freya_core::custom_attributes::CanvasRunnerContext;
+use freya_engine::prelude::*;
+use torin::prelude::Area;
+
+use crate::{
+    use_canvas,
+    use_platform,
+    UseCanvas,
+};
+
+/// An OpenGL texture owned by an [ExternalTextureRenderer].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExternalTexture {
+    /// OpenGL name of the texture, as returned by `glGenTextures`.
+    pub id: u32,
+    /// Texture target, usually `GL_TEXTURE_2D`.
+    pub target: u32,
+    /// Sized internal format, usually `GL_RGBA8`.
+    pub format: u32,
+    /// Where the first row of pixels is located.
+    pub origin: SurfaceOrigin,
+}
+
+/// Produces frames into an [ExternalTexture] that Freya then composites inside a node.
+///
+/// The texture is never copied, it is wrapped and sampled directly by Skia.
+/// Both methods run in the render thread with Freya's OpenGL context current,
+/// so any GL state modified must be restored before returning.
+///
+/// To embed a `wgpu` renderer, create it with the GL backend sharing this context
+/// and export the target texture through `wgpu-hal`.
+pub trait ExternalTextureRenderer: Send + Sync + 'static {
+    /// Called the first time and every time the node size changes, in physical pixels.
+    /// Must return the texture that will be rendered into from now on.
+    fn resize(&mut self, width: u32, height: u32) -> ExternalTexture;
+
+    /// Called every frame before compositing the texture.
+    fn render(&mut self, area: Area, scale_factor: f32);
+}
+
+struct ExternalTextureState<R: ExternalTextureRenderer> {
+    renderer: R,
+    size: (u32, u32),
+    area: Area,
+    image: Option<Image>,
+}
+
+impl<R: ExternalTextureRenderer> ExternalTextureState<R> {
+    fn render(&mut self, ctx: &mut CanvasRunnerContext) {
+        self.area = ctx.area / ctx.scale_factor;
+
+        let Some(mut direct_context) = ctx.canvas.direct_context() else {
+            return;
+        };
+
+        let size = (
+            ctx.area.width().round().max(1.) as u32,
+            ctx.area.height().round().max(1.) as u32,
+        );
+
+        // Only ask for a new texture on size changes, even if the last one couldn't be wrapped
+        if self.size != size {
+            self.size = size;
+            let texture = self.renderer.resize(size.0, size.1);
+            // SAFETY: The renderer guarantees the texture is alive and of the given size.
+            let backend_texture = unsafe {
+                backend_textures::make_gl(
+                    (size.0 as i32, size.1 as i32),
+                    Mipmapped::No,
+                    TextureInfo {
+                        target: texture.target,
+                        format: texture.format,
+                        protected: Protected::No,
+                        id: texture.id,
+                    },
+                    "external",
+                )
+            };
+            self.image = Image::from_texture(
+                &mut direct_context,
+                &backend_texture,
+                texture.origin,
+                ColorType::RGBA8888,
+                AlphaType::Premul,
+                None,
+            );
+            if self.image.is_none() {
+                tracing::warn!(
+                    "Failed to wrap the external texture {} of {}x{}",
+                    texture.id,
+                    size.0,
+                    size.1
+                );
+            }
+        }
+
+        self.renderer.render(ctx.area, ctx.scale_factor);
+
+        // The renderer might have touched the GL state
+        direct_context.reset(None);
+
+        if let Some(image) = &self.image {
+            let rect = Rect::new(
+                ctx.area.min_x(),
+                ctx.area.min_y(),
+                ctx.area.max_x(),
+                ctx.area.max_y(),
+            );
+            ctx.canvas
+                .draw_image_rect(image, None, rect, &Paint::default());
+        }
+    }
+}
+
+/// Handle returned by [use_external_texture].
+#[derive(Clone, PartialEq)]
+pub struct UseExternalTexture {
+    canvas: UseCanvas,
+}
+
+impl UseExternalTexture {
+    /// Get an [AttributeValue](dioxus_core::AttributeValue) for the `canvas_reference` attribute.
+    pub fn attribute(&self) -> dioxus_core::AttributeValue {
+        self.canvas.attribute()
+    }
+}
+
+/// Render an externally produced OpenGL texture inside a `rect`, such as a 3D viewport or a game engine.
+///
+/// The renderer is asked to render a new frame every time the event loop ticks.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use freya::torin::prelude::Area;
+/// struct Viewport;
+///
+/// impl ExternalTextureRenderer for Viewport {
+///     fn resize(&mut self, width: u32, height: u32) -> ExternalTexture {
+///         // (Re)allocate the texture and its framebuffer
+///         # unimplemented!()
+///     }
+///
+///     fn render(&mut self, area: Area, scale_factor: f32) {
+///         // Draw into the framebuffer
+///     }
+/// }
+///
+/// fn app() -> Element {
+///     let texture = use_external_texture(|| Viewport);
+///
+///     rsx!(rect {
+///         canvas_reference: texture.attribute(),
+///         width: "fill",
+///         height: "fill",
+///     })
+/// }
+/// ```
+pub fn use_external_texture<R: ExternalTextureRenderer>(
+    init: impl FnOnce() -> R,
+) -> UseExternalTexture {
+    let platform = use_platform();
+    let state = use_hook(|| {
+        Arc::new(Mutex::new(ExternalTextureState {
+            renderer: init(),
+            size: (0, 0),
+            area: Area::default(),
+            image: None,
+        }))
+    });
+
+    use_hook(|| {
+        let state = state.clone();
+        let mut ticker = platform.new_ticker();
+        spawn(async move {
+            loop {
+                ticker.tick().await;
+                let area = state.lock().unwrap().area;
+                platform.invalidate_drawing_area(area);
+                platform.request_animation_frame();
+            }
+        });
+    });
+
+    let canvas = use_canvas(move || {
+        let state = state.clone();
+        move |ctx| {
+            state.lock().unwrap().render(ctx);
+        }
+    });
+
+    UseExternalTexture { canvas }
+}
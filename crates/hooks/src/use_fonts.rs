@@ -0,0 +1,118 @@
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    fonts::{
+        FontError,
+        FontFallbacks,
+        FontSource,
+    },
+};
+use tokio::sync::oneshot;
+
+use crate::{
+    use_platform,
+    UsePlatform,
+};
+
+/// Register fonts at runtime and query the available ones, see [`use_fonts`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseFonts {
+    platform: UsePlatform,
+}
+
+impl UseFonts {
+    /// Register a font, returning the family it can be used with in the `font_family` attribute.
+    ///
+    /// Registering a font with the family of another one adds it to that family, e.g. for the bold variant.
+    pub async fn register(&self, source: FontSource) -> Result<String, FontError> {
+        self.send_register(source, None).await
+    }
+
+    /// Same as [UseFonts::register] but the font is used with the given family instead of its own.
+    pub async fn register_as(
+        &self,
+        source: FontSource,
+        family: impl Into<String>,
+    ) -> Result<String, FontError> {
+        self.send_register(source, Some(family.into())).await
+    }
+
+    /// Families of the registered fonts and the fonts installed in the system, sorted.
+    pub async fn families(&self) -> Vec<String> {
+        let (sender, receiver) = oneshot::channel();
+        if self
+            .platform
+            .send(EventLoopMessage::GetFontFamilies(sender))
+            .is_err()
+        {
+            return Vec::new();
+        }
+        receiver.await.unwrap_or_default()
+    }
+
+    /// Whether there is a registered or installed font with the given family.
+    pub async fn is_available(&self, family: &str) -> bool {
+        self.families()
+            .await
+            .iter()
+            .any(|f| f.eq_ignore_ascii_case(family))
+    }
+
+    /// Replace the chains of fonts used for the characters that the default fonts don't have,
+    /// e.g. emojis or CJK characters.
+    pub fn set_fallbacks(&self, fallbacks: FontFallbacks) {
+        self.platform
+            .send(EventLoopMessage::SetFontFallbacks(fallbacks))
+            .ok();
+    }
+
+    async fn send_register(
+        &self,
+        source: FontSource,
+        family: Option<String>,
+    ) -> Result<String, FontError> {
+        let (sender, receiver) = oneshot::channel();
+        self.platform
+            .send(EventLoopMessage::RegisterFont(source, family, sender))
+            .map_err(|_| FontError::Unavailable)?;
+        receiver.await.unwrap_or(Err(FontError::Unavailable))
+    }
+}
+
+/// Load fonts while the app is running, from files, bytes or the fonts installed in the system,
+/// and configure the fallback fonts of every script.
+///
+/// Texts are measured again after a font is registered, so they can start using it right away.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let fonts = use_fonts();
+///     let mut family = use_signal(|| None);
+///
+///     use_hook(move || {
+///         fonts.set_fallbacks(
+///             FontFallbacks::default().with_fallback(FontScript::Emoji, ["Twemoji"]),
+///         );
+///         spawn(async move {
+///             let source = FontSource::File("./fonts/Inter.ttf".into());
+///             if let Ok(font) = fonts.register(source).await {
+///                 family.set(Some(font));
+///             }
+///         });
+///     });
+///
+///     rsx!(
+///         label {
+///             font_family: family().unwrap_or_default(),
+///             "Hello, World! 👋 你好"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_fonts() -> UseFonts {
+    UseFonts {
+        platform: use_platform(),
+    }
+}
@@ -0,0 +1,134 @@
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_hook,
+};
+use dioxus_hooks::use_signal;
+use dioxus_signals::{
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    global_shortcuts::{
+        GlobalShortcut,
+        GlobalShortcutError,
+        GlobalShortcutEvent,
+    },
+    types::GlobalShortcutEventSender,
+};
+use tokio::sync::{
+    broadcast::error::RecvError,
+    oneshot,
+};
+
+use crate::{
+    use_platform,
+    UsePlatform,
+};
+
+/// Manage the global shortcuts of the app, see [`use_global_shortcuts`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseGlobalShortcuts {
+    platform: UsePlatform,
+    event: Signal<Option<GlobalShortcutEvent>>,
+}
+
+impl UseGlobalShortcuts {
+    /// Get the last triggered shortcut, subscribing to new triggers.
+    pub fn event(&self) -> Option<GlobalShortcutEvent> {
+        self.event.read().clone()
+    }
+
+    /// Register a shortcut in the operating system, replacing the one with the same id.
+    ///
+    /// Fails if the accelerator is invalid, if another shortcut of the app already uses the same keys
+    /// or if the operating system rejects it, e.g. because another application uses them.
+    pub async fn register(
+        &self,
+        id: impl Into<String>,
+        accelerator: impl Into<String>,
+    ) -> Result<(), GlobalShortcutError> {
+        let (sender, receiver) = oneshot::channel();
+        self.platform
+            .send(EventLoopMessage::RegisterGlobalShortcut(
+                GlobalShortcut::new(id, accelerator),
+                sender,
+            ))
+            .map_err(|_| GlobalShortcutError::Unsupported)?;
+        receiver
+            .await
+            .unwrap_or(Err(GlobalShortcutError::Unsupported))
+    }
+
+    /// Unregister the shortcut with the given id.
+    pub fn unregister(&self, id: impl Into<String>) {
+        self.platform
+            .send(EventLoopMessage::UnregisterGlobalShortcut(id.into()))
+            .ok();
+    }
+}
+
+/// Register keyboard shortcuts in the operating system and listen for them,
+/// even when the window is not focused or is hidden.
+///
+/// Supported on Windows, macOS and Linux with X11.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let shortcuts = use_global_shortcuts();
+///     let platform = use_platform();
+///     let mut error = use_signal(|| None);
+///
+///     use_hook(move || {
+///         spawn(async move {
+///             if let Err(err) = shortcuts.register("show", "CmdOrCtrl+Shift+Space").await {
+///                 error.set(Some(err.to_string()));
+///             }
+///         });
+///     });
+///
+///     use_effect(move || {
+///         if let Some(GlobalShortcutEvent {
+///             id,
+///             state: GlobalShortcutState::Pressed,
+///         }) = shortcuts.event()
+///         {
+///             if id == "show" {
+///                 platform.with_window(|window| {
+///                     window.set_visible(true);
+///                     window.focus_window();
+///                 });
+///             }
+///         }
+///     });
+///
+///     rsx!(label { "{error:?}" })
+/// }
+/// ```
+pub fn use_global_shortcuts() -> UseGlobalShortcuts {
+    let platform = use_platform();
+    let mut event = use_signal(|| None);
+
+    use_hook(|| {
+        let Some(sender) = try_consume_context::<GlobalShortcutEventSender>() else {
+            return;
+        };
+        let mut receiver = sender.subscribe();
+        spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(shortcut_event) => event.set(Some(shortcut_event)),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    });
+
+    UseGlobalShortcuts { platform, event }
+}
@@ -0,0 +1,363 @@
+use dioxus_core::prelude::{
+    try_consume_context,
+    use_hook,
+};
+use dioxus_hooks::use_context_provider;
+use dioxus_signals::{
+    CopyValue,
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::native_menu::accelerator_matches;
+use freya_elements::events::KeyboardData;
+
+/// Keys that undo the last change in [UseHistory::handle_keydown].
+pub const UNDO_SHORTCUT: &str = "CmdOrCtrl+Z";
+
+/// Keys that redo the last undone change in [UseHistory::handle_keydown].
+pub const REDO_SHORTCUTS: [&str; 2] = ["CmdOrCtrl+Shift+Z", "CmdOrCtrl+Y"];
+
+/// A change that can be undone and redone, see [use_history].
+pub trait Undoable: 'static {
+    /// Revert the change.
+    fn undo(&mut self);
+
+    /// Apply the change again after being undone.
+    fn redo(&mut self);
+
+    /// Whether what the change edits is gone, e.g. the component was unmounted.
+    /// Expired changes are skipped, see [UseHistory::forget_expired].
+    fn is_expired(&self) -> bool {
+        false
+    }
+}
+
+impl Undoable for Box<dyn Undoable> {
+    fn undo(&mut self) {
+        (**self).undo()
+    }
+
+    fn redo(&mut self) {
+        (**self).redo()
+    }
+
+    fn is_expired(&self) -> bool {
+        (**self).is_expired()
+    }
+}
+
+/// [Undoable] change made of a pair of callbacks.
+pub struct Command {
+    undo: Box<dyn FnMut()>,
+    redo: Box<dyn FnMut()>,
+    is_expired: Option<Box<dyn Fn() -> bool>>,
+}
+
+impl Command {
+    pub fn new(undo: impl FnMut() + 'static, redo: impl FnMut() + 'static) -> Self {
+        Self {
+            undo: Box::new(undo),
+            redo: Box::new(redo),
+            is_expired: None,
+        }
+    }
+
+    /// Mark the change as expired once the given callback returns `true`, see [Undoable::is_expired].
+    pub fn with_expiration(mut self, is_expired: impl Fn() -> bool + 'static) -> Self {
+        self.is_expired = Some(Box::new(is_expired));
+        self
+    }
+}
+
+impl Undoable for Command {
+    fn undo(&mut self) {
+        (self.undo)()
+    }
+
+    fn redo(&mut self) {
+        (self.redo)()
+    }
+
+    fn is_expired(&self) -> bool {
+        self.is_expired
+            .as_ref()
+            .is_some_and(|is_expired| is_expired())
+    }
+}
+
+/// Configuration for [use_history_with_config].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HistoryConfig {
+    /// Maximum amount of changes that can be undone, the oldest ones are forgotten.
+    pub max_depth: usize,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self { max_depth: 100 }
+    }
+}
+
+impl HistoryConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth.max(1);
+        self
+    }
+}
+
+/// History shared by the app and the built-in components, like `Input`, see [use_init_shared_history].
+pub type SharedHistory = UseHistory<Box<dyn Undoable>>;
+
+/// Stack of changes that can be undone and redone, see [use_history].
+pub struct UseHistory<C: Undoable> {
+    /// Every entry is a group of changes, undone and redone together.
+    undo_stack: Signal<Vec<Vec<C>>>,
+    redo_stack: Signal<Vec<Vec<C>>>,
+    /// Amount of groups being built, see [UseHistory::begin_group].
+    group_depth: CopyValue<usize>,
+    config: HistoryConfig,
+}
+
+impl<C: Undoable> Clone for UseHistory<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Undoable> Copy for UseHistory<C> {}
+
+impl<C: Undoable> PartialEq for UseHistory<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.undo_stack == other.undo_stack && self.redo_stack == other.redo_stack
+    }
+}
+
+impl<C: Undoable> UseHistory<C> {
+    /// Register a change that was already applied, discarding the undone changes.
+    pub fn push(&mut self, command: C) {
+        self.redo_stack.write().clear();
+        let mut undo_stack = self.undo_stack.write();
+        match undo_stack.last_mut() {
+            Some(group) if *self.group_depth.peek() > 0 => group.push(command),
+            _ => {
+                undo_stack.push(vec![command]);
+                let overflow = undo_stack.len().saturating_sub(self.config.max_depth);
+                undo_stack.drain(..overflow);
+            }
+        }
+    }
+
+    /// Apply a change and register it.
+    pub fn execute(&mut self, mut command: C) {
+        command.redo();
+        self.push(command);
+    }
+
+    /// Start grouping the next changes, so they are undone and redone together.
+    /// Groups can be nested, the changes are grouped until the outermost one ends.
+    pub fn begin_group(&mut self) {
+        let depth = *self.group_depth.peek();
+        if depth == 0 {
+            self.redo_stack.write().clear();
+            let mut undo_stack = self.undo_stack.write();
+            undo_stack.push(Vec::new());
+            let overflow = undo_stack.len().saturating_sub(self.config.max_depth);
+            undo_stack.drain(..overflow);
+        }
+        self.group_depth.set(depth + 1);
+    }
+
+    /// Stop grouping the changes, see [UseHistory::begin_group].
+    pub fn end_group(&mut self) {
+        let depth = self.group_depth.peek().saturating_sub(1);
+        self.group_depth.set(depth);
+        if depth == 0 && self.undo_stack.peek().last().is_some_and(Vec::is_empty) {
+            self.undo_stack.write().pop();
+        }
+    }
+
+    /// Group the changes registered while running the given callback.
+    pub fn group<R>(&mut self, callback: impl FnOnce(&mut Self) -> R) -> R {
+        self.begin_group();
+        let result = callback(self);
+        self.end_group();
+        result
+    }
+
+    /// Undo the last group of changes. Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        self.forget_expired();
+        // Leave the stack free while undoing, in case the commands read it
+        let Some(mut group) = self.undo_stack.write().pop() else {
+            return false;
+        };
+        for command in group.iter_mut().rev() {
+            command.undo();
+        }
+        self.redo_stack.write().push(group);
+        true
+    }
+
+    /// Redo the last undone group of changes. Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        self.forget_expired();
+        let Some(mut group) = self.redo_stack.write().pop() else {
+            return false;
+        };
+        for command in group.iter_mut() {
+            command.redo();
+        }
+        self.undo_stack.write().push(group);
+        true
+    }
+
+    /// Forget the changes that are [expired](Undoable::is_expired), and the groups left empty.
+    ///
+    /// Does nothing if the history was already dropped.
+    pub fn forget_expired(&mut self) {
+        let is_grouping = *self.group_depth.try_peek().as_deref().unwrap_or(&0) > 0;
+        for stack in [&mut self.undo_stack, &mut self.redo_stack] {
+            let Ok(mut stack) = stack.try_write() else {
+                return;
+            };
+            let len = stack.len();
+            let mut i = 0;
+            stack.retain_mut(|group| {
+                group.retain(|command| !command.is_expired());
+                i += 1;
+                // Keep the group being built
+                !group.is_empty() || (is_grouping && i == len)
+            });
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.read().is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.read().is_empty()
+    }
+
+    /// Amount of groups of changes that can be undone.
+    pub fn undo_len(&self) -> usize {
+        self.undo_stack.read().len()
+    }
+
+    /// Forget all the changes.
+    pub fn clear(&mut self) {
+        self.undo_stack.write().clear();
+        self.redo_stack.write().clear();
+    }
+
+    /// Undo or redo with [UNDO_SHORTCUT] and [REDO_SHORTCUTS]. Meant for `onglobalkeydown`.
+    ///
+    /// Returns `true` if the keys were a shortcut of the history.
+    pub fn handle_keydown(&mut self, e: &KeyboardData) -> bool {
+        if is_undo_shortcut(e) {
+            self.undo();
+            true
+        } else if is_redo_shortcut(e) {
+            self.redo();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Whether the key press is an [UNDO_SHORTCUT].
+pub fn is_undo_shortcut(e: &KeyboardData) -> bool {
+    accelerator_matches(UNDO_SHORTCUT, &e.key, e.modifiers)
+}
+
+/// Whether the key press is one of the [REDO_SHORTCUTS].
+pub fn is_redo_shortcut(e: &KeyboardData) -> bool {
+    REDO_SHORTCUTS
+        .iter()
+        .any(|shortcut| accelerator_matches(shortcut, &e.key, e.modifiers))
+}
+
+/// Create a stack of changes that can be undone and redone, e.g. for a drawing app.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut shapes = use_signal(Vec::<(f64, f64)>::new);
+///     let mut history = use_history::<Command>();
+///
+///     rsx!(
+///         rect {
+///             width: "fill",
+///             height: "fill",
+///             onglobalkeydown: move |e: KeyboardEvent| {
+///                 history.handle_keydown(&e);
+///             },
+///             onclick: move |e: MouseEvent| {
+///                 let point = e.get_element_coordinates().to_tuple();
+///                 history.execute(Command::new(
+///                     move || { shapes.write().pop(); },
+///                     move || shapes.write().push(point),
+///                 ));
+///             },
+///             label {
+///                 "{shapes.read().len()} shapes"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_history<C: Undoable>() -> UseHistory<C> {
+    use_history_with_config(HistoryConfig::default())
+}
+
+/// Same as [use_history] but with a custom [HistoryConfig].
+pub fn use_history_with_config<C: Undoable>(config: HistoryConfig) -> UseHistory<C> {
+    use_hook(|| UseHistory {
+        undo_stack: Signal::new(Vec::new()),
+        redo_stack: Signal::new(Vec::new()),
+        group_depth: CopyValue::new(0),
+        config,
+    })
+}
+
+/// Create a [SharedHistory] for this component and its descendants.
+///
+/// Built-in components that edit values, like `Input`, register their changes in it,
+/// so they are undone along with the ones of the app and in the same order.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut history = use_init_shared_history(HistoryConfig::new().with_max_depth(50));
+///     let mut name = use_signal(String::new);
+///
+///     rsx!(
+///         rect {
+///             onglobalkeydown: move |e: KeyboardEvent| {
+///                 history.handle_keydown(&e);
+///             },
+///             Input {
+///                 value: name,
+///                 onchange: move |text| name.set(text),
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_init_shared_history(config: HistoryConfig) -> SharedHistory {
+    let history = use_history_with_config(config);
+    use_context_provider(|| history)
+}
+
+/// Get the [SharedHistory] created by an ancestor component, if any.
+pub fn use_shared_history() -> Option<SharedHistory> {
+    use_hook(try_consume_context::<SharedHistory>)
+}
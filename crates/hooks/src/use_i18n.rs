@@ -0,0 +1,488 @@
+use std::{
+    fs,
+    path::PathBuf,
+};
+
+use dioxus_core::prelude::{
+    consume_context,
+    try_consume_context,
+};
+use dioxus_hooks::use_context_provider;
+use dioxus_signals::{
+    Readable,
+    Signal,
+    Writable,
+};
+pub use fluent_bundle::{
+    FluentArgs,
+    FluentValue,
+};
+use fluent_bundle::{
+    FluentBundle,
+    FluentResource,
+};
+use fluent_langneg::{
+    negotiate_languages,
+    NegotiationStrategy,
+};
+pub use icu_calendar::{
+    Date as CalendarDate,
+    DateTime as CalendarDateTime,
+    Iso,
+};
+pub use icu_datetime::options::length::{
+    Date as DateLength,
+    Time as TimeLength,
+};
+use icu_datetime::{
+    options::length,
+    DateFormatter,
+    DateTimeFormatter,
+};
+use icu_locid::Locale as IcuLocale;
+use icu_provider::DataLocale;
+pub use unic_langid::{
+    langid,
+    LanguageIdentifier,
+};
+
+/// Where the Fluent messages of a [Locale] are loaded from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocaleResource {
+    /// Messages embedded in the app, e.g with `include_str!`.
+    Static(&'static str),
+    /// Path of a `.ftl` file that is read when the i18n state is created.
+    Path(PathBuf),
+}
+
+/// Fluent messages of a language.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Locale {
+    pub id: LanguageIdentifier,
+    pub resource: LocaleResource,
+}
+
+impl Locale {
+    /// Create a [Locale] with messages embedded in the app.
+    pub fn new_static(id: LanguageIdentifier, messages: &'static str) -> Self {
+        Self {
+            id,
+            resource: LocaleResource::Static(messages),
+        }
+    }
+
+    /// Create a [Locale] with the messages of a `.ftl` file.
+    pub fn new_dynamic(id: LanguageIdentifier, path: impl Into<PathBuf>) -> Self {
+        Self {
+            id,
+            resource: LocaleResource::Path(path.into()),
+        }
+    }
+}
+
+/// Errors that might happen while loading or translating messages.
+#[derive(Debug, Clone, PartialEq)]
+pub enum I18nError {
+    /// The file of a locale could not be read.
+    Io(PathBuf, String),
+    /// The messages of a locale are not valid Fluent.
+    Parse(LanguageIdentifier, String),
+    /// There is no message with this ID in the selected language nor in the fallback language.
+    MissingMessage(String),
+    /// The message could not be formatted, e.g because of a missing argument.
+    Format(String, String),
+    /// The date could not be formatted in the selected language.
+    DateFormat(String),
+}
+
+impl std::fmt::Display for I18nError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "Failed to read locale file {path:?}: {err}"),
+            Self::Parse(id, err) => write!(f, "Failed to parse locale {id}: {err}"),
+            Self::MissingMessage(id) => write!(f, "Missing message: {id}"),
+            Self::Format(id, err) => write!(f, "Failed to format message {id}: {err}"),
+            Self::DateFormat(err) => write!(f, "Failed to format date: {err}"),
+        }
+    }
+}
+
+/// Configuration of the i18n state, see [use_init_i18n].
+#[derive(Debug, Clone, PartialEq)]
+pub struct I18nConfig {
+    fallback: LanguageIdentifier,
+    language: Option<LanguageIdentifier>,
+    locales: Vec<Locale>,
+    use_os_language: bool,
+}
+
+impl I18nConfig {
+    /// Create a config whose messages fall back to the given language.
+    pub fn new(fallback: LanguageIdentifier) -> Self {
+        Self {
+            fallback,
+            language: None,
+            locales: Vec::new(),
+            use_os_language: false,
+        }
+    }
+
+    /// Add the messages of a language.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locales.push(locale);
+        self
+    }
+
+    /// Start with the given language instead of the fallback one.
+    pub fn with_language(mut self, language: LanguageIdentifier) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Start with the available language that best matches the languages preferred in the OS.
+    pub fn with_os_language(mut self) -> Self {
+        self.use_os_language = true;
+        self
+    }
+}
+
+/// Languages preferred by the user in the OS, from the most to the least preferred.
+pub fn os_languages() -> Vec<LanguageIdentifier> {
+    sys_locale::get_locales()
+        .filter_map(|locale| locale.parse().ok())
+        .collect()
+}
+
+/// Pick the available language that best matches the requested ones, or the fallback if none does.
+/// A requested `es-MX` matches an available `es-ES` if there is no better match.
+pub fn negotiate_language(
+    requested: &[LanguageIdentifier],
+    available: &[LanguageIdentifier],
+    fallback: &LanguageIdentifier,
+) -> LanguageIdentifier {
+    negotiate_languages(
+        requested,
+        available,
+        Some(fallback),
+        NegotiationStrategy::Filtering,
+    )
+    .first()
+    .map_or_else(|| fallback.clone(), |language| (*language).clone())
+}
+
+/// Translate messages with [Fluent](https://projectfluent.org/), see [use_init_i18n].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseI18n {
+    language: Signal<LanguageIdentifier>,
+    fallback: Signal<LanguageIdentifier>,
+    bundles: Signal<Vec<FluentBundle<FluentResource>>>,
+}
+
+impl UseI18n {
+    /// Selected language.
+    ///
+    /// Components reading it are updated when the language changes.
+    pub fn language(&self) -> LanguageIdentifier {
+        self.language.read().clone()
+    }
+
+    /// Select the available language that best matches the given one.
+    pub fn set_language(&mut self, language: LanguageIdentifier) {
+        let language = negotiate_language(&[language], &self.languages(), &self.fallback.peek());
+        if *self.language.peek() != language {
+            self.language.set(language);
+        }
+    }
+
+    /// Languages with messages.
+    pub fn languages(&self) -> Vec<LanguageIdentifier> {
+        self.bundles
+            .peek()
+            .iter()
+            .filter_map(|bundle| bundle.locales.first().cloned())
+            .collect()
+    }
+
+    /// Translate a message, or an attribute of it with `message.attribute`, in the selected language.
+    /// Messages missing in the selected language are translated in the fallback language.
+    ///
+    /// Components calling it are updated when the language changes.
+    pub fn try_translate(&self, id: &str, args: Option<&FluentArgs>) -> Result<String, I18nError> {
+        let language = self.language.read();
+        let fallback = self.fallback.peek();
+        let bundles = self.bundles.peek();
+
+        let (message_id, attribute) = match id.split_once('.') {
+            Some((message_id, attribute)) => (message_id, Some(attribute)),
+            None => (id, None),
+        };
+
+        let find_bundle = |language: &LanguageIdentifier| {
+            bundles
+                .iter()
+                .find(|bundle| bundle.locales.first() == Some(language))
+        };
+
+        let (bundle, pattern) = [find_bundle(&language), find_bundle(&fallback)]
+            .into_iter()
+            .flatten()
+            .find_map(|bundle| {
+                let message = bundle.get_message(message_id)?;
+                let pattern = match attribute {
+                    Some(attribute) => message.get_attribute(attribute)?.value(),
+                    None => message.value()?,
+                };
+                Some((bundle, pattern))
+            })
+            .ok_or_else(|| I18nError::MissingMessage(id.to_string()))?;
+
+        let mut errors = Vec::new();
+        let text = bundle.format_pattern(pattern, args, &mut errors);
+
+        if let Some(err) = errors.first() {
+            return Err(I18nError::Format(id.to_string(), err.to_string()));
+        }
+
+        Ok(text.into_owned())
+    }
+
+    /// Same as [UseI18n::try_translate] but returning the ID of the message if it can't be translated.
+    pub fn translate(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        self.try_translate(id, args).unwrap_or_else(|err| {
+            tracing::warn!("{err}");
+            id.to_string()
+        })
+    }
+
+    /// Format a date in the selected language, e.g `Jan 15, 2024` in English or `15 ene 2024` in Spanish
+    /// with [DateLength::Medium].
+    ///
+    /// Components calling it are updated when the language changes.
+    pub fn try_format_date(
+        &self,
+        date: &CalendarDate<Iso>,
+        length: DateLength,
+    ) -> Result<String, I18nError> {
+        let locale = data_locale(&self.language.read())?;
+        DateFormatter::try_new_with_length(&locale, length)
+            .and_then(|formatter| formatter.format_to_string(&date.to_any()))
+            .map_err(|err| I18nError::DateFormat(err.to_string()))
+    }
+
+    /// Same as [UseI18n::try_format_date] but returning the date as `YYYY-MM-DD` if it can't be formatted.
+    pub fn format_date(&self, date: &CalendarDate<Iso>, length: DateLength) -> String {
+        self.try_format_date(date, length).unwrap_or_else(|err| {
+            tracing::warn!("{err}");
+            iso_date(date)
+        })
+    }
+
+    /// Format a date and a time of the day in the selected language,
+    /// e.g `January 15, 2024 at 9:30 PM` in English with [DateLength::Long] and [TimeLength::Short].
+    ///
+    /// Components calling it are updated when the language changes.
+    pub fn try_format_date_time(
+        &self,
+        date_time: &CalendarDateTime<Iso>,
+        date_length: DateLength,
+        time_length: TimeLength,
+    ) -> Result<String, I18nError> {
+        let locale = data_locale(&self.language.read())?;
+        let options = length::Bag::from_date_time_style(date_length, time_length);
+        DateTimeFormatter::try_new(&locale, options.into())
+            .and_then(|formatter| formatter.format_to_string(&date_time.to_any()))
+            .map_err(|err| I18nError::DateFormat(err.to_string()))
+    }
+
+    /// Same as [UseI18n::try_format_date_time] but returning the date and time as `YYYY-MM-DD HH:MM`
+    /// if they can't be formatted.
+    pub fn format_date_time(
+        &self,
+        date_time: &CalendarDateTime<Iso>,
+        date_length: DateLength,
+        time_length: TimeLength,
+    ) -> String {
+        self.try_format_date_time(date_time, date_length, time_length)
+            .unwrap_or_else(|err| {
+                tracing::warn!("{err}");
+                format!(
+                    "{} {:02}:{:02}",
+                    iso_date(&date_time.date),
+                    date_time.time.hour.number(),
+                    date_time.time.minute.number()
+                )
+            })
+    }
+}
+
+/// Locale of the formatters of ICU for a language.
+fn data_locale(language: &LanguageIdentifier) -> Result<DataLocale, I18nError> {
+    IcuLocale::try_from_bytes(language.to_string().as_bytes())
+        .map(|locale| DataLocale::from(&locale))
+        .map_err(|err| I18nError::DateFormat(err.to_string()))
+}
+
+fn iso_date(date: &CalendarDate<Iso>) -> String {
+    format!(
+        "{:04}-{:02}-{:02}",
+        date.year().number,
+        date.month().ordinal,
+        date.day_of_month().0
+    )
+}
+
+/// Load the messages of a [Locale] into a bundle, with the built-in functions like `NUMBER()`.
+fn create_bundle(locale: Locale) -> Result<FluentBundle<FluentResource>, I18nError> {
+    let messages = match locale.resource {
+        LocaleResource::Static(messages) => messages.to_string(),
+        LocaleResource::Path(path) => {
+            fs::read_to_string(&path).map_err(|err| I18nError::Io(path, err.to_string()))?
+        }
+    };
+
+    let resource = FluentResource::try_new(messages)
+        .map_err(|(_, errors)| I18nError::Parse(locale.id.clone(), format!("{errors:?}")))?;
+
+    let mut bundle = FluentBundle::new(vec![locale.id.clone()]);
+    bundle
+        .add_builtins()
+        .map_err(|err| I18nError::Parse(locale.id.clone(), err.to_string()))?;
+    bundle
+        .add_resource(resource)
+        .map_err(|errors| I18nError::Parse(locale.id, format!("{errors:?}")))?;
+
+    Ok(bundle)
+}
+
+/// Provide the i18n state to the app, it can later be accessed with [use_i18n] or [i18n].
+///
+/// Messages are written in [Fluent](https://projectfluent.org/), which supports plurals
+/// and number formatting according to the rules of every language:
+///
+/// ```fluent
+/// # en-US.ftl
+/// hello = Hello, {$name}!
+/// unread = {$count ->
+///     [one] You have one unread message
+///    *[other] You have {NUMBER($count)} unread messages
+/// }
+/// ```
+///
+/// Locales that can't be loaded are skipped and logged.
+///
+/// Dates are formatted in the selected language with [UseI18n::format_date] and [UseI18n::format_date_time].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     use_init_i18n(|| {
+///         I18nConfig::new(langid!("en-US"))
+///             .with_locale(Locale::new_dynamic(langid!("en-US"), "./en-US.ftl"))
+///             .with_locale(Locale::new_dynamic(langid!("es-ES"), "./es-ES.ftl"))
+///             .with_os_language()
+///     });
+///
+///     rsx!(Body {})
+/// }
+///
+/// #[component]
+/// fn Body() -> Element {
+///     let mut i18n = use_i18n();
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| i18n.set_language(langid!("es-ES")),
+///             label { "Español" }
+///         }
+///         label { {t!("hello", name: "Freya")} }
+///         label { {t!("unread", count: 3)} }
+///         label {
+///             {i18n.format_date(&CalendarDate::try_new_iso_date(2024, 1, 15).unwrap(), DateLength::Long)}
+///         }
+///     )
+/// }
+/// ```
+pub fn use_init_i18n(init: impl FnOnce() -> I18nConfig) -> UseI18n {
+    use_context_provider(|| {
+        let config = init();
+
+        let bundles = config
+            .locales
+            .into_iter()
+            .filter_map(|locale| {
+                create_bundle(locale)
+                    .inspect_err(|err| tracing::error!("{err}"))
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+
+        let available = bundles
+            .iter()
+            .filter_map(|bundle| bundle.locales.first().cloned())
+            .collect::<Vec<_>>();
+
+        let requested = if let Some(language) = config.language {
+            vec![language]
+        } else if config.use_os_language {
+            os_languages()
+        } else {
+            Vec::new()
+        };
+
+        let language = negotiate_language(&requested, &available, &config.fallback);
+
+        UseI18n {
+            language: Signal::new(language),
+            fallback: Signal::new(config.fallback),
+            bundles: Signal::new(bundles),
+        }
+    })
+}
+
+/// Access the i18n state provided with [use_init_i18n].
+pub fn use_i18n() -> UseI18n {
+    dioxus_hooks::use_context()
+}
+
+/// Same as [use_i18n] but it can be called outside of hooks, e.g in event handlers or the [t!](crate::t) macro.
+///
+/// # Panics
+/// Panics if there is no i18n state provided with [use_init_i18n].
+pub fn i18n() -> UseI18n {
+    consume_context()
+}
+
+/// Same as [i18n] but returning `None` if there is no i18n state.
+pub fn try_i18n() -> Option<UseI18n> {
+    try_consume_context()
+}
+
+/// Translate a message in the selected language, optionally with some arguments.
+/// The component is updated when the language changes.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # fn app() -> Element {
+/// rsx!(
+///     label { {t!("hello_world")} }
+///     label { {t!("hello", name: "Freya")} }
+///     label { {t!("unread", count: 3)} }
+///     label { {t!("button.tooltip")} }
+/// )
+/// # }
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($id:expr $(,)?) => {
+        $crate::i18n().translate($id, None)
+    };
+    ($id:expr, $( $name:ident : $value:expr ),+ $(,)?) => {{
+        let mut args = $crate::FluentArgs::new();
+        $(
+            args.set(stringify!($name), $value);
+        )+
+        $crate::i18n().translate($id, Some(&args))
+    }};
+}
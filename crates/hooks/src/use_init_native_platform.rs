@@ -14,7 +14,13 @@ use dioxus_signals::{
 };
 use freya_core::types::NativePlatformReceiver;
 
-use crate::use_init_asset_cacher;
+use crate::{
+    use_init_asset_cacher,
+    AccentColor,
+    LastUserInput,
+    SelectedText,
+    WindowLifecycle,
+};
 
 #[derive(Clone)]
 pub struct NavigationMark(bool);
@@ -48,10 +54,16 @@ pub fn use_init_native_platform() -> UsePlatformEvents {
         let platform_state = platform_receiver.borrow();
 
         let mut preferred_theme = Signal::new(platform_state.preferred_theme);
+        let mut accent_color = Signal::new(AccentColor(platform_state.accent_color));
         let mut focused_id = Signal::new(platform_state.focused_accessibility_id);
         let mut focused_node = Signal::new(platform_state.focused_accessibility_node.clone());
         let mut navigation_mode = Signal::new(platform_state.navigation_mode);
         let mut information = Signal::new(platform_state.information);
+        let mut monitors = Signal::new(platform_state.monitors.clone());
+        let mut soft_keyboard = Signal::new(platform_state.soft_keyboard);
+        let mut selected_text = Signal::new(SelectedText(platform_state.selected_text.clone()));
+        let mut window_lifecycle = Signal::new(WindowLifecycle::from_state(&platform_state));
+        let mut last_user_input = Signal::new(LastUserInput(platform_state.last_user_input));
 
         drop(platform_state);
 
@@ -71,6 +83,10 @@ pub fn use_init_native_platform() -> UsePlatformEvents {
                     *preferred_theme.write() = state.preferred_theme;
                 }
 
+                if accent_color.peek().0 != state.accent_color {
+                    *accent_color.write() = AccentColor(state.accent_color);
+                }
+
                 if *navigation_mode.peek() != state.navigation_mode {
                     *navigation_mode.write() = state.navigation_mode;
                 }
@@ -78,12 +94,38 @@ pub fn use_init_native_platform() -> UsePlatformEvents {
                 if *information.peek() != state.information {
                     *information.write() = state.information;
                 }
+
+                if *monitors.peek() != state.monitors {
+                    *monitors.write() = state.monitors.clone();
+                }
+
+                if *soft_keyboard.peek() != state.soft_keyboard {
+                    *soft_keyboard.write() = state.soft_keyboard;
+                }
+
+                if selected_text.peek().0 != state.selected_text {
+                    *selected_text.write() = SelectedText(state.selected_text.clone());
+                }
+
+                if *window_lifecycle.peek() != WindowLifecycle::from_state(&state) {
+                    *window_lifecycle.write() = WindowLifecycle::from_state(&state);
+                }
+
+                if last_user_input.peek().0 != state.last_user_input {
+                    *last_user_input.write() = LastUserInput(state.last_user_input);
+                }
             }
         });
 
         provide_context(preferred_theme);
+        provide_context(accent_color);
         provide_context(navigation_mode);
         provide_context(information);
+        provide_context(monitors);
+        provide_context(soft_keyboard);
+        provide_context(selected_text);
+        provide_context(window_lifecycle);
+        provide_context(last_user_input);
         provide_context(focused_id);
         provide_context(focused_node);
     });
@@ -0,0 +1,130 @@
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_hook,
+};
+use dioxus_hooks::use_signal;
+use dioxus_signals::{
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    native_menu::{
+        NativeMenu,
+        NativeMenuEvent,
+    },
+    types::NativeMenuEventSender,
+};
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+    use_platform,
+    UsePlatform,
+};
+
+/// Manage the native menu of the window, see [`use_menu`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseMenu {
+    platform: UsePlatform,
+    event: Signal<Option<NativeMenuEvent>>,
+}
+
+impl UseMenu {
+    /// Get the last activated item, subscribing to new activations.
+    pub fn event(&self) -> Option<NativeMenuEvent> {
+        self.event.read().clone()
+    }
+
+    /// Replace the menu of the window, or remove it if `None`.
+    pub fn set_menu(&self, menu: Option<NativeMenu>) {
+        self.platform
+            .send(EventLoopMessage::SetNativeMenu(menu))
+            .ok();
+    }
+
+    /// Check or uncheck a checkable item.
+    pub fn set_checked(&self, id: impl Into<String>, checked: bool) {
+        self.platform
+            .send(EventLoopMessage::SetNativeMenuItemChecked(
+                id.into(),
+                checked,
+            ))
+            .ok();
+    }
+
+    /// Enable or disable an item.
+    pub fn set_enabled(&self, id: impl Into<String>, enabled: bool) {
+        self.platform
+            .send(EventLoopMessage::SetNativeMenuItemEnabled(
+                id.into(),
+                enabled,
+            ))
+            .ok();
+    }
+}
+
+/// Manage the native menu of the window and listen for its items being activated,
+/// either by clicking them or by pressing their accelerators.
+///
+/// The initial menu is usually passed with `LaunchConfig::with_menu`.
+///
+/// The menu bar is only shown on Windows and macOS. On Linux only its accelerators work,
+/// see `NativeMenu` for more details.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn main() {
+///     launch_cfg(
+///         app,
+///         LaunchConfig::<()>::new().with_menu(
+///             NativeMenu::new().with_submenu(
+///                 NativeSubmenu::new("File")
+///                     .with_item(NativeMenuItem::new("save", "Save").accelerator("CmdOrCtrl+S"))
+///                     .with_separator()
+///                     .with_item(NativeMenuItem::new("autosave", "Autosave").checkable(false)),
+///             ),
+///         ),
+///     )
+/// }
+///
+/// fn app() -> Element {
+///     let menu = use_menu();
+///     let mut saves = use_signal(|| 0);
+///
+///     use_effect(move || {
+///         if let Some(NativeMenuEvent { id, .. }) = menu.event() {
+///             if id == "save" {
+///                 *saves.write() += 1;
+///             }
+///         }
+///     });
+///
+///     rsx!(label { "Saved {saves} times" })
+/// }
+/// ```
+pub fn use_menu() -> UseMenu {
+    let platform = use_platform();
+    let mut event = use_signal(|| None);
+
+    use_hook(|| {
+        let Some(sender) = try_consume_context::<NativeMenuEventSender>() else {
+            return;
+        };
+        let mut receiver = sender.subscribe();
+        spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(menu_event) => event.set(Some(menu_event)),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    });
+
+    UseMenu { platform, event }
+}
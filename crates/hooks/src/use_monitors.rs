@@ -0,0 +1,37 @@
+use dioxus_hooks::use_context;
+use dioxus_signals::{
+    ReadOnlySignal,
+    Signal,
+};
+use freya_core::platform_state::Monitors;
+
+/// Access the monitors connected to the device and the one where the window is.
+///
+/// It is updated when the window moves to another monitor, its scale factor changes
+/// or it gets focused again, as monitors might have been connected or disconnected meanwhile.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let monitors = use_monitors();
+///     let monitors = monitors.read();
+///
+///     rsx!(
+///         for monitor in &monitors.monitors {
+///             label {
+///                 "{monitor.name:?} {monitor.size:?} at {monitor.position:?}"
+///             }
+///         }
+///         if let Some(current) = monitors.current() {
+///             label {
+///                 "Current scale factor: {current.scale_factor}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_monitors() -> ReadOnlySignal<Monitors> {
+    use_context::<Signal<Monitors>>().into()
+}
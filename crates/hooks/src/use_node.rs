@@ -17,6 +17,13 @@ use freya_core::custom_attributes::{
     NodeReferenceLayout,
 };
 use tokio::sync::watch::channel;
+use torin::prelude::{
+    Area,
+    Size2D,
+};
+
+/// Minimum change, in logical pixels, for [use_node_layout] to notify its subscribers.
+pub const NODE_LAYOUT_EPSILON: f32 = 0.5;
 
 /// Subscribe to a Node layout changes.
 pub fn use_node() -> (AttributeValue, NodeReferenceLayout) {
@@ -117,12 +124,108 @@ pub fn use_node_with_reference() -> (NodeReference, ReadOnlySignal<NodeReference
     (NodeReference(tx), signal.into())
 }
 
+/// Layout of a Node observed with [use_node_layout].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct NodeLayout {
+    /// Area in window coordinates.
+    pub area: Area,
+    /// Area relative to the inner area of the parent Node.
+    pub local_area: Area,
+    /// Size of the content.
+    pub inner: Size2D,
+}
+
+impl NodeLayout {
+    /// Whether any coordinate differs from the other layout by more than the given epsilon.
+    pub fn differs(&self, other: &Self, epsilon: f32) -> bool {
+        let differs = |a: f32, b: f32| (a - b).abs() > epsilon;
+        differs(self.area.min_x(), other.area.min_x())
+            || differs(self.area.min_y(), other.area.min_y())
+            || differs(self.area.width(), other.area.width())
+            || differs(self.area.height(), other.area.height())
+            || differs(self.local_area.min_x(), other.local_area.min_x())
+            || differs(self.local_area.min_y(), other.local_area.min_y())
+            || differs(self.inner.width, other.inner.width)
+            || differs(self.inner.height, other.inner.height)
+    }
+}
+
+impl From<&NodeReferenceLayout> for NodeLayout {
+    fn from(layout: &NodeReferenceLayout) -> Self {
+        Self {
+            area: layout.area,
+            local_area: layout.local_area,
+            inner: layout.inner,
+        }
+    }
+}
+
+/// Get a signal with the layout of a Node, in both window and local coordinates,
+/// that is only updated when it changes by more than [NODE_LAYOUT_EPSILON].
+///
+/// Unlike [use_node_signal], sub-pixel changes, like the ones caused by animations,
+/// don't cause the subscribers to re-render on every frame.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let (reference, layout) = use_node_layout();
+///     let layout = layout.read();
+///
+///     rsx!(
+///         rect {
+///             padding: "20",
+///             rect {
+///                 reference,
+///                 width: "fill",
+///                 height: "100",
+///                 label {
+///                     "{layout.area.origin:?} in the window, {layout.local_area.origin:?} in the parent"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_node_layout() -> (AttributeValue, ReadOnlySignal<NodeLayout>) {
+    use_node_layout_with_epsilon(NODE_LAYOUT_EPSILON)
+}
+
+/// Same as [use_node_layout] but with a custom minimum change, in logical pixels.
+pub fn use_node_layout_with_epsilon(epsilon: f32) -> (AttributeValue, ReadOnlySignal<NodeLayout>) {
+    let (tx, signal) = use_hook(|| {
+        let (tx, mut rx) = channel::<NodeReferenceLayout>(NodeReferenceLayout::default());
+        let mut signal = Signal::new(NodeLayout::default());
+
+        spawn(async move {
+            while rx.changed().await.is_ok() {
+                let layout = NodeLayout::from(&*rx.borrow());
+                if signal.peek().differs(&layout, epsilon) {
+                    signal.set(layout);
+                }
+            }
+        });
+
+        (Arc::new(tx), signal)
+    });
+
+    (
+        AttributeValue::any_value(CustomAttributeValues::Reference(NodeReference(tx))),
+        signal.into(),
+    )
+}
+
 #[cfg(test)]
 mod test {
     use freya::prelude::*;
     use freya_testing::prelude::*;
 
-    use crate::use_node;
+    use crate::{
+        use_node,
+        use_node_layout,
+    };
 
     #[tokio::test]
     pub async fn track_size() {
@@ -165,4 +268,53 @@ mod test {
             Ok(300.0 * 0.5)
         );
     }
+
+    #[tokio::test]
+    pub async fn node_layout() {
+        fn use_node_layout_app() -> Element {
+            let (reference, layout) = use_node_layout();
+            let mut renders = use_signal(|| 0);
+            let mut padding = use_signal(|| 20.);
+
+            // Count the renders caused by the layout
+            use_effect(move || {
+                layout.read();
+                *renders.write() += 1;
+            });
+
+            rsx!(
+                rect {
+                    padding: "{padding}",
+                    onclick: move |_| padding += 0.25,
+                    width: "fill",
+                    height: "fill",
+                    rect {
+                        reference,
+                        width: "100",
+                        height: "50",
+                    }
+                    label {
+                        "{layout().area.min_x()} {layout().local_area.min_x()} {renders}"
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(use_node_layout_app);
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0).get(1);
+        assert_eq!(label.get(0).text(), Some("20 0 2"));
+
+        // Too small to be noticed
+        utils.click_cursor((5., 5.)).await;
+        assert_eq!(label.get(0).text(), Some("20 0 2"));
+
+        utils.click_cursor((5., 5.)).await;
+        assert_eq!(label.get(0).text(), Some("20 0 2"));
+
+        utils.click_cursor((5., 5.)).await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("20.75 0 3"));
+    }
 }
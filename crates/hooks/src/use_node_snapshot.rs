@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+use dioxus_core::{
+    prelude::spawn,
+    use_hook,
+    AttributeValue,
+};
+use dioxus_signals::{
+    ReadOnlySignal,
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::{
+    custom_attributes::{
+        CustomAttributeValues,
+        NodeReference,
+        NodeReferenceLayout,
+    },
+    event_loop_messages::EventLoopMessage,
+    render::ExportTarget,
+};
+use tokio::sync::{
+    oneshot,
+    watch::channel,
+};
+
+use crate::{
+    use_platform,
+    UsePlatform,
+};
+
+/// Created with [use_node_snapshot].
+#[derive(Clone, PartialEq)]
+pub struct UseNodeSnapshot {
+    reference: NodeReference,
+    layout: Signal<NodeReferenceLayout>,
+    platform: UsePlatform,
+}
+
+impl UseNodeSnapshot {
+    /// Get a [AttributeValue] for the `reference` attribute.
+    pub fn attribute(&self) -> AttributeValue {
+        AttributeValue::any_value(CustomAttributeValues::Reference(self.reference.clone()))
+    }
+
+    /// Get a [ReadOnlySignal] of the node layout.
+    pub fn layout(&self) -> ReadOnlySignal<NodeReferenceLayout> {
+        self.layout.into()
+    }
+
+    /// Rasterize the node and all its children into a PNG image.
+    ///
+    /// The image size will be the logical size of the node multiplied by `scale`.
+    /// Returns `None` if the node is not mounted or has an empty size.
+    pub async fn snapshot(&self, scale: f32) -> Option<Bytes> {
+        let (sender, receiver) = oneshot::channel();
+        self.platform
+            .send(EventLoopMessage::SnapshotNode {
+                reference: self.reference.clone(),
+                scale,
+                sender,
+            })
+            .ok()?;
+        receiver.await.ok().flatten()
+    }
+}
+
+impl From<&UseNodeSnapshot> for ExportTarget {
+    fn from(snapshot: &UseNodeSnapshot) -> Self {
+        Self::Node(snapshot.reference.clone())
+    }
+}
+
+/// Rasterize a node and its children into an image, useful for drag previews or exporting parts of the UI.
+///
+/// ## Usage
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let snapshot = use_node_snapshot();
+///
+///     let onpress = {
+///         let snapshot = snapshot.clone();
+///         move |_| {
+///             let snapshot = snapshot.clone();
+///             spawn(async move {
+///                 if let Some(png) = snapshot.snapshot(2.0).await {
+///                     std::fs::write("./snapshot.png", png).ok();
+///                 }
+///             });
+///         }
+///     };
+///
+///     rsx!(
+///         rect {
+///             reference: snapshot.attribute(),
+///             background: "rgb(0, 119, 182)",
+///             padding: "20",
+///             Button {
+///                 onpress,
+///                 label { "Export" }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_node_snapshot() -> UseNodeSnapshot {
+    let platform = use_platform();
+    let (tx, layout) = use_hook(|| {
+        let (tx, mut rx) = channel::<NodeReferenceLayout>(NodeReferenceLayout::default());
+        let mut signal = Signal::new(NodeReferenceLayout::default());
+
+        spawn(async move {
+            while rx.changed().await.is_ok() {
+                if *signal.peek() != *rx.borrow() {
+                    signal.set(rx.borrow().clone());
+                }
+            }
+        });
+
+        (Arc::new(tx), signal)
+    });
+
+    UseNodeSnapshot {
+        reference: NodeReference(tx),
+        layout,
+        platform,
+    }
+}
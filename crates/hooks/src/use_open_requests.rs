@@ -0,0 +1,63 @@
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_hook,
+};
+use dioxus_hooks::use_callback;
+use freya_core::{
+    open_requests::{
+        LaunchOpenRequests,
+        OpenRequest,
+    },
+    types::OpenRequestSender,
+};
+use tokio::sync::broadcast::error::RecvError;
+
+/// Handle the links and files the app is asked to open.
+///
+/// The handler runs first with the ones in the arguments the app was launched with, and then with the ones
+/// forwarded by new instances of the app, see `LaunchConfig::with_url_scheme` and `LaunchConfig::single_instance`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut page = use_signal(|| "home".to_string());
+///
+///     use_open_requests(move |request| {
+///         if let OpenRequest::Url(url) = request {
+///             if let Some(path) = url.strip_prefix("myapp://") {
+///                 page.set(path.to_string());
+///             }
+///         }
+///     });
+///
+///     rsx!(label { "Page: {page}" })
+/// }
+/// ```
+pub fn use_open_requests(handler: impl FnMut(OpenRequest) + 'static) {
+    let handler = use_callback(handler);
+
+    use_hook(|| {
+        let Some(sender) = try_consume_context::<OpenRequestSender>() else {
+            return;
+        };
+        // Subscribe right away so no request is missed while the task starts
+        let mut receiver = sender.subscribe();
+        let LaunchOpenRequests(launch_requests) =
+            try_consume_context::<LaunchOpenRequests>().unwrap_or_default();
+        spawn(async move {
+            for request in launch_requests {
+                handler.call(request);
+            }
+            loop {
+                match receiver.recv().await {
+                    Ok(request) => handler.call(request),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    });
+}
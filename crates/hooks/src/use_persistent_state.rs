@@ -0,0 +1,375 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    rc::Rc,
+    time::Duration,
+};
+
+use dioxus_core::prelude::{
+    use_drop,
+    use_hook,
+};
+use dioxus_hooks::{
+    use_effect,
+    use_signal,
+};
+use dioxus_signals::{
+    CopyValue,
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::app_dirs::{
+    app_config_dir,
+    app_name,
+    key_file_name,
+};
+use serde::{
+    de::DeserializeOwned,
+    Deserialize,
+    Serialize,
+};
+use serde_json::Value;
+
+use crate::use_debounce;
+
+/// Errors that might happen while loading or saving a persistent state.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PersistentStateError {
+    /// The storage could not be read or written.
+    Io(String),
+    /// The stored content is not valid.
+    Parse(String),
+    /// The stored content was saved by a newer version of the app.
+    UnknownVersion(u32),
+}
+
+impl std::fmt::Display for PersistentStateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Failed to access persistent state: {err}"),
+            Self::Parse(err) => write!(f, "Failed to parse persistent state: {err}"),
+            Self::UnknownVersion(version) => {
+                write!(f, "Persistent state has an unknown version: {version}")
+            }
+        }
+    }
+}
+
+/// Where the persistent states are saved, see [FileStorage] and [MemoryStorage].
+pub trait StorageBackend {
+    /// Get the content saved with the given key, if any.
+    fn load(&self, key: &str) -> Result<Option<String>, PersistentStateError>;
+
+    /// Save the content with the given key, replacing the previous one.
+    fn save(&self, key: &str, content: &str) -> Result<(), PersistentStateError>;
+}
+
+/// Save every state in a JSON file named after its key inside a folder.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileStorage {
+    dir: PathBuf,
+}
+
+impl Default for FileStorage {
+    /// Use a folder named after the app executable inside the config folder of the platform,
+    /// e.g. `~/.config/my-app` in Linux.
+    fn default() -> Self {
+        Self::in_config_dir(&app_name())
+    }
+}
+
+impl FileStorage {
+    /// Use the given folder.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Use a folder with the given name inside the config folder of the platform.
+    pub fn in_config_dir(app_name: &str) -> Self {
+        Self::new(app_config_dir(app_name))
+    }
+
+    /// Get the folder where the states are saved.
+    pub fn dir(&self) -> &PathBuf {
+        &self.dir
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key_file_name(key)))
+    }
+}
+
+impl StorageBackend for FileStorage {
+    fn load(&self, key: &str) -> Result<Option<String>, PersistentStateError> {
+        match fs::read_to_string(self.path(key)) {
+            Ok(content) => Ok(Some(content)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(PersistentStateError::Io(err.to_string())),
+        }
+    }
+
+    fn save(&self, key: &str, content: &str) -> Result<(), PersistentStateError> {
+        let path = self.path(key);
+        fs::create_dir_all(&self.dir).map_err(|err| PersistentStateError::Io(err.to_string()))?;
+        // Write a temporary file first so a crash never leaves a half written state behind
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, content)
+            .and_then(|_| fs::rename(&temp_path, &path))
+            .map_err(|err| PersistentStateError::Io(err.to_string()))
+    }
+}
+
+/// Keep the states in memory, useful for tests. Clones share the same states.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStorage {
+    states: Rc<RefCell<HashMap<String, String>>>,
+}
+
+impl MemoryStorage {
+    /// Get the content saved with the given key, if any.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.states.borrow().get(key).cloned()
+    }
+
+    /// Save the content with the given key.
+    pub fn set(&self, key: impl Into<String>, content: impl Into<String>) {
+        self.states.borrow_mut().insert(key.into(), content.into());
+    }
+}
+
+impl StorageBackend for MemoryStorage {
+    fn load(&self, key: &str) -> Result<Option<String>, PersistentStateError> {
+        Ok(self.get(key))
+    }
+
+    fn save(&self, key: &str, content: &str) -> Result<(), PersistentStateError> {
+        self.set(key, content);
+        Ok(())
+    }
+}
+
+type Migration = Box<dyn Fn(Value) -> Value>;
+
+/// Configuration for [use_persistent_state_with_config].
+pub struct PersistentStateConfig {
+    key: String,
+    storage: Rc<dyn StorageBackend>,
+    version: u32,
+    migrations: HashMap<u32, Migration>,
+    write_delay: Duration,
+}
+
+impl PersistentStateConfig {
+    /// Save the state with the given key in the [FileStorage::default].
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            storage: Rc::new(FileStorage::default()),
+            version: 0,
+            migrations: HashMap::default(),
+            write_delay: Duration::from_millis(500),
+        }
+    }
+
+    pub fn with_storage(mut self, storage: impl StorageBackend + 'static) -> Self {
+        self.storage = Rc::new(storage);
+        self
+    }
+
+    /// Version of the state shape, increase it along with a migration when it changes.
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Convert a state saved with the given version into the shape of the next version.
+    pub fn with_migration(
+        mut self,
+        from_version: u32,
+        migration: impl Fn(Value) -> Value + 'static,
+    ) -> Self {
+        self.migrations.insert(from_version, Box::new(migration));
+        self
+    }
+
+    /// Time to wait without changes before saving the state, so quick changes are saved only once.
+    pub fn with_write_delay(mut self, write_delay: Duration) -> Self {
+        self.write_delay = write_delay;
+        self
+    }
+
+    /// Load the state, migrating it from older versions if needed.
+    pub fn load<T: DeserializeOwned>(&self) -> Result<Option<T>, PersistentStateError> {
+        let Some(content) = self.storage.load(&self.key)? else {
+            return Ok(None);
+        };
+        let stored: StoredState<Value> = serde_json::from_str(&content)
+            .map_err(|err| PersistentStateError::Parse(err.to_string()))?;
+        if stored.version > self.version {
+            return Err(PersistentStateError::UnknownVersion(stored.version));
+        }
+        let mut value = stored.value;
+        for version in stored.version..self.version {
+            if let Some(migration) = self.migrations.get(&version) {
+                value = migration(value);
+            }
+        }
+        serde_json::from_value(value)
+            .map(Some)
+            .map_err(|err| PersistentStateError::Parse(err.to_string()))
+    }
+
+    /// Save the state right away.
+    pub fn save<T: Serialize>(&self, value: &T) -> Result<(), PersistentStateError> {
+        let content = self.serialize(value)?;
+        self.storage.save(&self.key, &content)
+    }
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<String, PersistentStateError> {
+        serde_json::to_string_pretty(&StoredState {
+            version: self.version,
+            value,
+        })
+        .map_err(|err| PersistentStateError::Parse(err.to_string()))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredState<V> {
+    version: u32,
+    value: V,
+}
+
+/// Create a signal that remembers its value across app launches, see [use_persistent_state_with_config].
+///
+/// The value is saved as JSON in the config folder of the platform, in a file named after the given key.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut recent_files = use_persistent_state("recent_files", Vec::<String>::new);
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| recent_files.write().push("notes.txt".to_string()),
+///             label {
+///                 "Open notes.txt"
+///             }
+///         }
+///         for file in recent_files.read().iter() {
+///             label {
+///                 "{file}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_persistent_state<T: Serialize + DeserializeOwned + 'static>(
+    key: impl Into<String>,
+    init: impl FnOnce() -> T,
+) -> Signal<T> {
+    use_persistent_state_with_config(|| PersistentStateConfig::new(key), init)
+}
+
+/// Same as [use_persistent_state] but with a custom [PersistentStateConfig].
+///
+/// The initial value is used when there is no saved state, or when it can't be loaded.
+/// Changes are saved once the write delay passes without new ones, and right away when the component is dropped.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use serde::{Serialize, Deserialize};
+/// #[derive(Serialize, Deserialize, Default)]
+/// struct Layout {
+///     sidebar_width: f32,
+///     sidebar_visible: bool,
+/// }
+///
+/// fn app() -> Element {
+///     let mut layout = use_persistent_state_with_config(
+///         || {
+///             PersistentStateConfig::new("layout")
+///                 .with_version(1)
+///                 // Version 0 only had the width
+///                 .with_migration(0, |width| {
+///                     serde_json::json!({ "sidebar_width": width, "sidebar_visible": true })
+///                 })
+///         },
+///         Layout::default,
+///     );
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| {
+///                 let mut layout = layout.write();
+///                 layout.sidebar_visible = !layout.sidebar_visible;
+///             },
+///             label {
+///                 "Toggle sidebar"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_persistent_state_with_config<T: Serialize + DeserializeOwned + 'static>(
+    config: impl FnOnce() -> PersistentStateConfig,
+    init: impl FnOnce() -> T,
+) -> Signal<T> {
+    let config = use_hook(|| Rc::new(config()));
+    let state = use_signal(|| {
+        config
+            .load()
+            .inspect_err(|err| tracing::warn!("{err}"))
+            .ok()
+            .flatten()
+            .unwrap_or_else(init)
+    });
+    // Latest content waiting to be saved
+    let mut pending = use_hook(|| CopyValue::new(None::<String>));
+    let mut is_first_run = use_hook(|| CopyValue::new(true));
+
+    let mut debounce = use_debounce(config.write_delay, {
+        let config = config.clone();
+        move |_| {
+            if let Some(content) = pending.write().take() {
+                if let Err(err) = config.storage.save(&config.key, &content) {
+                    tracing::error!("{err}");
+                }
+            }
+        }
+    });
+
+    use_effect({
+        let config = config.clone();
+        move || {
+            let content = config.serialize(&*state.read());
+            // The loaded value is already saved
+            if *is_first_run.peek() {
+                is_first_run.set(false);
+                return;
+            }
+            match content {
+                Ok(content) => {
+                    pending.set(Some(content));
+                    debounce.action(());
+                }
+                Err(err) => tracing::error!("{err}"),
+            }
+        }
+    });
+
+    use_drop(move || {
+        if let Some(content) = pending.write_unchecked().take() {
+            if let Err(err) = config.storage.save(&config.key, &content) {
+                tracing::error!("{err}");
+            }
+        }
+    });
+
+    state
+}
@@ -1,31 +1,83 @@
-use std::sync::Arc;
+use std::{
+    any::Any,
+    cell::RefCell,
+    path::{
+        Path,
+        PathBuf,
+    },
+    rc::Rc,
+    sync::Arc,
+};
 
+use bytes::Bytes;
 use dioxus_core::{
     prelude::{
         consume_context,
         provide_root_context,
+        spawn_forever,
         try_consume_context,
+        use_drop,
         use_hook,
     },
     ScopeId,
+    Task,
 };
+use dioxus_hooks::use_callback;
 use dioxus_signals::{
+    CopyValue,
     Readable,
     Signal,
+    Writable,
 };
 use freya_core::{
     accessibility::AccessibilityFocusStrategy,
     event_loop_messages::EventLoopMessage,
+    opener::{
+        OpenError,
+        OpenTarget,
+    },
     platform::{
         CursorIcon,
+        CursorImage,
         EventLoopProxy,
         Fullscreen,
+        LogicalPosition,
+        LogicalSize,
+        ResizeDirection,
+        UserAttentionType,
         Window,
+        WindowLevel,
+    },
+    platform_state::{
+        FramePacing,
+        FrameStats,
+        TaskbarProgress,
+        WindowEffect,
     },
+    printing::{
+        PrintError,
+        PrintOptions,
+        Printer,
+    },
+    render::{
+        CapturedFrame,
+        ExportError,
+        ExportFormat,
+        ExportTarget,
+    },
+    types::CloseRequestSender,
 };
 use tokio::sync::{
-    broadcast,
-    mpsc::UnboundedSender,
+    broadcast::{
+        self,
+        error::RecvError,
+    },
+    mpsc::{
+        unbounded_channel,
+        UnboundedReceiver,
+        UnboundedSender,
+    },
+    oneshot,
 };
 use torin::prelude::Area;
 
@@ -34,6 +86,20 @@ pub struct UsePlatform {
     ticker: Signal<Arc<broadcast::Receiver<()>>>,
     event_loop_proxy: Signal<Option<EventLoopProxy<EventLoopMessage>>>,
     platform_emitter: Signal<Option<UnboundedSender<EventLoopMessage>>>,
+    close_requests: CopyValue<CloseRequests>,
+}
+
+/// Identifier of a handler registered with [UsePlatform::on_close_request].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CloseRequestId(usize);
+
+/// Handlers of the requests to close the window, see [UsePlatform::on_close_request].
+struct CloseRequests {
+    sender: Option<CloseRequestSender>,
+    handlers: Vec<(CloseRequestId, Rc<RefCell<dyn FnMut() -> bool>>)>,
+    next_id: usize,
+    /// Listens for the requests while there are handlers, as the window closes by itself when nobody listens.
+    listener: Option<Task>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -59,6 +125,15 @@ impl UsePlatform {
                     consume_context::<Arc<broadcast::Receiver<()>>>(),
                     ScopeId::ROOT,
                 ),
+                close_requests: CopyValue::new_in_scope(
+                    CloseRequests {
+                        sender: try_consume_context::<CloseRequestSender>(),
+                        handlers: Vec::new(),
+                        next_id: 0,
+                        listener: None,
+                    },
+                    ScopeId::ROOT,
+                ),
             }),
         }
     }
@@ -80,6 +155,13 @@ impl UsePlatform {
         self.send(EventLoopMessage::SetCursorIcon(cursor_icon)).ok();
     }
 
+    /// Use an image as cursor, it can be loaded with `LaunchConfig::load_cursor`.
+    /// Go back to an icon with [UsePlatform::set_cursor].
+    pub fn set_custom_cursor(&self, cursor_image: CursorImage) {
+        self.send(EventLoopMessage::SetCustomCursor(cursor_image))
+            .ok();
+    }
+
     pub fn set_title(&self, title: impl Into<String>) {
         let title = title.into();
         self.with_window(move |window| {
@@ -87,6 +169,70 @@ impl UsePlatform {
         });
     }
 
+    /// Resize the inner area of the window, in logical pixels.
+    pub fn set_window_size(&self, width: f64, height: f64) {
+        self.with_window(move |window| {
+            let _ = window.request_inner_size(LogicalSize::new(width, height));
+        });
+    }
+
+    /// Limit how small the window can be resized, or remove the limit if `None`.
+    pub fn set_window_min_size(&self, min_size: Option<(f64, f64)>) {
+        self.with_window(move |window| {
+            window.set_min_inner_size(
+                min_size.map(|(width, height)| LogicalSize::new(width, height)),
+            );
+        });
+    }
+
+    /// Limit how big the window can be resized, or remove the limit if `None`.
+    pub fn set_window_max_size(&self, max_size: Option<(f64, f64)>) {
+        self.with_window(move |window| {
+            window.set_max_inner_size(
+                max_size.map(|(width, height)| LogicalSize::new(width, height)),
+            );
+        });
+    }
+
+    /// Move the window to the given position of the desktop, in logical pixels.
+    pub fn set_window_position(&self, x: f64, y: f64) {
+        self.with_window(move |window| {
+            window.set_outer_position(LogicalPosition::new(x, y));
+        });
+    }
+
+    /// Keep the window above the rest of windows.
+    pub fn set_always_on_top(&self, always_on_top: bool) {
+        self.with_window(move |window| {
+            window.set_window_level(if always_on_top {
+                WindowLevel::AlwaysOnTop
+            } else {
+                WindowLevel::Normal
+            });
+        });
+    }
+
+    /// Ask the user to pay attention to the window, e.g. by flashing its taskbar icon.
+    /// `None` cancels a previous request.
+    pub fn request_user_attention(&self, attention: Option<UserAttentionType>) {
+        self.with_window(move |window| {
+            window.request_user_attention(attention);
+        });
+    }
+
+    /// Show the progress of a task in the taskbar icon of the window, or hide it if `None`.
+    /// Only supported on Windows.
+    pub fn set_taskbar_progress(&self, progress: Option<TaskbarProgress>) {
+        self.send(EventLoopMessage::SetTaskbarProgress(progress))
+            .ok();
+    }
+
+    /// Change the effect shown behind the transparent areas of the window, or remove it if `None`.
+    /// The window needs to be transparent, see `LaunchConfig::with_window_effect`.
+    pub fn set_window_effect(&self, effect: Option<WindowEffect>) {
+        self.send(EventLoopMessage::SetWindowEffect(effect)).ok();
+    }
+
     pub fn with_window(&self, cb: impl FnOnce(&Window) + 'static + Send + Sync) {
         self.send(EventLoopMessage::WithWindow(Box::new(cb))).ok();
     }
@@ -97,6 +243,14 @@ impl UsePlatform {
         });
     }
 
+    /// Start resizing the window from the given edge or corner, following the cursor until the mouse is released.
+    /// Useful for undecorated windows, see `WindowResizeArea`.
+    pub fn start_resize(&self, direction: ResizeDirection) {
+        self.with_window(move |window| {
+            window.drag_resize_window(direction).ok();
+        });
+    }
+
     pub fn set_maximize_window(&self, maximize: bool) {
         self.with_window(move |window| {
             window.set_maximized(maximize);
@@ -121,6 +275,14 @@ impl UsePlatform {
         });
     }
 
+    /// Bring the window back from being minimized or maximized.
+    pub fn restore_window(&self) {
+        self.with_window(|window| {
+            window.set_minimized(false);
+            window.set_maximized(false);
+        });
+    }
+
     pub fn toggle_fullscreen_window(&self) {
         self.with_window(|window| match window.fullscreen() {
             Some(_) => window.set_fullscreen(None),
@@ -138,6 +300,33 @@ impl UsePlatform {
         });
     }
 
+    /// Enter or leave exclusive fullscreen, using the video mode of the current monitor
+    /// with the highest resolution and refresh rate. Falls back to borderless fullscreen if there is none.
+    pub fn set_exclusive_fullscreen_window(&self, fullscreen: bool) {
+        self.with_window(move |window| {
+            let video_mode = window.current_monitor().and_then(|monitor| {
+                monitor.video_modes().max_by_key(|video_mode| {
+                    let size = video_mode.size();
+                    (
+                        size.width * size.height,
+                        video_mode.refresh_rate_millihertz(),
+                    )
+                })
+            });
+            let fullscreen = fullscreen.then(|| match video_mode {
+                Some(video_mode) => Fullscreen::Exclusive(video_mode),
+                None => Fullscreen::Borderless(None),
+            });
+            window.set_fullscreen(fullscreen);
+        });
+    }
+
+    /// Send a custom message to the plugins, received as `PluginEvent::Message`.
+    pub fn send_plugin_message(&self, message: impl Any + Send + Sync) {
+        self.send(EventLoopMessage::PluginMessage(Box::new(message)))
+            .ok();
+    }
+
     pub fn invalidate_drawing_area(&self, area: Area) {
         self.send(EventLoopMessage::InvalidateArea(area)).ok();
     }
@@ -157,10 +346,210 @@ impl UsePlatform {
         }
     }
 
+    /// Encode the last rendered frame of the window into a PNG image.
+    pub async fn capture_window(&self) -> Option<Bytes> {
+        let (sender, receiver) = oneshot::channel();
+        self.send(EventLoopMessage::CaptureWindow(sender)).ok()?;
+        receiver.await.ok().flatten()
+    }
+
+    /// Render the window or a node into a PDF or SVG document, e.g. for printing or reports.
+    ///
+    /// The drawing commands are replayed into the vector backends instead of rasterizing them,
+    /// so text and shapes stay sharp at any size.
+    pub async fn export_bytes(
+        &self,
+        target: impl Into<ExportTarget>,
+        format: ExportFormat,
+    ) -> Result<Bytes, ExportError> {
+        let (sender, receiver) = oneshot::channel();
+        self.send(EventLoopMessage::Export {
+            target: target.into(),
+            format,
+            sender,
+        })
+        .map_err(|_| ExportError::Unavailable)?;
+        receiver
+            .await
+            .map_err(|_| ExportError::Unavailable)?
+            .ok_or(ExportError::NothingToExport)
+    }
+
+    /// Render the window or a node into a PDF or SVG document and save it into a file.
+    /// See [UsePlatform::export_bytes].
+    pub async fn export(
+        &self,
+        target: impl Into<ExportTarget>,
+        format: ExportFormat,
+        path: impl AsRef<Path>,
+    ) -> Result<(), ExportError> {
+        let document = self.export_bytes(target, format).await?;
+        std::fs::write(path, document).map_err(ExportError::Io)
+    }
+
+    /// Get the printers installed in the system.
+    pub async fn printers(&self) -> Vec<Printer> {
+        let (sender, receiver) = oneshot::channel();
+        if self.send(EventLoopMessage::GetPrinters(sender)).is_err() {
+            return Vec::new();
+        }
+        receiver.await.unwrap_or_default()
+    }
+
+    /// Print the window or a node and get the number of printed pages.
+    ///
+    /// The content is rendered at print resolution and split into pages where they are full,
+    /// following the `page_break` attribute of the elements.
+    pub async fn print(
+        &self,
+        target: impl Into<ExportTarget>,
+        options: PrintOptions,
+    ) -> Result<usize, PrintError> {
+        let (sender, receiver) = oneshot::channel();
+        self.send(EventLoopMessage::Print {
+            target: target.into(),
+            options,
+            sender,
+        })
+        .map_err(|_| PrintError::Unavailable)?;
+        receiver.await.map_err(|_| PrintError::Unavailable)?
+    }
+
+    /// Open a link with the default browser, or the app registered for its scheme, e.g. `mailto:`.
+    ///
+    /// Failures are also logged, so the result can be ignored.
+    pub async fn open_url(&self, url: impl Into<String>) -> Result<(), OpenError> {
+        self.open(OpenTarget::Url(url.into())).await
+    }
+
+    /// Open a file or folder with its default app.
+    pub async fn open_path(&self, path: impl Into<PathBuf>) -> Result<(), OpenError> {
+        self.open(OpenTarget::Path(path.into())).await
+    }
+
+    /// Show a file or folder selected in the file manager, e.g. for a "Show in folder" button.
+    ///
+    /// Some file managers in Linux only open the folder that contains it.
+    pub async fn reveal_in_file_manager(&self, path: impl Into<PathBuf>) -> Result<(), OpenError> {
+        self.open(OpenTarget::Reveal(path.into())).await
+    }
+
+    async fn open(&self, target: OpenTarget) -> Result<(), OpenError> {
+        let (sender, receiver) = oneshot::channel();
+        self.send(EventLoopMessage::Open { target, sender })
+            .map_err(|_| OpenError::Unavailable)?;
+        receiver.await.map_err(|_| OpenError::Unavailable)?
+    }
+
+    /// Receive the raw pixels of every frame rendered from now on, e.g. for screen recording.
+    /// Capturing stops when the receiver is dropped or [UsePlatform::stop_frame_capture] is called.
+    pub fn start_frame_capture(&self) -> UnboundedReceiver<CapturedFrame> {
+        let (sender, receiver) = unbounded_channel();
+        self.send(EventLoopMessage::SetFrameCapture(Some(sender)))
+            .ok();
+        receiver
+    }
+
+    /// Stop capturing frames.
+    pub fn stop_frame_capture(&self) {
+        self.send(EventLoopMessage::SetFrameCapture(None)).ok();
+    }
+
+    /// Change how frames are paced and presented, e.g. to disable vsync or limit the FPS.
+    pub fn set_frame_pacing(&self, frame_pacing: FramePacing) {
+        self.send(EventLoopMessage::SetFramePacing(frame_pacing))
+            .ok();
+    }
+
+    /// Limit the frame rate while the window is unfocused or minimized, or stop limiting it if `None`.
+    /// See `LaunchConfig::with_background_max_fps`.
+    pub fn set_background_max_fps(&self, background_max_fps: Option<u32>) {
+        self.send(EventLoopMessage::SetBackgroundMaxFps(background_max_fps))
+            .ok();
+    }
+
+    /// Paint the bounds, margins, paddings and alignments of every node over the app.
+    pub fn set_debug_layout(&self, debug_layout: bool) {
+        self.send(EventLoopMessage::SetDebugLayout(debug_layout))
+            .ok();
+    }
+
+    /// Get the rendering statistics, such as the current FPS.
+    pub async fn frame_stats(&self) -> Option<FrameStats> {
+        let (sender, receiver) = oneshot::channel();
+        self.send(EventLoopMessage::GetFrameStats(sender)).ok()?;
+        receiver.await.ok()
+    }
+
     /// Closes the whole app.
     pub fn exit(&self) {
         self.send(EventLoopMessage::ExitApp).ok();
     }
+
+    /// Close the window without running the close request handlers, e.g. once the user confirms it.
+    /// It's hidden instead if it's configured to be hidden to the tray on close.
+    pub fn close_window(&self) {
+        self.send(EventLoopMessage::CloseWindow).ok();
+    }
+
+    /// Run a handler when the user tries to close the window, e.g. to ask whether to save the changes.
+    ///
+    /// The window is only closed if every handler returns `true`, otherwise it stays open
+    /// and can be closed later with [UsePlatform::close_window].
+    /// See [use_close_request] to keep the handler while a component is alive.
+    pub fn on_close_request(&self, handler: impl FnMut() -> bool + 'static) -> CloseRequestId {
+        let mut close_requests = self.close_requests.write_unchecked();
+        let id = CloseRequestId(close_requests.next_id);
+        close_requests.next_id += 1;
+        close_requests
+            .handlers
+            .push((id, Rc::new(RefCell::new(handler))));
+
+        if close_requests.listener.is_none() {
+            if let Some(sender) = &close_requests.sender {
+                let mut receiver = sender.subscribe();
+                let platform = *self;
+                close_requests.listener = spawn_forever(async move {
+                    loop {
+                        match receiver.recv().await {
+                            Ok(()) => platform.handle_close_request(),
+                            Err(RecvError::Lagged(_)) => continue,
+                            Err(RecvError::Closed) => break,
+                        }
+                    }
+                });
+            }
+        }
+
+        id
+    }
+
+    /// Remove a handler registered with [UsePlatform::on_close_request].
+    pub fn remove_close_request(&self, id: CloseRequestId) {
+        let mut close_requests = self.close_requests.write_unchecked();
+        close_requests
+            .handlers
+            .retain(|(handler_id, _)| *handler_id != id);
+        if close_requests.handlers.is_empty() {
+            if let Some(listener) = close_requests.listener.take() {
+                listener.cancel();
+            }
+        }
+    }
+
+    fn handle_close_request(&self) {
+        // Handlers can register or remove other handlers while running
+        let handlers = self
+            .close_requests
+            .peek()
+            .handlers
+            .iter()
+            .map(|(_, handler)| handler.clone())
+            .collect::<Vec<_>>();
+        if handlers.iter().all(|handler| (handler.borrow_mut())()) {
+            self.close_window();
+        }
+    }
 }
 
 /// Get access to information and features of the platform.
@@ -168,6 +557,50 @@ pub fn use_platform() -> UsePlatform {
     use_hook(UsePlatform::current)
 }
 
+/// Run a handler when the user tries to close the window while this component is alive,
+/// returning `false` to keep it open. See [UsePlatform::on_close_request].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let platform = use_platform();
+///     let unsaved_changes = use_signal(|| true);
+///     let mut show_dialog = use_signal(|| false);
+///
+///     use_close_request(move || {
+///         if unsaved_changes() {
+///             show_dialog.set(true);
+///             false
+///         } else {
+///             true
+///         }
+///     });
+///
+///     rsx!(
+///         if show_dialog() {
+///             label {
+///                 "There are unsaved changes"
+///             }
+///             Button {
+///                 onpress: move |_| platform.close_window(),
+///                 label {
+///                     "Close without saving"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_close_request(mut handler: impl FnMut() -> bool + 'static) {
+    let platform = use_platform();
+    let handler = use_callback(move |()| handler());
+    let id = use_hook(|| platform.on_close_request(move || handler.call(())));
+
+    use_drop(move || platform.remove_close_request(id));
+}
+
 pub struct Ticker {
     inner: broadcast::Receiver<()>,
 }
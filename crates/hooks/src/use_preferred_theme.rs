@@ -1,11 +1,93 @@
-use dioxus_hooks::use_context;
+use dioxus_hooks::{
+    use_context,
+    use_effect,
+    use_signal,
+};
 use dioxus_signals::{
     ReadOnlySignal,
+    Readable,
     Signal,
+    Writable,
 };
 use freya_core::platform_state::PreferredTheme;
+use freya_engine::prelude::Color;
+
+use crate::{
+    use_init_theme,
+    Theme,
+    DARK_THEME,
+    LIGHT_THEME,
+};
 
 /// Access the preferred theme selected by the user.
 pub fn use_preferred_theme() -> ReadOnlySignal<PreferredTheme> {
     use_context::<Signal<PreferredTheme>>().into()
 }
+
+/// Accent color selected by the user in the operating system.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AccentColor(pub Option<Color>);
+
+impl AccentColor {
+    /// Get the accent color in a format that can be used in attributes and themes, e.g `rgb(0, 122, 255)`.
+    pub fn to_rgb(&self) -> Option<String> {
+        self.0
+            .map(|color| format!("rgb({}, {}, {})", color.r(), color.g(), color.b()))
+    }
+}
+
+/// Access the accent color selected by the user in the operating system, if supported.
+///
+/// It is kept in sync with the operating system while the app is running.
+pub fn use_accent_color() -> ReadOnlySignal<AccentColor> {
+    use_context::<Signal<AccentColor>>().into()
+}
+
+fn get_theme(preferred_theme: PreferredTheme) -> Theme {
+    match preferred_theme {
+        PreferredTheme::Dark => DARK_THEME,
+        PreferredTheme::Light | PreferredTheme::Auto => LIGHT_THEME,
+    }
+}
+
+/// Provide a [`Theme`] that follows the given [`PreferredTheme`].
+/// With [`PreferredTheme::Auto`] the light or dark theme will be swapped whenever the operating system preference changes.
+///
+/// The returned signal can be used to change the preference at runtime.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut preference = use_init_theme_with_preference(|| PreferredTheme::Auto);
+///
+///     rsx!(
+///         Body {
+///             Button {
+///                 onpress: move |_| preference.set(PreferredTheme::Dark),
+///                 label {
+///                     "Always dark"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_init_theme_with_preference(
+    preference_cb: impl FnOnce() -> PreferredTheme,
+) -> Signal<PreferredTheme> {
+    let system_theme = use_preferred_theme();
+    let preference = use_signal(preference_cb);
+    let mut theme = use_init_theme(|| get_theme(preference.peek().resolve(*system_theme.peek())));
+
+    use_effect(move || {
+        let new_theme = get_theme(preference.read().resolve(*system_theme.read()));
+        // Only swap the theme when the variant changes, so tweaks made to the current one are kept
+        if theme.peek().name != new_theme.name {
+            theme.set(new_theme);
+        }
+    });
+
+    preference
+}
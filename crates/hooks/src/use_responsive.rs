@@ -0,0 +1,189 @@
+use std::{
+    fmt,
+    str::FromStr,
+};
+
+use dioxus_core::prelude::{
+    try_consume_context,
+    use_hook,
+};
+use dioxus_hooks::{
+    use_context_provider,
+    use_memo,
+};
+use dioxus_signals::{
+    Memo,
+    Readable,
+};
+
+use crate::use_platform_information;
+
+/// Range of window widths, from the narrowest to the widest, see [use_responsive].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Breakpoint {
+    /// Narrower than [Breakpoints::sm], e.g. phones.
+    #[default]
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    /// Wider than [Breakpoints::xl].
+    Xl,
+}
+
+impl Breakpoint {
+    /// Whether this is the given breakpoint or a wider one.
+    pub fn is_above(&self, breakpoint: Breakpoint) -> bool {
+        *self >= breakpoint
+    }
+
+    /// Whether this is narrower than the given breakpoint.
+    pub fn is_below(&self, breakpoint: Breakpoint) -> bool {
+        *self < breakpoint
+    }
+}
+
+impl FromStr for Breakpoint {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "xs" => Ok(Self::Xs),
+            "sm" => Ok(Self::Sm),
+            "md" => Ok(Self::Md),
+            "lg" => Ok(Self::Lg),
+            "xl" => Ok(Self::Xl),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Xs => "xs",
+            Self::Sm => "sm",
+            Self::Md => "md",
+            Self::Lg => "lg",
+            Self::Xl => "xl",
+        })
+    }
+}
+
+/// Minimum window width, in logical pixels, of every [Breakpoint].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Breakpoints {
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+    pub xl: f32,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            sm: 640.,
+            md: 768.,
+            lg: 1024.,
+            xl: 1280.,
+        }
+    }
+}
+
+impl Breakpoints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_sm(mut self, sm: f32) -> Self {
+        self.sm = sm;
+        self
+    }
+
+    pub fn with_md(mut self, md: f32) -> Self {
+        self.md = md;
+        self
+    }
+
+    pub fn with_lg(mut self, lg: f32) -> Self {
+        self.lg = lg;
+        self
+    }
+
+    pub fn with_xl(mut self, xl: f32) -> Self {
+        self.xl = xl;
+        self
+    }
+
+    /// Get the breakpoint of the given width in logical pixels.
+    pub fn breakpoint(&self, width: f32) -> Breakpoint {
+        if width >= self.xl {
+            Breakpoint::Xl
+        } else if width >= self.lg {
+            Breakpoint::Lg
+        } else if width >= self.md {
+            Breakpoint::Md
+        } else if width >= self.sm {
+            Breakpoint::Sm
+        } else {
+            Breakpoint::Xs
+        }
+    }
+}
+
+/// Provide custom [Breakpoints] to the [use_responsive] hooks of this component and its descendants.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     use_init_breakpoints(|| Breakpoints::new().with_md(900.));
+///
+///     rsx!(
+///         Show {
+///             above: "md",
+///             label {
+///                 "Sidebar"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_init_breakpoints(breakpoints: impl FnOnce() -> Breakpoints) -> Breakpoints {
+    use_context_provider(breakpoints)
+}
+
+/// Get the [Breakpoint] of the window width, which only notifies its subscribers when it changes,
+/// unlike the window size in [use_platform_information] that changes on every resize.
+///
+/// The width is measured in logical pixels, so it's the same in high density screens.
+/// Uses the default [Breakpoints] unless they are provided with [use_init_breakpoints].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let breakpoint = use_responsive();
+///     let columns = match breakpoint() {
+///         Breakpoint::Xs | Breakpoint::Sm => 1,
+///         Breakpoint::Md => 2,
+///         Breakpoint::Lg | Breakpoint::Xl => 3,
+///     };
+///
+///     rsx!(
+///         label {
+///             "{columns} columns"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_responsive() -> Memo<Breakpoint> {
+    let platform_information = use_platform_information();
+    let breakpoints = use_hook(|| try_consume_context::<Breakpoints>().unwrap_or_default());
+
+    use_memo(move || {
+        let information = platform_information.read();
+        let width = information.viewport_size.width / information.scale_factor as f32;
+        breakpoints.breakpoint(width)
+    })
+}
@@ -0,0 +1,54 @@
+use std::ffi::OsString;
+
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_hook,
+};
+use dioxus_hooks::use_callback;
+use freya_core::types::SecondInstanceSender;
+use tokio::sync::broadcast::error::RecvError;
+
+/// Handle the arguments of the new instances of the app, without the program name,
+/// see `LaunchConfig::single_instance`.
+///
+/// The new instances exit after forwarding them, and the window of the running one is brought to front.
+/// Use [crate::use_open_requests] for the links and files in them.
+///
+/// The arguments are not always valid UTF-8, like the names of some files.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut tabs = use_signal(|| 1);
+///
+///     use_second_instance(move |args| {
+///         if args.iter().any(|arg| arg == "--new-tab") {
+///             tabs += 1;
+///         }
+///     });
+///
+///     rsx!(label { "{tabs} tabs" })
+/// }
+/// ```
+pub fn use_second_instance(handler: impl FnMut(Vec<OsString>) + 'static) {
+    let handler = use_callback(handler);
+
+    use_hook(|| {
+        let Some(sender) = try_consume_context::<SecondInstanceSender>() else {
+            return;
+        };
+        let mut receiver = sender.subscribe();
+        spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(args) => handler.call(args),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    });
+}
@@ -0,0 +1,316 @@
+use std::fmt;
+
+use dioxus_core::prelude::{
+    current_scope_id,
+    use_drop,
+    use_hook,
+    Callback,
+    ScopeId,
+};
+use dioxus_hooks::{
+    use_context,
+    use_context_provider,
+};
+use dioxus_signals::{
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::native_menu::{
+    accelerator_matches,
+    parse_accelerator,
+};
+use freya_elements::events::KeyboardEvent;
+
+/// Where a [Shortcut] can be triggered from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ShortcutScope {
+    /// Triggered from anywhere in the app.
+    #[default]
+    Global,
+    /// Only triggered while the focused element is inside the nearest `ShortcutArea`,
+    /// or inside the `ShortcutProvider` if there is none.
+    Focused,
+}
+
+/// Key chord registered with [UseShortcuts::register].
+///
+/// The accelerator uses the same format as the items of the native menu, e.g `CmdOrCtrl+K`,
+/// where `CmdOrCtrl` means `Cmd` on macOS and `Ctrl` on the rest of platforms.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shortcut {
+    pub accelerator: String,
+    pub scope: ShortcutScope,
+    /// Shortcuts with a higher priority win when the same keys are registered more than once.
+    pub priority: i32,
+}
+
+impl Shortcut {
+    pub fn new(accelerator: impl Into<String>) -> Self {
+        Self {
+            accelerator: accelerator.into(),
+            scope: ShortcutScope::default(),
+            priority: 0,
+        }
+    }
+
+    /// Where the shortcut can be triggered from. Defaults to [ShortcutScope::Global].
+    pub fn scope(mut self, scope: ShortcutScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Priority over other shortcuts with the same keys. Defaults to `0`.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// Reason why a [Shortcut] couldn't be registered.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShortcutError {
+    /// The accelerator couldn't be parsed.
+    InvalidAccelerator(String),
+    /// Another shortcut, with the given id, uses the same keys with the same scope and priority.
+    Conflict(String),
+}
+
+impl fmt::Display for ShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidAccelerator(accelerator) => {
+                write!(f, "Invalid accelerator '{accelerator}'")
+            }
+            Self::Conflict(id) => write!(f, "Keys already used by the shortcut '{id}'"),
+        }
+    }
+}
+
+impl std::error::Error for ShortcutError {}
+
+/// Identifies a `ShortcutArea`, the subtree where [ShortcutScope::Focused] shortcuts are triggered from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShortcutAreaId(usize);
+
+#[derive(Clone)]
+struct RegisteredShortcut {
+    id: String,
+    shortcut: Shortcut,
+    area: ShortcutAreaId,
+    owner: ScopeId,
+    handler: Callback<KeyboardEvent>,
+}
+
+impl RegisteredShortcut {
+    fn conflicts_with(&self, id: &str, shortcut: &Shortcut, area: ShortcutAreaId) -> bool {
+        self.id != id
+            && self.shortcut.scope == shortcut.scope
+            && (shortcut.scope == ShortcutScope::Global || self.area == area)
+            && self.shortcut.priority == shortcut.priority
+            && parse_accelerator(&self.shortcut.accelerator)
+                == parse_accelerator(&shortcut.accelerator)
+    }
+}
+
+/// Shortcuts registered in a `ShortcutProvider`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ShortcutRegistry {
+    shortcuts: Signal<Vec<RegisteredShortcut>>,
+    /// Whether the last key press was already handled by a focused shortcut.
+    consumed: Signal<bool>,
+    areas: Signal<usize>,
+}
+
+impl ShortcutRegistry {
+    fn register(
+        &mut self,
+        id: String,
+        shortcut: Shortcut,
+        area: ShortcutAreaId,
+        owner: ScopeId,
+        handler: Callback<KeyboardEvent>,
+    ) -> Result<(), ShortcutError> {
+        if parse_accelerator(&shortcut.accelerator).is_none() {
+            return Err(ShortcutError::InvalidAccelerator(shortcut.accelerator));
+        }
+        if let Some(other) = self
+            .shortcuts
+            .peek()
+            .iter()
+            .find(|other| other.conflicts_with(&id, &shortcut, area))
+        {
+            return Err(ShortcutError::Conflict(other.id.clone()));
+        }
+
+        let mut shortcuts = self.shortcuts.write();
+        shortcuts.retain(|registered| registered.id != id);
+        shortcuts.push(RegisteredShortcut {
+            id,
+            shortcut,
+            area,
+            owner,
+            handler,
+        });
+        Ok(())
+    }
+
+    fn unregister(&mut self, id: &str) {
+        self.shortcuts
+            .write()
+            .retain(|registered| registered.id != id);
+    }
+
+    fn unregister_owner(&mut self, owner: ScopeId) {
+        self.shortcuts
+            .write()
+            .retain(|registered| registered.owner != owner);
+    }
+
+    /// Create the id of a new `ShortcutArea`.
+    pub fn new_area(&mut self) -> ShortcutAreaId {
+        let mut areas = self.areas.write();
+        *areas += 1;
+        ShortcutAreaId(*areas)
+    }
+
+    /// Get the handler of the matching shortcut with the highest priority.
+    fn find(
+        &self,
+        scope: ShortcutScope,
+        area: ShortcutAreaId,
+        e: &KeyboardEvent,
+    ) -> Option<Callback<KeyboardEvent>> {
+        self.shortcuts
+            .peek()
+            .iter()
+            .filter(|registered| {
+                registered.shortcut.scope == scope
+                    && (scope == ShortcutScope::Global || registered.area == area)
+                    && accelerator_matches(&registered.shortcut.accelerator, &e.key, e.modifiers)
+            })
+            .max_by_key(|registered| registered.shortcut.priority)
+            .map(|registered| registered.handler)
+    }
+
+    /// Trigger the [ShortcutScope::Focused] shortcuts of the given area. Meant for `onkeydown`.
+    pub fn handle_focused(&mut self, area: ShortcutAreaId, e: KeyboardEvent) {
+        if let Some(handler) = self.find(ShortcutScope::Focused, area, &e) {
+            e.stop_propagation();
+            self.consumed.set(true);
+            handler.call(e);
+        }
+    }
+
+    /// Trigger the [ShortcutScope::Global] shortcuts,
+    /// unless the key press was already handled by a focused shortcut. Meant for `onglobalkeydown`.
+    pub fn handle_global(&mut self, e: KeyboardEvent) {
+        if self.consumed.replace(false) {
+            return;
+        }
+        if let Some(handler) = self.find(ShortcutScope::Global, ShortcutAreaId(0), &e) {
+            handler.call(e);
+        }
+    }
+}
+
+/// Create the [ShortcutRegistry] used by [use_shortcuts]. Usually used through the `ShortcutProvider` component.
+pub fn use_init_shortcuts() -> (ShortcutRegistry, ShortcutAreaId) {
+    let registry = use_context_provider(|| ShortcutRegistry {
+        shortcuts: Signal::new(Vec::new()),
+        consumed: Signal::new(false),
+        areas: Signal::new(0),
+    });
+    let area = use_context_provider(|| ShortcutAreaId(0));
+    (registry, area)
+}
+
+/// Create a new area for [ShortcutScope::Focused] shortcuts. Usually used through the `ShortcutArea` component.
+pub fn use_shortcut_area() -> (ShortcutRegistry, ShortcutAreaId) {
+    let mut registry = use_context::<ShortcutRegistry>();
+    let area = use_context_provider(|| registry.new_area());
+    (registry, area)
+}
+
+/// Register shortcuts, see [use_shortcuts].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseShortcuts {
+    registry: ShortcutRegistry,
+    area: ShortcutAreaId,
+    owner: ScopeId,
+}
+
+impl UseShortcuts {
+    /// Register a shortcut, replacing the one with the same id.
+    ///
+    /// Fails if the accelerator is invalid or if another shortcut uses the same keys
+    /// in the same scope and with the same priority.
+    pub fn register(
+        &self,
+        id: impl Into<String>,
+        shortcut: Shortcut,
+        handler: impl FnMut(KeyboardEvent) + 'static,
+    ) -> Result<(), ShortcutError> {
+        let mut registry = self.registry;
+        registry.register(
+            id.into(),
+            shortcut,
+            self.area,
+            self.owner,
+            Callback::new(handler),
+        )
+    }
+
+    /// Unregister the shortcut with the given id.
+    pub fn unregister(&self, id: &str) {
+        let mut registry = self.registry;
+        registry.unregister(id);
+    }
+}
+
+/// Register keyboard shortcuts without matching keys manually in `onglobalkeydown`.
+///
+/// The shortcuts registered by a component are unregistered when it is dropped.
+/// It needs a `ShortcutProvider` higher in the tree.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         ShortcutProvider {
+///             CommandPalette { }
+///         }
+///     )
+/// }
+///
+/// #[component]
+/// fn CommandPalette() -> Element {
+///     let shortcuts = use_shortcuts();
+///     let mut open = use_signal(|| false);
+///
+///     use_hook(move || {
+///         shortcuts
+///             .register("palette", Shortcut::new("CmdOrCtrl+Shift+P"), move |_| {
+///                 open.toggle()
+///             })
+///             .ok();
+///     });
+///
+///     rsx!(label { "Open: {open}" })
+/// }
+/// ```
+pub fn use_shortcuts() -> UseShortcuts {
+    let mut registry = use_context::<ShortcutRegistry>();
+    let area = use_context::<ShortcutAreaId>();
+    let owner = use_hook(|| current_scope_id().unwrap());
+
+    use_drop(move || registry.unregister_owner(owner));
+
+    UseShortcuts {
+        registry,
+        area,
+        owner,
+    }
+}
@@ -0,0 +1,88 @@
+use dioxus_hooks::use_context;
+use dioxus_signals::{
+    ReadOnlySignal,
+    Readable,
+    Signal,
+};
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    platform_state::SoftKeyboard,
+};
+
+use crate::{
+    use_platform,
+    UsePlatform,
+};
+
+/// Manage the on-screen keyboard, see [use_soft_keyboard].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseSoftKeyboard {
+    soft_keyboard: Signal<SoftKeyboard>,
+    platform: UsePlatform,
+}
+
+impl UseSoftKeyboard {
+    /// Height of the bottom of the window covered by the keyboard, in logical pixels.
+    /// It's `0.0` when the keyboard is hidden.
+    pub fn inset(&self) -> f32 {
+        self.soft_keyboard.read().visible_inset()
+    }
+
+    /// Check whether the keyboard is shown.
+    pub fn is_visible(&self) -> bool {
+        self.soft_keyboard.read().visible
+    }
+
+    /// Get the [SoftKeyboard] state.
+    pub fn state(&self) -> ReadOnlySignal<SoftKeyboard> {
+        self.soft_keyboard.into()
+    }
+
+    /// Show the keyboard. Only touch platforms (Android and iOS) have one.
+    pub fn show(&self) {
+        self.platform
+            .send(EventLoopMessage::SetSoftKeyboardVisible(true))
+            .ok();
+    }
+
+    /// Hide the keyboard. Only touch platforms (Android and iOS) have one.
+    pub fn hide(&self) {
+        self.platform
+            .send(EventLoopMessage::SetSoftKeyboardVisible(false))
+            .ok();
+    }
+}
+
+/// Access the on-screen keyboard of touch platforms.
+///
+/// Use [UseSoftKeyboard::inset] to leave space for the keyboard at the bottom of the window.
+/// `ScrollView` already scrolls to keep the focused element above it.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let soft_keyboard = use_soft_keyboard();
+///     let mut value = use_signal(String::new);
+///
+///     rsx!(
+///         rect {
+///             height: "fill",
+///             Input {
+///                 value,
+///                 onchange: move |txt| value.set(txt),
+///             }
+///         }
+///         rect {
+///             height: "{soft_keyboard.inset()}",
+///         }
+///     )
+/// }
+/// ```
+pub fn use_soft_keyboard() -> UseSoftKeyboard {
+    UseSoftKeyboard {
+        soft_keyboard: use_context(),
+        platform: use_platform(),
+    }
+}
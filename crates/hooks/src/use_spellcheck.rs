@@ -0,0 +1,310 @@
+use std::{
+    collections::HashSet,
+    rc::Rc,
+};
+
+use dioxus_core::AttributeValue;
+use dioxus_hooks::{
+    use_memo,
+    use_signal,
+};
+use dioxus_signals::{
+    Memo,
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::custom_attributes::CustomAttributeValues;
+
+use crate::{
+    TextEditor,
+    UseEditable,
+};
+
+/// Provider of the spelling of words, see [use_spellcheck].
+pub trait SpellChecker {
+    /// Whether the word is spelled correctly.
+    fn is_correct(&self, word: &str) -> bool;
+
+    /// Corrections for a misspelled word, the most likely first.
+    fn suggestions(&self, word: &str) -> Vec<String>;
+}
+
+/// [SpellChecker] that only accepts the words of a list, ignoring the case.
+///
+/// Suggests the words of the list that are one or two edits away from the misspelled one.
+pub struct WordListSpellChecker {
+    words: HashSet<String>,
+}
+
+impl WordListSpellChecker {
+    /// Maximum number of suggestions for a word.
+    const MAX_SUGGESTIONS: usize = 5;
+
+    pub fn new(words: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        Self {
+            words: words
+                .into_iter()
+                .map(|word| word.as_ref().to_lowercase())
+                .collect(),
+        }
+    }
+}
+
+impl SpellChecker for WordListSpellChecker {
+    fn is_correct(&self, word: &str) -> bool {
+        self.words.contains(&word.to_lowercase())
+    }
+
+    fn suggestions(&self, word: &str) -> Vec<String> {
+        let lowercase = word.to_lowercase();
+        let mut suggestions = self
+            .words
+            .iter()
+            .map(|candidate| (edit_distance(&lowercase, candidate), candidate))
+            .filter(|(distance, _)| *distance <= 2)
+            .collect::<Vec<_>>();
+        suggestions.sort();
+
+        // Keep the capitalization of the misspelled word
+        let capitalized = word.chars().next().is_some_and(char::is_uppercase);
+        suggestions
+            .into_iter()
+            .take(Self::MAX_SUGGESTIONS)
+            .map(|(_, candidate)| {
+                if capitalized {
+                    let mut chars = candidate.chars();
+                    chars
+                        .next()
+                        .map(|first| first.to_uppercase().chain(chars).collect())
+                        .unwrap_or_default()
+                } else {
+                    candidate.clone()
+                }
+            })
+            .collect()
+    }
+}
+
+/// Levenshtein distance between two words.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Words of a text along with their ranges in UTF-16 code units.
+///
+/// Apostrophes are part of the words, e.g `don't`, and words with digits are ignored.
+fn words(text: &str) -> Vec<(String, (usize, usize))> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut start = 0;
+    let mut utf16_idx = 0;
+
+    let mut push_word = |word: &mut String, start: usize| {
+        let trimmed = word.trim_end_matches('\'');
+        if !trimmed.is_empty() && !trimmed.chars().any(|ch| ch.is_numeric()) {
+            let len = trimmed.chars().map(char::len_utf16).sum::<usize>();
+            words.push((trimmed.to_string(), (start, start + len)));
+        }
+        word.clear();
+    };
+
+    for ch in text.chars() {
+        if ch.is_alphanumeric() || (ch == '\'' && !word.is_empty()) {
+            if word.is_empty() {
+                start = utf16_idx;
+            }
+            word.push(ch);
+        } else if !word.is_empty() {
+            push_word(&mut word, start);
+        }
+        utf16_idx += ch.len_utf16();
+    }
+    push_word(&mut word, start);
+
+    words
+}
+
+/// A misspelled word found by [use_spellcheck].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpellingError {
+    /// The misspelled word.
+    pub word: String,
+    /// Range of the word in UTF-16 code units.
+    pub range: (usize, usize),
+}
+
+/// Check the spelling of the text in a [UseEditable], see [use_spellcheck].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseSpellcheck {
+    editable: UseEditable,
+    checker: Signal<Rc<dyn SpellChecker>>,
+    enabled: Signal<bool>,
+    errors: Memo<Vec<SpellingError>>,
+}
+
+impl UseSpellcheck {
+    /// Misspelled words of the text.
+    pub fn errors(&self) -> Vec<SpellingError> {
+        self.errors.read().clone()
+    }
+
+    /// Misspelled word in the given position, in UTF-16 code units.
+    pub fn error_at(&self, position: usize) -> Option<SpellingError> {
+        self.errors
+            .read()
+            .iter()
+            .find(|error| (error.range.0..=error.range.1).contains(&position))
+            .cloned()
+    }
+
+    /// Misspelled word in the position of the cursor, e.g. to show corrections after a right click.
+    pub fn error_at_cursor(&self) -> Option<SpellingError> {
+        let cursor = self.editable.editor().read().cursor_pos();
+        self.error_at(cursor)
+    }
+
+    /// Corrections for a misspelled word, the most likely first.
+    pub fn suggestions(&self, error: &SpellingError) -> Vec<String> {
+        self.checker.read().suggestions(&error.word)
+    }
+
+    /// Replace a misspelled word with a correction. It can be undone.
+    pub fn correct(&mut self, error: &SpellingError, correction: &str) {
+        // Ignore errors from a previous version of the text
+        if !self.errors.peek().contains(error) {
+            return;
+        }
+        self.editable
+            .editor_mut()
+            .write()
+            .replace_ranges(&[error.range], correction);
+    }
+
+    /// Whether the text is being checked.
+    pub fn enabled(&self) -> bool {
+        *self.enabled.read()
+    }
+
+    /// Start or stop checking the text, it is enabled by default.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled.set(enabled);
+    }
+
+    /// Replace the [SpellChecker], e.g. to check another language.
+    pub fn set_checker(&mut self, checker: impl SpellChecker + 'static) {
+        self.checker.set(Rc::new(checker));
+    }
+
+    /// Create a spelling_errors attribute, with the misspelled words visible from the given editor Id.
+    pub fn errors_attr(&self, editor_id: usize) -> AttributeValue {
+        let ranges = self
+            .errors
+            .read()
+            .iter()
+            .map(|error| error.range)
+            .collect::<Vec<_>>();
+        AttributeValue::any_value(CustomAttributeValues::TextHighlights(
+            self.editable
+                .editor()
+                .read()
+                .get_visible_ranges(&ranges, editor_id),
+        ))
+    }
+}
+
+/// Check the spelling of the text in a [UseEditable] with a [SpellChecker] of your choice.
+///
+/// The misspelled words are underlined by passing [UseSpellcheck::errors_attr] to the `spelling_errors` attribute
+/// of the paragraphs, and the color can be changed with `spelling_error_color`.
+/// A correction menu can be built with [UseSpellcheck::error_at_cursor] and [UseSpellcheck::suggestions],
+/// as the cursor is moved to the clicked word with any mouse button.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut editable = use_editable(
+///         || EditableConfig::new("Helo World".to_string()),
+///         EditableMode::MultipleLinesSingleEditor,
+///     );
+///     let mut spellcheck = use_spellcheck(editable, || {
+///         WordListSpellChecker::new(["hello", "world"])
+///     });
+///     let mut show_corrections = use_signal(|| false);
+///
+///     let cursor_reference = editable.cursor_attr();
+///     let spelling_errors = spellcheck.errors_attr(0);
+///     let cursor_char = editable.editor().read().cursor_pos();
+///     let error = spellcheck.error_at_cursor().filter(|_| show_corrections());
+///
+///     rsx!(
+///         paragraph {
+///             cursor_id: "0",
+///             cursor_index: "{cursor_char}",
+///             cursor_mode: "editable",
+///             cursor_reference,
+///             spelling_errors,
+///             onmousedown: move |e| {
+///                 editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+///             },
+///             onrightclick: move |_| show_corrections.set(true),
+///             text {
+///                 "{editable.editor()}"
+///             }
+///         }
+///         if let Some(error) = error {
+///             for suggestion in spellcheck.suggestions(&error) {
+///                 Button {
+///                     onpress: {
+///                         let error = error.clone();
+///                         let suggestion = suggestion.clone();
+///                         move |_| {
+///                             spellcheck.correct(&error, &suggestion);
+///                             show_corrections.set(false);
+///                         }
+///                     },
+///                     label { "{suggestion}" }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_spellcheck<C: SpellChecker + 'static>(
+    editable: UseEditable,
+    init: impl FnOnce() -> C,
+) -> UseSpellcheck {
+    let checker = use_signal(|| Rc::new(init()) as Rc<dyn SpellChecker>);
+    let enabled = use_signal(|| true);
+    let errors = use_memo(move || {
+        if !*enabled.read() {
+            return Vec::new();
+        }
+        let checker = checker.read();
+        let text = editable.editor().read().to_string();
+        words(&text)
+            .into_iter()
+            .filter(|(word, _)| !checker.is_correct(word))
+            .map(|(word, range)| SpellingError { word, range })
+            .collect()
+    });
+
+    UseSpellcheck {
+        editable,
+        checker,
+        enabled,
+        errors,
+    }
+}
@@ -0,0 +1,120 @@
+use dioxus_hooks::{
+    use_context,
+    use_context_provider,
+    use_memo,
+};
+use dioxus_signals::{
+    Memo,
+    ReadOnlySignal,
+    Readable,
+    Signal,
+    Writable,
+    WritableRef,
+};
+
+/// App state shared with the components below the one that created it, see [create_store].
+///
+/// Components should read it through [use_store_selector], so they only re-render when the part they use changes.
+pub struct Store<T: 'static> {
+    state: Signal<T>,
+}
+
+impl<T: 'static> Clone for Store<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for Store<T> {}
+
+impl<T: 'static> PartialEq for Store<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<T: 'static> Store<T> {
+    /// Get a readable of the whole state, which notifies its subscribers on every change.
+    pub fn state(&self) -> ReadOnlySignal<T> {
+        self.state.into()
+    }
+
+    /// Get mutable access to the state, notifying the selectors once it's dropped.
+    pub fn write(&mut self) -> WritableRef<'static, Signal<T>> {
+        self.state.write()
+    }
+
+    /// Change the state with the given callback.
+    pub fn update(&mut self, update: impl FnOnce(&mut T)) {
+        update(&mut self.state.write());
+    }
+
+    /// Replace the state.
+    pub fn set(&mut self, state: T) {
+        self.state.set(state);
+    }
+
+    /// Run a callback with the current state without subscribing to it.
+    pub fn with_peek<R>(&self, callback: impl FnOnce(&T) -> R) -> R {
+        callback(&self.state.peek())
+    }
+}
+
+/// Create a [Store] and provide it to this component and its descendants.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// #[derive(Default)]
+/// struct AppState {
+///     user: String,
+///     unread_messages: usize,
+/// }
+///
+/// fn app() -> Element {
+///     let mut store = create_store(AppState::default);
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| store.update(|state| state.unread_messages += 1),
+///             label {
+///                 "New message"
+///             }
+///         }
+///         Badge {}
+///     )
+/// }
+///
+/// #[component]
+/// fn Badge() -> Element {
+///     // Only re-renders when the amount of messages changes, not the user
+///     let unread_messages = use_store_selector(|state: &AppState| state.unread_messages);
+///
+///     rsx!(
+///         label {
+///             "{unread_messages} unread"
+///         }
+///     )
+/// }
+/// ```
+pub fn create_store<T: 'static>(init: impl FnOnce() -> T) -> Store<T> {
+    use_context_provider(|| Store {
+        state: Signal::new(init()),
+    })
+}
+
+/// Get the [Store] of the given type created by an ancestor component.
+pub fn use_store<T: 'static>() -> Store<T> {
+    use_context()
+}
+
+/// Select a part of the [Store] of the given type, only notifying the subscribers when the selected value changes.
+///
+/// The selector runs again on every change of the store, so keep it cheap, e.g. clone an `Rc` rather than a `Vec`.
+pub fn use_store_selector<T: 'static, R: PartialEq + 'static>(
+    selector: impl Fn(&T) -> R + 'static,
+) -> Memo<R> {
+    let store = use_store::<T>();
+    use_memo(move || selector(&store.state.read()))
+}
@@ -0,0 +1,416 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::Display,
+    ops::Range,
+    rc::Rc,
+};
+
+use dioxus_core::use_hook;
+use dioxus_hooks::use_memo;
+use dioxus_signals::{
+    Memo,
+    Readable,
+};
+use tree_sitter::{
+    InputEdit,
+    Language,
+    LanguageError,
+    Node,
+    Parser,
+    Point,
+    Query,
+    QueryCursor,
+    QueryError,
+    Tree,
+};
+
+use crate::{
+    RichText,
+    Rope,
+    SpanStyle,
+    TextEdit,
+    TextEdits,
+    TextSpan,
+    TextSpans,
+    UseEditable,
+};
+
+/// Styles of the syntax highlighting captures, e.g `keyword` or `string`.
+///
+/// Captures with no style use the style of their parent capture if any,
+/// e.g `function.method` uses the style of `function`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyntaxTheme {
+    styles: HashMap<String, SpanStyle>,
+}
+
+impl SyntaxTheme {
+    /// Create a theme with no styles.
+    pub fn empty() -> Self {
+        Self {
+            styles: HashMap::new(),
+        }
+    }
+
+    /// Specify the style of a capture.
+    pub fn with_style(mut self, capture: impl Into<String>, style: SpanStyle) -> Self {
+        self.styles.insert(capture.into(), style);
+        self
+    }
+
+    /// Specify the color of a capture.
+    pub fn with_color(self, capture: impl Into<String>, color: impl Into<String>) -> Self {
+        self.with_style(
+            capture,
+            SpanStyle {
+                color: Some(color.into()),
+                ..SpanStyle::default()
+            },
+        )
+    }
+
+    /// Get the style of a capture.
+    pub fn style(&self, capture: &str) -> Option<&SpanStyle> {
+        let mut capture = capture;
+        loop {
+            if let Some(style) = self.styles.get(capture) {
+                return Some(style);
+            }
+            capture = &capture[..capture.rfind('.')?];
+        }
+    }
+}
+
+impl Default for SyntaxTheme {
+    fn default() -> Self {
+        Self::empty()
+            .with_color("attribute", "rgb(220, 220, 170)")
+            .with_style(
+                "comment",
+                SpanStyle {
+                    italic: true,
+                    color: Some("rgb(106, 153, 85)".to_string()),
+                    ..SpanStyle::default()
+                },
+            )
+            .with_color("constant", "rgb(79, 193, 255)")
+            .with_color("constructor", "rgb(78, 201, 176)")
+            .with_color("function", "rgb(220, 220, 170)")
+            .with_color("keyword", "rgb(197, 134, 192)")
+            .with_color("label", "rgb(86, 156, 214)")
+            .with_color("number", "rgb(181, 206, 168)")
+            .with_color("operator", "rgb(212, 212, 212)")
+            .with_color("property", "rgb(156, 220, 254)")
+            .with_color("punctuation", "rgb(212, 212, 212)")
+            .with_color("string", "rgb(206, 145, 120)")
+            .with_color("tag", "rgb(86, 156, 214)")
+            .with_color("type", "rgb(78, 201, 176)")
+            .with_color("variable", "rgb(156, 220, 254)")
+    }
+}
+
+/// Error creating a [SyntaxLanguage].
+#[derive(Debug)]
+pub enum SyntaxLanguageError {
+    /// The language is not compatible with the version of tree-sitter.
+    Language(LanguageError),
+    /// The highlights query is not valid.
+    Query(QueryError),
+}
+
+impl Display for SyntaxLanguageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Language(err) => err.fmt(f),
+            Self::Query(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SyntaxLanguageError {}
+
+/// A tree-sitter language along with its highlights query, see [use_syntax_highlighting].
+pub struct SyntaxLanguage {
+    parser: Parser,
+    query: Query,
+    theme: SyntaxTheme,
+}
+
+impl SyntaxLanguage {
+    /// Create a language from a tree-sitter grammar and its highlights query, e.g
+    /// `SyntaxLanguage::new(tree_sitter_rust::LANGUAGE, tree_sitter_rust::HIGHLIGHTS_QUERY)`.
+    pub fn new(
+        language: impl Into<Language>,
+        highlights_query: &str,
+    ) -> Result<Self, SyntaxLanguageError> {
+        let language = language.into();
+        let mut parser = Parser::new();
+        parser
+            .set_language(&language)
+            .map_err(SyntaxLanguageError::Language)?;
+        let query = Query::new(&language, highlights_query).map_err(SyntaxLanguageError::Query)?;
+
+        Ok(Self {
+            parser,
+            query,
+            theme: SyntaxTheme::default(),
+        })
+    }
+
+    /// Specify a custom theme.
+    pub fn with_theme(mut self, theme: SyntaxTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Highlight a whole text at once, useful for texts that are not edited such as code snippets.
+    /// Use [use_syntax_highlighting] to keep the highlights of an editable text updated.
+    pub fn highlight(self, text: &str) -> SyntaxHighlights {
+        let mut highlighter = SyntaxHighlighter::new(self);
+        highlighter.update(&Rope::from_str(text), &TextEdits::default());
+        highlighter.highlights()
+    }
+}
+
+/// Highlighted lines of a text, see [use_syntax_highlighting].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SyntaxHighlights {
+    lines: Vec<Rc<RichText>>,
+}
+
+impl SyntaxHighlights {
+    /// Get the highlighted lines, without their line breaks.
+    pub fn lines(&self) -> &[Rc<RichText>] {
+        &self.lines
+    }
+
+    /// Get a highlighted line, without its line break.
+    pub fn line(&self, line_idx: usize) -> Option<&RichText> {
+        self.lines.get(line_idx).map(Rc::as_ref)
+    }
+}
+
+/// Highlights a text incrementally, only the lines affected by every edit are highlighted again.
+struct SyntaxHighlighter {
+    language: SyntaxLanguage,
+    /// Style of every capture of the query.
+    capture_styles: Vec<Option<SpanStyle>>,
+    tree: Option<Tree>,
+    rope: Rope,
+    /// Version of the edits of the highlighted text.
+    version: u64,
+    /// Highlighted lines, `None` if they need to be highlighted again.
+    lines: Vec<Option<Rc<RichText>>>,
+}
+
+impl SyntaxHighlighter {
+    fn new(language: SyntaxLanguage) -> Self {
+        let capture_styles = language
+            .query
+            .capture_names()
+            .iter()
+            .map(|name| language.theme.style(name).cloned())
+            .collect();
+
+        Self {
+            language,
+            capture_styles,
+            tree: None,
+            rope: Rope::new(),
+            version: 0,
+            lines: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, rope: &Rope, edits: &TextEdits) {
+        if self.tree.is_some() && self.version == edits.version() {
+            return;
+        }
+
+        let old_tree = match (self.tree.take(), edits.since(self.version)) {
+            (Some(mut tree), Some(edits)) => {
+                for edit in edits {
+                    tree.edit(&input_edit(edit));
+
+                    // The lines after the edited ones are kept, just moved
+                    let (start_row, _) = edit.start_position;
+                    let edited_rows = edit.new_end_position.0 - start_row + 1;
+                    self.lines.splice(
+                        start_row..=edit.old_end_position.0.min(self.lines.len() - 1),
+                        vec![None; edited_rows],
+                    );
+                }
+                Some(tree)
+            }
+            // Unknown edits, highlight everything again
+            _ => {
+                self.lines = vec![None; rope.len_lines()];
+                None
+            }
+        };
+
+        self.version = edits.version();
+        self.rope = rope.clone();
+        self.tree = self.language.parser.parse_with(
+            &mut |byte, _| {
+                if byte <= rope.len_bytes() {
+                    let (chunk, chunk_byte, _, _) = rope.chunk_at_byte(byte);
+                    &chunk.as_bytes()[byte - chunk_byte..]
+                } else {
+                    &[]
+                }
+            },
+            old_tree.as_ref(),
+        );
+
+        // Lines whose syntax changed, e.g. after opening a comment
+        if let Some((old_tree, tree)) = old_tree.as_ref().zip(self.tree.as_ref()) {
+            for range in old_tree.changed_ranges(tree) {
+                for row in range.start_point.row..=range.end_point.row {
+                    if let Some(line) = self.lines.get_mut(row) {
+                        *line = None;
+                    }
+                }
+            }
+        }
+
+        for row in 0..self.lines.len() {
+            if self.lines[row].is_none() {
+                self.lines[row] = Some(Rc::new(self.highlight_line(row)));
+            }
+        }
+    }
+
+    /// Get the byte range of a line, without its line break.
+    fn line_range(&self, row: usize) -> Range<usize> {
+        let start = self.rope.line_to_byte(row);
+        let mut end = self.rope.line_to_byte(row + 1);
+        while end > start && matches!(self.rope.byte(end - 1), b'\n' | b'\r') {
+            end -= 1;
+        }
+        start..end
+    }
+
+    fn highlight_line(&self, row: usize) -> RichText {
+        let range = self.line_range(row);
+        let line = self.rope.byte_slice(range.clone()).to_string();
+
+        // Capture of every byte of the line, along with the length of its node
+        let mut captures = vec![None::<(usize, usize)>; line.len()];
+        if let Some(tree) = &self.tree {
+            let mut cursor = QueryCursor::new();
+            cursor.set_byte_range(range.clone());
+            let text = |node: Node| {
+                self.rope
+                    .byte_slice(node.byte_range())
+                    .chunks()
+                    .map(str::as_bytes)
+            };
+            let matches = cursor.captures(&self.language.query, tree.root_node(), text);
+            for (query_match, capture_idx) in matches {
+                let capture = query_match.captures[capture_idx];
+                let node_range = capture.node.byte_range();
+                let node_len = node_range.len();
+                let start = node_range.start.max(range.start) - range.start;
+                let end = node_range.end.min(range.end).saturating_sub(range.start);
+                for byte_capture in captures.iter_mut().take(end).skip(start) {
+                    // Inner nodes take precedence, the first capture wins for the same node
+                    let is_inner = match byte_capture {
+                        Some((len, _)) => node_len < *len,
+                        None => true,
+                    };
+                    if is_inner {
+                        *byte_capture = Some((node_len, capture.index as usize));
+                    }
+                }
+            }
+        }
+
+        let spans = TextSpans::from_spans(
+            line.char_indices()
+                .map(|(idx, ch)| {
+                    let style = captures[idx]
+                        .and_then(|(_, capture)| self.capture_styles[capture].clone())
+                        .unwrap_or_default();
+                    TextSpan::new(ch.len_utf16(), style)
+                })
+                .collect(),
+        );
+
+        RichText { text: line, spans }
+    }
+
+    fn highlights(&self) -> SyntaxHighlights {
+        SyntaxHighlights {
+            lines: self.lines.iter().flatten().cloned().collect(),
+        }
+    }
+}
+
+fn input_edit(edit: &TextEdit) -> InputEdit {
+    let point = |(row, column)| Point::new(row, column);
+    InputEdit {
+        start_byte: edit.start_byte,
+        old_end_byte: edit.old_end_byte,
+        new_end_byte: edit.new_end_byte,
+        start_position: point(edit.start_position),
+        old_end_position: point(edit.old_end_position),
+        new_end_position: point(edit.new_end_position),
+    }
+}
+
+/// Highlight the syntax of an editable text with [tree-sitter](https://tree-sitter.github.io/tree-sitter/).
+///
+/// The text is parsed incrementally and only the lines affected by every change are highlighted again,
+/// so the highlights are cheap to keep updated in code editors. Every line is a [RichText] to be rendered
+/// with a `text` element per segment.
+///
+/// Requires the `use_syntax_highlighting` feature.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut editable = use_editable(
+///         || EditableConfig::new("fn main() {}".to_string()),
+///         EditableMode::SingleLineMultipleEditors,
+///     );
+///     let highlights = use_syntax_highlighting(
+///         || {
+///             SyntaxLanguage::new(tree_sitter_rust::LANGUAGE, tree_sitter_rust::HIGHLIGHTS_QUERY)
+///                 .unwrap()
+///         },
+///         editable,
+///     );
+///
+///     rsx!(
+///         for (line_idx, line) in highlights.read().lines().iter().enumerate() {
+///             paragraph {
+///                 key: "{line_idx}",
+///                 for (segment, style) in line.segments() {
+///                     text {
+///                         color: style.color.as_deref().unwrap_or("white"),
+///                         font_style: style.font_style(),
+///                         "{segment}"
+///                     }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_syntax_highlighting(
+    language: impl FnOnce() -> SyntaxLanguage,
+    editable: UseEditable,
+) -> Memo<SyntaxHighlights> {
+    let highlighter = use_hook(|| Rc::new(RefCell::new(SyntaxHighlighter::new(language()))));
+
+    use_memo(move || {
+        let editor = editable.editor().read();
+        let mut highlighter = highlighter.borrow_mut();
+        highlighter.update(editor.rope(), editor.edits());
+        highlighter.highlights()
+    })
+}
@@ -0,0 +1,212 @@
+use dioxus_core::AttributeValue;
+use dioxus_hooks::{
+    use_memo,
+    use_signal,
+};
+use dioxus_signals::{
+    Memo,
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::custom_attributes::CustomAttributeValues;
+
+use crate::{
+    TextEditor,
+    UseEditable,
+};
+
+/// Find and replace text in a [UseEditable], see [use_text_search].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseTextSearch {
+    editable: UseEditable,
+    query: Signal<String>,
+    case_sensitive: Signal<bool>,
+    matches: Memo<Vec<(usize, usize)>>,
+}
+
+impl UseTextSearch {
+    /// Text being searched.
+    pub fn query(&self) -> String {
+        self.query.read().clone()
+    }
+
+    /// Change the text being searched.
+    pub fn set_query(&mut self, query: impl Into<String>) {
+        self.query.set(query.into());
+    }
+
+    /// Whether the search distinguishes uppercase and lowercase letters.
+    pub fn case_sensitive(&self) -> bool {
+        *self.case_sensitive.read()
+    }
+
+    /// Make the search distinguish uppercase and lowercase letters or not, it doesn't by default.
+    pub fn set_case_sensitive(&mut self, case_sensitive: bool) {
+        self.case_sensitive.set(case_sensitive);
+    }
+
+    /// Ranges of the matches in UTF-16 code units.
+    pub fn matches(&self) -> Vec<(usize, usize)> {
+        self.matches.read().clone()
+    }
+
+    /// Index of the match that is selected in the editor, if any.
+    pub fn current(&self) -> Option<usize> {
+        let selection = self.editable.editor().read().get_selection_range()?;
+        self.matches.read().iter().position(|m| *m == selection)
+    }
+
+    /// Create a match_highlights attribute, with the matches visible from the given editor Id.
+    pub fn matches_attr(&self, editor_id: usize) -> AttributeValue {
+        let matches = self.matches.read();
+        AttributeValue::any_value(CustomAttributeValues::TextHighlights(
+            self.editable
+                .editor()
+                .read()
+                .get_visible_ranges(&matches, editor_id),
+        ))
+    }
+
+    /// Select the next match after the cursor, going back to the first one after the last.
+    pub fn next(&mut self) {
+        let matches = self.find();
+        let cursor = self.editable.editor().peek().cursor_pos();
+        let next = matches
+            .iter()
+            .find(|(from, _)| *from >= cursor)
+            .or(matches.first());
+        if let Some(next) = next {
+            self.select(*next);
+        }
+    }
+
+    /// Select the previous match before the cursor or selection, going back to the last one before the first.
+    pub fn previous(&mut self) {
+        let matches = self.find();
+        let editor = self.editable.editor().peek();
+        let start = editor
+            .get_selection_range()
+            .map(|(from, _)| from)
+            .unwrap_or(editor.cursor_pos());
+        drop(editor);
+        let previous = matches
+            .iter()
+            .rev()
+            .find(|(from, _)| *from < start)
+            .or(matches.last());
+        if let Some(previous) = previous {
+            self.select(*previous);
+        }
+    }
+
+    /// Replace the selected match and select the next one.
+    /// If no match is selected the next one is selected instead.
+    pub fn replace(&mut self, text: &str) {
+        let selection = self.editable.editor().peek().get_selection_range();
+        if let Some(selection) = selection.filter(|selection| self.find().contains(selection)) {
+            self.editable
+                .editor_mut()
+                .write()
+                .replace_ranges(&[selection], text);
+        }
+        self.next();
+    }
+
+    /// Replace every match, returning how many were replaced.
+    /// It is undone as a single change.
+    pub fn replace_all(&mut self, text: &str) -> usize {
+        let matches = self.find();
+        if !matches.is_empty() {
+            self.editable
+                .editor_mut()
+                .write()
+                .replace_ranges(&matches, text);
+        }
+        matches.len()
+    }
+
+    /// Search in the current text, without waiting for the matches to be updated.
+    fn find(&self) -> Vec<(usize, usize)> {
+        self.editable
+            .editor()
+            .peek()
+            .find(&self.query.peek(), *self.case_sensitive.peek())
+    }
+
+    fn select(&mut self, (from, to): (usize, usize)) {
+        let mut editor = self.editable.editor_mut().write();
+        editor.clear_carets();
+        editor.set_selection((from, to));
+        editor.set_cursor_pos(to);
+    }
+}
+
+/// Search the text of a [UseEditable] to build a find and replace bar.
+///
+/// The matches are shown by passing [UseTextSearch::matches_attr] to the `match_highlights` attribute
+/// of the paragraphs, and the color can be changed with `match_highlight_color`.
+/// Moving between matches selects them in the editor.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut editable = use_editable(
+///         || EditableConfig::new("Hello World, hello Rust".to_string()),
+///         EditableMode::MultipleLinesSingleEditor,
+///     );
+///     let mut search = use_text_search(editable);
+///
+///     let cursor_reference = editable.cursor_attr();
+///     let highlights = editable.highlights_attr(0);
+///     let match_highlights = search.matches_attr(0);
+///     let cursor_char = editable.editor().read().cursor_pos();
+///
+///     rsx!(
+///         Input {
+///             value: search.query(),
+///             onchange: move |query| search.set_query(query),
+///         }
+///         Button {
+///             onpress: move |_| search.next(),
+///             label { "Next" }
+///         }
+///         Button {
+///             onpress: move |_| {
+///                 search.replace_all("Hi");
+///             },
+///             label { "Replace all" }
+///         }
+///         paragraph {
+///             cursor_id: "0",
+///             cursor_index: "{cursor_char}",
+///             cursor_mode: "editable",
+///             cursor_reference,
+///             highlights,
+///             match_highlights,
+///             text {
+///                 "{editable.editor()}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_text_search(editable: UseEditable) -> UseTextSearch {
+    let query = use_signal(String::new);
+    let case_sensitive = use_signal(|| false);
+    let matches = use_memo(move || {
+        editable
+            .editor()
+            .read()
+            .find(&query.read(), *case_sensitive.read())
+    });
+
+    UseTextSearch {
+        editable,
+        query,
+        case_sensitive,
+        matches,
+    }
+}
@@ -0,0 +1,84 @@
+use dioxus_clipboard::prelude::{
+    use_clipboard,
+    UseClipboard,
+};
+use dioxus_hooks::use_context;
+use dioxus_signals::{
+    Readable,
+    Signal,
+};
+use freya_core::types::{
+    AccessibilityNode,
+    AccessibilityRole,
+};
+
+/// Text selected with the `text_selection` attribute.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct SelectedText(pub Option<String>);
+
+/// Access the selected text, see [use_text_selection].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseTextSelection {
+    selected_text: Signal<SelectedText>,
+    focused_node: Signal<AccessibilityNode>,
+    clipboard: UseClipboard,
+}
+
+impl UseTextSelection {
+    /// Get the selected text, the texts of different `label`s or `paragraph`s are separated by new lines.
+    pub fn text(&self) -> Option<String> {
+        self.selected_text.read().0.clone()
+    }
+
+    /// Copy the selected text to the clipboard, if there is any.
+    ///
+    /// Nothing is copied while a text input is focused, as it copies its own selection.
+    /// Returns whether the selected text was copied.
+    pub fn copy(&mut self) -> bool {
+        let is_text_input_focused = matches!(
+            self.focused_node.peek().role(),
+            AccessibilityRole::TextInput | AccessibilityRole::MultilineTextInput
+        );
+        if is_text_input_focused {
+            return false;
+        }
+        match self.text() {
+            Some(text) => {
+                self.clipboard.set(text).ok();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Access the text selected by dragging the mouse over the `label`s and `paragraph`s
+/// inside an element with `text_selection: "enabled"`.
+///
+/// `NativeContainer` already copies it with `Ctrl + C` (`Cmd + C` in macOS), unless a text input is focused.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let text_selection = use_text_selection();
+///
+///     rsx!(
+///         rect {
+///             text_selection: "enabled",
+///             label { "Select me!" }
+///         }
+///         label {
+///             "Selected: {text_selection.text().unwrap_or_default()}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_text_selection() -> UseTextSelection {
+    UseTextSelection {
+        selected_text: use_context(),
+        focused_node: use_context(),
+        clipboard: use_clipboard(),
+    }
+}
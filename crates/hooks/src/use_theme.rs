@@ -1,14 +1,36 @@
-use dioxus_core::prelude::try_consume_context;
+use std::{
+    borrow::Cow,
+    time::Duration,
+};
+
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_hook,
+    Task,
+};
 use dioxus_hooks::{
     use_context,
     use_context_provider,
+    use_effect,
+    use_signal,
 };
 use dioxus_signals::{
     Readable,
     Signal,
+    Writable,
 };
+use freya_core::parsing::Parse;
+use freya_engine::prelude::Color;
 
-use crate::theming::*;
+use crate::{
+    apply_value,
+    theming::*,
+    use_animation_clock,
+    use_platform,
+    Ease,
+    Function,
+};
 
 /// Provide a custom [`Theme`].
 pub fn use_init_theme(theme_cb: impl FnOnce() -> Theme) -> Signal<Theme> {
@@ -21,6 +43,9 @@ pub fn use_init_default_theme() -> Signal<Theme> {
 }
 
 /// Subscribe to [`Theme`] changes.
+///
+/// The whole theme can be swapped at runtime with `use_theme().set(DARK_THEME)`,
+/// all the components using it will be updated.
 pub fn use_theme() -> Signal<Theme> {
     use_context::<Signal<Theme>>()
 }
@@ -28,10 +53,189 @@ pub fn use_theme() -> Signal<Theme> {
 /// Subscribe to [`Theme`] changes, default theme will be used if there is no provided [`Theme`].
 ///
 /// Primarily used by built-in components that have no control of whether they will inherit a [`Theme`] or not.
+///
+/// While a [`ThemeTransition`] is running, the returned theme uses the interpolated colors.
 pub fn use_get_theme() -> Theme {
-    try_consume_context::<Signal<Theme>>()
+    let mut theme = try_consume_context::<Signal<Theme>>()
         .map(|v| v.read().clone())
-        .unwrap_or_default()
+        .unwrap_or_default();
+
+    if let Some(transition_colors) = try_consume_context::<TransitionColors>() {
+        if let Some(colors) = &*transition_colors.0.read() {
+            theme.colors = colors.clone();
+        }
+    }
+
+    theme
+}
+
+/// Configuration of the animation used to interpolate the colors when the [`Theme`] changes.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ThemeTransition {
+    pub duration: Duration,
+    pub ease: Ease,
+    pub function: Function,
+}
+
+impl Default for ThemeTransition {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(250),
+            ease: Ease::InOut,
+            function: Function::Quad,
+        }
+    }
+}
+
+impl ThemeTransition {
+    /// Set the duration of the transition.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Set the easing type. See `Ease` for all the types.
+    pub fn ease(mut self, ease: Ease) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Set the easing function. See `Function` for all the types.
+    pub fn function(mut self, function: Function) -> Self {
+        self.function = function;
+        self
+    }
+}
+
+/// Colors of a running [`ThemeTransition`].
+#[derive(Clone, Copy)]
+struct TransitionColors(Signal<Option<ColorsSheet>>);
+
+/// Animate the colors of the given [`Theme`] every time it changes, instead of swapping them at once.
+/// Nothing is animated if the transition is `None`.
+///
+/// Must be called in the same component that provides the [`Theme`], the transition is only read once.
+pub fn use_theme_transition(theme: Signal<Theme>, transition: Option<ThemeTransition>) {
+    let platform = use_platform();
+    let clock = use_animation_clock();
+    let transition_colors =
+        use_context_provider(|| TransitionColors(Signal::new(None::<ColorsSheet>)));
+    let mut prev_colors = use_signal(|| theme.peek().colors.clone());
+    let mut task = use_signal::<Option<Task>>(|| None);
+    let transition = use_hook(|| transition);
+
+    use_effect(move || {
+        let colors = theme.read().colors.clone();
+        let mut current_colors = transition_colors.0;
+
+        // Start from wherever the previous transition was
+        let origin = current_colors
+            .peek()
+            .clone()
+            .unwrap_or_else(|| prev_colors.peek().clone());
+        prev_colors.set(colors.clone());
+
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+
+        let Some(transition) = transition.filter(|_| origin != colors) else {
+            current_colors.set(None);
+            return;
+        };
+
+        let mut ticker = platform.new_ticker();
+        let clock = clock.clone();
+        let transition_task = spawn(async move {
+            let start = clock.now();
+            loop {
+                platform.request_animation_frame();
+
+                let elapsed = clock.now() - start;
+                if elapsed >= transition.duration {
+                    break;
+                }
+
+                let progress = apply_value(
+                    0.,
+                    1.,
+                    elapsed.as_millis(),
+                    transition.duration,
+                    transition.ease,
+                    transition.function,
+                );
+                current_colors.set(Some(mix_colors_sheets(&origin, &colors, progress)));
+
+                ticker.tick().await;
+            }
+
+            current_colors.set(None);
+            task.write().take();
+        });
+        task.set(Some(transition_task));
+    });
+}
+
+/// Interpolate every color of two [`ColorsSheet`]s, colors that can't be parsed are swapped at once.
+fn mix_colors_sheets(
+    origin: &ColorsSheet,
+    destination: &ColorsSheet,
+    progress: f32,
+) -> ColorsSheet {
+    macro_rules! mix {
+        ($($field:ident),*) => {
+            ColorsSheet {
+                $(
+                    $field: mix_colors(&origin.$field, &destination.$field, progress),
+                )*
+            }
+        };
+    }
+
+    mix!(
+        primary,
+        focused_primary_border,
+        secondary,
+        tertiary,
+        surface,
+        secondary_surface,
+        neutral_surface,
+        focused_surface,
+        opposite_surface,
+        secondary_opposite_surface,
+        tertiary_opposite_surface,
+        background,
+        focused_border,
+        solid,
+        color,
+        primary_color,
+        placeholder_color,
+        highlight_color
+    )
+}
+
+fn mix_colors(
+    origin: &Cow<'static, str>,
+    destination: &Cow<'static, str>,
+    progress: f32,
+) -> Cow<'static, str> {
+    if origin == destination {
+        return destination.clone();
+    }
+    let (Ok(origin), Ok(destination)) = (Color::parse(origin), Color::parse(destination)) else {
+        return destination.clone();
+    };
+    let mix = |origin: u8, destination: u8| {
+        (origin as f32 + (destination as f32 - origin as f32) * progress.clamp(0., 1.)).round()
+            as u8
+    };
+    Cow::Owned(format!(
+        "rgb({}, {}, {}, {})",
+        mix(origin.r(), destination.r()),
+        mix(origin.g(), destination.g()),
+        mix(origin.b(), destination.b()),
+        mix(origin.a(), destination.a())
+    ))
 }
 
 /// This macro has three arguments separator by commas.
@@ -0,0 +1,259 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use dioxus_core::prelude::use_hook;
+use dioxus_signals::Signal;
+use serde::Deserialize;
+
+use crate::{
+    use_init_theme,
+    Theme,
+    BANANA_THEME,
+    DARK_THEME,
+    LIGHT_THEME,
+};
+
+/// Format of a theme file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeFileFormat {
+    Json,
+    Toml,
+    Ron,
+}
+
+impl ThemeFileFormat {
+    /// Get the format of a file from its extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            "ron" => Some(Self::Ron),
+            _ => None,
+        }
+    }
+}
+
+/// Errors that might happen while loading a theme file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThemeFileError {
+    /// The file could not be read.
+    Io(String),
+    /// The file extension is not `json`, `toml` or `ron`.
+    UnsupportedFormat(PathBuf),
+    /// The file content is not valid.
+    Parse(String),
+    /// There is no built-in theme with this name.
+    UnknownBase(String),
+    /// There is no color or component theme field with this name.
+    UnknownField(String),
+}
+
+impl std::fmt::Display for ThemeFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Failed to read theme file: {err}"),
+            Self::UnsupportedFormat(path) => write!(f, "Unsupported theme file format: {path:?}"),
+            Self::Parse(err) => write!(f, "Failed to parse theme file: {err}"),
+            Self::UnknownBase(base) => write!(f, "Unknown base theme: {base}"),
+            Self::UnknownField(field) => write!(f, "Unknown theme field: {field}"),
+        }
+    }
+}
+
+/// Content of a theme file, a built-in theme with some overrides.
+///
+/// Example in TOML:
+///
+/// ```toml
+/// base = "dark"
+///
+/// [colors]
+/// primary = "rgb(240, 100, 50)"
+///
+/// [components.button]
+/// padding = "8 16"
+/// "font_theme.color" = "key(primary)"
+/// ```
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ThemeFile {
+    /// Built-in theme to start from, `light`, `dark` or `banana`. Defaults to `light`.
+    pub base: Option<String>,
+    /// Overrides of the colors sheet, e.g `primary`.
+    pub colors: HashMap<String, String>,
+    /// Overrides of the component themes, by component and field, e.g `button` and `padding`.
+    pub components: HashMap<String, HashMap<String, String>>,
+}
+
+impl ThemeFile {
+    /// Parse the content of a theme file.
+    pub fn parse(content: &str, format: ThemeFileFormat) -> Result<Self, ThemeFileError> {
+        match format {
+            ThemeFileFormat::Json => {
+                serde_json::from_str(content).map_err(|err| ThemeFileError::Parse(err.to_string()))
+            }
+            ThemeFileFormat::Toml => {
+                toml::from_str(content).map_err(|err| ThemeFileError::Parse(err.to_string()))
+            }
+            ThemeFileFormat::Ron => {
+                ron::from_str(content).map_err(|err| ThemeFileError::Parse(err.to_string()))
+            }
+        }
+    }
+
+    /// Read and parse a theme file, the format is picked from its extension.
+    pub fn load(path: &Path) -> Result<Self, ThemeFileError> {
+        let format = ThemeFileFormat::from_path(path)
+            .ok_or_else(|| ThemeFileError::UnsupportedFormat(path.to_path_buf()))?;
+        let content =
+            fs::read_to_string(path).map_err(|err| ThemeFileError::Io(err.to_string()))?;
+        Self::parse(&content, format)
+    }
+
+    /// Create the [`Theme`] described by this file.
+    pub fn to_theme(&self) -> Result<Theme, ThemeFileError> {
+        let mut theme = match self.base.as_deref() {
+            None | Some("light") => LIGHT_THEME,
+            Some("dark") => DARK_THEME,
+            Some("banana") => BANANA_THEME,
+            Some(base) => return Err(ThemeFileError::UnknownBase(base.to_string())),
+        };
+
+        for (key, value) in &self.colors {
+            if !theme.colors.set(key, value.clone().into()) {
+                return Err(ThemeFileError::UnknownField(format!("colors.{key}")));
+            }
+        }
+
+        for (component, fields) in &self.components {
+            for (field, value) in fields {
+                if !theme.set_component_field(component, field, value.clone().into()) {
+                    return Err(ThemeFileError::UnknownField(format!("{component}.{field}")));
+                }
+            }
+        }
+
+        Ok(theme)
+    }
+}
+
+/// Read the [`Theme`] described by the given file.
+pub fn load_theme_file(path: impl AsRef<Path>) -> Result<Theme, ThemeFileError> {
+    ThemeFile::load(path.as_ref())?.to_theme()
+}
+
+/// Provide the [`Theme`] described by the given JSON, TOML or RON file, see [`ThemeFile`].
+/// The default theme is used if the file can't be loaded.
+///
+/// In debug builds the file is watched, so any change is applied right away without recompiling.
+pub fn use_init_theme_from_file(path: impl Into<PathBuf>) -> Signal<Theme> {
+    let path = use_hook(|| path.into());
+    let theme = use_init_theme(|| {
+        load_theme_file(&path).unwrap_or_else(|err| {
+            tracing::error!("{err}");
+            Theme::default()
+        })
+    });
+
+    #[cfg(debug_assertions)]
+    use_hook(|| {
+        dioxus_core::prelude::spawn(watch_theme_file(path, theme));
+    });
+
+    theme
+}
+
+/// Reload the theme every time the file is modified.
+#[cfg(debug_assertions)]
+async fn watch_theme_file(path: PathBuf, mut theme: Signal<Theme>) {
+    use std::time::Duration;
+
+    use dioxus_signals::Writable;
+
+    let modified_at = |path: &Path| fs::metadata(path).and_then(|metadata| metadata.modified());
+
+    let mut last_modified_at = modified_at(&path).ok();
+    let mut interval = tokio::time::interval(Duration::from_millis(500));
+    loop {
+        interval.tick().await;
+
+        let modified = modified_at(&path).ok();
+        if modified == last_modified_at {
+            continue;
+        }
+        last_modified_at = modified;
+
+        match load_theme_file(&path) {
+            Ok(new_theme) => {
+                tracing::info!("Reloaded theme file {path:?}");
+                theme.set(new_theme);
+            }
+            Err(err) => tracing::error!("{err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        ThemeFile,
+        ThemeFileError,
+        ThemeFileFormat,
+    };
+    use crate::DARK_THEME;
+
+    #[test]
+    fn parse_theme_files() {
+        let toml = r#"
+            base = "dark"
+
+            [colors]
+            primary = "red"
+
+            [components.button]
+            padding = "8 16"
+            "font_theme.color" = "key(primary)"
+        "#;
+        let json = r#"{
+            "base": "dark",
+            "colors": { "primary": "red" },
+            "components": { "button": { "padding": "8 16", "font_theme.color": "key(primary)" } }
+        }"#;
+        let ron = r#"(
+            base: Some("dark"),
+            colors: { "primary": "red" },
+            components: { "button": { "padding": "8 16", "font_theme.color": "key(primary)" } },
+        )"#;
+
+        for (content, format) in [
+            (toml, ThemeFileFormat::Toml),
+            (json, ThemeFileFormat::Json),
+            (ron, ThemeFileFormat::Ron),
+        ] {
+            let theme = ThemeFile::parse(content, format)
+                .unwrap()
+                .to_theme()
+                .unwrap();
+            assert_eq!(theme.name, "dark");
+            assert_eq!(theme.colors.primary, "red");
+            assert_eq!(theme.colors.background, DARK_THEME.colors.background);
+            assert_eq!(theme.button.padding, "8 16");
+            assert_eq!(theme.button.font_theme.color, "key(primary)");
+        }
+
+        let unknown_field =
+            ThemeFile::parse("[components.button]\npading = \"8\"", ThemeFileFormat::Toml)
+                .unwrap()
+                .to_theme();
+        assert_eq!(
+            unknown_field,
+            Err(ThemeFileError::UnknownField("button.pading".to_string()))
+        );
+    }
+}
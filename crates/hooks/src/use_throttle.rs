@@ -0,0 +1,185 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{
+    spawn,
+    use_drop,
+    use_hook,
+    Callback,
+    Task,
+};
+use dioxus_hooks::{
+    use_callback,
+    use_effect,
+    use_signal,
+};
+use dioxus_signals::{
+    CopyValue,
+    ReadOnlySignal,
+    Readable,
+    Writable,
+};
+use tokio::time::sleep;
+
+/// Run a callback at most once every some time, see [use_throttle].
+pub struct UseThrottle<T: 'static> {
+    callback: Callback<T>,
+    /// Latest value requested while waiting, run once the wait is over.
+    pending: CopyValue<Option<T>>,
+    task: CopyValue<Option<Task>>,
+    duration: Duration,
+}
+
+impl<T: 'static> Clone for UseThrottle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for UseThrottle<T> {}
+
+impl<T: 'static> PartialEq for UseThrottle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.task == other.task && self.duration == other.duration
+    }
+}
+
+impl<T: 'static> UseThrottle<T> {
+    /// Run the callback with the given value right away if it hasn't run recently,
+    /// otherwise run it with the latest value once enough time has passed.
+    pub fn action(&mut self, value: T) {
+        if self.task.peek().is_some() {
+            self.pending.set(Some(value));
+            return;
+        }
+
+        self.callback.call(value);
+
+        let callback = self.callback;
+        let mut pending = self.pending;
+        let mut task = self.task;
+        let duration = self.duration;
+        task.set(Some(spawn(async move {
+            loop {
+                sleep(duration).await;
+                // Keep waiting after running the latest value, so it's not followed by another one too soon
+                let Some(value) = pending.write().take() else {
+                    break;
+                };
+                callback.call(value);
+            }
+            task.set(None);
+        })));
+    }
+
+    /// Forget about the latest value waiting to be run, if any.
+    pub fn cancel(&mut self) {
+        self.pending.set(None);
+        if let Some(task) = self.task.write().take() {
+            task.cancel();
+        }
+    }
+
+    /// Whether the callback ran recently and new values will have to wait.
+    pub fn is_throttling(&self) -> bool {
+        self.task.read().is_some()
+    }
+}
+
+/// Run a callback at most once every given duration, e.g. to react to resize or scroll events.
+///
+/// The first value runs right away and the latest one requested while waiting runs once the duration passes,
+/// so the last change is never lost.
+/// The time is measured with a tokio timer, so it can be controlled in the tests with `advance_time`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let (reference, layout) = use_node_signal();
+///     let mut size = use_signal(|| (0., 0.));
+///     let mut on_resize = use_throttle(Duration::from_millis(100), move |new_size| {
+///         size.set(new_size);
+///     });
+///
+///     use_effect(move || {
+///         let area = layout.read().area;
+///         on_resize.action((area.width(), area.height()));
+///     });
+///
+///     rsx!(
+///         rect {
+///             reference,
+///             width: "fill",
+///             height: "fill",
+///             label {
+///                 "{size:?}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_throttle<T: 'static>(
+    duration: Duration,
+    callback: impl FnMut(T) + 'static,
+) -> UseThrottle<T> {
+    let callback = use_callback(callback);
+    let pending = use_hook(|| CopyValue::new(None::<T>));
+    let task = use_hook(|| CopyValue::new(None::<Task>));
+
+    use_drop(move || {
+        if let Some(task) = task.write_unchecked().take() {
+            task.cancel();
+        }
+    });
+
+    UseThrottle {
+        callback,
+        pending,
+        task,
+        duration,
+    }
+}
+
+/// Get a copy of a signal that follows its changes at most once every given duration.
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut position = use_signal(|| 0.);
+///     let throttled = use_throttled_signal(position.into(), Duration::from_millis(100));
+///
+///     rsx!(
+///         rect {
+///             width: "fill",
+///             height: "fill",
+///             onmousemove: move |e: MouseEvent| position.set(e.get_screen_coordinates().x),
+///             label {
+///                 "{throttled}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_throttled_signal<T: 'static + Clone>(
+    source: ReadOnlySignal<T>,
+    duration: Duration,
+) -> ReadOnlySignal<T> {
+    let mut throttled = use_signal(|| source.peek().clone());
+    let mut throttle = use_throttle(duration, move |value| throttled.set(value));
+    let mut is_first_run = use_hook(|| CopyValue::new(true));
+
+    use_effect(move || {
+        let value = source.read().clone();
+        // The initial value is already there
+        if *is_first_run.peek() {
+            is_first_run.set(false);
+            return;
+        }
+        throttle.action(value);
+    });
+
+    throttled.into()
+}
@@ -0,0 +1,253 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_drop,
+    use_hook,
+    Task,
+};
+use dioxus_hooks::{
+    use_callback,
+    use_effect,
+    use_signal,
+};
+use dioxus_signals::{
+    CopyValue,
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::platform_state::PlatformInformation;
+use tokio::time::{
+    sleep,
+    Instant,
+};
+
+/// Configuration for [use_interval_with_options] and [use_timeout_with_options].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TimerOptions {
+    /// Stop counting while the window is minimized, e.g. for polling that nobody would see.
+    pub pause_when_minimized: bool,
+    /// Don't start counting until [UseTimer::resume] is called.
+    pub start_paused: bool,
+}
+
+impl TimerOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_pause_when_minimized(mut self, pause_when_minimized: bool) -> Self {
+        self.pause_when_minimized = pause_when_minimized;
+        self
+    }
+
+    pub fn with_start_paused(mut self, start_paused: bool) -> Self {
+        self.start_paused = start_paused;
+        self
+    }
+}
+
+/// Control a timer created with [use_interval] or [use_timeout].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseTimer {
+    is_running: Signal<bool>,
+    ticks: Signal<u64>,
+    restarts: Signal<usize>,
+}
+
+impl UseTimer {
+    /// Times the timer has run its callback.
+    pub fn ticks(&self) -> u64 {
+        *self.ticks.read()
+    }
+
+    /// Whether the timer is counting. A timeout stops once it runs.
+    pub fn is_running(&self) -> bool {
+        *self.is_running.read()
+    }
+
+    /// Stop counting, keeping the time left until the next run.
+    pub fn pause(&mut self) {
+        if *self.is_running.peek() {
+            self.is_running.set(false);
+        }
+    }
+
+    /// Continue counting after being paused. A timeout that already ran will run again.
+    pub fn resume(&mut self) {
+        if !*self.is_running.peek() {
+            self.is_running.set(true);
+        }
+    }
+
+    /// Start counting again from the whole duration.
+    pub fn restart(&mut self) {
+        *self.restarts.write() += 1;
+        self.resume();
+    }
+}
+
+fn use_timer(
+    duration: Duration,
+    repeat: bool,
+    options: TimerOptions,
+    mut callback: impl FnMut() + 'static,
+) -> UseTimer {
+    let callback = use_callback(move |_: ()| callback());
+    let mut is_running = use_signal(|| !options.start_paused);
+    let mut ticks = use_signal(|| 0);
+    let restarts = use_signal(|| 0);
+    let platform_information = use_hook(try_consume_context::<Signal<PlatformInformation>>);
+    let mut task = use_hook(|| CopyValue::new(None::<Task>));
+    // Time left until the next run and when it started being counted
+    let mut remaining = use_hook(|| CopyValue::new(duration));
+    let mut started_at = use_hook(|| CopyValue::new(None::<Instant>));
+    let mut last_restarts = use_hook(|| CopyValue::new(0));
+
+    use_effect(move || {
+        let restarts = *restarts.read();
+        let is_minimized = options.pause_when_minimized
+            && platform_information.is_some_and(|info| info.read().is_minimized);
+        let is_active = *is_running.read() && !is_minimized;
+
+        // Stop counting, remembering the time left
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+        if let Some(started_at) = started_at.write().take() {
+            let left = remaining.peek().saturating_sub(started_at.elapsed());
+            remaining.set(left);
+        }
+        if restarts != *last_restarts.peek() {
+            last_restarts.set(restarts);
+            remaining.set(duration);
+        }
+
+        if !is_active {
+            return;
+        }
+
+        task.set(Some(spawn(async move {
+            loop {
+                started_at.set(Some(Instant::now()));
+                sleep(*remaining.peek()).await;
+                started_at.set(None);
+                remaining.set(duration);
+                *ticks.write() += 1;
+                callback.call(());
+                if !repeat {
+                    break;
+                }
+            }
+            task.set(None);
+            is_running.set(false);
+        })));
+    });
+
+    use_drop(move || {
+        if let Some(task) = task.write_unchecked().take() {
+            task.cancel();
+        }
+    });
+
+    UseTimer {
+        is_running,
+        ticks,
+        restarts,
+    }
+}
+
+/// Run a callback every given period until the component is dropped, see [use_interval_with_options].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut seconds = use_signal(|| 0);
+///     let mut timer = use_interval(Duration::from_secs(1), move || seconds += 1);
+///
+///     rsx!(
+///         label {
+///             "{seconds} seconds"
+///         }
+///         Button {
+///             onpress: move |_| {
+///                 if timer.is_running() {
+///                     timer.pause();
+///                 } else {
+///                     timer.resume();
+///                 }
+///             },
+///             label {
+///                 "Pause or resume"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_interval(period: Duration, callback: impl FnMut() + 'static) -> UseTimer {
+    use_interval_with_options(period, TimerOptions::default(), callback)
+}
+
+/// Same as [use_interval] but with custom [TimerOptions].
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use freya::prelude::*;
+/// # fn check_inbox() {}
+/// fn app() -> Element {
+///     let timer = use_interval_with_options(
+///         Duration::from_secs(30),
+///         TimerOptions::new().with_pause_when_minimized(true),
+///         check_inbox,
+///     );
+///
+///     rsx!(
+///         label {
+///             "Checked {timer.ticks()} times"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_interval_with_options(
+    period: Duration,
+    options: TimerOptions,
+    callback: impl FnMut() + 'static,
+) -> UseTimer {
+    use_timer(period, true, options, callback)
+}
+
+/// Run a callback once after the given delay, unless the component is dropped before.
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut show_hint = use_signal(|| false);
+///     use_timeout(Duration::from_secs(5), move || show_hint.set(true));
+///
+///     rsx!(
+///         if show_hint() {
+///             label {
+///                 "Press Ctrl+K to search"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_timeout(delay: Duration, callback: impl FnMut() + 'static) -> UseTimer {
+    use_timeout_with_options(delay, TimerOptions::default(), callback)
+}
+
+/// Same as [use_timeout] but with custom [TimerOptions].
+pub fn use_timeout_with_options(
+    delay: Duration,
+    options: TimerOptions,
+    callback: impl FnMut() + 'static,
+) -> UseTimer {
+    use_timer(delay, false, options, callback)
+}
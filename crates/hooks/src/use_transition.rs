@@ -0,0 +1,215 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{
+    spawn,
+    use_hook,
+    Task,
+};
+use dioxus_hooks::{
+    use_effect,
+    use_memo,
+    use_signal,
+};
+use dioxus_signals::{
+    ReadOnlySignal,
+    Readable,
+    Writable,
+};
+use freya_core::values::{
+    Border,
+    Fill,
+    Shadow,
+};
+use freya_engine::prelude::Color;
+
+use crate::{
+    apply_value,
+    use_animation::{
+        mix,
+        mix_border,
+        mix_color,
+        mix_fill,
+        mix_shadow,
+    },
+    use_animation_clock,
+    use_platform,
+    Ease,
+    Function,
+};
+
+/// Values that can be linearly interpolated, see [`use_transition`].
+pub trait Lerp: Clone + PartialEq + 'static {
+    /// Get the value at `progress` (from `0.0` to `1.0`) of the way between `self` and `to`.
+    fn lerp(&self, to: &Self, progress: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, to: &Self, progress: f32) -> Self {
+        mix(*self, *to, progress)
+    }
+}
+
+impl Lerp for f64 {
+    fn lerp(&self, to: &Self, progress: f32) -> Self {
+        self + (to - self) * progress as f64
+    }
+}
+
+impl Lerp for Color {
+    fn lerp(&self, to: &Self, progress: f32) -> Self {
+        mix_color(*self, *to, progress)
+    }
+}
+
+impl Lerp for Fill {
+    fn lerp(&self, to: &Self, progress: f32) -> Self {
+        mix_fill(self, to, progress)
+    }
+}
+
+impl Lerp for Shadow {
+    fn lerp(&self, to: &Self, progress: f32) -> Self {
+        mix_shadow(self, to, progress)
+    }
+}
+
+impl Lerp for Border {
+    fn lerp(&self, to: &Self, progress: f32) -> Self {
+        mix_border(self, to, progress)
+    }
+}
+
+impl<A: Lerp, B: Lerp> Lerp for (A, B) {
+    fn lerp(&self, to: &Self, progress: f32) -> Self {
+        (self.0.lerp(&to.0, progress), self.1.lerp(&to.1, progress))
+    }
+}
+
+impl<A: Lerp, B: Lerp, C: Lerp> Lerp for (A, B, C) {
+    fn lerp(&self, to: &Self, progress: f32) -> Self {
+        (
+            self.0.lerp(&to.0, progress),
+            self.1.lerp(&to.1, progress),
+            self.2.lerp(&to.2, progress),
+        )
+    }
+}
+
+/// Configuration of the animation used by [`use_transition`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct TransitionConfig {
+    pub duration: Duration,
+    pub ease: Ease,
+    pub function: Function,
+}
+
+impl Default for TransitionConfig {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(250),
+            ease: Ease::InOut,
+            function: Function::Quad,
+        }
+    }
+}
+
+impl TransitionConfig {
+    /// Set the duration of the transition.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Set the easing type. See `Ease` for all the types.
+    pub fn ease(mut self, ease: Ease) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Set the easing function. See `Function` for all the types.
+    pub fn function(mut self, function: Function) -> Self {
+        self.function = function;
+        self
+    }
+}
+
+/// Get a version of a reactive value that moves smoothly to every new value instead of jumping to it.
+///
+/// If the value changes while it is still moving, it moves towards the new value from wherever it was.
+/// The configuration is only read once.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut expanded = use_signal(|| false);
+///     let width = use_transition(
+///         move || if expanded() { 300. } else { 100. },
+///         TransitionConfig::default(),
+///     );
+///
+///     rsx!(rect {
+///         width: "{width}",
+///         height: "100",
+///         background: "blue",
+///         onclick: move |_| expanded.toggle(),
+///     })
+/// }
+/// ```
+pub fn use_transition<T: Lerp>(
+    value: impl 'static + FnMut() -> T,
+    config: TransitionConfig,
+) -> ReadOnlySignal<T> {
+    let platform = use_platform();
+    let clock = use_animation_clock();
+    let destination = use_memo(value);
+    let mut current = use_signal(|| destination.peek().clone());
+    let mut task = use_signal::<Option<Task>>(|| None);
+    let config = use_hook(|| config);
+
+    use_effect(move || {
+        let destination = destination();
+        let origin = current.peek().clone();
+
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+
+        if origin == destination {
+            return;
+        }
+
+        let mut ticker = platform.new_ticker();
+        let clock = clock.clone();
+        let transition_task = spawn(async move {
+            let start = clock.now();
+            loop {
+                platform.request_animation_frame();
+
+                let elapsed = clock.now() - start;
+                if elapsed >= config.duration {
+                    break;
+                }
+
+                let progress = apply_value(
+                    0.,
+                    1.,
+                    elapsed.as_millis(),
+                    config.duration,
+                    config.ease,
+                    config.function,
+                );
+                current.set(origin.lerp(&destination, progress));
+
+                ticker.tick().await;
+            }
+
+            current.set(destination);
+            task.write().take();
+        });
+        task.set(Some(transition_task));
+    });
+
+    current.into()
+}
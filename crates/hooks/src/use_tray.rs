@@ -0,0 +1,145 @@
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_hook,
+};
+use dioxus_hooks::use_signal;
+use dioxus_signals::{
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    native_menu::NativeSubmenu,
+    native_tray::{
+        NativeTrayEvent,
+        NativeTrayIcon,
+    },
+    types::NativeTrayEventSender,
+};
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+    use_platform,
+    UsePlatform,
+};
+
+/// Manage the icon of the app in the system tray, see [`use_tray`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseTray {
+    platform: UsePlatform,
+    event: Signal<Option<NativeTrayEvent>>,
+}
+
+impl UseTray {
+    /// Get the last interaction with the tray icon, subscribing to new interactions.
+    pub fn event(&self) -> Option<NativeTrayEvent> {
+        self.event.read().clone()
+    }
+
+    /// Replace the icon, or remove it from the tray if `None`.
+    pub fn set_icon(&self, icon: Option<NativeTrayIcon>) {
+        self.platform
+            .send(EventLoopMessage::SetNativeTrayIcon(icon))
+            .ok();
+    }
+
+    /// Replace the text shown when hovering the icon.
+    pub fn set_tooltip(&self, tooltip: Option<String>) {
+        self.platform
+            .send(EventLoopMessage::SetNativeTrayTooltip(tooltip))
+            .ok();
+    }
+
+    /// Replace the menu shown when right clicking the icon.
+    pub fn set_menu(&self, menu: Option<NativeSubmenu>) {
+        self.platform
+            .send(EventLoopMessage::SetNativeTrayMenu(menu))
+            .ok();
+    }
+
+    /// Check or uncheck a checkable item of the menu.
+    pub fn set_checked(&self, id: impl Into<String>, checked: bool) {
+        self.platform
+            .send(EventLoopMessage::SetNativeMenuItemChecked(
+                id.into(),
+                checked,
+            ))
+            .ok();
+    }
+
+    /// Enable or disable an item of the menu.
+    pub fn set_enabled(&self, id: impl Into<String>, enabled: bool) {
+        self.platform
+            .send(EventLoopMessage::SetNativeMenuItemEnabled(
+                id.into(),
+                enabled,
+            ))
+            .ok();
+    }
+}
+
+/// Manage the icon of the app in the system tray and listen for its clicks and menu items.
+///
+/// The initial icon is usually passed with `LaunchConfig::with_tray`,
+/// where the window can also be configured to be hidden to the tray when closed.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// static ICON: &[u8] = include_bytes!("../../../examples/rust_logo.png");
+///
+/// fn main() {
+///     launch_cfg(
+///         app,
+///         LaunchConfig::<()>::new().with_tray(
+///             NativeTray::new()
+///                 .with_icon(LaunchConfig::load_tray_icon(ICON))
+///                 .with_tooltip("My App")
+///                 .with_menu(
+///                     NativeSubmenu::new("Tray").with_item(NativeMenuItem::new("quit", "Quit")),
+///                 )
+///                 .with_hide_on_close(true),
+///         ),
+///     )
+/// }
+///
+/// fn app() -> Element {
+///     let tray = use_tray();
+///     let platform = use_platform();
+///
+///     use_effect(move || {
+///         if let Some(NativeTrayEvent::MenuItem(NativeMenuEvent { id, .. })) = tray.event() {
+///             if id == "quit" {
+///                 platform.exit();
+///             }
+///         }
+///     });
+///
+///     rsx!(label { "Close me, I will stay in the tray" })
+/// }
+/// ```
+pub fn use_tray() -> UseTray {
+    let platform = use_platform();
+    let mut event = use_signal(|| None);
+
+    use_hook(|| {
+        let Some(sender) = try_consume_context::<NativeTrayEventSender>() else {
+            return;
+        };
+        let mut receiver = sender.subscribe();
+        spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(tray_event) => event.set(Some(tray_event)),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    });
+
+    UseTray { platform, event }
+}
@@ -0,0 +1,294 @@
+use std::{
+    sync::mpsc::{
+        channel,
+        RecvTimeoutError,
+        Sender,
+    },
+    thread,
+    time::Duration,
+};
+
+use dioxus_core::prelude::{
+    spawn,
+    use_drop,
+};
+use dioxus_hooks::use_signal;
+use dioxus_signals::{
+    ReadOnlySignal,
+    Readable,
+    Signal,
+    Writable,
+};
+use tokio::sync::mpsc::unbounded_channel;
+use tts::{
+    Tts,
+    Voice,
+};
+
+/// How often the speech thread checks whether the engine is still speaking.
+const SPEAKING_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Commands sent to the speech thread.
+enum TtsCommand {
+    Speak(String, bool),
+    Stop,
+    SetRate(f32),
+    SetVoice(String),
+    Close,
+}
+
+/// Updates sent from the speech thread.
+enum TtsEvent {
+    Voices(Vec<TtsVoice>),
+    Speaking(bool),
+    Error(TtsError),
+}
+
+/// Errors that might happen while speaking.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TtsError {
+    /// The text-to-speech engine of the platform could not be started.
+    EngineUnavailable(String),
+    /// The engine failed to speak or to apply a setting.
+    Engine(String),
+    /// There is no voice with the given id.
+    VoiceNotFound(String),
+}
+
+impl std::fmt::Display for TtsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EngineUnavailable(err) => write!(f, "Text-to-speech unavailable: {err}"),
+            Self::Engine(err) => write!(f, "Text-to-speech failed: {err}"),
+            Self::VoiceNotFound(id) => write!(f, "Voice '{id}' not found"),
+        }
+    }
+}
+
+/// A voice of the text-to-speech engine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TtsVoice {
+    /// Identifier to select the voice with [UseTts::set_voice].
+    pub id: String,
+    pub name: String,
+    /// Language of the voice, e.g `en-US`.
+    pub language: String,
+}
+
+impl From<&Voice> for TtsVoice {
+    fn from(voice: &Voice) -> Self {
+        Self {
+            id: voice.id(),
+            name: voice.name(),
+            language: voice.language().to_string(),
+        }
+    }
+}
+
+/// Handle to speak text. Created with [use_tts].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseTts {
+    sender: Signal<Sender<TtsCommand>>,
+    rate: Signal<f32>,
+    voice: Signal<Option<String>>,
+    voices: Signal<Vec<TtsVoice>>,
+    speaking: Signal<bool>,
+    error: Signal<Option<TtsError>>,
+}
+
+impl UseTts {
+    fn send(&self, command: TtsCommand) {
+        self.sender.peek().send(command).ok();
+    }
+
+    /// Speak the given text, interrupting what was being spoken.
+    pub fn speak(&self, text: impl Into<String>) {
+        self.send(TtsCommand::Speak(text.into(), true));
+    }
+
+    /// Speak the given text once what is being spoken finishes.
+    pub fn queue(&self, text: impl Into<String>) {
+        self.send(TtsCommand::Speak(text.into(), false));
+    }
+
+    /// Stop speaking.
+    pub fn stop(&self) {
+        self.send(TtsCommand::Stop);
+    }
+
+    /// Change the speed of the speech, where `1.0` is the normal speed of the engine.
+    ///
+    /// The speed is limited to the range supported by the engine.
+    pub fn set_rate(&mut self, rate: f32) {
+        self.rate.set(rate);
+        self.send(TtsCommand::SetRate(rate));
+    }
+
+    /// Change the voice by its [TtsVoice::id], see [UseTts::voices].
+    pub fn set_voice(&mut self, id: impl Into<String>) {
+        let id = id.into();
+        self.voice.set(Some(id.clone()));
+        self.send(TtsCommand::SetVoice(id));
+    }
+
+    /// Get a [ReadOnlySignal] of the speed of the speech.
+    pub fn rate(&self) -> ReadOnlySignal<f32> {
+        self.rate.into()
+    }
+
+    /// Get a [ReadOnlySignal] of the id of the selected voice, `None` for the default one.
+    pub fn voice(&self) -> ReadOnlySignal<Option<String>> {
+        self.voice.into()
+    }
+
+    /// Get a [ReadOnlySignal] of the voices available in the engine.
+    pub fn voices(&self) -> ReadOnlySignal<Vec<TtsVoice>> {
+        self.voices.into()
+    }
+
+    /// Get a [ReadOnlySignal] of whether the engine is speaking.
+    pub fn is_speaking(&self) -> ReadOnlySignal<bool> {
+        self.speaking.into()
+    }
+
+    /// Get a [ReadOnlySignal] of the error.
+    pub fn error(&self) -> ReadOnlySignal<Option<TtsError>> {
+        self.error.into()
+    }
+}
+
+/// Map a rate relative to the normal speed to the range of the engine.
+fn engine_rate(tts: &Tts, rate: f32) -> f32 {
+    (tts.normal_rate() * rate).clamp(tts.min_rate(), tts.max_rate())
+}
+
+fn run_command(tts: &mut Tts, command: TtsCommand) -> Result<(), TtsError> {
+    let engine_err = |err: tts::Error| TtsError::Engine(err.to_string());
+    match command {
+        TtsCommand::Speak(text, interrupt) => {
+            tts.speak(text, interrupt).map_err(engine_err)?;
+        }
+        TtsCommand::Stop => {
+            tts.stop().map_err(engine_err)?;
+        }
+        TtsCommand::SetRate(rate) => {
+            let rate = engine_rate(tts, rate);
+            tts.set_rate(rate).map_err(engine_err)?;
+        }
+        TtsCommand::SetVoice(id) => {
+            let voices = tts.voices().map_err(engine_err)?;
+            let voice = voices
+                .iter()
+                .find(|voice| voice.id() == id)
+                .ok_or(TtsError::VoiceNotFound(id))?;
+            tts.set_voice(voice).map_err(engine_err)?;
+        }
+        TtsCommand::Close => {}
+    }
+    Ok(())
+}
+
+/// Speak text with the text-to-speech engine of the platform, e.g. to read a view aloud.
+///
+/// The engine is owned by a dedicated thread, and it stops speaking once the component that called this hook is dropped.
+///
+/// ## Usage
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut tts = use_tts();
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| {
+///                 tts.set_rate(1.5);
+///                 tts.speak("Hello, World!");
+///             },
+///             label { "Speak" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_tts() -> UseTts {
+    let mut error = use_signal(|| None);
+    let mut voices = use_signal(Vec::new);
+    let mut speaking = use_signal(|| false);
+    let rate = use_signal(|| 1.0);
+    let voice = use_signal(|| None);
+    let sender = use_signal(move || {
+        let (sender, receiver) = channel::<TtsCommand>();
+        let (event_sender, mut event_receiver) = unbounded_channel::<TtsEvent>();
+
+        thread::spawn(move || {
+            let mut tts = match Tts::default() {
+                Ok(tts) => tts,
+                Err(err) => {
+                    event_sender
+                        .send(TtsEvent::Error(TtsError::EngineUnavailable(
+                            err.to_string(),
+                        )))
+                        .ok();
+                    return;
+                }
+            };
+
+            if let Ok(engine_voices) = tts.voices() {
+                let engine_voices = engine_voices.iter().map(TtsVoice::from).collect();
+                event_sender.send(TtsEvent::Voices(engine_voices)).ok();
+            }
+
+            let can_poll = tts.supported_features().is_speaking;
+            let mut is_speaking = false;
+
+            loop {
+                match receiver.recv_timeout(SPEAKING_POLL_INTERVAL) {
+                    Ok(TtsCommand::Close) | Err(RecvTimeoutError::Disconnected) => {
+                        tts.stop().ok();
+                        break;
+                    }
+                    Ok(command) => {
+                        if let Err(err) = run_command(&mut tts, command) {
+                            event_sender.send(TtsEvent::Error(err)).ok();
+                        }
+                    }
+                    Err(RecvTimeoutError::Timeout) => {}
+                }
+
+                if can_poll {
+                    let now_speaking = tts.is_speaking().unwrap_or_default();
+                    if now_speaking != is_speaking {
+                        is_speaking = now_speaking;
+                        event_sender.send(TtsEvent::Speaking(is_speaking)).ok();
+                    }
+                }
+            }
+        });
+
+        // Forward updates from the speech thread to the signals
+        spawn(async move {
+            while let Some(event) = event_receiver.recv().await {
+                match event {
+                    TtsEvent::Voices(new_voices) => voices.set(new_voices),
+                    TtsEvent::Speaking(is_speaking) => speaking.set(is_speaking),
+                    TtsEvent::Error(err) => error.set(Some(err)),
+                }
+            }
+        });
+
+        sender
+    });
+
+    use_drop(move || {
+        sender.peek().send(TtsCommand::Close).ok();
+    });
+
+    UseTts {
+        sender,
+        rate,
+        voice,
+        voices,
+        speaking,
+        error,
+    }
+}
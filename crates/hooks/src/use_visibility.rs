@@ -0,0 +1,172 @@
+use dioxus_core::{
+    prelude::{
+        try_consume_context,
+        use_hook,
+    },
+    AttributeValue,
+};
+use dioxus_hooks::{
+    use_callback,
+    use_effect,
+    use_memo,
+};
+use dioxus_signals::{
+    CopyValue,
+    Memo,
+    ReadOnlySignal,
+    Readable,
+    Writable,
+};
+use freya_core::custom_attributes::NodeReferenceLayout;
+use torin::prelude::Area;
+
+use crate::{
+    use_node_signal,
+    use_platform_information,
+};
+
+/// Visible area of a scrollable container, provided by the `ScrollView` and `VirtualScrollView` components
+/// to the [use_visibility] hooks of their content.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ScrollViewport(pub ReadOnlySignal<NodeReferenceLayout>);
+
+/// How much of a node is inside the viewport, see [use_visibility].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Visibility {
+    /// Part of the node area that is visible, from `0.0` to `1.0`.
+    pub ratio: f32,
+}
+
+impl Visibility {
+    /// Whether any part of the node is visible.
+    pub fn is_visible(&self) -> bool {
+        self.ratio > 0.
+    }
+
+    /// Whether the node is completely visible.
+    pub fn is_fully_visible(&self) -> bool {
+        self.ratio >= 1.
+    }
+
+    /// Get the visibility of an area inside a viewport.
+    pub fn new(area: Area, viewport: Area) -> Self {
+        let total = area.area();
+        if total <= 0. {
+            return Self::default();
+        }
+        let visible = area
+            .intersection(&viewport)
+            .map(|intersection| intersection.area())
+            .unwrap_or_default();
+        Self {
+            ratio: (visible / total).clamp(0., 1.),
+        }
+    }
+
+    /// Amount of the given thresholds this visibility reaches.
+    fn reached_thresholds(&self, thresholds: &[f32]) -> usize {
+        thresholds
+            .iter()
+            .filter(|threshold| self.is_visible() && self.ratio >= **threshold)
+            .count()
+    }
+}
+
+/// Track how much of a node is within the viewport of its nearest `ScrollView`, or the window if there is none.
+///
+/// Pass the returned attribute as the `reference` of the node to track.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         ScrollView {
+///             for i in 0..100 {
+///                 Row { key: "{i}", i }
+///             }
+///         }
+///     )
+/// }
+///
+/// #[component]
+/// fn Row(i: usize) -> Element {
+///     let (reference, visibility) = use_visibility();
+///
+///     rsx!(
+///         rect {
+///             reference,
+///             height: "50",
+///             label {
+///                 "Row {i} is {visibility().ratio * 100.}% visible"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_visibility() -> (AttributeValue, Memo<Visibility>) {
+    let (reference, layout) = use_node_signal();
+    let platform_information = use_platform_information();
+    let scroll_viewport = use_hook(try_consume_context::<ScrollViewport>);
+
+    let visibility = use_memo(move || {
+        let area = layout.read().area;
+        let viewport = match scroll_viewport {
+            Some(ScrollViewport(viewport)) => viewport.read().area,
+            None => {
+                let information = platform_information.read();
+                let size = information.viewport_size / information.scale_factor as f32;
+                Area::new((0., 0.).into(), size)
+            }
+        };
+        Visibility::new(area, viewport)
+    });
+
+    (reference, visibility)
+}
+
+/// Same as [use_visibility], but also running a callback whenever the visible ratio of the node
+/// crosses any of the given thresholds, from `0.0` to `1.0`, in either direction.
+///
+/// A threshold of `0.0` is reached as soon as any part of the node is visible.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # fn mark_as_read(id: usize) {}
+/// #[component]
+/// fn Message(id: usize, text: String) -> Element {
+///     let (reference, _) = use_visibility_observer(vec![1.0], move |visibility: Visibility| {
+///         if visibility.is_fully_visible() {
+///             mark_as_read(id);
+///         }
+///     });
+///
+///     rsx!(
+///         label {
+///             reference,
+///             "{text}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_visibility_observer(
+    thresholds: impl Into<Vec<f32>>,
+    callback: impl FnMut(Visibility) + 'static,
+) -> (AttributeValue, Memo<Visibility>) {
+    let (reference, visibility) = use_visibility();
+    let callback = use_callback(callback);
+    let thresholds = use_hook(|| CopyValue::new(thresholds.into()));
+    let mut reached = use_hook(|| CopyValue::new(0));
+
+    use_effect(move || {
+        let visibility = *visibility.read();
+        let new_reached = visibility.reached_thresholds(&thresholds.peek());
+        if new_reached != *reached.peek() {
+            reached.set(new_reached);
+            callback.call(visibility);
+        }
+    });
+
+    (reference, visibility)
+}
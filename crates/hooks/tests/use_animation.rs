@@ -269,3 +269,266 @@ pub async fn sequential() {
     assert_eq!(width_a, 100.0);
     assert_eq!(width_b, 100.0);
 }
+
+#[test]
+pub fn spring() {
+    let mut spring = AnimSpring::new(0., 100.).stiffness(100.).damping(5.);
+    spring.prepare(AnimDirection::Forward);
+
+    spring.advance(50, AnimDirection::Forward);
+    let value = spring.read();
+    assert!(value > 0. && value < 100.);
+    assert!(spring.read_velocity() > 0.);
+
+    // Underdamped springs overshoot their destination
+    let overshoots = (0..1000).step_by(10).any(|index| {
+        spring.advance(index, AnimDirection::Forward);
+        spring.read() > 100.
+    });
+    assert!(overshoots);
+
+    // Eventually it settles in the destination
+    spring.advance(10_000, AnimDirection::Forward);
+    assert_eq!(spring.read(), 100.);
+    assert!(spring.is_finished(10_000, AnimDirection::Forward));
+
+    // In reverse it goes back to the origin
+    spring.prepare(AnimDirection::Reverse);
+    assert_eq!(spring.read(), 100.);
+    spring.advance(10_000, AnimDirection::Reverse);
+    assert_eq!(spring.read(), 0.);
+}
+
+#[test]
+pub fn spring_carry_over() {
+    let mut spring = AnimSpring::new(0., 100.);
+    spring.prepare(AnimDirection::Forward);
+    spring.advance(100, AnimDirection::Forward);
+    let (value, velocity) = (spring.read(), spring.read_velocity());
+    assert!(velocity > 0.);
+
+    // Retarget the spring while it's moving
+    let mut retargeted = AnimSpring::new(0., 200.);
+    retargeted.carry_over(&spring);
+    retargeted.prepare(AnimDirection::Forward);
+    assert_eq!(retargeted.read(), value);
+    assert_eq!(retargeted.read_velocity(), velocity);
+
+    retargeted.advance(10_000, AnimDirection::Forward);
+    assert_eq!(retargeted.read(), 200.);
+
+    // Springs that never started don't carry anything over
+    let mut other = AnimSpring::new(50., 100.);
+    other.carry_over(&AnimSpring::new(0., 100.));
+    other.prepare(AnimDirection::Forward);
+    assert_eq!(other.read(), 50.);
+
+    // An explicit start wins over the previous spring
+    let mut released = AnimSpring::new(0., 100.).start_from(30., -200.);
+    released.carry_over(&spring);
+    released.prepare(AnimDirection::Forward);
+    assert_eq!(released.read(), 30.);
+    assert_eq!(released.read_velocity(), -200.);
+}
+
+#[test]
+pub fn keyframes() {
+    let mut keyframes = AnimKeyframes::new(0.)
+        .keyframe(Keyframe::new(100.).time(100))
+        .keyframe(Keyframe::new(50.).time(50).function(Function::Quad));
+    keyframes.prepare(AnimDirection::Forward);
+    assert_eq!(keyframes.read(), 0.);
+
+    keyframes.advance(50, AnimDirection::Forward);
+    assert_eq!(keyframes.read(), 50.);
+
+    keyframes.advance(100, AnimDirection::Forward);
+    assert_eq!(keyframes.read(), 100.);
+
+    keyframes.advance(125, AnimDirection::Forward);
+    let value = keyframes.read();
+    assert!(value < 100. && value > 50.);
+
+    keyframes.advance(200, AnimDirection::Forward);
+    assert_eq!(keyframes.read(), 50.);
+    assert!(keyframes.is_finished(200, AnimDirection::Forward));
+
+    // In reverse the keyframes are played backwards
+    keyframes.prepare(AnimDirection::Reverse);
+    assert_eq!(keyframes.read(), 50.);
+
+    keyframes.advance(50, AnimDirection::Reverse);
+    assert_eq!(keyframes.read(), 100.);
+
+    keyframes.advance(100, AnimDirection::Reverse);
+    assert_eq!(keyframes.read(), 50.);
+
+    keyframes.advance(150, AnimDirection::Reverse);
+    assert_eq!(keyframes.read(), 0.);
+}
+
+#[test]
+pub fn font_variation() {
+    let mut font_variation =
+        AnimFontVariation::new("wght=100, slnt=0", "wght=900, wdth=75").time(100);
+    font_variation.prepare(AnimDirection::Forward);
+    // Axes only present in one of the values keep it during the whole animation
+    assert_eq!(font_variation.read(), "wght=100, slnt=0, wdth=75");
+
+    font_variation.advance(50, AnimDirection::Forward);
+    assert_eq!(font_variation.read(), "wght=500, slnt=0, wdth=75");
+
+    font_variation.advance(100, AnimDirection::Forward);
+    assert_eq!(font_variation.read(), "wght=900, slnt=0, wdth=75");
+    assert!(font_variation.is_finished(101, AnimDirection::Forward));
+
+    font_variation.prepare(AnimDirection::Reverse);
+    font_variation.advance(100, AnimDirection::Reverse);
+    assert_eq!(font_variation.read(), "wght=100, slnt=0, wdth=75");
+
+    // Invalid axes are skipped
+    let mut font_variation = AnimFontVariation::new("wght=100, none", "").time(100);
+    font_variation.prepare(AnimDirection::Forward);
+    font_variation.advance(100, AnimDirection::Forward);
+    assert_eq!(font_variation.read(), "wght=100");
+}
+
+#[test]
+pub fn invalid_list_values() {
+    let mut shadow = AnimShadow::new("none", "invalid, 0 0 10 0 red").time(100);
+    shadow.prepare(AnimDirection::Forward);
+    assert_eq!(shadow.read(), "");
+
+    shadow.advance(100, AnimDirection::Forward);
+    assert_eq!(shadow.read(), AnimShadow::new("0 0 10 0 red", "").read());
+    assert!(shadow.is_finished(101, AnimDirection::Forward));
+
+    let mut border = AnimBorder::new("", "1 inner red").time(100);
+    border.prepare(AnimDirection::Reverse);
+    border.advance(100, AnimDirection::Reverse);
+    assert_eq!(border.read(), "");
+}
+
+#[tokio::test]
+pub async fn animate_gradient_shadow_border() {
+    fn use_animation_app() -> Element {
+        let animation = use_animation(|_conf| {
+            (
+                AnimFill::new(
+                    "red",
+                    "linear-gradient(90deg, rgb(0, 0, 255) 0%, rgb(0, 255, 0) 100%)",
+                )
+                .time(50),
+                AnimShadow::new("0 0 10 0 red", "5 5 20 2 blue, 0 0 4 0 black").time(50),
+                AnimBorder::new("1 inner red", "4 inner blue").time(50),
+            )
+        });
+
+        let (background, shadow, border) = &*animation.get().read_unchecked();
+
+        use_hook(|| {
+            animation.start();
+        });
+
+        rsx!(rect {
+            background: "{background.read()}",
+            shadow: "{shadow.read()}",
+            border: "{border.read()}",
+        })
+    }
+
+    let mut utils = launch_test(use_animation_app);
+
+    // Disable event loop ticker
+    utils.config().event_loop_ticker = false;
+
+    utils.wait_for_update().await;
+
+    // Initial values
+    let style = utils.root().get(0).style();
+    assert_eq!(style.background, Fill::Color(Color::RED));
+    assert_eq!(style.shadows.len(), 1);
+    assert_eq!(style.borders[0].width.top, 1.0);
+
+    // Values somewhere in the middle
+    sleep(Duration::from_millis(15)).await;
+    utils.wait_for_update().await;
+
+    let style = utils.root().get(0).style();
+    let Fill::LinearGradient(gradient) = &style.background else {
+        panic!("Expected a linear gradient");
+    };
+    let first_stop = gradient.stops[0].color;
+    assert!(first_stop.r() < 255 && first_stop.b() > 0);
+    assert_eq!(style.shadows.len(), 2);
+    assert!(style.shadows[0].blur > 10.0 && style.shadows[0].blur < 20.0);
+    assert!(style.borders[0].width.top > 1.0 && style.borders[0].width.top < 4.0);
+
+    // Enable event loop ticker
+    utils.config().event_loop_ticker = true;
+
+    // Already finished
+    sleep(Duration::from_millis(50)).await;
+
+    // Values in the end
+    utils.wait_for_update().await;
+
+    let style = utils.root().get(0).style();
+    assert_eq!(
+        style.background,
+        Fill::parse("linear-gradient(90deg, rgb(0, 0, 255) 0%, rgb(0, 255, 0) 100%)").unwrap()
+    );
+    assert_eq!(style.shadows[1].fill, Fill::Color(Color::BLACK));
+    assert_eq!(style.borders[0].width.top, 4.0);
+    assert_eq!(style.borders[0].fill, Fill::Color(Color::BLUE));
+}
+
+#[tokio::test]
+pub async fn animation_clock() {
+    fn use_animation_app() -> Element {
+        let animation = use_animation(|_conf| AnimNum::new(10., 100.).time(50));
+
+        let progress = animation.get().read().read();
+
+        use_hook(|| {
+            animation.start();
+        });
+
+        rsx!(rect {
+            width: "{progress}",
+        })
+    }
+
+    let mut utils = launch_test(use_animation_app);
+
+    utils.animation_clock().pause();
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 10.0);
+
+    // Time doesn't pass while paused
+    sleep(Duration::from_millis(50)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 10.0);
+
+    // Halfway
+    utils.advance_animations(Duration::from_millis(25)).await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 55.0);
+
+    // Slow motion
+    utils.animation_clock().set_time_scale(0.1);
+    utils.animation_clock().resume();
+    sleep(Duration::from_millis(50)).await;
+    utils.animation_clock().pause();
+    utils.wait_for_update().await;
+
+    let width = utils.root().get(0).area().unwrap().width();
+    assert!(width > 55.0 && width < 100.0);
+
+    // Finished
+    utils.advance_animations(Duration::from_millis(50)).await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 100.0);
+}
@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn app_lifecycle() {
+    fn use_app_lifecycle_app() -> Element {
+        let lifecycle = use_app_lifecycle_with_idle_timeout(Duration::from_millis(200));
+        let AppLifecycle {
+            is_focused,
+            is_suspended,
+            is_idle,
+            ..
+        } = lifecycle();
+
+        rsx!(
+            label {
+                "{is_focused} {is_suspended} {is_idle}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_app_lifecycle_app);
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0);
+    assert_eq!(label.get(0).text(), Some("true false false"));
+
+    utils.set_window_focused(false);
+    utils.set_suspended(true);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("false true false"));
+
+    utils.set_window_focused(true);
+    utils.set_suspended(false);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("true false false"));
+
+    // Idle after some time without input
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("true false true"));
+
+    // Active again once the user moves the cursor
+    utils.move_cursor((50., 50.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("true false false"));
+}
@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn async_resource() {
+    fn async_resource_app() -> Element {
+        let mut id = use_signal(|| 1);
+        let resource = use_async_resource(move || {
+            let id = id();
+            async move {
+                sleep(Duration::from_millis(100)).await;
+                if id < 3 {
+                    Ok(format!("Item {id}"))
+                } else {
+                    Err("Not found")
+                }
+            }
+        });
+
+        let text = match &*resource.state().read() {
+            ResourceState::Loading => "Loading".to_string(),
+            ResourceState::Ready(item) => item.clone(),
+            ResourceState::Failed(err) => err.to_string(),
+        };
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                onclick: move |_| id += 1,
+                label {
+                    "{text}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(async_resource_app);
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0).get(0);
+    assert_eq!(label.get(0).text(), Some("Loading"));
+
+    utils.advance_time(Duration::from_millis(150)).await;
+    assert_eq!(label.get(0).text(), Some("Item 1"));
+
+    // Changing the ID cancels the previous future
+    utils.click_cursor((5., 5.)).await;
+    utils.advance_time(Duration::from_millis(50)).await;
+    assert_eq!(label.get(0).text(), Some("Loading"));
+    utils.click_cursor((5., 5.)).await;
+    utils.advance_time(Duration::from_millis(150)).await;
+    assert_eq!(label.get(0).text(), Some("Not found"));
+}
+
+#[tokio::test]
+async fn async_resource_retry() {
+    fn async_resource_retry_app() -> Element {
+        let mut attempts = use_signal(|| 0);
+        let resource = use_async_resource_with_retry(
+            RetryOptions::new()
+                .with_max_retries(3)
+                .with_delay(Duration::from_millis(100)),
+            move || {
+                *attempts.write() += 1;
+                let attempt = *attempts.peek();
+                async move {
+                    if attempt < 3 {
+                        Err(attempt)
+                    } else {
+                        Ok(attempt)
+                    }
+                }
+            },
+        );
+
+        rsx!(
+            label {
+                "{resource.value().unwrap_or_default()} after {resource.retries()} retries"
+            }
+        )
+    }
+
+    let mut utils = launch_test(async_resource_retry_app);
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0);
+    assert_eq!(label.get(0).text(), Some("0 after 0 retries"));
+
+    // First retry after 100ms, the second after 200ms more
+    utils.advance_time(Duration::from_millis(150)).await;
+    assert_eq!(label.get(0).text(), Some("0 after 1 retries"));
+
+    utils.advance_time(Duration::from_millis(200)).await;
+    assert_eq!(label.get(0).text(), Some("3 after 2 retries"));
+}
+
+#[test]
+fn retry_delay() {
+    let options = RetryOptions::new()
+        .with_delay(Duration::from_millis(100))
+        .with_backoff(2.0)
+        .with_max_delay(Duration::from_secs(1));
+
+    assert_eq!(options.delay_for(0), Duration::from_millis(100));
+    assert_eq!(options.delay_for(2), Duration::from_millis(400));
+    assert_eq!(options.delay_for(4), Duration::from_secs(1));
+    // Doesn't overflow after many retries
+    assert_eq!(options.delay_for(u32::MAX), Duration::from_secs(1));
+}
@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+async fn debounce() {
+    fn debounce_app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        let mut debounced_clicks = use_signal(|| 0);
+        let mut debounce = use_debounce(Duration::from_millis(100), move |value| {
+            debounced_clicks.set(value);
+        });
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                onclick: move |_| {
+                    clicks += 1;
+                    debounce.action(clicks());
+                },
+                label {
+                    "{debounced_clicks}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(debounce_app);
+    utils.wait_for_update().await;
+
+    // Only move the time when told to
+    utils.advance_time(Duration::ZERO).await;
+
+    let label = utils.root().get(0).get(0);
+    assert_eq!(label.get(0).text(), Some("0"));
+
+    utils.click_cursor((5., 5.)).await;
+    utils.advance_time(Duration::from_millis(50)).await;
+    utils.click_cursor((5., 5.)).await;
+    utils.advance_time(Duration::from_millis(50)).await;
+
+    // The second click restarted the wait
+    assert_eq!(label.get(0).text(), Some("0"));
+
+    utils.advance_time(Duration::from_millis(100)).await;
+    assert_eq!(label.get(0).text(), Some("2"));
+}
+
+#[tokio::test]
+async fn debounced_signal() {
+    fn debounced_signal_app() -> Element {
+        let mut value = use_signal(|| 0);
+        let debounced = use_debounced_signal(value.into(), Duration::from_millis(100));
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                onclick: move |_| value += 1,
+                label {
+                    "{debounced}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(debounced_signal_app);
+    utils.wait_for_update().await;
+
+    // Only move the time when told to
+    utils.advance_time(Duration::ZERO).await;
+
+    let label = utils.root().get(0).get(0);
+    assert_eq!(label.get(0).text(), Some("0"));
+
+    utils.click_cursor((5., 5.)).await;
+    utils.click_cursor((5., 5.)).await;
+    utils.advance_time(Duration::from_millis(50)).await;
+    assert_eq!(label.get(0).text(), Some("0"));
+
+    utils.advance_time(Duration::from_millis(100)).await;
+    assert_eq!(label.get(0).text(), Some("2"));
+}
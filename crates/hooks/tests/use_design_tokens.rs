@@ -0,0 +1,114 @@
+use freya::prelude::*;
+use freya_core::{
+    parsing::Parse,
+    values::Fill,
+};
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn design_tokens() {
+    fn design_tokens_app() -> Element {
+        let mut tokens = use_init_design_tokens(|| {
+            DesignTokens::new()
+                .with("surface", "rgb(200, 200, 200)")
+                .with("size", "50")
+        });
+
+        rsx!(rect {
+            design_tokens: tokens.read().attribute(),
+            width: "token(size)",
+            height: "token(size)",
+            background: "token(surface)",
+            onclick: move |_| {
+                let mut tokens = tokens.write();
+                tokens.insert("surface", "rgb(20, 20, 20)");
+                tokens.insert("size", "100");
+            },
+        })
+    }
+
+    let mut utils = launch_test(design_tokens_app);
+    utils.wait_for_update().await;
+
+    // The tokens are resolved from the first frame
+    let rect = utils.root().get(0);
+    assert_eq!(rect.area().unwrap().width(), 50.0);
+    assert_eq!(
+        rect.style().background,
+        Fill::Color(Color::parse("rgb(200, 200, 200)").unwrap())
+    );
+
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+
+    let rect = utils.root().get(0);
+    assert_eq!(rect.area().unwrap().width(), 100.0);
+    assert_eq!(
+        rect.style().background,
+        Fill::Color(Color::parse("rgb(20, 20, 20)").unwrap())
+    );
+}
+
+#[tokio::test]
+pub async fn scoped_design_tokens() {
+    #[component]
+    fn Card(surface: &'static str) -> Element {
+        let tokens = use_init_design_tokens(|| DesignTokens::new().with("surface", surface));
+
+        rsx!(rect {
+            design_tokens: tokens.read().attribute(),
+            width: "token(size)",
+            height: "token(size)",
+            background: "token(surface)",
+        })
+    }
+
+    fn design_tokens_app() -> Element {
+        let tokens = use_init_design_tokens(|| {
+            DesignTokens::new()
+                .with("surface", "rgb(200, 200, 200)")
+                .with("size", "50")
+        });
+
+        rsx!(
+            rect {
+                design_tokens: tokens.read().attribute(),
+                Card {
+                    surface: "red"
+                }
+                rect {
+                    width: "token(size)",
+                    height: "token(size)",
+                    background: "token(surface)",
+                }
+            }
+            rect {
+                width: "token(size)",
+                background: "token(surface)",
+            }
+        )
+    }
+
+    let mut utils = launch_test(design_tokens_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let provider = root.get(0);
+
+    // Inherits the size and overrides the surface
+    let card = provider.get(0);
+    assert_eq!(card.area().unwrap().width(), 50.0);
+    assert_eq!(card.style().background, Fill::Color(Color::RED));
+
+    // Unaffected by the tokens of its sibling
+    let sibling = provider.get(1);
+    assert_eq!(
+        sibling.style().background,
+        Fill::Color(Color::parse("rgb(200, 200, 200)").unwrap())
+    );
+
+    // Outside of the provider the tokens are not resolved
+    let outside = root.get(1);
+    assert_eq!(outside.style().background, Fill::Color(Color::TRANSPARENT));
+}
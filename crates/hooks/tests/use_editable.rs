@@ -1176,3 +1176,374 @@ pub async fn replace_text() {
         assert_eq!(cursor.text(), Some("0:7"));
     }
 }
+
+#[tokio::test]
+pub async fn multiple_cursors() {
+    fn use_editable_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("Hello Rustaceans\nHello Rustaceans".to_string()),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let editor = editable.editor().read();
+        let cursor_pos = editor.cursor_pos();
+        let cursor_reference = editable.cursor_attr();
+        let carets = editable.carets_attr(0);
+
+        let onmousedown = move |e: MouseEvent| {
+            editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+        };
+
+        let onglobalkeydown = move |e: Event<KeyboardData>| {
+            editable.process_event(&EditableEvent::KeyDown(e.data));
+        };
+
+        let onglobalkeyup = move |e: Event<KeyboardData>| {
+            editable.process_event(&EditableEvent::KeyUp(e.data));
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                paragraph {
+                    cursor_reference,
+                    height: "50%",
+                    width: "100%",
+                    cursor_id: "0",
+                    cursor_index: "{cursor_pos}",
+                    cursor_color: "black",
+                    cursor_mode: "editable",
+                    carets,
+                    onglobalkeydown,
+                    onglobalkeyup,
+                    onmousedown,
+                    text {
+                        color: "black",
+                        "{editor}"
+                    }
+                }
+                label {
+                    color: "black",
+                    height: "50%",
+                    "{editor.cursor_row()}:{editor.cursor_col()}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_editable_app);
+
+    let root = utils.root().get(0);
+    let ctrl = if cfg!(target_os = "macos") {
+        Key::Super
+    } else {
+        Key::Control
+    };
+
+    // Place the cursor in the first line
+    utils.push_event(TestEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (35.0, 3.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // Add a cursor in the second line
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: ctrl.clone(),
+        code: Code::ControlLeft,
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+    utils.push_event(TestEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (35.0, 25.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyUp,
+        key: ctrl,
+        code: Code::ControlLeft,
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(1).get(0).text(), Some("1:5"));
+    assert_eq!(root.get(0).state().cursor.carets, vec![5]);
+
+    // Insert text in both cursors
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("!".to_string()),
+        code: Code::Unidentified,
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+
+    let content = root.get(0).get(0).get(0);
+    assert_eq!(content.text(), Some("Hello! Rustaceans\nHello! Rustaceans"));
+    assert_eq!(root.get(1).get(0).text(), Some("1:6"));
+    assert_eq!(root.get(0).state().cursor.carets, vec![6]);
+
+    // Remove text in both cursors
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Backspace,
+        code: Code::Backspace,
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+
+    let content = root.get(0).get(0).get(0);
+    assert_eq!(content.text(), Some("Hello Rustaceans\nHello Rustaceans"));
+    assert_eq!(root.get(0).state().cursor.carets, vec![5]);
+
+    // Escape removes the additional cursors
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Escape,
+        code: Code::Escape,
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(0).state().cursor.carets, Vec::<usize>::new());
+}
+
+#[tokio::test]
+pub async fn multiple_cursors_undo() {
+    fn use_editable_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("Hello Rustaceans\nHello Rustaceans".to_string()),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let editor = editable.editor().read();
+        let cursor_pos = editor.cursor_pos();
+        let cursor_reference = editable.cursor_attr();
+        let carets = editable.carets_attr(0);
+
+        let onmousedown = move |e: MouseEvent| {
+            editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+        };
+
+        let onglobalkeydown = move |e: Event<KeyboardData>| {
+            editable.process_event(&EditableEvent::KeyDown(e.data));
+        };
+
+        let onglobalkeyup = move |e: Event<KeyboardData>| {
+            editable.process_event(&EditableEvent::KeyUp(e.data));
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                paragraph {
+                    cursor_reference,
+                    height: "50%",
+                    width: "100%",
+                    cursor_id: "0",
+                    cursor_index: "{cursor_pos}",
+                    cursor_color: "black",
+                    cursor_mode: "editable",
+                    carets,
+                    onglobalkeydown,
+                    onglobalkeyup,
+                    onmousedown,
+                    text {
+                        color: "black",
+                        "{editor}"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_editable_app);
+
+    let root = utils.root().get(0);
+    let (ctrl, meta_or_ctrl) = if cfg!(target_os = "macos") {
+        (Key::Super, Modifiers::META)
+    } else {
+        (Key::Control, Modifiers::CONTROL)
+    };
+
+    // Place the cursor in the first line
+    utils.push_event(TestEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (35.0, 3.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // Add a cursor in the second line
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: ctrl.clone(),
+        code: Code::ControlLeft,
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+    utils.push_event(TestEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (35.0, 25.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyUp,
+        key: ctrl,
+        code: Code::ControlLeft,
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(0).state().cursor.carets, vec![5]);
+
+    // Write in both cursors
+    for character in ["!", "?"] {
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character(character.to_string()),
+            code: Code::Unidentified,
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+    }
+
+    let content = root.get(0).get(0).get(0);
+    assert_eq!(
+        content.text(),
+        Some("Hello!? Rustaceans\nHello!? Rustaceans")
+    );
+
+    // Every undo reverts the edits of all the cursors
+    for expected in [
+        "Hello! Rustaceans\nHello! Rustaceans",
+        "Hello Rustaceans\nHello Rustaceans",
+    ] {
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("z".to_string()),
+            code: Code::KeyZ,
+            modifiers: meta_or_ctrl,
+        });
+        utils.wait_for_update().await;
+
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.text(), Some(expected));
+    }
+
+    // And redo applies them again
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("Z".to_string()),
+        code: Code::KeyZ,
+        modifiers: meta_or_ctrl | Modifiers::SHIFT,
+    });
+    utils.wait_for_update().await;
+
+    let content = root.get(0).get(0).get(0);
+    assert_eq!(content.text(), Some("Hello! Rustaceans\nHello! Rustaceans"));
+}
+
+#[tokio::test]
+pub async fn column_selection() {
+    fn use_editable_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("Hello Rustaceans\nHello Rustaceans".to_string()),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let editor = editable.editor().read();
+        let cursor_pos = editor.cursor_pos();
+        let cursor_reference = editable.cursor_attr();
+        let highlights = editable.highlights_attr(0);
+        let carets = editable.carets_attr(0);
+
+        let onmousedown = move |e: MouseEvent| {
+            editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+        };
+
+        let onmousemove = move |e: MouseEvent| {
+            editable.process_event(&EditableEvent::MouseMove(e.data, 0));
+        };
+
+        let onglobalkeydown = move |e: Event<KeyboardData>| {
+            editable.process_event(&EditableEvent::KeyDown(e.data));
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                paragraph {
+                    cursor_reference,
+                    height: "50%",
+                    width: "100%",
+                    cursor_id: "0",
+                    cursor_index: "{cursor_pos}",
+                    cursor_color: "black",
+                    cursor_mode: "editable",
+                    highlights,
+                    carets,
+                    onglobalkeydown,
+                    onmousedown,
+                    onmousemove,
+                    text {
+                        color: "black",
+                        "{editor}"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_editable_app);
+
+    let root = utils.root().get(0);
+
+    // Drag with Alt pressed from the start of the first line to the second line
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Alt,
+        code: Code::AltLeft,
+        modifiers: Modifiers::ALT,
+    });
+    utils.wait_for_update().await;
+    utils.push_event(TestEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (3.0, 3.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    utils.move_cursor((35., 25.)).await;
+    utils.wait_for_update().await;
+
+    // The same columns are selected in both lines
+    let cursor = root.get(0).state().cursor.clone();
+    assert_eq!(cursor.highlights, Some(vec![(17, 22), (0, 5)]));
+    assert_eq!(cursor.carets, vec![5]);
+
+    // Replace the selected text in both lines
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("Hi".to_string()),
+        code: Code::Unidentified,
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+
+    let content = root.get(0).get(0).get(0);
+    assert_eq!(content.text(), Some("Hi Rustaceans\nHi Rustaceans"));
+    assert_eq!(root.get(0).state().cursor.carets, vec![2]);
+}
@@ -0,0 +1,87 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+static SANSITA_SWASHED: &[u8] = include_bytes!("../../../examples/SansitaSwashed-Regular.ttf");
+
+#[tokio::test]
+pub async fn register_fonts() {
+    fn use_fonts_app() -> Element {
+        let fonts = use_fonts();
+        let mut family = use_signal(String::new);
+        let mut available = use_signal(|| false);
+        let mut error = use_signal(String::new);
+
+        let onglobalkeydown = move |e: KeyboardEvent| match &e.key {
+            Key::Character(c) if c == "r" => {
+                spawn(async move {
+                    let source = FontSource::Bytes(SANSITA_SWASHED.into());
+                    if let Ok(font) = fonts.register_as(source, "Sansita").await {
+                        available.set(fonts.is_available(&font).await);
+                        family.set(font);
+                    }
+                    if let Err(err) = fonts.register(FontSource::Bytes(vec![0u8; 8].into())).await {
+                        error.set(err.to_string());
+                    }
+                });
+            }
+            Key::Character(c) if c == "f" => {
+                fonts.set_fallbacks(
+                    FontFallbacks::empty().with_fallback(FontScript::Emoji, ["Sansita"]),
+                );
+            }
+            _ => {}
+        };
+
+        rsx!(
+            rect {
+                onglobalkeydown,
+                label {
+                    font_family: "Sansita",
+                    font_size: "30",
+                    "Hello, World!"
+                }
+                label { "{family} {available}" }
+                label { "{error}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_fonts_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+    let width = root.get(0).area().unwrap().width();
+    assert_eq!(root.get(1).get(0).text(), Some(" false"));
+
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("r".to_string()),
+        code: Code::KeyR,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(1).get(0).text(), Some("Sansita true"));
+    assert_eq!(root.get(2).get(0).text(), Some("Invalid font data"));
+    assert!(utils
+        .fonts()
+        .registered_families()
+        .contains(&"Sansita".to_string()));
+
+    // The text is measured again with the registered font
+    assert_ne!(root.get(0).area().unwrap().width(), width);
+
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("f".to_string()),
+        code: Code::KeyF,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        utils.fonts().default_fonts().last(),
+        Some(&"Sansita".to_string())
+    );
+}
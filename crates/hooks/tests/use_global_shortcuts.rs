@@ -0,0 +1,45 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn global_shortcuts() {
+    fn use_global_shortcuts_app() -> Element {
+        let shortcuts = use_global_shortcuts();
+        let mut error = use_signal(String::new);
+
+        use_hook(move || {
+            spawn(async move {
+                shortcuts.register("show", "Ctrl+Shift+Space").await.ok();
+                if let Err(err) = shortcuts.register("toggle", "Shift+Ctrl+Space").await {
+                    error.set(err.to_string());
+                }
+            });
+        });
+
+        let text = match shortcuts.event() {
+            Some(GlobalShortcutEvent { id, state }) => format!("{id} {state:?}"),
+            None => "none".to_string(),
+        };
+
+        rsx!(
+            label { "{text}" }
+            label { "{error}" }
+        )
+    }
+
+    let mut utils = launch_test(use_global_shortcuts_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(root.get(0).get(0).text(), Some("none"));
+    assert_eq!(
+        root.get(1).get(0).text(),
+        Some("Keys already used by the shortcut 'show'")
+    );
+    assert_eq!(utils.global_shortcuts().shortcuts.len(), 1);
+
+    utils.trigger_global_shortcut("show", GlobalShortcutState::Pressed);
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).get(0).text(), Some("show Pressed"));
+}
@@ -0,0 +1,88 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn history_commands() {
+    fn use_history_app() -> Element {
+        let mut values = use_signal(Vec::<usize>::new);
+        let mut history =
+            use_history_with_config::<Command>(HistoryConfig::new().with_max_depth(3));
+        let mut add = move |value: usize| {
+            history.execute(Command::new(
+                move || {
+                    values.write().pop();
+                },
+                move || values.write().push(value),
+            ));
+        };
+
+        let can_undo = history.can_undo();
+        let can_redo = history.can_redo();
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |_| add(values.read().len()),
+            }
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |_| {
+                    history.group(|_| {
+                        add(10);
+                        add(20);
+                    });
+                },
+            }
+            label {
+                onglobalkeydown: move |e: KeyboardEvent| {
+                    history.handle_keydown(&e);
+                },
+                "{values:?} {can_undo} {can_redo}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_history_app);
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(2);
+    assert_eq!(label.get(0).text(), Some("[] false false"));
+
+    utils.click_cursor((5., 5.)).await;
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(label.get(0).text(), Some("[0, 1] true false"));
+
+    // Grouped changes are undone at once
+    utils.click_cursor((5., 105.)).await;
+    assert_eq!(label.get(0).text(), Some("[0, 1, 10, 20] true false"));
+    utils
+        .press_with_modifiers(Key::Character("z".to_string()), Modifiers::CONTROL)
+        .await;
+    assert_eq!(label.get(0).text(), Some("[0, 1] true true"));
+    utils
+        .press_with_modifiers(Key::Character("y".to_string()), Modifiers::CONTROL)
+        .await;
+    assert_eq!(label.get(0).text(), Some("[0, 1, 10, 20] true false"));
+
+    // Only the last 3 changes are remembered
+    utils.click_cursor((5., 5.)).await;
+    for _ in 0..4 {
+        utils
+            .press_with_modifiers(Key::Character("z".to_string()), Modifiers::CONTROL)
+            .await;
+    }
+    assert_eq!(label.get(0).text(), Some("[0] false true"));
+
+    // A new change discards the undone ones
+    utils
+        .press_with_modifiers(
+            Key::Character("Z".to_string()),
+            Modifiers::CONTROL | Modifiers::SHIFT,
+        )
+        .await;
+    assert_eq!(label.get(0).text(), Some("[0, 1] true true"));
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(label.get(0).text(), Some("[0, 1, 2] true false"));
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "use_i18n")]
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+const EN_US: &str = r#"
+hello = Hello, {$name}!
+apples = {$count ->
+    [one] One apple
+   *[other] {$count} apples
+}
+only_english = Only in English
+"#;
+
+const ES_ES: &str = r#"
+hello = Hola, {$name}!
+apples = {$count ->
+    [one] Una manzana
+   *[other] {$count} manzanas
+}
+"#;
+
+#[tokio::test]
+pub async fn translate() {
+    fn use_i18n_app() -> Element {
+        use_init_i18n(|| {
+            I18nConfig::new(langid!("en-US"))
+                .with_locale(Locale::new_static(langid!("en-US"), EN_US))
+                .with_locale(Locale::new_static(langid!("es-ES"), ES_ES))
+        });
+        let mut i18n = use_i18n();
+
+        rsx!(
+            rect {
+                onclick: move |_| i18n.set_language(langid!("es-MX")),
+                label { {t!("hello", name: "Freya")} }
+                label { {t!("apples", count: 1)} }
+                label { {t!("apples", count: 3)} }
+                label { {t!("only_english")} }
+                label { {t!("missing")} }
+                label { "{i18n.language()}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_i18n_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+
+    // Arguments are isolated so they are shaped in their own direction
+    assert_eq!(
+        root.get(0).get(0).text(),
+        Some("Hello, \u{2068}Freya\u{2069}!")
+    );
+    assert_eq!(root.get(1).get(0).text(), Some("One apple"));
+    assert_eq!(root.get(2).get(0).text(), Some("\u{2068}3\u{2069} apples"));
+    assert_eq!(root.get(3).get(0).text(), Some("Only in English"));
+    assert_eq!(root.get(4).get(0).text(), Some("missing"));
+    assert_eq!(root.get(5).get(0).text(), Some("en-US"));
+
+    // The closest available language is selected
+    utils.click_cursor((5., 5.)).await;
+
+    assert_eq!(
+        root.get(0).get(0).text(),
+        Some("Hola, \u{2068}Freya\u{2069}!")
+    );
+    assert_eq!(root.get(1).get(0).text(), Some("Una manzana"));
+    assert_eq!(
+        root.get(2).get(0).text(),
+        Some("\u{2068}3\u{2069} manzanas")
+    );
+    // Missing messages fall back to the fallback language
+    assert_eq!(root.get(3).get(0).text(), Some("Only in English"));
+    assert_eq!(root.get(5).get(0).text(), Some("es-ES"));
+}
+
+#[tokio::test]
+pub async fn format_date() {
+    fn use_i18n_app() -> Element {
+        use_init_i18n(|| {
+            I18nConfig::new(langid!("en-US"))
+                .with_locale(Locale::new_static(langid!("en-US"), EN_US))
+                .with_locale(Locale::new_static(langid!("es-ES"), ES_ES))
+        });
+        let mut i18n = use_i18n();
+        let date = CalendarDate::try_new_iso_date(2024, 1, 15).unwrap();
+
+        rsx!(
+            rect {
+                onclick: move |_| i18n.set_language(langid!("es-ES")),
+                label { {i18n.format_date(&date, DateLength::Long)} }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_i18n_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+    assert_eq!(root.get(0).get(0).text(), Some("January 15, 2024"));
+
+    // Dates are formatted again in the new language
+    utils.click_cursor((5., 5.)).await;
+
+    assert_eq!(root.get(0).get(0).text(), Some("15 de enero de 2024"));
+}
+
+#[test]
+pub fn negotiate() {
+    let available = [langid!("en-US"), langid!("es-ES")];
+
+    assert_eq!(
+        negotiate_language(&[langid!("es-MX")], &available, &langid!("en-US")),
+        langid!("es-ES")
+    );
+    assert_eq!(
+        negotiate_language(&[langid!("fr-FR")], &available, &langid!("en-US")),
+        langid!("en-US")
+    );
+}
@@ -0,0 +1,51 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn menu_events() {
+    fn use_menu_app() -> Element {
+        let menu = use_menu();
+
+        use_hook(|| {
+            menu.set_menu(Some(
+                NativeMenu::new().with_submenu(
+                    NativeSubmenu::new("File")
+                        .with_item(NativeMenuItem::new("save", "Save").accelerator("CmdOrCtrl+S"))
+                        .with_item(NativeMenuItem::new("autosave", "Autosave").checkable(false)),
+                ),
+            ))
+        });
+
+        let text = match menu.event() {
+            Some(NativeMenuEvent { id, checked }) => format!("{id} {checked:?}"),
+            None => "none".to_string(),
+        };
+
+        rsx!(label {
+            onclick: move |_| menu.set_enabled("save", false),
+            "{text}"
+        })
+    }
+
+    let mut utils = launch_test(use_menu_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(root.get(0).get(0).text(), Some("none"));
+    assert_eq!(
+        utils.native_menu().unwrap().item("save").unwrap().label,
+        "Save"
+    );
+
+    utils.activate_menu_item("save");
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).get(0).text(), Some("save None"));
+
+    // Checkable items toggle when activated
+    utils.activate_menu_item("autosave");
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).get(0).text(), Some("autosave Some(true)"));
+
+    utils.click_cursor((5., 5.)).await;
+    assert!(!utils.native_menu().unwrap().item("save").unwrap().enabled);
+}
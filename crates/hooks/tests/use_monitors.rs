@@ -0,0 +1,48 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn monitors() {
+    fn use_monitors_app() -> Element {
+        let monitors = use_monitors();
+        let monitors = monitors.read();
+        let current = monitors
+            .current()
+            .and_then(|monitor| monitor.name.clone())
+            .unwrap_or_default();
+
+        rsx!(
+            label {
+                "{monitors.monitors.len()} {current}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_monitors_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(root.get(0).get(0).text(), Some("1 Test Monitor"));
+
+    // Connect a second monitor and move the window to it
+    let first_monitor = MonitorInfo {
+        name: Some("Test Monitor".to_string()),
+        size: (500., 500.).into(),
+        ..Default::default()
+    };
+    let second_monitor = MonitorInfo {
+        name: Some("Second Monitor".to_string()),
+        size: (1920., 1080.).into(),
+        position: (500., 0.).into(),
+        scale_factor: 2.,
+        refresh_rate: Some(144.),
+    };
+    utils.set_monitors(Monitors {
+        monitors: vec![first_monitor, second_monitor],
+        current: Some(1),
+        primary: Some(0),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(0).get(0).text(), Some("2 Second Monitor"));
+}
@@ -0,0 +1,39 @@
+use std::path::PathBuf;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn open_requests() {
+    fn use_open_requests_app() -> Element {
+        let mut opened = use_signal(Vec::<String>::new);
+
+        use_open_requests(move |request| {
+            let text = match request {
+                OpenRequest::Url(url) => url,
+                OpenRequest::File(path) => path.to_string_lossy().to_string(),
+            };
+            opened.write().push(text);
+        });
+
+        rsx!(
+            label {
+                "{opened.read().join(\",\")}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_open_requests_app);
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0);
+    assert_eq!(label.get(0).text(), Some(""));
+
+    utils.send_open_request(OpenRequest::Url("myapp://settings".to_string()));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("myapp://settings"));
+
+    utils.send_open_request(OpenRequest::File(PathBuf::from("notes.txt")));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("myapp://settings,notes.txt"));
+}
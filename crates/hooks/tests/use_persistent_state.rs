@@ -0,0 +1,121 @@
+#![cfg(feature = "use_persistent_state")]
+
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+thread_local! {
+    static STORAGE: MemoryStorage = MemoryStorage::default();
+}
+
+fn storage() -> MemoryStorage {
+    STORAGE.with(|storage| storage.clone())
+}
+
+#[tokio::test]
+async fn persistent_state() {
+    fn persistent_state_app() -> Element {
+        let mut count = use_persistent_state_with_config(
+            || {
+                PersistentStateConfig::new("count")
+                    .with_storage(storage())
+                    .with_write_delay(Duration::from_millis(100))
+            },
+            || 0,
+        );
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                onclick: move |_| count += 1,
+                label {
+                    "{count}"
+                }
+            }
+        )
+    }
+
+    storage().set("count", r#"{ "version": 0, "value": 5 }"#);
+
+    let mut utils = launch_test(persistent_state_app);
+    utils.wait_for_update().await;
+
+    // Only move the time when told to
+    utils.advance_time(Duration::ZERO).await;
+
+    // The saved value is loaded
+    let label = utils.root().get(0).get(0);
+    assert_eq!(label.get(0).text(), Some("5"));
+
+    utils.click_cursor((5., 5.)).await;
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(label.get(0).text(), Some("7"));
+
+    // Not saved until the write delay passes
+    utils.advance_time(Duration::from_millis(50)).await;
+    assert!(storage().get("count").unwrap().contains('5'));
+
+    utils.advance_time(Duration::from_millis(100)).await;
+    let saved: serde_json::Value = serde_json::from_str(&storage().get("count").unwrap()).unwrap();
+    assert_eq!(saved, serde_json::json!({ "version": 0, "value": 7 }));
+}
+
+#[tokio::test]
+async fn persistent_state_migration() {
+    fn persistent_state_migration_app() -> Element {
+        let name = use_persistent_state_with_config(
+            || {
+                PersistentStateConfig::new("name")
+                    .with_storage(storage())
+                    .with_version(2)
+                    .with_migration(0, |value| format!("{}", value.as_u64().unwrap()).into())
+                    .with_migration(1, |value| {
+                        format!("User {}", value.as_str().unwrap()).into()
+                    })
+            },
+            String::new,
+        );
+
+        rsx!(
+            label {
+                "{name}"
+            }
+        )
+    }
+
+    storage().set("name", r#"{ "version": 0, "value": 42 }"#);
+
+    let mut utils = launch_test(persistent_state_migration_app);
+    utils.wait_for_update().await;
+
+    // Migrated from a number to a string and then prefixed
+    let label = utils.root().get(0);
+    assert_eq!(label.get(0).text(), Some("User 42"));
+}
+
+#[tokio::test]
+async fn persistent_state_unknown_version() {
+    fn persistent_state_unknown_version_app() -> Element {
+        let value = use_persistent_state_with_config(
+            || PersistentStateConfig::new("future").with_storage(storage()),
+            || "default".to_string(),
+        );
+
+        rsx!(
+            label {
+                "{value}"
+            }
+        )
+    }
+
+    storage().set("future", r#"{ "version": 3, "value": "from the future" }"#);
+
+    let mut utils = launch_test(persistent_state_unknown_version_app);
+    utils.wait_for_update().await;
+
+    // States saved by newer versions of the app are ignored
+    let label = utils.root().get(0);
+    assert_eq!(label.get(0).text(), Some("default"));
+}
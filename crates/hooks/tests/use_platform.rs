@@ -0,0 +1,267 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn taskbar_progress() {
+    fn use_platform_app() -> Element {
+        let platform = use_platform();
+        let mut progress = use_signal(|| 0.5);
+
+        use_effect(move || {
+            platform.set_taskbar_progress(Some(TaskbarProgress::Normal(progress())));
+        });
+
+        rsx!(rect {
+            onclick: move |_| progress += 0.25,
+            width: "100%",
+            height: "100%",
+        })
+    }
+
+    let mut utils = launch_test(use_platform_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.taskbar_progress(), Some(TaskbarProgress::Normal(0.5)));
+
+    utils.click_cursor((100., 100.)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        utils.taskbar_progress(),
+        Some(TaskbarProgress::Normal(0.75))
+    );
+}
+
+#[tokio::test]
+pub async fn window_effect() {
+    fn use_platform_app() -> Element {
+        let platform = use_platform();
+        let mut enabled = use_signal(|| true);
+
+        use_effect(move || {
+            platform.set_window_effect(
+                enabled().then_some(WindowEffect::Vibrancy(VibrancyMaterial::Sidebar)),
+            );
+        });
+
+        rsx!(rect {
+            onclick: move |_| enabled.toggle(),
+            width: "100%",
+            height: "100%",
+        })
+    }
+
+    let mut utils = launch_test(use_platform_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        utils.window_effect(),
+        Some(WindowEffect::Vibrancy(VibrancyMaterial::Sidebar))
+    );
+
+    utils.click_cursor((100., 100.)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.window_effect(), None);
+}
+
+#[tokio::test]
+pub async fn export() {
+    fn use_platform_app() -> Element {
+        let platform = use_platform();
+        let snapshot = use_node_snapshot();
+        let mut result = use_signal(String::new);
+
+        let onclick = {
+            let snapshot = snapshot.clone();
+            move |_| {
+                let snapshot = snapshot.clone();
+                spawn(async move {
+                    let pdf = platform
+                        .export_bytes(ExportTarget::Window, ExportFormat::Pdf)
+                        .await
+                        .unwrap();
+                    let svg = platform
+                        .export_bytes(&snapshot, ExportFormat::Svg)
+                        .await
+                        .unwrap();
+                    let svg = String::from_utf8_lossy(&svg);
+                    result.set(format!(
+                        "{} {}",
+                        pdf.starts_with(b"%PDF"),
+                        svg.contains("<svg") && svg.contains("width=\"100\"")
+                    ));
+                });
+            }
+        };
+
+        rsx!(
+            rect {
+                reference: snapshot.attribute(),
+                onclick,
+                width: "100",
+                height: "50",
+                background: "red",
+            }
+            label { "{result}" }
+        )
+    }
+
+    let mut utils = launch_test(use_platform_app);
+    utils.wait_for_update().await;
+
+    utils.click_cursor((10., 10.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(1).get(0).text(), Some("true true"));
+}
+
+#[tokio::test]
+pub async fn print() {
+    fn use_platform_app() -> Element {
+        let platform = use_platform();
+        let document = use_node_snapshot();
+        let mut result = use_signal(String::new);
+
+        let onclick = {
+            let document = document.clone();
+            move |_| {
+                let document = document.clone();
+                spawn(async move {
+                    let printers = platform.printers().await;
+                    let pages = platform
+                        .print(&document, PrintOptions::new().with_printer("Office"))
+                        .await;
+                    let missing = platform
+                        .print(&document, PrintOptions::new().with_printer("Home"))
+                        .await;
+                    result.set(format!("{} {pages:?} {missing:?}", printers.len()));
+                });
+            }
+        };
+
+        rsx!(
+            rect {
+                reference: document.attribute(),
+                onclick,
+                width: "100",
+                rect {
+                    height: "50",
+                    background: "red",
+                }
+                rect {
+                    page_break: "before",
+                    height: "50",
+                    background: "blue",
+                }
+            }
+            label { "{result}" }
+        )
+    }
+
+    let mut utils = launch_test(use_platform_app);
+    utils.set_printers(vec![Printer {
+        name: "Office".to_string(),
+        is_default: true,
+    }]);
+    utils.wait_for_update().await;
+
+    utils.click_cursor((10., 10.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        utils.root().get(1).get(0).text(),
+        Some("1 Ok(2) Err(PrinterNotFound(\"Home\"))")
+    );
+    assert_eq!(utils.print_jobs().len(), 1);
+    assert_eq!(utils.print_jobs()[0].pages, 2);
+}
+
+#[tokio::test]
+pub async fn close_request() {
+    fn use_platform_app() -> Element {
+        let mut confirmed = use_signal(|| false);
+        let mut requests = use_signal(|| 0);
+
+        use_close_request(move || {
+            requests += 1;
+            confirmed()
+        });
+
+        rsx!(
+            rect {
+                onclick: move |_| confirmed.set(true),
+                width: "100%",
+                height: "50%",
+            }
+            label {
+                "{requests}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_platform_app);
+    utils.wait_for_update().await;
+
+    // The handler keeps the window open
+    utils.request_close();
+    utils.wait_for_update().await;
+    assert!(!utils.is_window_closed());
+    assert_eq!(utils.root().get(1).get(0).text(), Some("1"));
+
+    utils.click_cursor((100., 100.)).await;
+    utils.request_close();
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert!(utils.is_window_closed());
+    assert_eq!(utils.root().get(1).get(0).text(), Some("2"));
+}
+
+#[tokio::test]
+pub async fn open() {
+    fn use_platform_app() -> Element {
+        let platform = use_platform();
+        let mut result = use_signal(String::new);
+
+        let onclick = move |_| {
+            spawn(async move {
+                let url = platform.open_url("https://freyaui.dev").await;
+                let relative_url = platform.open_url("freyaui.dev").await;
+                let missing = platform.reveal_in_file_manager("missing.txt").await;
+                result.set(format!("{url:?} {relative_url:?} {missing:?}"));
+            });
+        };
+
+        rsx!(
+            rect {
+                onclick,
+                width: "100",
+                height: "100",
+            }
+            label { "{result}" }
+        )
+    }
+
+    let mut utils = launch_test(use_platform_app);
+    utils.wait_for_update().await;
+
+    utils.click_cursor((10., 10.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        utils.root().get(1).get(0).text(),
+        Some("Ok(()) Err(InvalidUrl(\"freyaui.dev\")) Err(NotFound(\"missing.txt\"))")
+    );
+    assert_eq!(
+        utils.opened(),
+        &[OpenTarget::Url("https://freyaui.dev".to_string())]
+    );
+}
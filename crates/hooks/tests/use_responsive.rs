@@ -0,0 +1,53 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+async fn responsive() {
+    fn responsive_app() -> Element {
+        let breakpoint = use_responsive();
+
+        rsx!(
+            label {
+                "{breakpoint}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(responsive_app);
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0);
+    assert_eq!(label.get(0).text(), Some("xs"));
+
+    utils.resize((700., 500.).into());
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("sm"));
+
+    utils.resize((1100., 500.).into());
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("lg"));
+}
+
+#[tokio::test]
+async fn custom_breakpoints() {
+    fn custom_breakpoints_app() -> Element {
+        use_init_breakpoints(|| Breakpoints::new().with_sm(300.).with_md(450.));
+        let breakpoint = use_responsive();
+
+        rsx!(
+            label {
+                "{breakpoint} {breakpoint().is_above(Breakpoint::Sm)}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(custom_breakpoints_app);
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0);
+    assert_eq!(label.get(0).text(), Some("md true"));
+
+    utils.resize((200., 500.).into());
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("xs false"));
+}
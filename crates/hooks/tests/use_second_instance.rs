@@ -0,0 +1,35 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn second_instance() {
+    fn use_second_instance_app() -> Element {
+        let mut launches = use_signal(Vec::<String>::new);
+
+        use_second_instance(move |args| {
+            launches
+                .write()
+                .push(args.join(" ".as_ref()).to_string_lossy().into_owned())
+        });
+
+        rsx!(
+            label {
+                "{launches.read().join(\",\")}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_second_instance_app);
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0);
+    assert_eq!(label.get(0).text(), Some(""));
+
+    utils.launch_second_instance(&[]);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some(""));
+
+    utils.launch_second_instance(&["--new-tab", "notes.txt"]);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some(",--new-tab notes.txt"));
+}
@@ -0,0 +1,41 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn soft_keyboard() {
+    fn use_soft_keyboard_app() -> Element {
+        let soft_keyboard = use_soft_keyboard();
+
+        rsx!(
+            rect {
+                onclick: move |_| soft_keyboard.show(),
+                width: "100%",
+                height: "100%",
+                label {
+                    "{soft_keyboard.is_visible()} {soft_keyboard.inset()}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_soft_keyboard_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("false 0"));
+
+    // The platform reports the area covered by the keyboard
+    utils.set_soft_keyboard(SoftKeyboard {
+        visible: false,
+        inset: 300.,
+    });
+    utils.wait_for_update().await;
+
+    // There is no inset while it is hidden
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("false 0"));
+
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("true 300"));
+}
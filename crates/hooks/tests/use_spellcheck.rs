@@ -0,0 +1,106 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn press(utils: &mut TestingHandler<()>, key: &str) {
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character(key.to_string()),
+        code: Code::Unidentified,
+        modifiers: Modifiers::default(),
+    });
+}
+
+#[tokio::test]
+pub async fn spellcheck() {
+    fn use_spellcheck_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("Helo wrld, don't 2nd".to_string()),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let mut spellcheck = use_spellcheck(editable, || {
+            WordListSpellChecker::new(["hello", "world", "word", "don't"])
+        });
+
+        let cursor_attr = editable.cursor_attr();
+        let spelling_errors = spellcheck.errors_attr(0);
+        let editor = editable.editor().read();
+        let cursor_pos = editor.cursor_pos();
+        let suggestions = spellcheck
+            .error_at_cursor()
+            .map(|error| spellcheck.suggestions(&error).join(","))
+            .unwrap_or_default();
+
+        let onglobalkeydown = move |e: KeyboardEvent| match &e.key {
+            Key::Character(c) if c == "c" => {
+                if let Some(error) = spellcheck.error_at_cursor() {
+                    let suggestion = spellcheck.suggestions(&error).remove(0);
+                    spellcheck.correct(&error, &suggestion);
+                }
+            }
+            Key::Character(c) if c == "m" => editable.editor_mut().write().set_cursor_pos(7),
+            Key::Character(c) if c == "d" => spellcheck.set_enabled(false),
+            Key::Character(c) if c == "z" => editable.undo(),
+            _ => {}
+        };
+
+        rsx!(
+            rect {
+                onglobalkeydown,
+                paragraph {
+                    cursor_reference: cursor_attr,
+                    cursor_id: "0",
+                    cursor_index: "{cursor_pos}",
+                    cursor_mode: "editable",
+                    spelling_errors,
+                    text {
+                        "{editor}"
+                    }
+                }
+                label {
+                    "{suggestions}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_spellcheck_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+
+    // Words with apostrophes are checked as a whole and words with digits are ignored
+    assert_eq!(
+        root.get(0).state().cursor.spelling_errors,
+        vec![(0, 4), (5, 9)]
+    );
+
+    // The cursor is in the first misspelled word
+    assert_eq!(root.get(1).get(0).text(), Some("Hello"));
+
+    // Correct it
+    press(&mut utils, "c");
+    utils.wait_for_update().await;
+    assert_eq!(
+        root.get(0).get(0).get(0).text(),
+        Some("Hello wrld, don't 2nd")
+    );
+    assert_eq!(root.get(0).state().cursor.spelling_errors, vec![(6, 10)]);
+
+    // Suggestions of the second misspelled word
+    press(&mut utils, "m");
+    utils.wait_for_update().await;
+    assert_eq!(root.get(1).get(0).text(), Some("world,word"));
+
+    // Undo the correction
+    press(&mut utils, "z");
+    utils.wait_for_update().await;
+    assert_eq!(
+        root.get(0).get(0).get(0).text(),
+        Some("Helo wrld, don't 2nd")
+    );
+
+    // Stop checking
+    press(&mut utils, "d");
+    utils.wait_for_update().await;
+    assert!(root.get(0).state().cursor.spelling_errors.is_empty());
+}
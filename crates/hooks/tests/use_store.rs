@@ -0,0 +1,65 @@
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[derive(Default)]
+struct AppState {
+    user: String,
+    count: usize,
+}
+
+#[tokio::test]
+async fn store_selector() {
+    static COUNTER_RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+    #[component]
+    fn Counter() -> Element {
+        let count = use_store_selector(|state: &AppState| state.count);
+        COUNTER_RENDERS.fetch_add(1, Ordering::Relaxed);
+
+        rsx!(
+            label {
+                "{count}"
+            }
+        )
+    }
+
+    fn store_selector_app() -> Element {
+        let mut store = create_store(AppState::default);
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |_| store.update(|state| state.user.push('a')),
+            }
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |_| store.update(|state| state.count += 1),
+            }
+            Counter {}
+        )
+    }
+
+    let mut utils = launch_test(store_selector_app);
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(2);
+    assert_eq!(label.get(0).text(), Some("0"));
+    assert_eq!(COUNTER_RENDERS.load(Ordering::Relaxed), 1);
+
+    // Changing the user doesn't re-render the counter
+    utils.click_cursor((5., 5.)).await;
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(COUNTER_RENDERS.load(Ordering::Relaxed), 1);
+
+    // Changing the count does
+    utils.click_cursor((5., 105.)).await;
+    assert_eq!(label.get(0).text(), Some("1"));
+    assert_eq!(COUNTER_RENDERS.load(Ordering::Relaxed), 2);
+}
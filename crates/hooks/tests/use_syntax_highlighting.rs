@@ -0,0 +1,150 @@
+#![cfg(feature = "use_syntax_highlighting")]
+
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+fn use_syntax_highlighting_app() -> Element {
+    let mut editable = use_editable(
+        || EditableConfig::new("fn main() {}\n// Hello".to_string()),
+        EditableMode::SingleLineMultipleEditors,
+    );
+    let highlights = use_syntax_highlighting(
+        || {
+            SyntaxLanguage::new(
+                tree_sitter_rust::LANGUAGE,
+                tree_sitter_rust::HIGHLIGHTS_QUERY,
+            )
+            .unwrap()
+        },
+        editable,
+    );
+
+    let onglobalkeydown = move |e: KeyboardEvent| {
+        editable.process_event(&EditableEvent::KeyDown(e.data));
+    };
+
+    rsx!(
+        rect {
+            onglobalkeydown,
+            for (line_idx, line) in highlights.read().lines().iter().enumerate() {
+                paragraph {
+                    key: "{line_idx}",
+                    for (segment, style) in line.segments() {
+                        text {
+                            color: style.color.as_deref().unwrap_or("white"),
+                            "{segment}"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[tokio::test]
+pub async fn syntax_highlighting() {
+    let mut utils = launch_test(use_syntax_highlighting_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+    let keyword = Color::from_rgb(197, 134, 192);
+    let comment = Color::from_rgb(106, 153, 85);
+
+    // Every line is highlighted
+    assert_eq!(root.children_ids().len(), 2);
+    let first_line = root.get(0);
+    assert_eq!(first_line.get(0).get(0).text(), Some("fn"));
+    assert_eq!(first_line.get(0).state().font_style.color, keyword);
+    let second_line = root.get(1);
+    assert_eq!(second_line.get(0).get(0).text(), Some("// Hello"));
+    assert_eq!(second_line.get(0).state().font_style.color, comment);
+
+    // Write at the start of the text
+    for c in ["p", "u", "b", " "] {
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character(c.to_string()),
+            code: Code::Unidentified,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+    }
+
+    // Only the edited line changed
+    let first_line = root.get(0);
+    assert_eq!(first_line.get(0).get(0).text(), Some("pub"));
+    assert_eq!(first_line.get(0).state().font_style.color, keyword);
+    assert_eq!(first_line.get(2).get(0).text(), Some("fn"));
+    assert_eq!(first_line.get(2).state().font_style.color, keyword);
+    let second_line = root.get(1);
+    assert_eq!(second_line.get(0).get(0).text(), Some("// Hello"));
+    assert_eq!(second_line.get(0).state().font_style.color, comment);
+}
+
+#[tokio::test]
+pub async fn incremental_edits() {
+    let mut utils = launch_test(use_syntax_highlighting_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+    let keyword = Color::from_rgb(197, 134, 192);
+    let comment = Color::from_rgb(106, 153, 85);
+    let meta_or_ctrl = if cfg!(target_os = "macos") {
+        Modifiers::META
+    } else {
+        Modifiers::CONTROL
+    };
+
+    // Break the first line, the lines after it are moved
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Enter,
+        code: Code::Enter,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(root.children_ids().len(), 3);
+    let second_line = root.get(1);
+    assert_eq!(second_line.get(0).get(0).text(), Some("fn"));
+    assert_eq!(second_line.get(0).state().font_style.color, keyword);
+    let third_line = root.get(2);
+    assert_eq!(third_line.get(0).get(0).text(), Some("// Hello"));
+    assert_eq!(third_line.get(0).state().font_style.color, comment);
+
+    // Undoing is an edit too
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("z".to_string()),
+        code: Code::KeyZ,
+        modifiers: meta_or_ctrl,
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(root.children_ids().len(), 2);
+    let first_line = root.get(0);
+    assert_eq!(first_line.get(0).get(0).text(), Some("fn"));
+    assert_eq!(first_line.get(0).state().font_style.color, keyword);
+    let second_line = root.get(1);
+    assert_eq!(second_line.get(0).get(0).text(), Some("// Hello"));
+    assert_eq!(second_line.get(0).state().font_style.color, comment);
+}
+
+#[test]
+pub fn highlight_whole_text() {
+    let highlights = SyntaxLanguage::new(
+        tree_sitter_rust::LANGUAGE,
+        tree_sitter_rust::HIGHLIGHTS_QUERY,
+    )
+    .unwrap()
+    .highlight("let a = 1;\n// Hello");
+
+    assert_eq!(highlights.lines().len(), 2);
+    let (segment, style) = highlights.line(0).unwrap().segments().next().unwrap();
+    assert_eq!(segment, "let");
+    assert_eq!(style.color.as_deref(), Some("rgb(197, 134, 192)"));
+    let (segment, style) = highlights.line(1).unwrap().segments().next().unwrap();
+    assert_eq!(segment, "// Hello");
+    assert!(style.italic);
+}
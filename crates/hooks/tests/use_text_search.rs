@@ -0,0 +1,171 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn press(utils: &mut TestingHandler<()>, key: &str) {
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character(key.to_string()),
+        code: Code::Unidentified,
+        modifiers: Modifiers::default(),
+    });
+}
+
+#[tokio::test]
+pub async fn find_and_replace() {
+    fn use_text_search_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("Hello World\nhello Rust\nHELLO".to_string()),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let mut search = use_text_search(editable);
+
+        let cursor_attr = editable.cursor_attr();
+        let match_highlights = search.matches_attr(0);
+        let editor = editable.editor().read();
+        let cursor_pos = editor.cursor_pos();
+        let current = search
+            .current()
+            .map(|current| current.to_string())
+            .unwrap_or_default();
+        let matches = search.matches().len();
+
+        let onglobalkeydown = move |e: KeyboardEvent| match &e.key {
+            Key::Character(c) if c == "f" => search.set_query("hello"),
+            Key::Character(c) if c == "c" => search.set_case_sensitive(true),
+            Key::Character(c) if c == "n" => search.next(),
+            Key::Character(c) if c == "p" => search.previous(),
+            Key::Character(c) if c == "r" => search.replace("Hola"),
+            Key::Character(c) if c == "a" => {
+                search.replace_all("Hi");
+            }
+            Key::Character(c) if c == "z" => editable.undo(),
+            _ => {}
+        };
+
+        rsx!(
+            rect {
+                onglobalkeydown,
+                paragraph {
+                    cursor_reference: cursor_attr,
+                    cursor_id: "0",
+                    cursor_index: "{cursor_pos}",
+                    cursor_mode: "editable",
+                    match_highlights,
+                    text {
+                        "{editor}"
+                    }
+                }
+                label {
+                    "{current}/{matches}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_text_search_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+
+    // Nothing is searched yet
+    assert_eq!(root.get(1).get(0).text(), Some("/0"));
+    assert!(root.get(0).state().cursor.match_highlights.is_empty());
+
+    // Search ignoring the case
+    press(&mut utils, "f");
+    utils.wait_for_update().await;
+    assert_eq!(root.get(1).get(0).text(), Some("/3"));
+    assert_eq!(
+        root.get(0).state().cursor.match_highlights,
+        vec![(0, 5), (12, 17), (23, 28)]
+    );
+
+    // Move between the matches
+    press(&mut utils, "n");
+    utils.wait_for_update().await;
+    assert_eq!(root.get(1).get(0).text(), Some("0/3"));
+    press(&mut utils, "n");
+    utils.wait_for_update().await;
+    assert_eq!(root.get(1).get(0).text(), Some("1/3"));
+    press(&mut utils, "p");
+    utils.wait_for_update().await;
+    assert_eq!(root.get(1).get(0).text(), Some("0/3"));
+    press(&mut utils, "p");
+    utils.wait_for_update().await;
+    assert_eq!(root.get(1).get(0).text(), Some("2/3"));
+
+    // Replace the selected match, the next one is selected
+    press(&mut utils, "r");
+    utils.wait_for_update().await;
+    assert_eq!(
+        root.get(0).get(0).get(0).text(),
+        Some("Hello World\nhello Rust\nHola")
+    );
+    assert_eq!(root.get(1).get(0).text(), Some("0/2"));
+
+    // Only search with the same case
+    press(&mut utils, "c");
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).state().cursor.match_highlights, vec![(12, 17)]);
+
+    // Replace all the matches
+    press(&mut utils, "a");
+    utils.wait_for_update().await;
+    assert_eq!(
+        root.get(0).get(0).get(0).text(),
+        Some("Hello World\nHi Rust\nHola")
+    );
+    assert_eq!(root.get(1).get(0).text(), Some("/0"));
+
+    // Undo the replacement
+    press(&mut utils, "z");
+    utils.wait_for_update().await;
+    assert_eq!(
+        root.get(0).get(0).get(0).text(),
+        Some("Hello World\nhello Rust\nHola")
+    );
+}
+
+#[tokio::test]
+pub async fn replace_all_is_undone_at_once() {
+    fn use_text_search_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("a-a-a".to_string()),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let mut search = use_text_search(editable);
+        let editor = editable.editor().read();
+
+        let onglobalkeydown = move |e: KeyboardEvent| match &e.key {
+            Key::Character(c) if c == "a" => {
+                search.set_query("a");
+                search.replace_all("bb");
+            }
+            Key::Character(c) if c == "z" => editable.undo(),
+            _ => {}
+        };
+
+        rsx!(
+            label {
+                onglobalkeydown,
+                "{editor}:{editor.cursor_pos()}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_text_search_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+    assert_eq!(root.get(0).text(), Some("a-a-a:0"));
+
+    // The cursor is placed after the last replacement
+    press(&mut utils, "a");
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).text(), Some("bb-bb-bb:8"));
+
+    // Every replacement is undone
+    press(&mut utils, "z");
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).text(), Some("a-a-a:5"));
+}
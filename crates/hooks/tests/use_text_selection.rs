@@ -0,0 +1,129 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn text_selection() {
+    fn use_text_selection_app() -> Element {
+        let text_selection = use_text_selection();
+
+        rsx!(
+            rect {
+                text_selection: "enabled",
+                height: "100",
+                label {
+                    height: "50",
+                    "Hello"
+                }
+                label {
+                    height: "50",
+                    "World"
+                }
+            }
+            label {
+                "{text_selection.text().unwrap_or_default()}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_text_selection_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let selected_label = root.get(1);
+    assert_eq!(selected_label.get(0).text(), Some(""));
+
+    // Drag from the start of the first label to the end of the second one
+    utils.push_event(TestEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (1., 5.).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.move_cursor((490., 75.)).await;
+    utils.push_event(TestEvent::Mouse {
+        name: EventName::MouseUp,
+        cursor: (490., 75.).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(selected_label.get(0).text(), Some("Hello\nWorld"));
+
+    // Clicking outside of the selectable texts removes the selection
+    utils.click_cursor((5., 250.)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(selected_label.get(0).text(), Some(""));
+}
+
+#[tokio::test]
+pub async fn text_selection_with_focused_input() {
+    fn text_selection_with_focused_input_app() -> Element {
+        let mut text_selection = use_text_selection();
+        let mut value = use_signal(String::new);
+        let mut copied = use_signal(|| None::<bool>);
+
+        rsx!(
+            rect {
+                text_selection: "enabled",
+                height: "50",
+                label {
+                    height: "50",
+                    "Hello"
+                }
+            }
+            Input {
+                value,
+                onchange: move |new_value| value.set(new_value),
+            }
+            label {
+                "{copied:?}"
+            }
+            rect {
+                onglobalkeydown: move |e: KeyboardEvent| {
+                    if e.modifiers.contains(Modifiers::CONTROL) {
+                        copied.set(Some(text_selection.copy()));
+                    }
+                },
+            }
+        )
+    }
+
+    let mut utils = launch_test(text_selection_with_focused_input_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let copied_label = root.get(2);
+
+    // Select the label
+    utils.push_event(TestEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (1., 5.).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.move_cursor((490., 25.)).await;
+    utils.push_event(TestEvent::Mouse {
+        name: EventName::MouseUp,
+        cursor: (490., 25.).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    utils
+        .press_with_modifiers(Key::Character("c".to_string()), Modifiers::CONTROL)
+        .await;
+    utils.wait_for_update().await;
+    assert_eq!(copied_label.get(0).text(), Some("Some(true)"));
+
+    // The focused input copies its own selection instead
+    utils.press(Key::Tab).await;
+    utils.wait_for_update().await;
+    utils
+        .press_with_modifiers(Key::Character("c".to_string()), Modifiers::CONTROL)
+        .await;
+    utils.wait_for_update().await;
+    assert_eq!(copied_label.get(0).text(), Some("Some(false)"));
+}
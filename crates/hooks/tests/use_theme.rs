@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_core::{
+    parsing::Parse,
+    values::Fill,
+};
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+use tokio::time::sleep;
+
+#[tokio::test]
+pub async fn switch_theme() {
+    fn switch_theme_app() -> Element {
+        rsx!(
+            ThemeProvider {
+                theme: LIGHT_THEME,
+                ThemeSwitcher { }
+            }
+        )
+    }
+
+    #[allow(non_snake_case)]
+    fn ThemeSwitcher() -> Element {
+        let mut theme = use_theme();
+        let BodyTheme { background, .. } = use_applied_theme!(&None::<BodyThemeWith>, body);
+
+        rsx!(rect {
+            width: "100%",
+            height: "100%",
+            background: "{background}",
+            onclick: move |_| theme.set(DARK_THEME),
+        })
+    }
+
+    let mut utils = launch_test(switch_theme_app);
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        utils.root().get(0).style().background,
+        Fill::Color(Color::parse(&LIGHT_THEME.colors.background).unwrap())
+    );
+
+    utils.click_cursor((5., 5.)).await;
+
+    assert_eq!(
+        utils.root().get(0).style().background,
+        Fill::Color(Color::parse(&DARK_THEME.colors.background).unwrap())
+    );
+}
+
+#[tokio::test]
+pub async fn theme_transition() {
+    fn theme_transition_app() -> Element {
+        rsx!(
+            ThemeProvider {
+                theme: LIGHT_THEME,
+                transition: ThemeTransition::default().duration(Duration::from_millis(50)),
+                ThemeSwitcher { }
+            }
+        )
+    }
+
+    #[allow(non_snake_case)]
+    fn ThemeSwitcher() -> Element {
+        let mut theme = use_theme();
+        let BodyTheme { background, .. } = use_applied_theme!(&None::<BodyThemeWith>, body);
+
+        rsx!(rect {
+            width: "100%",
+            height: "100%",
+            background: "{background}",
+            onclick: move |_| theme.set(DARK_THEME),
+        })
+    }
+
+    let mut utils = launch_test(theme_transition_app);
+
+    // Disable event loop ticker
+    utils.config().event_loop_ticker = false;
+
+    utils.wait_for_update().await;
+
+    let light_background = Fill::Color(Color::parse(&LIGHT_THEME.colors.background).unwrap());
+    let dark_background = Fill::Color(Color::parse(&DARK_THEME.colors.background).unwrap());
+
+    assert_eq!(utils.root().get(0).style().background, light_background);
+
+    utils.click_cursor((5., 5.)).await;
+
+    // Colors somewhere in the middle
+    sleep(Duration::from_millis(15)).await;
+    utils.wait_for_update().await;
+
+    let background = utils.root().get(0).style().background;
+    assert_ne!(background, light_background);
+    assert_ne!(background, dark_background);
+
+    // Enable event loop ticker
+    utils.config().event_loop_ticker = true;
+
+    // Already finished
+    sleep(Duration::from_millis(50)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).style().background, dark_background);
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+async fn throttle() {
+    fn throttle_app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        let mut calls = use_signal(Vec::new);
+        let mut throttle = use_throttle(Duration::from_millis(100), move |value| {
+            calls.write().push(value);
+        });
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                onclick: move |_| {
+                    clicks += 1;
+                    throttle.action(clicks());
+                },
+                label {
+                    "{calls:?}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(throttle_app);
+    utils.wait_for_update().await;
+
+    // Only move the time when told to
+    utils.advance_time(Duration::ZERO).await;
+
+    let label = utils.root().get(0).get(0);
+
+    // The first click runs right away
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(label.get(0).text(), Some("[1]"));
+
+    utils.click_cursor((5., 5.)).await;
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(label.get(0).text(), Some("[1]"));
+
+    // Only the latest click runs once the time passes
+    utils.advance_time(Duration::from_millis(150)).await;
+    assert_eq!(label.get(0).text(), Some("[1, 3]"));
+
+    utils.advance_time(Duration::from_millis(100)).await;
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(label.get(0).text(), Some("[1, 3, 4]"));
+}
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+async fn interval() {
+    fn interval_app() -> Element {
+        let mut timer = use_interval(Duration::from_millis(100), || {});
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                onclick: move |_| {
+                    if timer.is_running() {
+                        timer.pause();
+                    } else {
+                        timer.resume();
+                    }
+                },
+                label {
+                    "{timer.ticks()}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(interval_app);
+    utils.advance_time(Duration::ZERO).await;
+
+    let label = utils.root().get(0).get(0);
+    assert_eq!(label.get(0).text(), Some("0"));
+
+    utils.advance_time(Duration::from_millis(250)).await;
+    assert_eq!(label.get(0).text(), Some("2"));
+
+    // Paused with 50ms left
+    utils.click_cursor((5., 5.)).await;
+    utils.advance_time(Duration::from_millis(500)).await;
+    assert_eq!(label.get(0).text(), Some("2"));
+
+    utils.click_cursor((5., 5.)).await;
+    utils.advance_time(Duration::from_millis(60)).await;
+    assert_eq!(label.get(0).text(), Some("3"));
+}
+
+#[tokio::test]
+async fn timeout() {
+    fn timeout_app() -> Element {
+        let mut message = use_signal(|| "Waiting");
+        let mut timer = use_timeout(Duration::from_millis(100), move || message.set("Done"));
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                onclick: move |_| timer.restart(),
+                label {
+                    "{message} {timer.ticks()} {timer.is_running()}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(timeout_app);
+    utils.advance_time(Duration::ZERO).await;
+
+    let label = utils.root().get(0).get(0);
+    assert_eq!(label.get(0).text(), Some("Waiting 0 true"));
+
+    utils.advance_time(Duration::from_millis(150)).await;
+    assert_eq!(label.get(0).text(), Some("Done 1 false"));
+
+    // Only runs once
+    utils.advance_time(Duration::from_millis(150)).await;
+    assert_eq!(label.get(0).text(), Some("Done 1 false"));
+
+    utils.click_cursor((5., 5.)).await;
+    utils.advance_time(Duration::from_millis(150)).await;
+    assert_eq!(label.get(0).text(), Some("Done 2 false"));
+}
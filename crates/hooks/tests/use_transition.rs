@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn transition_and_retarget() {
+    fn use_transition_app() -> Element {
+        let mut target = use_signal(|| 10.);
+        let width = use_transition(
+            move || target(),
+            TransitionConfig::default()
+                .duration(Duration::from_millis(100))
+                .function(Function::Linear),
+        );
+
+        rsx!(rect {
+            width: "{width}",
+            height: "50",
+            onclick: move |_| target.set(if target() == 10. { 100. } else { 10. }),
+        })
+    }
+
+    let mut utils = launch_test(use_transition_app);
+
+    utils.animation_clock().pause();
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 10.0);
+
+    // Move towards 100
+    utils.click_cursor((5., 5.)).await;
+    utils.advance_animations(Duration::from_millis(50)).await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 55.0);
+
+    // Go back to 10 from wherever it was
+    utils.click_cursor((5., 5.)).await;
+    utils.advance_animations(Duration::from_millis(50)).await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 32.5);
+
+    utils.advance_animations(Duration::from_millis(50)).await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 10.0);
+}
@@ -0,0 +1,39 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn tray_events() {
+    fn use_tray_app() -> Element {
+        let tray = use_tray();
+
+        use_hook(|| {
+            tray.set_tooltip(Some("Tray".to_string()));
+            tray.set_menu(Some(
+                NativeSubmenu::new("Tray").with_item(NativeMenuItem::new("show", "Show")),
+            ));
+        });
+
+        let text = match tray.event() {
+            Some(NativeTrayEvent::Click(button)) => format!("click {button:?}"),
+            Some(NativeTrayEvent::MenuItem(NativeMenuEvent { id, .. })) => format!("item {id}"),
+            _ => "none".to_string(),
+        };
+
+        rsx!(label { "{text}" })
+    }
+
+    let mut utils = launch_test(use_tray_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(root.get(0).get(0).text(), Some("none"));
+    assert_eq!(utils.native_tray().tooltip.as_deref(), Some("Tray"));
+
+    utils.click_tray_icon(MouseButton::Left);
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).get(0).text(), Some("click Left"));
+
+    utils.activate_menu_item("show");
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).get(0).text(), Some("item show"));
+}
@@ -0,0 +1,105 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+async fn visibility() {
+    #[component]
+    fn Row() -> Element {
+        let (reference, visibility) = use_visibility();
+
+        rsx!(
+            rect {
+                reference,
+                height: "50",
+                width: "fill",
+                label {
+                    "{visibility().ratio}"
+                }
+            }
+        )
+    }
+
+    fn visibility_app() -> Element {
+        rsx!(
+            ScrollView {
+                height: "100",
+                for i in 0..4 {
+                    Row { key: "{i}" }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(visibility_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let content = utils.root().get(0).get(0).get(0);
+    let ratio = |i: usize| content.get(i).get(0).get(0).text().map(str::to_string);
+
+    assert_eq!(ratio(0).as_deref(), Some("1"));
+    assert_eq!(ratio(1).as_deref(), Some("1"));
+    assert_eq!(ratio(2).as_deref(), Some("0"));
+
+    // Scroll down by 25
+    utils.scroll((5., 5.), (0., -5.)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(ratio(0).as_deref(), Some("0.5"));
+    assert_eq!(ratio(1).as_deref(), Some("1"));
+    assert_eq!(ratio(2).as_deref(), Some("0.5"));
+    assert_eq!(ratio(3).as_deref(), Some("0"));
+}
+
+#[tokio::test]
+async fn visibility_observer() {
+    #[component]
+    fn Tracked(changes: Signal<Vec<f32>>) -> Element {
+        let (reference, _) =
+            use_visibility_observer(vec![0.0, 1.0], move |visibility: Visibility| {
+                changes.write().push(visibility.ratio);
+            });
+
+        rsx!(rect {
+            reference,
+            height: "50",
+            width: "fill",
+        })
+    }
+
+    fn visibility_observer_app() -> Element {
+        let changes = use_signal(Vec::<f32>::new);
+
+        rsx!(
+            label {
+                "{changes:?}"
+            }
+            ScrollView {
+                height: "100",
+                rect {
+                    height: "100",
+                }
+                Tracked {
+                    changes
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(visibility_observer_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0);
+    assert_eq!(label.get(0).text(), Some("[]"));
+
+    // Partially visible
+    utils.scroll((5., 50.), (0., -5.)).await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("[0.5]"));
+
+    // Fully visible
+    utils.scroll((5., 50.), (0., -20.)).await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("[0.5, 1.0]"));
+}
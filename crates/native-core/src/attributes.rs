@@ -15,6 +15,7 @@ pub enum AttributeName {
     BackgroundOpacity,
     Border,
     Direction,
+    WritingDirection,
     Shadow,
     CornerRadius,
     CornerSmoothing,
@@ -26,11 +27,14 @@ pub enum AttributeName {
     FontStyle,
     FontWeight,
     FontWidth,
+    FontVariation,
     MainAlign,
     CrossAlign,
     TextAlign,
     TextShadow,
+    TextStroke,
     MaxLines,
+    LineClamp,
     LineHeight,
     LetterSpacing,
     WordSpacing,
@@ -41,6 +45,10 @@ pub enum AttributeName {
     TextHeight,
     Rotate,
     Overflow,
+    Cursor,
+    TextSelection,
+    PageBreak,
+    Link,
     Margin,
     Position,
     PositionTop,
@@ -50,6 +58,7 @@ pub enum AttributeName {
     Opacity,
     Content,
     CanvasReference,
+    DesignTokens,
     Layer,
     OffsetY,
     OffsetX,
@@ -60,7 +69,12 @@ pub enum AttributeName {
     CursorMode,
     CursorId,
     Highlights,
+    Carets,
+    MatchHighlights,
     HighlightColor,
+    MatchHighlightColor,
+    SpellingErrors,
+    SpellingErrorColor,
     HighlightMode,
     ImageReference,
     ImageData,
@@ -68,6 +82,7 @@ pub enum AttributeName {
     SvgContent,
     Spacing,
     Scale,
+    Cache,
 
     // Image element
     AspectRatio,
@@ -230,151 +245,185 @@ pub enum AttributeName {
     //       handle actions in the form of an event.
 }
 
-impl FromStr for AttributeName {
-    type Err = String;
+/// Implement [FromStr] and [AttributeName::as_str] from the same list of names,
+/// so the parsing and the printing of the attributes can't differ.
+macro_rules! attribute_names {
+    ($($attribute:ident => $name:literal,)*) => {
+        impl FromStr for AttributeName {
+            type Err = String;
 
-    fn from_str(attr: &str) -> Result<Self, Self::Err> {
-        match attr {
-            "width" => Ok(AttributeName::Width),
-            "height" => Ok(AttributeName::Height),
-            "min_width" => Ok(AttributeName::MinWidth),
-            "min_height" => Ok(AttributeName::MinHeight),
-            "max_width" => Ok(AttributeName::MaxWidth),
-            "max_height" => Ok(AttributeName::MaxHeight),
-            "visible_width" => Ok(AttributeName::VisibleWidth),
-            "visible_height" => Ok(AttributeName::VisibleHeight),
-            "padding" => Ok(AttributeName::Padding),
-            "background" => Ok(AttributeName::Background),
-            "background_opacity" => Ok(AttributeName::BackgroundOpacity),
-            "border" => Ok(AttributeName::Border),
-            "direction" => Ok(AttributeName::Direction),
-            "shadow" => Ok(AttributeName::Shadow),
-            "corner_radius" => Ok(AttributeName::CornerRadius),
-            "corner_smoothing" => Ok(AttributeName::CornerSmoothing),
-            "color" => Ok(AttributeName::Color),
-            "fill" => Ok(AttributeName::Fill),
-            "stroke" => Ok(AttributeName::Stroke),
-            "font_size" => Ok(AttributeName::FontSize),
-            "font_family" => Ok(AttributeName::FontFamily),
-            "font_style" => Ok(AttributeName::FontStyle),
-            "font_weight" => Ok(AttributeName::FontWeight),
-            "font_width" => Ok(AttributeName::FontWidth),
-            "main_align" => Ok(AttributeName::MainAlign),
-            "cross_align" => Ok(AttributeName::CrossAlign),
-            "text_align" => Ok(AttributeName::TextAlign),
-            "text_shadow" => Ok(AttributeName::TextShadow),
-            "max_lines" => Ok(AttributeName::MaxLines),
-            "line_height" => Ok(AttributeName::LineHeight),
-            "letter_spacing" => Ok(AttributeName::LetterSpacing),
-            "word_spacing" => Ok(AttributeName::WordSpacing),
-            "decoration" => Ok(AttributeName::Decoration),
-            "decoration_color" => Ok(AttributeName::DecorationColor),
-            "decoration_style" => Ok(AttributeName::DecorationStyle),
-            "text_overflow" => Ok(AttributeName::TextOverflow),
-            "text_height" => Ok(AttributeName::TextHeight),
-            "rotate" => Ok(AttributeName::Rotate),
-            "overflow" => Ok(AttributeName::Overflow),
-            "margin" => Ok(AttributeName::Margin),
-            "position" => Ok(AttributeName::Position),
-            "position_top" => Ok(AttributeName::PositionTop),
-            "position_right" => Ok(AttributeName::PositionRight),
-            "position_bottom" => Ok(AttributeName::PositionBottom),
-            "position_left" => Ok(AttributeName::PositionLeft),
-            "opacity" => Ok(AttributeName::Opacity),
-            "content" => Ok(AttributeName::Content),
-            "canvas_reference" => Ok(AttributeName::CanvasReference),
-            "layer" => Ok(AttributeName::Layer),
-            "offset_y" => Ok(AttributeName::OffsetY),
-            "offset_x" => Ok(AttributeName::OffsetX),
-            "reference" => Ok(AttributeName::Reference),
-            "cursor_reference" => Ok(AttributeName::CursorReference),
-            "cursor_index" => Ok(AttributeName::CursorIndex),
-            "cursor_color" => Ok(AttributeName::CursorColor),
-            "cursor_mode" => Ok(AttributeName::CursorMode),
-            "cursor_id" => Ok(AttributeName::CursorId),
-            "highlights" => Ok(AttributeName::Highlights),
-            "highlight_color" => Ok(AttributeName::HighlightColor),
-            "highlight_mode" => Ok(AttributeName::HighlightMode),
-            "image_reference" => Ok(AttributeName::ImageReference),
-            "image_data" => Ok(AttributeName::ImageData),
-            "svg_data" => Ok(AttributeName::SvgData),
-            "svg_content" => Ok(AttributeName::SvgContent),
-            "spacing" => Ok(AttributeName::Spacing),
-            "scale" => Ok(AttributeName::Scale),
-            "aspect_ratio" => Ok(AttributeName::AspectRatio),
-            "cover" => Ok(AttributeName::ImageCover),
-            "cache_key" => Ok(AttributeName::ImageCacheKey),
-            "sampling" => Ok(AttributeName::Sampling),
-            "a11y_id" => Ok(AttributeName::A11yId),
-            "a11y_focusable" => Ok(AttributeName::A11yFocusable),
-            "a11y_auto_focus" => Ok(AttributeName::A11yAutoFocus),
-            "a11y_name" => Ok(AttributeName::A11yName),
-            "a11y_description" => Ok(AttributeName::A11yDescription),
-            "a11y_value" => Ok(AttributeName::A11yValue),
-            "a11y_access_key" => Ok(AttributeName::A11yAccessKey),
-            "a11y_author_id" => Ok(AttributeName::A11yAuthorId),
-            "a11y_keyboard_shortcut" => Ok(AttributeName::A11yKeyboardShortcut),
-            "a11y_language" => Ok(AttributeName::A11yLanguage),
-            "a11y_placeholder" => Ok(AttributeName::A11yPlaceholder),
-            "a11y_role_description" => Ok(AttributeName::A11yRoleDescription),
-            "a11y_state_description" => Ok(AttributeName::A11yStateDescription),
-            "a11y_tooltip" => Ok(AttributeName::A11yTooltip),
-            "a11y_url" => Ok(AttributeName::A11yUrl),
-            "a11y_row_index_text" => Ok(AttributeName::A11yRowIndexText),
-            "a11y_column_index_text" => Ok(AttributeName::A11yColumnIndexText),
-            "a11y_scroll_x" => Ok(AttributeName::A11yScrollX),
-            "a11y_scroll_x_min" => Ok(AttributeName::A11yScrollXMin),
-            "a11y_scroll_x_max" => Ok(AttributeName::A11yScrollXMax),
-            "a11y_scroll_y" => Ok(AttributeName::A11yScrollY),
-            "a11y_scroll_y_min" => Ok(AttributeName::A11yScrollYMin),
-            "a11y_scroll_y_max" => Ok(AttributeName::A11yScrollYMax),
-            "a11y_numeric_value" => Ok(AttributeName::A11yNumericValue),
-            "a11y_min_numeric_value" => Ok(AttributeName::A11yMinNumericValue),
-            "a11y_max_numeric_value" => Ok(AttributeName::A11yMaxNumericValue),
-            "a11y_numeric_value_step" => Ok(AttributeName::A11yNumericValueStep),
-            "a11y_numeric_value_jump" => Ok(AttributeName::A11yNumericValueJump),
-            "a11y_row_count" => Ok(AttributeName::A11yRowCount),
-            "a11y_column_count" => Ok(AttributeName::A11yColumnCount),
-            "a11y_row_index" => Ok(AttributeName::A11yRowIndex),
-            "a11y_column_index" => Ok(AttributeName::A11yColumnIndex),
-            "a11y_row_span" => Ok(AttributeName::A11yRowSpan),
-            "a11y_column_span" => Ok(AttributeName::A11yColumnSpan),
-            "a11y_level" => Ok(AttributeName::A11yLevel),
-            "a11y_size_of_set" => Ok(AttributeName::A11ySizeOfSet),
-            "a11y_position_in_set" => Ok(AttributeName::A11yPositionInSet),
-            "a11y_color_value" => Ok(AttributeName::A11yColorValue),
-            "a11y_expanded" => Ok(AttributeName::A11yExpanded),
-            "a11y_selected" => Ok(AttributeName::A11ySelected),
-            "a11y_hovered" => Ok(AttributeName::A11yHovered),
-            "a11y_hidden" => Ok(AttributeName::A11yHidden),
-            "a11y_linked" => Ok(AttributeName::A11yLinked),
-            "a11y_multiselectable" => Ok(AttributeName::A11yMultiselectable),
-            "a11y_required" => Ok(AttributeName::A11yRequired),
-            "a11y_visited" => Ok(AttributeName::A11yVisited),
-            "a11y_busy" => Ok(AttributeName::A11yBusy),
-            "a11y_live_atomic" => Ok(AttributeName::A11yLiveAtomic),
-            "a11y_modal" => Ok(AttributeName::A11yModal),
-            "a11y_touch_transparent" => Ok(AttributeName::A11yTouchTransparent),
-            "a11y_read_only" => Ok(AttributeName::A11yReadOnly),
-            "a11y_disabled" => Ok(AttributeName::A11yDisabled),
-            "a11y_is_spelling_error" => Ok(AttributeName::A11yIsSpellingError),
-            "a11y_is_grammar_error" => Ok(AttributeName::A11yIsGrammarError),
-            "a11y_is_search_match" => Ok(AttributeName::A11yIsSearchMatch),
-            "a11y_is_suggestion" => Ok(AttributeName::A11yIsSuggestion),
-            "a11y_role" => Ok(AttributeName::A11yRole),
-            "a11y_invalid" => Ok(AttributeName::A11yInvalid),
-            "a11y_toggled" => Ok(AttributeName::A11yToggled),
-            "a11y_live" => Ok(AttributeName::A11yLive),
-            "a11y_default_action_verb" => Ok(AttributeName::A11yDefaultActionVerb),
-            "a11y_orientation" => Ok(AttributeName::A11yOrientation),
-            "a11y_sort_direction" => Ok(AttributeName::A11ySortDirection),
-            "a11y_current" => Ok(AttributeName::A11yCurrent),
-            "a11y_auto_complete" => Ok(AttributeName::A11yAutoComplete),
-            "a11y_has_popup" => Ok(AttributeName::A11yHasPopup),
-            "a11y_list_style" => Ok(AttributeName::A11yListStyle),
-            "a11y_vertical_offset" => Ok(AttributeName::A11yVerticalOffset),
-            "a11y_member_of" => Ok(AttributeName::A11yMemberOf),
-            _ => Err(format!("{attr} not supported.")),
+            fn from_str(attr: &str) -> Result<Self, Self::Err> {
+                match attr {
+                    $($name => Ok(AttributeName::$attribute),)*
+                    _ => Err(format!("{attr} not supported.")),
+                }
+            }
         }
-    }
+
+        impl AttributeName {
+            /// Get the name of the attribute as written in the elements.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(AttributeName::$attribute => $name,)*
+                }
+            }
+        }
+    };
+}
+
+attribute_names! {
+    Width => "width",
+    Height => "height",
+    MinWidth => "min_width",
+    MinHeight => "min_height",
+    MaxWidth => "max_width",
+    MaxHeight => "max_height",
+    VisibleWidth => "visible_width",
+    VisibleHeight => "visible_height",
+    Padding => "padding",
+    Background => "background",
+    BackgroundOpacity => "background_opacity",
+    Border => "border",
+    Direction => "direction",
+    WritingDirection => "writing_direction",
+    Shadow => "shadow",
+    CornerRadius => "corner_radius",
+    CornerSmoothing => "corner_smoothing",
+    Color => "color",
+    Fill => "fill",
+    Stroke => "stroke",
+    FontSize => "font_size",
+    FontFamily => "font_family",
+    FontStyle => "font_style",
+    FontWeight => "font_weight",
+    FontWidth => "font_width",
+    FontVariation => "font_variation",
+    MainAlign => "main_align",
+    CrossAlign => "cross_align",
+    TextAlign => "text_align",
+    TextShadow => "text_shadow",
+    TextStroke => "text_stroke",
+    MaxLines => "max_lines",
+    LineClamp => "line_clamp",
+    LineHeight => "line_height",
+    LetterSpacing => "letter_spacing",
+    WordSpacing => "word_spacing",
+    Decoration => "decoration",
+    DecorationColor => "decoration_color",
+    DecorationStyle => "decoration_style",
+    TextOverflow => "text_overflow",
+    TextHeight => "text_height",
+    Rotate => "rotate",
+    Overflow => "overflow",
+    Cursor => "cursor",
+    TextSelection => "text_selection",
+    PageBreak => "page_break",
+    Link => "link",
+    Margin => "margin",
+    Position => "position",
+    PositionTop => "position_top",
+    PositionRight => "position_right",
+    PositionBottom => "position_bottom",
+    PositionLeft => "position_left",
+    Opacity => "opacity",
+    Content => "content",
+    CanvasReference => "canvas_reference",
+    DesignTokens => "design_tokens",
+    Layer => "layer",
+    OffsetY => "offset_y",
+    OffsetX => "offset_x",
+    Reference => "reference",
+    CursorReference => "cursor_reference",
+    CursorIndex => "cursor_index",
+    CursorColor => "cursor_color",
+    CursorMode => "cursor_mode",
+    CursorId => "cursor_id",
+    Highlights => "highlights",
+    Carets => "carets",
+    MatchHighlights => "match_highlights",
+    HighlightColor => "highlight_color",
+    MatchHighlightColor => "match_highlight_color",
+    SpellingErrors => "spelling_errors",
+    SpellingErrorColor => "spelling_error_color",
+    HighlightMode => "highlight_mode",
+    ImageReference => "image_reference",
+    ImageData => "image_data",
+    SvgData => "svg_data",
+    SvgContent => "svg_content",
+    Spacing => "spacing",
+    Scale => "scale",
+    Cache => "cache",
+    AspectRatio => "aspect_ratio",
+    ImageCover => "cover",
+    ImageCacheKey => "cache_key",
+    Sampling => "sampling",
+    A11yId => "a11y_id",
+    A11yFocusable => "a11y_focusable",
+    A11yAutoFocus => "a11y_auto_focus",
+    A11yName => "a11y_name",
+    A11yDescription => "a11y_description",
+    A11yValue => "a11y_value",
+    A11yAccessKey => "a11y_access_key",
+    A11yAuthorId => "a11y_author_id",
+    A11yKeyboardShortcut => "a11y_keyboard_shortcut",
+    A11yLanguage => "a11y_language",
+    A11yPlaceholder => "a11y_placeholder",
+    A11yRoleDescription => "a11y_role_description",
+    A11yStateDescription => "a11y_state_description",
+    A11yTooltip => "a11y_tooltip",
+    A11yUrl => "a11y_url",
+    A11yRowIndexText => "a11y_row_index_text",
+    A11yColumnIndexText => "a11y_column_index_text",
+    A11yScrollX => "a11y_scroll_x",
+    A11yScrollXMin => "a11y_scroll_x_min",
+    A11yScrollXMax => "a11y_scroll_x_max",
+    A11yScrollY => "a11y_scroll_y",
+    A11yScrollYMin => "a11y_scroll_y_min",
+    A11yScrollYMax => "a11y_scroll_y_max",
+    A11yNumericValue => "a11y_numeric_value",
+    A11yMinNumericValue => "a11y_min_numeric_value",
+    A11yMaxNumericValue => "a11y_max_numeric_value",
+    A11yNumericValueStep => "a11y_numeric_value_step",
+    A11yNumericValueJump => "a11y_numeric_value_jump",
+    A11yRowCount => "a11y_row_count",
+    A11yColumnCount => "a11y_column_count",
+    A11yRowIndex => "a11y_row_index",
+    A11yColumnIndex => "a11y_column_index",
+    A11yRowSpan => "a11y_row_span",
+    A11yColumnSpan => "a11y_column_span",
+    A11yLevel => "a11y_level",
+    A11ySizeOfSet => "a11y_size_of_set",
+    A11yPositionInSet => "a11y_position_in_set",
+    A11yColorValue => "a11y_color_value",
+    A11yExpanded => "a11y_expanded",
+    A11ySelected => "a11y_selected",
+    A11yHovered => "a11y_hovered",
+    A11yHidden => "a11y_hidden",
+    A11yLinked => "a11y_linked",
+    A11yMultiselectable => "a11y_multiselectable",
+    A11yRequired => "a11y_required",
+    A11yVisited => "a11y_visited",
+    A11yBusy => "a11y_busy",
+    A11yLiveAtomic => "a11y_live_atomic",
+    A11yModal => "a11y_modal",
+    A11yTouchTransparent => "a11y_touch_transparent",
+    A11yReadOnly => "a11y_read_only",
+    A11yDisabled => "a11y_disabled",
+    A11yIsSpellingError => "a11y_is_spelling_error",
+    A11yIsGrammarError => "a11y_is_grammar_error",
+    A11yIsSearchMatch => "a11y_is_search_match",
+    A11yIsSuggestion => "a11y_is_suggestion",
+    A11yRole => "a11y_role",
+    A11yInvalid => "a11y_invalid",
+    A11yToggled => "a11y_toggled",
+    A11yLive => "a11y_live",
+    A11yDefaultActionVerb => "a11y_default_action_verb",
+    A11yOrientation => "a11y_orientation",
+    A11ySortDirection => "a11y_sort_direction",
+    A11yCurrent => "a11y_current",
+    A11yAutoComplete => "a11y_auto_complete",
+    A11yHasPopup => "a11y_has_popup",
+    A11yListStyle => "a11y_list_style",
+    A11yVerticalOffset => "a11y_vertical_offset",
+    A11yMemberOf => "a11y_member_of",
 }
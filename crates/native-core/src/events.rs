@@ -27,6 +27,9 @@ pub enum EventName {
     GlobalKeyDown,
     GlobalKeyUp,
 
+    ImePreedit,
+    ImeCommit,
+
     TouchCancel,
     TouchStart,
     TouchMove,
@@ -40,6 +43,11 @@ pub enum EventName {
     GlobalFileHoverCancelled,
 
     FileDrop,
+
+    TextOverflow,
+
+    LinkClick,
+    LinkHover,
 }
 
 impl FromStr for EventName {
@@ -65,6 +73,8 @@ impl FromStr for EventName {
             "keyup" => Ok(EventName::KeyUp),
             "globalkeydown" => Ok(EventName::GlobalKeyDown),
             "globalkeyup" => Ok(EventName::GlobalKeyUp),
+            "imepreedit" => Ok(EventName::ImePreedit),
+            "imecommit" => Ok(EventName::ImeCommit),
             "touchcancel" => Ok(EventName::TouchCancel),
             "touchstart" => Ok(EventName::TouchStart),
             "touchmove" => Ok(EventName::TouchMove),
@@ -76,6 +86,9 @@ impl FromStr for EventName {
             "filedrop" => Ok(EventName::FileDrop),
             "globalfilehover" => Ok(EventName::GlobalFileHover),
             "globalfilehovercancelled" => Ok(EventName::GlobalFileHoverCancelled),
+            "textoverflow" => Ok(EventName::TextOverflow),
+            "linkclick" => Ok(EventName::LinkClick),
+            "linkhover" => Ok(EventName::LinkHover),
             _ => Err(()),
         }
     }
@@ -102,6 +115,8 @@ impl From<EventName> for &str {
             EventName::KeyDown => "keydown",
             EventName::GlobalKeyDown => "globalkeydown",
             EventName::GlobalKeyUp => "globalkeyup",
+            EventName::ImePreedit => "imepreedit",
+            EventName::ImeCommit => "imecommit",
             EventName::TouchCancel => "touchcancel",
             EventName::TouchStart => "touchstart",
             EventName::TouchMove => "touchmove",
@@ -113,6 +128,9 @@ impl From<EventName> for &str {
             EventName::FileDrop => "filedrop",
             EventName::GlobalFileHover => "globalfilehover",
             EventName::GlobalFileHoverCancelled => "globalfilehovercancelled",
+            EventName::TextOverflow => "textoverflow",
+            EventName::LinkClick => "linkclick",
+            EventName::LinkHover => "linkhover",
         }
     }
 }
@@ -211,6 +229,7 @@ impl EventName {
     // Bubble all events except:
     // - Global Keyboard events
     // - Mouse movements events
+    // - Text overflow and link events
     pub fn does_bubble(&self) -> bool {
         !matches!(
             self,
@@ -222,6 +241,9 @@ impl EventName {
                 | Self::PointerEnter
                 | Self::MouseMove
                 | Self::PointerOver
+                | Self::TextOverflow
+                | Self::LinkClick
+                | Self::LinkHover
         )
     }
 
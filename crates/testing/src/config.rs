@@ -1,4 +1,7 @@
-use std::time::Duration;
+use std::{
+    path::PathBuf,
+    time::Duration,
+};
 
 use torin::geometry::Size2D;
 
@@ -9,6 +12,9 @@ pub struct TestingConfig<T: 'static + Clone> {
     pub size: Size2D,
     pub event_loop_ticker: bool,
     pub state: Option<T>,
+    /// Folder of the baselines for the visual snapshots.
+    /// Relative paths start from the folder of the crate being tested.
+    pub snapshots_dir: PathBuf,
 }
 
 impl<T: 'static + Clone> Default for TestingConfig<T> {
@@ -18,6 +24,7 @@ impl<T: 'static + Clone> Default for TestingConfig<T> {
             size: Size2D::from((500.0, 500.0)),
             event_loop_ticker: true,
             state: None,
+            snapshots_dir: PathBuf::from("tests/snapshots"),
         }
     }
 }
@@ -36,4 +43,14 @@ impl<T: 'static + Clone> TestingConfig<T> {
     pub fn vdom_timeout(&self) -> Duration {
         self.vdom_timeout
     }
+
+    /// Get the folder of the visual snapshots baselines.
+    pub fn snapshots_dir(&self) -> PathBuf {
+        if self.snapshots_dir.is_relative() {
+            if let Some(manifest_dir) = std::env::var_os("CARGO_MANIFEST_DIR") {
+                return PathBuf::from(manifest_dir).join(&self.snapshots_dir);
+            }
+        }
+        self.snapshots_dir.clone()
+    }
 }
@@ -35,6 +35,12 @@ pub enum TestEvent {
         code: Code,
         modifiers: Modifiers,
     },
+    /// An IME event.
+    Ime {
+        name: EventName,
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
     /// A Touch event.
     Touch {
         name: EventName,
@@ -72,6 +78,9 @@ impl From<TestEvent> for PlatformEvent {
                     modifiers,
                 },
             ),
+            TestEvent::Ime { name, text, cursor } => {
+                (name, PlatformEventData::Ime { text, cursor })
+            }
             TestEvent::Mouse {
                 name,
                 cursor,
@@ -0,0 +1,84 @@
+use accesskit::Role;
+use freya_core::accessibility::AccessibilityTree;
+use freya_elements::events::keyboard::{
+    Key,
+    Modifiers,
+};
+
+use crate::{
+    node_handle::NodeHandle,
+    test_handler::TestingHandler,
+    test_node::TestNode,
+};
+
+impl<T: 'static + Clone> TestingHandler<T> {
+    /// Shorthand to simulate pressing `Tab` to move the focus to the next focusable element.
+    ///
+    /// ```rust
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.focus_next();
+    /// ```
+    pub async fn focus_next(&mut self) {
+        self.press(Key::Tab).await;
+        // The focus is moved by the event loop after the key is handled
+        self.wait_for_update().await;
+    }
+
+    /// Shorthand to simulate pressing `Shift + Tab` to move the focus to the previous focusable element.
+    ///
+    /// ```rust
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.focus_previous();
+    /// ```
+    pub async fn focus_previous(&mut self) {
+        self.press_with_modifiers(Key::Tab, Modifiers::SHIFT).await;
+        self.wait_for_update().await;
+    }
+
+    /// Get the focused element, which is the root when nothing else is focused.
+    pub fn focused_node(&mut self) -> Option<NodeHandle<'_, T>> {
+        let node_id = self.accessibility_tree.focused_node_id()?;
+        let node = self.utils.get_node_by_id(node_id);
+        Some(NodeHandle::new(node, self))
+    }
+
+    /// Get the focusable elements in the order they are navigated with the keyboard.
+    pub fn focus_order(&self) -> Vec<TestNode> {
+        let node_ids = {
+            let fdom = self.utils.sdom.get();
+            AccessibilityTree::focus_order(fdom.rdom())
+        };
+        node_ids
+            .into_iter()
+            .map(|(_, node_id)| self.utils.get_node_by_id(node_id))
+            .collect()
+    }
+
+    /// Press `Tab` once per given role, asserting that every focused element has that role.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils
+    ///     .assert_focus_order(&[Role::TextInput, Role::CheckBox, Role::Button])
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn assert_focus_order(&mut self, roles: &[Role]) {
+        for (i, role) in roles.iter().enumerate() {
+            self.focus_next().await;
+            let focused_role = self.focused_node().and_then(|node| node.role());
+            assert_eq!(
+                focused_role,
+                Some(*role),
+                "Expected the focus stop {i} to be a {role:?}"
+            );
+        }
+    }
+}
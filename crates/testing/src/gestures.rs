@@ -0,0 +1,176 @@
+use freya_core::events::{
+    EventName,
+    PlatformEvent,
+    PlatformEventData,
+};
+use freya_elements::events::{
+    MouseButton,
+    TouchPhase,
+};
+use torin::prelude::CursorPoint;
+
+use crate::test_handler::TestingHandler;
+
+/// Intermediate moves sent when dragging or pinching.
+const GESTURE_STEPS: usize = 10;
+
+/// Points between `from` (excluded) and `to` (included).
+fn gesture_path(from: CursorPoint, to: CursorPoint) -> impl Iterator<Item = CursorPoint> {
+    (1..=GESTURE_STEPS).map(move |step| from.lerp(to, step as f64 / GESTURE_STEPS as f64))
+}
+
+impl<T: 'static + Clone> TestingHandler<T> {
+    /// Shorthand to simulate scrolling with the wheel over the given location,
+    /// negative values scroll down or to the right.
+    ///
+    /// ```rust
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.scroll((5., 5.), (0., -100.));
+    /// ```
+    pub async fn scroll(&mut self, cursor: impl Into<CursorPoint>, scroll: impl Into<CursorPoint>) {
+        self.push_event(PlatformEvent {
+            name: EventName::Wheel,
+            data: PlatformEventData::Wheel {
+                scroll: scroll.into(),
+                cursor: cursor.into(),
+            },
+        });
+        self.wait_for_update().await;
+    }
+
+    /// Shorthand to simulate dragging the cursor from a location to another while holding the left button.
+    ///
+    /// ```rust
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.drag_cursor((5., 5.), (100., 5.));
+    /// ```
+    pub async fn drag_cursor(&mut self, from: impl Into<CursorPoint>, to: impl Into<CursorPoint>) {
+        let (from, to) = (from.into(), to.into());
+        self.push_mouse(EventName::MouseDown, from);
+        self.wait_for_update().await;
+        for cursor in gesture_path(from, to) {
+            self.push_mouse(EventName::MouseMove, cursor);
+            self.wait_for_update().await;
+        }
+        self.push_mouse(EventName::MouseUp, to);
+        self.wait_for_update().await;
+    }
+
+    /// Shorthand to simulate a finger touching the screen, moving or leaving it, depending on the phase.
+    /// Useful to build multi-touch sequences, as every finger is identified by its own ID.
+    ///
+    /// ```rust
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.touch(0, TouchPhase::Started, (5., 5.));
+    /// ```
+    pub async fn touch(
+        &mut self,
+        finger_id: u64,
+        phase: TouchPhase,
+        location: impl Into<CursorPoint>,
+    ) {
+        self.push_touch(finger_id, phase, location.into());
+        self.wait_for_update().await;
+    }
+
+    /// Shorthand to simulate tapping with a finger in the given location.
+    ///
+    /// ```rust
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.tap((5., 5.));
+    /// ```
+    pub async fn tap(&mut self, location: impl Into<CursorPoint>) {
+        let location = location.into();
+        self.touch(0, TouchPhase::Started, location).await;
+        self.touch(0, TouchPhase::Ended, location).await;
+    }
+
+    /// Shorthand to simulate dragging a finger from a location to another.
+    ///
+    /// ```rust
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.touch_drag((5., 100.), (5., 5.));
+    /// ```
+    pub async fn touch_drag(&mut self, from: impl Into<CursorPoint>, to: impl Into<CursorPoint>) {
+        let (from, to) = (from.into(), to.into());
+        self.touch(0, TouchPhase::Started, from).await;
+        for location in gesture_path(from, to) {
+            self.touch(0, TouchPhase::Moved, location).await;
+        }
+        self.touch(0, TouchPhase::Ended, to).await;
+    }
+
+    /// Shorthand to simulate pinching with two fingers around the given center,
+    /// moving them from being `from_distance` pixels apart to `to_distance`.
+    /// Pinching out (a bigger `to_distance`) usually zooms in.
+    ///
+    /// ```rust
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.pinch((100., 100.), 50., 150.);
+    /// ```
+    pub async fn pinch(
+        &mut self,
+        center: impl Into<CursorPoint>,
+        from_distance: f64,
+        to_distance: f64,
+    ) {
+        let center = center.into();
+        let fingers = |distance: f64| {
+            let offset = CursorPoint::new(distance / 2., 0.).to_vector();
+            (center - offset, center + offset)
+        };
+        let (first_from, second_from) = fingers(from_distance);
+        let (first_to, second_to) = fingers(to_distance);
+
+        self.touch(0, TouchPhase::Started, first_from).await;
+        self.touch(1, TouchPhase::Started, second_from).await;
+        for (first, second) in
+            gesture_path(first_from, first_to).zip(gesture_path(second_from, second_to))
+        {
+            self.touch(0, TouchPhase::Moved, first).await;
+            self.touch(1, TouchPhase::Moved, second).await;
+        }
+        self.touch(0, TouchPhase::Ended, first_to).await;
+        self.touch(1, TouchPhase::Ended, second_to).await;
+    }
+
+    fn push_mouse(&mut self, name: EventName, cursor: CursorPoint) {
+        self.push_event(PlatformEvent {
+            name,
+            data: PlatformEventData::Mouse {
+                cursor,
+                button: Some(MouseButton::Left),
+            },
+        });
+    }
+
+    fn push_touch(&mut self, finger_id: u64, phase: TouchPhase, location: CursorPoint) {
+        let name = match phase {
+            TouchPhase::Started => EventName::TouchStart,
+            TouchPhase::Moved => EventName::TouchMove,
+            TouchPhase::Ended => EventName::TouchEnd,
+            TouchPhase::Cancelled => EventName::TouchCancel,
+        };
+        self.push_event(PlatformEvent {
+            name,
+            data: PlatformEventData::Touch {
+                location,
+                finger_id,
+                phase,
+                force: None,
+            },
+        });
+    }
+}
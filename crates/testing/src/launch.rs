@@ -1,6 +1,7 @@
 use std::{
     future::Future,
     path::PathBuf,
+    time::Instant,
 };
 
 use accesskit::{
@@ -35,17 +36,27 @@ use freya_core::{
     },
     event_loop_messages::EventLoopMessage,
     events::NodesState,
+    fonts::{
+        FontFallbacks,
+        FontRegistry,
+    },
+    global_shortcuts::GlobalShortcuts,
+    native_tray::NativeTray,
     platform::CursorIcon,
     platform_state::{
+        MonitorInfo,
+        Monitors,
         NativePlatformState,
         NavigationMode,
         PlatformInformation,
         PreferredTheme,
+        SoftKeyboard,
     },
+    style::default_fonts,
     types::EventsQueue,
 };
 use freya_elements as dioxus_elements;
-use freya_engine::prelude::*;
+use freya_hooks::AnimationClock;
 use tokio::{
     runtime::Runtime,
     sync::{
@@ -113,21 +124,35 @@ pub fn launch_test_with_config<T: 'static + Clone>(
         focused_accessibility_id: ACCESSIBILITY_ROOT_ID,
         focused_accessibility_node: NodeBuilder::new(Role::Window).build(),
         preferred_theme: PreferredTheme::default(),
+        accent_color: None,
         navigation_mode: NavigationMode::default(),
-        information: PlatformInformation::new(config.size, false, false, false),
+        information: PlatformInformation::new(config.size, false, false, false, SCALE_FACTOR),
         scale_factor: SCALE_FACTOR,
+        monitors: Monitors {
+            monitors: vec![MonitorInfo {
+                name: Some("Test Monitor".to_string()),
+                size: config.size,
+                position: (0., 0.).into(),
+                scale_factor: SCALE_FACTOR,
+                refresh_rate: Some(60.),
+            }],
+            current: Some(0),
+            primary: Some(0),
+        },
+        soft_keyboard: SoftKeyboard::default(),
+        selected_text: None,
+        is_window_focused: true,
+        is_suspended: false,
+        last_user_input: Instant::now(),
     });
-    let mut font_collection = FontCollection::new();
-    let font_mgr = FontMgr::default();
-    font_collection.set_dynamic_font_manager(font_mgr.clone());
-    font_collection.set_default_font_manager(font_mgr.clone(), None);
+    let (fonts, font_collection) = FontRegistry::new(&[], default_fonts(), FontFallbacks::empty());
 
     let mut handler = TestingHandler {
         vdom,
         events_queue: EventsQueue::new(),
         nodes_state: NodesState::default(),
         font_collection,
-        font_mgr,
+        fonts,
         event_emitter,
         event_receiver,
         utils: TestUtils { sdom },
@@ -136,7 +161,29 @@ pub fn launch_test_with_config<T: 'static + Clone>(
         platform_event_receiver,
         accessibility_tree: AccessibilityTree::new(ACCESSIBILITY_ROOT_ID),
         ticker_sender: broadcast::channel(5).0,
+        animation_clock: AnimationClock::default(),
         cursor_icon: CursorIcon::default(),
+        custom_cursor: None,
+        cursor_position: None,
+        hovered_cursor_icon: None,
+        native_menu: None,
+        native_menu_sender: broadcast::channel(16).0,
+        native_tray: NativeTray::default(),
+        native_tray_sender: broadcast::channel(16).0,
+        taskbar_progress: None,
+        window_effect: None,
+        global_shortcuts: GlobalShortcuts::default(),
+        global_shortcut_sender: broadcast::channel(16).0,
+        close_request_sender: broadcast::channel(4).0,
+        is_window_closed: false,
+        open_request_sender: broadcast::channel(16).0,
+        second_instance_sender: broadcast::channel(16).0,
+        webviews: Vec::new(),
+        printers: Vec::new(),
+        print_jobs: Vec::new(),
+        opened: Vec::new(),
+        plugin_messages: Vec::new(),
+        is_time_frozen: false,
         platform_sender,
         platform_receiver,
     };
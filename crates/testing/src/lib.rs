@@ -152,17 +152,54 @@
 //!     assert_eq!(label_text.text(), Some("Hello World!"));
 //! }
 //! ````
+//!
+//! ## Visual snapshots example
+//!
+//! Render the component with Skia and compare it against a stored baseline, so visual regressions are caught.
+//! Baselines are saved in `tests/snapshots` when `FREYA_UPDATE_SNAPSHOTS` is set, otherwise a missing baseline fails the test.
+//! When the comparison fails, the new snapshot (`.actual.png`) and the different pixels (`.diff.png`) are saved next to the baseline.
+//!
+//! ```rust, no_run
+//! #[tokio::test]
+//! async fn visual_test() {
+//!     fn our_component() -> Element {
+//!         rsx!(
+//!             Button {
+//!                 label {
+//!                     "Hello World!"
+//!                 }
+//!             }
+//!         )
+//!     }
+//!
+//!     let mut utils = launch_test(our_component);
+//!     utils.wait_for_update().await;
+//!
+//!     utils.assert_snapshot("button");
+//!
+//!     // Allow up to 1% of the pixels to be different
+//!     utils.assert_snapshot_with_options("button", SnapshotOptions::new().with_threshold(0.01));
+//! }
+//! ```
 
 pub mod config;
 pub mod event;
+pub mod focus;
+pub mod gestures;
 pub mod launch;
+pub mod node_handle;
 pub mod test_handler;
 pub mod test_node;
 pub mod test_utils;
+pub mod visual;
 
 const SCALE_FACTOR: f64 = 1.0;
 
 pub mod prelude {
+    pub use accesskit::{
+        Role,
+        Toggled,
+    };
     pub use freya_core::{
         accessibility::*,
         custom_attributes::*,
@@ -177,8 +214,10 @@ pub mod prelude {
         config::*,
         event::*,
         launch::*,
+        node_handle::*,
         test_handler::*,
         test_node::*,
         test_utils::*,
+        visual::*,
     };
 }
@@ -0,0 +1,87 @@
+use std::ops::Deref;
+
+use accesskit::Role;
+use freya_core::types::AccessibilityId;
+
+use crate::{
+    test_handler::TestingHandler,
+    test_node::TestNode,
+};
+
+/// [TestNode] found with the queries of the [TestingHandler], that can also be interacted with.
+///
+/// ```rust, no_run
+/// # use freya_testing::prelude::*;
+/// # use freya::prelude::*;
+/// # async fn test() {
+/// # let mut utils = launch_test(|| rsx!( Button { label { "Save" } } ));
+/// utils.find_by_role(Role::Button).unwrap().click().await;
+/// # }
+/// ```
+pub struct NodeHandle<'a, T: 'static + Clone> {
+    node: TestNode,
+    handler: &'a mut TestingHandler<T>,
+}
+
+impl<T: 'static + Clone> Deref for NodeHandle<'_, T> {
+    type Target = TestNode;
+
+    fn deref(&self) -> &Self::Target {
+        &self.node
+    }
+}
+
+impl<'a, T: 'static + Clone> NodeHandle<'a, T> {
+    pub(crate) fn new(node: TestNode, handler: &'a mut TestingHandler<T>) -> Self {
+        Self { node, handler }
+    }
+
+    /// Simulate a click in the center of the Node. Will panic if the Node has no layout.
+    pub async fn click(&mut self) {
+        let area = self.node.area().expect("The Node has no layout");
+        self.handler.click_cursor(area.center().to_f64()).await;
+    }
+
+    /// Simulate a cursor move to the center of the Node. Will panic if the Node has no layout.
+    pub async fn hover(&mut self) {
+        let area = self.node.area().expect("The Node has no layout");
+        self.handler.move_cursor(area.center().to_f64()).await;
+    }
+
+    /// Get the [TestNode] again, with its latest state.
+    pub fn refresh(&mut self) -> &TestNode {
+        self.node = self.handler.utils.get_node_by_id(self.node.id());
+        &self.node
+    }
+
+    /// Stop interacting with the Node.
+    pub fn into_node(self) -> TestNode {
+        self.node
+    }
+}
+
+impl<T: 'static + Clone> TestingHandler<T> {
+    /// Get the first element showing the given text, like a `label` or a `paragraph`.
+    pub fn find_by_text(&mut self, text: &str) -> Option<NodeHandle<'_, T>> {
+        let node = self.root().find_by_text(text)?;
+        Some(NodeHandle::new(node, self))
+    }
+
+    /// Get the first element with the given accessibility role.
+    pub fn find_by_role(&mut self, role: Role) -> Option<NodeHandle<'_, T>> {
+        let node = self.root().find_by_role(role)?;
+        Some(NodeHandle::new(node, self))
+    }
+
+    /// Get the element with the given accessibility ID.
+    pub fn find_by_a11y_id(&mut self, id: AccessibilityId) -> Option<NodeHandle<'_, T>> {
+        let node = self.root().find_by_a11y_id(id)?;
+        Some(NodeHandle::new(node, self))
+    }
+
+    /// Get the first element with the given accessibility name.
+    pub fn find_by_a11y_name(&mut self, name: &str) -> Option<NodeHandle<'_, T>> {
+        let node = self.root().find_by_a11y_name(name)?;
+        Some(NodeHandle::new(node, self))
+    }
+}
@@ -1,4 +1,6 @@
 use std::{
+    any::Any,
+    ffi::OsString,
     fs::File,
     io::Write,
     path::PathBuf,
@@ -16,26 +18,79 @@ use freya_core::{
     dom::SafeDOM,
     event_loop_messages::EventLoopMessage,
     events::{
+        cursor_icon_at,
         process_events,
         EventName,
         NodesState,
         PlatformEvent,
         PlatformEventData,
     },
+    fonts::{
+        FontFallbacks,
+        FontRegistry,
+        ResolvedFonts,
+    },
+    global_shortcuts::{
+        GlobalShortcutEvent,
+        GlobalShortcutState,
+        GlobalShortcuts,
+    },
     layout::process_layout,
+    native_menu::NativeMenu,
+    native_tray::{
+        NativeTray,
+        NativeTrayEvent,
+    },
+    open_requests::{
+        LaunchOpenRequests,
+        OpenRequest,
+    },
+    opener::OpenTarget,
+    platform::CursorImage,
+    platform_state::{
+        Monitors,
+        NativePlatformState,
+        SoftKeyboard,
+        TaskbarProgress,
+        WindowEffect,
+    },
+    printing::{
+        PrintError,
+        PrintOptions,
+        Printer,
+    },
     render::{
+        export_node,
+        print_node,
         Compositor,
+        DamageTracker,
         RenderPipeline,
+        RenderedLayer,
     },
     states::AccessibilityNodeState,
-    style::default_fonts,
     types::{
+        CloseRequestSender,
         EventEmitter,
         EventReceiver,
         EventsQueue,
+        GlobalShortcutEventSender,
+        NativeMenuEventSender,
         NativePlatformReceiver,
         NativePlatformSender,
+        NativeTrayEventSender,
+        OpenRequestSender,
+        SecondInstanceSender,
     },
+    webview::{
+        WebViewContent,
+        WebViewEvent,
+        WebViewId,
+    },
+};
+use freya_elements::events::keyboard::{
+    Code,
+    Key,
+    Modifiers,
 };
 use freya_engine::prelude::{
     raster_n32_premul,
@@ -43,8 +98,9 @@ use freya_engine::prelude::{
     Data,
     EncodedImageFormat,
     FontCollection,
-    FontMgr,
+    Surface,
 };
+use freya_hooks::AnimationClock;
 use freya_native_core::{
     dioxus::NodeImmutableDioxusExt,
     prelude::NodeImmutable,
@@ -58,6 +114,7 @@ use tokio::{
         },
     },
     time::{
+        self,
         interval,
         timeout,
     },
@@ -81,6 +138,30 @@ use crate::{
     SCALE_FACTOR,
 };
 
+/// Duration of a frame when advancing the time, like the ticker of the event loop.
+const FRAME_DURATION: Duration = Duration::from_millis(16);
+
+/// Webview created by the app, only its state is emulated.
+#[derive(Debug)]
+pub struct TestWebView {
+    pub id: WebViewId,
+    pub content: WebViewContent,
+    /// Area of the webview in the window, `None` if it is hidden.
+    pub area: Option<Area>,
+    /// Scripts run in the page.
+    pub scripts: Vec<String>,
+    sender: UnboundedSender<WebViewEvent>,
+}
+
+/// Document sent to a printer by the app, nothing is really printed.
+#[derive(Debug)]
+pub struct TestPrintJob {
+    pub options: PrintOptions,
+    pub pages: usize,
+    /// PDF with all the pages.
+    pub document: Vec<u8>,
+}
+
 /// Manages the lifecycle of your tests.
 pub struct TestingHandler<T: 'static + Clone> {
     pub(crate) vdom: VirtualDom,
@@ -94,11 +175,34 @@ pub struct TestingHandler<T: 'static + Clone> {
     pub(crate) platform_sender: NativePlatformSender,
     pub(crate) platform_receiver: NativePlatformReceiver,
     pub(crate) font_collection: FontCollection,
-    pub(crate) font_mgr: FontMgr,
+    pub(crate) fonts: FontRegistry,
     pub(crate) accessibility_tree: AccessibilityTree,
     pub(crate) config: TestingConfig<T>,
     pub(crate) ticker_sender: broadcast::Sender<()>,
+    pub(crate) animation_clock: AnimationClock,
     pub(crate) cursor_icon: CursorIcon,
+    pub(crate) custom_cursor: Option<CursorImage>,
+    pub(crate) cursor_position: Option<CursorPoint>,
+    pub(crate) hovered_cursor_icon: Option<CursorIcon>,
+    pub(crate) native_menu: Option<NativeMenu>,
+    pub(crate) native_menu_sender: NativeMenuEventSender,
+    pub(crate) native_tray: NativeTray,
+    pub(crate) native_tray_sender: NativeTrayEventSender,
+    pub(crate) taskbar_progress: Option<TaskbarProgress>,
+    pub(crate) window_effect: Option<WindowEffect>,
+    pub(crate) global_shortcuts: GlobalShortcuts,
+    pub(crate) global_shortcut_sender: GlobalShortcutEventSender,
+    pub(crate) close_request_sender: CloseRequestSender,
+    pub(crate) is_window_closed: bool,
+    pub(crate) open_request_sender: OpenRequestSender,
+    pub(crate) second_instance_sender: SecondInstanceSender,
+    pub(crate) webviews: Vec<TestWebView>,
+    pub(crate) printers: Vec<Printer>,
+    pub(crate) print_jobs: Vec<TestPrintJob>,
+    pub(crate) opened: Vec<OpenTarget>,
+    pub(crate) plugin_messages: Vec<Box<dyn Any + Send + Sync>>,
+    /// Whether the time only moves with [TestingHandler::advance_time].
+    pub(crate) is_time_frozen: bool,
 }
 
 impl<T: 'static + Clone> TestingHandler<T> {
@@ -113,6 +217,22 @@ impl<T: 'static + Clone> TestingHandler<T> {
             .insert_any_root_context(Box::new(self.platform_receiver.clone()));
         self.vdom
             .insert_any_root_context(Box::new(Arc::new(self.ticker_sender.subscribe())));
+        self.vdom
+            .insert_any_root_context(Box::new(self.animation_clock.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.native_menu_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.native_tray_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.global_shortcut_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.close_request_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.open_request_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.second_instance_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(LaunchOpenRequests::default()));
         self.vdom.insert_any_root_context(Box::new(
             self.utils.sdom.get_mut().accessibility_generator().clone(),
         ));
@@ -132,6 +252,11 @@ impl<T: 'static + Clone> TestingHandler<T> {
         self.cursor_icon
     }
 
+    /// Get the current [CursorImage], if the cursor is using an image instead of an icon.
+    pub fn custom_cursor(&self) -> Option<&CursorImage> {
+        self.custom_cursor.as_ref()
+    }
+
     /// Get the [SafeDOM]
     pub fn sdom(&self) -> &SafeDOM {
         self.utils.sdom()
@@ -142,11 +267,203 @@ impl<T: 'static + Clone> TestingHandler<T> {
         self.accessibility_tree.focused_id
     }
 
+    /// Get the current [NativeMenu] of the window.
+    pub fn native_menu(&self) -> Option<&NativeMenu> {
+        self.native_menu.as_ref()
+    }
+
+    /// Get the current [NativeTray] of the app.
+    pub fn native_tray(&self) -> &NativeTray {
+        &self.native_tray
+    }
+
+    /// Get the registered [GlobalShortcuts].
+    pub fn global_shortcuts(&self) -> &GlobalShortcuts {
+        &self.global_shortcuts
+    }
+
+    /// Simulate that the keys of a registered global shortcut have been pressed or released.
+    pub fn trigger_global_shortcut(&mut self, id: &str, state: GlobalShortcutState) {
+        if self.global_shortcuts.get(id).is_some() {
+            self.global_shortcut_sender
+                .send(GlobalShortcutEvent {
+                    id: id.to_string(),
+                    state,
+                })
+                .ok();
+        }
+    }
+
+    /// Simulate that the user tried to close the window, running the close request handlers of the app if any.
+    pub fn request_close(&mut self) {
+        if self.close_request_sender.receiver_count() > 0 {
+            self.close_request_sender.send(()).ok();
+        } else {
+            self.is_window_closed = true;
+        }
+    }
+
+    /// Check whether the window has been closed, see [TestingHandler::request_close].
+    pub fn is_window_closed(&self) -> bool {
+        self.is_window_closed
+    }
+
+    /// Simulate that the app was opened again with a link or a file, like a second instance would forward it.
+    pub fn send_open_request(&mut self, request: OpenRequest) {
+        self.open_request_sender.send(request).ok();
+    }
+
+    /// Simulate that another instance of the app was launched with the given arguments and forwarded them.
+    pub fn launch_second_instance(&mut self, args: &[&str]) {
+        self.second_instance_sender
+            .send(args.iter().map(OsString::from).collect())
+            .ok();
+    }
+
+    /// Simulate that the window gained or lost the focus.
+    pub fn set_window_focused(&mut self, focused: bool) {
+        self.platform_sender.send_modify(|state| {
+            state.is_window_focused = focused;
+        });
+    }
+
+    /// Simulate that the app went to the background or came back, e.g. in Android.
+    pub fn set_suspended(&mut self, suspended: bool) {
+        self.platform_sender.send_modify(|state| {
+            state.is_suspended = suspended;
+        });
+    }
+
+    /// Get the fonts registered at runtime and the default fonts, including the fallbacks.
+    pub fn fonts(&self) -> &FontRegistry {
+        &self.fonts
+    }
+
+    /// Get the progress shown in the taskbar icon of the window.
+    pub fn taskbar_progress(&self) -> Option<TaskbarProgress> {
+        self.taskbar_progress
+    }
+
+    /// Get the effect shown behind the transparent areas of the window.
+    pub fn window_effect(&self) -> Option<WindowEffect> {
+        self.window_effect
+    }
+
+    /// Get the webviews created by the app.
+    pub fn webviews(&self) -> &[TestWebView] {
+        &self.webviews
+    }
+
+    /// Set the printers the app can print to, there are none by default.
+    pub fn set_printers(&mut self, printers: Vec<Printer>) {
+        self.printers = printers;
+    }
+
+    /// Get the documents printed by the app.
+    pub fn print_jobs(&self) -> &[TestPrintJob] {
+        &self.print_jobs
+    }
+
+    /// Get the links and files opened, or shown in the file manager, by the app.
+    pub fn opened(&self) -> &[OpenTarget] {
+        &self.opened
+    }
+
+    /// Get the messages of the given type sent to the plugins, e.g. with `UsePlatform::send_plugin_message`.
+    pub fn plugin_messages<M: Any>(&self) -> Vec<&M> {
+        self.plugin_messages
+            .iter()
+            .filter_map(|message| message.downcast_ref())
+            .collect()
+    }
+
+    /// Simulate that a webview has sent an event, e.g a message from its page.
+    pub fn send_webview_event(&mut self, id: WebViewId, event: WebViewEvent) {
+        if let Some(webview) = self.webviews.iter().find(|webview| webview.id == id) {
+            webview.sender.send(event).ok();
+        }
+    }
+
+    /// Activate an item of the [NativeMenu] or the tray menu as if it was clicked, toggling it if it is checkable.
+    pub fn activate_menu_item(&mut self, id: &str) {
+        if let Some(item) = self.native_menu.as_mut().and_then(|menu| menu.item_mut(id)) {
+            self.native_menu_sender.send(item.activate(None)).ok();
+        } else if let Some(item) = self
+            .native_tray
+            .menu
+            .as_mut()
+            .and_then(|menu| menu.item_mut(id))
+        {
+            self.native_tray_sender
+                .send(NativeTrayEvent::MenuItem(item.activate(None)))
+                .ok();
+        }
+    }
+
+    /// Click the [NativeTray] icon with the given button.
+    pub fn click_tray_icon(&mut self, button: MouseButton) {
+        self.native_tray_sender
+            .send(NativeTrayEvent::Click(button))
+            .ok();
+    }
+
+    /// Get the [AnimationClock] that drives the animations.
+    pub fn animation_clock(&self) -> &AnimationClock {
+        &self.animation_clock
+    }
+
+    /// Pause the animations and move them forward by the given duration, then apply the changes.
+    /// Animations won't move by themselves until [AnimationClock::resume] is called.
+    pub async fn advance_animations(&mut self, duration: Duration) {
+        self.animation_clock.pause();
+        self.animation_clock.step(duration);
+        self.ticker_sender.send(()).unwrap();
+        self.wait_for_update().await;
+    }
+
+    /// Move the time forward by the given duration, frame by frame, applying the changes of every frame.
+    ///
+    /// Timers like `sleep` or `interval`, animations and debounces run as if that time had really passed,
+    /// so there is no need to wait for them. After the first call the time is frozen and only moves with this method,
+    /// which requires the default single threaded runtime of `#[tokio::test]`.
+    ///
+    /// ```rust, no_run
+    /// # use std::time::Duration;
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.advance_time(Duration::from_millis(500)).await;
+    /// # }
+    /// ```
+    pub async fn advance_time(&mut self, duration: Duration) {
+        if !self.is_time_frozen {
+            time::pause();
+            self.is_time_frozen = true;
+        }
+
+        let mut remaining = duration;
+        loop {
+            let step = remaining.min(FRAME_DURATION);
+            time::advance(step).await;
+            remaining -= step;
+            self.wait_for_update().await;
+            if remaining.is_zero() {
+                break;
+            }
+        }
+    }
+
     /// Apply the latest changes of the virtual dom.
     pub async fn wait_for_update(&mut self) -> (bool, bool) {
         self.wait_for_work(self.config.size());
 
-        let mut ticker = if self.config.event_loop_ticker {
+        // Ticking is pointless while the animations are paused,
+        // and with a frozen time the frames are ticked by `advance_time`
+        let mut ticker = if self.config.event_loop_ticker
+            && !self.animation_clock.is_paused()
+            && !self.is_time_frozen
+        {
             Some(interval(Duration::from_millis(16)))
         } else {
             None
@@ -180,11 +497,224 @@ impl<T: 'static + Clone> TestingHandler<T> {
                     }
                     EventLoopMessage::SetCursorIcon(icon) => {
                         self.cursor_icon = icon;
+                        self.custom_cursor = None;
+                    }
+                    EventLoopMessage::SetCustomCursor(cursor_image) => {
+                        self.custom_cursor = Some(cursor_image);
                     }
                     EventLoopMessage::RemeasureTextGroup(text_measurement) => {
                         let fdom = self.utils.sdom.get();
                         fdom.measure_paragraphs(text_measurement, SCALE_FACTOR);
                     }
+                    EventLoopMessage::SetAttributeOverride {
+                        node_id,
+                        attribute,
+                        value,
+                    } => match value {
+                        Some(value) => self
+                            .utils
+                            .sdom
+                            .get_mut()
+                            .set_attribute_override(node_id, attribute, value),
+                        None => self
+                            .utils
+                            .sdom
+                            .get_mut()
+                            .remove_attribute_override(node_id, attribute),
+                    },
+                    EventLoopMessage::ClearAttributeOverrides => {
+                        self.utils.sdom.get_mut().clear_attribute_overrides();
+                    }
+                    EventLoopMessage::SetNativeMenu(menu) => {
+                        self.native_menu = menu;
+                    }
+                    EventLoopMessage::SetNativeMenuItemChecked(id, checked) => {
+                        let menu_item = self
+                            .native_menu
+                            .as_mut()
+                            .and_then(|menu| menu.item_mut(&id));
+                        let tray_item = self
+                            .native_tray
+                            .menu
+                            .as_mut()
+                            .and_then(|menu| menu.item_mut(&id));
+                        for item in menu_item.into_iter().chain(tray_item) {
+                            item.set_checked(checked);
+                        }
+                    }
+                    EventLoopMessage::SetNativeMenuItemEnabled(id, enabled) => {
+                        let menu_item = self
+                            .native_menu
+                            .as_mut()
+                            .and_then(|menu| menu.item_mut(&id));
+                        let tray_item = self
+                            .native_tray
+                            .menu
+                            .as_mut()
+                            .and_then(|menu| menu.item_mut(&id));
+                        for item in menu_item.into_iter().chain(tray_item) {
+                            item.enabled = enabled;
+                        }
+                    }
+                    EventLoopMessage::SetNativeTrayIcon(icon) => {
+                        self.native_tray.icon = icon;
+                    }
+                    EventLoopMessage::SetNativeTrayTooltip(tooltip) => {
+                        self.native_tray.tooltip = tooltip;
+                    }
+                    EventLoopMessage::SetNativeTrayMenu(menu) => {
+                        self.native_tray.menu = menu;
+                    }
+                    EventLoopMessage::RegisterGlobalShortcut(shortcut, sender) => {
+                        sender.send(self.global_shortcuts.register(shortcut)).ok();
+                    }
+                    EventLoopMessage::UnregisterGlobalShortcut(id) => {
+                        self.global_shortcuts.remove(&id);
+                    }
+                    EventLoopMessage::SetTaskbarProgress(progress) => {
+                        self.taskbar_progress = progress;
+                    }
+                    EventLoopMessage::SetWindowEffect(effect) => {
+                        self.window_effect = effect;
+                    }
+                    EventLoopMessage::SetSoftKeyboardVisible(visible) => {
+                        self.platform_sender.send_modify(|state| {
+                            state.soft_keyboard.visible = visible;
+                        });
+                    }
+                    EventLoopMessage::SoftKeyboardChanged(soft_keyboard) => {
+                        self.set_soft_keyboard(soft_keyboard);
+                    }
+                    EventLoopMessage::RegisterFont(source, alias, sender) => {
+                        let result = self.fonts.register(
+                            source,
+                            alias.as_deref(),
+                            &mut self.font_collection,
+                        );
+                        if result.is_ok() {
+                            self.utils.sdom().get().invalidate_fonts();
+                        }
+                        sender.send(result).ok();
+                    }
+                    EventLoopMessage::SetFontFallbacks(fallbacks) => {
+                        self.fonts.set_fallbacks(fallbacks);
+                        self.utils.sdom().get().invalidate_fonts();
+                    }
+                    EventLoopMessage::GetFontFamilies(sender) => {
+                        sender.send(self.fonts.families()).ok();
+                    }
+                    EventLoopMessage::CreateWebView {
+                        id,
+                        config,
+                        area,
+                        sender,
+                    } => {
+                        self.webviews.push(TestWebView {
+                            id,
+                            content: config.content,
+                            area: Some(area),
+                            scripts: Vec::new(),
+                            sender,
+                        });
+                    }
+                    EventLoopMessage::SetWebViewArea(id, area) => {
+                        if let Some(webview) = self.webviews.iter_mut().find(|w| w.id == id) {
+                            webview.area = area;
+                        }
+                    }
+                    EventLoopMessage::SetWebViewContent(id, content) => {
+                        if let Some(webview) = self.webviews.iter_mut().find(|w| w.id == id) {
+                            webview.content = content;
+                        }
+                    }
+                    EventLoopMessage::EvaluateWebViewScript(id, script) => {
+                        if let Some(webview) = self.webviews.iter_mut().find(|w| w.id == id) {
+                            webview.scripts.push(script);
+                        }
+                    }
+                    EventLoopMessage::RemoveWebView(id) => {
+                        self.webviews.retain(|webview| webview.id != id);
+                    }
+                    EventLoopMessage::Export {
+                        target,
+                        format,
+                        sender,
+                    } => {
+                        let fdom = self.utils.sdom().get();
+                        let document = target.find_node(&fdom).and_then(|node_id| {
+                            export_node(
+                                &fdom,
+                                node_id,
+                                format,
+                                SCALE_FACTOR as f32,
+                                &mut self.font_collection,
+                                self.fonts.font_mgr(),
+                                &ResolvedFonts::new(
+                                    vec!["Fira Sans".to_string()],
+                                    &FontFallbacks::empty(),
+                                ),
+                            )
+                        });
+                        sender.send(document.map(Into::into)).ok();
+                    }
+                    EventLoopMessage::CloseWindow => {
+                        self.is_window_closed = true;
+                    }
+                    EventLoopMessage::GetPrinters(sender) => {
+                        sender.send(self.printers.clone()).ok();
+                    }
+                    EventLoopMessage::Print {
+                        target,
+                        options,
+                        sender,
+                    } => {
+                        let printer_exists = match &options.printer {
+                            Some(name) => self.printers.iter().any(|p| &p.name == name),
+                            None => !self.printers.is_empty(),
+                        };
+                        let fdom = self.utils.sdom().get();
+                        let document = target.find_node(&fdom).and_then(|node_id| {
+                            print_node(
+                                &fdom,
+                                node_id,
+                                &options,
+                                SCALE_FACTOR as f32,
+                                &mut self.font_collection,
+                                self.fonts.font_mgr(),
+                                &ResolvedFonts::new(
+                                    vec!["Fira Sans".to_string()],
+                                    &FontFallbacks::empty(),
+                                ),
+                            )
+                        });
+                        drop(fdom);
+                        let result = match document {
+                            _ if !printer_exists => Err(PrintError::PrinterNotFound(
+                                options.printer.clone().unwrap_or_default(),
+                            )),
+                            Some(document) => {
+                                let pages = document.pages;
+                                self.print_jobs.push(TestPrintJob {
+                                    options,
+                                    pages,
+                                    document: document.data,
+                                });
+                                Ok(pages)
+                            }
+                            None => Err(PrintError::NothingToPrint),
+                        };
+                        sender.send(result).ok();
+                    }
+                    EventLoopMessage::Open { target, sender } => {
+                        let result = target.validate();
+                        if result.is_ok() {
+                            self.opened.push(target);
+                        }
+                        sender.send(result).ok();
+                    }
+                    EventLoopMessage::PluginMessage(message) => {
+                        self.plugin_messages.push(message);
+                    }
                     _ => {}
                 }
             }
@@ -206,15 +736,32 @@ impl<T: 'static + Clone> TestingHandler<T> {
             }
         }
 
-        timeout(self.config.vdom_timeout(), self.vdom.wait_for_work())
-            .await
-            .ok();
+        let (must_repaint, must_relayout) = if self.is_time_frozen {
+            // Run all the work that is due without waiting, as the time would move
+            let (mut must_repaint, mut must_relayout) = (false, false);
+            while timeout(Duration::ZERO, self.vdom.wait_for_work())
+                .await
+                .is_ok()
+            {
+                let (repaint, relayout) = self
+                    .utils
+                    .sdom()
+                    .get_mut()
+                    .render_mutations(&mut self.vdom, SCALE_FACTOR as f32);
+                must_repaint |= repaint;
+                must_relayout |= relayout;
+            }
+            (must_repaint, must_relayout)
+        } else {
+            timeout(self.config.vdom_timeout(), self.vdom.wait_for_work())
+                .await
+                .ok();
 
-        let (must_repaint, must_relayout) = self
-            .utils
-            .sdom()
-            .get_mut()
-            .render_mutations(&mut self.vdom, SCALE_FACTOR as f32);
+            self.utils
+                .sdom()
+                .get_mut()
+                .render_mutations(&mut self.vdom, SCALE_FACTOR as f32)
+        };
 
         self.wait_for_work(self.config.size());
 
@@ -233,9 +780,22 @@ impl<T: 'static + Clone> TestingHandler<T> {
             },
             &mut self.font_collection,
             SCALE_FACTOR as f32,
-            &default_fonts(),
+            self.fonts.default_fonts(),
         );
 
+        let text_overflow_events = self.utils.sdom().get_mut().process_text_overflow();
+        if !text_overflow_events.is_empty() {
+            self.event_emitter.send(text_overflow_events).ok();
+        }
+
+        // Update the selected text
+        {
+            let mut fdom = self.utils.sdom().get_mut();
+            for event in self.events_queue.iter() {
+                fdom.process_text_selection(event, SCALE_FACTOR, &self.platform_sender);
+            }
+        }
+
         let fdom = &self.utils.sdom().get_mut();
         {
             let rdom = fdom.rdom();
@@ -258,6 +818,27 @@ impl<T: 'static + Clone> TestingHandler<T> {
             });
         }
 
+        // Show the cursor icon of the hovered nodes
+        if let Some(cursor) = self
+            .events_queue
+            .iter()
+            .rev()
+            .find_map(|event| match event.data {
+                PlatformEventData::Mouse { cursor, .. } => Some(cursor),
+                _ => None,
+            })
+        {
+            self.cursor_position = Some(cursor);
+        }
+        if let Some(cursor) = self.cursor_position {
+            let cursor_icon = cursor_icon_at(fdom, cursor, SCALE_FACTOR);
+            if self.hovered_cursor_icon != cursor_icon {
+                self.hovered_cursor_icon = cursor_icon;
+                self.cursor_icon = cursor_icon.unwrap_or_default();
+                self.custom_cursor = None;
+            }
+        }
+
         process_events(
             fdom,
             &mut self.events_queue,
@@ -284,6 +865,8 @@ impl<T: 'static + Clone> TestingHandler<T> {
     /// For mouse movements and clicks you can use shorcuts like [Self::move_cursor] and [Self::click_cursor].
     pub fn push_event(&mut self, event: impl Into<PlatformEvent>) {
         self.events_queue.push(event.into());
+        self.platform_sender
+            .send_if_modified(NativePlatformState::notify_user_input);
     }
 
     /// Get the root node
@@ -301,6 +884,20 @@ impl<T: 'static + Clone> TestingHandler<T> {
             .get(0)
     }
 
+    /// Replace the simulated [Monitors], e.g. to simulate that a monitor was connected.
+    pub fn set_monitors(&mut self, monitors: Monitors) {
+        self.platform_sender.send_modify(|state| {
+            state.monitors = monitors;
+        });
+    }
+
+    /// Replace the simulated [SoftKeyboard], e.g. to simulate that the on-screen keyboard covers part of the window.
+    pub fn set_soft_keyboard(&mut self, soft_keyboard: SoftKeyboard) {
+        self.platform_sender.send_modify(|state| {
+            state.soft_keyboard = soft_keyboard;
+        });
+    }
+
     /// Resize the simulated canvas.
     ///
     /// ```rust, no_run
@@ -331,6 +928,33 @@ impl<T: 'static + Clone> TestingHandler<T> {
     /// utils.save_snapshot("./snapshot.png");
     /// ```
     pub fn create_snapshot(&mut self) -> Data {
+        let mut surface = self.render_surface();
+        let image = surface.image_snapshot();
+        let mut context = surface.direct_context();
+        image
+            .encode(context.as_mut(), EncodedImageFormat::PNG, None)
+            .expect("Failed to encode the snapshot.")
+    }
+
+    /// Render the app and get the layers in the order they were painted,
+    /// like plugins drawing between the layers receive them.
+    pub fn rendered_layers(&mut self) -> Vec<i16> {
+        let mut layers = Vec::new();
+        self.render_surface_with_hook(Some(&mut |rendered_layer: RenderedLayer| {
+            layers.push(rendered_layer.layer)
+        }));
+        layers
+    }
+
+    /// Render the app into a new surface.
+    pub(crate) fn render_surface(&mut self) -> Surface {
+        self.render_surface_with_hook(None)
+    }
+
+    fn render_surface_with_hook(
+        &mut self,
+        layer_hook: Option<&mut dyn FnMut(RenderedLayer)>,
+    ) -> Surface {
         let fdom = self.utils.sdom.get();
         let (width, height) = self.config.size.to_i32().to_tuple();
 
@@ -354,6 +978,7 @@ impl<T: 'static + Clone> TestingHandler<T> {
             compositor_dirty_area: &mut fdom.compositor_dirty_area(),
             compositor_dirty_nodes: &mut fdom.compositor_dirty_nodes(),
             compositor_cache: &mut fdom.compositor_cache(),
+            raster_cache: &mut fdom.raster_cache(),
             layers: &mut fdom.layers(),
             layout: &mut fdom.layout(),
             background: Color::WHITE,
@@ -362,19 +987,21 @@ impl<T: 'static + Clone> TestingHandler<T> {
             compositor: &mut compositor,
             scale_factor: SCALE_FACTOR as f32,
             selected_node: None,
+            debug_layout: false,
             font_collection: &mut self.font_collection,
-            font_manager: &self.font_mgr,
-            default_fonts: &["Fira Sans".to_string()],
+            font_manager: self.fonts.font_mgr(),
+            default_fonts: &ResolvedFonts::new(
+                vec!["Fira Sans".to_string()],
+                &FontFallbacks::empty(),
+            ),
             images_cache: &mut fdom.images_cache(),
+            damage_tracker: &mut DamageTracker::default(),
+            buffer_age: 0,
+            layer_hook,
         };
         render_pipeline.run();
 
-        // Capture snapshot
-        let image = surface.image_snapshot();
-        let mut context = surface.direct_context();
-        image
-            .encode(context.as_mut(), EncodedImageFormat::PNG, None)
-            .expect("Failed to encode the snapshot.")
+        surface
     }
 
     /// Render the app into a canvas and save it into a file.
@@ -440,4 +1067,146 @@ impl<T: 'static + Clone> TestingHandler<T> {
         });
         self.wait_for_update().await;
     }
+
+    /// Shorthand to simulate typing the given text into the focused node, pressing a key for every character.
+    ///
+    /// ```rust
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.type_text("Hello");
+    /// ```
+    pub async fn type_text(&mut self, text: &str) {
+        for character in text.chars() {
+            let modifiers = if character.is_uppercase() {
+                Modifiers::SHIFT
+            } else {
+                Modifiers::default()
+            };
+            self.press_with_modifiers(Key::Character(character.to_string()), modifiers)
+                .await;
+        }
+    }
+
+    /// Shorthand to simulate pressing and releasing the given key.
+    ///
+    /// ```rust
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.press(Key::Backspace);
+    /// ```
+    pub async fn press(&mut self, key: Key) {
+        self.press_with_modifiers(key, Modifiers::default()).await;
+    }
+
+    /// Shorthand to simulate pressing and releasing the given key while holding the given modifiers.
+    ///
+    /// ```rust
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.press_with_modifiers(Key::Character("a".to_string()), Modifiers::CONTROL);
+    /// ```
+    pub async fn press_with_modifiers(&mut self, key: Key, modifiers: Modifiers) {
+        let code = key_code(&key);
+        self.push_event(PlatformEvent {
+            name: EventName::KeyDown,
+            data: PlatformEventData::Keyboard {
+                key: key.clone(),
+                code,
+                modifiers,
+            },
+        });
+        self.wait_for_update().await;
+        self.push_event(PlatformEvent {
+            name: EventName::KeyUp,
+            data: PlatformEventData::Keyboard {
+                key,
+                code,
+                modifiers,
+            },
+        });
+        self.wait_for_update().await;
+    }
+
+    /// Shorthand to simulate the IME composing the given text, with the cursor in the given byte range of it.
+    /// An empty text cancels the composition.
+    ///
+    /// ```rust
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.ime_preedit("日本", Some((6, 6)));
+    /// ```
+    pub async fn ime_preedit(&mut self, text: &str, cursor: Option<(usize, usize)>) {
+        self.push_event(PlatformEvent {
+            name: EventName::ImePreedit,
+            data: PlatformEventData::Ime {
+                text: text.to_string(),
+                cursor,
+            },
+        });
+        self.wait_for_update().await;
+    }
+
+    /// Shorthand to simulate the IME committing the given text,
+    /// which is also received as a key press like in the desktop platforms.
+    ///
+    /// ```rust
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.ime_commit("日本");
+    /// ```
+    pub async fn ime_commit(&mut self, text: &str) {
+        // The composition is cleared before committing it
+        self.push_event(PlatformEvent {
+            name: EventName::ImePreedit,
+            data: PlatformEventData::Ime {
+                text: String::new(),
+                cursor: None,
+            },
+        });
+        self.push_event(PlatformEvent {
+            name: EventName::ImeCommit,
+            data: PlatformEventData::Ime {
+                text: text.to_string(),
+                cursor: None,
+            },
+        });
+        self.push_event(PlatformEvent {
+            name: EventName::KeyDown,
+            data: PlatformEventData::Keyboard {
+                key: Key::Character(text.to_string()),
+                code: Code::Unidentified,
+                modifiers: Modifiers::default(),
+            },
+        });
+        self.wait_for_update().await;
+    }
+}
+
+/// Physical key that produces the given key in a US keyboard layout.
+fn key_code(key: &Key) -> Code {
+    match key {
+        Key::Character(character) => {
+            let mut chars = character.chars();
+            match (chars.next(), chars.next()) {
+                (Some(' '), None) => Code::Space,
+                (Some(character), None) if character.is_ascii_alphabetic() => {
+                    format!("Key{}", character.to_ascii_uppercase())
+                        .parse()
+                        .unwrap_or(Code::Unidentified)
+                }
+                (Some(character), None) if character.is_ascii_digit() => {
+                    format!("Digit{character}")
+                        .parse()
+                        .unwrap_or(Code::Unidentified)
+                }
+                _ => Code::Unidentified,
+            }
+        }
+        key => key.to_string().parse().unwrap_or(Code::Unidentified),
+    }
 }
@@ -1,10 +1,16 @@
+use accesskit::{
+    Role,
+    Toggled,
+};
 use freya_core::{
     custom_attributes::CustomAttributeValues,
     node::NodeState,
     states::{
+        AccessibilityNodeState,
         StyleState,
         ViewportState,
     },
+    types::AccessibilityId,
 };
 use freya_native_core::{
     node::NodeType,
@@ -88,6 +94,11 @@ impl TestNode {
         &self.utils
     }
 
+    /// Get the NodeId of this Node
+    pub fn id(&self) -> NodeId {
+        self.node_id
+    }
+
     /// Get the NodeId from the parent
     pub fn parent_id(&self) -> Option<NodeId> {
         let sdom = self.utils().sdom();
@@ -147,6 +158,96 @@ impl TestNode {
         self.node_type.is_placeholder()
     }
 
+    /// Get the accessibility role of this Node, if it's an element.
+    pub fn role(&self) -> Option<Role> {
+        self.state
+            .accessibility
+            .builder
+            .as_ref()
+            .map(|builder| builder.role())
+    }
+
+    /// Get whether this Node is checked, like a checkbox, for the assistive technologies.
+    pub fn toggled(&self) -> Option<Toggled> {
+        self.state
+            .accessibility
+            .builder
+            .as_ref()
+            .and_then(|builder| builder.toggled())
+    }
+
+    /// Get the first descendant element showing the given text, like a `label` or a `paragraph`.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( label { "Value B" } ));
+    /// let label = utils.root().find_by_text("Value B").unwrap();
+    /// ```
+    pub fn find_by_text(&self, matching_text: &str) -> Option<Self> {
+        let text_node = self.get_by_text(matching_text)?;
+        let sdom = self.utils().sdom();
+        let fdom = sdom.get();
+        let layout = fdom.layout();
+        let mut node = fdom.rdom().get(text_node.node_id)?;
+        // Texts of paragraphs are inside of `text` elements, which have no layout
+        while layout.get(node.id()).is_none() {
+            node = node.parent()?;
+        }
+        Some(self.utils.get_node_by_id(node.id()))
+    }
+
+    /// Get the first descendant element with the given accessibility role.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( Button { label { "Save" } } ));
+    /// let button = utils.root().find_by_role(Role::Button).unwrap();
+    /// ```
+    pub fn find_by_role(&self, role: Role) -> Option<Self> {
+        self.find_all_by_role(role).into_iter().next()
+    }
+
+    /// Get all the descendant elements with the given accessibility role, in the order they appear.
+    pub fn find_all_by_role(&self, role: Role) -> Vec<Self> {
+        self.find_by_accessibility(|state| {
+            state
+                .builder
+                .as_ref()
+                .is_some_and(|builder| builder.role() == role)
+        })
+    }
+
+    /// Get the descendant element with the given accessibility ID, like the one of [use_focus](freya_hooks::use_focus).
+    pub fn find_by_a11y_id(&self, id: AccessibilityId) -> Option<Self> {
+        self.find_by_accessibility(|state| state.a11y_id == Some(id))
+            .into_iter()
+            .next()
+    }
+
+    /// Get the first descendant element with the given accessibility name, set with the `a11y_name` attribute.
+    pub fn find_by_a11y_name(&self, name: &str) -> Option<Self> {
+        self.find_by_accessibility(|state| {
+            state.builder.as_ref().and_then(|builder| builder.name()) == Some(name)
+        })
+        .into_iter()
+        .next()
+    }
+
+    fn find_by_accessibility(
+        &self,
+        matcher: impl Fn(&AccessibilityNodeState) -> bool,
+    ) -> Vec<Self> {
+        self.utils()
+            .get_node_matching_inside_id(self.node_id, |node| {
+                node.node_type().is_element()
+                    && node
+                        .get::<AccessibilityNodeState>()
+                        .is_some_and(|state| matcher(&state))
+            })
+    }
+
     /// Get a descendant Node of this Node that matches a certain text.
     pub fn get_by_text(&self, matching_text: &str) -> Option<Self> {
         self.utils()
@@ -0,0 +1,306 @@
+use std::{
+    fmt,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use freya_core::render::{
+    capture_surface_frame,
+    CapturedFrame,
+};
+use freya_engine::prelude::{
+    raster_from_data,
+    AlphaType,
+    CachingHint,
+    ColorType,
+    Data,
+    EncodedImageFormat,
+    Image,
+    ImageInfo,
+};
+
+use crate::test_handler::TestingHandler;
+
+/// Set this environment variable to overwrite the baselines with the new snapshots.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "FREYA_UPDATE_SNAPSHOTS";
+
+/// How strict the comparison of a snapshot against its baseline is.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SnapshotOptions {
+    /// Maximum difference in any channel for two pixels to be considered equal,
+    /// so small antialiasing changes are ignored.
+    pub tolerance: u8,
+    /// Maximum fraction (`0.0` to `1.0`) of different pixels before the comparison fails.
+    pub threshold: f32,
+}
+
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        Self {
+            tolerance: 8,
+            threshold: 0.001,
+        }
+    }
+}
+
+impl SnapshotOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the per channel tolerance.
+    pub fn with_tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the fraction of pixels allowed to be different.
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+/// Result of a snapshot that didn't match its baseline.
+#[derive(Debug)]
+pub enum SnapshotMismatch {
+    /// There is no baseline yet, set [UPDATE_SNAPSHOTS_ENV] to create it.
+    Missing { actual_path: PathBuf },
+    /// The snapshot and the baseline have different sizes.
+    Size {
+        baseline: (u32, u32),
+        actual: (u32, u32),
+        actual_path: PathBuf,
+    },
+    /// Too many pixels are different.
+    Pixels {
+        different_pixels: usize,
+        total_pixels: usize,
+        actual_path: PathBuf,
+        diff_path: PathBuf,
+    },
+}
+
+impl SnapshotMismatch {
+    /// Fraction (`0.0` to `1.0`) of different pixels.
+    pub fn ratio(&self) -> f32 {
+        match self {
+            Self::Missing { .. } | Self::Size { .. } => 1.0,
+            Self::Pixels {
+                different_pixels,
+                total_pixels,
+                ..
+            } => *different_pixels as f32 / *total_pixels as f32,
+        }
+    }
+}
+
+impl fmt::Display for SnapshotMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { actual_path } => write!(
+                f,
+                "There is no baseline, see {} and run the tests with `{UPDATE_SNAPSHOTS_ENV}` set to save it",
+                actual_path.display()
+            ),
+            Self::Size {
+                baseline,
+                actual,
+                actual_path,
+            } => write!(
+                f,
+                "The snapshot is {}x{} but the baseline is {}x{}, see {}",
+                actual.0,
+                actual.1,
+                baseline.0,
+                baseline.1,
+                actual_path.display()
+            ),
+            Self::Pixels {
+                different_pixels,
+                actual_path,
+                diff_path,
+                ..
+            } => write!(
+                f,
+                "{different_pixels} pixels ({:.3}%) are different from the baseline, see {} and {}",
+                self.ratio() * 100.0,
+                actual_path.display(),
+                diff_path.display()
+            ),
+        }
+    }
+}
+
+impl<T: 'static + Clone> TestingHandler<T> {
+    /// Render the app and compare it against the baseline with the given name, with the default [SnapshotOptions].
+    /// Will panic if they don't match, leaving the new snapshot and the differences next to the baseline.
+    ///
+    /// The baselines are stored in [TestingConfig::snapshots_dir](crate::config::TestingConfig::snapshots_dir),
+    /// they are created or overwritten when the `FREYA_UPDATE_SNAPSHOTS` environment variable is set.
+    /// A missing baseline is a mismatch otherwise, so a forgotten baseline doesn't silently pass in CI.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.assert_snapshot("empty_rect");
+    /// ```
+    #[track_caller]
+    pub fn assert_snapshot(&mut self, name: &str) {
+        self.assert_snapshot_with_options(name, SnapshotOptions::default());
+    }
+
+    /// Same as [TestingHandler::assert_snapshot] but with custom [SnapshotOptions].
+    #[track_caller]
+    pub fn assert_snapshot_with_options(&mut self, name: &str, options: SnapshotOptions) {
+        if let Err(mismatch) = self.match_snapshot(name, options) {
+            panic!("Snapshot `{name}` doesn't match: {mismatch}");
+        }
+    }
+
+    /// Render the app and compare it against the baseline with the given name.
+    pub fn match_snapshot(
+        &mut self,
+        name: &str,
+        options: SnapshotOptions,
+    ) -> Result<(), SnapshotMismatch> {
+        let snapshots_dir = self.config.snapshots_dir();
+        fs::create_dir_all(&snapshots_dir).expect("Failed to create the snapshots folder.");
+
+        let baseline_path = snapshots_dir.join(format!("{name}.png"));
+        let actual_path = snapshots_dir.join(format!("{name}.actual.png"));
+        let diff_path = snapshots_dir.join(format!("{name}.diff.png"));
+
+        let mut surface = self.render_surface();
+        let actual = capture_surface_frame(&mut surface).expect("Failed to read the snapshot.");
+
+        if std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some() {
+            write_png(&baseline_path, &actual);
+            remove_artifacts(&actual_path, &diff_path);
+            return Ok(());
+        }
+
+        if !baseline_path.exists() {
+            write_png(&actual_path, &actual);
+            return Err(SnapshotMismatch::Missing { actual_path });
+        }
+
+        let baseline = read_png(&baseline_path);
+
+        if (baseline.width, baseline.height) != (actual.width, actual.height) {
+            write_png(&actual_path, &actual);
+            return Err(SnapshotMismatch::Size {
+                baseline: (baseline.width, baseline.height),
+                actual: (actual.width, actual.height),
+                actual_path,
+            });
+        }
+
+        let (diff, different_pixels) = diff_frames(&baseline, &actual, options.tolerance);
+        let total_pixels = (actual.width * actual.height) as usize;
+
+        if different_pixels as f32 > total_pixels as f32 * options.threshold {
+            write_png(&actual_path, &actual);
+            write_png(&diff_path, &diff);
+            return Err(SnapshotMismatch::Pixels {
+                different_pixels,
+                total_pixels,
+                actual_path,
+                diff_path,
+            });
+        }
+
+        remove_artifacts(&actual_path, &diff_path);
+        Ok(())
+    }
+}
+
+/// Create an image with the different pixels in red over a faded copy of the baseline.
+fn diff_frames(
+    baseline: &CapturedFrame,
+    actual: &CapturedFrame,
+    tolerance: u8,
+) -> (CapturedFrame, usize) {
+    let mut different_pixels = 0;
+    let mut pixels = Vec::with_capacity(actual.pixels.len());
+
+    for (a, b) in baseline
+        .pixels
+        .chunks_exact(4)
+        .zip(actual.pixels.chunks_exact(4))
+    {
+        let is_different = a.iter().zip(b).any(|(a, b)| a.abs_diff(*b) > tolerance);
+        if is_different {
+            different_pixels += 1;
+            pixels.extend_from_slice(&[255, 0, 0, 255]);
+        } else {
+            let luma = (a[0] as u32 * 299 + a[1] as u32 * 587 + a[2] as u32 * 114) / 1000;
+            let faded = (luma / 4 + 191) as u8;
+            pixels.extend_from_slice(&[faded, faded, faded, 255]);
+        }
+    }
+
+    let diff = CapturedFrame {
+        width: actual.width,
+        height: actual.height,
+        pixels: pixels.into(),
+    };
+    (diff, different_pixels)
+}
+
+fn image_info(width: u32, height: u32) -> ImageInfo {
+    ImageInfo::new(
+        (width as i32, height as i32),
+        ColorType::RGBA8888,
+        AlphaType::Premul,
+        None,
+    )
+}
+
+fn read_png(path: &Path) -> CapturedFrame {
+    let bytes = fs::read(path).expect("Failed to read the baseline.");
+    let image =
+        Image::from_encoded(Data::new_copy(&bytes)).expect("Failed to decode the baseline.");
+    let (width, height) = (image.width() as u32, image.height() as u32);
+    let row_bytes = width as usize * 4;
+    let mut pixels = vec![0; row_bytes * height as usize];
+
+    if !image.read_pixels(
+        &image_info(width, height),
+        &mut pixels,
+        row_bytes,
+        (0, 0),
+        CachingHint::Disallow,
+    ) {
+        panic!("Failed to read the pixels of the baseline.");
+    }
+
+    CapturedFrame {
+        width,
+        height,
+        pixels: pixels.into(),
+    }
+}
+
+fn write_png(path: &Path, frame: &CapturedFrame) {
+    let image = raster_from_data(
+        &image_info(frame.width, frame.height),
+        Data::new_copy(&frame.pixels),
+        frame.width as usize * 4,
+    )
+    .expect("Failed to create the snapshot image.");
+    let data = image
+        .encode(None, EncodedImageFormat::PNG, None)
+        .expect("Failed to encode the snapshot.");
+    fs::write(path, &*data).expect("Failed to save the snapshot.");
+}
+
+/// Remove the artifacts left by a previous failure.
+fn remove_artifacts(actual_path: &Path, diff_path: &Path) {
+    fs::remove_file(actual_path).ok();
+    fs::remove_file(diff_path).ok();
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use freya::prelude::*;
 use freya_testing::prelude::*;
 
@@ -130,3 +132,280 @@ async fn match_by_text() {
         Some("Hello, Rust!")
     );
 }
+
+#[tokio::test]
+async fn type_text() {
+    fn app() -> Element {
+        let mut value = use_signal(String::new);
+
+        rsx!(Input {
+            value,
+            onchange: move |new_value| {
+                value.set(new_value);
+            }
+        })
+    }
+
+    let mut utils = launch_test(app);
+    let root = utils.root();
+    let paragraph = root.get(0).get(0).get(0).get(0).get(0);
+    utils.wait_for_update().await;
+
+    // Focus the input
+    utils.click_cursor((15., 25.)).await;
+    utils.wait_for_update().await;
+
+    utils.type_text("Hello!").await;
+    assert_eq!(paragraph.get(0).get(0).text(), Some("Hello!"));
+
+    utils.press(Key::Backspace).await;
+    assert_eq!(paragraph.get(0).get(0).text(), Some("Hello"));
+
+    utils.ime_preedit("日本", Some((6, 6))).await;
+    assert_eq!(paragraph.get(1).get(0).text(), Some("日本"));
+
+    utils.ime_commit("日本").await;
+    assert_eq!(paragraph.children_ids().len(), 1);
+    assert_eq!(paragraph.get(0).get(0).text(), Some("Hello日本"));
+}
+
+#[tokio::test]
+async fn find_nodes() {
+    fn app() -> Element {
+        let mut count = use_signal(|| 0);
+
+        rsx!(
+            label {
+                "Count is {count}"
+            }
+            paragraph {
+                text {
+                    "Value B"
+                }
+            }
+            Button {
+                onpress: move |_| count += 1,
+                label {
+                    "Increase"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let paragraph = utils.find_by_text("Value B").unwrap();
+    assert_eq!(paragraph.role(), Some(Role::Paragraph));
+    assert!(paragraph.layout().is_some());
+
+    assert!(utils.find_by_text("Value C").is_none());
+    assert!(utils.find_by_role(Role::Slider).is_none());
+
+    utils.find_by_role(Role::Button).unwrap().click().await;
+
+    let label = utils.find_by_text("Count is 1").unwrap();
+    assert_eq!(label.role(), Some(Role::Label));
+
+    let label_id = label.id();
+    let a11y_id = label.state().accessibility.a11y_id.unwrap();
+    assert_eq!(utils.find_by_a11y_id(a11y_id).unwrap().id(), label_id);
+}
+
+#[tokio::test]
+async fn visual_snapshots() {
+    fn app() -> Element {
+        let mut background = use_signal(|| "red");
+        rsx!(rect {
+            width: "100",
+            height: "100",
+            background: "{background}",
+            onclick: move |_| background.set("blue"),
+        })
+    }
+
+    let snapshots_dir =
+        std::env::temp_dir().join(format!("freya-visual-snapshots-{}", std::process::id()));
+    let mut utils = launch_test_with_config(
+        app,
+        TestingConfig::<()> {
+            size: (200.0, 200.0).into(),
+            snapshots_dir: snapshots_dir.clone(),
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    // There is no baseline yet, so it fails
+    let mismatch = utils
+        .match_snapshot("square", SnapshotOptions::default())
+        .unwrap_err();
+    assert!(matches!(mismatch, SnapshotMismatch::Missing { .. }));
+    assert!(!snapshots_dir.join("square.png").exists());
+
+    // Accept the new snapshot as the baseline
+    std::fs::rename(
+        snapshots_dir.join("square.actual.png"),
+        snapshots_dir.join("square.png"),
+    )
+    .unwrap();
+    utils.assert_snapshot("square");
+
+    utils.click_cursor((50.0, 50.0)).await;
+
+    let mismatch = utils
+        .match_snapshot("square", SnapshotOptions::default())
+        .unwrap_err();
+    assert_eq!(mismatch.ratio(), 0.25);
+    assert!(snapshots_dir.join("square.actual.png").exists());
+    assert!(snapshots_dir.join("square.diff.png").exists());
+
+    // A quarter of the pixels changed, so this is within the threshold
+    utils.assert_snapshot_with_options("square", SnapshotOptions::new().with_threshold(0.3));
+    assert!(!snapshots_dir.join("square.diff.png").exists());
+
+    std::fs::remove_dir_all(snapshots_dir).ok();
+}
+
+#[tokio::test]
+async fn advance_time() {
+    fn app() -> Element {
+        let mut message = use_signal(|| "Waiting");
+        let mut ticks = use_signal(|| 0);
+
+        use_hook(move || {
+            spawn(async move {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                message.set("Done");
+            });
+            spawn(async move {
+                let mut interval = tokio::time::interval(Duration::from_millis(100));
+                interval.tick().await;
+                loop {
+                    interval.tick().await;
+                    *ticks.write() += 1;
+                }
+            });
+        });
+
+        rsx!(
+            label {
+                "{message}"
+            }
+            label {
+                "{ticks}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).text(), Some("Waiting"));
+    assert_eq!(utils.root().get(1).get(0).text(), Some("0"));
+
+    utils.advance_time(Duration::from_millis(350)).await;
+
+    assert_eq!(utils.root().get(0).get(0).text(), Some("Waiting"));
+    assert_eq!(utils.root().get(1).get(0).text(), Some("3"));
+
+    // The time doesn't move by itself anymore
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(1).get(0).text(), Some("3"));
+
+    utils.advance_time(Duration::from_secs(60)).await;
+
+    assert_eq!(utils.root().get(0).get(0).text(), Some("Done"));
+    assert_eq!(utils.root().get(1).get(0).text(), Some("603"));
+}
+
+#[tokio::test]
+async fn gestures() {
+    fn app() -> Element {
+        let mut scrolled = use_signal(|| 0.);
+        let mut dragged = use_signal(|| 0);
+        let mut touches = use_signal(Vec::<String>::new);
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                onwheel: move |e| *scrolled.write() += e.get_delta_y(),
+                onmousemove: move |_| *dragged.write() += 1,
+                ontouchstart: move |e| touches.write().push(format!("start {}", e.get_finger_id())),
+                ontouchend: move |e| touches.write().push(format!("end {}", e.get_finger_id())),
+                label {
+                    "{scrolled}"
+                }
+                label {
+                    "{dragged}"
+                }
+                label {
+                    "{touches.read().join(\", \")}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    utils.scroll((100., 100.), (0., -50.)).await;
+    assert_eq!(utils.root().get(0).get(0).get(0).text(), Some("-50"));
+
+    utils.drag_cursor((10., 10.), (200., 10.)).await;
+    assert_eq!(utils.root().get(0).get(1).get(0).text(), Some("10"));
+
+    utils.tap((10., 10.)).await;
+    assert_eq!(
+        utils.root().get(0).get(2).get(0).text(),
+        Some("start 0, end 0")
+    );
+
+    utils.pinch((250., 250.), 50., 150.).await;
+    assert_eq!(
+        utils.root().get(0).get(2).get(0).text(),
+        Some("start 0, end 0, start 0, start 1, end 0, end 1")
+    );
+}
+
+#[tokio::test]
+async fn focus_navigation() {
+    fn app() -> Element {
+        rsx!(
+            Input {
+                value: "",
+                onchange: |_| {}
+            }
+            Button {
+                label {
+                    "Save"
+                }
+            }
+            Button {
+                label {
+                    "Cancel"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let order = utils.focus_order();
+    assert_eq!(order.len(), 3);
+    assert_eq!(order[1].role(), Some(Role::Button));
+
+    utils
+        .assert_focus_order(&[Role::TextInput, Role::Button, Role::Button])
+        .await;
+
+    utils.focus_previous().await;
+    assert_eq!(utils.focused_node().unwrap().id(), order[1].id());
+
+    // Wraps around to the start
+    utils.focus_next().await;
+    utils.focus_next().await;
+    assert_eq!(utils.focused_node().unwrap().id(), order[0].id());
+}
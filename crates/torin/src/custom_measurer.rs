@@ -22,10 +22,20 @@ pub trait LayoutMeasurer<Key: NodeKey> {
 
     fn should_measure_inner_children(&mut self, node_id: Key) -> bool;
 
-    fn notify_layout_references(&self, _node_id: Key, _area: Area, _inner_sizes: Size2D) {}
+    /// Called with the new area of the Nodes that have layout references,
+    /// along with the inner area of their parent.
+    fn notify_layout_references(
+        &self,
+        _node_id: Key,
+        _area: Area,
+        _parent_area: Area,
+        _inner_sizes: Size2D,
+    ) {
+    }
 }
 
 // No-op measurer, use it when you don't need one.
+#[derive(Clone)]
 pub struct NoopMeasurer;
 
 impl LayoutMeasurer<usize> for NoopMeasurer {
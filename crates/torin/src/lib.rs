@@ -13,6 +13,7 @@ pub mod dom_adapter;
 pub mod geometry;
 pub mod measure;
 pub mod node;
+pub mod parallel;
 pub mod scaled;
 pub mod sendanymap;
 pub mod torin;
@@ -28,6 +29,7 @@ pub mod prelude {
         geometry::*,
         measure::*,
         node::*,
+        parallel::*,
         scaled::*,
         sendanymap::*,
         torin::*,
@@ -1,3 +1,5 @@
+use std::mem;
+
 pub use euclid::Rect;
 use rustc_hash::FxHashMap;
 
@@ -13,6 +15,11 @@ use crate::{
         Size2D,
     },
     node::Node,
+    parallel::{
+        DeferredSubtree,
+        MeasuredSubtree,
+        SubtreeScheduler,
+    },
     prelude::{
         AlignAxis,
         Alignment,
@@ -22,6 +29,7 @@ use crate::{
         LayoutMetadata,
         Length,
         Torin,
+        WritingDirection,
     },
     size::Size,
 };
@@ -44,6 +52,10 @@ where
     pub measurer: &'a mut Option<L>,
     pub dom_adapter: &'a mut D,
     pub layout_metadata: LayoutMetadata,
+    /// Measures big independent subtrees in parallel, if any.
+    pub scheduler: Option<&'a dyn SubtreeScheduler<Key>>,
+    /// Subtrees deferred while measuring the children of the current Node.
+    pub deferred_subtrees: Option<Vec<DeferredSubtree<Key>>>,
 }
 
 impl<Key, L, D> MeasureContext<'_, Key, L, D>
@@ -71,6 +83,15 @@ where
         // Current phase of measurement
         phase: Phase,
     ) -> (bool, LayoutNode) {
+        // Paddings, margins and offsets are mirrored in right-to-left Nodes
+        let mirrored_node;
+        let node = if node.writing_direction.is_rtl() {
+            mirrored_node = node.mirrored();
+            &mirrored_node
+        } else {
+            node
+        };
+
         // 1. If parent is dirty
         // 2. If this Node has been marked as dirty
         // 3. If there is no know cached data about this Node.
@@ -247,6 +268,7 @@ where
                 .without_gaps(&node.margin);
 
             let mut inner_sizes = Size2D::default();
+            let mut is_deferred = false;
 
             if measure_inner_children && phase_measure_inner_children {
                 // Create an area containing the available space inside the inner area
@@ -254,17 +276,32 @@ where
 
                 available_area.move_with_offsets(&node.offset_x, &node.offset_y);
 
-                // Measure the layout of this Node's children
-                self.measure_children(
-                    &node_id,
-                    node,
-                    &mut available_area,
-                    &mut inner_sizes,
-                    must_cache_children,
-                    &mut area,
-                    &mut inner_area,
-                    true,
-                );
+                if self.should_defer_children(node_id, node, phase) {
+                    // The children will be measured later in parallel with other subtrees
+                    if let Some(deferred_subtrees) = &mut self.deferred_subtrees {
+                        deferred_subtrees.push(DeferredSubtree {
+                            node_id,
+                            node: node.clone(),
+                            area,
+                            inner_area,
+                            available_area,
+                            must_cache_children,
+                        });
+                    }
+                    is_deferred = true;
+                } else {
+                    // Measure the layout of this Node's children
+                    self.measure_children(
+                        &node_id,
+                        node,
+                        &mut available_area,
+                        &mut inner_sizes,
+                        must_cache_children,
+                        &mut area,
+                        &mut inner_area,
+                        true,
+                    );
+                }
             }
 
             inner_sizes.width += node.padding.horizontal();
@@ -278,9 +315,15 @@ where
             };
 
             // In case of any layout listener, notify it with the new areas.
-            if node.has_layout_references {
+            // Deferred Nodes are notified once their children are measured.
+            if node.has_layout_references && !is_deferred {
                 if let Some(measurer) = self.measurer {
-                    measurer.notify_layout_references(node_id, layout_node.area, inner_sizes);
+                    measurer.notify_layout_references(
+                        node_id,
+                        layout_node.area,
+                        *parent_area,
+                        inner_sizes,
+                    );
                 }
             }
 
@@ -318,6 +361,75 @@ where
         }
     }
 
+    /// Whether the children of this Node can be measured independently of its siblings.
+    /// This is the case for Nodes whose size does not depend on their children and
+    /// whose subtree is big enough to be worth measuring in parallel.
+    fn should_defer_children(&mut self, node_id: Key, node: &Node, phase: Phase) -> bool {
+        let Some(scheduler) = self.scheduler else {
+            return false;
+        };
+
+        if phase != Phase::Final
+            || self.deferred_subtrees.is_none()
+            || node.width.inner_sized()
+            || node.height.inner_sized()
+        {
+            return false;
+        }
+
+        // Count the Nodes of the subtree until reaching the threshold
+        let min_subtree_size = scheduler.min_subtree_size();
+        let mut subtree_size = 0;
+        let mut stack = vec![node_id];
+        while let Some(node_id) = stack.pop() {
+            subtree_size += 1;
+            if subtree_size >= min_subtree_size {
+                return true;
+            }
+            stack.extend(self.dom_adapter.children_of(&node_id));
+        }
+
+        false
+    }
+
+    /// Measure the children of a deferred Node, returning the layout results of the whole subtree.
+    pub fn measure_deferred_subtree(
+        &mut self,
+        subtree: DeferredSubtree<Key>,
+    ) -> MeasuredSubtree<Key> {
+        let DeferredSubtree {
+            node_id,
+            node,
+            mut area,
+            mut inner_area,
+            mut available_area,
+            must_cache_children,
+        } = subtree;
+
+        let mut inner_sizes = Size2D::default();
+        self.measure_children(
+            &node_id,
+            &node,
+            &mut available_area,
+            &mut inner_sizes,
+            must_cache_children,
+            &mut area,
+            &mut inner_area,
+            true,
+        );
+
+        inner_sizes.width += node.padding.horizontal();
+        inner_sizes.height += node.padding.vertical();
+
+        MeasuredSubtree {
+            node_id,
+            area,
+            inner_sizes,
+            has_layout_references: node.has_layout_references,
+            results: mem::take(&mut self.layout.results),
+        }
+    }
+
     /// Measure the children layouts of a Node
     #[allow(clippy::too_many_arguments)]
     pub fn measure_children(
@@ -339,6 +451,12 @@ where
     ) {
         let children = self.dom_adapter.children_of(parent_node_id);
 
+        // Collect the subtrees of the children that can be measured in parallel
+        let parent_deferred_subtrees = mem::replace(
+            &mut self.deferred_subtrees,
+            self.scheduler.map(|_| Vec::new()),
+        );
+
         let mut initial_phase_flex_grows = FxHashMap::default();
         let mut initial_phase_sizes = FxHashMap::default();
         let mut initial_phase_inner_sizes = Size2D::default();
@@ -374,10 +492,14 @@ where
             )
         };
 
+        let is_rtl = parent_node.writing_direction.is_rtl();
+
+        // Right-to-left Nodes need the sizes of their children to place them from the right
         let needs_initial_phase = parent_node.cross_alignment.is_not_start()
             || parent_node.main_alignment.is_not_start()
             || parent_node.content.is_fit()
-            || parent_node.content.is_flex();
+            || parent_node.content.is_flex()
+            || is_rtl;
 
         let mut initial_phase_area = *area;
         let mut initial_phase_inner_area = *inner_area;
@@ -429,6 +551,7 @@ where
 
                 if parent_node.cross_alignment.is_not_start()
                     || parent_node.main_alignment.is_spaced()
+                    || is_rtl
                 {
                     initial_phase_sizes.insert(*child_id, child_areas.area.size);
                 }
@@ -486,7 +609,10 @@ where
                 });
 
         if needs_initial_phase {
-            if parent_node.main_alignment.is_not_start() {
+            let is_horizontal_rtl = is_rtl && parent_node.direction == Direction::Horizontal;
+            let is_vertical_rtl = is_rtl && parent_node.direction == Direction::Vertical;
+
+            if parent_node.main_alignment.is_not_start() || is_horizontal_rtl {
                 // Adjust the available and inner areas of the Main axis
                 Self::shrink_area_to_fit_when_unbounded(
                     available_area,
@@ -503,11 +629,15 @@ where
                     initial_phase_inner_sizes_with_flex,
                     &parent_node.main_alignment,
                     &parent_node.direction,
+                    parent_node.writing_direction,
                     AlignmentDirection::Main,
                 );
             }
 
-            if parent_node.cross_alignment.is_not_start() || parent_node.content.is_fit() {
+            if parent_node.cross_alignment.is_not_start()
+                || parent_node.content.is_fit()
+                || is_vertical_rtl
+            {
                 // Adjust the available and inner areas of the Cross axis
                 Self::shrink_area_to_fit_when_unbounded(
                     available_area,
@@ -531,6 +661,7 @@ where
             let is_last_child = last_child == Some(child_id);
 
             let mut adapted_available_area = *available_area;
+            let mut flex_width = None;
 
             if parent_node.content.is_flex() {
                 let flex_grow = initial_phase_flex_grows.get(&child_id);
@@ -546,6 +677,7 @@ where
                         AlignAxis::Width => {
                             let size = flex_available_width / 100. * flex_grow_per;
                             adapted_available_area.size.width = size;
+                            flex_width = Some(size);
                         }
                     }
                 }
@@ -561,6 +693,7 @@ where
                     initial_phase_inner_sizes_with_flex,
                     &parent_node.main_alignment,
                     &parent_node.direction,
+                    parent_node.writing_direction,
                     non_absolute_children_len,
                     is_first_child,
                 );
@@ -577,11 +710,27 @@ where
                         *initial_phase_size,
                         &parent_node.cross_alignment,
                         &parent_node.direction,
+                        parent_node.writing_direction,
                         AlignmentDirection::Cross,
                     );
                 }
             }
 
+            // Place the child from the right edge of the available area
+            if is_rtl && child_data.position.is_stacked() {
+                let initial_phase_size = initial_phase_sizes.get(&child_id);
+
+                if let Some(initial_phase_size) = initial_phase_size {
+                    let (max_x, width) = if let Some(flex_width) = flex_width {
+                        (available_area.max_x(), flex_width)
+                    } else {
+                        (adapted_available_area.max_x(), initial_phase_size.width)
+                    };
+                    adapted_available_area.origin.x = max_x - width;
+                    adapted_available_area.size.width = width;
+                }
+            }
+
             // Final measurement
             let (child_revalidated, mut child_areas) = self.measure_node(
                 child_id,
@@ -617,6 +766,31 @@ where
                 self.layout.cache_node(child_id, child_areas);
             }
         }
+
+        let deferred_subtrees = mem::replace(&mut self.deferred_subtrees, parent_deferred_subtrees);
+        if let (Some(scheduler), Some(deferred_subtrees)) = (self.scheduler, deferred_subtrees) {
+            if !deferred_subtrees.is_empty() {
+                // Results are returned in the same order as the subtrees were deferred
+                let measured_subtrees =
+                    scheduler.measure_subtrees(deferred_subtrees, self.layout_metadata);
+                for measured_subtree in measured_subtrees {
+                    for (node_id, layout_node) in measured_subtree.results {
+                        self.layout.cache_node(node_id, layout_node);
+                    }
+
+                    if measured_subtree.has_layout_references {
+                        if let Some(measurer) = self.measurer {
+                            measurer.notify_layout_references(
+                                measured_subtree.node_id,
+                                measured_subtree.area,
+                                *inner_area,
+                                measured_subtree.inner_sizes,
+                            );
+                        }
+                    }
+                }
+            }
+        }
     }
 
     /// Align the content of this node.
@@ -626,11 +800,25 @@ where
         contents_size: Size2D,
         alignment: &Alignment,
         direction: &Direction,
+        writing_direction: WritingDirection,
         alignment_direction: AlignmentDirection,
     ) {
         let axis = AlignAxis::new(direction, alignment_direction);
 
         match axis {
+            // Right-to-left content is aligned by moving the right edge of the available area
+            AlignAxis::Width if writing_direction.is_rtl() => match alignment {
+                Alignment::Center => {
+                    let new_max_x = (inner_area.width() / 2.0) + (contents_size.width / 2.0);
+                    available_area.size.width =
+                        inner_area.min_x() + new_max_x - available_area.min_x();
+                }
+                Alignment::End => {
+                    available_area.size.width =
+                        inner_area.min_x() + contents_size.width - available_area.min_x();
+                }
+                _ => {}
+            },
             AlignAxis::Height => match alignment {
                 Alignment::Center => {
                     let new_origin_y = (inner_area.height() / 2.0) - (contents_size.height / 2.0);
@@ -663,6 +851,7 @@ where
         inner_sizes: Size2D,
         alignment: &Alignment,
         direction: &Direction,
+        writing_direction: WritingDirection,
         siblings_len: usize,
         is_first_sibling: bool,
     ) {
@@ -692,29 +881,35 @@ where
                 }
                 _ => {}
             },
-            AlignAxis::Width => match alignment {
-                Alignment::SpaceBetween if !is_first_sibling => {
-                    let all_gaps_sizes = initial_available_area.width() - inner_sizes.width;
-                    let gap_size = all_gaps_sizes / (siblings_len - 1) as f32;
-                    available_area.origin.x += gap_size;
-                }
-                Alignment::SpaceEvenly => {
-                    let all_gaps_sizes = initial_available_area.width() - inner_sizes.width;
-                    let gap_size = all_gaps_sizes / (siblings_len + 1) as f32;
-                    available_area.origin.x += gap_size;
-                }
-                Alignment::SpaceAround => {
-                    let all_gaps_sizes = initial_available_area.width() - inner_sizes.width;
-                    let one_gap_size = all_gaps_sizes / siblings_len as f32;
-                    let gap_size = if is_first_sibling {
-                        one_gap_size / 2.
-                    } else {
-                        one_gap_size
-                    };
+            AlignAxis::Width => {
+                let gap_size = match alignment {
+                    Alignment::SpaceBetween if !is_first_sibling => {
+                        let all_gaps_sizes = initial_available_area.width() - inner_sizes.width;
+                        all_gaps_sizes / (siblings_len - 1) as f32
+                    }
+                    Alignment::SpaceEvenly => {
+                        let all_gaps_sizes = initial_available_area.width() - inner_sizes.width;
+                        all_gaps_sizes / (siblings_len + 1) as f32
+                    }
+                    Alignment::SpaceAround => {
+                        let all_gaps_sizes = initial_available_area.width() - inner_sizes.width;
+                        let one_gap_size = all_gaps_sizes / siblings_len as f32;
+                        if is_first_sibling {
+                            one_gap_size / 2.
+                        } else {
+                            one_gap_size
+                        }
+                    }
+                    _ => return,
+                };
+
+                // Right-to-left gaps move the right edge of the available area
+                if writing_direction.is_rtl() {
+                    available_area.size.width -= gap_size;
+                } else {
                     available_area.origin.x += gap_size;
                 }
-                _ => {}
-            },
+            }
         }
     }
 
@@ -739,8 +934,13 @@ where
         match parent_node.direction {
             Direction::Horizontal => {
                 // Move the available area
-                available_area.origin.x = child_area.max_x() + spacing.get();
-                available_area.size.width -= child_area.size.width + spacing.get();
+                if parent_node.writing_direction.is_rtl() && phase == Phase::Final {
+                    available_area.size.width =
+                        child_area.min_x() - spacing.get() - available_area.min_x();
+                } else {
+                    available_area.origin.x = child_area.max_x() + spacing.get();
+                    available_area.size.width -= child_area.size.width + spacing.get();
+                }
 
                 inner_sizes.height = child_area.height().max(inner_sizes.height);
                 inner_sizes.width += spacing.get();
@@ -823,14 +1023,17 @@ where
         }
 
         let axis = AlignAxis::new(&parent_node.direction, alignment_direction);
+        let is_rtl = parent_node.writing_direction.is_rtl();
         let (is_vertical_not_start, is_horizontal_not_start) = match parent_node.direction {
             Direction::Vertical => (
                 parent_node.main_alignment.is_not_start(),
-                parent_node.cross_alignment.is_not_start() || parent_node.content.is_fit(),
+                parent_node.cross_alignment.is_not_start()
+                    || parent_node.content.is_fit()
+                    || is_rtl,
             ),
             Direction::Horizontal => (
                 parent_node.cross_alignment.is_not_start() || parent_node.content.is_fit(),
-                parent_node.main_alignment.is_not_start(),
+                parent_node.main_alignment.is_not_start() || is_rtl,
             ),
         };
         let NodeData {
@@ -2,7 +2,10 @@ pub use euclid::Rect;
 
 use crate::{
     alignment::Alignment,
-    direction::Direction,
+    direction::{
+        Direction,
+        WritingDirection,
+    },
     gaps::Gaps,
     geometry::Length,
     prelude::{
@@ -61,6 +64,9 @@ pub struct Node {
     pub contains_text: bool,
 
     pub spacing: Length,
+
+    /// Horizontal order of it's inner Nodes, paddings, margins and offsets
+    pub writing_direction: WritingDirection,
 }
 
 impl Scaled for Node {
@@ -258,6 +264,32 @@ impl Node {
         }
     }
 
+    /// Construct a new Node given a size, a direction and a writing direction
+    pub fn from_size_and_direction_and_writing_direction(
+        width: Size,
+        height: Size,
+        direction: Direction,
+        writing_direction: WritingDirection,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            direction,
+            writing_direction,
+            ..Default::default()
+        }
+    }
+
+    /// Copy of this Node with the left and right paddings and margins swapped and the horizontal offset inverted.
+    pub fn mirrored(&self) -> Self {
+        Self {
+            padding: self.padding.mirrored(),
+            margin: self.margin.mirrored(),
+            offset_x: Length::new(-self.offset_x.get()),
+            ..self.clone()
+        }
+    }
+
     /// Has properties that depend on the inner Nodes?
     pub fn does_depend_on_inner(&self) -> bool {
         self.width.inner_sized()
@@ -271,5 +303,6 @@ impl Node {
         self.cross_alignment.is_not_start()
             || self.main_alignment.is_not_start()
             || self.has_layout_references
+            || self.writing_direction.is_rtl()
     }
 }
@@ -0,0 +1,153 @@
+use rustc_hash::FxHashMap;
+
+use crate::{
+    dom_adapter::{
+        LayoutNode,
+        NodeKey,
+    },
+    geometry::{
+        Area,
+        Size2D,
+    },
+    node::Node,
+    torin::LayoutMetadata,
+};
+
+/// Minimum amount of Nodes a subtree must have to be measured in parallel.
+pub const DEFAULT_MIN_PARALLEL_SUBTREE_SIZE: usize = 256;
+
+/// Node whose children measurement was deferred, as its size does not depend on them.
+#[derive(Clone, Debug)]
+pub struct DeferredSubtree<Key: NodeKey> {
+    pub node_id: Key,
+    pub node: Node,
+    pub area: Area,
+    pub inner_area: Area,
+    pub available_area: Area,
+    pub must_cache_children: bool,
+}
+
+/// Layout results of a deferred subtree.
+#[derive(Debug)]
+pub struct MeasuredSubtree<Key: NodeKey> {
+    pub node_id: Key,
+    pub area: Area,
+    pub inner_sizes: Size2D,
+    pub has_layout_references: bool,
+    pub results: FxHashMap<Key, LayoutNode>,
+}
+
+/// Measures the deferred subtrees of a Node.
+pub trait SubtreeScheduler<Key: NodeKey>: Sync {
+    /// Minimum amount of Nodes a subtree must have to be deferred.
+    fn min_subtree_size(&self) -> usize;
+
+    /// Measure the given subtrees, returning their results in the same order.
+    fn measure_subtrees(
+        &self,
+        subtrees: Vec<DeferredSubtree<Key>>,
+        layout_metadata: LayoutMetadata,
+    ) -> Vec<MeasuredSubtree<Key>>;
+}
+
+#[cfg(feature = "parallel")]
+mod rayon_scheduler {
+    use rayon::prelude::*;
+
+    use super::{
+        DeferredSubtree,
+        MeasuredSubtree,
+        SubtreeScheduler,
+    };
+    use crate::{
+        custom_measurer::LayoutMeasurer,
+        dom_adapter::{
+            DOMAdapter,
+            NodeKey,
+        },
+        geometry::Area,
+        measure::MeasureContext,
+        torin::{
+            LayoutMetadata,
+            Torin,
+        },
+    };
+
+    /// Measures every subtree in the rayon thread pool, with its own copy of the measurer and DOM adapter.
+    pub struct RayonScheduler<L, D> {
+        pub measurer: Option<L>,
+        pub dom_adapter: D,
+        pub min_subtree_size: usize,
+    }
+
+    impl<Key, L, D> SubtreeScheduler<Key> for RayonScheduler<L, D>
+    where
+        Key: NodeKey + Send + Sync,
+        L: LayoutMeasurer<Key> + Clone + Send + Sync,
+        D: DOMAdapter<Key> + Clone + Send + Sync,
+    {
+        fn min_subtree_size(&self) -> usize {
+            self.min_subtree_size
+        }
+
+        fn measure_subtrees(
+            &self,
+            subtrees: Vec<DeferredSubtree<Key>>,
+            layout_metadata: LayoutMetadata,
+        ) -> Vec<MeasuredSubtree<Key>> {
+            subtrees
+                .into_par_iter()
+                .map(|subtree| {
+                    let mut layout = Torin::new();
+                    let mut measurer = self.measurer.clone();
+                    let mut dom_adapter = self.dom_adapter.clone();
+                    let mut measure_context = MeasureContext {
+                        layout: &mut layout,
+                        measurer: &mut measurer,
+                        dom_adapter: &mut dom_adapter,
+                        layout_metadata,
+                        scheduler: Some(self),
+                        deferred_subtrees: None,
+                    };
+                    measure_context.measure_deferred_subtree(subtree)
+                })
+                .collect()
+        }
+    }
+
+    impl<Key: NodeKey + Send + Sync> Torin<Key> {
+        /// Measure dirty Nodes, measuring in parallel the children of the Nodes
+        /// whose size doesn't depend on them and that have at least `min_subtree_size` descendants.
+        ///
+        /// The results are the same as with [`Torin::measure`], regardless of the scheduling of the threads.
+        /// # Panics
+        /// Might panic if the final root node is not found.
+        pub fn measure_parallel<L, D>(
+            &mut self,
+            suggested_root_id: Key,
+            root_area: Area,
+            measurer: &mut Option<L>,
+            dom_adapter: &mut D,
+            min_subtree_size: usize,
+        ) where
+            L: LayoutMeasurer<Key> + Clone + Send + Sync,
+            D: DOMAdapter<Key> + Clone + Send + Sync,
+        {
+            let scheduler = RayonScheduler {
+                measurer: measurer.clone(),
+                dom_adapter: dom_adapter.clone(),
+                min_subtree_size,
+            };
+            self.measure_with_scheduler(
+                suggested_root_id,
+                root_area,
+                measurer,
+                dom_adapter,
+                Some(&scheduler),
+            );
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+pub use rayon_scheduler::*;
@@ -4,7 +4,7 @@ use std::collections::HashMap;
 
 use crate::prelude::*;
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct TestingDOM {
     mapper: HashMap<usize, (Option<usize>, Vec<usize>, u16, Node)>,
 }
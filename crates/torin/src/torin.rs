@@ -18,12 +18,14 @@ use crate::{
         MeasureContext,
         Phase,
     },
+    parallel::SubtreeScheduler,
     prelude::{
         AreaModel,
         Gaps,
     },
 };
 
+#[derive(Clone, Copy, Debug)]
 pub struct LayoutMetadata {
     pub root_area: Area,
 }
@@ -246,6 +248,18 @@ impl<Key: NodeKey> Torin<Key> {
         root_area: Area,
         measurer: &mut Option<impl LayoutMeasurer<Key>>,
         dom_adapter: &mut impl DOMAdapter<Key>,
+    ) {
+        self.measure_with_scheduler(suggested_root_id, root_area, measurer, dom_adapter, None);
+    }
+
+    /// Measure dirty Nodes, optionally deferring independent subtrees to the given scheduler.
+    pub(crate) fn measure_with_scheduler(
+        &mut self,
+        suggested_root_id: Key,
+        root_area: Area,
+        measurer: &mut Option<impl LayoutMeasurer<Key>>,
+        dom_adapter: &mut impl DOMAdapter<Key>,
+        scheduler: Option<&dyn SubtreeScheduler<Key>>,
     ) {
         // If there are previosuly cached results
         // But no dirty nodes, we can simply skip the measurement
@@ -295,6 +309,8 @@ impl<Key: NodeKey> Torin<Key> {
             layout_metadata,
             dom_adapter,
             measurer,
+            scheduler,
+            deferred_subtrees: None,
         };
 
         let (root_revalidated, mut root_layout_node) = measure_context.measure_node(
@@ -13,3 +13,24 @@ impl Direction {
         }
     }
 }
+
+/// Horizontal order in which the inner Nodes are laid out.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum WritingDirection {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl WritingDirection {
+    pub const fn is_rtl(&self) -> bool {
+        matches!(self, Self::Rtl)
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Ltr => "ltr".to_string(),
+            Self::Rtl => "rtl".to_string(),
+        }
+    }
+}
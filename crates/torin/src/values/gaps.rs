@@ -62,6 +62,15 @@ impl Gaps {
         self.left.get()
     }
 
+    /// Swap the left and right gaps.
+    pub fn mirrored(&self) -> Self {
+        Self {
+            left: self.right,
+            right: self.left,
+            ..*self
+        }
+    }
+
     pub fn pretty(&self) -> String {
         format!(
             "({}, {}, {}, {})",
@@ -0,0 +1,123 @@
+#![cfg(feature = "parallel")]
+
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+/// Root with 4 fixed-size columns, each containing 100 rows with 2 auto-sized labels.
+fn create_dom() -> TestingDOM {
+    let mut mocked_dom = TestingDOM::default();
+    let columns = (1..=4).collect::<Vec<usize>>();
+    mocked_dom.add(
+        0,
+        None,
+        columns.clone(),
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Direction::Horizontal,
+        ),
+    );
+
+    let mut next_id = 5;
+    for column in columns {
+        let rows = (next_id..next_id + 100).collect::<Vec<usize>>();
+        next_id += 100;
+        mocked_dom.add(
+            column,
+            Some(0),
+            rows.clone(),
+            Node::from_size_and_direction(
+                Size::Percentage(Length::new(25.0)),
+                Size::Percentage(Length::new(100.0)),
+                Direction::Vertical,
+            ),
+        );
+        for row in rows {
+            let labels = vec![next_id, next_id + 1];
+            next_id += 2;
+            mocked_dom.add(
+                row,
+                Some(column),
+                labels.clone(),
+                Node::from_size_and_direction(
+                    Size::Percentage(Length::new(100.0)),
+                    Size::Inner,
+                    Direction::Horizontal,
+                ),
+            );
+            for label in labels {
+                mocked_dom.add(
+                    label,
+                    Some(row),
+                    vec![],
+                    Node::from_size_and_direction(
+                        Size::Pixels(Length::new(50.0)),
+                        Size::Pixels(Length::new(10.0)),
+                        Direction::Vertical,
+                    ),
+                );
+            }
+        }
+    }
+
+    mocked_dom
+}
+
+#[test]
+pub fn parallel_measurement() {
+    let root_area = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0));
+    let mut mocked_dom = create_dom();
+
+    let (mut sequential_layout, mut measurer) = test_utils();
+    sequential_layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+    let (mut parallel_layout, mut measurer) = test_utils();
+    parallel_layout.measure_parallel(0, root_area, &mut measurer, &mut mocked_dom, 100);
+
+    assert_eq!(parallel_layout.size(), sequential_layout.size());
+    for (node_id, layout_node) in &sequential_layout.results {
+        assert_eq!(parallel_layout.get(*node_id), Some(layout_node));
+    }
+
+    // Second label of the last row of the last column
+    assert_eq!(
+        parallel_layout.get(1204).unwrap().area,
+        Rect::new(Point2D::new(800.0, 990.0), Size2D::new(50.0, 10.0)),
+    );
+}
+
+#[test]
+pub fn parallel_measurement_after_invalidation() {
+    let root_area = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0));
+    let mut mocked_dom = create_dom();
+
+    let (mut layout, mut measurer) = test_utils();
+    layout.measure_parallel(0, root_area, &mut measurer, &mut mocked_dom, 100);
+
+    // Make the labels of the last column taller
+    for label in 1005..1205 {
+        mocked_dom.set_node(
+            label,
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(50.0)),
+                Size::Pixels(Length::new(20.0)),
+                Direction::Vertical,
+            ),
+        );
+        layout.invalidate(label);
+    }
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure_parallel(0, root_area, &mut measurer, &mut mocked_dom, 100);
+
+    assert_eq!(
+        layout.get(1204).unwrap().area,
+        Rect::new(Point2D::new(800.0, 1980.0), Size2D::new(50.0, 20.0)),
+    );
+    // Other columns are untouched
+    assert_eq!(
+        layout.get(904).unwrap().area,
+        Rect::new(Point2D::new(550.0, 990.0), Size2D::new(50.0, 10.0)),
+    );
+}
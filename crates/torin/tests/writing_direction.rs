@@ -0,0 +1,278 @@
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn rtl_horizontal() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node {
+            spacing: Length::new(10.0),
+            ..Node::from_size_and_direction_and_writing_direction(
+                Size::Pixels(Length::new(1000.0)),
+                Size::Pixels(Length::new(1000.0)),
+                Direction::Horizontal,
+                WritingDirection::Rtl,
+            )
+        },
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(900.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(690.0, 0.0), Size2D::new(200.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn rtl_vertical_inner_sized() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(1000.0)),
+            Size::Pixels(Length::new(1000.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2, 3],
+        Node::from_size_and_direction_and_writing_direction(
+            Size::Inner,
+            Size::Inner,
+            Direction::Vertical,
+            WritingDirection::Rtl,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 150.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(50.0, 100.0), Size2D::new(50.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn rtl_main_alignment() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node {
+            writing_direction: WritingDirection::Rtl,
+            ..Node::from_size_and_alignments_and_direction(
+                Size::Pixels(Length::new(1000.0)),
+                Size::Pixels(Length::new(1000.0)),
+                Alignment::Center,
+                Alignment::Start,
+                Direction::Horizontal,
+            )
+        },
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(550.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(350.0, 0.0), Size2D::new(200.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn rtl_padding() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node {
+            writing_direction: WritingDirection::Rtl,
+            ..Node::from_size_and_padding(
+                Size::Pixels(Length::new(200.0)),
+                Size::Pixels(Length::new(200.0)),
+                Gaps::new(5.0, 10.0, 15.0, 20.0),
+            )
+        },
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The left and right paddings are swapped
+    assert_eq!(
+        layout.get(0).unwrap().inner_area,
+        Rect::new(Point2D::new(10.0, 5.0), Size2D::new(170.0, 180.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(80.0, 5.0), Size2D::new(100.0, 180.0)),
+    );
+}
+
+#[test]
+pub fn rtl_offset() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node {
+            writing_direction: WritingDirection::Rtl,
+            ..Node::from_size_and_scroll(
+                Size::Pixels(Length::new(200.0)),
+                Size::Pixels(Length::new(200.0)),
+                Length::new(50.0),
+                Length::new(0.0),
+            )
+        },
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The offset moves the children to the left
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(50.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+}
@@ -0,0 +1,89 @@
+use std::{
+    process::Command,
+    thread,
+};
+
+use freya_core::event_loop_messages::EventLoopMessage;
+use freya_engine::prelude::Color;
+use winit::event_loop::EventLoopProxy;
+
+/// Read the accent color of the operating system in a background thread,
+/// sending it back with [EventLoopMessage::AccentColorChanged].
+pub fn request_accent_color(proxy: &EventLoopProxy<EventLoopMessage>) {
+    let proxy = proxy.clone();
+    thread::spawn(move || {
+        proxy
+            .send_event(EventLoopMessage::AccentColorChanged(read_accent_color()))
+            .ok();
+    });
+}
+
+/// Run the given command and get its trimmed output, if it succeeded.
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Windows stores the accent color as a `0xAABBGGRR` DWORD in the registry.
+#[cfg(target_os = "windows")]
+fn read_accent_color() -> Option<Color> {
+    let output = command_output(
+        "reg",
+        &[
+            "query",
+            r"HKCU\Software\Microsoft\Windows\DWM",
+            "/v",
+            "AccentColor",
+        ],
+    )?;
+    let value = output.split_whitespace().last()?.trim_start_matches("0x");
+    let abgr = u32::from_str_radix(value, 16).ok()?;
+    Some(Color::from_rgb(
+        abgr as u8,
+        (abgr >> 8) as u8,
+        (abgr >> 16) as u8,
+    ))
+}
+
+/// macOS stores the index of the accent color, which is blue when it's missing.
+#[cfg(target_os = "macos")]
+fn read_accent_color() -> Option<Color> {
+    let index = command_output("defaults", &["read", "-g", "AppleAccentColor"])
+        .and_then(|output| output.parse::<i8>().ok());
+    let (r, g, b) = match index {
+        Some(-1) => (142, 142, 147),
+        Some(0) => (255, 59, 48),
+        Some(1) => (255, 149, 0),
+        Some(2) => (255, 204, 0),
+        Some(3) => (40, 205, 65),
+        Some(5) => (175, 82, 222),
+        Some(6) => (255, 45, 85),
+        _ => (0, 122, 255),
+    };
+    Some(Color::from_rgb(r, g, b))
+}
+
+/// GNOME stores the name of the accent color.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn read_accent_color() -> Option<Color> {
+    let output = command_output(
+        "gsettings",
+        &["get", "org.gnome.desktop.interface", "accent-color"],
+    )?;
+    let (r, g, b) = match output.trim_matches('\'') {
+        "blue" => (53, 132, 228),
+        "teal" => (33, 144, 164),
+        "green" => (58, 148, 74),
+        "yellow" => (200, 136, 0),
+        "orange" => (237, 91, 0),
+        "red" => (230, 45, 66),
+        "pink" => (213, 97, 153),
+        "purple" => (145, 65, 172),
+        "slate" => (111, 131, 150),
+        _ => return None,
+    };
+    Some(Color::from_rgb(r, g, b))
+}
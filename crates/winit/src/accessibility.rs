@@ -8,7 +8,10 @@ use freya_core::{
     dom::DioxusDOM,
     event_loop_messages::EventLoopMessage,
     states::AccessibilityNodeState,
-    types::NativePlatformSender,
+    types::{
+        AccessibilityId,
+        NativePlatformSender,
+    },
 };
 use freya_native_core::{
     prelude::NodeImmutable,
@@ -16,10 +19,6 @@ use freya_native_core::{
 };
 use torin::torin::Torin;
 use winit::{
-    dpi::{
-        LogicalPosition,
-        LogicalSize,
-    },
     event::WindowEvent,
     event_loop::EventLoopProxy,
     window::Window,
@@ -30,6 +29,8 @@ pub struct WinitAcessibilityTree {
     accessibility_tree: AccessibilityTree,
     accessibility_adapter: Adapter,
     adapter_initialized: bool,
+    /// Focus of the latest update sent to the adapter.
+    last_focus: Option<AccessibilityId>,
 }
 
 impl WinitAcessibilityTree {
@@ -40,6 +41,7 @@ impl WinitAcessibilityTree {
             accessibility_tree,
             accessibility_adapter,
             adapter_initialized: false,
+            last_focus: None,
         }
     }
 
@@ -60,6 +62,7 @@ impl WinitAcessibilityTree {
         dirty_nodes: &mut AccessibilityDirtyNodes,
     ) {
         let tree = self.accessibility_tree.init(rdom, layout, dirty_nodes);
+        self.last_focus = Some(tree.focus);
         self.accessibility_adapter.update_if_active(|| {
             self.adapter_initialized = true;
             tree
@@ -72,13 +75,18 @@ impl WinitAcessibilityTree {
         rdom: &DioxusDOM,
         layout: &Torin<NodeId>,
         platform_sender: &NativePlatformSender,
-        window: &Window,
         dirty_nodes: &mut AccessibilityDirtyNodes,
     ) {
         let (tree, node_id) = self
             .accessibility_tree
             .process_updates(rdom, layout, dirty_nodes);
 
+        // Nothing changed since the last update
+        if tree.nodes.is_empty() && self.last_focus == Some(tree.focus) {
+            return;
+        }
+        self.last_focus = Some(tree.focus);
+
         // Notify the components
         platform_sender.send_modify(|state| {
             state.focused_accessibility_id = tree.focus;
@@ -89,21 +97,6 @@ impl WinitAcessibilityTree {
                 AccessibilityTree::create_node(&node_ref, layout_node, &node_accessibility)
         });
 
-        // Update the Window IME Position
-        let layout_node = layout.get(node_id);
-        if let Some(layout_node) = layout_node {
-            let area = layout_node.visible_area();
-            window.set_ime_cursor_area(
-                LogicalPosition::new(area.min_x(), area.min_y()),
-                LogicalSize::new(area.width(), area.height()),
-            );
-        } else {
-            window.set_ime_cursor_area(
-                window.inner_position().unwrap_or_default(),
-                LogicalSize::<u32>::default(),
-            );
-        }
-
         if self.adapter_initialized {
             // Update the Adapter
             self.accessibility_adapter.update_if_active(|| tree);
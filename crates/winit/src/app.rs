@@ -1,9 +1,14 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::Instant,
+};
 
 use accesskit::{
     NodeBuilder,
     Role,
 };
+use bytes::Bytes;
 use dioxus_core::{
     Event,
     VirtualDom,
@@ -13,41 +18,84 @@ use freya_core::{
         AccessibilityFocusStrategy,
         ACCESSIBILITY_ROOT_ID,
     },
+    custom_attributes::NodeReference,
     dom::SafeDOM,
     event_loop_messages::{
         EventLoopMessage,
         TextGroupMeasurement,
     },
     events::{
+        cursor_icon_at,
         process_events,
         NodesState,
         PlatformEvent,
     },
+    fonts::{
+        FontError,
+        FontFallbacks,
+        FontRegistry,
+        FontSource,
+    },
     layout::process_layout,
+    open_requests::LaunchOpenRequests,
+    platform::CursorImage,
     platform_state::{
+        FramePacing,
+        FramePhase,
+        FrameStatsRecorder,
+        Monitors,
         NativePlatformState,
         NavigationMode,
         PlatformInformation,
+        SoftKeyboard,
     },
     plugins::{
         PluginEvent,
         PluginHandle,
         PluginsManager,
     },
+    printing::{
+        PrintError,
+        PrintOptions,
+    },
+    profile_span,
     render::{
+        capture_surface_frame,
+        export_node,
+        find_node_by_reference,
+        ime_cursor_area,
+        print_node,
+        snapshot_node,
+        CapturedFrame,
         Compositor,
+        DamageTracker,
+        ExportFormat,
+        ExportTarget,
+        ParagraphCache,
+        PrintDocument,
         RenderPipeline,
+        RenderedLayer,
     },
     types::{
+        CloseRequestSender,
         EventEmitter,
         EventReceiver,
         EventsQueue,
+        GlobalShortcutEventSender,
+        NativeMenuEventSender,
         NativePlatformReceiver,
         NativePlatformSender,
+        NativeTrayEventSender,
+        OpenRequestSender,
+        SecondInstanceSender,
     },
 };
 use freya_engine::prelude::*;
-use freya_native_core::prelude::NodeImmutableDioxusExt;
+use freya_native_core::{
+    attributes::AttributeName,
+    prelude::NodeImmutableDioxusExt,
+    NodeId,
+};
 use futures_task::Waker;
 use futures_util::Future;
 use pin_utils::pin_mut;
@@ -59,14 +107,28 @@ use tokio::{
         watch,
     },
 };
-use torin::geometry::Area;
+use torin::geometry::{
+    Area,
+    CursorPoint,
+};
 use winit::{
-    dpi::PhysicalSize,
-    event_loop::EventLoopProxy,
-    window::Window,
+    dpi::{
+        PhysicalPosition,
+        PhysicalSize,
+    },
+    event_loop::{
+        ActiveEventLoop,
+        EventLoopProxy,
+    },
+    window::{
+        CursorIcon,
+        CustomCursor,
+        Window,
+    },
 };
 
 use crate::{
+    accent_color::request_accent_color,
     accessibility::WinitAcessibilityTree,
     devtools::{
         Devtools,
@@ -82,6 +144,7 @@ pub struct Application {
     pub(crate) sdom: SafeDOM,
     pub(crate) vdom: VirtualDom,
     pub(crate) compositor: Compositor,
+    pub(crate) damage_tracker: DamageTracker,
     pub(crate) events: EventsQueue,
     pub(crate) vdom_waker: Waker,
     pub(crate) proxy: EventLoopProxy<EventLoopMessage>,
@@ -93,13 +156,31 @@ pub struct Application {
     pub(crate) platform_receiver: NativePlatformReceiver,
     pub(crate) accessibility: WinitAcessibilityTree,
     pub(crate) font_collection: FontCollection,
-    pub(crate) font_mgr: FontMgr,
+    pub(crate) fonts: FontRegistry,
     pub(crate) ticker_sender: broadcast::Sender<()>,
+    pub(crate) native_menu_sender: NativeMenuEventSender,
+    pub(crate) native_tray_sender: NativeTrayEventSender,
+    pub(crate) global_shortcut_sender: GlobalShortcutEventSender,
+    pub(crate) close_request_sender: CloseRequestSender,
+    pub(crate) open_request_sender: OpenRequestSender,
+    pub(crate) second_instance_sender: SecondInstanceSender,
+    /// Links and files the app was launched with, handled once the app subscribes to them.
+    pub(crate) launch_open_requests: LaunchOpenRequests,
     pub(crate) plugins: PluginsManager,
     pub(crate) process_layout_on_next_render: bool,
     pub(crate) process_accessibility_on_next_render: bool,
     pub(crate) init_accessibility_on_next_render: bool,
-    pub(crate) default_fonts: Vec<String>,
+    pub(crate) frame_capture: Option<mpsc::UnboundedSender<CapturedFrame>>,
+    pub(crate) frame_stats: FrameStatsRecorder,
+    pub(crate) last_frame_at: Option<Instant>,
+    pub(crate) scheduled_frame: Option<Instant>,
+    /// Area of the IME candidate window sent to the window.
+    pub(crate) ime_cursor_area: Option<Area>,
+    /// Paragraph shaped to place the IME candidate window, kept while its text and style don't change.
+    pub(crate) ime_paragraph_cache: ParagraphCache,
+    /// Cursor icon of the hovered nodes, set with the `cursor` attribute.
+    pub(crate) hovered_cursor_icon: Option<CursorIcon>,
+    pub(crate) custom_cursors: HashMap<CursorImage, CustomCursor>,
 }
 
 impl Application {
@@ -113,30 +194,27 @@ impl Application {
         fonts_config: EmbeddedFonts,
         plugins: PluginsManager,
         default_fonts: Vec<String>,
+        font_fallbacks: FontFallbacks,
         accessibility: WinitAcessibilityTree,
     ) -> Self {
-        let mut font_collection = FontCollection::new();
-        let def_mgr = FontMgr::default();
-
-        let mut provider = TypefaceFontProvider::new();
-
-        for (font_name, font_data) in fonts_config {
-            let ft_type = def_mgr.new_from_data(font_data, None).unwrap();
-            provider.register_typeface(ft_type, Some(font_name));
-        }
-
-        let font_mgr: FontMgr = provider.into();
-        font_collection.set_default_font_manager(def_mgr, None);
-        font_collection.set_dynamic_font_manager(font_mgr.clone());
+        let (fonts, font_collection) =
+            FontRegistry::new(&fonts_config, default_fonts, font_fallbacks);
 
         let (event_emitter, event_receiver) = mpsc::unbounded_channel();
         let (platform_sender, platform_receiver) = watch::channel(NativePlatformState {
             focused_accessibility_id: ACCESSIBILITY_ROOT_ID,
             focused_accessibility_node: NodeBuilder::new(Role::Window).build(),
             preferred_theme: window.theme().map(|theme| theme.into()).unwrap_or_default(),
+            accent_color: None,
             navigation_mode: NavigationMode::default(),
             information: PlatformInformation::from_winit(window),
             scale_factor: window.scale_factor(),
+            monitors: Monitors::from_winit(window),
+            soft_keyboard: SoftKeyboard::default(),
+            selected_text: None,
+            is_window_focused: window.has_focus(),
+            is_suspended: false,
+            last_user_input: Instant::now(),
         });
 
         let mut app = Self {
@@ -153,14 +231,29 @@ impl Application {
             platform_sender,
             platform_receiver,
             font_collection,
-            font_mgr,
+            fonts,
             ticker_sender: broadcast::channel(5).0,
+            native_menu_sender: broadcast::channel(16).0,
+            native_tray_sender: broadcast::channel(16).0,
+            global_shortcut_sender: broadcast::channel(16).0,
+            close_request_sender: broadcast::channel(4).0,
+            open_request_sender: broadcast::channel(16).0,
+            second_instance_sender: broadcast::channel(16).0,
+            launch_open_requests: LaunchOpenRequests::default(),
             plugins,
             process_layout_on_next_render: false,
             process_accessibility_on_next_render: false,
             init_accessibility_on_next_render: false,
-            default_fonts,
             compositor: Compositor::default(),
+            damage_tracker: DamageTracker::default(),
+            frame_capture: None,
+            frame_stats: FrameStatsRecorder::default(),
+            last_frame_at: None,
+            scheduled_frame: None,
+            ime_cursor_area: None,
+            ime_paragraph_cache: ParagraphCache::new(1),
+            hovered_cursor_icon: None,
+            custom_cursors: HashMap::new(),
         };
 
         app.plugins.send(
@@ -168,6 +261,8 @@ impl Application {
             PluginHandle::new(&app.proxy),
         );
 
+        request_accent_color(&app.proxy);
+
         app
     }
 
@@ -188,6 +283,20 @@ impl Application {
             .insert_any_root_context(Box::new(self.platform_receiver.clone()));
         self.vdom
             .insert_any_root_context(Box::new(Arc::new(self.ticker_sender.subscribe())));
+        self.vdom
+            .insert_any_root_context(Box::new(self.native_menu_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.native_tray_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.global_shortcut_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.close_request_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.open_request_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.second_instance_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.launch_open_requests.clone()));
         self.vdom
             .insert_any_root_context(Box::new(self.sdom.get().accessibility_generator().clone()));
 
@@ -219,7 +328,9 @@ impl Application {
 
         if repaint {
             if let Some(devtools) = &self.devtools {
-                devtools.update(&self.sdom.get());
+                let fdom = self.sdom.get();
+                devtools.update(&fdom);
+                devtools.record_updated_nodes(&fdom);
             }
         }
 
@@ -228,6 +339,8 @@ impl Application {
 
     /// Poll the VirtualDOM for any new change
     pub fn poll_vdom(&mut self, window: &Window) {
+        let _span = profile_span!("poll_vdom");
+        let started_at = Instant::now();
         let mut cx = std::task::Context::from_waker(&self.vdom_waker);
 
         {
@@ -241,6 +354,9 @@ impl Application {
                                 .get(event.node_id)
                                 .and_then(|node| node.mounted_id())
                             {
+                                if let Some(devtools) = &self.devtools {
+                                    devtools.record_handler(event.name, event.node_id);
+                                }
                                 let name = event.name.into();
                                 let data = event.data.any();
                                 let event = Event::new(data, event.bubbles);
@@ -260,10 +376,21 @@ impl Application {
                 std::task::Poll::Ready(_) => {
                     self.proxy.send_event(EventLoopMessage::PollVDOM).ok();
                 }
-                std::task::Poll::Pending => return,
+                std::task::Poll::Pending => {
+                    self.frame_stats
+                        .record_phase(FramePhase::PollVDOM, started_at.elapsed());
+                    return;
+                }
             }
         }
 
+        self.update_dom(window);
+        self.frame_stats
+            .record_phase(FramePhase::PollVDOM, started_at.elapsed());
+    }
+
+    /// Apply the pending DOM changes and request a redraw if anything changed.
+    fn update_dom(&mut self, window: &Window) {
         let (must_repaint, must_relayout) = self.render_mutations(window.scale_factor() as f32);
 
         if must_relayout {
@@ -276,6 +403,35 @@ impl Application {
         }
     }
 
+    /// Override the value of an attribute of a Node, or remove the override if `None`.
+    pub fn set_attribute_override(
+        &mut self,
+        node_id: NodeId,
+        attribute: AttributeName,
+        value: Option<String>,
+        window: &Window,
+    ) {
+        match value {
+            Some(value) => self
+                .sdom
+                .get_mut()
+                .set_attribute_override(node_id, attribute, value),
+            None => self
+                .sdom
+                .get_mut()
+                .remove_attribute_override(node_id, attribute),
+        }
+
+        self.update_dom(window);
+    }
+
+    /// Remove all the attribute overrides.
+    pub fn clear_attribute_overrides(&mut self, window: &Window) {
+        self.sdom.get_mut().clear_attribute_overrides();
+
+        self.update_dom(window);
+    }
+
     /// Process the events queue
     pub fn process_events(&mut self, scale_factor: f64) {
         let focus_id = self.accessibility.focused_node_id();
@@ -283,6 +439,9 @@ impl Application {
             PluginEvent::StartedMeasuringEvents,
             PluginHandle::new(&self.proxy),
         );
+        if let Some(devtools) = &self.devtools {
+            devtools.record_platform_events(&self.sdom.get(), &self.events);
+        }
         process_events(
             &self.sdom.get(),
             &mut self.events,
@@ -306,7 +465,7 @@ impl Application {
             .init_accessibility(rdom, &layout, &mut dirty_accessibility_tree);
     }
 
-    pub fn process_accessibility(&mut self, window: &Window) {
+    pub fn process_accessibility(&mut self) {
         let fdom = self.sdom.get();
         let rdom = fdom.rdom();
         let layout = fdom.layout();
@@ -315,18 +474,38 @@ impl Application {
             rdom,
             &layout,
             &self.platform_sender,
-            window,
             &mut dirty_accessibility_tree,
         );
     }
 
+    /// Update the selected text with the given event.
+    fn process_text_selection(&mut self, event: &PlatformEvent, scale_factor: f64) {
+        let changed =
+            self.sdom
+                .get_mut()
+                .process_text_selection(event, scale_factor, &self.platform_sender);
+        if changed {
+            self.proxy
+                .send_event(EventLoopMessage::RequestRerender)
+                .ok();
+        }
+    }
+
     /// Send an event
     pub fn send_event(&mut self, event: PlatformEvent, scale_factor: f64) {
+        self.plugins.send(
+            PluginEvent::PlatformEvent(&event),
+            PluginHandle::new(&self.proxy),
+        );
+        self.process_text_selection(&event, scale_factor);
         self.events.push(event);
         self.process_events(scale_factor);
     }
 
-    /// Render the App into the Window Canvas
+    /// Render the App into the Window Canvas.
+    ///
+    /// Returns the area of the surface that was updated, or `None` if it was fully updated.
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         &mut self,
         hovered_node: &HoveredNode,
@@ -335,7 +514,10 @@ impl Application {
         dirty_surface: &mut Surface,
         window: &Window,
         scale_factor: f64,
-    ) {
+        buffer_age: u32,
+        debug_layout: bool,
+    ) -> Option<Area> {
+        let started_at = Instant::now();
         self.plugins.send(
             PluginEvent::BeforeRender {
                 canvas: surface.canvas(),
@@ -345,13 +527,34 @@ impl Application {
             PluginHandle::new(&self.proxy),
         );
 
-        self.start_render(
+        // Plugins drawing between the layers need every layer to be painted again
+        if self.plugins.draws_between_layers() {
+            self.compositor.reset();
+            self.sdom
+                .get()
+                .compositor_dirty_area()
+                .unite_or_insert(&Area::new(
+                    (0.0, 0.0).into(),
+                    window.inner_size().to_torin(),
+                ));
+        }
+
+        // Plugins might draw anywhere in the surface
+        let buffer_age = if self.plugins.is_empty() {
+            buffer_age
+        } else {
+            0
+        };
+
+        let damage = self.start_render(
             hovered_node,
             background,
             surface,
             dirty_surface,
             window.inner_size(),
             scale_factor as f32,
+            buffer_age,
+            debug_layout,
         );
 
         self.plugins.send(
@@ -362,14 +565,28 @@ impl Application {
             },
             PluginHandle::new(&self.proxy),
         );
+
+        self.frame_stats
+            .record_phase(FramePhase::Paint, started_at.elapsed());
+
+        if let Some(frame_capture) = &self.frame_capture {
+            if let Some(frame) = capture_surface_frame(surface) {
+                // Stop capturing once the receiver is gone
+                if frame_capture.send(frame).is_err() {
+                    self.frame_capture = None;
+                }
+            }
+        }
+
+        damage
     }
 
     /// Resize the Window
     pub fn resize(&mut self, window: &Window) {
         self.process_layout_on_next_render = true;
         self.process_accessibility_on_next_render = true;
-        self.init_accessibility_on_next_render = true;
         self.compositor.reset();
+        self.damage_tracker.reset();
         self.sdom
             .get()
             .compositor_dirty_area()
@@ -383,6 +600,107 @@ impl Application {
         })
     }
 
+    /// Register a font, the texts are measured again as they might use it.
+    pub fn register_font(
+        &mut self,
+        source: FontSource,
+        alias: Option<String>,
+        window: &Window,
+    ) -> Result<String, FontError> {
+        let family = self
+            .fonts
+            .register(source, alias.as_deref(), &mut self.font_collection)?;
+        self.invalidate_fonts(window);
+        Ok(family)
+    }
+
+    /// Replace the chains of fallback fonts, the texts are measured again with them.
+    pub fn set_font_fallbacks(&mut self, fallbacks: FontFallbacks, window: &Window) {
+        if *self.fonts.fallbacks() == fallbacks {
+            return;
+        }
+        self.fonts.set_fallbacks(fallbacks);
+        self.invalidate_fonts(window);
+    }
+
+    fn invalidate_fonts(&mut self, window: &Window) {
+        self.process_layout_on_next_render = true;
+        self.process_accessibility_on_next_render = true;
+        self.compositor.reset();
+        self.damage_tracker.reset();
+        let fdom = self.sdom.get();
+        fdom.invalidate_fonts();
+        self.ime_paragraph_cache.clear();
+        fdom.compositor_dirty_area().unite_or_insert(&Area::new(
+            (0.0, 0.0).into(),
+            window.inner_size().to_torin(),
+        ));
+        window.request_redraw();
+    }
+
+    /// Rasterize the node with the given reference into a PNG image.
+    pub fn snapshot_node(
+        &mut self,
+        reference: &NodeReference,
+        scale: f32,
+        scale_factor: f64,
+    ) -> Option<Bytes> {
+        let fdom = self.sdom.get();
+        let node_id = find_node_by_reference(&fdom, reference)?;
+        let data = snapshot_node(
+            &fdom,
+            node_id,
+            scale,
+            scale_factor as f32,
+            &mut self.font_collection,
+            self.fonts.font_mgr(),
+            self.fonts.default_fonts(),
+        )?;
+        Some(Bytes::copy_from_slice(&data))
+    }
+
+    /// Render the window or a node into a PDF or SVG document.
+    pub fn export(
+        &mut self,
+        target: &ExportTarget,
+        format: ExportFormat,
+        scale_factor: f64,
+    ) -> Option<Bytes> {
+        let fdom = self.sdom.get();
+        let node_id = target.find_node(&fdom)?;
+        let data = export_node(
+            &fdom,
+            node_id,
+            format,
+            scale_factor as f32,
+            &mut self.font_collection,
+            self.fonts.font_mgr(),
+            self.fonts.default_fonts(),
+        )?;
+        Some(Bytes::from(data))
+    }
+
+    /// Render the window or a node into a paginated document ready to be printed.
+    pub fn print(
+        &mut self,
+        target: &ExportTarget,
+        options: &PrintOptions,
+        scale_factor: f64,
+    ) -> Result<PrintDocument, PrintError> {
+        let fdom = self.sdom.get();
+        let node_id = target.find_node(&fdom).ok_or(PrintError::NothingToPrint)?;
+        print_node(
+            &fdom,
+            node_id,
+            options,
+            scale_factor as f32,
+            &mut self.font_collection,
+            self.fonts.font_mgr(),
+            self.fonts.default_fonts(),
+        )
+        .ok_or(PrintError::NothingToPrint)
+    }
+
     /// Measure the a text group given it's ID.
     pub fn measure_text_group(&self, text_measurement: TextGroupMeasurement, scale_factor: f64) {
         self.sdom
@@ -404,11 +722,131 @@ impl Application {
         self.process_accessibility_on_next_render = true;
     }
 
+    /// Get when the next frame should be rendered if it is too early according to the [FramePacing].
+    pub fn frame_deadline(
+        &self,
+        frame_pacing: &FramePacing,
+        is_background: bool,
+    ) -> Option<Instant> {
+        let deadline = self.last_frame_at? + frame_pacing.frame_interval(is_background)?;
+        (Instant::now() < deadline).then_some(deadline)
+    }
+
+    /// Check if anything needs to be rendered again.
+    pub fn has_pending_changes(&self) -> bool {
+        let fdom = self.sdom.get();
+        self.compositor.is_full_render()
+            || fdom.compositor_dirty_area().is_some()
+            || !fdom.compositor_dirty_nodes().is_empty()
+            || self.frame_capture.is_some()
+    }
+
     /// Notify components subscribed to event loop ticks.
     pub fn event_loop_tick(&self) {
         self.ticker_sender.send(()).ok();
     }
 
+    /// Refresh the [Monitors], notifying the components only if they changed.
+    pub fn update_monitors(&mut self, window: &Window) {
+        let monitors = Monitors::from_winit(window);
+        self.platform_sender.send_if_modified(|state| {
+            let is_different = state.monitors != monitors;
+            state.monitors = monitors;
+            is_different
+        });
+    }
+
+    /// Place the IME candidate window next to the cursor of the focused node.
+    pub fn update_ime_cursor_area(&mut self, window: &Window, scale_factor: f64) {
+        let area = {
+            let fdom = self.sdom.get();
+            self.accessibility
+                .focused_node_id()
+                .and_then(|focused_node_id| {
+                    self.ime_paragraph_cache
+                        .prepare(scale_factor as f32, self.fonts.default_fonts());
+                    ime_cursor_area(
+                        &fdom,
+                        focused_node_id,
+                        &mut self.ime_paragraph_cache,
+                        &mut self.font_collection,
+                        self.fonts.default_fonts(),
+                        scale_factor as f32,
+                    )
+                })
+        };
+
+        if self.ime_cursor_area == area {
+            return;
+        }
+        self.ime_cursor_area = area;
+
+        if let Some(area) = area {
+            window.set_ime_cursor_area(
+                PhysicalPosition::new(area.min_x(), area.min_y()),
+                PhysicalSize::new(area.width(), area.height()),
+            );
+        } else {
+            window.set_ime_cursor_area(
+                window.inner_position().unwrap_or_default(),
+                PhysicalSize::<u32>::default(),
+            );
+        }
+    }
+
+    /// Show the cursor icon of the hovered nodes.
+    ///
+    /// The default icon is only restored when leaving the nodes with a `cursor` attribute,
+    /// so icons set manually elsewhere are kept.
+    pub fn update_cursor_icon(&mut self, window: &Window, cursor: CursorPoint, scale_factor: f64) {
+        let cursor_icon = cursor_icon_at(&self.sdom.get(), cursor, scale_factor);
+
+        if self.hovered_cursor_icon == cursor_icon {
+            return;
+        }
+        self.hovered_cursor_icon = cursor_icon;
+
+        window.set_cursor(cursor_icon.unwrap_or_default());
+    }
+
+    /// Use an image as cursor, the native cursors are created only once per image.
+    pub fn set_custom_cursor(
+        &mut self,
+        cursor_image: CursorImage,
+        window: &Window,
+        event_loop: &ActiveEventLoop,
+    ) {
+        if !self.custom_cursors.contains_key(&cursor_image) {
+            let source = match CustomCursor::from_rgba(
+                cursor_image.rgba.clone(),
+                cursor_image.width,
+                cursor_image.height,
+                cursor_image.hotspot.0,
+                cursor_image.hotspot.1,
+            ) {
+                Ok(source) => source,
+                Err(err) => {
+                    tracing::error!("Failed to create custom cursor: {err}");
+                    return;
+                }
+            };
+            let custom_cursor = event_loop.create_custom_cursor(source);
+            self.custom_cursors
+                .insert(cursor_image.clone(), custom_cursor);
+        }
+
+        window.set_cursor(self.custom_cursors[&cursor_image].clone());
+    }
+
+    /// Update the [SoftKeyboard], notifying the components only if it changed.
+    pub fn set_soft_keyboard(&mut self, soft_keyboard: SoftKeyboard) {
+        self.platform_sender.send_if_modified(|state| {
+            let is_different = state.soft_keyboard != soft_keyboard;
+            state.soft_keyboard = soft_keyboard;
+            is_different
+        });
+    }
+
     /// Update the [NavigationMode].
     pub fn set_navigation_mode(&mut self, navigation_mode: NavigationMode) {
         self.platform_sender.send_modify(|state| {
@@ -418,6 +856,7 @@ impl Application {
 
     /// Measure the layout
     pub fn process_layout(&mut self, window_size: PhysicalSize<u32>, scale_factor: f64) {
+        let started_at = Instant::now();
         {
             let fdom = self.sdom.get();
 
@@ -431,13 +870,26 @@ impl Application {
                 Area::from_size(window_size.to_torin()),
                 &mut self.font_collection,
                 scale_factor as f32,
-                &self.default_fonts,
+                self.fonts.default_fonts(),
             );
 
             self.plugins.send(
                 PluginEvent::FinishedMeasuringLayout(&fdom.layout()),
                 PluginHandle::new(&self.proxy),
             );
+
+            self.plugins.send(
+                PluginEvent::AfterLayout {
+                    freya_dom: &fdom,
+                    scale_factor: scale_factor as f32,
+                },
+                PluginHandle::new(&self.proxy),
+            );
+        }
+
+        let text_overflow_events = self.sdom.get_mut().process_text_overflow();
+        if !text_overflow_events.is_empty() {
+            self.event_emitter.send(text_overflow_events).ok();
         }
 
         if let Some(devtools) = &self.devtools {
@@ -453,9 +905,12 @@ impl Application {
                 fdom.paragraphs().len()
             );
         }
+        self.frame_stats
+            .record_phase(FramePhase::Layout, started_at.elapsed());
     }
 
     /// Start rendering the RealDOM to Window
+    #[allow(clippy::too_many_arguments)]
     pub fn start_render(
         &mut self,
         hovered_node: &HoveredNode,
@@ -464,18 +919,31 @@ impl Application {
         dirty_surface: &mut Surface,
         window_size: PhysicalSize<u32>,
         scale_factor: f32,
-    ) {
+        buffer_age: u32,
+        debug_layout: bool,
+    ) -> Option<Area> {
         let fdom = self.sdom.get();
         let hovered_node = hovered_node
             .as_ref()
             .and_then(|hovered_node| *hovered_node.lock().unwrap());
 
+        let draws_between_layers = self.plugins.draws_between_layers();
+        let plugins = &mut self.plugins;
+        let plugin_handle = PluginHandle::new(&self.proxy);
+        let mut layer_hook = |rendered_layer: RenderedLayer| {
+            plugins.send(
+                PluginEvent::AfterRenderLayer(rendered_layer),
+                plugin_handle.clone(),
+            )
+        };
+
         let mut render_pipeline = RenderPipeline {
             canvas_area: Area::from_size(window_size.to_torin()),
             rdom: fdom.rdom(),
             compositor_dirty_area: &mut fdom.compositor_dirty_area(),
             compositor_dirty_nodes: &mut fdom.compositor_dirty_nodes(),
             compositor_cache: &mut fdom.compositor_cache(),
+            raster_cache: &mut fdom.raster_cache(),
             layers: &mut fdom.layers(),
             layout: &mut fdom.layout(),
             background,
@@ -484,11 +952,19 @@ impl Application {
             compositor: &mut self.compositor,
             scale_factor,
             selected_node: hovered_node,
+            debug_layout,
             font_collection: &mut self.font_collection,
-            font_manager: &self.font_mgr,
-            default_fonts: &self.default_fonts,
+            font_manager: self.fonts.font_mgr(),
+            default_fonts: self.fonts.default_fonts(),
             images_cache: &mut fdom.images_cache(),
+            damage_tracker: &mut self.damage_tracker,
+            buffer_age,
+            layer_hook: if draws_between_layers {
+                Some(&mut layer_hook)
+            } else {
+                None
+            },
         };
-        render_pipeline.run();
+        render_pipeline.run()
     }
 }
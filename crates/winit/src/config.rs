@@ -2,7 +2,21 @@ use std::io::Cursor;
 
 use freya_core::{
     event_loop_messages::EventLoopMessage,
+    fonts::{
+        FontFallbacks,
+        FontScript,
+    },
+    native_menu::NativeMenu,
+    native_tray::{
+        NativeTray,
+        NativeTrayIcon,
+    },
     parsing::Parse,
+    platform::CursorImage,
+    platform_state::{
+        FramePacing,
+        WindowEffect,
+    },
     plugins::{
         FreyaPlugin,
         PluginsManager,
@@ -39,6 +53,8 @@ pub struct WindowConfig {
     pub title: &'static str,
     /// Make the Window transparent or not.
     pub transparent: bool,
+    /// Effect shown behind the transparent areas of the Window.
+    pub window_effect: Option<WindowEffect>,
     /// Background color of the Window.
     pub background: Color,
     /// Window visibility. Default to `true`.
@@ -53,6 +69,24 @@ pub struct WindowConfig {
     pub window_attributes_hook: Option<WindowBuilderHook>,
     /// Hook function called with the Event Loop Builder.
     pub event_loop_builder_hook: Option<EventLoopBuilderHook>,
+    /// How frames are paced and presented.
+    pub frame_pacing: FramePacing,
+    /// Menu bar rendered by the operating system.
+    pub menu: Option<NativeMenu>,
+    /// Icon in the system tray.
+    pub tray: Option<NativeTray>,
+    /// Also send mouse events for touches, so components made for the mouse can be used with the fingers.
+    /// Defaults to `true` in touch platforms (Android and iOS).
+    pub touch_as_mouse: bool,
+    /// Paint the bounds, margins, paddings and alignments of every node over the app.
+    /// Toggle it with `Ctrl+Shift+L`.
+    pub debug_layout: bool,
+    /// Key under which the size, position and maximized state of the Window are remembered across runs.
+    pub remember_window_state: Option<String>,
+    /// Custom URL schemes opened with this app, e.g. `myapp` for `myapp://settings`.
+    pub url_schemes: Vec<String>,
+    /// Id under which only one instance of the app can run, the others forward their arguments to it.
+    pub single_instance: Option<String>,
 }
 
 impl Default for WindowConfig {
@@ -64,6 +98,7 @@ impl Default for WindowConfig {
             decorations: true,
             title: "Freya App",
             transparent: false,
+            window_effect: None,
             background: Color::WHITE,
             visible: true,
             icon: None,
@@ -71,6 +106,14 @@ impl Default for WindowConfig {
             on_exit: None,
             window_attributes_hook: None,
             event_loop_builder_hook: None,
+            frame_pacing: FramePacing::default(),
+            menu: None,
+            tray: None,
+            touch_as_mouse: cfg!(any(target_os = "android", target_os = "ios")),
+            debug_layout: false,
+            remember_window_state: None,
+            url_schemes: Vec::new(),
+            single_instance: None,
         }
     }
 }
@@ -82,6 +125,7 @@ pub struct LaunchConfig<'a, T: Clone = ()> {
     pub embedded_fonts: EmbeddedFonts<'a>,
     pub plugins: PluginsManager,
     pub default_fonts: Vec<String>,
+    pub font_fallbacks: FontFallbacks,
 }
 
 impl<'a, T: Clone> Default for LaunchConfig<'a, T> {
@@ -92,6 +136,7 @@ impl<'a, T: Clone> Default for LaunchConfig<'a, T> {
             embedded_fonts: Default::default(),
             plugins: Default::default(),
             default_fonts: default_fonts(),
+            font_fallbacks: FontFallbacks::default(),
         }
     }
 }
@@ -104,6 +149,27 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
 
 impl LaunchConfig<'_, ()> {
     pub fn load_icon(icon: &[u8]) -> Icon {
+        let (rgba, width, height) = Self::decode_icon(icon);
+        Icon::from_rgba(rgba, width, height).expect("Failed to open icon")
+    }
+
+    pub fn load_tray_icon(icon: &[u8]) -> NativeTrayIcon {
+        let (rgba, width, height) = Self::decode_icon(icon);
+        NativeTrayIcon::from_rgba(rgba, width, height)
+    }
+
+    /// Load an image to use as cursor, the hotspot is the point of the image placed at the pointer.
+    pub fn load_cursor(cursor: &[u8], hotspot: (u16, u16)) -> CursorImage {
+        let (rgba, width, height) = Self::decode_icon(cursor);
+        CursorImage::from_rgba(
+            rgba,
+            width.try_into().expect("Cursor image is too big"),
+            height.try_into().expect("Cursor image is too big"),
+            hotspot,
+        )
+    }
+
+    fn decode_icon(icon: &[u8]) -> (Vec<u8>, u32, u32) {
         let reader = ImageReader::new(Cursor::new(icon))
             .with_guessed_format()
             .expect("Cursor io never fails");
@@ -112,8 +178,7 @@ impl LaunchConfig<'_, ()> {
             .expect("Failed to open icon path")
             .into_rgba8();
         let (width, height) = image.dimensions();
-        let rgba = image.into_raw();
-        Icon::from_rgba(rgba, width, height).expect("Failed to open icon")
+        (image.into_raw(), width, height)
     }
 }
 
@@ -154,6 +219,31 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
         self
     }
 
+    /// Show a platform effect, like the macOS vibrancy or the Windows Mica, behind the transparent areas of the Window.
+    ///
+    /// This makes the Window transparent, so use a translucent background to let the effect through:
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn main() {
+    ///     launch_cfg(
+    ///         app,
+    ///         LaunchConfig::<()>::new()
+    ///             .with_window_effect(WindowEffect::Mica { dark: None })
+    ///             .with_background("transparent"),
+    ///     )
+    /// }
+    ///
+    /// fn app() -> Element {
+    ///     rsx!(label { "Hello, World!" })
+    /// }
+    /// ```
+    pub fn with_window_effect(mut self, window_effect: WindowEffect) -> Self {
+        self.window_config.transparent = true;
+        self.window_config.window_effect = Some(window_effect);
+        self
+    }
+
     /// Pass a custom value that your app will consume.
     pub fn with_state(mut self, state: T) -> Self {
         self.state = Some(state);
@@ -190,12 +280,43 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
         self
     }
 
+    /// Replace the chain of fonts used for the characters of the given script that the default fonts don't have,
+    /// e.g `with_font_fallback(FontScript::Emoji, ["Noto Color Emoji"])`.
+    pub fn with_font_fallback(
+        mut self,
+        script: FontScript,
+        families: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.font_fallbacks.set(script, families);
+        self
+    }
+
+    /// Replace all the chains of fallback fonts.
+    pub fn with_font_fallbacks(mut self, fallbacks: FontFallbacks) -> Self {
+        self.font_fallbacks = fallbacks;
+        self
+    }
+
     /// Specify the Window icon.
     pub fn with_icon(mut self, icon: Icon) -> Self {
         self.window_config.icon = Some(icon);
         self
     }
 
+    /// Specify the menu bar rendered by the operating system.
+    ///
+    /// Only shown on Windows and macOS, see [NativeMenu] for the other platforms.
+    pub fn with_menu(mut self, menu: NativeMenu) -> Self {
+        self.window_config.menu = Some(menu);
+        self
+    }
+
+    /// Specify the icon in the system tray.
+    pub fn with_tray(mut self, tray: NativeTray) -> Self {
+        self.window_config.tray = Some(tray);
+        self
+    }
+
     /// Register a callback that will be executed when the window is created.
     pub fn on_setup(mut self, callback: impl FnOnce(&mut Window) + 'static) -> Self {
         self.window_config.on_setup = Some(Box::new(callback));
@@ -231,4 +352,136 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
         self.window_config.event_loop_builder_hook = Some(Box::new(event_loop_builder_hook));
         self
     }
+
+    /// Specify how frames are paced and presented.
+    pub fn with_frame_pacing(mut self, frame_pacing: FramePacing) -> Self {
+        self.window_config.frame_pacing = frame_pacing;
+        self
+    }
+
+    /// Enable or disable waiting for the display refresh before presenting a frame.
+    pub fn with_vsync(mut self, vsync: bool) -> Self {
+        self.window_config.frame_pacing.vsync = vsync;
+        self
+    }
+
+    /// Limit the amount of frames rendered per second, useful to save battery.
+    pub fn with_max_fps(mut self, max_fps: u32) -> Self {
+        self.window_config.frame_pacing.max_fps = Some(max_fps);
+        self
+    }
+
+    /// Skip the requested frames in which nothing changed.
+    pub fn with_render_on_change(mut self, render_on_change: bool) -> Self {
+        self.window_config.frame_pacing.render_on_change = render_on_change;
+        self
+    }
+
+    /// Limit the amount of frames rendered per second while the window is unfocused or minimized,
+    /// and pause the animations while it's minimized, to save battery.
+    pub fn with_background_max_fps(mut self, background_max_fps: u32) -> Self {
+        self.window_config.frame_pacing.background_max_fps = Some(background_max_fps);
+        self
+    }
+
+    /// Send mouse events for touches too, enabled by default in touch platforms (Android and iOS).
+    pub fn with_touch_as_mouse(mut self, touch_as_mouse: bool) -> Self {
+        self.window_config.touch_as_mouse = touch_as_mouse;
+        self
+    }
+
+    /// Paint the bounds, margins, paddings and alignments of every node over the app, like the layout inspector of a browser.
+    /// In debug builds it can also be toggled with `Ctrl+Shift+L`.
+    pub fn with_debug_layout(mut self, debug_layout: bool) -> Self {
+        self.window_config.debug_layout = debug_layout;
+        self
+    }
+
+    /// Save the size, position, maximized state and monitor of the Window when closing the app,
+    /// and restore them the next time it's launched with the same key.
+    ///
+    /// The state is saved in the config folder of the platform.
+    /// The position is only restored if the monitor is still connected, otherwise the system places the Window,
+    /// and the size is reduced to fit in the monitor if needed.
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn main() {
+    ///     launch_cfg(
+    ///         app,
+    ///         LaunchConfig::<()>::new()
+    ///             .with_size(900., 600.)
+    ///             .remember_window_state("main"),
+    ///     )
+    /// }
+    ///
+    /// fn app() -> Element {
+    ///     rsx!(label { "Hello, World!" })
+    /// }
+    /// ```
+    pub fn remember_window_state(mut self, key: impl Into<String>) -> Self {
+        self.window_config.remember_window_state = Some(key.into());
+        self
+    }
+
+    /// Open the links with the given custom URL scheme with this app, e.g. `myapp` for `myapp://settings`.
+    ///
+    /// The scheme is registered for the current user on Windows and Linux when the app launches.
+    /// On macOS it must be declared in the `CFBundleURLTypes` of the `Info.plist` of the app bundle instead,
+    /// and the links are only received as arguments, not through Apple Events.
+    ///
+    /// The links and the files the app is opened with are received with `use_open_requests`.
+    /// Combine it with [LaunchConfig::single_instance] so the links open in the running app.
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn main() {
+    ///     launch_cfg(
+    ///         app,
+    ///         LaunchConfig::<()>::new()
+    ///             .with_url_scheme("myapp")
+    ///             .single_instance("com.example.myapp"),
+    ///     )
+    /// }
+    ///
+    /// fn app() -> Element {
+    ///     let mut opened = use_signal(Vec::new);
+    ///
+    ///     use_open_requests(move |request| opened.write().push(request));
+    ///
+    ///     rsx!(label { "Opened {opened.read().len()} links and files" })
+    /// }
+    /// ```
+    pub fn with_url_scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.window_config.url_schemes.push(scheme.into());
+        self
+    }
+
+    /// Only run one instance of the app with the given id, e.g. `com.example.myapp`.
+    ///
+    /// Launching the app again forwards its arguments to the running instance and exits.
+    /// The window of the running instance is brought to front and it receives the arguments with
+    /// `use_second_instance`, and the links and files in them with `use_open_requests`.
+    ///
+    /// The running instance listens in a local port only for the current user, and the id
+    /// should be unique to the app as other apps using the same id would be considered the same.
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn main() {
+    ///     launch_cfg(app, LaunchConfig::<()>::new().single_instance("com.example.editor"))
+    /// }
+    ///
+    /// fn app() -> Element {
+    ///     let mut launches = use_signal(|| 1);
+    ///
+    ///     use_second_instance(move |_args| launches += 1);
+    ///
+    ///     rsx!(label { "Launched {launches} times" })
+    /// }
+    /// ```
+    pub fn single_instance(mut self, app_id: impl Into<String>) -> Self {
+        self.window_config.single_instance = Some(app_id.into());
+        self
+    }
 }
@@ -1,16 +1,39 @@
-use std::sync::{
-    Arc,
-    Mutex,
+use std::{
+    collections::{
+        HashSet,
+        VecDeque,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Instant,
 };
 
+use accesskit::Role;
 use freya_core::{
+    accessibility::{
+        AccessibilityTree,
+        NodeAccessibility,
+    },
     dom::FreyaDOM,
+    events::{
+        PlatformEvent,
+        PlatformEventData,
+    },
     node::{
         get_node_state,
         NodeState,
     },
+    states::AccessibilityNodeState,
+    types::{
+        AccessibilityId,
+        EventsQueue,
+    },
 };
 use freya_native_core::{
+    attributes::AttributeName,
+    events::EventName,
     prelude::{
         NodeId,
         NodeImmutable,
@@ -19,25 +42,170 @@ use freya_native_core::{
 };
 use tokio::sync::watch;
 use torin::prelude::LayoutNode;
+use tracing::{
+    field::{
+        Field,
+        Visit,
+    },
+    span::{
+        Attributes,
+        Id,
+    },
+    Event,
+    Level,
+    Subscriber,
+};
+use tracing_subscriber::{
+    filter::Targets,
+    layer::Context,
+    prelude::*,
+    registry::LookupSpan,
+    Layer,
+};
 
 pub type DevtoolsReceiver = watch::Receiver<Vec<NodeInfo>>;
+pub type TimelineReceiver = watch::Receiver<VecDeque<TimelineEntry>>;
 pub type HoveredNode = Option<Arc<Mutex<Option<NodeId>>>>;
 
+/// Maximum amount of entries kept in the timeline.
+const TIMELINE_CAPACITY: usize = 500;
+
 #[derive(Clone)]
 pub struct Devtools {
     sender: watch::Sender<Vec<NodeInfo>>,
+    timeline: watch::Sender<VecDeque<TimelineEntry>>,
+    /// Nodes of the app, as opposed to those of the Devtools.
+    app_nodes: Arc<Mutex<HashSet<NodeId>>>,
 }
 
 impl Devtools {
     pub fn new() -> (Self, DevtoolsReceiver) {
         let (sender, receiver) = watch::channel(Vec::new());
+        let (timeline, _) = watch::channel(VecDeque::new());
+
+        (
+            Self {
+                sender,
+                timeline,
+                app_nodes: Arc::default(),
+            },
+            receiver,
+        )
+    }
+
+    /// Get notified of the new entries in the timeline.
+    pub fn timeline(&self) -> TimelineReceiver {
+        self.timeline.subscribe()
+    }
 
-        (Self { sender }, receiver)
+    fn record(&self, kind: TimelineEntryKind) {
+        self.timeline.send_modify(|timeline| {
+            if timeline.len() == TIMELINE_CAPACITY {
+                timeline.pop_front();
+            }
+            timeline.push_back(TimelineEntry {
+                time: Instant::now(),
+                kind,
+            });
+        });
+    }
+
+    /// Record the events received from the platform along with the Nodes of the app under the cursor.
+    ///
+    /// Movements of the cursor are ignored, as are the pointer events not hitting the app.
+    pub fn record_platform_events(&self, fdom: &FreyaDOM, events: &EventsQueue) {
+        let app_nodes = self.app_nodes.lock().unwrap();
+        let layout = fdom.layout();
+        let layers = fdom.layers();
+        let mut hit_test_index = fdom.hit_test_index();
+        hit_test_index.update(&layers, &layout, fdom.rdom());
+
+        for PlatformEvent { name, data } in events {
+            if is_movement(*name) {
+                continue;
+            }
+            let cursor = match data {
+                PlatformEventData::Mouse { cursor, .. }
+                | PlatformEventData::Wheel { cursor, .. }
+                | PlatformEventData::File { cursor, .. } => Some(cursor),
+                PlatformEventData::Touch { location, .. } => Some(location),
+                _ => None,
+            };
+            let hit_nodes = cursor
+                .map(|cursor| {
+                    hit_test_index
+                        .query(cursor.to_f32())
+                        .into_iter()
+                        .rev()
+                        .map(|(_, entry)| entry.node_id)
+                        .filter(|node_id| app_nodes.contains(node_id))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            if cursor.is_some() && hit_nodes.is_empty() {
+                continue;
+            }
+
+            self.record(TimelineEntryKind::PlatformEvent {
+                name: *name,
+                hit_nodes,
+            });
+        }
+    }
+
+    /// Record that an event was dispatched to the handler of a Node, except for movements of the cursor.
+    pub fn record_handler(&self, name: EventName, node_id: NodeId) {
+        if !is_movement(name) && self.app_nodes.lock().unwrap().contains(&node_id) {
+            self.record(TimelineEntryKind::HandlerRan { name, node_id });
+        }
+    }
+
+    /// Tracing layer recording the renders of the components and the writes to signals of the app.
+    ///
+    /// Dioxus only traces the writes to signals in debug builds.
+    pub fn tracing_layer<S>(&self) -> impl Layer<S>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        TimelineLayer {
+            devtools: self.clone(),
+        }
+        .with_filter(
+            Targets::new()
+                .with_target("dioxus_core", Level::TRACE)
+                .with_target("dioxus_signals", Level::TRACE),
+        )
+    }
+
+    /// Install [Devtools::tracing_layer] as the global tracing subscriber.
+    /// Does nothing if there is one already.
+    pub fn init_tracing(&self) {
+        tracing_subscriber::registry()
+            .with(self.tracing_layer())
+            .try_init()
+            .ok();
+    }
+
+    /// Record the Nodes of the app updated after their components rendered again.
+    pub fn record_updated_nodes(&self, fdom: &FreyaDOM) {
+        let updated_nodes = {
+            let app_nodes = self.app_nodes.lock().unwrap();
+            fdom.updated_nodes()
+                .iter()
+                .filter(|node_id| app_nodes.contains(node_id))
+                .copied()
+                .collect::<Vec<_>>()
+        };
+        if !updated_nodes.is_empty() {
+            self.record(TimelineEntryKind::NodesUpdated(updated_nodes));
+        }
     }
 
     pub fn update(&self, fdom: &FreyaDOM) {
         let rdom = fdom.rdom();
         let layout = fdom.layout();
+        let attribute_overrides = fdom.attribute_overrides();
 
         let mut new_nodes = Vec::new();
 
@@ -58,6 +226,20 @@ impl Devtools {
                 let has_layout = layout.get(node.id()).is_some();
                 if has_layout {
                     let node_type = node.node_type();
+                    let layout_node = layout.get(node.id()).unwrap();
+                    let accessibility = node.get_accessibility_id().and_then(|id| {
+                        let state = node.get::<AccessibilityNodeState>()?;
+                        // Only the Nodes with a builder are part of the accessibility tree
+                        state.builder.as_ref()?;
+                        let accessibility_node =
+                            AccessibilityTree::create_node(&node, layout_node, &state);
+                        Some(AccessibilityNodeInfo {
+                            id,
+                            role: accessibility_node.role(),
+                            name: accessibility_node.name().map(str::to_string),
+                            is_focusable: state.a11y_focusable.is_enabled(),
+                        })
+                    });
                     new_nodes.push(NodeInfo {
                         id: node.id(),
                         parent_id: node.parent_id(),
@@ -69,12 +251,16 @@ impl Devtools {
                         tag: *node_type.tag().unwrap(),
                         height: node.height(),
                         state: get_node_state(&node),
-                        layout_node: layout.get(node.id()).unwrap().clone(),
+                        layout_node: layout_node.clone(),
+                        attribute_overrides: attribute_overrides.get(node.id()),
+                        accessibility,
                     });
                 }
             }
         });
 
+        *self.app_nodes.lock().unwrap() = new_nodes.iter().map(|node| node.id).collect();
+
         self.sender
             .send(new_nodes)
             .expect("Failed to sync the Devtools.");
@@ -90,4 +276,118 @@ pub struct NodeInfo {
     pub height: u16,
     pub state: NodeState,
     pub layout_node: LayoutNode,
+    /// Attributes whose values were overridden, e.g. from the Devtools.
+    pub attribute_overrides: Vec<(AttributeName, String)>,
+    /// How the Node is exposed to assistive technologies, if it's part of the accessibility tree.
+    pub accessibility: Option<AccessibilityNodeInfo>,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct AccessibilityNodeInfo {
+    pub id: AccessibilityId,
+    pub role: Role,
+    /// Name announced by assistive technologies, the inner text for labels and paragraphs.
+    pub name: Option<String>,
+    pub is_focusable: bool,
+}
+
+/// Context provided by the components of the Devtools, so their renders are left out of the timeline.
+#[derive(Clone, Copy)]
+pub struct DevtoolsScope;
+
+/// Whether the current component is part of the Devtools.
+fn in_devtools_scope() -> bool {
+    dioxus_core::prelude::try_consume_context::<DevtoolsScope>().is_some()
+}
+
+struct TimelineLayer {
+    devtools: Devtools,
+}
+
+impl<S: Subscriber> Layer<S> for TimelineLayer {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+        // Dioxus opens a `render` span every time a component runs
+        if attrs.metadata().name() != "render" || in_devtools_scope() {
+            return;
+        }
+        let mut visitor = FieldVisitor::new("scope");
+        attrs.record(&mut visitor);
+        if let Some(name) = visitor.value {
+            self.devtools
+                .record(TimelineEntryKind::ComponentRendered { name });
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if !event.metadata().target().starts_with("dioxus_signals") || in_devtools_scope() {
+            return;
+        }
+        let mut visitor = FieldVisitor::new("message");
+        event.record(&mut visitor);
+        // Dioxus traces `Write on signal at {location} finished, updating subscribers`
+        let location = visitor.value.and_then(|message| {
+            let location = message.strip_prefix("Write on signal at ")?;
+            let (location, _) = location.split_once(" finished")?;
+            Some(location.to_string())
+        });
+        if let Some(location) = location {
+            self.devtools
+                .record(TimelineEntryKind::SignalWritten { location });
+        }
+    }
+}
+
+/// Read the value of a single field of a span or event.
+struct FieldVisitor {
+    name: &'static str,
+    value: Option<String>,
+}
+
+impl FieldVisitor {
+    fn new(name: &'static str) -> Self {
+        Self { name, value: None }
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == self.name {
+            self.value = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == self.name {
+            self.value = Some(format!("{value:?}"));
+        }
+    }
+}
+
+/// Movements of the cursor are too frequent to be shown in the timeline.
+fn is_movement(name: EventName) -> bool {
+    matches!(name, EventName::MouseMove | EventName::PointerOver)
+}
+
+/// Something that happened in the app, to debug why it rendered again or why an event had no effect.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TimelineEntry {
+    pub time: Instant,
+    pub kind: TimelineEntryKind,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum TimelineEntryKind {
+    /// An event was received from the platform, with the Nodes under the cursor, topmost first.
+    PlatformEvent {
+        name: EventName,
+        hit_nodes: Vec<NodeId>,
+    },
+    /// An event was dispatched to the handler of a Node.
+    HandlerRan { name: EventName, node_id: NodeId },
+    /// A signal was written, at the given location of the source code.
+    SignalWritten { location: String },
+    /// A component rendered again, usually because a signal it reads was written.
+    ComponentRendered { name: String },
+    /// Nodes were updated after their components rendered again.
+    NodesUpdated(Vec<NodeId>),
 }
@@ -22,6 +22,7 @@ use gl::{
 use glutin::{
     config::{
         ConfigTemplateBuilder,
+        GetGlConfig,
         GlConfig,
     },
     context::{
@@ -40,6 +41,7 @@ use glutin::{
         PossiblyCurrentGlContext,
     },
     surface::{
+        Rect as GlutinRect,
         Surface as GlutinSurface,
         SurfaceAttributesBuilder,
         SwapInterval,
@@ -48,6 +50,7 @@ use glutin::{
 };
 use glutin_winit::DisplayBuilder;
 use raw_window_handle::HasWindowHandle;
+use torin::prelude::Area;
 use winit::{
     dpi::PhysicalSize,
     event_loop::ActiveEventLoop,
@@ -155,7 +158,10 @@ impl OpenGLDriver {
 
         // Try setting vsync.
         gl_surface
-            .set_swap_interval(&gl_context, SwapInterval::Wait(NonZeroU32::new(1).unwrap()))
+            .set_swap_interval(
+                &gl_context,
+                swap_interval(config.window_config.frame_pacing.vsync),
+            )
             .ok();
 
         load_with(|s| {
@@ -215,10 +221,76 @@ impl OpenGLDriver {
         (driver, window, skia_surface)
     }
 
+    /// Enable or disable waiting for the display refresh when swapping buffers.
+    pub fn set_vsync(&mut self, vsync: bool) {
+        self.gl_surface
+            .set_swap_interval(&self.gl_context, swap_interval(vsync))
+            .ok();
+    }
+
     pub fn make_current(&mut self) {
         self.gl_context.make_current(&self.gl_surface).unwrap();
     }
 
+    /// Release the context, the window surface might be destroyed by the platform after this (e.g Android).
+    pub fn suspend(&mut self) {
+        self.gl_context.make_not_current_in_place().ok();
+    }
+
+    /// Create the window surface again after the app was suspended.
+    pub fn resume(&mut self, window: &Window, vsync: bool) -> (SkiaSurface, SkiaSurface) {
+        let gl_config = self.gl_context.config();
+        let window_handle = window.window_handle().unwrap();
+        let size = window.inner_size();
+
+        let attrs = SurfaceAttributesBuilder::<WindowSurface>::new().build(
+            window_handle.as_raw(),
+            size.as_gl_width(),
+            size.as_gl_height(),
+        );
+
+        self.gl_surface = unsafe {
+            gl_config
+                .display()
+                .create_window_surface(&gl_config, &attrs)
+                .expect("Could not create gl window surface")
+        };
+
+        self.make_current();
+        self.set_vsync(vsync);
+
+        self.resize(size)
+    }
+
+    /// Swap the buffers, hinting the compositor that only `damage` changed.
+    pub fn present(&mut self, damage: Option<Area>) {
+        let damage = damage
+            .filter(|damage| !damage.is_empty())
+            .and_then(|damage| {
+                // Damage rects use a bottom-left origin
+                let height = self.gl_surface.height()? as f32;
+                let damage = damage.round_out();
+                Some(GlutinRect::new(
+                    damage.min_x() as i32,
+                    (height - damage.max_y()) as i32,
+                    damage.width() as i32,
+                    damage.height() as i32,
+                ))
+            });
+
+        if let Some(damage) = damage {
+            if self
+                .gl_surface
+                .swap_buffers_with_damage(&self.gl_context, &[damage])
+                .is_ok()
+            {
+                return;
+            }
+        }
+
+        self.gl_surface.swap_buffers(&self.gl_context).unwrap();
+    }
+
     pub fn resize(&mut self, size: PhysicalSize<u32>) -> (SkiaSurface, SkiaSurface) {
         let render_target = backend_render_targets::make_gl(
             size.to_skia(),
@@ -244,3 +316,11 @@ impl OpenGLDriver {
         (surface, dirty_surface)
     }
 }
+
+fn swap_interval(vsync: bool) -> SwapInterval {
+    if vsync {
+        SwapInterval::Wait(NonZeroU32::new(1).unwrap())
+    } else {
+        SwapInterval::DontWait
+    }
+}
@@ -0,0 +1,79 @@
+use freya_engine::prelude::Surface as SkiaSurface;
+use glutin::surface::GlSurface;
+use torin::prelude::Area;
+use winit::{
+    dpi::PhysicalSize,
+    event_loop::ActiveEventLoop,
+    window::{
+        Window,
+        WindowAttributes,
+    },
+};
+
+use super::OpenGLDriver;
+use crate::LaunchConfig;
+
+pub enum GraphicsDriver {
+    OpenGl(OpenGLDriver),
+}
+
+impl GraphicsDriver {
+    pub fn new<State: Clone + 'static>(
+        event_loop: &ActiveEventLoop,
+        window_attributes: WindowAttributes,
+        config: &LaunchConfig<State>,
+    ) -> (Self, Window, SkiaSurface) {
+        let (driver, window, surface) = OpenGLDriver::new(event_loop, window_attributes, config);
+        (Self::OpenGl(driver), window, surface)
+    }
+
+    pub fn make_current(&mut self) {
+        match self {
+            Self::OpenGl(gl) => gl.make_current(),
+        }
+    }
+
+    /// Enable or disable waiting for the display refresh before presenting.
+    pub fn set_vsync(&mut self, vsync: bool) {
+        match self {
+            Self::OpenGl(gl) => gl.set_vsync(vsync),
+        }
+    }
+
+    /// Age of the back buffer that will be rendered next, `0` if unknown.
+    pub fn buffer_age(&self) -> u32 {
+        match self {
+            Self::OpenGl(gl) => gl.gl_surface.buffer_age(),
+        }
+    }
+
+    /// Submit the rendered frame, only presenting the `damage` area if given.
+    pub fn flush_and_submit(&mut self, damage: Option<Area>) {
+        match self {
+            Self::OpenGl(gl) => {
+                gl.gr_context.flush_and_submit();
+                gl.present(damage);
+            }
+        }
+    }
+
+    /// Release the graphics context while the app is in the background.
+    pub fn suspend(&mut self) {
+        match self {
+            Self::OpenGl(gl) => gl.suspend(),
+        }
+    }
+
+    /// Recreate the window surface once the app is back in the foreground.
+    pub fn resume(&mut self, window: &Window, vsync: bool) -> (SkiaSurface, SkiaSurface) {
+        match self {
+            Self::OpenGl(gl) => gl.resume(window, vsync),
+        }
+    }
+
+    pub fn resize(&mut self, size: PhysicalSize<u32>) -> (SkiaSurface, SkiaSurface) {
+        match self {
+            Self::OpenGl(gl) => gl.resize(size),
+        }
+    }
+}
@@ -0,0 +1,718 @@
+use std::{
+    future::Future,
+    io,
+    path::Path,
+    sync::Arc,
+    task::{
+        Context,
+        Poll,
+        Waker,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use accesskit::{
+    NodeBuilder,
+    Role,
+};
+use bytes::Bytes;
+use dioxus_core::{
+    Event,
+    VirtualDom,
+};
+use freya_core::{
+    accessibility::{
+        AccessibilityTree,
+        ACCESSIBILITY_ROOT_ID,
+    },
+    dom::SafeDOM,
+    event_loop_messages::EventLoopMessage,
+    events::{
+        process_events,
+        DomEvent,
+        NodesState,
+        PlatformEvent,
+    },
+    fonts::FontRegistry,
+    layout::process_layout,
+    open_requests::LaunchOpenRequests,
+    platform_state::{
+        Monitors,
+        NativePlatformState,
+        NavigationMode,
+        PlatformInformation,
+        PreferredTheme,
+        SoftKeyboard,
+    },
+    printing::PrintError,
+    render::{
+        capture_surface_frame,
+        capture_surface_png,
+        export_node,
+        find_node_by_reference,
+        snapshot_node,
+        CapturedFrame,
+        Compositor,
+        DamageTracker,
+        ExportTarget,
+        RenderPipeline,
+    },
+    states::AccessibilityNodeState,
+    types::{
+        CloseRequestSender,
+        EventEmitter,
+        EventReceiver,
+        EventsQueue,
+        GlobalShortcutEventSender,
+        NativeMenuEventSender,
+        NativePlatformSender,
+        NativeTrayEventSender,
+        OpenRequestSender,
+        SecondInstanceSender,
+    },
+};
+#[cfg(not(target_arch = "wasm32"))]
+use freya_core::{
+    printing::PrintOptions,
+    render::{
+        print_node,
+        PrintDocument,
+    },
+};
+use freya_engine::prelude::{
+    raster_n32_premul,
+    Canvas,
+    Color,
+    FontCollection,
+    SamplingOptions,
+    Surface as SkiaSurface,
+};
+use freya_native_core::prelude::NodeImmutableDioxusExt;
+use pin_utils::pin_mut;
+use tokio::{
+    select,
+    sync::{
+        broadcast,
+        mpsc::{
+            unbounded_channel,
+            UnboundedReceiver,
+            UnboundedSender,
+        },
+        watch,
+    },
+    time::timeout,
+};
+use torin::prelude::Area;
+use winit::dpi::{
+    LogicalSize,
+    PhysicalSize,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::printing::{
+    request_print,
+    request_printers,
+    submit_document,
+};
+use crate::{
+    opener::request_open,
+    size::WinitSize,
+    LaunchConfig,
+};
+
+/// Maximum time to wait for the VirtualDOM in [HeadlessRenderer::wait_for_update].
+const VDOM_TIMEOUT: Duration = Duration::from_millis(16);
+
+/// Runs the app without a window, rendering into CPU memory.
+///
+/// Useful for generating screenshots and documentation images or running visual tests in CI,
+/// as it uses the fonts, scale factor and background of the [LaunchConfig] instead of the
+/// simplified environment of `freya-testing`.
+///
+/// Plugins are not run, and the requests that need a window (title, cursor, menus...) are ignored.
+pub struct HeadlessRenderer {
+    vdom: VirtualDom,
+    sdom: SafeDOM,
+    size: PhysicalSize<u32>,
+    scale_factor: f64,
+    background: Color,
+    debug_layout: bool,
+    surface: SkiaSurface,
+    dirty_surface: SkiaSurface,
+    compositor: Compositor,
+    damage_tracker: DamageTracker,
+    font_collection: FontCollection,
+    fonts: FontRegistry,
+    events: EventsQueue,
+    nodes_state: NodesState,
+    event_emitter: EventEmitter,
+    event_receiver: EventReceiver,
+    platform_sender: NativePlatformSender,
+    platform_event_emitter: UnboundedSender<EventLoopMessage>,
+    platform_event_receiver: UnboundedReceiver<EventLoopMessage>,
+    accessibility: AccessibilityTree,
+    ticker_sender: broadcast::Sender<()>,
+    is_suspended: bool,
+}
+
+impl HeadlessRenderer {
+    /// Create the renderer, the window size of the config is used as the size of the frames in logical pixels.
+    pub fn new<State: Clone + 'static>(
+        mut vdom: VirtualDom,
+        sdom: SafeDOM,
+        config: LaunchConfig<State>,
+        scale_factor: f64,
+    ) -> Self {
+        let (fonts, font_collection) = FontRegistry::new(
+            &config.embedded_fonts,
+            config.default_fonts,
+            config.font_fallbacks,
+        );
+        let size =
+            LogicalSize::<f64>::from(config.window_config.size).to_physical::<u32>(scale_factor);
+        let background = config.window_config.background;
+        let debug_layout = config.window_config.debug_layout;
+
+        let (event_emitter, event_receiver) = unbounded_channel();
+        let (platform_event_emitter, platform_event_receiver) =
+            unbounded_channel::<EventLoopMessage>();
+        let (platform_sender, platform_receiver) = watch::channel(NativePlatformState {
+            focused_accessibility_id: ACCESSIBILITY_ROOT_ID,
+            focused_accessibility_node: NodeBuilder::new(Role::Window).build(),
+            preferred_theme: PreferredTheme::default(),
+            accent_color: None,
+            navigation_mode: NavigationMode::default(),
+            information: PlatformInformation::new(
+                size.to_torin(),
+                false,
+                false,
+                false,
+                scale_factor,
+            ),
+            scale_factor,
+            monitors: Monitors::default(),
+            soft_keyboard: SoftKeyboard::default(),
+            selected_text: None,
+            is_window_focused: true,
+            is_suspended: false,
+            last_user_input: Instant::now(),
+        });
+        let ticker_sender = broadcast::channel(5).0;
+        let native_menu_sender: NativeMenuEventSender = broadcast::channel(16).0;
+        let native_tray_sender: NativeTrayEventSender = broadcast::channel(16).0;
+        let global_shortcut_sender: GlobalShortcutEventSender = broadcast::channel(16).0;
+        let close_request_sender: CloseRequestSender = broadcast::channel(4).0;
+        let open_request_sender: OpenRequestSender = broadcast::channel(16).0;
+        let second_instance_sender: SecondInstanceSender = broadcast::channel(16).0;
+
+        // Insert built-in VirtualDOM contexts
+        if let Some(state) = config.state {
+            vdom.insert_any_root_context(Box::new(state));
+        }
+        vdom.insert_any_root_context(Box::new(platform_event_emitter.clone()));
+        vdom.insert_any_root_context(Box::new(platform_receiver));
+        vdom.insert_any_root_context(Box::new(Arc::new(ticker_sender.subscribe())));
+        vdom.insert_any_root_context(Box::new(native_menu_sender));
+        vdom.insert_any_root_context(Box::new(native_tray_sender));
+        vdom.insert_any_root_context(Box::new(global_shortcut_sender));
+        vdom.insert_any_root_context(Box::new(close_request_sender));
+        vdom.insert_any_root_context(Box::new(open_request_sender));
+        vdom.insert_any_root_context(Box::new(second_instance_sender));
+        vdom.insert_any_root_context(Box::new(LaunchOpenRequests::default()));
+        vdom.insert_any_root_context(Box::new(sdom.get().accessibility_generator().clone()));
+
+        // Init the RealDOM
+        sdom.get_mut().init_dom(&mut vdom, scale_factor as f32);
+
+        let (surface, dirty_surface) = create_surfaces(size, background);
+
+        let mut renderer = Self {
+            vdom,
+            sdom,
+            size,
+            scale_factor,
+            background,
+            debug_layout,
+            surface,
+            dirty_surface,
+            compositor: Compositor::default(),
+            damage_tracker: DamageTracker::default(),
+            font_collection,
+            fonts,
+            events: EventsQueue::new(),
+            nodes_state: NodesState::default(),
+            event_emitter,
+            event_receiver,
+            platform_sender,
+            platform_event_emitter,
+            platform_event_receiver,
+            accessibility: AccessibilityTree::new(ACCESSIBILITY_ROOT_ID),
+            ticker_sender,
+            is_suspended: false,
+        };
+        renderer.resize(size);
+        renderer
+    }
+
+    /// Get the [SafeDOM].
+    pub fn sdom(&self) -> &SafeDOM {
+        &self.sdom
+    }
+
+    /// Size of the frames in physical pixels.
+    pub fn size(&self) -> PhysicalSize<u32> {
+        self.size
+    }
+
+    /// Change the size of the frames, in physical pixels.
+    pub fn resize(&mut self, size: PhysicalSize<u32>) {
+        self.size = size;
+        (self.surface, self.dirty_surface) = create_surfaces(size, self.background);
+        self.compositor.reset();
+        self.damage_tracker.reset();
+        {
+            let fdom = self.sdom.get();
+            fdom.compositor_dirty_area()
+                .unite_or_insert(&Area::from_size(size.to_torin()));
+            fdom.layout().reset();
+        }
+        self.platform_sender.send_modify(|state| {
+            state.information.viewport_size = size.to_torin();
+        });
+        self.process_layout();
+    }
+
+    /// Change the scale factor, the layout is measured again with it.
+    pub(crate) fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+        self.platform_sender.send_modify(|state| {
+            state.scale_factor = scale_factor;
+            state.information.scale_factor = scale_factor;
+        });
+        self.resize(self.size);
+    }
+
+    /// Simulate the app going to the background like in Android, where the surface is destroyed.
+    ///
+    /// Nothing is rendered until [HeadlessRenderer::resume] is called.
+    pub fn suspend(&mut self) {
+        self.is_suspended = true;
+        self.platform_sender.send_modify(|state| {
+            state.is_suspended = true;
+        });
+    }
+
+    /// Bring the app back to the foreground, recreating the surface and rendering everything again.
+    pub fn resume(&mut self) {
+        if !self.is_suspended {
+            return;
+        }
+        self.is_suspended = false;
+        self.platform_sender.send_modify(|state| {
+            state.is_suspended = false;
+        });
+        self.resize(self.size);
+    }
+
+    /// Send an event to the app, like a mouse click or a key press.
+    pub fn push_event(&mut self, event: PlatformEvent) {
+        let text_selection_changed = self.sdom.get_mut().process_text_selection(
+            &event,
+            self.scale_factor,
+            &self.platform_sender,
+        );
+        if text_selection_changed {
+            self.platform_event_emitter
+                .send(EventLoopMessage::RequestRerender)
+                .ok();
+        }
+
+        self.events.push(event);
+        process_events(
+            &self.sdom.get(),
+            &mut self.events,
+            &self.event_emitter,
+            &mut self.nodes_state,
+            self.scale_factor,
+            self.accessibility.focused_node_id(),
+        );
+    }
+
+    /// Apply the pending changes of the app.
+    ///
+    /// Async work like futures or effects might need more than one call to finish.
+    pub async fn wait_for_update(&mut self) {
+        loop {
+            let platform_ev = self.platform_event_receiver.try_recv();
+            let vdom_events = self.event_receiver.try_recv();
+
+            if vdom_events.is_err() && platform_ev.is_err() {
+                break;
+            }
+
+            if let Ok(ev) = platform_ev {
+                self.handle_message(ev);
+            }
+
+            if let Ok(events) = vdom_events {
+                self.handle_dom_events(events);
+            }
+        }
+
+        timeout(VDOM_TIMEOUT, self.vdom.wait_for_work()).await.ok();
+
+        self.sdom
+            .get_mut()
+            .render_mutations(&mut self.vdom, self.scale_factor as f32);
+
+        self.process_layout();
+
+        self.ticker_sender.send(()).ok();
+    }
+
+    /// Apply the pending changes of the app without waiting, the waker is called once there are new ones.
+    ///
+    /// Returns whether anything changed.
+    pub(crate) fn poll(&mut self, waker: &Waker) -> bool {
+        enum Work {
+            DomEvents(Vec<DomEvent>),
+            Message(EventLoopMessage),
+            VirtualDom,
+        }
+
+        let mut cx = Context::from_waker(waker);
+        let mut changed = false;
+
+        loop {
+            let work = {
+                let fut = async {
+                    select! {
+                        Some(events) = self.event_receiver.recv() => Work::DomEvents(events),
+                        Some(message) = self.platform_event_receiver.recv() => Work::Message(message),
+                        _ = self.vdom.wait_for_work() => Work::VirtualDom,
+                    }
+                };
+                pin_mut!(fut);
+                fut.poll(&mut cx)
+            };
+
+            match work {
+                Poll::Ready(Work::DomEvents(events)) => self.handle_dom_events(events),
+                Poll::Ready(Work::Message(message)) => self.handle_message(message),
+                Poll::Ready(Work::VirtualDom) => {
+                    self.sdom
+                        .get_mut()
+                        .render_mutations(&mut self.vdom, self.scale_factor as f32);
+                }
+                Poll::Pending => break,
+            }
+            changed = true;
+        }
+
+        if changed {
+            self.process_layout();
+        }
+
+        changed
+    }
+
+    /// Render the app and draw the frame into the given canvas.
+    pub(crate) fn draw(&mut self, canvas: &Canvas) {
+        self.render();
+        self.surface
+            .draw(canvas, (0, 0), SamplingOptions::default(), None);
+
+        self.ticker_sender.send(()).ok();
+    }
+
+    /// Render the current state of the app, unless it is suspended.
+    pub fn render(&mut self) {
+        // There is no surface to render into while in the background
+        if self.is_suspended {
+            return;
+        }
+
+        let fdom = self.sdom.get();
+        let mut render_pipeline = RenderPipeline {
+            canvas_area: Area::from_size(self.size.to_torin()),
+            rdom: fdom.rdom(),
+            compositor_dirty_area: &mut fdom.compositor_dirty_area(),
+            compositor_dirty_nodes: &mut fdom.compositor_dirty_nodes(),
+            compositor_cache: &mut fdom.compositor_cache(),
+            raster_cache: &mut fdom.raster_cache(),
+            layers: &mut fdom.layers(),
+            layout: &mut fdom.layout(),
+            background: self.background,
+            surface: &mut self.surface,
+            dirty_surface: &mut self.dirty_surface,
+            compositor: &mut self.compositor,
+            scale_factor: self.scale_factor as f32,
+            selected_node: None,
+            debug_layout: self.debug_layout,
+            font_collection: &mut self.font_collection,
+            font_manager: self.fonts.font_mgr(),
+            default_fonts: self.fonts.default_fonts(),
+            images_cache: &mut fdom.images_cache(),
+            damage_tracker: &mut self.damage_tracker,
+            // The surface keeps the previous frame
+            buffer_age: 1,
+            layer_hook: None,
+        };
+        render_pipeline.run();
+    }
+
+    /// Render the app and encode the frame as a PNG image, `None` while it is suspended.
+    pub fn capture_png(&mut self) -> Option<Bytes> {
+        if self.is_suspended {
+            return None;
+        }
+        self.render();
+        capture_surface_png(&mut self.surface)
+    }
+
+    /// Render the app and read the raw pixels of the frame, `None` while it is suspended.
+    pub fn capture_frame(&mut self) -> Option<CapturedFrame> {
+        if self.is_suspended {
+            return None;
+        }
+        self.render();
+        capture_surface_frame(&mut self.surface)
+    }
+
+    /// Render the app and save the frame as a PNG image in the given path.
+    pub fn save_png(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        let png = self
+            .capture_png()
+            .ok_or_else(|| io::Error::other("Failed to encode the frame"))?;
+        std::fs::write(path, png)
+    }
+
+    /// Render the app into pages and submit them to the printer, getting the number of printed pages.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn print(&mut self, options: &PrintOptions) -> Result<usize, PrintError> {
+        self.render();
+        let document = self.print_document(&ExportTarget::Window, options)?;
+        submit_document(&document, options)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn print_document(
+        &mut self,
+        target: &ExportTarget,
+        options: &PrintOptions,
+    ) -> Result<PrintDocument, PrintError> {
+        let fdom = self.sdom.get();
+        let node_id = target.find_node(&fdom).ok_or(PrintError::NothingToPrint)?;
+        print_node(
+            &fdom,
+            node_id,
+            options,
+            self.scale_factor as f32,
+            &mut self.font_collection,
+            self.fonts.font_mgr(),
+            self.fonts.default_fonts(),
+        )
+        .ok_or(PrintError::NothingToPrint)
+    }
+
+    fn handle_dom_events(&mut self, events: Vec<DomEvent>) {
+        let fdom = self.sdom.get();
+        let rdom = fdom.rdom();
+        for event in events {
+            if let Some(element_id) = rdom.get(event.node_id).and_then(|node| node.mounted_id()) {
+                let name = event.name.into();
+                let data = event.data.any();
+                let event = Event::new(data, event.bubbles);
+                self.vdom.runtime().handle_event(name, event, element_id);
+                self.vdom.process_events();
+            }
+        }
+    }
+
+    fn handle_message(&mut self, message: EventLoopMessage) {
+        match message {
+            EventLoopMessage::FocusAccessibilityNode(strategy) => {
+                let fdom = self.sdom.get();
+                self.accessibility
+                    .focus_node_with_strategy(strategy, fdom.rdom());
+            }
+            EventLoopMessage::InvalidateArea(mut area) => {
+                area.size *= self.scale_factor as f32;
+                self.sdom
+                    .get()
+                    .compositor_dirty_area()
+                    .unite_or_insert(&area);
+            }
+            EventLoopMessage::RequestFullRerender => self.resize(self.size),
+            EventLoopMessage::RemeasureTextGroup(text_measurement) => {
+                self.sdom
+                    .get()
+                    .measure_paragraphs(text_measurement, self.scale_factor);
+            }
+            EventLoopMessage::SetAttributeOverride {
+                node_id,
+                attribute,
+                value,
+            } => match value {
+                Some(value) => self
+                    .sdom
+                    .get_mut()
+                    .set_attribute_override(node_id, attribute, value),
+                None => self
+                    .sdom
+                    .get_mut()
+                    .remove_attribute_override(node_id, attribute),
+            },
+            EventLoopMessage::ClearAttributeOverrides => {
+                self.sdom.get_mut().clear_attribute_overrides();
+            }
+            EventLoopMessage::SetDebugLayout(debug_layout) => {
+                self.debug_layout = debug_layout;
+                self.resize(self.size);
+            }
+            EventLoopMessage::PlatformEvent(platform_event) => self.push_event(platform_event),
+            EventLoopMessage::SnapshotNode {
+                reference,
+                scale,
+                sender,
+            } => {
+                let fdom = self.sdom.get();
+                let snapshot = find_node_by_reference(&fdom, &reference).and_then(|node_id| {
+                    snapshot_node(
+                        &fdom,
+                        node_id,
+                        scale,
+                        self.scale_factor as f32,
+                        &mut self.font_collection,
+                        self.fonts.font_mgr(),
+                        self.fonts.default_fonts(),
+                    )
+                });
+                sender
+                    .send(snapshot.map(|data| Bytes::copy_from_slice(&data)))
+                    .ok();
+            }
+            EventLoopMessage::CaptureWindow(sender) => {
+                sender.send(self.capture_png()).ok();
+            }
+            EventLoopMessage::Export {
+                target,
+                format,
+                sender,
+            } => {
+                let fdom = self.sdom.get();
+                let document = target.find_node(&fdom).and_then(|node_id| {
+                    export_node(
+                        &fdom,
+                        node_id,
+                        format,
+                        self.scale_factor as f32,
+                        &mut self.font_collection,
+                        self.fonts.font_mgr(),
+                        self.fonts.default_fonts(),
+                    )
+                });
+                sender.send(document.map(Bytes::from)).ok();
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            EventLoopMessage::GetPrinters(sender) => request_printers(sender),
+            #[cfg(not(target_arch = "wasm32"))]
+            EventLoopMessage::Print {
+                target,
+                options,
+                sender,
+            } => match self.print_document(&target, &options) {
+                Ok(document) => request_print(document, options, sender),
+                Err(err) => {
+                    sender.send(Err(err)).ok();
+                }
+            },
+            // Printing is not available in the browser
+            #[cfg(target_arch = "wasm32")]
+            EventLoopMessage::GetPrinters(sender) => {
+                sender.send(Vec::new()).ok();
+            }
+            #[cfg(target_arch = "wasm32")]
+            EventLoopMessage::Print { sender, .. } => {
+                sender.send(Err(PrintError::Unsupported)).ok();
+            }
+            EventLoopMessage::Open { target, sender } => request_open(target, sender),
+            EventLoopMessage::RegisterFont(source, alias, sender) => {
+                let result =
+                    self.fonts
+                        .register(source, alias.as_deref(), &mut self.font_collection);
+                if result.is_ok() {
+                    self.invalidate_fonts();
+                }
+                sender.send(result).ok();
+            }
+            EventLoopMessage::SetFontFallbacks(fallbacks) => {
+                if *self.fonts.fallbacks() != fallbacks {
+                    self.fonts.set_fallbacks(fallbacks);
+                    self.invalidate_fonts();
+                }
+            }
+            EventLoopMessage::GetFontFamilies(sender) => {
+                sender.send(self.fonts.families()).ok();
+            }
+            _ => {}
+        }
+    }
+
+    /// Measure and paint everything again with the current fonts.
+    fn invalidate_fonts(&mut self) {
+        self.sdom.get().invalidate_fonts();
+        self.resize(self.size);
+    }
+
+    /// Measure the layout and update the accessibility tree.
+    fn process_layout(&mut self) {
+        process_layout(
+            &self.sdom.get(),
+            Area::from_size(self.size.to_torin()),
+            &mut self.font_collection,
+            self.scale_factor as f32,
+            self.fonts.default_fonts(),
+        );
+
+        let text_overflow_events = self.sdom.get_mut().process_text_overflow();
+        if !text_overflow_events.is_empty() {
+            self.event_emitter.send(text_overflow_events).ok();
+        }
+
+        let fdom = self.sdom.get();
+
+        let rdom = fdom.rdom();
+        let layout = fdom.layout();
+        let mut dirty_accessibility_tree = fdom.accessibility_dirty_nodes();
+        let (tree, node_id) =
+            self.accessibility
+                .process_updates(rdom, &layout, &mut dirty_accessibility_tree);
+
+        // Notify the components
+        self.platform_sender.send_modify(|state| {
+            state.focused_accessibility_id = tree.focus;
+            let node_ref = rdom.get(node_id).unwrap();
+            let node_accessibility = node_ref.get::<AccessibilityNodeState>().unwrap();
+            let layout_node = layout.get(node_id).unwrap();
+            state.focused_accessibility_node =
+                AccessibilityTree::create_node(&node_ref, layout_node, &node_accessibility)
+        });
+    }
+}
+
+/// Create the main and dirty surfaces in CPU memory.
+fn create_surfaces(size: PhysicalSize<u32>, background: Color) -> (SkiaSurface, SkiaSurface) {
+    let mut surface = raster_n32_premul(size.to_skia()).expect("Failed to create the surface.");
+    surface.canvas().clear(background);
+
+    let mut dirty_surface = surface
+        .new_surface_with_dimensions(size.to_skia())
+        .expect("Failed to create the dirty surface.");
+    dirty_surface.canvas().clear(background);
+
+    (surface, dirty_surface)
+}
@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use dioxus_core::VirtualDom;
+use freya_core::{
+    dom::SafeDOM,
+    event_loop_messages::EventLoopMessage,
+};
+use freya_engine::prelude::{
+    raster_n32_premul,
+    AlphaType,
+    ColorType,
+    ImageInfo,
+    Surface as SkiaSurface,
+};
+use wasm_bindgen::{
+    Clamped,
+    JsCast,
+};
+use web_sys::{
+    CanvasRenderingContext2d,
+    ImageData,
+};
+use winit::{
+    application::ApplicationHandler,
+    dpi::{
+        LogicalSize,
+        PhysicalSize,
+    },
+    event::WindowEvent,
+    event_loop::{
+        ActiveEventLoop,
+        EventLoop,
+    },
+    platform::web::{
+        EventLoopExtWebSys,
+        WindowAttributesExtWebSys,
+        WindowExtWebSys,
+    },
+    window::{
+        Window,
+        WindowId,
+    },
+};
+
+use crate::{
+    size::WinitSize,
+    FreyaRenderer,
+    LaunchConfig,
+};
+
+struct NotCreatedState<State: Clone + 'static> {
+    vdom: VirtualDom,
+    sdom: SafeDOM,
+    config: LaunchConfig<'static, State>,
+}
+
+struct CreatedState {
+    renderer: FreyaRenderer,
+    window: Arc<Window>,
+    surface: SkiaSurface,
+    context: CanvasRenderingContext2d,
+}
+
+/// Runs the app inside a `<canvas>` of a web page, for the `wasm32` target.
+///
+/// Frames are rasterized by Skia in the CPU and copied into the canvas, and the browser
+/// events are received through winit. The canvas is appended to the `<body>` unless
+/// another one is given with [LaunchConfig::with_window_attributes].
+///
+/// This is feature-limited compared to the native renderer: plugins, menus, tray icons,
+/// global shortcuts, printing and window effects are not available.
+pub struct WebRenderer<State: Clone + 'static> {
+    not_created: Option<NotCreatedState<State>>,
+    created: Option<CreatedState>,
+}
+
+impl<State: Clone + 'static> WebRenderer<State> {
+    /// Start rendering the app, this returns immediately as the browser drives the event loop.
+    pub fn launch(vdom: VirtualDom, sdom: SafeDOM, mut config: LaunchConfig<'static, State>) {
+        let mut event_loop_builder = EventLoop::<EventLoopMessage>::with_user_event();
+        let event_loop_builder_hook = config.window_config.event_loop_builder_hook.take();
+        if let Some(event_loop_builder_hook) = event_loop_builder_hook {
+            event_loop_builder_hook(&mut event_loop_builder);
+        }
+        let event_loop = event_loop_builder
+            .build()
+            .expect("Failed to create event loop.");
+
+        event_loop.spawn_app(WebRenderer {
+            not_created: Some(NotCreatedState { vdom, sdom, config }),
+            created: None,
+        });
+    }
+
+    /// Copy the rasterized frame into the canvas.
+    fn present(surface: &mut SkiaSurface, context: &CanvasRenderingContext2d) {
+        let (width, height) = (surface.width(), surface.height());
+        if width <= 0 || height <= 0 {
+            return;
+        }
+
+        // Canvas images are not premultiplied
+        let info = ImageInfo::new(
+            (width, height),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+        let row_bytes = width as usize * 4;
+        let mut pixels = vec![0; row_bytes * height as usize];
+
+        if !surface.read_pixels(&info, &mut pixels, row_bytes, (0, 0)) {
+            return;
+        }
+
+        if let Ok(image_data) = ImageData::new_with_u8_clamped_array_and_sh(
+            Clamped(&pixels),
+            width as u32,
+            height as u32,
+        ) {
+            context.put_image_data(&image_data, 0.0, 0.0).ok();
+        }
+    }
+}
+
+impl<State: Clone + 'static> ApplicationHandler<EventLoopMessage> for WebRenderer<State> {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        let Some(NotCreatedState {
+            vdom,
+            sdom,
+            mut config,
+        }) = self.not_created.take()
+        else {
+            return;
+        };
+
+        let mut window_attributes = Window::default_attributes()
+            .with_title(config.window_config.title)
+            .with_inner_size(LogicalSize::<f64>::from(config.window_config.size))
+            .with_append(true);
+
+        if let Some(with_window_attributes) = config.window_config.window_attributes_hook.take() {
+            window_attributes = (with_window_attributes)(window_attributes);
+        }
+
+        let window = Arc::new(
+            event_loop
+                .create_window(window_attributes)
+                .expect("Failed to create the canvas."),
+        );
+
+        let context = window
+            .canvas()
+            .and_then(|canvas| canvas.get_context("2d").ok().flatten())
+            .and_then(|context| context.dyn_into::<CanvasRenderingContext2d>().ok())
+            .expect("Failed to get the 2D context of the canvas.");
+
+        let surface = create_surface(window.inner_size());
+        let renderer = FreyaRenderer::new(window.clone(), vdom, sdom, config);
+
+        window.request_redraw();
+
+        self.created = Some(CreatedState {
+            renderer,
+            window,
+            surface,
+            context,
+        });
+    }
+
+    fn window_event(&mut self, _event_loop: &ActiveEventLoop, _id: WindowId, event: WindowEvent) {
+        let Some(CreatedState {
+            renderer,
+            window,
+            surface,
+            context,
+        }) = &mut self.created
+        else {
+            return;
+        };
+
+        match event {
+            WindowEvent::RedrawRequested => {
+                renderer.render(surface.canvas());
+                Self::present(surface, context);
+            }
+            WindowEvent::Resized(size) => {
+                *surface = create_surface(size);
+                renderer.handle_event(&event);
+                window.request_redraw();
+            }
+            event => {
+                renderer.handle_event(&event);
+            }
+        }
+    }
+
+    fn about_to_wait(&mut self, _event_loop: &ActiveEventLoop) {
+        // Keep the async work of the app going between the browser events
+        if let Some(CreatedState { renderer, .. }) = &mut self.created {
+            renderer.update();
+        }
+    }
+}
+
+fn create_surface(size: PhysicalSize<u32>) -> SkiaSurface {
+    raster_n32_premul(size.to_skia()).expect("Failed to create the surface.")
+}
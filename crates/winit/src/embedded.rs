@@ -0,0 +1,161 @@
+use std::{
+    sync::Arc,
+    task::Waker,
+};
+
+use dioxus_core::VirtualDom;
+use freya_core::{
+    dom::SafeDOM,
+    events::cursor_icon_at,
+};
+use freya_engine::prelude::Canvas;
+use futures_task::{
+    waker,
+    ArcWake,
+};
+use winit::{
+    event::{
+        Touch,
+        WindowEvent,
+    },
+    window::{
+        CursorIcon,
+        Window,
+    },
+};
+
+use crate::{
+    input::InputState,
+    HeadlessRenderer,
+    LaunchConfig,
+};
+
+/// Used to request a redraw of the host window once the VirtualDOM has new work
+fn window_waker(window: &Arc<Window>) -> Waker {
+    struct WindowHandle(Arc<Window>);
+
+    impl ArcWake for WindowHandle {
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            arc_self.0.request_redraw();
+        }
+    }
+
+    waker(Arc::new(WindowHandle(window.clone())))
+}
+
+/// Renders a Freya app inside a window owned by another winit application, like a game or an existing tool.
+///
+/// The host keeps control of the event loop, it only needs to forward the [WindowEvent]s with
+/// [FreyaRenderer::handle_event] and draw the UI into its own canvas with [FreyaRenderer::render]
+/// whenever the window is redrawn. The UI covers the whole window.
+///
+/// Plugins are not run, and the requests that need to own the window (title, menus, custom cursors...) are ignored.
+pub struct FreyaRenderer {
+    renderer: HeadlessRenderer,
+    window: Arc<Window>,
+    waker: Waker,
+    input: InputState,
+    touch_as_mouse: bool,
+    hovered_cursor_icon: Option<CursorIcon>,
+}
+
+impl FreyaRenderer {
+    /// Create the renderer for the given window, the size of the config is ignored in favour of the window size.
+    pub fn new<State: Clone + 'static>(
+        window: Arc<Window>,
+        vdom: VirtualDom,
+        sdom: SafeDOM,
+        config: LaunchConfig<State>,
+    ) -> Self {
+        let touch_as_mouse = config.window_config.touch_as_mouse;
+        let mut renderer = HeadlessRenderer::new(vdom, sdom, config, window.scale_factor());
+        renderer.resize(window.inner_size());
+
+        let mut freya_renderer = Self {
+            renderer,
+            waker: window_waker(&window),
+            window,
+            input: InputState::default(),
+            touch_as_mouse,
+            hovered_cursor_icon: None,
+        };
+        freya_renderer.update();
+        freya_renderer
+    }
+
+    /// Get the [SafeDOM].
+    pub fn sdom(&self) -> &SafeDOM {
+        self.renderer.sdom()
+    }
+
+    /// Forward an event of the host window to the app.
+    ///
+    /// Returns whether the event was used by Freya.
+    pub fn handle_event(&mut self, event: &WindowEvent) -> bool {
+        match event {
+            WindowEvent::Resized(size) => {
+                self.renderer.resize(*size);
+                self.window.request_redraw();
+            }
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                self.renderer.set_scale_factor(*scale_factor);
+                self.window.request_redraw();
+            }
+            _ => {
+                let Some(mut events) = self.input.translate(event) else {
+                    return false;
+                };
+
+                if let WindowEvent::Touch(Touch { phase, .. }) = event {
+                    if self.touch_as_mouse {
+                        events.extend(self.input.touch_as_mouse(*phase));
+                    }
+                }
+
+                if events.is_empty() {
+                    return false;
+                }
+
+                for event in events {
+                    self.renderer.push_event(event);
+                }
+            }
+        }
+
+        self.update();
+        true
+    }
+
+    /// Apply the pending changes of the app, the window is asked to redraw if there were any.
+    ///
+    /// Called by [FreyaRenderer::handle_event] and [FreyaRenderer::render] already,
+    /// but the host might also call it from its event loop to keep async work going.
+    pub fn update(&mut self) {
+        if self.renderer.poll(&self.waker) {
+            self.update_cursor_icon();
+            self.window.request_redraw();
+        }
+    }
+
+    /// Draw the app into the canvas of the host window, at its top left corner.
+    pub fn render(&mut self, canvas: &Canvas) {
+        self.update();
+        self.renderer.draw(canvas);
+    }
+
+    /// Show the cursor icon of the hovered node.
+    fn update_cursor_icon(&mut self) {
+        let cursor_icon = cursor_icon_at(
+            &self.renderer.sdom().get(),
+            self.input.cursor_pos,
+            self.window.scale_factor(),
+        );
+
+        if self.hovered_cursor_icon == cursor_icon {
+            return;
+        }
+        self.hovered_cursor_icon = cursor_icon;
+
+        self.window.set_cursor(cursor_icon.unwrap_or_default());
+    }
+}
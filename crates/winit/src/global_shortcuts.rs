@@ -0,0 +1,176 @@
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    global_shortcuts::{
+        GlobalShortcut,
+        GlobalShortcutError,
+        GlobalShortcutEvent,
+        GlobalShortcutState,
+        GlobalShortcuts,
+    },
+    types::GlobalShortcutEventSender,
+};
+use winit::event_loop::EventLoopProxy;
+
+/// Registers the [GlobalShortcuts] of the app in the operating system.
+///
+/// Supported on Windows, macOS and Linux with X11.
+pub struct WinitGlobalShortcuts {
+    shortcuts: GlobalShortcuts,
+    #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+    native: Option<platform::PlatformShortcuts>,
+}
+
+impl WinitGlobalShortcuts {
+    pub fn new(proxy: &EventLoopProxy<EventLoopMessage>) -> Self {
+        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+        {
+            platform::listen_events(proxy);
+            Self {
+                shortcuts: GlobalShortcuts::default(),
+                native: platform::PlatformShortcuts::new(),
+            }
+        }
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+        {
+            let _ = proxy;
+            Self {
+                shortcuts: GlobalShortcuts::default(),
+            }
+        }
+    }
+
+    /// Register a shortcut, replacing the one with the same id.
+    pub fn register(&mut self, shortcut: GlobalShortcut) -> Result<(), GlobalShortcutError> {
+        self.shortcuts.check(&shortcut)?;
+
+        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+        {
+            let Some(native) = &mut self.native else {
+                return Err(GlobalShortcutError::Unsupported);
+            };
+            native.register(&shortcut)?;
+            self.shortcuts.insert(shortcut);
+            Ok(())
+        }
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+        Err(GlobalShortcutError::Unsupported)
+    }
+
+    pub fn unregister(&mut self, id: &str) {
+        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+        if let Some(native) = &mut self.native {
+            native.unregister(id);
+        }
+        self.shortcuts.remove(id);
+    }
+
+    /// Notify the VirtualDOM about the shortcut with the given native id being triggered.
+    pub fn trigger(
+        &self,
+        native_id: u32,
+        state: GlobalShortcutState,
+        sender: &GlobalShortcutEventSender,
+    ) {
+        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+        if let Some(id) = self
+            .native
+            .as_ref()
+            .and_then(|native| native.id_of(native_id))
+        {
+            sender
+                .send(GlobalShortcutEvent {
+                    id: id.to_string(),
+                    state,
+                })
+                .ok();
+        }
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+        let _ = (native_id, state, sender);
+    }
+}
+
+#[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+mod platform {
+    use std::collections::HashMap;
+
+    use freya_core::{
+        event_loop_messages::EventLoopMessage,
+        global_shortcuts::{
+            GlobalShortcut,
+            GlobalShortcutError,
+            GlobalShortcutState,
+        },
+    };
+    use global_hotkey::{
+        hotkey::HotKey,
+        GlobalHotKeyEvent,
+        GlobalHotKeyManager,
+        HotKeyState,
+    };
+    use winit::event_loop::EventLoopProxy;
+
+    /// Forward the triggered shortcuts to the event loop.
+    pub fn listen_events(proxy: &EventLoopProxy<EventLoopMessage>) {
+        let proxy = proxy.clone();
+        GlobalHotKeyEvent::set_event_handler(Some(move |event: GlobalHotKeyEvent| {
+            let state = match event.state {
+                HotKeyState::Pressed => GlobalShortcutState::Pressed,
+                HotKeyState::Released => GlobalShortcutState::Released,
+            };
+            proxy
+                .send_event(EventLoopMessage::GlobalShortcutTriggered(event.id, state))
+                .ok();
+        }));
+    }
+
+    pub struct PlatformShortcuts {
+        manager: GlobalHotKeyManager,
+        hotkeys: HashMap<String, HotKey>,
+    }
+
+    impl PlatformShortcuts {
+        /// Returns `None` if the platform doesn't support them, e.g. Wayland.
+        pub fn new() -> Option<Self> {
+            Some(Self {
+                manager: GlobalHotKeyManager::new().ok()?,
+                hotkeys: HashMap::default(),
+            })
+        }
+
+        /// Register a shortcut, replacing the one with the same id.
+        ///
+        /// The previous hotkey is only unregistered once the new one is registered,
+        /// so it keeps working if the registration fails.
+        pub fn register(&mut self, shortcut: &GlobalShortcut) -> Result<(), GlobalShortcutError> {
+            let hotkey = shortcut.accelerator.parse::<HotKey>().map_err(|_| {
+                GlobalShortcutError::InvalidAccelerator(shortcut.accelerator.clone())
+            })?;
+            let previous_hotkey = self.hotkeys.get(&shortcut.id).copied();
+            if previous_hotkey == Some(hotkey) {
+                return Ok(());
+            }
+            self.manager
+                .register(hotkey)
+                .map_err(|err| GlobalShortcutError::RegistrationFailed(err.to_string()))?;
+            if let Some(previous_hotkey) = previous_hotkey {
+                self.manager.unregister(previous_hotkey).ok();
+            }
+            self.hotkeys.insert(shortcut.id.clone(), hotkey);
+            Ok(())
+        }
+
+        pub fn unregister(&mut self, id: &str) {
+            if let Some(hotkey) = self.hotkeys.remove(id) {
+                self.manager.unregister(hotkey).ok();
+            }
+        }
+
+        /// Get the id of the shortcut with the given native id.
+        pub fn id_of(&self, native_id: u32) -> Option<&str> {
+            self.hotkeys
+                .iter()
+                .find(|(_, hotkey)| hotkey.id() == native_id)
+                .map(|(id, _)| id.as_str())
+        }
+    }
+}
@@ -0,0 +1,519 @@
+use std::path::PathBuf;
+
+use freya_core::events::{
+    EventName,
+    PlatformEvent,
+    PlatformEventData,
+};
+use freya_elements::events::{
+    Code,
+    Key,
+};
+use torin::geometry::CursorPoint;
+use winit::{
+    event::{
+        ElementState,
+        Ime,
+        KeyEvent,
+        MouseButton,
+        MouseScrollDelta,
+        Touch,
+        TouchPhase,
+        WindowEvent,
+    },
+    keyboard::ModifiersState,
+};
+
+use crate::keyboard::{
+    map_winit_key,
+    map_winit_modifiers,
+    map_winit_physical_key,
+};
+
+pub(crate) const WHEEL_SPEED_MODIFIER: f64 = 53.0;
+pub(crate) const TOUCHPAD_SPEED_MODIFIER: f64 = 2.0;
+
+/// Position of the cursor when it's outside of the window.
+const OUTSIDE_CURSOR: CursorPoint = CursorPoint::new(-1.0, -1.0);
+
+/// State of the pointer and the keyboard, used by the renderers to translate the winit [WindowEvent]s into [PlatformEvent]s.
+pub(crate) struct InputState {
+    pub cursor_pos: CursorPoint,
+    pub mouse_state: ElementState,
+    pub modifiers_state: ModifiersState,
+    /// File dropped in the window, sent once winit tells where the cursor is.
+    pub dropped_file_path: Option<PathBuf>,
+}
+
+impl Default for InputState {
+    fn default() -> Self {
+        Self {
+            cursor_pos: OUTSIDE_CURSOR,
+            mouse_state: ElementState::Released,
+            modifiers_state: ModifiersState::default(),
+            dropped_file_path: None,
+        }
+    }
+}
+
+impl InputState {
+    /// Translate an input event of the window into the events for the app, updating the state.
+    ///
+    /// Returns `None` if it's not an input event, e.g. a resize.
+    pub fn translate(&mut self, event: &WindowEvent) -> Option<Vec<PlatformEvent>> {
+        let events = match event {
+            WindowEvent::CursorMoved { position, .. } => {
+                self.cursor_pos = CursorPoint::from((position.x, position.y));
+
+                let mut events = vec![self.mouse_event(EventName::MouseMove, None)];
+                if let Some(dropped_file_path) = self.dropped_file_path.take() {
+                    events.push(PlatformEvent {
+                        name: EventName::FileDrop,
+                        data: PlatformEventData::File {
+                            file_path: Some(dropped_file_path),
+                            cursor: self.cursor_pos,
+                        },
+                    });
+                }
+                events
+            }
+            WindowEvent::CursorLeft { .. } => {
+                // Keep following the cursor while a button is pressed, e.g. when dragging
+                if self.mouse_state == ElementState::Released {
+                    self.cursor_pos = OUTSIDE_CURSOR;
+                    vec![self.mouse_event(EventName::MouseMove, None)]
+                } else {
+                    Vec::new()
+                }
+            }
+            WindowEvent::MouseInput { state, button, .. } => {
+                self.mouse_state = *state;
+
+                let name = match state {
+                    ElementState::Pressed => EventName::MouseDown,
+                    ElementState::Released => match button {
+                        MouseButton::Middle => EventName::MiddleClick,
+                        MouseButton::Right => EventName::RightClick,
+                        MouseButton::Left => EventName::MouseUp,
+                        _ => EventName::PointerUp,
+                    },
+                };
+                vec![self.mouse_event(name, Some(*button))]
+            }
+            WindowEvent::MouseWheel { delta, phase, .. } => {
+                if *phase != TouchPhase::Moved {
+                    return Some(Vec::new());
+                }
+
+                let scroll = match delta {
+                    MouseScrollDelta::LineDelta(x, y) => (
+                        *x as f64 * WHEEL_SPEED_MODIFIER,
+                        *y as f64 * WHEEL_SPEED_MODIFIER,
+                    ),
+                    MouseScrollDelta::PixelDelta(pos) => (
+                        pos.x * TOUCHPAD_SPEED_MODIFIER,
+                        pos.y * TOUCHPAD_SPEED_MODIFIER,
+                    ),
+                };
+                vec![PlatformEvent {
+                    name: EventName::Wheel,
+                    data: PlatformEventData::Wheel {
+                        scroll: CursorPoint::from(scroll),
+                        cursor: self.cursor_pos,
+                    },
+                }]
+            }
+            WindowEvent::ModifiersChanged(modifiers) => {
+                self.modifiers_state = modifiers.state();
+                Vec::new()
+            }
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key,
+                        logical_key,
+                        state,
+                        ..
+                    },
+                ..
+            } => {
+                let name = match state {
+                    ElementState::Pressed => EventName::KeyDown,
+                    ElementState::Released => EventName::KeyUp,
+                };
+                vec![PlatformEvent {
+                    name,
+                    data: PlatformEventData::Keyboard {
+                        key: map_winit_key(logical_key),
+                        code: map_winit_physical_key(physical_key),
+                        modifiers: map_winit_modifiers(self.modifiers_state),
+                    },
+                }]
+            }
+            WindowEvent::Ime(Ime::Preedit(text, cursor)) => vec![PlatformEvent {
+                name: EventName::ImePreedit,
+                data: PlatformEventData::Ime {
+                    text: text.clone(),
+                    cursor: *cursor,
+                },
+            }],
+            // Clear the text being composed
+            WindowEvent::Ime(Ime::Disabled) => vec![PlatformEvent {
+                name: EventName::ImePreedit,
+                data: PlatformEventData::Ime {
+                    text: String::new(),
+                    cursor: None,
+                },
+            }],
+            WindowEvent::Ime(Ime::Commit(text)) => vec![
+                PlatformEvent {
+                    name: EventName::ImeCommit,
+                    data: PlatformEventData::Ime {
+                        text: text.clone(),
+                        cursor: None,
+                    },
+                },
+                PlatformEvent {
+                    name: EventName::KeyDown,
+                    data: PlatformEventData::Keyboard {
+                        key: Key::Character(text.clone()),
+                        code: Code::Unidentified,
+                        modifiers: map_winit_modifiers(self.modifiers_state),
+                    },
+                },
+            ],
+            WindowEvent::Touch(Touch {
+                location,
+                phase,
+                id,
+                force,
+                ..
+            }) => {
+                self.cursor_pos = CursorPoint::from((location.x, location.y));
+
+                let name = match phase {
+                    TouchPhase::Cancelled => EventName::TouchCancel,
+                    TouchPhase::Ended => EventName::TouchEnd,
+                    TouchPhase::Moved => EventName::TouchMove,
+                    TouchPhase::Started => EventName::TouchStart,
+                };
+                vec![PlatformEvent {
+                    name,
+                    data: PlatformEventData::Touch {
+                        location: self.cursor_pos,
+                        finger_id: *id,
+                        phase: *phase,
+                        force: *force,
+                    },
+                }]
+            }
+            WindowEvent::DroppedFile(file_path) => {
+                self.dropped_file_path = Some(file_path.clone());
+                Vec::new()
+            }
+            WindowEvent::HoveredFile(file_path) => vec![PlatformEvent {
+                name: EventName::GlobalFileHover,
+                data: PlatformEventData::File {
+                    file_path: Some(file_path.clone()),
+                    cursor: self.cursor_pos,
+                },
+            }],
+            WindowEvent::HoveredFileCancelled => vec![PlatformEvent {
+                name: EventName::GlobalFileHoverCancelled,
+                data: PlatformEventData::File {
+                    file_path: None,
+                    cursor: self.cursor_pos,
+                },
+            }],
+            _ => return None,
+        };
+        Some(events)
+    }
+
+    /// Mouse events equivalent to a touch, so mouse-only components work with the fingers.
+    pub fn touch_as_mouse(&mut self, phase: TouchPhase) -> Vec<PlatformEvent> {
+        let mut events = match phase {
+            TouchPhase::Started => vec![
+                self.mouse_event(EventName::MouseMove, None),
+                self.mouse_event(EventName::MouseDown, Some(MouseButton::Left)),
+            ],
+            TouchPhase::Moved => vec![self.mouse_event(EventName::MouseMove, None)],
+            TouchPhase::Ended => {
+                vec![self.mouse_event(EventName::MouseUp, Some(MouseButton::Left))]
+            }
+            TouchPhase::Cancelled => Vec::new(),
+        };
+
+        // Fingers don't hover, so leave the window once they are lifted
+        if matches!(phase, TouchPhase::Ended | TouchPhase::Cancelled) {
+            self.cursor_pos = OUTSIDE_CURSOR;
+            events.push(self.mouse_event(EventName::MouseMove, None));
+        }
+
+        events
+    }
+
+    fn mouse_event(&self, name: EventName, button: Option<MouseButton>) -> PlatformEvent {
+        PlatformEvent {
+            name,
+            data: PlatformEventData::Mouse {
+                cursor: self.cursor_pos,
+                button,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use freya_core::events::{
+        EventName,
+        PlatformEvent,
+        PlatformEventData,
+    };
+    use winit::{
+        dpi::{
+            PhysicalPosition,
+            PhysicalSize,
+        },
+        event::{
+            DeviceId,
+            ElementState,
+            Ime,
+            MouseButton,
+            MouseScrollDelta,
+            Touch,
+            TouchPhase,
+            WindowEvent,
+        },
+    };
+
+    use super::*;
+
+    fn device_id() -> DeviceId {
+        // SAFETY: Only used to build the events of the tests
+        unsafe { DeviceId::dummy() }
+    }
+
+    fn names(events: &[PlatformEvent]) -> Vec<EventName> {
+        events.iter().map(|event| event.name).collect()
+    }
+
+    fn cursor_moved(x: f64, y: f64) -> WindowEvent {
+        WindowEvent::CursorMoved {
+            device_id: device_id(),
+            position: PhysicalPosition::new(x, y),
+        }
+    }
+
+    fn mouse_input(state: ElementState, button: MouseButton) -> WindowEvent {
+        WindowEvent::MouseInput {
+            device_id: device_id(),
+            state,
+            button,
+        }
+    }
+
+    fn touch(phase: TouchPhase, x: f64, y: f64) -> WindowEvent {
+        WindowEvent::Touch(Touch {
+            device_id: device_id(),
+            phase,
+            location: PhysicalPosition::new(x, y),
+            force: None,
+            id: 1,
+        })
+    }
+
+    #[test]
+    fn mouse() {
+        let mut input = InputState::default();
+
+        let events = input.translate(&cursor_moved(10., 20.)).unwrap();
+        assert_eq!(names(&events), [EventName::MouseMove]);
+        assert_eq!(input.cursor_pos, CursorPoint::new(10., 20.));
+
+        let events = input
+            .translate(&mouse_input(ElementState::Pressed, MouseButton::Left))
+            .unwrap();
+        assert_eq!(names(&events), [EventName::MouseDown]);
+        assert!(matches!(
+            events[0].data,
+            PlatformEventData::Mouse {
+                button: Some(MouseButton::Left),
+                ..
+            }
+        ));
+
+        // The cursor is followed outside of the window while dragging
+        let cursor_left = WindowEvent::CursorLeft {
+            device_id: device_id(),
+        };
+        assert!(input.translate(&cursor_left).unwrap().is_empty());
+        assert_eq!(input.cursor_pos, CursorPoint::new(10., 20.));
+
+        let events = input
+            .translate(&mouse_input(ElementState::Released, MouseButton::Right))
+            .unwrap();
+        assert_eq!(names(&events), [EventName::RightClick]);
+
+        let events = input.translate(&cursor_left).unwrap();
+        assert_eq!(names(&events), [EventName::MouseMove]);
+        assert_eq!(input.cursor_pos, OUTSIDE_CURSOR);
+    }
+
+    #[test]
+    fn wheel() {
+        let mut input = InputState::default();
+        input.translate(&cursor_moved(5., 5.));
+
+        let wheel = |delta, phase| WindowEvent::MouseWheel {
+            device_id: device_id(),
+            delta,
+            phase,
+        };
+
+        let events = input
+            .translate(&wheel(
+                MouseScrollDelta::LineDelta(0., 1.),
+                TouchPhase::Moved,
+            ))
+            .unwrap();
+        let PlatformEventData::Wheel { scroll, cursor } = &events[0].data else {
+            panic!("Expected a wheel event");
+        };
+        assert_eq!(*scroll, CursorPoint::new(0., WHEEL_SPEED_MODIFIER));
+        assert_eq!(*cursor, CursorPoint::new(5., 5.));
+
+        let events = input
+            .translate(&wheel(
+                MouseScrollDelta::PixelDelta(PhysicalPosition::new(3., 0.)),
+                TouchPhase::Moved,
+            ))
+            .unwrap();
+        let PlatformEventData::Wheel { scroll, .. } = &events[0].data else {
+            panic!("Expected a wheel event");
+        };
+        assert_eq!(*scroll, CursorPoint::new(3. * TOUCHPAD_SPEED_MODIFIER, 0.));
+
+        // Only the movements scroll
+        assert!(input
+            .translate(&wheel(
+                MouseScrollDelta::LineDelta(0., 1.),
+                TouchPhase::Ended
+            ))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn ime() {
+        let mut input = InputState::default();
+
+        let events = input
+            .translate(&WindowEvent::Ime(Ime::Preedit(
+                "にほ".to_string(),
+                Some((0, 2)),
+            )))
+            .unwrap();
+        assert_eq!(names(&events), [EventName::ImePreedit]);
+
+        // Disabling the IME clears the text being composed
+        let events = input.translate(&WindowEvent::Ime(Ime::Disabled)).unwrap();
+        assert_eq!(names(&events), [EventName::ImePreedit]);
+        assert!(matches!(
+            &events[0].data,
+            PlatformEventData::Ime { text, cursor: None } if text.is_empty()
+        ));
+
+        // The committed text is also written like a key
+        let events = input
+            .translate(&WindowEvent::Ime(Ime::Commit("日本".to_string())))
+            .unwrap();
+        assert_eq!(names(&events), [EventName::ImeCommit, EventName::KeyDown]);
+        assert!(matches!(
+            &events[1].data,
+            PlatformEventData::Keyboard { key: Key::Character(text), .. } if text == "日本"
+        ));
+
+        assert!(input.translate(&WindowEvent::Ime(Ime::Enabled)).is_none());
+    }
+
+    #[test]
+    fn dropped_file() {
+        let mut input = InputState::default();
+        let path = PathBuf::from("notes.txt");
+
+        let events = input
+            .translate(&WindowEvent::HoveredFile(path.clone()))
+            .unwrap();
+        assert_eq!(names(&events), [EventName::GlobalFileHover]);
+
+        // The file is dropped once the cursor position is known
+        assert!(input
+            .translate(&WindowEvent::DroppedFile(path.clone()))
+            .unwrap()
+            .is_empty());
+        let events = input.translate(&cursor_moved(30., 40.)).unwrap();
+        assert_eq!(names(&events), [EventName::MouseMove, EventName::FileDrop]);
+        assert!(matches!(
+            &events[1].data,
+            PlatformEventData::File { file_path: Some(file_path), cursor }
+                if *file_path == path && *cursor == CursorPoint::new(30., 40.)
+        ));
+
+        // And only once
+        let events = input.translate(&cursor_moved(31., 40.)).unwrap();
+        assert_eq!(names(&events), [EventName::MouseMove]);
+
+        let events = input.translate(&WindowEvent::HoveredFileCancelled).unwrap();
+        assert_eq!(names(&events), [EventName::GlobalFileHoverCancelled]);
+    }
+
+    #[test]
+    fn touch_as_mouse() {
+        let mut input = InputState::default();
+
+        let events = input
+            .translate(&touch(TouchPhase::Started, 10., 10.))
+            .unwrap();
+        assert_eq!(names(&events), [EventName::TouchStart]);
+        assert_eq!(
+            names(&input.touch_as_mouse(TouchPhase::Started)),
+            [EventName::MouseMove, EventName::MouseDown]
+        );
+
+        input.translate(&touch(TouchPhase::Moved, 20., 10.));
+        let events = input.touch_as_mouse(TouchPhase::Moved);
+        assert_eq!(names(&events), [EventName::MouseMove]);
+        assert!(matches!(
+            events[0].data,
+            PlatformEventData::Mouse { cursor, button: None } if cursor == CursorPoint::new(20., 10.)
+        ));
+
+        // The finger leaves the window once lifted
+        input.translate(&touch(TouchPhase::Ended, 20., 10.));
+        assert_eq!(
+            names(&input.touch_as_mouse(TouchPhase::Ended)),
+            [EventName::MouseUp, EventName::MouseMove]
+        );
+        assert_eq!(input.cursor_pos, OUTSIDE_CURSOR);
+
+        input.translate(&touch(TouchPhase::Started, 5., 5.));
+        assert_eq!(
+            names(&input.touch_as_mouse(TouchPhase::Cancelled)),
+            [EventName::MouseMove]
+        );
+        assert_eq!(input.cursor_pos, OUTSIDE_CURSOR);
+    }
+
+    #[test]
+    fn other_events() {
+        let mut input = InputState::default();
+        assert!(input
+            .translate(&WindowEvent::Resized(PhysicalSize::new(100, 100)))
+            .is_none());
+        assert!(input.translate(&WindowEvent::Focused(true)).is_none());
+    }
+}
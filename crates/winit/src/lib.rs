@@ -2,15 +2,52 @@ pub use config::{
     WindowConfig,
     *,
 };
+pub use drivers::HeadlessRenderer;
+#[cfg(target_arch = "wasm32")]
+pub use drivers::WebRenderer;
+pub use embedded::FreyaRenderer;
+#[cfg(not(target_arch = "wasm32"))]
 pub use renderer::WinitRenderer;
+#[cfg(target_os = "android")]
+pub use winit::platform::android::activity::AndroidApp;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod accent_color;
+#[cfg(not(target_arch = "wasm32"))]
 mod accessibility;
+#[cfg(not(target_arch = "wasm32"))]
 mod app;
 mod config;
 pub mod devtools;
 mod drivers;
+mod embedded;
+#[cfg(not(target_arch = "wasm32"))]
+mod global_shortcuts;
+mod input;
 mod keyboard;
+#[cfg(not(target_arch = "wasm32"))]
+mod native_menu;
+#[cfg(not(target_arch = "wasm32"))]
+mod native_tray;
+#[cfg(not(target_arch = "wasm32"))]
+mod open_requests;
+mod opener;
+#[cfg(not(target_arch = "wasm32"))]
+mod printing;
+#[cfg(not(target_arch = "wasm32"))]
 mod renderer;
 mod size;
+#[cfg(any(target_os = "android", test))]
+mod soft_keyboard;
+#[cfg(not(target_arch = "wasm32"))]
+mod taskbar;
+#[cfg(not(target_arch = "wasm32"))]
+mod webviews;
+#[cfg(not(target_arch = "wasm32"))]
+mod window_effect;
+#[cfg(not(target_arch = "wasm32"))]
+mod window_geometry;
+#[cfg(not(target_arch = "wasm32"))]
 mod window_state;
+#[cfg(not(target_arch = "wasm32"))]
 mod winit_waker;
@@ -0,0 +1,366 @@
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    native_menu::{
+        NativeMenu,
+        NativeMenuItem,
+    },
+    types::NativeMenuEventSender,
+};
+use keyboard_types::{
+    Key,
+    Modifiers,
+};
+use winit::{
+    event_loop::EventLoopProxy,
+    window::Window,
+};
+
+use self::platform::{
+    PlatformItems,
+    PlatformMenu,
+};
+
+/// Keeps the [NativeMenu] of the window in sync with the operating system.
+///
+/// On platforms without native menus only the accelerators are handled, see [WinitNativeMenu::handle_shortcut].
+/// This includes Linux, where muda can only attach menu bars to GTK windows.
+pub struct WinitNativeMenu {
+    menu: Option<NativeMenu>,
+    native: Option<PlatformMenu>,
+}
+
+impl WinitNativeMenu {
+    pub fn new(
+        menu: Option<NativeMenu>,
+        window: &Window,
+        proxy: &EventLoopProxy<EventLoopMessage>,
+    ) -> Self {
+        platform::listen_events(proxy);
+
+        let mut native_menu = Self {
+            menu: None,
+            native: None,
+        };
+        native_menu.set_menu(menu, window);
+        native_menu
+    }
+
+    /// Replace the menu, or remove it if `None`.
+    pub fn set_menu(&mut self, menu: Option<NativeMenu>, window: &Window) {
+        if let Some(native) = self.native.take() {
+            native.remove(window);
+        }
+        self.native = menu
+            .as_ref()
+            .and_then(|menu| PlatformMenu::new(menu, window));
+        self.menu = menu;
+    }
+
+    pub fn set_checked(&mut self, id: &str, checked: bool) {
+        set_item_checked(
+            self.menu.as_mut().and_then(|menu| menu.item_mut(id)),
+            self.native.as_ref().map(PlatformMenu::items),
+            checked,
+        );
+    }
+
+    pub fn set_enabled(&mut self, id: &str, enabled: bool) {
+        set_item_enabled(
+            self.menu.as_mut().and_then(|menu| menu.item_mut(id)),
+            self.native.as_ref().map(PlatformMenu::items),
+            enabled,
+        );
+    }
+
+    /// Notify the activation of an item to the VirtualDOM.
+    /// Returns `false` if the item is not part of this menu.
+    pub fn activate(&mut self, id: &str, sender: &NativeMenuEventSender) -> bool {
+        let Some(item) = self.menu.as_mut().and_then(|menu| menu.item_mut(id)) else {
+            return false;
+        };
+        let native_checked = self
+            .native
+            .as_ref()
+            .and_then(|native| native.items().is_checked(id));
+        sender.send(item.activate(native_checked)).ok();
+        true
+    }
+
+    /// Activate the item whose accelerator matches the pressed keys, when the platform doesn't do it already.
+    /// Returns `true` if an item was activated.
+    pub fn handle_shortcut(
+        &mut self,
+        key: &Key,
+        modifiers: Modifiers,
+        sender: &NativeMenuEventSender,
+    ) -> bool {
+        if self.native.is_some() {
+            return false;
+        }
+
+        let Some(id) = self
+            .menu
+            .as_ref()
+            .and_then(|menu| menu.item_by_shortcut(key, modifiers))
+            .map(|item| item.id.clone())
+        else {
+            return false;
+        };
+        self.activate(&id, sender)
+    }
+}
+
+/// Check or uncheck a checkable item of a menu and its native counterpart, if any.
+/// Used by both the window menu and the tray menu.
+pub(crate) fn set_item_checked(
+    item: Option<&mut NativeMenuItem>,
+    native: Option<&PlatformItems>,
+    checked: bool,
+) {
+    let Some(item) = item else {
+        return;
+    };
+    item.set_checked(checked);
+    if let Some(native) = native {
+        native.set_checked(&item.id, checked);
+    }
+}
+
+/// Enable or disable an item of a menu and its native counterpart, if any.
+pub(crate) fn set_item_enabled(
+    item: Option<&mut NativeMenuItem>,
+    native: Option<&PlatformItems>,
+    enabled: bool,
+) {
+    let Some(item) = item else {
+        return;
+    };
+    item.enabled = enabled;
+    if let Some(native) = native {
+        native.set_enabled(&item.id, enabled);
+    }
+}
+
+#[cfg(any(target_os = "windows", target_os = "macos"))]
+pub(crate) mod platform {
+    use std::collections::HashMap;
+
+    use freya_core::{
+        event_loop_messages::EventLoopMessage,
+        native_menu::{
+            NativeMenu,
+            NativeMenuEntry,
+            NativeSubmenu,
+        },
+    };
+    use muda::{
+        accelerator::Accelerator,
+        CheckMenuItem,
+        Menu,
+        MenuEvent,
+        MenuId,
+        MenuItem,
+        PredefinedMenuItem,
+        Submenu,
+    };
+    use winit::{
+        event_loop::EventLoopProxy,
+        window::Window,
+    };
+
+    /// Forward the activated items to the event loop.
+    pub fn listen_events(proxy: &EventLoopProxy<EventLoopMessage>) {
+        let proxy = proxy.clone();
+        MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
+            proxy
+                .send_event(EventLoopMessage::NativeMenuItemActivated(event.id.0))
+                .ok();
+        }));
+    }
+
+    enum PlatformItem {
+        Normal(MenuItem),
+        Check(CheckMenuItem),
+    }
+
+    /// Native items of a menu, by their id.
+    #[derive(Default)]
+    pub struct PlatformItems(HashMap<String, PlatformItem>);
+
+    impl PlatformItems {
+        /// Create the native version of a submenu, registering its items.
+        pub fn create_submenu(&mut self, submenu: &NativeSubmenu) -> Submenu {
+            let platform_submenu = Submenu::new(&submenu.label, submenu.enabled);
+            for entry in &submenu.entries {
+                match entry {
+                    NativeMenuEntry::Item(item) => {
+                        let id = MenuId::new(&item.id);
+                        let accelerator = item
+                            .accelerator
+                            .as_deref()
+                            .and_then(|accelerator| accelerator.parse::<Accelerator>().ok());
+                        if let Some(checked) = item.checked {
+                            let check_item = CheckMenuItem::with_id(
+                                id,
+                                &item.label,
+                                item.enabled,
+                                checked,
+                                accelerator,
+                            );
+                            platform_submenu.append(&check_item).ok();
+                            self.0
+                                .insert(item.id.clone(), PlatformItem::Check(check_item));
+                        } else {
+                            let normal_item =
+                                MenuItem::with_id(id, &item.label, item.enabled, accelerator);
+                            platform_submenu.append(&normal_item).ok();
+                            self.0
+                                .insert(item.id.clone(), PlatformItem::Normal(normal_item));
+                        }
+                    }
+                    NativeMenuEntry::Separator => {
+                        platform_submenu
+                            .append(&PredefinedMenuItem::separator())
+                            .ok();
+                    }
+                    NativeMenuEntry::Submenu(submenu) => {
+                        let nested_submenu = self.create_submenu(submenu);
+                        platform_submenu.append(&nested_submenu).ok();
+                    }
+                }
+            }
+            platform_submenu
+        }
+
+        pub fn set_checked(&self, id: &str, checked: bool) {
+            if let Some(PlatformItem::Check(item)) = self.0.get(id) {
+                item.set_checked(checked);
+            }
+        }
+
+        pub fn set_enabled(&self, id: &str, enabled: bool) {
+            match self.0.get(id) {
+                Some(PlatformItem::Normal(item)) => item.set_enabled(enabled),
+                Some(PlatformItem::Check(item)) => item.set_enabled(enabled),
+                None => {}
+            }
+        }
+
+        pub fn is_checked(&self, id: &str) -> Option<bool> {
+            match self.0.get(id) {
+                Some(PlatformItem::Check(item)) => Some(item.is_checked()),
+                _ => None,
+            }
+        }
+    }
+
+    pub struct PlatformMenu {
+        menu: Menu,
+        items: PlatformItems,
+    }
+
+    impl PlatformMenu {
+        pub fn new(native_menu: &NativeMenu, window: &Window) -> Option<Self> {
+            let mut items = PlatformItems::default();
+            let menu = Menu::new();
+
+            for submenu in &native_menu.submenus {
+                menu.append(&items.create_submenu(submenu)).ok()?;
+            }
+
+            let platform_menu = Self { menu, items };
+            platform_menu.init(window)?;
+            Some(platform_menu)
+        }
+
+        #[cfg(target_os = "macos")]
+        fn init(&self, _window: &Window) -> Option<()> {
+            self.menu.init_for_nsapp();
+            Some(())
+        }
+
+        #[cfg(target_os = "windows")]
+        fn init(&self, window: &Window) -> Option<()> {
+            let hwnd = win32_handle(window)?;
+            // SAFETY: The handle belongs to a window that is alive
+            unsafe { self.menu.init_for_hwnd(hwnd) }.ok()
+        }
+
+        #[cfg(target_os = "macos")]
+        pub fn remove(self, _window: &Window) {
+            self.menu.remove_for_nsapp();
+        }
+
+        #[cfg(target_os = "windows")]
+        pub fn remove(self, window: &Window) {
+            if let Some(hwnd) = win32_handle(window) {
+                // SAFETY: The handle belongs to a window that is alive
+                unsafe { self.menu.remove_for_hwnd(hwnd) }.ok();
+            }
+        }
+
+        pub fn items(&self) -> &PlatformItems {
+            &self.items
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    pub(crate) fn win32_handle(window: &Window) -> Option<isize> {
+        use raw_window_handle::{
+            HasWindowHandle,
+            RawWindowHandle,
+        };
+
+        match window.window_handle().ok()?.as_raw() {
+            RawWindowHandle::Win32(handle) => Some(handle.hwnd.get()),
+            _ => None,
+        }
+    }
+}
+
+/// Native menus are not supported on this platform, so none is ever created.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub(crate) mod platform {
+    use freya_core::{
+        event_loop_messages::EventLoopMessage,
+        native_menu::NativeMenu,
+    };
+    use winit::{
+        event_loop::EventLoopProxy,
+        window::Window,
+    };
+
+    pub fn listen_events(_proxy: &EventLoopProxy<EventLoopMessage>) {}
+
+    pub enum PlatformItems {}
+
+    impl PlatformItems {
+        pub fn set_checked(&self, _id: &str, _checked: bool) {
+            match *self {}
+        }
+
+        pub fn set_enabled(&self, _id: &str, _enabled: bool) {
+            match *self {}
+        }
+
+        pub fn is_checked(&self, _id: &str) -> Option<bool> {
+            match *self {}
+        }
+    }
+
+    pub enum PlatformMenu {}
+
+    impl PlatformMenu {
+        pub fn new(_native_menu: &NativeMenu, _window: &Window) -> Option<Self> {
+            None
+        }
+
+        pub fn remove(self, _window: &Window) {
+            match self {}
+        }
+
+        pub fn items(&self) -> &PlatformItems {
+            match *self {}
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    native_menu::NativeSubmenu,
+    native_tray::{
+        NativeTray,
+        NativeTrayEvent,
+        NativeTrayIcon,
+    },
+    types::NativeTrayEventSender,
+};
+use winit::event_loop::EventLoopProxy;
+
+use self::platform::PlatformTray;
+use crate::native_menu::{
+    set_item_checked,
+    set_item_enabled,
+};
+
+/// Keeps the [NativeTray] in sync with the operating system.
+///
+/// Only Windows and macOS support it, other platforms keep track of its state but don't show anything.
+pub struct WinitNativeTray {
+    tray: NativeTray,
+    native: Option<PlatformTray>,
+}
+
+impl WinitNativeTray {
+    pub fn new(tray: Option<NativeTray>, proxy: &EventLoopProxy<EventLoopMessage>) -> Self {
+        platform::listen_events(proxy);
+
+        let mut native_tray = Self {
+            tray: tray.unwrap_or_default(),
+            native: None,
+        };
+        native_tray.sync();
+        native_tray
+    }
+
+    /// Whether the window should be hidden instead of closing the app,
+    /// only when there is a tray icon to bring it back.
+    pub fn hide_on_close(&self) -> bool {
+        self.tray.hide_on_close && self.native.is_some()
+    }
+
+    pub fn set_icon(&mut self, icon: Option<NativeTrayIcon>) {
+        self.tray.icon = icon;
+        self.sync();
+    }
+
+    pub fn set_tooltip(&mut self, tooltip: Option<String>) {
+        if let Some(native) = &self.native {
+            native.set_tooltip(tooltip.as_deref());
+        }
+        self.tray.tooltip = tooltip;
+    }
+
+    pub fn set_menu(&mut self, menu: Option<NativeSubmenu>) {
+        self.tray.menu = menu;
+        if let Some(native) = &mut self.native {
+            native.set_menu(self.tray.menu.as_ref());
+        }
+    }
+
+    pub fn set_checked(&mut self, id: &str, checked: bool) {
+        set_item_checked(
+            self.tray.menu.as_mut().and_then(|menu| menu.item_mut(id)),
+            self.native.as_ref().map(PlatformTray::items),
+            checked,
+        );
+    }
+
+    pub fn set_enabled(&mut self, id: &str, enabled: bool) {
+        set_item_enabled(
+            self.tray.menu.as_mut().and_then(|menu| menu.item_mut(id)),
+            self.native.as_ref().map(PlatformTray::items),
+            enabled,
+        );
+    }
+
+    /// Notify the activation of an item of the tray menu to the VirtualDOM.
+    /// Returns `false` if the item is not part of the tray menu.
+    pub fn activate(&mut self, id: &str, sender: &NativeTrayEventSender) -> bool {
+        let Some(item) = self.tray.menu.as_mut().and_then(|menu| menu.item_mut(id)) else {
+            return false;
+        };
+        let native_checked = self
+            .native
+            .as_ref()
+            .and_then(|native| native.items().is_checked(id));
+        sender
+            .send(NativeTrayEvent::MenuItem(item.activate(native_checked)))
+            .ok();
+        true
+    }
+
+    /// Create, update or remove the native tray icon to match the current state.
+    fn sync(&mut self) {
+        match (&self.tray.icon, &mut self.native) {
+            (Some(icon), Some(native)) => native.set_icon(icon),
+            (Some(_), None) => self.native = PlatformTray::new(&self.tray),
+            (None, native) => *native = None,
+        }
+    }
+}
+
+#[cfg(any(target_os = "windows", target_os = "macos"))]
+mod platform {
+    use freya_core::{
+        event_loop_messages::EventLoopMessage,
+        native_menu::NativeSubmenu,
+        native_tray::{
+            NativeTray,
+            NativeTrayEvent,
+            NativeTrayIcon,
+        },
+        platform::MouseButton,
+    };
+    use tray_icon::{
+        Icon,
+        MouseButtonState,
+        TrayIcon,
+        TrayIconBuilder,
+        TrayIconEvent,
+    };
+    use winit::event_loop::EventLoopProxy;
+
+    use crate::native_menu::platform::PlatformItems;
+
+    /// Forward the interactions with the icon to the event loop.
+    pub fn listen_events(proxy: &EventLoopProxy<EventLoopMessage>) {
+        let proxy = proxy.clone();
+        TrayIconEvent::set_event_handler(Some(move |event: TrayIconEvent| {
+            let event = match event {
+                TrayIconEvent::Click {
+                    button,
+                    button_state: MouseButtonState::Up,
+                    ..
+                } => NativeTrayEvent::Click(map_button(button)),
+                TrayIconEvent::DoubleClick { button, .. } => {
+                    NativeTrayEvent::DoubleClick(map_button(button))
+                }
+                _ => return,
+            };
+            proxy
+                .send_event(EventLoopMessage::NativeTrayEvent(event))
+                .ok();
+        }));
+    }
+
+    fn map_button(button: tray_icon::MouseButton) -> MouseButton {
+        match button {
+            tray_icon::MouseButton::Left => MouseButton::Left,
+            tray_icon::MouseButton::Right => MouseButton::Right,
+            tray_icon::MouseButton::Middle => MouseButton::Middle,
+        }
+    }
+
+    fn create_icon(icon: &NativeTrayIcon) -> Option<Icon> {
+        Icon::from_rgba(icon.rgba.clone(), icon.width, icon.height).ok()
+    }
+
+    pub struct PlatformTray {
+        tray_icon: TrayIcon,
+        items: PlatformItems,
+    }
+
+    impl PlatformTray {
+        pub fn new(tray: &NativeTray) -> Option<Self> {
+            let mut items = PlatformItems::default();
+            let mut builder = TrayIconBuilder::new().with_icon(create_icon(tray.icon.as_ref()?)?);
+            if let Some(tooltip) = &tray.tooltip {
+                builder = builder.with_tooltip(tooltip);
+            }
+            if let Some(menu) = &tray.menu {
+                builder = builder
+                    .with_menu(Box::new(items.create_submenu(menu)))
+                    .with_menu_on_left_click(false);
+            }
+            let tray_icon = builder.build().ok()?;
+            Some(Self { tray_icon, items })
+        }
+
+        pub fn items(&self) -> &PlatformItems {
+            &self.items
+        }
+
+        pub fn set_icon(&self, icon: &NativeTrayIcon) {
+            self.tray_icon.set_icon(create_icon(icon)).ok();
+        }
+
+        pub fn set_tooltip(&self, tooltip: Option<&str>) {
+            self.tray_icon.set_tooltip(tooltip).ok();
+        }
+
+        pub fn set_menu(&mut self, menu: Option<&NativeSubmenu>) {
+            self.items = PlatformItems::default();
+            let menu = menu.map(|menu| {
+                Box::new(self.items.create_submenu(menu)) as Box<dyn tray_icon::menu::ContextMenu>
+            });
+            self.tray_icon.set_menu(menu);
+        }
+    }
+}
+
+/// Tray icons are not supported on this platform, so none is ever created.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+mod platform {
+    use freya_core::{
+        event_loop_messages::EventLoopMessage,
+        native_menu::NativeSubmenu,
+        native_tray::{
+            NativeTray,
+            NativeTrayIcon,
+        },
+    };
+    use winit::event_loop::EventLoopProxy;
+
+    use crate::native_menu::platform::PlatformItems;
+
+    pub fn listen_events(_proxy: &EventLoopProxy<EventLoopMessage>) {}
+
+    pub enum PlatformTray {}
+
+    impl PlatformTray {
+        pub fn new(_tray: &NativeTray) -> Option<Self> {
+            None
+        }
+
+        pub fn items(&self) -> &PlatformItems {
+            match *self {}
+        }
+
+        pub fn set_icon(&self, _icon: &NativeTrayIcon) {
+            match *self {}
+        }
+
+        pub fn set_tooltip(&self, _tooltip: Option<&str>) {
+            match *self {}
+        }
+
+        pub fn set_menu(&mut self, _menu: Option<&NativeSubmenu>) {
+            match *self {}
+        }
+    }
+}
@@ -0,0 +1,615 @@
+use std::{
+    ffi::{
+        OsStr,
+        OsString,
+    },
+    fs::{
+        self,
+        File,
+        OpenOptions,
+    },
+    io::{
+        ErrorKind,
+        Read,
+        Write,
+    },
+    net::{
+        Ipv4Addr,
+        Shutdown,
+        SocketAddr,
+        TcpListener,
+        TcpStream,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::Duration,
+};
+
+use fs4::fs_std::FileExt;
+
+/// How long to wait for the running instance to accept the arguments before considering it dead.
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// How long to wait for the other instance to send the arguments or answer them.
+const IO_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Biggest message accepted from another instance, in bytes.
+const MAX_MESSAGE_LEN: u64 = 1024 * 1024;
+
+/// How many times to try to reach the instance holding the lock before giving up.
+const LOCK_ATTEMPTS: usize = 20;
+
+/// Time between the attempts to reach the instance holding the lock.
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Make the operating system open the links with the given URL schemes with this executable, for the current user.
+///
+/// On macOS the schemes must be declared in the `Info.plist` of the app bundle instead.
+pub(crate) fn register_url_schemes(schemes: &[String]) {
+    if schemes.is_empty() {
+        return;
+    }
+    let Ok(executable) = std::env::current_exe() else {
+        return;
+    };
+    for scheme in schemes {
+        if let Err(err) = register_url_scheme(scheme, &executable) {
+            tracing::warn!("Failed to register the URL scheme '{scheme}': {err}");
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn register_url_scheme(scheme: &str, executable: &Path) -> std::io::Result<()> {
+    use std::process::Command;
+
+    let key = format!("HKCU\\Software\\Classes\\{scheme}");
+    let command_key = format!("{key}\\shell\\open\\command");
+    let description = format!("URL:{scheme}");
+    let command = format!("\"{}\" \"%1\"", executable.display());
+    for args in [
+        vec![key.as_str(), "/ve", "/d", description.as_str()],
+        vec![key.as_str(), "/v", "URL Protocol", "/d", ""],
+        vec![command_key.as_str(), "/ve", "/d", command.as_str()],
+    ] {
+        Command::new("reg")
+            .arg("add")
+            .args(args)
+            .arg("/f")
+            .output()?;
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn register_url_scheme(scheme: &str, executable: &Path) -> std::io::Result<()> {
+    use std::process::Command;
+
+    let app_name = app_name(executable);
+    let file_name = format!("{app_name}-{scheme}-handler.desktop");
+    let applications = dirs::data_dir()
+        .ok_or(std::io::ErrorKind::NotFound)?
+        .join("applications");
+    fs::create_dir_all(&applications)?;
+    fs::write(
+        applications.join(&file_name),
+        format!(
+            "[Desktop Entry]\nType=Application\nName={app_name}\nExec=\"{}\" %u\nNoDisplay=true\nMimeType=x-scheme-handler/{scheme};\n",
+            executable.display()
+        ),
+    )?;
+    Command::new("xdg-mime")
+        .args(["default", &file_name, &format!("x-scheme-handler/{scheme}")])
+        .output()?;
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+fn register_url_scheme(scheme: &str, _executable: &Path) -> std::io::Result<()> {
+    tracing::info!("The URL scheme '{scheme}' must be declared in the app bundle");
+    Ok(())
+}
+
+/// Lets only one instance of the app run, see `LaunchConfig::single_instance`.
+///
+/// The running instance holds an exclusive lock of a file, which the operating system releases
+/// even if it crashes, so two instances launched at the same time can't both run. It also listens in a local port, written along with two random tokens in a file
+/// only readable by the user, so the new instances can send it their arguments. The new instances
+/// send the first token to prove they read the file, and the running one answers with the second one
+/// so a stale file pointing to a port now used by something else is not mistaken for a running instance.
+pub(crate) struct SingleInstance {
+    listener: TcpListener,
+    secrets: Secrets,
+    path: PathBuf,
+    /// Held until this is dropped.
+    _lock: File,
+}
+
+/// Result of [SingleInstance::acquire].
+pub(crate) enum Acquired {
+    /// This is the running instance.
+    Running(SingleInstance),
+    /// The arguments were forwarded to the running instance, so this one should exit.
+    Forwarded,
+    /// The instances can't coordinate, e.g. because there is no folder for the instance file.
+    Unavailable,
+}
+
+/// Random tokens shared by the instances through the instance file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Secrets {
+    /// Sent by the new instances.
+    token: String,
+    /// Sent back by the running instance.
+    ack: String,
+}
+
+impl Secrets {
+    fn random() -> std::io::Result<Self> {
+        Ok(Self {
+            token: random_token()?,
+            ack: random_token()?,
+        })
+    }
+}
+
+/// Content of the instance file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct InstanceInfo {
+    port: u16,
+    secrets: Secrets,
+}
+
+impl InstanceInfo {
+    fn parse(content: &str) -> Option<Self> {
+        let mut lines = content.lines().map(str::trim);
+        let port = lines.next()?.parse().ok()?;
+        let token = lines.next().filter(|token| !token.is_empty())?;
+        let ack = lines.next().filter(|ack| !ack.is_empty())?;
+        Some(Self {
+            port,
+            secrets: Secrets {
+                token: token.to_string(),
+                ack: ack.to_string(),
+            },
+        })
+    }
+
+    fn serialize(&self) -> String {
+        format!(
+            "{}\n{}\n{}",
+            self.port, self.secrets.token, self.secrets.ack
+        )
+    }
+
+    /// Send the arguments to the running instance and wait for its answer.
+    fn forward(&self, args: &[OsString]) -> std::io::Result<()> {
+        let address = SocketAddr::from((Ipv4Addr::LOCALHOST, self.port));
+        let mut stream = TcpStream::connect_timeout(&address, CONNECT_TIMEOUT)?;
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+        stream.write_all(&encode_message(&self.secrets.token, args))?;
+        stream.shutdown(Shutdown::Write)?;
+
+        let mut reply = String::new();
+        stream
+            .take(self.secrets.ack.len() as u64)
+            .read_to_string(&mut reply)?;
+        if reply == self.secrets.ack {
+            Ok(())
+        } else {
+            Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Not answered by a running instance",
+            ))
+        }
+    }
+}
+
+impl SingleInstance {
+    /// Become the running instance of the app with the given id, or send the arguments
+    /// to the running one if there is one.
+    pub fn acquire(app_id: &str) -> Acquired {
+        let Some(dir) = instance_dir() else {
+            tracing::warn!("There is no folder to coordinate the instances of '{app_id}'");
+            return Acquired::Unavailable;
+        };
+        let args = std::env::args_os()
+            .skip(1)
+            .map(absolute_path_arg)
+            .collect::<Vec<_>>();
+        Self::acquire_in(&dir, app_id, &args)
+    }
+
+    fn acquire_in(dir: &Path, app_id: &str, args: &[OsString]) -> Acquired {
+        let file_name = file_name(app_id);
+        let path = dir.join(format!("{file_name}.instance"));
+        let lock = match open_private(&dir.join(format!("{file_name}.lock")), false) {
+            Ok(lock) => lock,
+            Err(err) => {
+                tracing::warn!("Failed to open the single instance lock of '{app_id}': {err}");
+                return Acquired::Unavailable;
+            }
+        };
+
+        for _ in 0..LOCK_ATTEMPTS {
+            if lock.try_lock_exclusive().is_ok() {
+                return match Self::create(path, lock) {
+                    Ok(single_instance) => Acquired::Running(single_instance),
+                    Err(err) => {
+                        tracing::warn!("Failed to listen for other instances of '{app_id}': {err}");
+                        Acquired::Unavailable
+                    }
+                };
+            }
+
+            // The running instance might have not started listening yet
+            let info = fs::read_to_string(&path)
+                .ok()
+                .and_then(|content| InstanceInfo::parse(&content));
+            if info.is_some_and(|info| info.forward(args).is_ok()) {
+                tracing::info!("Forwarded the arguments to the running instance of '{app_id}'");
+                return Acquired::Forwarded;
+            }
+            std::thread::sleep(LOCK_RETRY_INTERVAL);
+        }
+
+        tracing::warn!("The running instance of '{app_id}' didn't answer");
+        Acquired::Unavailable
+    }
+
+    fn create(path: PathBuf, lock: File) -> std::io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        let info = InstanceInfo {
+            port: listener.local_addr()?.port(),
+            secrets: Secrets::random()?,
+        };
+        open_private(&path, true)?.write_all(info.serialize().as_bytes())?;
+        Ok(Self {
+            listener,
+            secrets: info.secrets,
+            path,
+            _lock: lock,
+        })
+    }
+
+    /// Receive the arguments of the new instances in the background.
+    ///
+    /// Every connection is handled in its own thread, so a client that never finishes doesn't block the others.
+    pub fn listen(&self, on_arguments: impl Fn(Vec<OsString>) + Clone + Send + 'static) {
+        let listener = match self.listener.try_clone() {
+            Ok(listener) => listener,
+            Err(err) => {
+                tracing::warn!("Failed to listen for other instances: {err}");
+                return;
+            }
+        };
+        let secrets = self.secrets.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let secrets = secrets.clone();
+                let on_arguments = on_arguments.clone();
+                std::thread::spawn(move || match receive(stream, &secrets) {
+                    Ok(args) => on_arguments(args),
+                    Err(err) => {
+                        tracing::debug!("Rejected a connection from another instance: {err}")
+                    }
+                });
+            }
+        });
+    }
+
+    /// Remove the instance file, unless another instance replaced it.
+    pub fn release(self) {
+        let is_own = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|content| InstanceInfo::parse(&content))
+            .is_some_and(|info| info.secrets == self.secrets);
+        if is_own {
+            fs::remove_file(&self.path).ok();
+        }
+    }
+}
+
+/// Read the arguments sent by a new instance and answer it.
+fn receive(mut stream: TcpStream, secrets: &Secrets) -> std::io::Result<Vec<OsString>> {
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+    let mut message = Vec::new();
+    (&stream).take(MAX_MESSAGE_LEN).read_to_end(&mut message)?;
+    let (token, args) = decode_message(&message)
+        .ok_or_else(|| std::io::Error::new(ErrorKind::InvalidData, "Malformed message"))?;
+    if token != secrets.token.as_bytes() {
+        return Err(std::io::Error::new(
+            ErrorKind::PermissionDenied,
+            "Wrong token",
+        ));
+    }
+    stream.write_all(secrets.ack.as_bytes())?;
+    Ok(args)
+}
+
+/// The token followed by the arguments, each one prefixed with its length.
+fn encode_message(token: &str, args: &[OsString]) -> Vec<u8> {
+    let mut message = Vec::new();
+    let mut push = |bytes: &[u8]| {
+        message.extend((bytes.len() as u32).to_le_bytes());
+        message.extend(bytes);
+    };
+    push(token.as_bytes());
+    for arg in args {
+        push(&arg_to_bytes(arg));
+    }
+    message
+}
+
+fn decode_message(mut message: &[u8]) -> Option<(Vec<u8>, Vec<OsString>)> {
+    let mut chunks = Vec::new();
+    while !message.is_empty() {
+        let (len, rest) = message.split_first_chunk::<4>()?;
+        let len = u32::from_le_bytes(*len) as usize;
+        if rest.len() < len {
+            return None;
+        }
+        let (chunk, rest) = rest.split_at(len);
+        chunks.push(chunk);
+        message = rest;
+    }
+    let (token, args) = chunks.split_first()?;
+    Some((
+        token.to_vec(),
+        args.iter().map(|arg| arg_from_bytes(arg)).collect(),
+    ))
+}
+
+#[cfg(unix)]
+fn arg_to_bytes(arg: &OsStr) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+    arg.as_bytes().to_vec()
+}
+
+#[cfg(unix)]
+fn arg_from_bytes(bytes: &[u8]) -> OsString {
+    use std::os::unix::ffi::OsStringExt;
+    OsString::from_vec(bytes.to_vec())
+}
+
+#[cfg(windows)]
+fn arg_to_bytes(arg: &OsStr) -> Vec<u8> {
+    use std::os::windows::ffi::OsStrExt;
+    arg.encode_wide().flat_map(u16::to_le_bytes).collect()
+}
+
+#[cfg(windows)]
+fn arg_from_bytes(bytes: &[u8]) -> OsString {
+    use std::os::windows::ffi::OsStringExt;
+    let wide = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect::<Vec<_>>();
+    OsString::from_wide(&wide)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn arg_to_bytes(arg: &OsStr) -> Vec<u8> {
+    arg.to_string_lossy().into_owned().into_bytes()
+}
+
+#[cfg(not(any(unix, windows)))]
+fn arg_from_bytes(bytes: &[u8]) -> OsString {
+    String::from_utf8_lossy(bytes).into_owned().into()
+}
+
+/// The runtime folder of the user, or the cache one if there is none, which other users can't read.
+fn instance_dir() -> Option<PathBuf> {
+    dirs::runtime_dir().or_else(dirs::cache_dir)
+}
+
+fn file_name(app_id: &str) -> String {
+    app_id
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Open or create a file only the current user can read and write.
+fn open_private(path: &Path, truncate: bool) -> std::io::Result<File> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(truncate);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let file = options.open(path)?;
+    // The mode only applies when the file is created
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    }
+    Ok(file)
+}
+
+fn random_token() -> std::io::Result<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes).map_err(std::io::Error::other)?;
+    Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+/// Files are opened by the running instance, which may be in another folder.
+fn absolute_path_arg(arg: OsString) -> OsString {
+    match fs::canonicalize(&arg) {
+        Ok(path) => path.into_os_string(),
+        Err(_) => arg,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn app_name(executable: &Path) -> String {
+    executable
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "freya".to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        ffi::OsString,
+        path::PathBuf,
+        sync::mpsc,
+        time::Duration,
+    };
+
+    use super::*;
+
+    /// Empty folder only used by the calling test.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "freya-single-instance-{name}-{}",
+            random_token().unwrap()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn parse_instance_info() {
+        let info = InstanceInfo {
+            port: 4321,
+            secrets: Secrets::random().unwrap(),
+        };
+        assert_eq!(InstanceInfo::parse(&info.serialize()), Some(info));
+
+        assert_eq!(InstanceInfo::parse(""), None);
+        assert_eq!(InstanceInfo::parse("4321"), None);
+        assert_eq!(InstanceInfo::parse("4321\ntoken"), None);
+        assert_eq!(InstanceInfo::parse("port\ntoken\nack"), None);
+        assert_eq!(InstanceInfo::parse("99999\ntoken\nack"), None);
+    }
+
+    #[test]
+    fn encode_message() {
+        let args = vec![
+            OsString::from("--new-tab"),
+            OsString::new(),
+            OsString::from("ñ.txt"),
+        ];
+        let message = super::encode_message("token", &args);
+        assert_eq!(decode_message(&message), Some((b"token".to_vec(), args)));
+
+        // Truncated messages are rejected
+        assert_eq!(decode_message(&message[..message.len() - 1]), None);
+        assert_eq!(decode_message(&[]), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn encode_non_utf8_message() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let args = vec![OsString::from_vec(vec![0x66, 0x6f, 0x80, 0x6f])];
+        let message = super::encode_message("token", &args);
+        assert_eq!(decode_message(&message).unwrap().1, args);
+    }
+
+    #[test]
+    fn forward_arguments() {
+        let dir = temp_dir("forward");
+        let Acquired::Running(running) = SingleInstance::acquire_in(&dir, "app", &[]) else {
+            panic!("The first instance should run");
+        };
+        let (sender, receiver) = mpsc::channel();
+        running.listen(move |args| sender.send(args).unwrap());
+
+        let args = vec![OsString::from("--new-tab"), OsString::from("notes.txt")];
+        assert!(matches!(
+            SingleInstance::acquire_in(&dir, "app", &args),
+            Acquired::Forwarded
+        ));
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(args));
+
+        // Another app is not affected
+        assert!(matches!(
+            SingleInstance::acquire_in(&dir, "other-app", &[]),
+            Acquired::Running(_)
+        ));
+
+        // Once it exits the next instance runs
+        running.release();
+        assert!(!dir.join("app.instance").exists());
+        assert!(matches!(
+            SingleInstance::acquire_in(&dir, "app", &[]),
+            Acquired::Running(_)
+        ));
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn stale_instance_file() {
+        let dir = temp_dir("stale");
+
+        // Something unrelated listens in the port of the file left by a crashed instance
+        let unrelated = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let info = InstanceInfo {
+            port: unrelated.local_addr().unwrap().port(),
+            secrets: Secrets::random().unwrap(),
+        };
+        fs::write(dir.join("app.instance"), info.serialize()).unwrap();
+        std::thread::spawn(move || {
+            // Echo whatever it receives
+            for mut stream in unrelated.incoming().flatten() {
+                let mut message = Vec::new();
+                (&stream).take(1024).read_to_end(&mut message).ok();
+                stream.write_all(&message).ok();
+            }
+        });
+
+        assert!(matches!(
+            SingleInstance::acquire_in(&dir, "app", &[]),
+            Acquired::Running(_)
+        ));
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn simultaneous_launches() {
+        let dir = temp_dir("simultaneous");
+
+        let launches = (0..4)
+            .map(|_| {
+                let dir = dir.clone();
+                std::thread::spawn(move || match SingleInstance::acquire_in(&dir, "app", &[]) {
+                    Acquired::Running(running) => {
+                        running.listen(|_| {});
+                        // Keep running while the others launch
+                        std::thread::sleep(Duration::from_secs(3));
+                        true
+                    }
+                    _ => false,
+                })
+            })
+            .collect::<Vec<_>>();
+        let running = launches
+            .into_iter()
+            .filter(|launch| launch.join().unwrap())
+            .count();
+        assert_eq!(running, 1);
+
+        fs::remove_dir_all(dir).ok();
+    }
+}
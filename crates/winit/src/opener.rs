@@ -0,0 +1,183 @@
+#[cfg(any(
+    target_os = "windows",
+    all(unix, not(any(target_os = "android", target_os = "ios")))
+))]
+use std::process::{
+    Command,
+    Stdio,
+};
+use std::{
+    ffi::OsStr,
+    path::Path,
+};
+
+use freya_core::opener::{
+    OpenError,
+    OpenTarget,
+};
+use tokio::sync::oneshot;
+
+/// Open the target in a background thread, as the launched program might take a while to answer.
+pub fn request_open(target: OpenTarget, sender: oneshot::Sender<Result<(), OpenError>>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    std::thread::spawn(move || {
+        let result = open_target(&target);
+        if let Err(err) = &result {
+            tracing::warn!("{err}");
+        }
+        sender.send(result).ok();
+    });
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = target;
+        sender.send(Err(OpenError::Unsupported)).ok();
+    }
+}
+
+/// Open a link or a file with the default app, or show a file in the file manager.
+pub fn open_target(target: &OpenTarget) -> Result<(), OpenError> {
+    target.validate()?;
+    match target {
+        OpenTarget::Url(url) => open(OsStr::new(url)),
+        OpenTarget::Path(path) => open(path.as_os_str()),
+        OpenTarget::Reveal(path) => reveal(path),
+    }
+}
+
+/// Turn the output of a command into an [OpenError].
+#[cfg(any(
+    target_os = "windows",
+    all(unix, not(any(target_os = "android", target_os = "ios")))
+))]
+fn command_result(output: std::io::Result<std::process::Output>) -> Result<(), OpenError> {
+    match output {
+        Ok(output) if output.status.success() => Ok(()),
+        Ok(output) => Err(OpenError::Failed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        )),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Err(OpenError::Unsupported),
+        Err(err) => Err(OpenError::Failed(err.to_string())),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn open(target: &OsStr) -> Result<(), OpenError> {
+    command_result(
+        Command::new("open")
+            .arg(target)
+            .stdin(Stdio::null())
+            .output(),
+    )
+}
+
+#[cfg(target_os = "macos")]
+fn reveal(path: &Path) -> Result<(), OpenError> {
+    command_result(
+        Command::new("open")
+            .arg("-R")
+            .arg(path)
+            .stdin(Stdio::null())
+            .output(),
+    )
+}
+
+/// `start` would need the link escaped for `cmd`, so the shell's URL handler is used instead,
+/// which also opens files with their default app.
+#[cfg(target_os = "windows")]
+fn open(target: &OsStr) -> Result<(), OpenError> {
+    command_result(
+        Command::new("rundll32")
+            .arg("url.dll,FileProtocolHandler")
+            .arg(target)
+            .stdin(Stdio::null())
+            .output(),
+    )
+}
+
+/// The exit code of the explorer is not reliable, so it only fails if it can't be launched.
+#[cfg(target_os = "windows")]
+fn reveal(path: &Path) -> Result<(), OpenError> {
+    let mut select = std::ffi::OsString::from("/select,");
+    select.push(path.canonicalize().unwrap_or_else(|_| path.to_path_buf()));
+    Command::new("explorer")
+        .arg(select)
+        .stdin(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|err| OpenError::Failed(err.to_string()))
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "macos", target_os = "android", target_os = "ios"))
+))]
+fn open(target: &OsStr) -> Result<(), OpenError> {
+    command_result(
+        Command::new("xdg-open")
+            .arg(target)
+            .stdin(Stdio::null())
+            .output(),
+    )
+}
+
+/// File managers that follow the freedesktop spec select the file, the others only open its folder.
+#[cfg(all(
+    unix,
+    not(any(target_os = "macos", target_os = "android", target_os = "ios"))
+))]
+fn reveal(path: &Path) -> Result<(), OpenError> {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let show_items = command_result(
+        Command::new("dbus-send")
+            .args([
+                "--session",
+                "--print-reply",
+                "--dest=org.freedesktop.FileManager1",
+                "/org/freedesktop/FileManager1",
+                "org.freedesktop.FileManager1.ShowItems",
+            ])
+            .arg(format!("array:string:{}", file_uri(&path)))
+            .arg("string:")
+            .output(),
+    );
+    match (show_items, path.parent()) {
+        (Ok(()), _) => Ok(()),
+        (Err(_), Some(folder)) => open(folder.as_os_str()),
+        (Err(err), None) => Err(err),
+    }
+}
+
+/// Percent-encode the path into a `file://` URI.
+#[cfg(all(
+    unix,
+    not(any(target_os = "macos", target_os = "android", target_os = "ios"))
+))]
+fn file_uri(path: &Path) -> String {
+    use std::os::unix::ffi::OsStrExt;
+
+    let mut uri = String::from("file://");
+    for &byte in path.as_os_str().as_bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    uri
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    all(unix, not(any(target_os = "android", target_os = "ios")))
+)))]
+fn open(_target: &OsStr) -> Result<(), OpenError> {
+    Err(OpenError::Unsupported)
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    all(unix, not(any(target_os = "android", target_os = "ios")))
+)))]
+fn reveal(_path: &Path) -> Result<(), OpenError> {
+    Err(OpenError::Unsupported)
+}
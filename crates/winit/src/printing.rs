@@ -0,0 +1,527 @@
+use freya_core::{
+    printing::{
+        PrintError,
+        PrintOptions,
+        Printer,
+    },
+    render::PrintDocument,
+};
+use tokio::sync::oneshot;
+
+/// List the printers in a background thread, as the print system might take a while to answer.
+pub fn request_printers(sender: oneshot::Sender<Vec<Printer>>) {
+    std::thread::spawn(move || {
+        sender.send(platform::list_printers()).ok();
+    });
+}
+
+/// Submit the document in a background thread, sending back the number of printed pages.
+pub fn request_print(
+    document: PrintDocument,
+    options: PrintOptions,
+    sender: oneshot::Sender<Result<usize, PrintError>>,
+) {
+    std::thread::spawn(move || {
+        sender.send(submit_document(&document, &options)).ok();
+    });
+}
+
+/// Submit the document to the printer in the options, or the default printer.
+pub fn submit_document(
+    document: &PrintDocument,
+    options: &PrintOptions,
+) -> Result<usize, PrintError> {
+    if let Some(printer) = &options.printer {
+        if !platform::list_printers().iter().any(|p| &p.name == printer) {
+            return Err(PrintError::PrinterNotFound(printer.clone()));
+        }
+    }
+    platform::send_to_printer(document, options)?;
+    Ok(document.pages)
+}
+
+#[cfg(any(unix, target_os = "windows"))]
+fn no_default_printer() -> PrintError {
+    PrintError::Failed("There is no default printer".to_string())
+}
+
+/// CUPS is the print system of Linux and macOS, its library is loaded when printing
+/// so apps still run in systems without it.
+#[cfg(unix)]
+mod platform {
+    use std::{
+        ffi::{
+            c_char,
+            c_int,
+            c_void,
+            CStr,
+            CString,
+        },
+        ptr,
+    };
+
+    use freya_core::{
+        printing::{
+            PrintError,
+            PrintOptions,
+            Printer,
+        },
+        render::PrintDocument,
+    };
+    use libloading::{
+        Library,
+        Symbol,
+    };
+
+    use super::no_default_printer;
+
+    #[cfg(target_os = "macos")]
+    const LIBRARY: &str = "libcups.2.dylib";
+    #[cfg(not(target_os = "macos"))]
+    const LIBRARY: &str = "libcups.so.2";
+
+    /// `HTTP_STATUS_CONTINUE`, the server waits for the rest of the document.
+    const HTTP_STATUS_CONTINUE: c_int = 100;
+    /// `IPP_STATUS_REDIRECTION_OTHER_SITE`, the first status that is not a success.
+    const IPP_STATUS_REDIRECTION_OTHER_SITE: c_int = 0x0200;
+    /// `CUPS_HTTP_DEFAULT`, the connection to the default server.
+    const CUPS_HTTP_DEFAULT: *mut c_void = ptr::null_mut();
+    const CUPS_FORMAT_PDF: &CStr = c"application/pdf";
+
+    /// `cups_option_t`, only handled by libcups.
+    enum CupsOption {}
+
+    /// `cups_dest_t`, filled by libcups.
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct CupsDest {
+        name: *mut c_char,
+        instance: *mut c_char,
+        is_default: c_int,
+        num_options: c_int,
+        options: *mut CupsOption,
+    }
+
+    type CupsGetDests = unsafe extern "C" fn(*mut *mut CupsDest) -> c_int;
+    type CupsFreeDests = unsafe extern "C" fn(c_int, *mut CupsDest);
+    type CupsAddOption =
+        unsafe extern "C" fn(*const c_char, *const c_char, c_int, *mut *mut CupsOption) -> c_int;
+    type CupsFreeOptions = unsafe extern "C" fn(c_int, *mut CupsOption);
+    type CupsCreateJob = unsafe extern "C" fn(
+        *mut c_void,
+        *const c_char,
+        *const c_char,
+        c_int,
+        *mut CupsOption,
+    ) -> c_int;
+    type CupsStartDocument = unsafe extern "C" fn(
+        *mut c_void,
+        *const c_char,
+        c_int,
+        *const c_char,
+        *const c_char,
+        c_int,
+    ) -> c_int;
+    type CupsWriteRequestData = unsafe extern "C" fn(*mut c_void, *const c_char, usize) -> c_int;
+    type CupsFinishDocument = unsafe extern "C" fn(*mut c_void, *const c_char) -> c_int;
+    type CupsCancelJob = unsafe extern "C" fn(*const c_char, c_int) -> c_int;
+    type CupsLastErrorString = unsafe extern "C" fn() -> *const c_char;
+
+    fn load_library() -> Result<Library, PrintError> {
+        // SAFETY: Loading libcups doesn't run any initialization with side effects
+        unsafe { Library::new(LIBRARY) }.map_err(|_| PrintError::Unsupported)
+    }
+
+    /// Function exported by libcups with the given name and signature.
+    fn function<'a, T>(library: &'a Library, name: &[u8]) -> Result<Symbol<'a, T>, PrintError> {
+        // SAFETY: The signatures of the functions match the ones declared in `cups/cups.h`
+        unsafe { library.get(name) }.map_err(|err| PrintError::Failed(err.to_string()))
+    }
+
+    fn c_string(text: &str) -> Result<CString, PrintError> {
+        CString::new(text).map_err(|err| PrintError::Failed(err.to_string()))
+    }
+
+    /// Error of the last call to libcups in this thread.
+    fn last_error(library: &Library) -> PrintError {
+        let message = function::<CupsLastErrorString>(library, b"cupsLastErrorString")
+            .ok()
+            // SAFETY: libcups returns a valid string owned by the thread
+            .map(|last_error_string| unsafe { CStr::from_ptr(last_error_string()) })
+            .map(|message| message.to_string_lossy().to_string())
+            .unwrap_or_default();
+        PrintError::Failed(message)
+    }
+
+    pub fn list_printers() -> Vec<Printer> {
+        load_library()
+            .and_then(|library| printers(&library))
+            .unwrap_or_default()
+    }
+
+    fn printers(library: &Library) -> Result<Vec<Printer>, PrintError> {
+        let get_dests = function::<CupsGetDests>(library, b"cupsGetDests")?;
+        let free_dests = function::<CupsFreeDests>(library, b"cupsFreeDests")?;
+
+        let mut dests = ptr::null_mut();
+        // SAFETY: libcups allocates the destinations and they are freed with `cupsFreeDests`
+        unsafe {
+            let count = get_dests(&mut dests);
+            if dests.is_null() {
+                return Ok(Vec::new());
+            }
+            let printers = std::slice::from_raw_parts(dests, count.max(0) as usize)
+                .iter()
+                // Instances are presets of the options of a printer
+                .filter(|dest| dest.instance.is_null() && !dest.name.is_null())
+                .map(|dest| Printer {
+                    name: CStr::from_ptr(dest.name).to_string_lossy().to_string(),
+                    is_default: dest.is_default != 0,
+                })
+                .collect();
+            free_dests(count, dests);
+            Ok(printers)
+        }
+    }
+
+    /// Send the PDF as a job to CUPS, which converts it to whatever the printer understands.
+    pub fn send_to_printer(
+        document: &PrintDocument,
+        options: &PrintOptions,
+    ) -> Result<(), PrintError> {
+        let library = load_library()?;
+        let printer = match &options.printer {
+            Some(printer) => printer.clone(),
+            None => printers(&library)?
+                .into_iter()
+                .find(|printer| printer.is_default)
+                .map(|printer| printer.name)
+                .ok_or_else(no_default_printer)?,
+        };
+
+        let add_option = function::<CupsAddOption>(&library, b"cupsAddOption")?;
+        let free_options = function::<CupsFreeOptions>(&library, b"cupsFreeOptions")?;
+        let create_job = function::<CupsCreateJob>(&library, b"cupsCreateJob")?;
+        let start_document = function::<CupsStartDocument>(&library, b"cupsStartDocument")?;
+        let write_request_data =
+            function::<CupsWriteRequestData>(&library, b"cupsWriteRequestData")?;
+        let finish_document = function::<CupsFinishDocument>(&library, b"cupsFinishDocument")?;
+        let cancel_job = function::<CupsCancelJob>(&library, b"cupsCancelJob")?;
+
+        let printer = c_string(&printer)?;
+        let title = c_string(&options.job_name)?;
+        let copies = c_string(&options.copies.to_string())?;
+
+        // SAFETY: The strings outlive the calls and the options are freed with `cupsFreeOptions`
+        unsafe {
+            let mut job_options = ptr::null_mut();
+            let num_options = add_option(c"copies".as_ptr(), copies.as_ptr(), 0, &mut job_options);
+            let job_id = create_job(
+                CUPS_HTTP_DEFAULT,
+                printer.as_ptr(),
+                title.as_ptr(),
+                num_options,
+                job_options,
+            );
+            free_options(num_options, job_options);
+            if job_id == 0 {
+                return Err(last_error(&library));
+            }
+
+            let is_sent = start_document(
+                CUPS_HTTP_DEFAULT,
+                printer.as_ptr(),
+                job_id,
+                title.as_ptr(),
+                CUPS_FORMAT_PDF.as_ptr(),
+                1,
+            ) == HTTP_STATUS_CONTINUE
+                && write_request_data(
+                    CUPS_HTTP_DEFAULT,
+                    document.data.as_ptr().cast(),
+                    document.data.len(),
+                ) == HTTP_STATUS_CONTINUE;
+            let status = finish_document(CUPS_HTTP_DEFAULT, printer.as_ptr());
+
+            if !is_sent || status >= IPP_STATUS_REDIRECTION_OTHER_SITE {
+                let err = last_error(&library);
+                cancel_job(printer.as_ptr(), job_id);
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Windows prints through GDI, so the pages are rasterized and drawn into the device context of the printer.
+#[cfg(target_os = "windows")]
+mod platform {
+    use std::{
+        mem,
+        slice,
+    };
+
+    use freya_core::{
+        printing::{
+            PrintError,
+            PrintOptions,
+            Printer,
+            PRINT_DPI,
+        },
+        render::PrintDocument,
+    };
+    use freya_engine::prelude::{
+        raster_n32_premul,
+        AlphaType,
+        Color,
+        ColorType,
+        ImageInfo,
+        Picture,
+    };
+    use windows::{
+        core::{
+            w,
+            Error,
+            HSTRING,
+            PCWSTR,
+            PWSTR,
+        },
+        Win32::{
+            Graphics::{
+                Gdi::{
+                    CreateDCW,
+                    DeleteDC,
+                    GetDeviceCaps,
+                    StretchDIBits,
+                    BITMAPINFO,
+                    BITMAPINFOHEADER,
+                    BI_RGB,
+                    DIB_RGB_COLORS,
+                    HDC,
+                    LOGPIXELSX,
+                    PHYSICALHEIGHT,
+                    PHYSICALOFFSETX,
+                    PHYSICALOFFSETY,
+                    PHYSICALWIDTH,
+                    SRCCOPY,
+                },
+                Printing::{
+                    EnumPrintersW,
+                    GetDefaultPrinterW,
+                    PRINTER_ENUM_CONNECTIONS,
+                    PRINTER_ENUM_LOCAL,
+                    PRINTER_INFO_4W,
+                },
+            },
+            Storage::Xps::{
+                AbortDoc,
+                EndDoc,
+                EndPage,
+                StartDocW,
+                StartPage,
+                DOCINFOW,
+            },
+        },
+    };
+
+    use super::no_default_printer;
+
+    /// Error of the last call to the Windows API in this thread.
+    fn last_error() -> PrintError {
+        PrintError::Failed(Error::from_win32().to_string())
+    }
+
+    fn default_printer() -> Option<String> {
+        let mut len = 0;
+        // SAFETY: The first call only asks for the length of the name, including the null terminator
+        unsafe { GetDefaultPrinterW(PWSTR::null(), &mut len) };
+        if len == 0 {
+            return None;
+        }
+        let mut name = vec![0u16; len as usize];
+        // SAFETY: The buffer has the length asked by the previous call
+        unsafe { GetDefaultPrinterW(PWSTR(name.as_mut_ptr()), &mut len) }
+            .as_bool()
+            .then(|| String::from_utf16_lossy(&name[..len.saturating_sub(1) as usize]))
+    }
+
+    pub fn list_printers() -> Vec<Printer> {
+        let flags = PRINTER_ENUM_LOCAL | PRINTER_ENUM_CONNECTIONS;
+        let (mut needed, mut returned) = (0, 0);
+        // SAFETY: The first call only asks for the size of the buffer
+        unsafe { EnumPrintersW(flags, PCWSTR::null(), 4, None, &mut needed, &mut returned) }.ok();
+        if needed == 0 {
+            return Vec::new();
+        }
+
+        // Aligned for the structs written at the start of the buffer
+        let mut buffer = vec![0u64; (needed as usize).div_ceil(mem::size_of::<u64>())];
+        // SAFETY: The buffer is at least `needed` bytes long
+        let bytes =
+            unsafe { slice::from_raw_parts_mut(buffer.as_mut_ptr().cast::<u8>(), needed as usize) };
+        // SAFETY: Level 4 fills the buffer with `PRINTER_INFO_4W` structs
+        let infos = unsafe {
+            if EnumPrintersW(
+                flags,
+                PCWSTR::null(),
+                4,
+                Some(bytes),
+                &mut needed,
+                &mut returned,
+            )
+            .is_err()
+            {
+                return Vec::new();
+            }
+            slice::from_raw_parts(buffer.as_ptr().cast::<PRINTER_INFO_4W>(), returned as usize)
+        };
+
+        let default = default_printer();
+        infos
+            .iter()
+            // SAFETY: The names point to null terminated strings inside the buffer
+            .filter_map(|info| unsafe { info.pPrinterName.to_string() }.ok())
+            .map(|name| Printer {
+                is_default: default.as_deref() == Some(name.as_str()),
+                name,
+            })
+            .collect()
+    }
+
+    pub fn send_to_printer(
+        document: &PrintDocument,
+        options: &PrintOptions,
+    ) -> Result<(), PrintError> {
+        let printer = match &options.printer {
+            Some(printer) => printer.clone(),
+            None => default_printer().ok_or_else(no_default_printer)?,
+        };
+        let printer = HSTRING::from(printer);
+        let job_name = HSTRING::from(options.job_name.as_str());
+        let doc_info = DOCINFOW {
+            cbSize: mem::size_of::<DOCINFOW>() as i32,
+            lpszDocName: PCWSTR(job_name.as_ptr()),
+            ..Default::default()
+        };
+
+        // SAFETY: The device context is deleted once the job is submitted
+        unsafe {
+            let hdc = CreateDCW(w!("WINSPOOL"), &printer, PCWSTR::null(), None);
+            if hdc.is_invalid() {
+                return Err(last_error());
+            }
+
+            let result = if StartDocW(hdc, &doc_info) > 0 {
+                let result = (0..options.copies)
+                    .flat_map(|_| &document.page_pictures)
+                    .try_for_each(|picture| print_page(hdc, picture, options));
+                if result.is_ok() {
+                    EndDoc(hdc);
+                } else {
+                    AbortDoc(hdc);
+                }
+                result
+            } else {
+                Err(last_error())
+            };
+
+            let _ = DeleteDC(hdc);
+            result
+        }
+    }
+
+    /// Rasterize the page with at most [PRINT_DPI] and stretch it over the whole paper.
+    ///
+    /// # Safety
+    ///
+    /// `hdc` must be the device context of a printer with a started document.
+    unsafe fn print_page(
+        hdc: HDC,
+        picture: &Picture,
+        options: &PrintOptions,
+    ) -> Result<(), PrintError> {
+        let page_size = options.page_size();
+        let dpi = GetDeviceCaps(hdc, LOGPIXELSX) as f32;
+        let scale = PRINT_DPI.min(dpi) / 72.;
+        let size = (
+            (page_size.width * scale).ceil() as i32,
+            (page_size.height * scale).ceil() as i32,
+        );
+
+        let mut surface = raster_n32_premul(size)
+            .ok_or_else(|| PrintError::Failed("Failed to create the page surface".to_string()))?;
+        let canvas = surface.canvas();
+        canvas.clear(Color::WHITE);
+        canvas.scale((scale, scale));
+        canvas.draw_picture(picture, None, None);
+
+        // GDI expects BGRA pixels
+        let info = ImageInfo::new(size, ColorType::BGRA8888, AlphaType::Premul, None);
+        let row_bytes = size.0 as usize * 4;
+        let mut pixels = vec![0; row_bytes * size.1 as usize];
+        if !surface.read_pixels(&info, &mut pixels, row_bytes, (0, 0)) {
+            return Err(PrintError::Failed("Failed to read the page".to_string()));
+        }
+
+        let bitmap_info = BITMAPINFO {
+            bmiHeader: BITMAPINFOHEADER {
+                biSize: mem::size_of::<BITMAPINFOHEADER>() as u32,
+                biWidth: size.0,
+                // Negative for rows from top to bottom
+                biHeight: -size.1,
+                biPlanes: 1,
+                biBitCount: 32,
+                biCompression: BI_RGB.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        if StartPage(hdc) <= 0 {
+            return Err(last_error());
+        }
+        // The origin of the device is the printable area, which starts after the unprintable edges of the paper
+        let copied = StretchDIBits(
+            hdc,
+            -GetDeviceCaps(hdc, PHYSICALOFFSETX),
+            -GetDeviceCaps(hdc, PHYSICALOFFSETY),
+            GetDeviceCaps(hdc, PHYSICALWIDTH),
+            GetDeviceCaps(hdc, PHYSICALHEIGHT),
+            0,
+            0,
+            size.0,
+            size.1,
+            Some(pixels.as_ptr().cast()),
+            &bitmap_info,
+            DIB_RGB_COLORS,
+            SRCCOPY,
+        );
+        if EndPage(hdc) <= 0 || copied == 0 {
+            return Err(last_error());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+mod platform {
+    use freya_core::{
+        printing::{
+            PrintError,
+            PrintOptions,
+            Printer,
+        },
+        render::PrintDocument,
+    };
+
+    pub fn list_printers() -> Vec<Printer> {
+        Vec::new()
+    }
+
+    pub fn send_to_printer(
+        _document: &PrintDocument,
+        _options: &PrintOptions,
+    ) -> Result<(), PrintError> {
+        Err(PrintError::Unsupported)
+    }
+}
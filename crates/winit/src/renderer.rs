@@ -1,52 +1,70 @@
-use std::path::PathBuf;
+use std::time::Instant;
 
 use dioxus_core::VirtualDom;
 use freya_core::{
     accessibility::AccessibilityFocusStrategy,
     dom::SafeDOM,
     event_loop_messages::EventLoopMessage,
-    events::{
-        EventName,
-        PlatformEvent,
-        PlatformEventData,
+    events::PlatformEvent,
+    native_tray::NativeTrayEvent,
+    open_requests::OpenRequest,
+    platform_state::{
+        FramePhase,
+        NativePlatformState,
+        NavigationMode,
     },
-    platform_state::NavigationMode,
+    plugins::{
+        PluginEvent,
+        PluginHandle,
+    },
+    profile_span,
+    render::capture_surface_png,
 };
+#[cfg(debug_assertions)]
 use freya_elements::events::{
-    Code,
+    keyboard::Modifiers,
     Key,
 };
-use torin::geometry::CursorPoint;
 use winit::{
     application::ApplicationHandler,
     event::{
         ElementState,
-        Ime,
         KeyEvent,
         MouseButton,
-        MouseScrollDelta,
         StartCause,
         Touch,
-        TouchPhase,
         WindowEvent,
     },
     event_loop::{
+        ControlFlow,
         EventLoop,
         EventLoopProxy,
     },
-    keyboard::ModifiersState,
 };
 
 use crate::{
+    accent_color::request_accent_color,
     devtools::{
         Devtools,
         HoveredNode,
     },
+    input::InputState,
     keyboard::{
         map_winit_key,
         map_winit_modifiers,
-        map_winit_physical_key,
     },
+    open_requests::{
+        register_url_schemes,
+        Acquired,
+        SingleInstance,
+    },
+    opener::request_open,
+    printing::{
+        request_print,
+        request_printers,
+    },
+    taskbar::set_taskbar_progress,
+    window_effect::set_window_effect,
     window_state::{
         CreatedState,
         NotCreatedState,
@@ -55,19 +73,14 @@ use crate::{
     LaunchConfig,
 };
 
-const WHEEL_SPEED_MODIFIER: f64 = 53.0;
-const TOUCHPAD_SPEED_MODIFIER: f64 = 2.0;
-
 /// Window renderer using Skia, Glutin and Winit.
 pub struct WinitRenderer<'a, State: Clone + 'static> {
     pub(crate) event_loop_proxy: EventLoopProxy<EventLoopMessage>,
     pub(crate) state: WindowState<'a, State>,
     pub(crate) hovered_node: HoveredNode,
-    pub(crate) cursor_pos: CursorPoint,
-    pub(crate) mouse_state: ElementState,
-    pub(crate) modifiers_state: ModifiersState,
-    pub(crate) dropped_file_path: Option<PathBuf>,
+    pub(crate) input: InputState,
     pub(crate) custom_scale_factor: f64,
+    pub(crate) single_instance: Option<SingleInstance>,
 }
 
 impl<'a, State: Clone + 'static> WinitRenderer<'a, State> {
@@ -80,6 +93,17 @@ impl<'a, State: Clone + 'static> WinitRenderer<'a, State> {
         hovered_node: HoveredNode,
     ) {
         let mut event_loop_builder = EventLoop::<EventLoopMessage>::with_user_event();
+        register_url_schemes(&config.window_config.url_schemes);
+        let single_instance = match &config.window_config.single_instance {
+            Some(app_id) => match SingleInstance::acquire(app_id) {
+                Acquired::Running(single_instance) => Some(single_instance),
+                // The arguments were sent to the running instance
+                Acquired::Forwarded => return,
+                Acquired::Unavailable => None,
+            },
+            None => None,
+        };
+
         let event_loop_builder_hook = config.window_config.event_loop_builder_hook.take();
         if let Some(event_loop_builder_hook) = event_loop_builder_hook {
             event_loop_builder_hook(&mut event_loop_builder);
@@ -89,8 +113,23 @@ impl<'a, State: Clone + 'static> WinitRenderer<'a, State> {
             .expect("Failed to create event loop.");
         let proxy = event_loop.create_proxy();
 
+        if let Some(single_instance) = &single_instance {
+            let proxy = proxy.clone();
+            let schemes = config.window_config.url_schemes.clone();
+            single_instance.listen(move |args| {
+                let open_requests = OpenRequest::from_args(args.clone(), &schemes);
+                proxy
+                    .send_event(EventLoopMessage::SecondInstance {
+                        args,
+                        open_requests,
+                    })
+                    .ok();
+            });
+        }
+
         let mut winit_renderer =
             WinitRenderer::new(vdom, sdom, config, devtools, hovered_node, proxy);
+        winit_renderer.single_instance = single_instance;
 
         event_loop.run_app(&mut winit_renderer).unwrap();
     }
@@ -112,11 +151,9 @@ impl<'a, State: Clone + 'static> WinitRenderer<'a, State> {
             }),
             hovered_node,
             event_loop_proxy: proxy,
-            cursor_pos: CursorPoint::default(),
-            mouse_state: ElementState::Released,
-            modifiers_state: ModifiersState::default(),
-            dropped_file_path: None,
+            input: InputState::default(),
             custom_scale_factor: 0.,
+            single_instance: None,
         }
     }
 
@@ -129,6 +166,44 @@ impl<'a, State: Clone + 'static> WinitRenderer<'a, State> {
             .send_event(event, scale_factor);
     }
 
+    /// Translate and send an input event of the window, other events are ignored.
+    fn send_input_event(&mut self, event: &WindowEvent) {
+        let Some(events) = self.input.translate(event) else {
+            return;
+        };
+
+        let scale_factor = self.scale_factor();
+        let CreatedState {
+            app,
+            window,
+            window_config,
+            ..
+        } = self.state.created_state();
+
+        if matches!(event, WindowEvent::MouseInput { .. }) {
+            app.set_navigation_mode(NavigationMode::NotKeyboard);
+        }
+
+        for event in events {
+            app.send_event(event, scale_factor);
+        }
+
+        if let WindowEvent::Touch(Touch { phase, .. }) = event {
+            if window_config.touch_as_mouse {
+                for event in self.input.touch_as_mouse(*phase) {
+                    app.send_event(event, scale_factor);
+                }
+            }
+        }
+
+        if matches!(
+            event,
+            WindowEvent::CursorMoved { .. } | WindowEvent::CursorLeft { .. }
+        ) {
+            app.update_cursor_icon(window, self.input.cursor_pos, scale_factor);
+        }
+    }
+
     /// Get the current scale factor of the Window
     fn scale_factor(&self) -> f64 {
         match &self.state {
@@ -161,18 +236,74 @@ impl<'a, State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'a
         if !self.state.has_been_created() {
             self.state.create(event_loop, &self.event_loop_proxy);
             self.run_on_setup();
+        } else {
+            let CreatedState {
+                surface,
+                dirty_surface,
+                window,
+                window_config,
+                app,
+                graphics_driver,
+                is_suspended,
+                ..
+            } = self.state.created_state();
+
+            // The platform (e.g Android) destroys the window surface when the app goes to the background
+            if *is_suspended {
+                *is_suspended = false;
+                app.platform_sender.send_modify(|state| {
+                    state.is_suspended = false;
+                });
+
+                let (new_surface, new_dirty_surface) =
+                    graphics_driver.resume(window, window_config.frame_pacing.vsync);
+
+                *surface = new_surface;
+                *dirty_surface = new_dirty_surface;
+
+                app.resize(window);
+                window.request_redraw();
+            }
+        }
+    }
+
+    fn suspended(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {
+        if let WindowState::Created(CreatedState {
+            graphics_driver,
+            is_suspended,
+            app,
+            ..
+        }) = &mut self.state
+        {
+            *is_suspended = true;
+            app.platform_sender.send_modify(|state| {
+                state.is_suspended = true;
+            });
+            graphics_driver.suspend();
         }
     }
 
     fn new_events(
         &mut self,
-        _event_loop: &winit::event_loop::ActiveEventLoop,
+        event_loop: &winit::event_loop::ActiveEventLoop,
         cause: winit::event::StartCause,
     ) {
-        if cause == StartCause::Init {
-            self.event_loop_proxy
-                .send_event(EventLoopMessage::PollVDOM)
-                .ok();
+        match cause {
+            StartCause::Init => {
+                self.event_loop_proxy
+                    .send_event(EventLoopMessage::PollVDOM)
+                    .ok();
+            }
+            StartCause::ResumeTimeReached { .. } => {
+                // Render the frame that was deferred because of the FPS limit
+                if let WindowState::Created(CreatedState { app, window, .. }) = &mut self.state {
+                    if app.scheduled_frame.take().is_some() {
+                        event_loop.set_control_flow(ControlFlow::Wait);
+                        window.request_redraw();
+                    }
+                }
+            }
+            _ => {}
         }
     }
 
@@ -182,7 +313,18 @@ impl<'a, State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'a
         event: EventLoopMessage,
     ) {
         let scale_factor = self.scale_factor();
-        let CreatedState { window, app, .. } = self.state.created_state();
+        let CreatedState {
+            window,
+            window_config,
+            app,
+            dirty_surface,
+            graphics_driver,
+            native_menu,
+            native_tray,
+            global_shortcuts,
+            webviews,
+            ..
+        } = self.state.created_state();
         match event {
             EventLoopMessage::FocusAccessibilityNode(strategy) => {
                 app.request_focus_node(strategy);
@@ -216,12 +358,194 @@ impl<'a, State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'a
                 app.init_accessibility_on_next_render = true;
             }
             EventLoopMessage::SetCursorIcon(icon) => window.set_cursor(icon),
+            EventLoopMessage::SetCustomCursor(cursor_image) => {
+                app.set_custom_cursor(cursor_image, window, event_loop)
+            }
             EventLoopMessage::WithWindow(use_window) => (use_window)(window),
             EventLoopMessage::ExitApp => event_loop.exit(),
+            EventLoopMessage::CloseWindow => {
+                if native_tray.hide_on_close() {
+                    window.set_visible(false);
+                } else {
+                    event_loop.exit()
+                }
+            }
+            EventLoopMessage::SecondInstance {
+                args,
+                open_requests,
+            } => {
+                window.set_visible(true);
+                window.set_minimized(false);
+                window.focus_window();
+                app.second_instance_sender.send(args).ok();
+                for request in open_requests {
+                    app.open_request_sender.send(request).ok();
+                }
+            }
             EventLoopMessage::PlatformEvent(platform_event) => self.send_event(platform_event),
+            EventLoopMessage::PluginMessage(message) => {
+                app.plugins.send(
+                    PluginEvent::Message(&*message),
+                    PluginHandle::new(&app.proxy),
+                );
+            }
             EventLoopMessage::PollVDOM => {
                 app.poll_vdom(window);
             }
+            EventLoopMessage::SnapshotNode {
+                reference,
+                scale,
+                sender,
+            } => {
+                sender
+                    .send(app.snapshot_node(&reference, scale, scale_factor))
+                    .ok();
+            }
+            EventLoopMessage::CaptureWindow(sender) => {
+                // The window surface is undefined once presented, but the dirty surface keeps the whole frame
+                sender.send(capture_surface_png(dirty_surface)).ok();
+            }
+            EventLoopMessage::Export {
+                target,
+                format,
+                sender,
+            } => {
+                sender.send(app.export(&target, format, scale_factor)).ok();
+            }
+            EventLoopMessage::GetPrinters(sender) => {
+                request_printers(sender);
+            }
+            EventLoopMessage::Print {
+                target,
+                options,
+                sender,
+            } => match app.print(&target, &options, scale_factor) {
+                Ok(document) => request_print(document, options, sender),
+                Err(err) => {
+                    sender.send(Err(err)).ok();
+                }
+            },
+            EventLoopMessage::Open { target, sender } => request_open(target, sender),
+            EventLoopMessage::SetFrameCapture(frame_capture) => {
+                app.frame_capture = frame_capture;
+                window.request_redraw();
+            }
+            EventLoopMessage::SetFramePacing(frame_pacing) => {
+                if frame_pacing.vsync != window_config.frame_pacing.vsync {
+                    graphics_driver.set_vsync(frame_pacing.vsync);
+                }
+                window_config.frame_pacing = frame_pacing;
+                window.request_redraw();
+            }
+            EventLoopMessage::SetBackgroundMaxFps(background_max_fps) => {
+                window_config.frame_pacing.background_max_fps = background_max_fps;
+                window.request_redraw();
+            }
+            EventLoopMessage::GetFrameStats(sender) => {
+                sender.send(app.frame_stats.stats()).ok();
+            }
+            EventLoopMessage::SetAttributeOverride {
+                node_id,
+                attribute,
+                value,
+            } => {
+                app.set_attribute_override(node_id, attribute, value, window);
+            }
+            EventLoopMessage::ClearAttributeOverrides => {
+                app.clear_attribute_overrides(window);
+            }
+            EventLoopMessage::SetDebugLayout(debug_layout) => {
+                window_config.debug_layout = debug_layout;
+                app.resize(window);
+                window.request_redraw();
+            }
+            EventLoopMessage::AccentColorChanged(accent_color) => {
+                app.platform_sender.send_if_modified(|state| {
+                    let changed = state.accent_color != accent_color;
+                    state.accent_color = accent_color;
+                    changed
+                });
+            }
+            EventLoopMessage::SetNativeMenu(menu) => native_menu.set_menu(menu, window),
+            EventLoopMessage::SetNativeMenuItemChecked(id, checked) => {
+                native_menu.set_checked(&id, checked);
+                native_tray.set_checked(&id, checked);
+            }
+            EventLoopMessage::SetNativeMenuItemEnabled(id, enabled) => {
+                native_menu.set_enabled(&id, enabled);
+                native_tray.set_enabled(&id, enabled);
+            }
+            EventLoopMessage::NativeMenuItemActivated(id) => {
+                if !native_menu.activate(&id, &app.native_menu_sender) {
+                    native_tray.activate(&id, &app.native_tray_sender);
+                }
+            }
+            EventLoopMessage::SetNativeTrayIcon(icon) => native_tray.set_icon(icon),
+            EventLoopMessage::SetNativeTrayTooltip(tooltip) => native_tray.set_tooltip(tooltip),
+            EventLoopMessage::SetNativeTrayMenu(menu) => native_tray.set_menu(menu),
+            EventLoopMessage::NativeTrayEvent(tray_event) => {
+                // Bring back the window that was hidden to the tray
+                if native_tray.hide_on_close()
+                    && tray_event == NativeTrayEvent::Click(MouseButton::Left)
+                    && window.is_visible() == Some(false)
+                {
+                    window.set_visible(true);
+                    window.focus_window();
+                }
+                app.native_tray_sender.send(tray_event).ok();
+            }
+            EventLoopMessage::RegisterGlobalShortcut(shortcut, sender) => {
+                sender.send(global_shortcuts.register(shortcut)).ok();
+            }
+            EventLoopMessage::UnregisterGlobalShortcut(id) => global_shortcuts.unregister(&id),
+            EventLoopMessage::GlobalShortcutTriggered(native_id, state) => {
+                global_shortcuts.trigger(native_id, state, &app.global_shortcut_sender)
+            }
+            EventLoopMessage::SetTaskbarProgress(progress) => {
+                set_taskbar_progress(window, progress)
+            }
+            EventLoopMessage::SetWindowEffect(effect) => set_window_effect(window, effect),
+            EventLoopMessage::SetSoftKeyboardVisible(visible) => {
+                // Only touch platforms show the keyboard along with the IME,
+                // disabling it anywhere else would break the input of text
+                #[cfg(any(target_os = "android", target_os = "ios"))]
+                window.set_ime_allowed(visible);
+                // Android reports the area covered by the keyboard instead, see `about_to_wait`
+                #[cfg(target_os = "ios")]
+                {
+                    let soft_keyboard = app.platform_receiver.borrow().soft_keyboard;
+                    app.set_soft_keyboard(freya_core::platform_state::SoftKeyboard {
+                        visible,
+                        ..soft_keyboard
+                    });
+                }
+                #[cfg(not(any(target_os = "android", target_os = "ios")))]
+                let _ = visible;
+            }
+            EventLoopMessage::SoftKeyboardChanged(soft_keyboard) => {
+                app.set_soft_keyboard(soft_keyboard);
+            }
+            EventLoopMessage::RegisterFont(source, alias, sender) => {
+                sender.send(app.register_font(source, alias, window)).ok();
+            }
+            EventLoopMessage::SetFontFallbacks(fallbacks) => {
+                app.set_font_fallbacks(fallbacks, window);
+            }
+            EventLoopMessage::GetFontFamilies(sender) => {
+                sender.send(app.fonts.families()).ok();
+            }
+            EventLoopMessage::CreateWebView {
+                id,
+                config,
+                area,
+                sender,
+            } => webviews.create(window, id, config, area, sender),
+            EventLoopMessage::SetWebViewArea(id, area) => webviews.set_area(id, area),
+            EventLoopMessage::SetWebViewContent(id, content) => webviews.set_content(id, content),
+            EventLoopMessage::EvaluateWebViewScript(id, script) => {
+                webviews.evaluate_script(id, script)
+            }
+            EventLoopMessage::RemoveWebView(id) => webviews.remove(id),
             _ => {}
         }
     }
@@ -240,29 +564,68 @@ impl<'a, State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'a
             window_config,
             app,
             is_window_focused,
+            is_suspended,
             graphics_driver,
+            native_menu,
+            native_tray,
+            window_geometry,
             ..
         } = self.state.created_state();
         app.accessibility
             .process_accessibility_event(&event, window);
+        if matches!(
+            event,
+            WindowEvent::CursorMoved { .. }
+                | WindowEvent::MouseInput { .. }
+                | WindowEvent::MouseWheel { .. }
+                | WindowEvent::KeyboardInput { .. }
+                | WindowEvent::Touch(_)
+        ) {
+            app.platform_sender
+                .send_if_modified(NativePlatformState::notify_user_input);
+        }
         match event {
             WindowEvent::ThemeChanged(theme) => {
                 app.platform_sender.send_modify(|state| {
                     state.preferred_theme = theme.into();
                 });
+                // The accent color usually changes along with the theme
+                request_accent_color(&app.proxy);
             }
-            WindowEvent::CloseRequested => event_loop.exit(),
-            WindowEvent::Ime(Ime::Commit(text)) => {
-                self.send_event(PlatformEvent {
-                    name: EventName::KeyDown,
-                    data: PlatformEventData::Keyboard {
-                        key: Key::Character(text),
-                        code: Code::Unidentified,
-                        modifiers: map_winit_modifiers(self.modifiers_state),
-                    },
-                });
+            WindowEvent::CloseRequested => {
+                if app.close_request_sender.receiver_count() > 0 {
+                    // Let the app decide, it closes the window later with `EventLoopMessage::CloseWindow`
+                    app.close_request_sender.send(()).ok();
+                } else if native_tray.hide_on_close() {
+                    window.set_visible(false);
+                } else {
+                    event_loop.exit()
+                }
             }
             WindowEvent::RedrawRequested => {
+                // There is no surface to render into while in the background
+                if *is_suspended {
+                    return;
+                }
+
+                let is_minimized = window.is_minimized().unwrap_or_default();
+                let is_background = !*is_window_focused || is_minimized;
+                // Animations wait for the ticks, so they stop requesting frames while nobody can see them
+                let skip_ticks =
+                    is_minimized && window_config.frame_pacing.background_max_fps.is_some();
+
+                // Defer the frame if it comes too early for the FPS limit
+                if let Some(deadline) =
+                    app.frame_deadline(&window_config.frame_pacing, is_background)
+                {
+                    app.scheduled_frame = Some(deadline);
+                    event_loop.set_control_flow(ControlFlow::WaitUntil(deadline));
+                    return;
+                }
+
+                let frame_start = Instant::now();
+                let _frame_span = profile_span!("frame");
+
                 app.platform_sender.send_if_modified(|state| {
                     let scale_factor_is_different = state.scale_factor == scale_factor;
                     state.scale_factor = scale_factor;
@@ -273,10 +636,13 @@ impl<'a, State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'a
                     app.process_layout(window.inner_size(), scale_factor);
 
                     app.process_layout_on_next_render = false;
+
+                    // The hovered nodes might have changed
+                    app.update_cursor_icon(window, self.input.cursor_pos, scale_factor);
                 }
 
                 if app.process_accessibility_on_next_render {
-                    app.process_accessibility(window);
+                    app.process_accessibility();
                 }
 
                 if app.init_accessibility_on_next_render {
@@ -284,76 +650,53 @@ impl<'a, State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'a
                     app.init_accessibility_on_next_render = false;
                 }
 
+                app.update_ime_cursor_area(window, scale_factor);
+
+                // Nothing changed so there is no need to present a new frame
+                if window_config.frame_pacing.render_on_change && !app.has_pending_changes() {
+                    app.frame_stats.record_skipped();
+                    if !skip_ticks {
+                        app.event_loop_tick();
+                    }
+                    return;
+                }
+
                 graphics_driver.make_current();
 
-                app.render(
+                let damage = app.render(
                     &self.hovered_node,
                     window_config.background,
                     surface,
                     dirty_surface,
                     window,
                     scale_factor,
+                    graphics_driver.buffer_age(),
+                    window_config.debug_layout,
                 );
 
-                app.event_loop_tick();
+                if !skip_ticks {
+                    app.event_loop_tick();
+                }
                 window.pre_present_notify();
-                graphics_driver.flush_and_submit();
-            }
-            WindowEvent::MouseInput { state, button, .. } => {
-                app.set_navigation_mode(NavigationMode::NotKeyboard);
-
-                self.mouse_state = state;
-
-                let name = match state {
-                    ElementState::Pressed => EventName::MouseDown,
-                    ElementState::Released => match button {
-                        MouseButton::Middle => EventName::MiddleClick,
-                        MouseButton::Right => EventName::RightClick,
-                        MouseButton::Left => EventName::MouseUp,
-                        _ => EventName::PointerUp,
-                    },
-                };
-
-                self.send_event(PlatformEvent {
-                    name,
-                    data: PlatformEventData::Mouse {
-                        cursor: self.cursor_pos,
-                        button: Some(button),
-                    },
-                });
-            }
-            WindowEvent::MouseWheel { delta, phase, .. } => {
-                if TouchPhase::Moved == phase {
-                    let scroll_data = {
-                        match delta {
-                            MouseScrollDelta::LineDelta(x, y) => (
-                                (x as f64 * WHEEL_SPEED_MODIFIER),
-                                (y as f64 * WHEEL_SPEED_MODIFIER),
-                            ),
-                            MouseScrollDelta::PixelDelta(pos) => (
-                                (pos.x * TOUCHPAD_SPEED_MODIFIER),
-                                (pos.y * TOUCHPAD_SPEED_MODIFIER),
-                            ),
-                        }
-                    };
-
-                    self.send_event(PlatformEvent {
-                        name: EventName::Wheel,
-                        data: PlatformEventData::Wheel {
-                            scroll: CursorPoint::from(scroll_data),
-                            cursor: self.cursor_pos,
-                        },
-                    });
+                let present_start = Instant::now();
+                {
+                    let _span = profile_span!("present");
+                    graphics_driver.flush_and_submit(damage);
                 }
-            }
-            WindowEvent::ModifiersChanged(modifiers) => {
-                self.modifiers_state = modifiers.state();
+                app.frame_stats
+                    .record_phase(FramePhase::Present, present_start.elapsed());
+
+                app.last_frame_at = Some(frame_start);
+                app.frame_stats.record_rendered(frame_start);
+                app.plugins.send(
+                    PluginEvent::FramePresented(&app.frame_stats.stats()),
+                    PluginHandle::new(&app.proxy),
+                );
             }
             WindowEvent::KeyboardInput {
                 event:
                     KeyEvent {
-                        physical_key,
-                        logical_key,
+                        ref logical_key,
                         state,
                         ..
                     },
@@ -367,9 +710,9 @@ impl<'a, State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'a
                 {
                     let is_control_pressed = {
                         if cfg!(target_os = "macos") {
-                            self.modifiers_state.super_key()
+                            self.input.modifiers_state.super_key()
                         } else {
-                            self.modifiers_state.control_key()
+                            self.input.modifiers_state.control_key()
                         }
                     };
 
@@ -394,78 +737,28 @@ impl<'a, State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'a
                     }
                 }
 
-                let name = match state {
-                    ElementState::Pressed => EventName::KeyDown,
-                    ElementState::Released => EventName::KeyUp,
-                };
-                self.send_event(PlatformEvent {
-                    name,
-                    data: PlatformEventData::Keyboard {
-                        key: map_winit_key(&logical_key),
-                        code: map_winit_physical_key(&physical_key),
-                        modifiers: map_winit_modifiers(self.modifiers_state),
-                    },
-                })
-            }
-            WindowEvent::CursorLeft { .. } => {
-                if self.mouse_state == ElementState::Released {
-                    self.cursor_pos = CursorPoint::new(-1.0, -1.0);
-
-                    self.send_event(PlatformEvent {
-                        name: EventName::MouseMove,
-                        data: PlatformEventData::Mouse {
-                            cursor: self.cursor_pos,
-                            button: None,
-                        },
-                    });
-                }
-            }
-            WindowEvent::CursorMoved { position, .. } => {
-                self.cursor_pos = CursorPoint::from((position.x, position.y));
+                let key = map_winit_key(logical_key);
+                let modifiers = map_winit_modifiers(self.input.modifiers_state);
 
-                self.send_event(PlatformEvent {
-                    name: EventName::MouseMove,
-                    data: PlatformEventData::Mouse {
-                        cursor: self.cursor_pos,
-                        button: None,
-                    },
-                });
+                // Toggle the layout debug overlay in debug builds, the app still receives the keys
+                #[cfg(debug_assertions)]
+                if state == ElementState::Pressed
+                    && modifiers.contains(Modifiers::CONTROL | Modifiers::SHIFT)
+                    && matches!(&key, Key::Character(character) if character.eq_ignore_ascii_case("l"))
+                {
+                    window_config.debug_layout = !window_config.debug_layout;
+                    app.resize(window);
+                    window.request_redraw();
+                }
 
-                if let Some(dropped_file_path) = self.dropped_file_path.take() {
-                    self.send_event(PlatformEvent {
-                        name: EventName::FileDrop,
-                        data: PlatformEventData::File {
-                            file_path: Some(dropped_file_path),
-                            cursor: self.cursor_pos,
-                        },
-                    });
+                // Menu accelerators take precedence over the app
+                if state == ElementState::Pressed
+                    && native_menu.handle_shortcut(&key, modifiers, &app.native_menu_sender)
+                {
+                    return;
                 }
-            }
-            WindowEvent::Touch(Touch {
-                location,
-                phase,
-                id,
-                force,
-                ..
-            }) => {
-                self.cursor_pos = CursorPoint::from((location.x, location.y));
-
-                let name = match phase {
-                    TouchPhase::Cancelled => EventName::TouchCancel,
-                    TouchPhase::Ended => EventName::TouchEnd,
-                    TouchPhase::Moved => EventName::TouchMove,
-                    TouchPhase::Started => EventName::TouchStart,
-                };
-
-                self.send_event(PlatformEvent {
-                    name,
-                    data: PlatformEventData::Touch {
-                        location: self.cursor_pos,
-                        finger_id: id,
-                        phase,
-                        force,
-                    },
-                });
+
+                self.send_input_event(&event);
             }
             WindowEvent::Resized(size) => {
                 let (new_surface, new_dirty_surface) = graphics_driver.resize(size);
@@ -476,36 +769,73 @@ impl<'a, State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'a
                 window.request_redraw();
 
                 app.resize(window);
+
+                if let Some(window_geometry) = window_geometry {
+                    window_geometry.update(window);
+                }
             }
-            WindowEvent::DroppedFile(file_path) => {
-                self.dropped_file_path = Some(file_path);
-            }
-            WindowEvent::HoveredFile(file_path) => {
-                self.send_event(PlatformEvent {
-                    name: EventName::GlobalFileHover,
-                    data: PlatformEventData::File {
-                        file_path: Some(file_path),
-                        cursor: self.cursor_pos,
-                    },
-                });
+            WindowEvent::Moved(_) => {
+                // The window might be in a different monitor now
+                app.update_monitors(window);
+
+                if let Some(window_geometry) = window_geometry {
+                    window_geometry.update(window);
+                }
             }
-            WindowEvent::HoveredFileCancelled => {
-                self.send_event(PlatformEvent {
-                    name: EventName::GlobalFileHoverCancelled,
-                    data: PlatformEventData::File {
-                        file_path: None,
-                        cursor: self.cursor_pos,
-                    },
-                });
+            WindowEvent::ScaleFactorChanged { .. } => {
+                app.update_monitors(window);
+
+                // Measure everything again with the new scale factor
+                app.resize(window);
+                window.request_redraw();
             }
             WindowEvent::Focused(is_focused) => {
                 *is_window_focused = is_focused;
+                app.platform_sender.send_modify(|state| {
+                    state.is_window_focused = is_focused;
+                    // Not every platform resizes the window when minimizing it
+                    state.information.is_minimized = window.is_minimized().unwrap_or_default();
+                });
+                // The accent color might have been changed while the window was in the background
+                if is_focused {
+                    request_accent_color(&app.proxy);
+                    // Monitors might have been connected or disconnected too
+                    app.update_monitors(window);
+                }
+            }
+            _ => self.send_input_event(&event),
+        }
+    }
+
+    fn about_to_wait(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        if let WindowState::Created(CreatedState { webviews, .. }) = &mut self.state {
+            webviews.poll(event_loop);
+        }
+
+        // The activity is notified when the keyboard covers the window, which also wakes up the event loop
+        #[cfg(target_os = "android")]
+        if let WindowState::Created(CreatedState { window, app, .. }) = &self.state {
+            let soft_keyboard = crate::soft_keyboard::android_soft_keyboard(event_loop, window);
+            if app.platform_receiver.borrow().soft_keyboard != soft_keyboard {
+                self.event_loop_proxy
+                    .send_event(EventLoopMessage::SoftKeyboardChanged(soft_keyboard))
+                    .ok();
             }
-            _ => {}
         }
     }
 
     fn exiting(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {
+        if let WindowState::Created(CreatedState {
+            window,
+            window_geometry: Some(window_geometry),
+            ..
+        }) = &mut self.state
+        {
+            window_geometry.save(window);
+        }
+        if let Some(single_instance) = self.single_instance.take() {
+            single_instance.release();
+        }
         self.run_on_exit();
     }
 }
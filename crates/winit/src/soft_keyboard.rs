@@ -0,0 +1,82 @@
+use freya_core::platform_state::SoftKeyboard;
+#[cfg(target_os = "android")]
+use winit::{
+    event_loop::ActiveEventLoop,
+    platform::android::ActiveEventLoopExtAndroid,
+    window::Window,
+};
+
+/// Read the state of the on-screen keyboard from the content rect of the activity,
+/// which Android shrinks to the area of the window that the keyboard doesn't cover.
+#[cfg(target_os = "android")]
+pub fn android_soft_keyboard(event_loop: &ActiveEventLoop, window: &Window) -> SoftKeyboard {
+    let content_rect = event_loop.android_app().content_rect();
+    soft_keyboard_from_content(
+        window.inner_size().height as i32,
+        content_rect.top,
+        content_rect.bottom,
+        window.scale_factor(),
+    )
+}
+
+/// State of the on-screen keyboard given the physical height of the window and the
+/// vertical bounds of the area it leaves uncovered.
+pub(crate) fn soft_keyboard_from_content(
+    window_height: i32,
+    content_top: i32,
+    content_bottom: i32,
+    scale_factor: f64,
+) -> SoftKeyboard {
+    // Empty until the activity reports it
+    if content_bottom <= content_top {
+        return SoftKeyboard::default();
+    }
+
+    let covered = (window_height - content_bottom).max(0);
+    let inset = covered as f32 / scale_factor as f32;
+    SoftKeyboard {
+        visible: inset > 0.,
+        inset,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn soft_keyboard_inset() {
+        // Not reported yet
+        assert_eq!(
+            soft_keyboard_from_content(2000, 0, 0, 2.0),
+            SoftKeyboard::default()
+        );
+
+        // The content covers the whole window
+        assert_eq!(
+            soft_keyboard_from_content(2000, 0, 2000, 2.0),
+            SoftKeyboard {
+                visible: false,
+                inset: 0.
+            }
+        );
+
+        // The keyboard covers the bottom of the window, in logical pixels
+        assert_eq!(
+            soft_keyboard_from_content(2000, 100, 1200, 2.0),
+            SoftKeyboard {
+                visible: true,
+                inset: 400.
+            }
+        );
+
+        // Content bigger than the window, e.g while rotating it
+        assert_eq!(
+            soft_keyboard_from_content(1000, 0, 1200, 1.0),
+            SoftKeyboard {
+                visible: false,
+                inset: 0.
+            }
+        );
+    }
+}
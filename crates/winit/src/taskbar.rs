@@ -0,0 +1,71 @@
+use freya_core::platform_state::TaskbarProgress;
+use winit::window::Window;
+
+/// Show the progress in the taskbar icon of the window, or hide it if `None`.
+///
+/// Only Windows supports it, other platforms ignore it.
+pub fn set_taskbar_progress(window: &Window, progress: Option<TaskbarProgress>) {
+    #[cfg(target_os = "windows")]
+    platform::set_progress(window, progress);
+    #[cfg(not(target_os = "windows"))]
+    let _ = (window, progress);
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use freya_core::platform_state::TaskbarProgress;
+    use windows::Win32::{
+        Foundation::HWND,
+        System::Com::{
+            CoCreateInstance,
+            CLSCTX_INPROC_SERVER,
+        },
+        UI::Shell::{
+            ITaskbarList3,
+            TaskbarList,
+            TBPF_ERROR,
+            TBPF_INDETERMINATE,
+            TBPF_NOPROGRESS,
+            TBPF_NORMAL,
+            TBPF_PAUSED,
+        },
+    };
+    use winit::window::Window;
+
+    use crate::native_menu::platform::win32_handle;
+
+    /// Steps used to represent the progress.
+    const TOTAL: u64 = 1000;
+
+    pub fn set_progress(window: &Window, progress: Option<TaskbarProgress>) {
+        let Some(hwnd) = win32_handle(window) else {
+            return;
+        };
+        let hwnd = HWND(hwnd as _);
+
+        // SAFETY: COM is initialized by winit in the event loop thread and the handle belongs to a window that is alive
+        unsafe {
+            let taskbar: windows::core::Result<ITaskbarList3> =
+                CoCreateInstance(&TaskbarList, None, CLSCTX_INPROC_SERVER);
+            let Ok(taskbar) = taskbar else {
+                return;
+            };
+            if taskbar.HrInit().is_err() {
+                return;
+            }
+
+            let (state, value) = match progress {
+                None => (TBPF_NOPROGRESS, None),
+                Some(TaskbarProgress::Normal(value)) => (TBPF_NORMAL, Some(value)),
+                Some(TaskbarProgress::Paused(value)) => (TBPF_PAUSED, Some(value)),
+                Some(TaskbarProgress::Error(value)) => (TBPF_ERROR, Some(value)),
+                Some(TaskbarProgress::Indeterminate) => (TBPF_INDETERMINATE, None),
+            };
+            if let Some(value) = value {
+                let completed = (value.clamp(0., 1.) * TOTAL as f64) as u64;
+                taskbar.SetProgressValue(hwnd, completed, TOTAL).ok();
+            }
+            taskbar.SetProgressState(hwnd, state).ok();
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use freya_core::webview::{
+    WebViewConfig,
+    WebViewContent,
+    WebViewEvent,
+    WebViewId,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use torin::prelude::Area;
+use winit::{
+    event_loop::ActiveEventLoop,
+    window::Window,
+};
+
+/// Native webviews embedded in the window, placed over the area of their elements.
+///
+/// Requires the `webview` feature. Supported on Windows, macOS and Linux with X11, where GTK is required.
+#[derive(Default)]
+pub struct WinitWebViews {
+    #[cfg(feature = "webview")]
+    webviews: std::collections::HashMap<WebViewId, wry::WebView>,
+}
+
+impl WinitWebViews {
+    pub fn create(
+        &mut self,
+        window: &Window,
+        id: WebViewId,
+        config: WebViewConfig,
+        area: Area,
+        sender: UnboundedSender<WebViewEvent>,
+    ) {
+        #[cfg(feature = "webview")]
+        match platform::create(window, config, area, sender) {
+            Ok(webview) => {
+                self.webviews.insert(id, webview);
+            }
+            Err(err) => tracing::error!("Failed to create the webview: {err}"),
+        }
+        #[cfg(not(feature = "webview"))]
+        {
+            let _ = (window, id, config, area, sender);
+            tracing::warn!("WebViews require the `webview` feature");
+        }
+    }
+
+    pub fn set_area(&mut self, id: WebViewId, area: Option<Area>) {
+        #[cfg(feature = "webview")]
+        if let Some(webview) = self.webviews.get(&id) {
+            platform::set_area(webview, area);
+        }
+        #[cfg(not(feature = "webview"))]
+        let _ = (id, area);
+    }
+
+    pub fn set_content(&mut self, id: WebViewId, content: WebViewContent) {
+        #[cfg(feature = "webview")]
+        if let Some(webview) = self.webviews.get(&id) {
+            let result = match content {
+                WebViewContent::Url(url) => webview.load_url(&url),
+                WebViewContent::Html(html) => webview.load_html(&html),
+            };
+            if let Err(err) = result {
+                tracing::error!("Failed to load the content of the webview: {err}");
+            }
+        }
+        #[cfg(not(feature = "webview"))]
+        let _ = (id, content);
+    }
+
+    pub fn evaluate_script(&mut self, id: WebViewId, script: String) {
+        #[cfg(feature = "webview")]
+        if let Some(webview) = self.webviews.get(&id) {
+            if let Err(err) = webview.evaluate_script(&script) {
+                tracing::error!("Failed to evaluate a script in the webview: {err}");
+            }
+        }
+        #[cfg(not(feature = "webview"))]
+        let _ = (id, script);
+    }
+
+    pub fn remove(&mut self, id: WebViewId) {
+        #[cfg(feature = "webview")]
+        self.webviews.remove(&id);
+        #[cfg(not(feature = "webview"))]
+        let _ = id;
+    }
+
+    /// Process the events of the webviews that are not dispatched by the event loop of the window.
+    pub fn poll(&mut self, event_loop: &ActiveEventLoop) {
+        // GTK has its own event loop, keep it running while there are webviews
+        #[cfg(all(feature = "webview", target_os = "linux"))]
+        if !self.webviews.is_empty() {
+            while gtk::events_pending() {
+                gtk::main_iteration_do(false);
+            }
+            if event_loop.control_flow() == winit::event_loop::ControlFlow::Wait {
+                event_loop.set_control_flow(winit::event_loop::ControlFlow::wait_duration(
+                    std::time::Duration::from_millis(16),
+                ));
+            }
+        }
+        #[cfg(not(all(feature = "webview", target_os = "linux")))]
+        let _ = event_loop;
+    }
+}
+
+#[cfg(feature = "webview")]
+mod platform {
+    use freya_core::webview::{
+        WebViewConfig,
+        WebViewContent,
+        WebViewEvent,
+        WEBVIEW_INIT_SCRIPT,
+    };
+    use tokio::sync::mpsc::UnboundedSender;
+    use torin::prelude::Area;
+    use winit::window::Window;
+    use wry::{
+        dpi::{
+            LogicalPosition,
+            LogicalSize,
+        },
+        PageLoadEvent,
+        Rect,
+        WebView,
+        WebViewBuilder,
+    };
+
+    fn bounds(area: Area) -> Rect {
+        Rect {
+            position: LogicalPosition::new(area.min_x(), area.min_y()).into(),
+            size: LogicalSize::new(area.width(), area.height()).into(),
+        }
+    }
+
+    pub fn create(
+        window: &Window,
+        config: WebViewConfig,
+        area: Area,
+        sender: UnboundedSender<WebViewEvent>,
+    ) -> wry::Result<WebView> {
+        #[cfg(target_os = "linux")]
+        if let Err(err) = gtk::init() {
+            tracing::error!("Failed to initialize GTK: {err}");
+        }
+
+        let ipc_sender = sender.clone();
+        let mut builder = WebViewBuilder::new()
+            .with_bounds(bounds(area))
+            .with_initialization_script(WEBVIEW_INIT_SCRIPT)
+            .with_devtools(config.devtools)
+            .with_transparent(config.transparent)
+            .with_ipc_handler(move |request| {
+                ipc_sender
+                    .send(WebViewEvent::Message(request.into_body()))
+                    .ok();
+            })
+            .with_on_page_load_handler(move |event, url| {
+                let event = match event {
+                    PageLoadEvent::Started => WebViewEvent::LoadStarted(url),
+                    PageLoadEvent::Finished => WebViewEvent::LoadFinished(url),
+                };
+                sender.send(event).ok();
+            });
+
+        if let Some(user_agent) = &config.user_agent {
+            builder = builder.with_user_agent(user_agent);
+        }
+
+        builder = match &config.content {
+            WebViewContent::Url(url) => builder.with_url(url),
+            WebViewContent::Html(html) => builder.with_html(html),
+        };
+
+        builder.build_as_child(window)
+    }
+
+    pub fn set_area(webview: &WebView, area: Option<Area>) {
+        match area {
+            Some(area) => {
+                webview.set_bounds(bounds(area)).ok();
+                webview.set_visible(true).ok();
+            }
+            None => {
+                webview.set_visible(false).ok();
+            }
+        }
+    }
+}
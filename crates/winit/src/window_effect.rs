@@ -0,0 +1,117 @@
+use freya_core::platform_state::WindowEffect;
+use winit::window::Window;
+
+/// Replace the effect shown behind the transparent areas of the window, or remove it if `None`.
+///
+/// Unsupported effects are ignored.
+pub fn set_window_effect(window: &Window, effect: Option<WindowEffect>) {
+    platform::clear(window);
+    window.set_blur(false);
+
+    let Some(effect) = effect else {
+        return;
+    };
+
+    let applied = match effect {
+        // KDE Plasma and macOS blur is provided by winit
+        #[cfg(not(target_os = "windows"))]
+        WindowEffect::Blur => {
+            window.set_blur(true);
+            true
+        }
+        effect => platform::apply(window, effect),
+    };
+
+    if !applied {
+        tracing::warn!("The window effect {effect:?} is not supported in this platform");
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use freya_core::platform_state::{
+        VibrancyMaterial,
+        WindowEffect,
+    };
+    use window_vibrancy::{
+        apply_vibrancy,
+        clear_vibrancy,
+        NSVisualEffectMaterial,
+    };
+    use winit::window::Window;
+
+    pub fn clear(window: &Window) {
+        clear_vibrancy(window).ok();
+    }
+
+    pub fn apply(window: &Window, effect: WindowEffect) -> bool {
+        let WindowEffect::Vibrancy(material) = effect else {
+            return false;
+        };
+        let material = match material {
+            VibrancyMaterial::Titlebar => NSVisualEffectMaterial::Titlebar,
+            VibrancyMaterial::Selection => NSVisualEffectMaterial::Selection,
+            VibrancyMaterial::Menu => NSVisualEffectMaterial::Menu,
+            VibrancyMaterial::Popover => NSVisualEffectMaterial::Popover,
+            VibrancyMaterial::Sidebar => NSVisualEffectMaterial::Sidebar,
+            VibrancyMaterial::HeaderView => NSVisualEffectMaterial::HeaderView,
+            VibrancyMaterial::Sheet => NSVisualEffectMaterial::Sheet,
+            VibrancyMaterial::WindowBackground => NSVisualEffectMaterial::WindowBackground,
+            VibrancyMaterial::HudWindow => NSVisualEffectMaterial::HudWindow,
+            VibrancyMaterial::FullScreenUI => NSVisualEffectMaterial::FullScreenUI,
+            VibrancyMaterial::Tooltip => NSVisualEffectMaterial::Tooltip,
+            VibrancyMaterial::ContentBackground => NSVisualEffectMaterial::ContentBackground,
+            VibrancyMaterial::UnderWindowBackground => {
+                NSVisualEffectMaterial::UnderWindowBackground
+            }
+            VibrancyMaterial::UnderPageBackground => NSVisualEffectMaterial::UnderPageBackground,
+        };
+        apply_vibrancy(window, material, None, None).is_ok()
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use freya_core::platform_state::WindowEffect;
+    use window_vibrancy::{
+        apply_acrylic,
+        apply_blur,
+        apply_mica,
+        apply_tabbed,
+        clear_acrylic,
+        clear_blur,
+        clear_mica,
+        clear_tabbed,
+    };
+    use winit::window::Window;
+
+    pub fn clear(window: &Window) {
+        // Only the applied effect can be cleared, the rest fail
+        clear_acrylic(window).ok();
+        clear_blur(window).ok();
+        clear_mica(window).ok();
+        clear_tabbed(window).ok();
+    }
+
+    pub fn apply(window: &Window, effect: WindowEffect) -> bool {
+        match effect {
+            WindowEffect::Acrylic { tint } => apply_acrylic(window, tint).is_ok(),
+            WindowEffect::Mica { dark } => apply_mica(window, dark).is_ok(),
+            WindowEffect::Tabbed { dark } => apply_tabbed(window, dark).is_ok(),
+            WindowEffect::Blur => apply_blur(window, None).is_ok(),
+            WindowEffect::Vibrancy(_) => false,
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+mod platform {
+    use freya_core::platform_state::WindowEffect;
+    use winit::window::Window;
+
+    pub fn clear(_window: &Window) {}
+
+    pub fn apply(_window: &Window, _effect: WindowEffect) -> bool {
+        false
+    }
+}
@@ -0,0 +1,281 @@
+use std::{
+    fs,
+    path::PathBuf,
+};
+
+use freya_core::app_dirs::{
+    app_config_dir,
+    app_name,
+    key_file_name,
+};
+use winit::{
+    dpi::{
+        LogicalSize,
+        PhysicalPosition,
+        PhysicalSize,
+    },
+    event_loop::ActiveEventLoop,
+    monitor::MonitorHandle,
+    window::{
+        Window,
+        WindowAttributes,
+    },
+};
+
+/// Minimum part of the window, in physical pixels, that must be inside a monitor for its saved position to be restored.
+const MIN_VISIBLE_AREA: i32 = 64;
+
+/// Size, position and maximized state of a window remembered across runs,
+/// see [LaunchConfig::remember_window_state](crate::LaunchConfig::remember_window_state).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct WindowGeometry {
+    key: String,
+    /// Size while not maximized.
+    size: Option<LogicalSize<f64>>,
+    /// Position while not maximized.
+    position: Option<PhysicalPosition<i32>>,
+    maximized: bool,
+    /// Name of the monitor the window was in.
+    monitor: Option<String>,
+}
+
+impl WindowGeometry {
+    /// Load the geometry saved with the given key, if any.
+    pub fn load(key: &str) -> Self {
+        let geometry = fs::read_to_string(Self::path(key))
+            .ok()
+            .map(|content| Self::parse(&content))
+            .unwrap_or_default();
+        Self {
+            key: key.to_string(),
+            ..geometry
+        }
+    }
+
+    /// Save the geometry of the window, keeping the size and position it had before being maximized.
+    pub fn save(&mut self, window: &Window) {
+        self.update(window);
+        self.maximized = window.is_maximized();
+
+        let path = Self::path(&self.key);
+        let result = path
+            .parent()
+            .map(fs::create_dir_all)
+            .unwrap_or(Ok(()))
+            .and_then(|_| fs::write(&path, self.serialize()));
+        if let Err(err) = result {
+            tracing::warn!("Failed to save the window state to {path:?}: {err}");
+        }
+    }
+
+    /// Remember the current size, position and monitor of the window, unless it's maximized or minimized.
+    pub fn update(&mut self, window: &Window) {
+        if window.is_maximized() || window.is_minimized().unwrap_or_default() {
+            return;
+        }
+        self.size = Some(window.inner_size().to_logical(window.scale_factor()));
+        // Not available in some platforms, like Wayland
+        self.position = window.outer_position().ok();
+        self.monitor = window.current_monitor().and_then(|monitor| monitor.name());
+    }
+
+    /// Restore the saved geometry in the window attributes.
+    ///
+    /// The position is only restored if the monitor is still connected and the window would be visible in it,
+    /// and the size is reduced to fit in the monitor, e.g. after changing its resolution.
+    pub fn apply(
+        &self,
+        mut window_attributes: WindowAttributes,
+        event_loop: &ActiveEventLoop,
+    ) -> WindowAttributes {
+        let monitor = self.saved_monitor(event_loop);
+
+        if let (Some(position), Some(monitor)) = (self.position, &monitor) {
+            window_attributes = window_attributes.with_position(position);
+            tracing::info!(
+                "Restoring the window position in the {:?} monitor",
+                monitor.name()
+            );
+        }
+
+        if let Some(size) = self.size {
+            let size = match monitor.or_else(|| event_loop.primary_monitor()) {
+                Some(monitor) => {
+                    let monitor_size: LogicalSize<f64> =
+                        monitor.size().to_logical(monitor.scale_factor());
+                    LogicalSize::new(
+                        size.width.min(monitor_size.width),
+                        size.height.min(monitor_size.height),
+                    )
+                }
+                None => size,
+            };
+            window_attributes = window_attributes.with_inner_size(size);
+        }
+
+        window_attributes.with_maximized(self.maximized)
+    }
+
+    /// Get the monitor the window was in, as long as it's still connected and the window would be visible in it.
+    fn saved_monitor(&self, event_loop: &ActiveEventLoop) -> Option<MonitorHandle> {
+        self.find_monitor(event_loop.available_monitors(), |monitor| {
+            (monitor.name(), monitor.position(), monitor.size())
+        })
+    }
+
+    /// Find the saved monitor among the given ones, described by their name, position and size.
+    fn find_monitor<M>(
+        &self,
+        mut monitors: impl Iterator<Item = M>,
+        describe: impl Fn(&M) -> (Option<String>, PhysicalPosition<i32>, PhysicalSize<u32>),
+    ) -> Option<M> {
+        let position = self.position?;
+        monitors.find(|monitor| {
+            let (name, monitor_position, monitor_size) = describe(monitor);
+            let is_same_monitor = self.monitor.is_none() || name == self.monitor;
+            let is_visible = position.x + MIN_VISIBLE_AREA > monitor_position.x
+                && position.y + MIN_VISIBLE_AREA > monitor_position.y
+                && position.x + MIN_VISIBLE_AREA < monitor_position.x + monitor_size.width as i32
+                && position.y + MIN_VISIBLE_AREA < monitor_position.y + monitor_size.height as i32;
+            is_same_monitor && is_visible
+        })
+    }
+
+    /// Saved in a file named after the key inside the config folder of the platform,
+    /// in a folder named after the app executable.
+    fn path(key: &str) -> PathBuf {
+        app_config_dir(&app_name()).join(format!("{}.window", key_file_name(key)))
+    }
+
+    fn parse(content: &str) -> Self {
+        let mut geometry = Self::default();
+        for line in content.lines() {
+            let Some((name, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim();
+            match name.trim() {
+                "size" => {
+                    geometry.size = value
+                        .split_once('x')
+                        .and_then(|(width, height)| {
+                            Some((width.parse().ok()?, height.parse().ok()?))
+                        })
+                        .filter(|(width, height): &(f64, f64)| *width > 0. && *height > 0.)
+                        .map(|(width, height)| LogicalSize::new(width, height));
+                }
+                "position" => {
+                    geometry.position = value.split_once(',').and_then(|(x, y)| {
+                        Some(PhysicalPosition::new(x.parse().ok()?, y.parse().ok()?))
+                    });
+                }
+                "maximized" => geometry.maximized = value == "true",
+                "monitor" => geometry.monitor = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        geometry
+    }
+
+    fn serialize(&self) -> String {
+        let mut content = format!("maximized={}\n", self.maximized);
+        if let Some(size) = self.size {
+            content.push_str(&format!("size={}x{}\n", size.width, size.height));
+        }
+        if let Some(position) = self.position {
+            content.push_str(&format!("position={},{}\n", position.x, position.y));
+        }
+        if let Some(monitor) = &self.monitor {
+            content.push_str(&format!("monitor={monitor}\n"));
+        }
+        content
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn monitor(
+        name: &str,
+        x: i32,
+        y: i32,
+    ) -> (Option<String>, PhysicalPosition<i32>, PhysicalSize<u32>) {
+        (
+            Some(name.to_string()),
+            PhysicalPosition::new(x, y),
+            PhysicalSize::new(1920, 1080),
+        )
+    }
+
+    #[test]
+    fn serialize_and_parse() {
+        let geometry = WindowGeometry {
+            key: String::new(),
+            size: Some(LogicalSize::new(800., 600.5)),
+            position: Some(PhysicalPosition::new(-20, 40)),
+            maximized: true,
+            monitor: Some("DP-1".to_string()),
+        };
+        assert_eq!(
+            geometry.serialize(),
+            "maximized=true\nsize=800x600.5\nposition=-20,40\nmonitor=DP-1\n"
+        );
+        assert_eq!(WindowGeometry::parse(&geometry.serialize()), geometry);
+
+        // Only the maximized state is always saved
+        let geometry = WindowGeometry::default();
+        assert_eq!(geometry.serialize(), "maximized=false\n");
+        assert_eq!(WindowGeometry::parse(&geometry.serialize()), geometry);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let geometry = WindowGeometry::parse(
+            "size=0x600\nposition=10\nmaximized=yes\nunknown=1\nmonitor = HDMI-1 \nbroken",
+        );
+        assert_eq!(
+            geometry,
+            WindowGeometry {
+                monitor: Some("HDMI-1".to_string()),
+                ..WindowGeometry::default()
+            }
+        );
+        assert_eq!(WindowGeometry::parse(""), WindowGeometry::default());
+    }
+
+    #[test]
+    fn saved_monitor() {
+        let monitors = [monitor("DP-1", 0, 0), monitor("HDMI-1", 1920, 0)];
+        let find = |geometry: &WindowGeometry| {
+            geometry
+                .find_monitor(monitors.iter(), |monitor| (*monitor).clone())
+                .and_then(|(name, _, _)| name.clone())
+        };
+
+        let mut geometry = WindowGeometry {
+            position: Some(PhysicalPosition::new(2000, 100)),
+            monitor: Some("HDMI-1".to_string()),
+            ..WindowGeometry::default()
+        };
+        assert_eq!(find(&geometry).as_deref(), Some("HDMI-1"));
+
+        // The monitor was disconnected
+        geometry.monitor = Some("DP-2".to_string());
+        assert_eq!(find(&geometry), None);
+
+        // Without a saved name any monitor where the window is visible works
+        geometry.monitor = None;
+        assert_eq!(find(&geometry).as_deref(), Some("HDMI-1"));
+
+        // The window would be mostly outside of the monitors
+        geometry.position = Some(PhysicalPosition::new(3830, 100));
+        assert_eq!(find(&geometry), None);
+        geometry.position = Some(PhysicalPosition::new(-100, 100));
+        assert_eq!(find(&geometry), None);
+
+        // There is no saved position
+        geometry.position = None;
+        assert_eq!(find(&geometry), None);
+    }
+}
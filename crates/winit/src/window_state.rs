@@ -4,6 +4,10 @@ use dioxus_core::VirtualDom;
 use freya_core::{
     dom::SafeDOM,
     event_loop_messages::EventLoopMessage,
+    open_requests::{
+        LaunchOpenRequests,
+        OpenRequest,
+    },
 };
 use freya_engine::prelude::*;
 use winit::{
@@ -21,7 +25,13 @@ use crate::{
     config::WindowConfig,
     devtools::Devtools,
     drivers::GraphicsDriver,
+    global_shortcuts::WinitGlobalShortcuts,
+    native_menu::WinitNativeMenu,
+    native_tray::WinitNativeTray,
     size::WinitSize,
+    webviews::WinitWebViews,
+    window_effect::set_window_effect,
+    window_geometry::WindowGeometry,
     LaunchConfig,
 };
 
@@ -40,6 +50,12 @@ pub struct CreatedState {
     pub(crate) window: Window,
     pub(crate) window_config: WindowConfig,
     pub(crate) is_window_focused: bool,
+    pub(crate) is_suspended: bool,
+    pub(crate) native_menu: WinitNativeMenu,
+    pub(crate) native_tray: WinitNativeTray,
+    pub(crate) global_shortcuts: WinitGlobalShortcuts,
+    pub(crate) webviews: WinitWebViews,
+    pub(crate) window_geometry: Option<WindowGeometry>,
 }
 
 pub enum WindowState<'a, State: Clone + 'static> {
@@ -95,6 +111,15 @@ impl<'a, State: Clone + 'a> WindowState<'a, State> {
                 window_attributes.with_max_inner_size(LogicalSize::<f64>::from(max_size));
         }
 
+        let window_geometry = config
+            .window_config
+            .remember_window_state
+            .as_deref()
+            .map(WindowGeometry::load);
+        if let Some(window_geometry) = &window_geometry {
+            window_attributes = window_geometry.apply(window_attributes, event_loop);
+        }
+
         if let Some(with_window_attributes) = config.window_config.window_attributes_hook.take() {
             window_attributes = (with_window_attributes)(window_attributes);
         }
@@ -104,13 +129,23 @@ impl<'a, State: Clone + 'a> WindowState<'a, State> {
 
         let accessibility = WinitAcessibilityTree::new(&window, event_loop_proxy.clone());
 
+        if config.window_config.window_effect.is_some() {
+            set_window_effect(&window, config.window_config.window_effect);
+        }
+
         if config.window_config.visible {
             window.set_visible(true);
         }
 
-        // Allow IME
+        // Allow IME, in touch platforms this shows the on-screen keyboard so it waits until an input is focused
+        #[cfg(not(any(target_os = "android", target_os = "ios")))]
         window.set_ime_allowed(true);
 
+        let native_menu =
+            WinitNativeMenu::new(config.window_config.menu.take(), &window, event_loop_proxy);
+
+        let global_shortcuts = WinitGlobalShortcuts::new(event_loop_proxy);
+
         let mut dirty_surface = surface
             .new_surface_with_dimensions(window.inner_size().to_skia())
             .unwrap();
@@ -138,9 +173,14 @@ impl<'a, State: Clone + 'a> WindowState<'a, State> {
             config.embedded_fonts,
             config.plugins,
             config.default_fonts,
+            config.font_fallbacks,
             accessibility,
         );
 
+        app.launch_open_requests = LaunchOpenRequests(OpenRequest::from_args(
+            std::env::args_os().skip(1),
+            &config.window_config.url_schemes,
+        ));
         app.init_doms(scale_factor as f32, config.state);
         app.process_layout(window.inner_size(), scale_factor);
 
@@ -152,6 +192,12 @@ impl<'a, State: Clone + 'a> WindowState<'a, State> {
             app,
             window_config: config.window_config,
             is_window_focused: false,
+            is_suspended: false,
+            native_menu,
+            native_tray,
+            global_shortcuts,
+            webviews: WinitWebViews::default(),
+            window_geometry,
         });
     }
 }
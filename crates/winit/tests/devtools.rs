@@ -0,0 +1,131 @@
+use dioxus_core::NoOpMutations;
+use freya::prelude::*;
+use freya_winit::devtools::{
+    Devtools,
+    DevtoolsScope,
+    TimelineEntryKind,
+    TimelineReceiver,
+};
+use tracing_subscriber::prelude::*;
+
+fn rendered_components(timeline: &TimelineReceiver) -> Vec<String> {
+    timeline
+        .borrow()
+        .iter()
+        .filter_map(|entry| match &entry.kind {
+            TimelineEntryKind::ComponentRendered { name } => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn count_renders(timeline: &TimelineReceiver, component: &str) -> usize {
+    rendered_components(timeline)
+        .iter()
+        .filter(|name| *name == component)
+        .count()
+}
+
+#[component]
+fn Counter(count: ReadOnlySignal<i32>) -> Element {
+    rsx!(
+        label { "{count}" }
+    )
+}
+
+#[tokio::test]
+pub async fn timeline_signals_and_renders() {
+    fn app() -> Element {
+        let mut count = use_signal(|| 0);
+
+        use_hook(move || {
+            spawn(async move {
+                count += 1;
+            })
+        });
+
+        rsx!(Counter { count })
+    }
+
+    let (devtools, _) = Devtools::new();
+    let timeline = devtools.timeline();
+    let _guard = tracing::subscriber::set_default(
+        tracing_subscriber::registry().with(devtools.tracing_layer()),
+    );
+
+    let mut vdom = VirtualDom::new(app);
+    vdom.rebuild_in_place();
+
+    assert_eq!(count_renders(&timeline, "Counter"), 1);
+
+    // The spawned task writes the signal, so the Counter renders again
+    vdom.wait_for_work().await;
+    vdom.render_immediate(&mut NoOpMutations);
+
+    assert_eq!(count_renders(&timeline, "Counter"), 2);
+
+    let written_at = timeline
+        .borrow()
+        .iter()
+        .find_map(|entry| match &entry.kind {
+            TimelineEntryKind::SignalWritten { location } => Some(location.clone()),
+            _ => None,
+        })
+        .unwrap();
+    assert!(written_at.contains("devtools.rs"));
+}
+
+#[tokio::test]
+pub async fn timeline_ignores_devtools_components() {
+    #[component]
+    fn Panel() -> Element {
+        rsx!(rect {})
+    }
+
+    fn app() -> Element {
+        let count = use_signal(|| 0);
+
+        rsx!(
+            Counter { count }
+            DevtoolsPanel { }
+        )
+    }
+
+    #[component]
+    fn DevtoolsPanel() -> Element {
+        use_context_provider(|| DevtoolsScope);
+
+        rsx!(Panel {})
+    }
+
+    let (devtools, _) = Devtools::new();
+    let timeline = devtools.timeline();
+    let _guard = tracing::subscriber::set_default(
+        tracing_subscriber::registry().with(devtools.tracing_layer()),
+    );
+
+    let mut vdom = VirtualDom::new(app);
+    vdom.rebuild_in_place();
+
+    assert_eq!(count_renders(&timeline, "Counter"), 1);
+    assert_eq!(count_renders(&timeline, "Panel"), 0);
+}
+
+#[test]
+pub fn timeline_capacity() {
+    let (devtools, _) = Devtools::new();
+    let timeline = devtools.timeline();
+    let _guard = tracing::subscriber::set_default(
+        tracing_subscriber::registry().with(devtools.tracing_layer()),
+    );
+
+    for i in 0..600 {
+        let _span = tracing::trace_span!(target: "dioxus_core", "render", scope = %i).entered();
+    }
+
+    let rendered = rendered_components(&timeline);
+    // Only the latest entries are kept
+    assert_eq!(rendered.len(), 500);
+    assert_eq!(rendered.first().unwrap(), "100");
+    assert_eq!(rendered.last().unwrap(), "599");
+}
@@ -0,0 +1,181 @@
+use freya::prelude::*;
+use freya_core::{
+    events::{
+        PlatformEvent,
+        PlatformEventData,
+    },
+    render::CapturedFrame,
+};
+use freya_native_core::events::EventName;
+use winit::{
+    dpi::PhysicalSize,
+    event::MouseButton,
+};
+
+/// Color of the pixel of a frame in the given physical position.
+fn pixel(frame: &CapturedFrame, x: u32, y: u32) -> [u8; 4] {
+    let start = ((y * frame.width + x) * 4) as usize;
+    frame.pixels[start..start + 4].try_into().unwrap()
+}
+
+fn mouse_event(name: EventName, cursor: (f64, f64)) -> PlatformEvent {
+    PlatformEvent {
+        name,
+        data: PlatformEventData::Mouse {
+            cursor: cursor.into(),
+            button: Some(MouseButton::Left),
+        },
+    }
+}
+
+#[tokio::test]
+pub async fn launch() {
+    fn headless_app() -> Element {
+        rsx!(label { "Hello, World!" })
+    }
+
+    let renderer = launch_headless(
+        headless_app,
+        LaunchConfig::<()>::new().with_size(200.0, 100.0),
+        2.0,
+    );
+
+    // The frames are measured in physical pixels
+    assert_eq!(renderer.size(), PhysicalSize::new(400, 200));
+}
+
+#[tokio::test]
+pub async fn render_frame() {
+    fn headless_app() -> Element {
+        rsx!(rect {
+            width: "50%",
+            height: "100%",
+            background: "rgb(255, 0, 0)",
+        })
+    }
+
+    let mut renderer = launch_headless(
+        headless_app,
+        LaunchConfig::<()>::new()
+            .with_size(100.0, 50.0)
+            .with_background("rgb(0, 0, 255)"),
+        1.0,
+    );
+    renderer.wait_for_update().await;
+
+    let frame = renderer.capture_frame().unwrap();
+    assert_eq!((frame.width, frame.height), (100, 50));
+    assert_eq!(pixel(&frame, 10, 10), [255, 0, 0, 255]);
+    // The background of the config is painted behind the app
+    assert_eq!(pixel(&frame, 90, 10), [0, 0, 255, 255]);
+
+    let png = renderer.capture_png().unwrap();
+    assert!(png.starts_with(b"\x89PNG"));
+}
+
+#[tokio::test]
+pub async fn deliver_events() {
+    fn headless_app() -> Element {
+        let mut clicked = use_signal(|| false);
+        let mut pressed = use_signal(|| false);
+
+        let background = match (clicked(), pressed()) {
+            (false, false) => "rgb(255, 0, 0)",
+            (true, false) => "rgb(0, 255, 0)",
+            _ => "rgb(0, 0, 255)",
+        };
+
+        rsx!(rect {
+            width: "100%",
+            height: "100%",
+            background,
+            onclick: move |_| clicked.set(true),
+            onglobalkeydown: move |_| pressed.set(true),
+        })
+    }
+
+    let mut renderer = launch_headless(
+        headless_app,
+        LaunchConfig::<()>::new().with_size(100.0, 100.0),
+        1.0,
+    );
+    renderer.wait_for_update().await;
+
+    let frame = renderer.capture_frame().unwrap();
+    assert_eq!(pixel(&frame, 50, 50), [255, 0, 0, 255]);
+
+    // Mouse events
+    renderer.push_event(mouse_event(EventName::MouseDown, (50.0, 50.0)));
+    renderer.wait_for_update().await;
+    renderer.push_event(mouse_event(EventName::MouseUp, (50.0, 50.0)));
+    renderer.wait_for_update().await;
+
+    let frame = renderer.capture_frame().unwrap();
+    assert_eq!(pixel(&frame, 50, 50), [0, 255, 0, 255]);
+
+    // Keyboard events
+    renderer.push_event(PlatformEvent {
+        name: EventName::KeyDown,
+        data: PlatformEventData::Keyboard {
+            key: Key::Enter,
+            code: Code::Enter,
+            modifiers: Modifiers::empty(),
+        },
+    });
+    renderer.wait_for_update().await;
+
+    let frame = renderer.capture_frame().unwrap();
+    assert_eq!(pixel(&frame, 50, 50), [0, 0, 255, 255]);
+}
+
+#[tokio::test]
+pub async fn suspend_and_resume() {
+    fn headless_app() -> Element {
+        let lifecycle = use_app_lifecycle();
+        let mut resumed = use_signal(|| false);
+
+        use_effect(move || {
+            if lifecycle().is_suspended {
+                resumed.set(true);
+            }
+        });
+
+        let background = if resumed() {
+            "rgb(0, 255, 0)"
+        } else {
+            "rgb(255, 0, 0)"
+        };
+
+        rsx!(rect {
+            width: "100%",
+            height: "100%",
+            background,
+        })
+    }
+
+    let mut renderer = launch_headless(
+        headless_app,
+        LaunchConfig::<()>::new().with_size(100.0, 100.0),
+        1.0,
+    );
+    renderer.wait_for_update().await;
+
+    let frame = renderer.capture_frame().unwrap();
+    assert_eq!(pixel(&frame, 50, 50), [255, 0, 0, 255]);
+
+    // There is nothing to capture while in the background
+    renderer.suspend();
+    renderer.wait_for_update().await;
+    renderer.wait_for_update().await;
+    assert!(renderer.capture_frame().is_none());
+    assert!(renderer.capture_png().is_none());
+
+    // The new surface gets the changes made in the background
+    renderer.resume();
+    renderer.wait_for_update().await;
+
+    let frame = renderer.capture_frame().unwrap();
+    assert_eq!((frame.width, frame.height), (100, 100));
+    assert_eq!(pixel(&frame, 0, 0), [0, 255, 0, 255]);
+    assert_eq!(pixel(&frame, 99, 99), [0, 255, 0, 255]);
+}
@@ -0,0 +1,73 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+#[cfg(feature = "use_audio")]
+use freya::prelude::*;
+
+#[cfg(not(feature = "use_audio"))]
+fn main() {
+    panic!("Run with the 'use_audio' feature");
+}
+
+#[cfg(feature = "use_audio")]
+fn main() {
+    launch(app);
+}
+
+#[cfg(feature = "use_audio")]
+fn app() -> Element {
+    let mut audio = use_audio();
+
+    use_hook(move || {
+        if let Some(path) = std::env::args().nth(1) {
+            if let Ok(bytes) = std::fs::read(path) {
+                audio.load(bytes);
+            }
+        }
+    });
+
+    let volume = audio.volume();
+    let looping = audio.looping();
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "50",
+            spacing: "10",
+            if let Some(err) = &*audio.error().read() {
+                label {
+                    color: "black",
+                    "{err}"
+                }
+            }
+            rect {
+                direction: "horizontal",
+                spacing: "10",
+                Button {
+                    onpress: move |_| audio.play(),
+                    label { "Play" }
+                }
+                Button {
+                    onpress: move |_| audio.pause(),
+                    label { "Pause" }
+                }
+                Button {
+                    onpress: move |_| audio.stop(),
+                    label { "Stop" }
+                }
+                Button {
+                    onpress: move |_| audio.set_looping(!looping()),
+                    label { "Loop: {looping}" }
+                }
+            }
+            Slider {
+                size: "200",
+                value: volume() as f64 * 100.,
+                onmoved: move |value: f64| audio.set_volume(value as f32 / 100.)
+            }
+        }
+    )
+}
@@ -3,6 +3,8 @@
     windows_subsystem = "windows"
 )]
 
+use std::time::Duration;
+
 use freya::prelude::*;
 
 fn main() {
@@ -10,6 +12,8 @@ fn main() {
 }
 
 fn app() -> Element {
+    let mut loading = use_signal(|| false);
+
     rsx!(
         Body {
             rect {
@@ -29,6 +33,25 @@ fn app() -> Element {
                 OutlineButton {
                     label { "Button C" }
                 }
+                Button {
+                    variant: ButtonVariant::Ghost,
+                    size: ButtonSize::Small,
+                    label { "Button D" }
+                }
+                Button {
+                    variant: ButtonVariant::Danger,
+                    size: ButtonSize::Large,
+                    loading: loading(),
+                    onpress: move |_| {
+                        loading.set(true);
+                        spawn(async move {
+                            tokio::time::sleep(Duration::from_secs(2)).await;
+                            loading.set(false);
+                        });
+                    },
+                    trailing: rsx!(label { "!" }),
+                    label { "Button E" }
+                }
             }
         }
     )
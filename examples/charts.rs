@@ -0,0 +1,73 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Charts", (900.0, 650.0));
+}
+
+fn app() -> Element {
+    let mut visits = use_signal(|| vec![120., 180., 150., 210., 190., 240.]);
+
+    let labels = ["Jan", "Feb", "Mar", "Apr", "May", "Jun"]
+        .map(String::from)
+        .to_vec();
+
+    let shuffle = move |_| {
+        visits.with_mut(|visits| {
+            for (i, value) in visits.iter_mut().enumerate() {
+                *value = (*value * (1.3 - i as f32 * 0.1)).round() % 300.;
+            }
+        })
+    };
+
+    rsx!(
+        Body {
+            padding: "20",
+            spacing: "20",
+            Button {
+                onpress: shuffle,
+                label { "Change data" }
+            }
+            rect {
+                direction: "horizontal",
+                width: "100%",
+                height: "fill",
+                spacing: "20",
+                LineChart {
+                    theme: theme_with!(ChartTheme {
+                        width: "50%".into(),
+                    }),
+                    labels: labels.clone(),
+                    series: vec![
+                        ChartSeries::new("Visits", visits()),
+                        ChartSeries::new("Sales", [30., 45., 60., 50., 80., 95.]),
+                    ],
+                }
+                BarChart {
+                    theme: theme_with!(ChartTheme {
+                        width: "fill".into(),
+                    }),
+                    labels: labels.clone(),
+                    series: vec![
+                        ChartSeries::new("2023", [40., 55., 35., 70., 65., 80.]),
+                        ChartSeries::new("2024", visits().iter().map(|v| v / 3.).collect::<Vec<_>>()),
+                    ],
+                }
+            }
+            PieChart {
+                theme: theme_with!(ChartTheme {
+                    height: "250".into(),
+                }),
+                slices: vec![
+                    PieSlice::new("Desktop", visits()[0]),
+                    PieSlice::new("Mobile", visits()[1]),
+                    PieSlice::new("Tablet", visits()[2]),
+                ],
+            }
+        }
+    )
+}
@@ -11,7 +11,7 @@ fn main() {
     launch_with_title(app, "Checkbox");
 }
 
-#[derive(PartialEq, Eq, Hash)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum Choice {
     First,
     Second,
@@ -20,8 +20,26 @@ enum Choice {
 
 fn app() -> Element {
     let mut selected = use_signal::<HashSet<Choice>>(HashSet::default);
+    let all = [Choice::First, Choice::Second, Choice::Third];
+    let all_selected =
+        CheckboxState::from_states(all.map(|choice| selected.read().contains(&choice)));
 
     rsx!(
+        Tile {
+            onselect: move |_| {
+                if all_selected.is_checked() {
+                    selected.write().clear();
+                } else {
+                    selected.write().extend(all);
+                }
+            },
+            leading: rsx!(
+                Checkbox {
+                    selected: all_selected,
+                }
+            ),
+            label { "Select all" }
+        }
         Tile {
             onselect: move |_| {
                 if selected.read().contains(&Choice::First) {
@@ -0,0 +1,65 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::new()
+            .with_title("Custom Titlebar")
+            .with_decorations(false),
+    )
+}
+
+fn app() -> Element {
+    rsx!(
+        WindowResizeArea {
+            rect {
+                width: "100%",
+                height: "100%",
+                rect {
+                    width: "100%",
+                    direction: "horizontal",
+                    background: "rgb(235, 235, 235)",
+                    cross_align: "center",
+                    rect {
+                        width: "fill",
+                        WindowDragArea {
+                            rect {
+                                width: "100%",
+                                height: "32",
+                                direction: "horizontal",
+                                cross_align: "center",
+                                padding: "0 10",
+                                spacing: "8",
+                                label {
+                                    "Custom Titlebar"
+                                }
+                                WindowDragExclusion {
+                                    Button {
+                                        label {
+                                            "Not draggable"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    WindowCaptionButtons { }
+                }
+                rect {
+                    width: "100%",
+                    height: "fill",
+                    main_align: "center",
+                    cross_align: "center",
+                    label {
+                        "Drag the titlebar, double click it to maximize or drag the edges to resize"
+                    }
+                }
+            }
+        }
+    )
+}
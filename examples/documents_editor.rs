@@ -340,6 +340,7 @@ fn PageNotFound() -> Element {
 fn DocumentEditor(path: String, mut editable: UseEditable) -> Element {
     let cursor_reference = editable.cursor_attr();
     let highlights = editable.highlights_attr(0);
+    let carets = editable.carets_attr(0);
     let editor = editable.editor().read();
     let cursor_char = editor.cursor_pos();
 
@@ -376,6 +377,7 @@ fn DocumentEditor(path: String, mut editable: UseEditable) -> Element {
                 cursor_mode: "editable",
                 cursor_color: "black",
                 highlights,
+                carets,
                 onclick,
                 onmousemove,
                 onmousedown,
@@ -0,0 +1,77 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Drawing Canvas", (700.0, 500.0));
+}
+
+const COLORS: [&str; 4] = [
+    "rgb(30, 30, 30)",
+    "rgb(220, 38, 38)",
+    "rgb(37, 99, 235)",
+    "rgb(22, 163, 74)",
+];
+
+fn app() -> Element {
+    let mut controller = use_drawing_controller();
+    let mut color = use_signal(|| COLORS[0]);
+    let mut stroke_width = use_signal(|| 50.);
+
+    let onexport = move |_| {
+        if let Some(png) = controller.export_png(2.) {
+            std::fs::write("./drawing.png", png).ok();
+        }
+    };
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            rect {
+                direction: "horizontal",
+                cross_align: "center",
+                padding: "8",
+                spacing: "8",
+                for c in COLORS {
+                    rect {
+                        key: "{c}",
+                        width: "28",
+                        height: "28",
+                        corner_radius: "14",
+                        background: c,
+                        border: if color() == c { "3 inner white" } else { "none" },
+                        onclick: move |_| color.set(c),
+                    }
+                }
+                Slider {
+                    width: "120",
+                    value: stroke_width(),
+                    onmoved: move |value| stroke_width.set(value),
+                }
+                Button {
+                    onpress: move |_| {
+                        controller.undo();
+                    },
+                    label { "Undo" }
+                }
+                Button {
+                    onpress: move |_| controller.clear(),
+                    label { "Clear" }
+                }
+                Button {
+                    onpress: onexport,
+                    label { "Export" }
+                }
+            }
+            DrawingCanvas {
+                controller,
+                color: color(),
+                stroke_width: 1. + stroke_width() as f32 / 5.,
+            }
+        }
+    )
+}
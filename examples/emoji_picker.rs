@@ -0,0 +1,31 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Emoji Picker", (400.0, 500.0));
+}
+
+fn app() -> Element {
+    let mut text = use_signal(String::new);
+
+    rsx!(
+        rect {
+            main_align: "center",
+            cross_align: "center",
+            height: "100%",
+            width: "100%",
+            spacing: "10",
+            label {
+                font_size: "24",
+                "{text}"
+            }
+            EmojiPicker {
+                onselect: move |emoji: String| text.write().push_str(&emoji)
+            }
+        }
+    )
+}
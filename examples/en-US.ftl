@@ -1,3 +1,8 @@
-hello_world = Hello, World!
-
-hello = Hello, {$name}!
\ No newline at end of file
+hello_world = Hello, World!
+
+hello = Hello, {$name}!
+
+apples = {$count ->
+    [one] One apple
+   *[other] {NUMBER($count)} apples
+}
@@ -1,3 +1,8 @@
-hello_world = Hola, Mundo!
-
-hello = Hola, {$name}!
\ No newline at end of file
+hello_world = Hola, Mundo!
+
+hello = Hola, {$name}!
+
+apples = {$count ->
+    [one] Una manzana
+   *[other] {NUMBER($count)} manzanas
+}
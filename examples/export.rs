@@ -0,0 +1,86 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Export", (600.0, 400.0));
+}
+
+fn app() -> Element {
+    let platform = use_platform();
+    let report = use_node_snapshot();
+    let mut status = use_signal(|| "Nothing exported yet".to_string());
+
+    let export = move |target: ExportTarget, format: ExportFormat| {
+        spawn(async move {
+            let path = format!("./report.{}", format.extension());
+            let result = platform.export(target, format, &path).await;
+            status.set(match result {
+                Ok(()) => format!("Exported to {path}"),
+                Err(err) => err.to_string(),
+            });
+        });
+    };
+
+    rsx!(
+        rect {
+            width: "fill",
+            padding: "8",
+            spacing: "8",
+            direction: "horizontal",
+            cross_align: "center",
+            Button {
+                onpress: {
+                    let report = report.clone();
+                    move |_| export((&report).into(), ExportFormat::Pdf)
+                },
+                label { "Export report as PDF" }
+            }
+            Button {
+                onpress: {
+                    let report = report.clone();
+                    move |_| export((&report).into(), ExportFormat::Svg)
+                },
+                label { "Export report as SVG" }
+            }
+            Button {
+                onpress: move |_| export(ExportTarget::Window, ExportFormat::Pdf),
+                label { "Export window as PDF" }
+            }
+        }
+        rect {
+            reference: report.attribute(),
+            margin: "8",
+            padding: "16",
+            spacing: "8",
+            corner_radius: "8",
+            background: "rgb(240, 240, 240)",
+            label {
+                font_size: "24",
+                font_weight: "bold",
+                "Monthly report"
+            }
+            label { "Revenue: 12.400€" }
+            label { "Expenses: 8.150€" }
+            rect {
+                width: "200",
+                height: "12",
+                corner_radius: "6",
+                background: "rgb(200, 200, 200)",
+                rect {
+                    width: "65%",
+                    height: "fill",
+                    corner_radius: "6",
+                    background: "rgb(0, 119, 182)",
+                }
+            }
+        }
+        label {
+            margin: "8",
+            "{status}"
+        }
+    )
+}
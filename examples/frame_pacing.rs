@@ -0,0 +1,109 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use std::time::Duration;
+
+use freya::prelude::*;
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::new()
+            .with_title("Frame pacing")
+            .with_max_fps(30)
+            .with_render_on_change(true),
+    );
+}
+
+fn app() -> Element {
+    let platform = use_platform();
+    let mut frame_pacing = use_signal(|| FramePacing {
+        max_fps: Some(30),
+        render_on_change: true,
+        ..FramePacing::default()
+    });
+    let mut stats = use_signal(FrameStats::default);
+
+    let animation = use_animation(|conf| {
+        conf.auto_start(true);
+        conf.on_finish(OnFinish::Restart);
+        AnimNum::new(0., 360.).time(2000)
+    });
+
+    use_hook(move || {
+        spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(1));
+            loop {
+                interval.tick().await;
+                if let Some(frame_stats) = platform.frame_stats().await {
+                    stats.set(frame_stats);
+                }
+            }
+        });
+    });
+
+    let mut update = move |edit: fn(&mut FramePacing)| {
+        edit(&mut frame_pacing.write());
+        platform.set_frame_pacing(frame_pacing());
+    };
+
+    let rotate = animation.get().read().read();
+    let FrameStats {
+        rendered_frames,
+        skipped_frames,
+        last_frame_duration,
+        fps,
+    } = stats();
+    let FramePacing {
+        vsync,
+        max_fps,
+        render_on_change,
+        background_max_fps,
+    } = frame_pacing();
+
+    rsx!(
+        rect {
+            height: "fill",
+            width: "fill",
+            main_align: "center",
+            cross_align: "center",
+            spacing: "8",
+            rect {
+                width: "80",
+                height: "80",
+                background: "rgb(131, 111, 255)",
+                rotate: "{rotate}deg",
+            }
+            label { "{fps} FPS, {rendered_frames} rendered, {skipped_frames} skipped, last took {last_frame_duration:?}" }
+            Button {
+                onpress: move |_| update(|pacing| pacing.vsync = !pacing.vsync),
+                label { "VSync: {vsync}" }
+            }
+            Button {
+                onpress: move |_| update(|pacing| {
+                    pacing.max_fps = match pacing.max_fps {
+                        Some(30) => Some(60),
+                        Some(_) => None,
+                        None => Some(30),
+                    }
+                }),
+                label { "Max FPS: {max_fps:?}" }
+            }
+            Button {
+                onpress: move |_| update(|pacing| pacing.render_on_change = !pacing.render_on_change),
+                label { "Render on change: {render_on_change}" }
+            }
+            Button {
+                onpress: move |_| update(|pacing| {
+                    pacing.background_max_fps = match pacing.background_max_fps {
+                        Some(_) => None,
+                        None => Some(5),
+                    }
+                }),
+                label { "Background max FPS: {background_max_fps:?}" }
+            }
+        }
+    )
+}
@@ -0,0 +1,52 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Global Shortcuts", (400.0, 200.0));
+}
+
+fn app() -> Element {
+    let shortcuts = use_global_shortcuts();
+    let platform = use_platform();
+    let mut status = use_signal(|| "Registering...".to_string());
+
+    use_hook(move || {
+        spawn(async move {
+            let result = shortcuts.register("show", "CmdOrCtrl+Shift+Space").await;
+            status.set(match result {
+                Ok(()) => "Minimize the window and press CmdOrCtrl+Shift+Space".to_string(),
+                Err(err) => err.to_string(),
+            });
+        });
+    });
+
+    use_effect(move || {
+        if let Some(GlobalShortcutEvent {
+            id,
+            state: GlobalShortcutState::Pressed,
+        }) = shortcuts.event()
+        {
+            if id == "show" {
+                platform.with_window(|window| {
+                    window.set_minimized(false);
+                    window.set_visible(true);
+                    window.focus_window();
+                });
+            }
+        }
+    });
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            main_align: "center",
+            cross_align: "center",
+            label { "{status}" }
+        }
+    )
+}
@@ -3,20 +3,23 @@
     windows_subsystem = "windows"
 )]
 
-use dioxus_i18n::{
-    prelude::*,
-    t,
-    unic_langid::langid,
-};
+#[cfg(feature = "use_i18n")]
 use freya::prelude::*;
 
+#[cfg(not(feature = "use_i18n"))]
+fn main() {
+    panic!("Run with the 'use_i18n' feature");
+}
+
+#[cfg(feature = "use_i18n")]
 fn main() {
     launch_with_props(app, "freya + i18n", (300.0, 200.0));
 }
 
+#[cfg(feature = "use_i18n")]
 #[allow(non_snake_case)]
 fn Body() -> Element {
-    let mut i18n = i18n();
+    let mut i18n = use_i18n();
 
     let change_to_english = move |_| i18n.set_language(langid!("en-US"));
     let change_to_spanish = move |_| i18n.set_language(langid!("es-ES"));
@@ -46,10 +49,12 @@ fn Body() -> Element {
             }
 
             label { {t!("hello", name: "Dioxus")} }
+            label { {t!("apples", count: 3)} }
         }
     )
 }
 
+#[cfg(feature = "use_i18n")]
 fn app() -> Element {
     use_init_i18n(|| {
         I18nConfig::new(langid!("en-US"))
@@ -61,6 +66,7 @@ fn app() -> Element {
                 langid!("es-ES"),
                 include_str!("./es-ES.ftl"),
             ))
+            .with_os_language()
     });
 
     rsx!(Body {})
@@ -0,0 +1,63 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Markdown", (700.0, 600.0));
+}
+
+const CONTENT: &str = r#"# Freya
+
+Freya is a **cross-platform** GUI library for Rust, powered by [Dioxus](https://dioxuslabs.com) and [Skia](https://skia.org).
+
+> Still in *development*, expect ~~breaking~~ changes.
+
+## Features
+
+- Components
+- Theming
+  1. Light
+  2. Dark
+- Animations
+
+## Roadmap
+
+- [x] Markdown
+- [ ] Math
+
+## Example
+
+```rust
+fn app() -> Element {
+    rsx!(label { "Hello, World!" })
+}
+```
+
+| Platform | Supported |
+|----------|:---------:|
+| Windows  | Yes       |
+| macOS    | Yes       |
+| Linux    | Yes       |
+
+---
+
+![Freya](https://raw.githubusercontent.com/marc2332/freya/refs/heads/main/examples/rust_logo.png)
+"#;
+
+fn app() -> Element {
+    rsx!(
+        ScrollView {
+            padding: "16",
+            Markdown {
+                content: CONTENT,
+                onlink: move |url: String| {
+                    println!("Opening {url}");
+                    open_link(url.as_str());
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,83 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::new()
+            .with_size(400.0, 300.0)
+            .with_title("Native Menu")
+            .with_menu(
+                NativeMenu::new()
+                    .with_submenu(
+                        NativeSubmenu::new("Counter")
+                            .with_item(
+                                NativeMenuItem::new("increase", "Increase")
+                                    .accelerator("CmdOrCtrl+Up"),
+                            )
+                            .with_item(
+                                NativeMenuItem::new("decrease", "Decrease")
+                                    .accelerator("CmdOrCtrl+Down"),
+                            )
+                            .with_separator()
+                            .with_item(
+                                NativeMenuItem::new("reset", "Reset").accelerator("CmdOrCtrl+R"),
+                            ),
+                    )
+                    .with_submenu(
+                        NativeSubmenu::new("View").with_item(
+                            NativeMenuItem::new("dark", "Dark Theme")
+                                .checkable(false)
+                                .accelerator("CmdOrCtrl+Shift+D"),
+                        ),
+                    ),
+            ),
+    );
+}
+
+fn app() -> Element {
+    let menu = use_menu();
+    let mut count = use_signal(|| 0);
+    let mut dark = use_signal(|| false);
+
+    use_effect(move || {
+        let Some(NativeMenuEvent { id, checked }) = menu.event() else {
+            return;
+        };
+        match id.as_str() {
+            "increase" => *count.write() += 1,
+            "decrease" => *count.write() -= 1,
+            "reset" => count.set(0),
+            "dark" => dark.set(checked.unwrap_or_default()),
+            _ => {}
+        }
+    });
+
+    // Nothing to decrease below zero
+    use_effect(move || menu.set_enabled("decrease", count() > 0));
+
+    let (background, color) = if dark() {
+        ("rgb(30, 30, 30)", "white")
+    } else {
+        ("white", "black")
+    };
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            main_align: "center",
+            cross_align: "center",
+            background,
+            color,
+            label {
+                font_size: "50",
+                "{count}"
+            }
+        }
+    )
+}
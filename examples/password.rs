@@ -12,6 +12,7 @@ fn main() {
 fn app() -> Element {
     let mut password = use_signal(String::new);
     let mut is_hidden = use_signal(|| true);
+    let mut pin = use_signal(String::new);
 
     rsx!(
         rect {
@@ -47,6 +48,24 @@ fn app() -> Element {
                     }
                 }
             }
+            label {
+                color: "black",
+                "PIN:"
+            }
+            Input {
+                mode: InputMode::new_password(),
+                reveal_toggle: true,
+                max_length: 6,
+                placeholder: "Only numbers",
+                value: pin.read().clone(),
+                onvalidate: |validator: InputValidator| {
+                    let is_number = validator.text().chars().all(|ch| ch.is_ascii_digit());
+                    validator.set_valid(is_number);
+                },
+                onchange: move |e| {
+                    pin.set(e)
+                }
+            }
         }
     )
 }
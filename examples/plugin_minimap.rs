@@ -0,0 +1,126 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    plugins::{
+        FreyaPlugin,
+        PluginEvent,
+        PluginHandle,
+    },
+};
+use skia_safe::{
+    Color,
+    Paint,
+    PaintStyle,
+    Rect,
+};
+
+/// Toggle the minimap.
+struct ToggleMinimap;
+
+/// Draws a small map of the layout in the corner of the window.
+#[derive(Default)]
+struct MinimapPlugin {
+    hidden: bool,
+    areas: Vec<(Rect, i16)>,
+    width: f32,
+    height: f32,
+}
+
+impl FreyaPlugin for MinimapPlugin {
+    fn on_event(&mut self, event: &PluginEvent, handle: PluginHandle) {
+        if event.message::<ToggleMinimap>().is_some() {
+            self.hidden = !self.hidden;
+            handle.send_event_loop_event(EventLoopMessage::RequestRerender);
+            return;
+        }
+
+        match event {
+            PluginEvent::AfterLayout { freya_dom, .. } => {
+                let layout = freya_dom.layout();
+                let layers = freya_dom.layers();
+                self.areas.clear();
+                for (layer, nodes) in layers.iter() {
+                    for node_id in nodes {
+                        if let Some(layout_node) = layout.get(*node_id) {
+                            let area = layout_node.visible_area();
+                            self.areas.push((
+                                Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                                *layer,
+                            ));
+                            self.width = self.width.max(area.max_x());
+                            self.height = self.height.max(area.max_y());
+                        }
+                    }
+                }
+            }
+            PluginEvent::AfterRender { canvas, .. } if !self.hidden => {
+                let scale = 0.2;
+                canvas.save();
+                canvas.translate((self.width * (1. - scale) - 10., 10.));
+                canvas.scale((scale, scale));
+
+                let mut paint = Paint::default();
+                paint.set_anti_alias(true);
+                paint.set_color(Color::from_argb(200, 30, 30, 30));
+                canvas.draw_rect(Rect::from_wh(self.width, self.height), &paint);
+
+                paint.set_style(PaintStyle::Stroke);
+                paint.set_stroke_width(2. / scale);
+                for (area, layer) in &self.areas {
+                    let shade = (*layer as i32 * 40).clamp(-120, 120);
+                    paint.set_color(Color::from_rgb(
+                        (135 - shade) as u8,
+                        206,
+                        (135 + shade) as u8,
+                    ));
+                    canvas.draw_rect(area, &paint);
+                }
+                canvas.restore();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::new()
+            .with_plugin(MinimapPlugin::default())
+            .with_size(700.0, 500.),
+    )
+}
+
+fn app() -> Element {
+    let platform = use_platform();
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            padding: "16",
+            spacing: "8",
+            Button {
+                onpress: move |_| platform.send_plugin_message(ToggleMinimap),
+                label { "Toggle minimap" }
+            }
+            for i in 0..8 {
+                rect {
+                    key: "{i}",
+                    width: "{200 + i * 30}",
+                    height: "32",
+                    corner_radius: "6",
+                    background: "rgb(230, 230, 230)",
+                    main_align: "center",
+                    padding: "0 8",
+                    label { "Row {i}" }
+                }
+            }
+        }
+    )
+}
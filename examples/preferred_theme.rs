@@ -9,40 +9,37 @@ fn main() {
     launch(app);
 }
 
-fn get_theme(preferred_theme: PreferredTheme) -> Theme {
-    match preferred_theme {
-        PreferredTheme::Dark => DARK_THEME,
-        PreferredTheme::Light => LIGHT_THEME,
-    }
-}
-
 fn app() -> Element {
-    let preferred_theme = use_preferred_theme();
-    let mut current_theme = use_init_theme(|| get_theme(*preferred_theme.peek()));
+    let mut preference = use_init_theme_with_preference(|| PreferredTheme::Auto);
+    let system_theme = *use_preferred_theme().read();
+    let accent_color = use_accent_color();
+    let theme = use_theme();
 
-    let is_dark = current_theme.read().name == "dark";
-
-    use_memo(move || {
-        let theme = get_theme(preferred_theme());
-        if theme != *current_theme.peek() {
-            current_theme.set(theme);
-        }
-    });
+    let accent = accent_color
+        .read()
+        .to_rgb()
+        .unwrap_or_else(|| "Unknown".to_string());
 
     rsx!(
         Body {
-            Switch {
-                enabled: is_dark,
-                ontoggled: move |_| {
-                    if is_dark {
-                        *current_theme.write() = LIGHT_THEME
-                    } else {
-                        *current_theme.write() = DARK_THEME
+            Dropdown {
+                value: format!("{:?}", preference()),
+                for variant in [PreferredTheme::Auto, PreferredTheme::Light, PreferredTheme::Dark] {
+                    DropdownItem {
+                        value: format!("{variant:?}"),
+                        onpress: move |_| preference.set(variant),
+                        label { "{variant:?}" }
                     }
                 }
             }
             label {
-                "Current Theme: {current_theme.read().name}"
+                "Current Theme: {theme.read().name}"
+            }
+            label {
+                "System Theme: {system_theme:?}"
+            }
+            label {
+                "Accent Color: {accent}"
             }
         }
     )
@@ -0,0 +1,110 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Print", (600.0, 500.0));
+}
+
+fn app() -> Element {
+    let platform = use_platform();
+    let report = use_node_snapshot();
+    let mut printers = use_signal(Vec::<Printer>::new);
+    let mut selected = use_signal(|| None::<String>);
+    let mut status = use_signal(|| "Nothing printed yet".to_string());
+
+    use_hook(move || {
+        spawn(async move {
+            let list = platform.printers().await;
+            selected.set(
+                list.iter()
+                    .find(|printer| printer.is_default)
+                    .map(|printer| printer.name.clone()),
+            );
+            printers.set(list);
+        });
+    });
+
+    let print = move |target: ExportTarget| {
+        spawn(async move {
+            let mut options = PrintOptions::new().with_job_name("Monthly report");
+            if let Some(printer) = selected() {
+                options = options.with_printer(printer);
+            }
+            status.set(match platform.print(target, options).await {
+                Ok(pages) => format!("Printed {pages} pages"),
+                Err(err) => err.to_string(),
+            });
+        });
+    };
+
+    rsx!(
+        rect {
+            width: "fill",
+            padding: "8",
+            spacing: "8",
+            direction: "horizontal",
+            cross_align: "center",
+            for printer in printers() {
+                Button {
+                    key: "{printer.name}",
+                    onpress: {
+                        let name = printer.name.clone();
+                        move |_| selected.set(Some(name.clone()))
+                    },
+                    label {
+                        if selected().as_ref() == Some(&printer.name) {
+                            "✓ {printer.name}"
+                        } else {
+                            "{printer.name}"
+                        }
+                    }
+                }
+            }
+            Button {
+                onpress: {
+                    let report = report.clone();
+                    move |_| print((&report).into())
+                },
+                label { "Print report" }
+            }
+        }
+        ScrollView {
+            rect {
+                reference: report.attribute(),
+                padding: "16",
+                spacing: "8",
+                for month in ["January", "February", "March"] {
+                    rect {
+                        key: "{month}",
+                        page_break: "after",
+                        spacing: "8",
+                        label {
+                            font_size: "24",
+                            font_weight: "bold",
+                            "{month}"
+                        }
+                        for day in 1..=20 {
+                            rect {
+                                key: "{day}",
+                                page_break: "avoid",
+                                padding: "8",
+                                corner_radius: "6",
+                                background: "rgb(240, 240, 240)",
+                                label { "{month} {day}" }
+                                label { "Revenue: {day * 120}€" }
+                            }
+                        }
+                    }
+                }
+            }
+            label {
+                margin: "8",
+                "{status}"
+            }
+        }
+    )
+}
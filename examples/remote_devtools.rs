@@ -0,0 +1,36 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+//! Inspect an app running in another process.
+//!
+//! Launch the app to inspect with the `FREYA_REMOTE_DEVTOOLS` environment variable, e.g.:
+//! `FREYA_REMOTE_DEVTOOLS=127.0.0.1:7878 cargo run --example counter --features remote-devtools --release`
+//! and then this example with `cargo run --example remote_devtools --features remote-devtools`.
+
+#[cfg(feature = "remote-devtools")]
+use freya::{
+    prelude::*,
+    remote_devtools::{
+        RemoteDevTools,
+        DEFAULT_REMOTE_DEVTOOLS_ADDRESS,
+    },
+};
+
+#[cfg(not(feature = "remote-devtools"))]
+fn main() {
+    panic!("Run with the 'remote-devtools' feature");
+}
+
+#[cfg(feature = "remote-devtools")]
+fn main() {
+    launch_with_props(app, "Remote Devtools", (1000.0, 700.0));
+}
+
+#[cfg(feature = "remote-devtools")]
+fn app() -> Element {
+    rsx!(RemoteDevTools {
+        address: DEFAULT_REMOTE_DEVTOOLS_ADDRESS.to_string()
+    })
+}
@@ -0,0 +1,67 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+static SANSITA_SWASHED: &[u8] = include_bytes!("./SansitaSwashed-Regular.ttf");
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::new()
+            .with_size(400.0, 300.)
+            .with_font_fallback(FontScript::Emoji, ["Noto Color Emoji", "Twemoji"]),
+    );
+}
+
+fn app() -> Element {
+    let fonts = use_fonts();
+    let mut family = use_signal(|| None::<String>);
+    let mut status = use_signal(|| "Using the default font".to_string());
+
+    let register = move |_| {
+        spawn(async move {
+            let source = FontSource::Bytes(SANSITA_SWASHED.into());
+            match fonts.register(source).await {
+                Ok(font) => {
+                    status.set(format!("Registered '{font}'"));
+                    family.set(Some(font));
+                }
+                Err(err) => status.set(err.to_string()),
+            }
+        });
+    };
+
+    let check = move |_| {
+        spawn(async move {
+            let available = fonts.is_available("Noto Sans CJK JP").await;
+            status.set(format!("Noto Sans CJK JP available: {available}"));
+        });
+    };
+
+    rsx!(
+        rect {
+            main_align: "center",
+            cross_align: "center",
+            height: "100%",
+            width: "100%",
+            spacing: "10",
+            label {
+                font_size: "24",
+                font_family: family().unwrap_or_default(),
+                "Hello, World! 👋 你好"
+            }
+            label { "{status}" }
+            Button {
+                onpress: register,
+                label { "Register font" }
+            }
+            Button {
+                onpress: check,
+                label { "Check CJK font" }
+            }
+        }
+    )
+}
@@ -124,6 +124,7 @@ fn ShaderEditor(editable: UseEditable) -> Element {
                     };
 
                     let highlights = editable.highlights_attr(line_index);
+                    let carets = editable.carets_attr(line_index);
 
                     rsx! {
                         rect {
@@ -154,6 +155,7 @@ fn ShaderEditor(editable: UseEditable) -> Element {
                                 onmousedown,
                                 onmousemove,
                                 highlights,
+                                carets,
                                 highlight_mode: "expanded",
                                 text {
                                     color: "rgb(240, 240, 240)",
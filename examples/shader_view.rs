@@ -0,0 +1,58 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use std::collections::HashMap;
+
+use freya::prelude::*;
+
+fn main() {
+    launch(app);
+}
+
+const SHADER: &str = "
+ uniform vec2 u_resolution;
+ uniform vec2 u_cursor;
+ uniform float u_time;
+ uniform float u_intensity;
+
+ vec4 main(vec2 coords) {
+     vec2 uv = coords / u_resolution;
+     float dist = distance(coords, u_cursor) / length(u_resolution);
+     float glow = u_intensity * (1.0 - smoothstep(0.0, 0.3, dist));
+     return vec4(uv.x + glow, uv.y, abs(sin(u_time)) + glow, 1.0);
+ }
+ ";
+
+fn app() -> Element {
+    let mut intensity = use_signal(|| 50.0);
+
+    let uniforms = HashMap::from([(
+        "u_intensity".to_string(),
+        UniformValue::Float(intensity() as f32 / 100.),
+    )]);
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            ShaderView {
+                sksl: SHADER,
+                uniforms,
+                height: "calc(100% - 50)",
+            }
+            rect {
+                height: "50",
+                main_align: "center",
+                cross_align: "center",
+                width: "100%",
+                Slider {
+                    size: "200",
+                    value: intensity(),
+                    onmoved: move |value| intensity.set(value),
+                }
+            }
+        }
+    )
+}
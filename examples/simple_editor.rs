@@ -25,6 +25,7 @@ fn app() -> Element {
 
     let cursor_reference = editable.cursor_attr();
     let highlights = editable.highlights_attr(0);
+    let carets = editable.carets_attr(0);
     let editor = editable.editor().read();
     let cursor_char = editor.cursor_pos();
 
@@ -65,6 +66,7 @@ fn app() -> Element {
                     cursor_mode: "editable",
                     cursor_color: "black",
                     highlights,
+                    carets,
                     cursor_reference,
                     onclick,
                     onmousemove,
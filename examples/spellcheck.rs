@@ -0,0 +1,133 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+const WORDS: &[&str] = &[
+    "a",
+    "and",
+    "are",
+    "brown",
+    "dog",
+    "fox",
+    "hello",
+    "is",
+    "jumps",
+    "lazy",
+    "over",
+    "quick",
+    "spelling",
+    "the",
+    "this",
+    "underlined",
+    "with",
+    "words",
+    "world",
+    "wrong",
+];
+
+fn main() {
+    launch_with_props(app, "Spellcheck", (600.0, 400.0));
+}
+
+fn app() -> Element {
+    let mut editable = use_editable(
+        || {
+            EditableConfig::new(
+                "Helo world!\nThe quik brown fox jumps ovr the lazy dog.\nWords with wrong speling are underlined."
+                    .to_string(),
+            )
+        },
+        EditableMode::MultipleLinesSingleEditor,
+    );
+    let mut spellcheck = use_spellcheck(editable, || WordListSpellChecker::new(WORDS));
+    let mut show_corrections = use_signal(|| false);
+
+    let cursor_reference = editable.cursor_attr();
+    let highlights = editable.highlights_attr(0);
+    let spelling_errors = spellcheck.errors_attr(0);
+    let cursor_char = editable.editor().read().cursor_pos();
+    let error = spellcheck
+        .error_at_cursor()
+        .filter(|_| *show_corrections.read());
+
+    let onmousedown = move |e: MouseEvent| {
+        show_corrections.set(false);
+        editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+    };
+
+    let onmousemove = move |e: MouseEvent| {
+        editable.process_event(&EditableEvent::MouseMove(e.data, 0));
+    };
+
+    let onclick = move |_: MouseEvent| {
+        editable.process_event(&EditableEvent::Click);
+    };
+
+    // The cursor is moved to the clicked word by `onmousedown` first
+    let onrightclick = move |_: MouseEvent| {
+        editable.process_event(&EditableEvent::Click);
+        show_corrections.set(true);
+    };
+
+    let onglobalkeydown = move |e: KeyboardEvent| {
+        editable.process_event(&EditableEvent::KeyDown(e.data));
+    };
+
+    let onglobalkeyup = move |e: KeyboardEvent| {
+        editable.process_event(&EditableEvent::KeyUp(e.data));
+    };
+
+    rsx!(
+        Body {
+            padding: "20",
+            spacing: "10",
+            Switch {
+                enabled: spellcheck.enabled(),
+                ontoggled: move |_| {
+                    let enabled = spellcheck.enabled();
+                    spellcheck.set_enabled(!enabled);
+                }
+            }
+            paragraph {
+                width: "fill",
+                cursor_id: "0",
+                cursor_index: "{cursor_char}",
+                cursor_mode: "editable",
+                highlights,
+                spelling_errors,
+                cursor_reference,
+                onclick,
+                onmousemove,
+                onmousedown,
+                onrightclick,
+                onglobalkeydown,
+                onglobalkeyup,
+                text {
+                    font_size: "18",
+                    "{editable.editor()}"
+                }
+            }
+            if let Some(error) = error {
+                Menu {
+                    onclose: move |_| show_corrections.set(false),
+                    for suggestion in spellcheck.suggestions(&error) {
+                        MenuButton {
+                            onpress: {
+                                let error = error.clone();
+                                let suggestion = suggestion.clone();
+                                move |_| {
+                                    spellcheck.correct(&error, &suggestion);
+                                    show_corrections.set(false);
+                                }
+                            },
+                            label { "{suggestion}" }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,55 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+#[cfg(feature = "terminal")]
+use freya::prelude::*;
+
+#[cfg(not(feature = "terminal"))]
+fn main() {
+    panic!("Run with the 'terminal' feature");
+}
+
+#[cfg(feature = "terminal")]
+fn main() {
+    launch_with_props(app, "Terminal", (900.0, 600.0));
+}
+
+#[cfg(feature = "terminal")]
+fn app() -> Element {
+    let mut title = use_signal(|| "Shell".to_string());
+    let mut exit_code = use_signal(|| None::<Option<u32>>);
+    let mut session = use_signal(|| 0);
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "32",
+            padding: "8",
+            direction: "horizontal",
+            cross_align: "center",
+            spacing: "8",
+            label {
+                "{title}"
+            }
+            if let Some(exit_code) = exit_code() {
+                label {
+                    "Exited with {exit_code:?}"
+                }
+                Button {
+                    onpress: move |_| {
+                        exit_code.set(None);
+                        session += 1;
+                    },
+                    label { "Restart" }
+                }
+            }
+        }
+        Terminal {
+            key: "{session}",
+            ontitle: move |new_title| title.set(new_title),
+            onexit: move |code| exit_code.set(Some(code)),
+        }
+    )
+}
@@ -67,6 +67,7 @@ fn Editor(mut editable: UseEditable) -> Element {
     let mut focus = use_focus();
     let cursor_reference = editable.cursor_attr();
     let highlights = editable.highlights_attr(0);
+    let carets = editable.carets_attr(0);
     let editor = editable.editor().read();
     let cursor_char = editor.cursor_pos();
 
@@ -103,6 +104,7 @@ fn Editor(mut editable: UseEditable) -> Element {
                 cursor_mode: "editable",
                 cursor_color: "black",
                 highlights,
+                carets,
                 onglobalclick,
                 onmousemove,
                 onmousedown,
@@ -0,0 +1,76 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+#[cfg(feature = "use_tts")]
+use freya::prelude::*;
+
+#[cfg(not(feature = "use_tts"))]
+fn main() {
+    panic!("Run with the 'use_tts' feature");
+}
+
+#[cfg(feature = "use_tts")]
+fn main() {
+    launch_with_props(app, "Text to speech", (500.0, 400.0));
+}
+
+#[cfg(feature = "use_tts")]
+fn app() -> Element {
+    let mut tts = use_tts();
+    let mut text = use_signal(|| "Hello, World!".to_string());
+
+    let speaking = if *tts.is_speaking().read() {
+        "Speaking..."
+    } else {
+        "Silent"
+    };
+
+    rsx!(
+        rect {
+            main_align: "center",
+            cross_align: "center",
+            height: "100%",
+            width: "100%",
+            spacing: "10",
+            Input {
+                value: text(),
+                width: "300",
+                onchange: move |value| text.set(value),
+            }
+            Slider {
+                width: "200",
+                value: tts.rate()() as f64 * 50.,
+                onmoved: move |value: f64| tts.set_rate(value as f32 / 50.),
+            }
+            Dropdown {
+                value: tts.voice()().unwrap_or_else(|| "Default voice".to_string()),
+                for voice in tts.voices().read().iter().cloned() {
+                    DropdownItem {
+                        key: "{voice.id}",
+                        value: voice.id.clone(),
+                        onpress: move |_| tts.set_voice(voice.id.clone()),
+                        label { "{voice.name} ({voice.language})" }
+                    }
+                }
+            }
+            rect {
+                direction: "horizontal",
+                spacing: "10",
+                Button {
+                    onpress: move |_| tts.speak(text()),
+                    label { "Speak" }
+                }
+                Button {
+                    onpress: move |_| tts.stop(),
+                    label { "Stop" }
+                }
+            }
+            label { "{speaking}" }
+            if let Some(err) = &*tts.error().read() {
+                label { "{err}" }
+            }
+        }
+    )
+}
@@ -0,0 +1,78 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+#[cfg(feature = "webview")]
+use freya::prelude::*;
+
+#[cfg(not(feature = "webview"))]
+fn main() {
+    panic!("Run with the 'webview' feature");
+}
+
+#[cfg(feature = "webview")]
+fn main() {
+    launch_with_props(app, "WebView", (800.0, 600.0));
+}
+
+#[cfg(feature = "webview")]
+const PAGE: &str = r#"
+<html>
+    <body style="font-family: sans-serif">
+        <h1>Hello from the web</h1>
+        <button onclick="window.freya.postMessage('Clicked at ' + new Date().toLocaleTimeString())">
+            Send a message to Freya
+        </button>
+        <p id="reply"></p>
+        <script>
+            window.freya.onmessage = (message) => {
+                document.getElementById("reply").textContent = message;
+            };
+        </script>
+    </body>
+</html>
+"#;
+
+#[cfg(feature = "webview")]
+fn app() -> Element {
+    let controller = use_webview_controller();
+    let mut last_message = use_signal(|| None::<String>);
+    let mut show_docs = use_signal(|| false);
+
+    rsx!(
+        rect {
+            width: "fill",
+            padding: "8",
+            spacing: "8",
+            direction: "horizontal",
+            cross_align: "center",
+            Button {
+                onpress: move |_| show_docs.toggle(),
+                label {
+                    if show_docs() { "Show page" } else { "Show docs" }
+                }
+            }
+            Button {
+                onpress: move |_| controller.post_message("Hello from Freya!"),
+                label { "Send a message to the page" }
+            }
+            label {
+                {last_message().unwrap_or_else(|| "No messages yet".to_string())}
+            }
+        }
+        if show_docs() {
+            WebView {
+                key: "docs",
+                url: "https://docs.rs/freya",
+            }
+        } else {
+            WebView {
+                key: "page",
+                controller,
+                html: PAGE,
+                onmessage: move |message| last_message.set(Some(message)),
+            }
+        }
+    )
+}
@@ -0,0 +1,70 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Zoomable View", (700.0, 500.0));
+}
+
+fn app() -> Element {
+    let selected = use_signal(|| None::<usize>);
+
+    rsx!(
+        ZoomableView {
+            show_minimap: true,
+            rect {
+                padding: "40",
+                spacing: "40",
+                background: "rgb(225, 235, 245)",
+                for row in 0..3 {
+                    rect {
+                        key: "{row}",
+                        direction: "horizontal",
+                        spacing: "40",
+                        for column in 0..4 {
+                            Place {
+                                key: "{column}",
+                                place: row * 4 + column,
+                                selected
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        label {
+            position: "absolute",
+            position_top: "8",
+            position_left: "8",
+            "Drag to pan, Ctrl + wheel or pinch to zoom"
+        }
+    )
+}
+
+#[component]
+fn Place(place: usize, mut selected: Signal<Option<usize>>) -> Element {
+    let (background, color) = if selected() == Some(place) {
+        ("rgb(103, 80, 164)", "white")
+    } else {
+        ("white", "black")
+    };
+
+    rsx!(
+        rect {
+            width: "240",
+            height: "160",
+            corner_radius: "12",
+            main_align: "center",
+            cross_align: "center",
+            background,
+            onclick: move |_| selected.set(Some(place)),
+            label {
+                color,
+                "Place {place}"
+            }
+        }
+    )
+}